@@ -0,0 +1,54 @@
+use std::ffi::CStr;
+
+use kand::{
+    TAInt,
+    types::{BarType, MAType, PaymentTiming, PriceSource},
+};
+use pyo3::{exceptions::PyDeprecationWarning, prelude::*};
+
+/// Emits a `DeprecationWarning` at the Python call site of a legacy binding.
+pub fn warn_deprecated(py: Python, message: &CStr) -> PyResult<()> {
+    PyErr::warn(
+        py,
+        py.get_type::<PyDeprecationWarning>().as_any(),
+        message,
+        1,
+    )
+}
+
+/// Converts the integer moving average type passed from Python into an `MAType`.
+///
+/// The values follow `MAType`: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA, 4=RMA, 5=SMA, 6=T3, 7=TEMA,
+/// 8=TRIMA, 9=WMA.
+pub fn parse_ma_type(ma_type: TAInt) -> PyResult<MAType> {
+    MAType::try_from(ma_type).map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid MA type: {ma_type}"))
+    })
+}
+
+/// Converts the integer payment timing passed from Python into a `PaymentTiming`.
+///
+/// The values follow `PaymentTiming`: 0=end of each period, 1=beginning of each period.
+pub fn parse_payment_timing(when: TAInt) -> PyResult<PaymentTiming> {
+    PaymentTiming::try_from(when).map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid payment timing: {when}"))
+    })
+}
+
+/// Converts the integer price source passed from Python into a `PriceSource`.
+///
+/// The values follow `PriceSource`: 0=close, 1=high for upward and low for downward moves.
+pub fn parse_price_source(source: TAInt) -> PyResult<PriceSource> {
+    PriceSource::try_from(source).map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid price source: {source}"))
+    })
+}
+
+/// Converts the integer bar type passed from Python into a `BarType`.
+///
+/// The values follow `BarType`: 0=time, 1=tick, 2=volume, 3=dollar, 4=tick imbalance.
+pub fn parse_bar_type(bar_type: TAInt) -> PyResult<BarType> {
+    BarType::try_from(bar_type).map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid bar type: {bar_type}"))
+    })
+}
@@ -1,5 +1,6 @@
 use pyo3::prelude::*;
 
+pub mod helper;
 pub mod ta;
 
 /// A Python module implemented in Rust.
@@ -8,6 +9,11 @@
 #[pyo3(name = "_kand")]
 fn kand(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
+    // Add all bar functions
+    m.add_function(wrap_pyfunction!(ta::bars::mtf::align_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::bars::resample::resample_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::bars::trade_bars::trade_bars_py, m)?)?;
+
     // Add all OHLCV functions
     m.add_function(wrap_pyfunction!(ta::ohlcv::ad::ad_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::ad::ad_inc_py, m)?)?;
@@ -17,12 +23,14 @@ fn kand(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(ta::ohlcv::adx::adx_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::adxr::adxr_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::adxr::adxr_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::apo::apo_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::aroon::aroon_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::aroon::aroon_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::aroonosc::aroonosc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::aroonosc::aroonosc_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::atr::atr_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::atr::atr_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::bandwidth::bandwidth_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::bbands::bbands_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::bbands::bbands_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::bop::bop_py, m)?)?;
@@ -43,25 +51,47 @@ fn kand(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_long_shadow::cdl_long_shadow_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_marubozu::cdl_marubozu_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::cdl_marubozu::cdl_marubozu_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cmf::cmf_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cmf::cmf_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cmo::cmo_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::cmo::cmo_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::dema::dema_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::dema::dema_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::donchian::donchian_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::dx::dx_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::dx::dx_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::ecl::ecl_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::ecl::ecl_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::ema::ema_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::ema::ema_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::emv::emv_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::emv::emv_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::force_index::force_index_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::force_index::force_index_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ichimoku::ichimoku_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::kagi::kagi_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::kama::kama_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::kama::kama_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::keltner::keltner_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::keltner::keltner_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::line_break::line_break_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ma::ma_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::macd::macd_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::macd::macd_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::macdext::macdext_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::mama::mama_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::medprice::medprice_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::medprice::medprice_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::mfi::mfi_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::mfi::mfi_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::midpoint::midpoint_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::midpoint::midpoint_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::midprice::midprice_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::midprice::midprice_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::minus_di::minus_di_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::minus_di::minus_di_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::minus_dm::minus_dm_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::minus_dm::minus_dm_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::mom::mom_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::mom::mom_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::natr::natr_py, m)?)?;
@@ -72,6 +102,13 @@ fn kand(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(ta::ohlcv::plus_di::plus_di_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::plus_dm::plus_dm_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::plus_dm::plus_dm_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::pnf::pnf_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ppo::ppo_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::pvt::pvt_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::pvt::pvt_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::range_bars::range_bars_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::renko::renko_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::renko::renko_atr_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::rma::rma_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::rma::rma_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::roc::roc_py, m)?)?;
@@ -86,9 +123,15 @@ fn kand(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(ta::ohlcv::rsi::rsi_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::sar::sar_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::sar::sar_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::sarext::sarext_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::sarext::sarext_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::sma::sma_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::sma::sma_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::starc::starc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::starc::starc_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::stoch::stoch_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::stochf::stochf_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::stochrsi::stochrsi_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::supertrend::supertrend_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::supertrend::supertrend_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::t3::t3_py, m)?)?;
@@ -103,6 +146,8 @@ fn kand(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(ta::ohlcv::trix::trix_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::typprice::typprice_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::typprice::typprice_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ultosc::ultosc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::ultosc::ultosc_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::vegas::vegas_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::vegas::vegas_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::wclprice::wclprice_py, m)?)?;
@@ -111,20 +156,84 @@ fn kand(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(ta::ohlcv::willr::willr_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::wma::wma_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::wma::wma_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::volume_index::nvi_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::volume_index::nvi_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::volume_index::pvi_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::volume_index::pvi_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::vwap::vwap_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::ohlcv::vwap::vwap_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::vwap_bands::anchored_vwap_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::vwap_bands::session_vwap_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::vwap_bands::vwap_bands_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::ohlcv::vwap_bands::vwap_bands_inc_py, m)?)?;
 
     // Add all stats functions
+    m.add_function(wrap_pyfunction!(ta::stats::alpha::alpha_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::alpha::alpha_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::beta::beta_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::beta::beta_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::calmar::calmar_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::calmar::calmar_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::calmar::calmar_expanding_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::calmar::calmar_expanding_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::correl::correl_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::correl::correl_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::drawdown::drawdown_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::drawdown::drawdown_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::drawdown::drawdown_expanding_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::drawdown::drawdown_expanding_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::fv::fv_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::fv::fv_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::irr::irr_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::kelly::kelly_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::kelly::kelly_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::kelly::kelly_expanding_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::kelly::kelly_expanding_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::max::max_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::max::max_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::min::min_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::min::min_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::nper::nper_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::nper::nper_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::pmt::pmt_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::pmt::pmt_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::pv::pv_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::pv::pv_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::rate::rate_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::rate::rate_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::ret_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::ret_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::ret::ret_expanding_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sharpe::sharpe_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sharpe::sharpe_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sharpe::sharpe_expanding_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sharpe::sharpe_expanding_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sortino::sortino_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sortino::sortino_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sortino::sortino_expanding_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::sortino::sortino_expanding_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::stddev::stddev_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::stddev::stddev_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::sum::sum_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::sum::sum_inc_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::var::var_py, m)?)?;
     m.add_function(wrap_pyfunction!(ta::stats::var::var_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::winrate::trade_pnl_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::winrate::trade_pnl_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::winrate::winrate_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::winrate::winrate_inc_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::winrate::winrate_expanding_py, m)?)?;
+    m.add_function(wrap_pyfunction!(ta::stats::winrate::winrate_expanding_inc_py, m)?)?;
+
+    // Add all streaming classes
+    m.add_class::<ta::ohlcv::aroon::AroonPy>()?;
+    m.add_class::<ta::ohlcv::aroonosc::AroonoscPy>()?;
+    m.add_class::<ta::ohlcv::dmi::DmiPy>()?;
+    m.add_class::<ta::stats::max::MaxPy>()?;
+    m.add_class::<ta::ohlcv::midpoint::MidpointPy>()?;
+    m.add_class::<ta::ohlcv::midprice::MidpricePy>()?;
+    m.add_class::<ta::stats::min::MinPy>()?;
+    m.add_class::<ta::ohlcv::willr::WillrPy>()?;
 
     // Add all helper functions
 
@@ -0,0 +1,3 @@
+pub mod mtf;
+pub mod resample;
+pub mod trade_bars;
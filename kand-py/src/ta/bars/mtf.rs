@@ -0,0 +1,56 @@
+use kand::{TAFloat, bars::mtf};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Aligns higher time frame values onto lower time frame bars without look-ahead.
+///
+/// Each lower time frame bar receives the value of the latest higher time frame bar that had
+/// closed by the end of the lower bar, NaN before the first one.
+///
+/// Args:
+///     timestamp: Lower time frame bar start times as a 1-D NumPy array of type `int64`.
+///     interval: Length of the lower time frame bars (must be > 0).
+///     htf_timestamp: Higher time frame bar start times as a 1-D NumPy array of type `int64`.
+///     htf_interval: Length of the higher time frame bars (must be > 0).
+///     htf_value: Values computed on the higher time frame as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///     A 1-D NumPy array with one aligned value per lower time frame bar.
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> ts = np.arange(12) * 60
+///     >>> aligned = kand.align(ts, 60, np.array([0, 300, 600]), 300, np.array([1.0, 2.0, 3.0]))
+///     ```
+#[pyfunction]
+#[pyo3(name = "align", signature = (timestamp, interval, htf_timestamp, htf_interval, htf_value))]
+pub fn align_py(
+    py: Python,
+    timestamp: PyReadonlyArray1<i64>,
+    interval: i64,
+    htf_timestamp: PyReadonlyArray1<i64>,
+    htf_interval: i64,
+    htf_value: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_timestamp = timestamp.as_slice()?;
+    let input_htf_timestamp = htf_timestamp.as_slice()?;
+    let input_htf_value = htf_value.as_slice()?;
+
+    let mut output_value = vec![TAFloat::NAN; input_timestamp.len()];
+
+    py.allow_threads(|| {
+        mtf::align(
+            input_timestamp,
+            interval,
+            input_htf_timestamp,
+            htf_interval,
+            input_htf_value,
+            &mut output_value,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_value.into_pyarray(py).into())
+}
@@ -0,0 +1,101 @@
+use kand::{TAFloat, bars::resample};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Resamples OHLCV bars from NumPy arrays onto a longer time frame.
+///
+/// Bars are grouped by epoch-aligned intervals: first open, highest high, lowest low, last
+/// close and summed volume. Intervals without source bars produce no bar.
+///
+/// Args:
+///     timestamp: Bar start times as a 1-D NumPy array of type `int64`, strictly increasing.
+///     open: Open prices as a 1-D NumPy array of type `TAFloat`.
+///     high: High prices as a 1-D NumPy array of type `TAFloat`.
+///     low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///     close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///     volume: Volumes as a 1-D NumPy array of type `TAFloat`.
+///     interval: Length of the resampled bars in timestamp units (must be > 0).
+///     offset: Shift of the interval grid from the epoch (0 <= offset < interval). Defaults to 0.
+///
+/// Returns:
+///     A tuple of six 1-D NumPy arrays with one entry per resampled bar, the last bar possibly
+///     covering only part of its interval:
+///     - Bar start times
+///     - Open prices
+///     - High prices
+///     - Low prices
+///     - Close prices
+///     - Volumes
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> ts = np.arange(10) * 60_000
+///     >>> price = np.linspace(10.0, 11.0, 10)
+///     >>> ts5, o, h, l, c, v = kand.resample(ts, price, price, price, price, np.ones(10), 300_000)
+///     ```
+#[pyfunction]
+#[pyo3(name = "resample", signature = (timestamp, open, high, low, close, volume, interval, offset=0))]
+#[allow(clippy::type_complexity)]
+pub fn resample_py(
+    py: Python,
+    timestamp: PyReadonlyArray1<i64>,
+    open: PyReadonlyArray1<TAFloat>,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    volume: PyReadonlyArray1<TAFloat>,
+    interval: i64,
+    offset: i64,
+) -> PyResult<(
+    Py<PyArray1<i64>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_timestamp = timestamp.as_slice()?;
+    let input_open = open.as_slice()?;
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let input_volume = volume.as_slice()?;
+
+    let mut output_timestamp = Vec::new();
+    let mut output_open = Vec::new();
+    let mut output_high = Vec::new();
+    let mut output_low = Vec::new();
+    let mut output_close = Vec::new();
+    let mut output_volume = Vec::new();
+
+    py.allow_threads(|| {
+        resample::resample(
+            input_timestamp,
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            input_volume,
+            interval,
+            offset,
+            &mut output_timestamp,
+            &mut output_open,
+            &mut output_high,
+            &mut output_low,
+            &mut output_close,
+            &mut output_volume,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_timestamp.into_pyarray(py).into(),
+        output_open.into_pyarray(py).into(),
+        output_high.into_pyarray(py).into(),
+        output_low.into_pyarray(py).into(),
+        output_close.into_pyarray(py).into(),
+        output_volume.into_pyarray(py).into(),
+    ))
+}
@@ -0,0 +1,105 @@
+use kand::{TAFloat, TAInt, bars::trade_bars};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_bar_type;
+
+/// Aggregates trades from NumPy arrays into OHLCV bars.
+///
+/// Bars close on a fixed time interval, trade count, traded volume, traded value or tick
+/// imbalance. Trades are never split between bars.
+///
+/// Args:
+///     timestamp: Trade timestamps as a 1-D NumPy array of type `int64`, non-decreasing.
+///     price: Trade prices as a 1-D NumPy array of type `TAFloat`.
+///     size: Trade sizes as a 1-D NumPy array of type `TAFloat`.
+///     threshold: Interval length in timestamp units, trade count, volume, value or imbalance
+///         per bar (must be > 0, and a whole number for time and tick bars).
+///     bar_type: 0=time, 1=tick, 2=volume, 3=dollar, 4=tick imbalance. Defaults to 0.
+///
+/// Returns:
+///     A tuple of eight 1-D NumPy arrays with one entry per bar:
+///     - Bar timestamps (interval start for time bars, first trade otherwise)
+///     - Open prices
+///     - High prices
+///     - Low prices
+///     - Close prices
+///     - Volumes
+///     - Trade counts
+///     - Whether each bar is complete; the last bar is False while its rule has not been met
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> ts = np.array([0, 20, 45, 61, 70, 130])
+///     >>> price = np.array([10.0, 10.5, 9.8, 10.1, 10.4, 10.2])
+///     >>> size = np.array([1.0, 2.0, 1.0, 3.0, 1.0, 2.0])
+///     >>> ts_out, o, h, l, c, v, n, complete = kand.trade_bars(ts, price, size, 60.0)
+///     >>> complete
+///     array([ True,  True, False])
+///     ```
+#[pyfunction]
+#[pyo3(name = "trade_bars", signature = (timestamp, price, size, threshold, bar_type=0))]
+#[allow(clippy::type_complexity)]
+pub fn trade_bars_py(
+    py: Python,
+    timestamp: PyReadonlyArray1<i64>,
+    price: PyReadonlyArray1<TAFloat>,
+    size: PyReadonlyArray1<TAFloat>,
+    threshold: TAFloat,
+    bar_type: TAInt,
+) -> PyResult<(
+    Py<PyArray1<i64>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<usize>>,
+    Py<PyArray1<bool>>,
+)> {
+    let input_timestamp = timestamp.as_slice()?;
+    let input_price = price.as_slice()?;
+    let input_size = size.as_slice()?;
+    let bar_type = parse_bar_type(bar_type)?;
+
+    let mut output_timestamp = Vec::new();
+    let mut output_open = Vec::new();
+    let mut output_high = Vec::new();
+    let mut output_low = Vec::new();
+    let mut output_close = Vec::new();
+    let mut output_volume = Vec::new();
+    let mut output_trades = Vec::new();
+    let mut output_complete = Vec::new();
+
+    py.allow_threads(|| {
+        trade_bars::trade_bars(
+            input_timestamp,
+            input_price,
+            input_size,
+            bar_type,
+            threshold,
+            &mut output_timestamp,
+            &mut output_open,
+            &mut output_high,
+            &mut output_low,
+            &mut output_close,
+            &mut output_volume,
+            &mut output_trades,
+            &mut output_complete,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_timestamp.into_pyarray(py).into(),
+        output_open.into_pyarray(py).into(),
+        output_high.into_pyarray(py).into(),
+        output_low.into_pyarray(py).into(),
+        output_close.into_pyarray(py).into(),
+        output_volume.into_pyarray(py).into(),
+        output_trades.into_pyarray(py).into(),
+        output_complete.into_pyarray(py).into(),
+    ))
+}
@@ -1,2 +1,3 @@
+pub mod bars;
 pub mod ohlcv;
 pub mod stats;
@@ -0,0 +1,107 @@
+use kand::{TAFloat, TAInt, ohlcv::apo};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_ma_type;
+
+/// Computes the Absolute Price Oscillator (APO) over a NumPy array.
+///
+/// The fast, slow and signal lines each use their own moving average type:
+/// - APO: Difference between the fast and slow moving averages of price, in price units
+/// - Signal Line: MA of the APO line
+/// - Histogram: Difference between APO line and signal line
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///   fast_period: Period for fast MA calculation (typically 12).
+///   slow_period: Period for slow MA calculation (typically 26).
+///   signal_period: Period for signal line calculation (typically 9).
+///   fast_ma_type: Moving average type for the fast line: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA,
+///     4=RMA, 5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+///   slow_ma_type: Moving average type for the slow line. Defaults to 5 (SMA).
+///   signal_ma_type: Moving average type for the signal line. Defaults to 5 (SMA).
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - APO values
+///   - Signal line values
+///   - Histogram values
+///   - Fast MA values
+///   - Slow MA values
+///   Each array has the same length as the input, with initial elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> data = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+///   >>> apo, signal, histogram, fast_ma, slow_ma = kand.apo(data, 2, 3, 2)
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "apo",
+    signature = (
+        data,
+        fast_period=12,
+        slow_period=26,
+        signal_period=9,
+        fast_ma_type=5,
+        slow_ma_type=5,
+        signal_ma_type=5
+    )
+)]
+#[allow(clippy::type_complexity)]
+pub fn apo_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    fast_ma_type: TAInt,
+    slow_ma_type: TAInt,
+    signal_ma_type: TAInt,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input = data.as_slice()?;
+    let len = input.len();
+    let fast_ma_type = parse_ma_type(fast_ma_type)?;
+    let slow_ma_type = parse_ma_type(slow_ma_type)?;
+    let signal_ma_type = parse_ma_type(signal_ma_type)?;
+
+    let mut output_apo = vec![0.0; len];
+    let mut output_signal = vec![0.0; len];
+    let mut output_histogram = vec![0.0; len];
+    let mut output_fast_ma = vec![0.0; len];
+    let mut output_slow_ma = vec![0.0; len];
+
+    py.allow_threads(|| {
+        apo::apo(
+            input,
+            fast_period,
+            fast_ma_type,
+            slow_period,
+            slow_ma_type,
+            signal_period,
+            signal_ma_type,
+            output_apo.as_mut_slice(),
+            output_signal.as_mut_slice(),
+            output_histogram.as_mut_slice(),
+            output_fast_ma.as_mut_slice(),
+            output_slow_ma.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_apo.into_pyarray(py).into(),
+        output_signal.into_pyarray(py).into(),
+        output_histogram.into_pyarray(py).into(),
+        output_fast_ma.into_pyarray(py).into(),
+        output_slow_ma.into_pyarray(py).into(),
+    ))
+}
@@ -1,7 +1,9 @@
-use kand::{TAFloat, ohlcv::aroonosc};
+use kand::{Indicator, TAFloat, ohlcv::aroonosc};
 use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
 use pyo3::prelude::*;
 
+use crate::helper::warn_deprecated;
+
 /// Calculate Aroon Oscillator for a NumPy array.
 ///
 /// The Aroon Oscillator measures the strength of a trend by comparing the time since the last high and low.
@@ -78,6 +80,11 @@ pub fn aroonosc_py(
 
 /// Calculate the next Aroon Oscillator value incrementally.
 ///
+/// Deprecated:
+///   The days since the period high/low cannot be tracked exactly from the previous values once
+///   the extreme leaves the window. Use the `Aroonosc` streaming object, which keeps the
+///   rolling windows between calls.
+///
 /// Args:
 ///
 ///   high: Current period's high price.
@@ -129,16 +136,74 @@ pub fn aroonosc_inc_py(
     days_since_low: usize,
     period: usize,
 ) -> PyResult<(TAFloat, TAFloat, TAFloat, usize, usize)> {
-    py.allow_threads(|| {
-        aroonosc::aroonosc_inc(
-            high,
-            low,
-            prev_high,
-            prev_low,
-            days_since_high,
-            days_since_low,
-            period,
-        )
-    })
+    warn_deprecated(
+        py,
+        c"kand.aroonosc_inc loses the period extreme once it leaves the window; use kand.Aroonosc instead",
+    )?;
+    #[allow(deprecated)]
+    aroonosc::aroonosc_inc_legacy(
+        high,
+        low,
+        prev_high,
+        prev_low,
+        days_since_high,
+        days_since_low,
+        period,
+    )
     .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
 }
+
+/// Streaming Aroon Oscillator.
+///
+/// Keeps monotonic windows of the last `period + 1` highs and lows between calls, so every
+/// update is O(1) amortized and the days since the extremes stay exact.
+///
+/// Args:
+///   period: The lookback period for calculations (must be >= 2).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> osc = kand.Aroonosc(2)
+///   >>> [osc.update(h, l) for h, l in [(10.0, 8.0), (12.0, 9.0), (11.0, 7.0)]]
+///   [None, None, -50.0]
+///   ```
+#[pyclass(name = "Aroonosc")]
+pub struct AroonoscPy {
+    inner: aroonosc::Aroonosc,
+}
+
+#[pymethods]
+impl AroonoscPy {
+    #[new]
+    #[pyo3(signature = (period))]
+    fn new(period: usize) -> PyResult<Self> {
+        let inner = aroonosc::Aroonosc::new(period)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    /// Adds the next bar and returns the oscillator, or None while the window is filling.
+    fn update(&mut self, high: TAFloat, low: TAFloat) -> PyResult<Option<TAFloat>> {
+        self.inner
+            .update((high, low))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    /// Clears the rolling windows.
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    /// Whether enough bars were consumed for `update` to return values.
+    #[getter]
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    /// Number of bars consumed before the first value.
+    #[getter]
+    fn lookback(&self) -> usize {
+        self.inner.lookback()
+    }
+}
@@ -0,0 +1,69 @@
+use kand::{TAFloat, ohlcv::bandwidth};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculates %B and bandwidth for any price band.
+///
+/// %B locates the price within the band (0 on the lower band, 1 on the upper band) and
+/// bandwidth is the band width relative to the middle line. Works with the output of
+/// `bbands`, `keltner`, `donchian` or `starc`.
+///
+/// Args:
+///   price: Prices as a 1-D NumPy array of type `TAFloat`, usually the close.
+///   upper: Upper band values as a 1-D NumPy array of type `TAFloat`.
+///   middle: Middle band values as a 1-D NumPy array of type `TAFloat`.
+///   lower: Lower band values as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A tuple of two 1-D NumPy arrays containing:
+///   - %B values
+///   - Bandwidth values
+///   NaN band values give NaN outputs.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> price = np.array([12.0, 7.0])
+///   >>> upper = np.array([12.0, 12.0])
+///   >>> middle = np.array([10.0, 10.0])
+///   >>> lower = np.array([8.0, 8.0])
+///   >>> percent_b, bandwidth = kand.bandwidth(price, upper, middle, lower)
+///   >>> print(percent_b)
+///   [ 1.   -0.25]
+///   ```
+#[pyfunction]
+#[pyo3(name = "bandwidth", signature = (price, upper, middle, lower))]
+pub fn bandwidth_py(
+    py: Python,
+    price: PyReadonlyArray1<TAFloat>,
+    upper: PyReadonlyArray1<TAFloat>,
+    middle: PyReadonlyArray1<TAFloat>,
+    lower: PyReadonlyArray1<TAFloat>,
+) -> PyResult<(Py<PyArray1<TAFloat>>, Py<PyArray1<TAFloat>>)> {
+    let price_slice = price.as_slice()?;
+    let upper_slice = upper.as_slice()?;
+    let middle_slice = middle.as_slice()?;
+    let lower_slice = lower.as_slice()?;
+    let len = price_slice.len();
+
+    let mut output_percent_b = vec![0.0; len];
+    let mut output_bandwidth = vec![0.0; len];
+
+    py.allow_threads(|| {
+        bandwidth::bandwidth(
+            price_slice,
+            upper_slice,
+            middle_slice,
+            lower_slice,
+            output_percent_b.as_mut_slice(),
+            output_bandwidth.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_percent_b.into_pyarray(py).into(),
+        output_bandwidth.into_pyarray(py).into(),
+    ))
+}
@@ -1,11 +1,13 @@
-use kand::{TAFloat, ohlcv::bbands};
+use kand::{TAFloat, TAInt, ohlcv::bbands};
 use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
 use pyo3::prelude::*;
 
+use crate::helper::parse_ma_type;
+
 /// Calculate Bollinger Bands for a NumPy array.
 ///
 /// Bollinger Bands consist of:
-/// - A middle band (N-period simple moving average)
+/// - A middle band (N-period moving average, simple by default)
 /// - An upper band (K standard deviations above middle band)
 /// - A lower band (K standard deviations below middle band)
 ///
@@ -14,6 +16,8 @@
 ///   period: The time period for calculations (must be >= 2).
 ///   dev_up: Number of standard deviations for upper band.
 ///   dev_down: Number of standard deviations for lower band.
+///   ma_type: Moving average type for the middle band: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA, 4=RMA,
+///     5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
 ///
 /// Returns:
 ///   A tuple of 7 1-D NumPy arrays containing:
@@ -24,7 +28,7 @@
 ///   - Variance values
 ///   - Sum values
 ///   - Sum of squares values
-///   The first (period-1) elements of each array contain NaN values.
+///   The lookback elements of each array contain NaN values.
 ///
 /// Examples:
 ///   ```python
@@ -34,13 +38,14 @@
 ///   >>> upper, middle, lower, sma, var, sum, sum_sq = kand.bbands(price, 3, 2.0, 2.0)
 ///   ```
 #[pyfunction]
-#[pyo3(name = "bbands", signature = (price, period, dev_up, dev_down))]
+#[pyo3(name = "bbands", signature = (price, period, dev_up, dev_down, ma_type=5))]
 pub fn bbands_py(
     py: Python,
     price: PyReadonlyArray1<TAFloat>,
     period: usize,
     dev_up: TAFloat,
     dev_down: TAFloat,
+    ma_type: TAInt,
 ) -> PyResult<(
     Py<PyArray1<TAFloat>>,
     Py<PyArray1<TAFloat>>,
@@ -52,6 +57,7 @@ pub fn bbands_py(
 )> {
     let price_slice = price.as_slice()?;
     let len = price_slice.len();
+    let ma_type = parse_ma_type(ma_type)?;
 
     let mut output_upper = vec![0.0; len];
     let mut output_middle = vec![0.0; len];
@@ -67,6 +73,7 @@ pub fn bbands_py(
             period,
             dev_up,
             dev_down,
+            ma_type,
             output_upper.as_mut_slice(),
             output_middle.as_mut_slice(),
             output_lower.as_mut_slice(),
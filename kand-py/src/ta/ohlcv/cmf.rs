@@ -0,0 +1,147 @@
+use kand::{TAFloat, ohlcv::cmf};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes Chaikin Money Flow (CMF) over NumPy arrays.
+///
+/// CMF is the volume-weighted average of the Money Flow Multiplier over a rolling window.
+/// Positive values indicate accumulation, negative values distribution.
+///
+/// Args:
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+///   period: Summation period (must be >= 2). Defaults to 20.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - CMF values
+///   - Rolling sum of money flow volume
+///   - Rolling sum of volume
+///   Each array has the same length as the input, with the first `period-1` elements
+///   containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([12.0, 12.0, 12.0])
+///   >>> low = np.array([8.0, 8.0, 8.0])
+///   >>> close = np.array([11.0, 9.0, 12.0])
+///   >>> volume = np.array([100.0, 100.0, 200.0])
+///   >>> cmf, mfv_sum, volume_sum = kand.cmf(high, low, close, volume, 2)
+///   >>> print(cmf)
+///   [nan 0.  0.5]
+///   ```
+#[pyfunction]
+#[pyo3(name = "cmf", signature = (high, low, close, volume, period=20))]
+#[allow(clippy::type_complexity)]
+pub fn cmf_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    volume: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let high_slice = high.as_slice()?;
+    let low_slice = low.as_slice()?;
+    let close_slice = close.as_slice()?;
+    let volume_slice = volume.as_slice()?;
+    let len = high_slice.len();
+
+    let mut output_cmf = vec![0.0; len];
+    let mut output_mfv_sum = vec![0.0; len];
+    let mut output_volume_sum = vec![0.0; len];
+
+    py.allow_threads(|| {
+        cmf::cmf(
+            high_slice,
+            low_slice,
+            close_slice,
+            volume_slice,
+            period,
+            output_cmf.as_mut_slice(),
+            output_mfv_sum.as_mut_slice(),
+            output_volume_sum.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_cmf.into_pyarray(py).into(),
+        output_mfv_sum.into_pyarray(py).into(),
+        output_volume_sum.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculates the next CMF value incrementally.
+///
+/// Args:
+///   high: Current high price.
+///   low: Current low price.
+///   close: Current close price.
+///   volume: Current volume.
+///   old_high: High of the bar leaving the window, `period` bars back.
+///   old_low: Low of the bar leaving the window.
+///   old_close: Close of the bar leaving the window.
+///   old_volume: Volume of the bar leaving the window.
+///   prev_mfv_sum: Previous rolling sum of money flow volume.
+///   prev_volume_sum: Previous rolling sum of volume.
+///
+/// Returns:
+///   A tuple of (cmf, mfv_sum, volume_sum).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> cmf, mfv_sum, volume_sum = kand.cmf_inc(
+///   ...     12.0, 8.0, 12.0, 200.0, 12.0, 8.0, 11.0, 100.0, 0.0, 200.0
+///   ... )
+///   >>> print(cmf, mfv_sum, volume_sum)
+///   0.5 150.0 300.0
+///   ```
+#[pyfunction]
+#[pyo3(name = "cmf_inc", signature = (
+    high,
+    low,
+    close,
+    volume,
+    old_high,
+    old_low,
+    old_close,
+    old_volume,
+    prev_mfv_sum,
+    prev_volume_sum
+))]
+pub fn cmf_inc_py(
+    high: TAFloat,
+    low: TAFloat,
+    close: TAFloat,
+    volume: TAFloat,
+    old_high: TAFloat,
+    old_low: TAFloat,
+    old_close: TAFloat,
+    old_volume: TAFloat,
+    prev_mfv_sum: TAFloat,
+    prev_volume_sum: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    cmf::cmf_inc(
+        high,
+        low,
+        close,
+        volume,
+        old_high,
+        old_low,
+        old_close,
+        old_volume,
+        prev_mfv_sum,
+        prev_volume_sum,
+    )
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,106 @@
+use kand::{TAFloat, ohlcv::cmo};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the Chande Momentum Oscillator (CMO) over NumPy arrays.
+///
+/// The CMO is a momentum oscillator that compares the Wilder-smoothed gains and losses of recent
+/// price changes. It ranges from -100 to +100, with +50 and -50 as common overbought and oversold
+/// levels.
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Window size for CMO calculation. Must be positive and less than input length.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - CMO values
+///   - Average gain values
+///   - Average loss values
+///   Each array has the same length as the input, with the first `period` elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42])
+///   >>> cmo, avg_gain, avg_loss = kand.cmo(prices, 5)
+///   ```
+#[pyfunction]
+#[pyo3(name = "cmo", signature = (prices, period))]
+pub fn cmo_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    // Convert the input NumPy array to a Rust slice
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    // Create new output arrays using vec
+    let mut output_cmo = vec![0.0; len];
+    let mut output_avg_gain = vec![0.0; len];
+    let mut output_avg_loss = vec![0.0; len];
+
+    // Perform the CMO calculation while releasing the GIL to allow other Python threads to run
+    py.allow_threads(|| {
+        cmo::cmo(
+            input_prices,
+            period,
+            output_cmo.as_mut_slice(),
+            output_avg_gain.as_mut_slice(),
+            output_avg_loss.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    // Convert the output arrays to Python objects
+    Ok((
+        output_cmo.into_pyarray(py).into(),
+        output_avg_gain.into_pyarray(py).into(),
+        output_avg_loss.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculates a single CMO value incrementally.
+///
+/// This function provides an optimized way to calculate the latest CMO value
+/// when streaming data is available, without needing the full price history.
+///
+/// Args:
+///   current_price: The current period's price value.
+///   prev_price: The previous period's price value.
+///   prev_avg_gain: The previous period's average gain.
+///   prev_avg_loss: The previous period's average loss.
+///   period: The time period for CMO calculation.
+///
+/// Returns:
+///   A tuple containing (CMO value, new average gain, new average loss).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> cmo, avg_gain, avg_loss = kand.cmo_inc(45.42, 45.10, 0.24, 0.14, 14)
+///   ```
+#[pyfunction]
+#[pyo3(name = "cmo_inc", signature = (current_price, prev_price, prev_avg_gain, prev_avg_loss, period))]
+pub fn cmo_inc_py(
+    current_price: TAFloat,
+    prev_price: TAFloat,
+    prev_avg_gain: TAFloat,
+    prev_avg_loss: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    cmo::cmo_inc(
+        current_price,
+        prev_price,
+        prev_avg_gain,
+        prev_avg_loss,
+        period,
+    )
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
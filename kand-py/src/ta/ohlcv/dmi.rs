@@ -0,0 +1,68 @@
+use kand::{Indicator, TAFloat, ohlcv::dmi};
+use pyo3::prelude::*;
+
+/// Streaming Directional Movement System.
+///
+/// Keeps one Wilder-smoothed +DM, -DM and TR state between calls and derives +DI, -DI, DX
+/// and ADX from it, matching the `plus_di`, `minus_di`, `dx` and `adx` batch functions.
+///
+/// Args:
+///   period: The smoothing period (typically 14, must be >= 2).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> dmi = kand.Dmi(2)
+///   >>> bars = [(10.0, 8.0, 9.0), (11.0, 9.0, 10.0), (12.0, 9.5, 11.0), (11.5, 9.0, 9.5)]
+///   >>> values = [dmi.update(high, low, close) for high, low, close in bars]
+///   >>> values[1] is None, values[2][3] is None, values[3][3] is None
+///   (True, True, False)
+///   ```
+#[pyclass(name = "Dmi")]
+pub struct DmiPy {
+    inner: dmi::Dmi,
+}
+
+#[pymethods]
+impl DmiPy {
+    #[new]
+    #[pyo3(signature = (period))]
+    fn new(period: usize) -> PyResult<Self> {
+        let inner = dmi::Dmi::new(period)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    /// Adds the next bar and returns (+DI, -DI, DX, ADX), or None while the smoothing warms up.
+    ///
+    /// ADX is None until `2 * period - 1` bars have been consumed.
+    fn update(
+        &mut self,
+        high: TAFloat,
+        low: TAFloat,
+        close: TAFloat,
+    ) -> PyResult<Option<(TAFloat, TAFloat, TAFloat, Option<TAFloat>)>> {
+        let output = self
+            .inner
+            .update((high, low, close))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(output.map(|output| (output.plus_di, output.minus_di, output.dx, output.adx)))
+    }
+
+    /// Clears the smoothed state.
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    /// Whether enough bars were consumed for `update` to return values.
+    #[getter]
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    /// Number of bars consumed before the first value.
+    #[getter]
+    fn lookback(&self) -> usize {
+        self.inner.lookback()
+    }
+}
@@ -0,0 +1,84 @@
+use kand::{TAFloat, ohlcv::donchian};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes Donchian Channels over NumPy arrays.
+///
+/// The upper and lower bands are the highest high and lowest low of the last `period` bars,
+/// and the middle line is their average.
+///
+/// Args:
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Window size (must be >= 2). Defaults to 20.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - Upper band values
+///   - Middle line values
+///   - Lower band values
+///   - %B values
+///   - Bandwidth values
+///   Each array has the same length as the input, with the first `period-1` elements
+///   containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([10.0, 12.0, 11.0, 13.0])
+///   >>> low = np.array([8.0, 9.0, 9.5, 10.0])
+///   >>> close = np.array([9.0, 11.0, 10.0, 12.5])
+///   >>> upper, middle, lower, percent_b, bandwidth = kand.donchian(high, low, close, 2)
+///   ```
+#[pyfunction]
+#[pyo3(name = "donchian", signature = (high, low, close, period=20))]
+#[allow(clippy::type_complexity)]
+pub fn donchian_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let high_slice = high.as_slice()?;
+    let low_slice = low.as_slice()?;
+    let close_slice = close.as_slice()?;
+    let len = high_slice.len();
+
+    let mut output_upper = vec![0.0; len];
+    let mut output_middle = vec![0.0; len];
+    let mut output_lower = vec![0.0; len];
+    let mut output_percent_b = vec![0.0; len];
+    let mut output_bandwidth = vec![0.0; len];
+
+    py.allow_threads(|| {
+        donchian::donchian(
+            high_slice,
+            low_slice,
+            close_slice,
+            period,
+            output_upper.as_mut_slice(),
+            output_middle.as_mut_slice(),
+            output_lower.as_mut_slice(),
+            output_percent_b.as_mut_slice(),
+            output_bandwidth.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_upper.into_pyarray(py).into(),
+        output_middle.into_pyarray(py).into(),
+        output_lower.into_pyarray(py).into(),
+        output_percent_b.into_pyarray(py).into(),
+        output_bandwidth.into_pyarray(py).into(),
+    ))
+}
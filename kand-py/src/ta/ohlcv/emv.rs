@@ -0,0 +1,128 @@
+use kand::{TAFloat, ohlcv::emv};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes Arms' Ease of Movement (EMV) over NumPy arrays.
+///
+/// EMV relates the move of the bar midpoint to the volume needed to make it and smooths the
+/// single-bar values with an SMA.
+///
+/// Args:
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+///   period: SMA smoothing period (must be >= 2). Defaults to 14.
+///   divisor: Volume scale divisor (must be positive). Defaults to 100,000,000.
+///
+/// Returns:
+///   A tuple of two 1-D NumPy arrays containing:
+///   - Smoothed EMV values, NaN for the first `period` elements
+///   - Single-bar EMV values, NaN for the first element
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([10.0, 12.0, 13.0])
+///   >>> low = np.array([8.0, 10.0, 9.0])
+///   >>> volume = np.array([100.0, 200.0, 400.0])
+///   >>> emv, emv_raw = kand.emv(high, low, volume, 2, 100.0)
+///   >>> print(emv)
+///   [nan nan  1.]
+///   ```
+#[pyfunction]
+#[pyo3(name = "emv", signature = (high, low, volume, period=14, divisor=100_000_000.0))]
+#[allow(clippy::type_complexity)]
+pub fn emv_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    volume: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    divisor: TAFloat,
+) -> PyResult<(Py<PyArray1<TAFloat>>, Py<PyArray1<TAFloat>>)> {
+    let high_slice = high.as_slice()?;
+    let low_slice = low.as_slice()?;
+    let volume_slice = volume.as_slice()?;
+    let len = high_slice.len();
+
+    let mut output_emv = vec![0.0; len];
+    let mut output_emv_raw = vec![0.0; len];
+
+    py.allow_threads(|| {
+        emv::emv(
+            high_slice,
+            low_slice,
+            volume_slice,
+            period,
+            divisor,
+            output_emv.as_mut_slice(),
+            output_emv_raw.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_emv.into_pyarray(py).into(),
+        output_emv_raw.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculates the next EMV value incrementally.
+///
+/// Args:
+///   high: Current high price.
+///   low: Current low price.
+///   volume: Current volume.
+///   prev_high: Previous high price.
+///   prev_low: Previous low price.
+///   old_emv_raw: Single-bar EMV leaving the SMA window, `period` bars back.
+///   prev_emv: Previous smoothed EMV value.
+///   period: SMA smoothing period (must be >= 2).
+///   divisor: Volume scale divisor (must be positive).
+///
+/// Returns:
+///   A tuple of (emv, emv_raw).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.emv_inc(13.0, 9.0, 400.0, 12.0, 10.0, 2.0, 2.0, 2, 100.0)
+///   (1.0, 0.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "emv_inc", signature = (
+    high,
+    low,
+    volume,
+    prev_high,
+    prev_low,
+    old_emv_raw,
+    prev_emv,
+    period,
+    divisor
+))]
+pub fn emv_inc_py(
+    high: TAFloat,
+    low: TAFloat,
+    volume: TAFloat,
+    prev_high: TAFloat,
+    prev_low: TAFloat,
+    old_emv_raw: TAFloat,
+    prev_emv: TAFloat,
+    period: usize,
+    divisor: TAFloat,
+) -> PyResult<(TAFloat, TAFloat)> {
+    emv::emv_inc(
+        high,
+        low,
+        volume,
+        prev_high,
+        prev_low,
+        old_emv_raw,
+        prev_emv,
+        period,
+        divisor,
+    )
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
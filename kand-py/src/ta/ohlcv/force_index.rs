@@ -0,0 +1,79 @@
+use kand::{TAFloat, ohlcv::force_index};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes Elder's Force Index over NumPy arrays.
+///
+/// The Force Index is the EMA of each close-to-close change multiplied by its volume.
+///
+/// Args:
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+///   period: EMA smoothing period (must be >= 2). Defaults to 13.
+///
+/// Returns:
+///   A new 1-D NumPy array containing the Force Index values. The array has the same length
+///   as the input, with the first `period` elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> close = np.array([10.0, 11.0, 10.5, 12.0])
+///   >>> volume = np.array([100.0, 200.0, 100.0, 150.0])
+///   >>> result = kand.force_index(close, volume, 2)
+///   >>> print(result)
+///   [ nan  nan  75. 175.]
+///   ```
+#[pyfunction]
+#[pyo3(name = "force_index", signature = (close, volume, period=13))]
+pub fn force_index_py(
+    py: Python,
+    close: PyReadonlyArray1<TAFloat>,
+    volume: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_close = close.as_slice()?;
+    let input_volume = volume.as_slice()?;
+    let len = input_close.len();
+
+    let mut output = vec![0.0; len];
+
+    py.allow_threads(|| {
+        force_index::force_index(input_close, input_volume, period, output.as_mut_slice())
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output.into_pyarray(py).into())
+}
+
+/// Calculates the next Force Index value incrementally.
+///
+/// Args:
+///   close: Current close price.
+///   prev_close: Previous close price.
+///   volume: Current volume.
+///   prev_force_index: Previous Force Index value.
+///   period: EMA smoothing period (must be >= 2).
+///
+/// Returns:
+///   The new Force Index value.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.force_index_inc(12.0, 10.5, 150.0, 75.0, 2)
+///   175.0
+///   ```
+#[pyfunction]
+#[pyo3(name = "force_index_inc", signature = (close, prev_close, volume, prev_force_index, period))]
+pub fn force_index_inc_py(
+    close: TAFloat,
+    prev_close: TAFloat,
+    volume: TAFloat,
+    prev_force_index: TAFloat,
+    period: usize,
+) -> PyResult<TAFloat> {
+    force_index::force_index_inc(close, prev_close, volume, prev_force_index, period)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
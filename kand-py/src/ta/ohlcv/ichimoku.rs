@@ -0,0 +1,99 @@
+use kand::{TAFloat, ohlcv::ichimoku};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculates Ichimoku Kinko Hyo for a price series.
+///
+/// The Senkou spans are `displacement` elements longer than the input: element `i` is the
+/// cloud plotted at bar `i`, and the trailing elements hold the cloud projected past the
+/// last bar. The Chikou span at bar `i` is the close of bar `i + displacement`, NaN for the
+/// last `displacement` bars.
+///
+/// Args:
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   tenkan_period: Tenkan-sen period (must be >= 2). Defaults to 9.
+///   kijun_period: Kijun-sen period (must be >= 2). Defaults to 26.
+///   senkou_b_period: Senkou Span B period (must be >= 2). Defaults to 52.
+///   displacement: Forward shift of the cloud and backward shift of the Chikou span.
+///     Defaults to 26.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - Tenkan-sen values
+///   - Kijun-sen values
+///   - Senkou Span A values (`displacement` longer than the input)
+///   - Senkou Span B values (`displacement` longer than the input)
+///   - Chikou Span values
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([10.0, 11.0, 12.0, 13.0, 12.0, 11.0])
+///   >>> low = np.array([8.0, 9.0, 10.0, 11.0, 10.0, 9.0])
+///   >>> close = np.array([9.0, 10.0, 11.0, 12.0, 11.0, 10.0])
+///   >>> tenkan, kijun, senkou_a, senkou_b, chikou = kand.ichimoku(high, low, close, 2, 3, 4, 2)
+///   >>> len(senkou_a)
+///   8
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "ichimoku",
+    signature = (high, low, close, tenkan_period=9, kijun_period=26, senkou_b_period=52, displacement=26)
+)]
+#[allow(clippy::type_complexity)]
+pub fn ichimoku_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    tenkan_period: usize,
+    kijun_period: usize,
+    senkou_b_period: usize,
+    displacement: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let high_slice = high.as_slice()?;
+    let low_slice = low.as_slice()?;
+    let close_slice = close.as_slice()?;
+    let len = high_slice.len();
+
+    let mut output_tenkan = vec![0.0; len];
+    let mut output_kijun = vec![0.0; len];
+    let mut output_senkou_a = vec![0.0; len + displacement];
+    let mut output_senkou_b = vec![0.0; len + displacement];
+    let mut output_chikou = vec![0.0; len];
+
+    py.allow_threads(|| {
+        ichimoku::ichimoku(
+            high_slice,
+            low_slice,
+            close_slice,
+            tenkan_period,
+            kijun_period,
+            senkou_b_period,
+            displacement,
+            output_tenkan.as_mut_slice(),
+            output_kijun.as_mut_slice(),
+            output_senkou_a.as_mut_slice(),
+            output_senkou_b.as_mut_slice(),
+            output_chikou.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_tenkan.into_pyarray(py).into(),
+        output_kijun.into_pyarray(py).into(),
+        output_senkou_a.into_pyarray(py).into(),
+        output_senkou_b.into_pyarray(py).into(),
+        output_chikou.into_pyarray(py).into(),
+    ))
+}
@@ -0,0 +1,91 @@
+use kand::{TAFloat, TAInt, ohlcv::kagi};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_price_source;
+
+/// Builds a Kagi chart from NumPy arrays.
+///
+/// A line extends while price keeps moving its way and turns once price moves back by the
+/// reversal amount. Rising lines turn thick (yang) above the previous shoulder and falling
+/// lines turn thin (yin) below the previous waist.
+///
+/// Args:
+///     high: High prices as a 1-D NumPy array of type `TAFloat`.
+///     low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///     close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///     reversal: Price move against a line needed to turn it (must be > 0).
+///     source: 0 to measure moves on closes, 1 on highs and lows. Defaults to 0.
+///
+/// Returns:
+///     A tuple of six 1-D NumPy arrays with one entry per line, the last line still forming:
+///     - Line start prices
+///     - Line end prices
+///     - Line directions (1 up, -1 down)
+///     - Line thickness (1 yang, -1 yin)
+///     - Index of the bar where each line starts
+///     - Index of the bar where each line ends
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> close = np.array([10.0, 12.0, 13.0, 11.5, 10.5, 11.0, 14.0])
+///     >>> starts, ends, directions, thickness, start_idx, end_idx = kand.kagi(close, close, close, 2.0)
+///     ```
+#[pyfunction]
+#[pyo3(name = "kagi", signature = (high, low, close, reversal, source=0))]
+#[allow(clippy::type_complexity)]
+pub fn kagi_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    reversal: TAFloat,
+    source: TAInt,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAInt>>,
+    Py<PyArray1<TAInt>>,
+    Py<PyArray1<usize>>,
+    Py<PyArray1<usize>>,
+)> {
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let source = parse_price_source(source)?;
+
+    let mut output_start = Vec::new();
+    let mut output_end = Vec::new();
+    let mut output_direction = Vec::new();
+    let mut output_thickness = Vec::new();
+    let mut output_start_index = Vec::new();
+    let mut output_end_index = Vec::new();
+
+    py.allow_threads(|| {
+        kagi::kagi(
+            input_high,
+            input_low,
+            input_close,
+            reversal,
+            source,
+            &mut output_start,
+            &mut output_end,
+            &mut output_direction,
+            &mut output_thickness,
+            &mut output_start_index,
+            &mut output_end_index,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_start.into_pyarray(py).into(),
+        output_end.into_pyarray(py).into(),
+        output_direction.into_pyarray(py).into(),
+        output_thickness.into_pyarray(py).into(),
+        output_start_index.into_pyarray(py).into(),
+        output_end_index.into_pyarray(py).into(),
+    ))
+}
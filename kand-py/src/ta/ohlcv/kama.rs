@@ -0,0 +1,107 @@
+use kand::{TAFloat, ohlcv::kama};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate Kaufman Adaptive Moving Average (KAMA) for a NumPy array.
+///
+/// KAMA adapts its speed to the market noise. The efficiency ratio compares the net price
+/// change over the period with the sum of all bar-to-bar changes: in a clean trend the average
+/// follows price like a 2-period EMA, in a choppy market it slows down to a 30-period EMA.
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Period used for the efficiency ratio (must be >= 2).
+///
+/// Returns:
+///   A tuple of 2 1-D NumPy arrays containing:
+///   - KAMA values
+///   - Rolling sums of absolute price changes
+///   The first `period` elements of each array contain NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([10.0, 11.0, 12.0, 13.0, 14.0])
+///   >>> kama, sum_roc = kand.kama(prices, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "kama", signature = (prices, period))]
+pub fn kama_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(Py<PyArray1<TAFloat>>, Py<PyArray1<TAFloat>>)> {
+    let prices_slice = prices.as_slice()?;
+    let len = prices_slice.len();
+
+    let mut output_kama = vec![0.0; len];
+    let mut output_sum_roc = vec![0.0; len];
+
+    py.allow_threads(|| {
+        kama::kama(
+            prices_slice,
+            period,
+            output_kama.as_mut_slice(),
+            output_sum_roc.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_kama.into_pyarray(py).into(),
+        output_sum_roc.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the next KAMA value incrementally.
+///
+/// Args:
+///   price: Current price.
+///   prev_price: Previous price.
+///   old_price: Price `period` bars ago.
+///   prev_old_price: Price `period + 1` bars ago, which leaves the window.
+///   prev_kama: Previous KAMA value.
+///   prev_sum_roc: Previous rolling sum of absolute price changes.
+///   period: Period used for the efficiency ratio (must be >= 2).
+///
+/// Returns:
+///   A tuple containing:
+///   - Updated KAMA value
+///   - Updated sum of absolute price changes
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kama, sum_roc = kand.kama_inc(
+///   ...     15.0,  # price
+///   ...     14.0,  # prev_price
+///   ...     12.0,  # old_price
+///   ...     11.0,  # prev_old_price
+///   ...     13.0,  # prev_kama
+///   ...     3.0,   # prev_sum_roc
+///   ...     3      # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(name = "kama_inc", signature = (price, prev_price, old_price, prev_old_price, prev_kama, prev_sum_roc, period))]
+pub fn kama_inc_py(
+    price: TAFloat,
+    prev_price: TAFloat,
+    old_price: TAFloat,
+    prev_old_price: TAFloat,
+    prev_kama: TAFloat,
+    prev_sum_roc: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat)> {
+    kama::kama_inc(
+        price,
+        prev_price,
+        old_price,
+        prev_old_price,
+        prev_kama,
+        prev_sum_roc,
+        period,
+    )
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
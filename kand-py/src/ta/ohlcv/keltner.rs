@@ -0,0 +1,172 @@
+use kand::{TAFloat, TAInt, ohlcv::keltner};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_ma_type;
+
+/// Computes Keltner Channels over NumPy arrays.
+///
+/// The middle line is a moving average of the close and the bands sit a multiple of the
+/// Average True Range above and below it.
+///
+/// Args:
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Period of the middle line moving average. Defaults to 20.
+///   atr_period: Period of the ATR. Defaults to 10.
+///   multiplier: ATR multiple for the bands. Defaults to 2.0.
+///   ma_type: Moving average type for the middle line: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA,
+///     4=RMA, 5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 1 (EMA).
+///
+/// Returns:
+///   A tuple of six 1-D NumPy arrays containing:
+///   - Upper band values
+///   - Middle line values
+///   - Lower band values
+///   - ATR values
+///   - %B values
+///   - Bandwidth values
+///   Each array has the same length as the input, with the warm-up elements containing
+///   NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([10.0, 11.0, 12.0, 11.5, 12.5])
+///   >>> low = np.array([9.0, 9.5, 10.5, 10.0, 11.0])
+///   >>> close = np.array([9.5, 10.5, 11.5, 10.5, 12.0])
+///   >>> upper, middle, lower, atr, percent_b, bandwidth = kand.keltner(
+///   ...     high, low, close, 3, 2, 2.0, 5
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "keltner",
+    signature = (high, low, close, period=20, atr_period=10, multiplier=2.0, ma_type=1)
+)]
+#[allow(clippy::type_complexity)]
+pub fn keltner_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    atr_period: usize,
+    multiplier: TAFloat,
+    ma_type: TAInt,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let high_slice = high.as_slice()?;
+    let low_slice = low.as_slice()?;
+    let close_slice = close.as_slice()?;
+    let len = high_slice.len();
+    let ma_type = parse_ma_type(ma_type)?;
+
+    let mut output_upper = vec![0.0; len];
+    let mut output_middle = vec![0.0; len];
+    let mut output_lower = vec![0.0; len];
+    let mut output_atr = vec![0.0; len];
+    let mut output_percent_b = vec![0.0; len];
+    let mut output_bandwidth = vec![0.0; len];
+
+    py.allow_threads(|| {
+        keltner::keltner(
+            high_slice,
+            low_slice,
+            close_slice,
+            period,
+            atr_period,
+            multiplier,
+            ma_type,
+            output_upper.as_mut_slice(),
+            output_middle.as_mut_slice(),
+            output_lower.as_mut_slice(),
+            output_atr.as_mut_slice(),
+            output_percent_b.as_mut_slice(),
+            output_bandwidth.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_upper.into_pyarray(py).into(),
+        output_middle.into_pyarray(py).into(),
+        output_lower.into_pyarray(py).into(),
+        output_atr.into_pyarray(py).into(),
+        output_percent_b.into_pyarray(py).into(),
+        output_bandwidth.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculates the next Keltner Channel values incrementally.
+///
+/// The incremental form is EMA-only: other middle line types need more state than the
+/// previous value.
+///
+/// Args:
+///   high: Current high price.
+///   low: Current low price.
+///   close: Current close price.
+///   prev_close: Previous close price.
+///   prev_middle: Previous EMA middle line value.
+///   prev_atr: Previous ATR value.
+///   period: Period of the EMA middle line.
+///   atr_period: Period of the ATR.
+///   multiplier: ATR multiple for the bands.
+///
+/// Returns:
+///   A tuple of (upper, middle, lower, atr, percent_b, bandwidth).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> upper, middle, lower, atr, percent_b, bandwidth = kand.keltner_inc(
+///   ...     12.0, 10.0, 11.0, 10.0, 10.0, 1.0, 3, 2, 2.0
+///   ... )
+///   >>> print(middle, atr)
+///   10.5 1.5
+///   ```
+#[pyfunction]
+#[pyo3(name = "keltner_inc", signature = (
+    high,
+    low,
+    close,
+    prev_close,
+    prev_middle,
+    prev_atr,
+    period,
+    atr_period,
+    multiplier
+))]
+pub fn keltner_inc_py(
+    high: TAFloat,
+    low: TAFloat,
+    close: TAFloat,
+    prev_close: TAFloat,
+    prev_middle: TAFloat,
+    prev_atr: TAFloat,
+    period: usize,
+    atr_period: usize,
+    multiplier: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    keltner::keltner_inc(
+        high,
+        low,
+        close,
+        prev_close,
+        prev_middle,
+        prev_atr,
+        period,
+        atr_period,
+        multiplier,
+    )
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
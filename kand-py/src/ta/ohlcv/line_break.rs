@@ -0,0 +1,66 @@
+use kand::{TAFloat, TAInt, ohlcv::line_break};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Builds an N-line break chart from a NumPy array of closes.
+///
+/// A close beyond the last line draws a new line in the same direction. Reversing needs a close
+/// beyond the range of the last `lines` lines.
+///
+/// Args:
+///     close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///     lines: Number of lines a reversal has to break. Defaults to 3.
+///
+/// Returns:
+///     A tuple of four 1-D NumPy arrays with one entry per line:
+///     - Line open prices
+///     - Line close prices
+///     - Line directions (1 up, -1 down)
+///     - Index of the source bar that completed each line
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> close = np.array([10.0, 11.0, 12.0, 13.0, 11.5, 9.5, 9.0])
+///     >>> opens, closes, directions, indices = kand.line_break(close)
+///     ```
+#[pyfunction]
+#[pyo3(name = "line_break", signature = (close, lines=3))]
+#[allow(clippy::type_complexity)]
+pub fn line_break_py(
+    py: Python,
+    close: PyReadonlyArray1<TAFloat>,
+    lines: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAInt>>,
+    Py<PyArray1<usize>>,
+)> {
+    let input_close = close.as_slice()?;
+
+    let mut output_open = Vec::new();
+    let mut output_close = Vec::new();
+    let mut output_direction = Vec::new();
+    let mut output_index = Vec::new();
+
+    py.allow_threads(|| {
+        line_break::line_break(
+            input_close,
+            lines,
+            &mut output_open,
+            &mut output_close,
+            &mut output_direction,
+            &mut output_index,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_open.into_pyarray(py).into(),
+        output_close.into_pyarray(py).into(),
+        output_direction.into_pyarray(py).into(),
+        output_index.into_pyarray(py).into(),
+    ))
+}
@@ -0,0 +1,49 @@
+use kand::{TAFloat, TAInt, ohlcv::ma};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_ma_type;
+
+/// Computes a moving average of the selected type over a NumPy array.
+///
+/// Dispatches to the moving average selected by `ma_type`, like TA-Lib's `MA` function.
+/// T3 uses a volume factor of 0.7 and MAMA ignores `period` and uses limits of 0.5 and 0.05.
+///
+/// Args:
+///     data: Input data as a 1-D NumPy array of type `TAFloat`.
+///     period: Window size for the moving average. Must be >= 2.
+///     ma_type: Moving average type: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA, 4=RMA, 5=SMA, 6=T3,
+///         7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+///
+/// Returns:
+///     A new 1-D NumPy array containing the moving average values. The array has the same length
+///     as the input, with the lookback elements containing NaN values.
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> data = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+///     >>> result = kand.ma(data, 3, 5)
+///     >>> print(result)
+///     [nan, nan, 2.0, 3.0, 4.0]
+///     ```
+#[pyfunction]
+#[pyo3(name = "ma", signature = (data, period, ma_type=5))]
+pub fn ma_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    ma_type: TAInt,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input = data.as_slice()?;
+    let len = input.len();
+    let ma_type = parse_ma_type(ma_type)?;
+
+    let mut output = vec![0.0; len];
+
+    py.allow_threads(|| ma::ma(input, period, ma_type, output.as_mut_slice()))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output.into_pyarray(py).into())
+}
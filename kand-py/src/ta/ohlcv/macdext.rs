@@ -0,0 +1,109 @@
+use kand::{TAFloat, TAInt, ohlcv::macdext};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_ma_type;
+
+/// Computes MACD with controllable moving average types (MACDEXT) over a NumPy array.
+///
+/// Like MACD, but the fast, slow and signal lines each use their own moving average type:
+/// - MACD Line: Difference between fast and slow MAs
+/// - Signal Line: MA of the MACD line
+/// - Histogram: Difference between MACD line and signal line
+///
+/// As in TA-Lib, a slow period shorter than the fast one swaps the two lines with their MA types.
+///
+/// Args:
+///   data: Input price data as a 1-D NumPy array of type `TAFloat`.
+///   fast_period: Period for fast MA calculation (typically 12).
+///   slow_period: Period for slow MA calculation (typically 26).
+///   signal_period: Period for signal line calculation (typically 9).
+///   fast_ma_type: Moving average type for the fast line: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA,
+///     4=RMA, 5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+///   slow_ma_type: Moving average type for the slow line. Defaults to 5 (SMA).
+///   signal_ma_type: Moving average type for the signal line. Defaults to 5 (SMA).
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - MACD line values
+///   - Signal line values
+///   - MACD histogram values
+///   - Fast MA values
+///   - Slow MA values
+///   Each array has the same length as the input, with initial elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> data = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+///   >>> macd_line, signal_line, histogram, fast_ma, slow_ma = kand.macdext(data, 2, 3, 2, 1, 1, 5)
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "macdext",
+    signature = (
+        data,
+        fast_period,
+        slow_period,
+        signal_period,
+        fast_ma_type=5,
+        slow_ma_type=5,
+        signal_ma_type=5
+    )
+)]
+#[allow(clippy::type_complexity)]
+pub fn macdext_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    fast_ma_type: TAInt,
+    slow_ma_type: TAInt,
+    signal_ma_type: TAInt,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input = data.as_slice()?;
+    let len = input.len();
+    let fast_ma_type = parse_ma_type(fast_ma_type)?;
+    let slow_ma_type = parse_ma_type(slow_ma_type)?;
+    let signal_ma_type = parse_ma_type(signal_ma_type)?;
+
+    let mut macd_line = vec![0.0; len];
+    let mut signal_line = vec![0.0; len];
+    let mut histogram = vec![0.0; len];
+    let mut fast_ma = vec![0.0; len];
+    let mut slow_ma = vec![0.0; len];
+
+    py.allow_threads(|| {
+        macdext::macdext(
+            input,
+            fast_period,
+            fast_ma_type,
+            slow_period,
+            slow_ma_type,
+            signal_period,
+            signal_ma_type,
+            macd_line.as_mut_slice(),
+            signal_line.as_mut_slice(),
+            histogram.as_mut_slice(),
+            fast_ma.as_mut_slice(),
+            slow_ma.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        macd_line.into_pyarray(py).into(),
+        signal_line.into_pyarray(py).into(),
+        histogram.into_pyarray(py).into(),
+        fast_ma.into_pyarray(py).into(),
+        slow_ma.into_pyarray(py).into(),
+    ))
+}
@@ -0,0 +1,60 @@
+use kand::{TAFloat, ohlcv::mama};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate MESA Adaptive Moving Average (MAMA) and Following Adaptive Moving Average (FAMA)
+/// for a NumPy array.
+///
+/// MAMA is an EMA whose alpha follows the rate of change of the Hilbert Transform phase, bounded
+/// by `fast_limit` and `slow_limit`. FAMA applies half of that alpha to MAMA itself.
+///
+/// The phase history cannot be passed around as scalars, so there is no incremental form.
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///   fast_limit: Upper limit of the adaptive alpha (0.01 to 0.99). Defaults to 0.5.
+///   slow_limit: Lower limit of the adaptive alpha (0.01 to 0.99). Defaults to 0.05.
+///
+/// Returns:
+///   A tuple of 2 1-D NumPy arrays containing:
+///   - MAMA values
+///   - FAMA values
+///   The first 32 elements of each array contain NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = 100.0 + np.sin(np.arange(64) * 0.3)
+///   >>> mama, fama = kand.mama(prices, 0.5, 0.05)
+///   ```
+#[pyfunction]
+#[pyo3(name = "mama", signature = (prices, fast_limit=0.5, slow_limit=0.05))]
+pub fn mama_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    fast_limit: TAFloat,
+    slow_limit: TAFloat,
+) -> PyResult<(Py<PyArray1<TAFloat>>, Py<PyArray1<TAFloat>>)> {
+    let prices_slice = prices.as_slice()?;
+    let len = prices_slice.len();
+
+    let mut output_mama = vec![0.0; len];
+    let mut output_fama = vec![0.0; len];
+
+    py.allow_threads(|| {
+        mama::mama(
+            prices_slice,
+            fast_limit,
+            slow_limit,
+            output_mama.as_mut_slice(),
+            output_fama.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_mama.into_pyarray(py).into(),
+        output_fama.into_pyarray(py).into(),
+    ))
+}
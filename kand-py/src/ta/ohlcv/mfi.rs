@@ -19,8 +19,8 @@
 ///   - MFI values (0-100)
 ///   - Typical prices
 ///   - Money flows
-///   - Positive money flows
-///   - Negative money flows
+///   - Rolling sums of positive money flow, NaN for the first `period` elements
+///   - Rolling sums of negative money flow, NaN for the first `period` elements
 ///
 /// Examples:
 ///   ```python
@@ -84,3 +84,69 @@ pub fn mfi_py(
         neg_flows.into_pyarray(py).into(),
     ))
 }
+
+/// Calculates the next Money Flow Index value incrementally.
+///
+/// Args:
+///   high: Current high price.
+///   low: Current low price.
+///   close: Current close price.
+///   volume: Current volume.
+///   prev_typ_price: Previous typical price.
+///   old_typ_price: Typical price of the bar leaving the window, `period` bars back.
+///   old_prev_typ_price: Typical price of the bar before the one leaving the window.
+///   old_money_flow: Money flow of the bar leaving the window.
+///   prev_pos_flow: Previous rolling sum of positive money flow.
+///   prev_neg_flow: Previous rolling sum of negative money flow.
+///
+/// Returns:
+///   A tuple of (mfi, typ_price, money_flow, pos_flow, neg_flow).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> mfi, typ_price, money_flow, pos_flow, neg_flow = kand.mfi_inc(
+///   ...     11.0, 9.0, 10.0, 150.0, 11.0, 10.0, 9.0, 1500.0, 3700.0, 0.0
+///   ... )
+///   >>> (pos_flow, neg_flow)
+///   (2200.0, 1500.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "mfi_inc", signature = (
+    high,
+    low,
+    close,
+    volume,
+    prev_typ_price,
+    old_typ_price,
+    old_prev_typ_price,
+    old_money_flow,
+    prev_pos_flow,
+    prev_neg_flow
+))]
+pub fn mfi_inc_py(
+    high: TAFloat,
+    low: TAFloat,
+    close: TAFloat,
+    volume: TAFloat,
+    prev_typ_price: TAFloat,
+    old_typ_price: TAFloat,
+    old_prev_typ_price: TAFloat,
+    old_money_flow: TAFloat,
+    prev_pos_flow: TAFloat,
+    prev_neg_flow: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    mfi::mfi_inc(
+        high,
+        low,
+        close,
+        volume,
+        prev_typ_price,
+        old_typ_price,
+        old_prev_typ_price,
+        old_money_flow,
+        prev_pos_flow,
+        prev_neg_flow,
+    )
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -1,7 +1,9 @@
-use kand::{TAFloat, ohlcv::midpoint};
+use kand::{Indicator, TAFloat, ohlcv::midpoint};
 use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
 use pyo3::prelude::*;
 
+use crate::helper::warn_deprecated;
+
 /// Calculates Midpoint values for a NumPy array.
 ///
 /// The Midpoint is a technical indicator that represents the arithmetic mean of the highest and lowest
@@ -66,6 +68,11 @@ pub fn midpoint_py(
 /// Provides an optimized way to calculate the next Midpoint value when new data arrives,
 /// without recalculating the entire series.
 ///
+/// Deprecated:
+///   The highest and lowest values cannot be tracked exactly from the previous values once the
+///   extreme leaves the window. Use the `Midpoint` streaming object, which keeps the rolling
+///   windows between calls.
+///
 /// Args:
 ///   price: Current price value as `TAFloat`.
 ///   prev_highest: Previous highest value as `TAFloat`.
@@ -97,6 +104,66 @@ pub fn midpoint_inc_py(
     prev_lowest: TAFloat,
     period: usize,
 ) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
-    py.allow_threads(|| midpoint::midpoint_inc(price, prev_highest, prev_lowest, period))
+    warn_deprecated(
+        py,
+        c"kand.midpoint_inc never drops an extreme that leaves the window; use kand.Midpoint instead",
+    )?;
+    #[allow(deprecated)]
+    midpoint::midpoint_inc_legacy(price, prev_highest, prev_lowest, period)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
 }
+
+/// Streaming Midpoint.
+///
+/// Keeps monotonic windows of the last `period` prices between calls, so every update is
+/// O(1) amortized and stays exact when an extreme leaves the window.
+///
+/// Args:
+///   period: Time period for calculation (must be >= 2).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> midpoint = kand.Midpoint(2)
+///   >>> [midpoint.update(price) for price in [10.0, 12.0, 11.0]]
+///   [None, 11.0, 11.5]
+///   ```
+#[pyclass(name = "Midpoint")]
+pub struct MidpointPy {
+    inner: midpoint::Midpoint,
+}
+
+#[pymethods]
+impl MidpointPy {
+    #[new]
+    #[pyo3(signature = (period))]
+    fn new(period: usize) -> PyResult<Self> {
+        let inner = midpoint::Midpoint::new(period)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    /// Adds the next price and returns the midpoint, or None while the window is filling.
+    fn update(&mut self, price: TAFloat) -> PyResult<Option<TAFloat>> {
+        self.inner
+            .update(price)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    /// Clears the rolling windows.
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    /// Whether enough bars were consumed for `update` to return values.
+    #[getter]
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    /// Number of bars consumed before the first value.
+    #[getter]
+    fn lookback(&self) -> usize {
+        self.inner.lookback()
+    }
+}
@@ -1,7 +1,9 @@
-use kand::{TAFloat, ohlcv::midprice};
+use kand::{Indicator, TAFloat, ohlcv::midprice};
 use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
 use pyo3::prelude::*;
 
+use crate::helper::warn_deprecated;
+
 /// Calculates Midpoint Price values for a NumPy array.
 ///
 /// The Midpoint Price is a technical indicator that represents the mean value between the highest high
@@ -71,6 +73,11 @@ pub fn midprice_py(
 /// Provides an optimized way to calculate the next Midpoint Price value when new data arrives,
 /// without recalculating the entire series.
 ///
+/// Deprecated:
+///   The highest high and lowest low cannot be tracked exactly from the previous values once
+///   the extreme leaves the window. Use the `Midprice` streaming object, which keeps the
+///   rolling windows between calls.
+///
 /// Args:
 ///
 ///   high: Current high price value as `TAFloat`.
@@ -106,6 +113,66 @@ pub fn midprice_inc_py(
     prev_lowest: TAFloat,
     period: usize,
 ) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
-    py.allow_threads(|| midprice::midprice_inc(high, low, prev_highest, prev_lowest, period))
+    warn_deprecated(
+        py,
+        c"kand.midprice_inc never drops an extreme that leaves the window; use kand.Midprice instead",
+    )?;
+    #[allow(deprecated)]
+    midprice::midprice_inc_legacy(high, low, prev_highest, prev_lowest, period)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
 }
+
+/// Streaming Midpoint Price.
+///
+/// Keeps monotonic windows of the last `period` highs and lows between calls, so every
+/// update is O(1) amortized and stays exact when an extreme leaves the window.
+///
+/// Args:
+///   period: Time period for calculation (must be >= 2).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> midprice = kand.Midprice(2)
+///   >>> [midprice.update(h, l) for h, l in [(10.0, 8.0), (12.0, 9.0), (11.0, 10.0)]]
+///   [None, 10.0, 10.5]
+///   ```
+#[pyclass(name = "Midprice")]
+pub struct MidpricePy {
+    inner: midprice::Midprice,
+}
+
+#[pymethods]
+impl MidpricePy {
+    #[new]
+    #[pyo3(signature = (period))]
+    fn new(period: usize) -> PyResult<Self> {
+        let inner = midprice::Midprice::new(period)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    /// Adds the next bar and returns the midpoint price, or None while the window is filling.
+    fn update(&mut self, high: TAFloat, low: TAFloat) -> PyResult<Option<TAFloat>> {
+        self.inner
+            .update((high, low))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    /// Clears the rolling windows.
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    /// Whether enough bars were consumed for `update` to return values.
+    #[getter]
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    /// Number of bars consumed before the first value.
+    #[getter]
+    fn lookback(&self) -> usize {
+        self.inner.lookback()
+    }
+}
@@ -3,10 +3,11 @@
 pub mod adr;
 pub mod adx;
 pub mod adxr;
-// pub mod apo;
+pub mod apo;
 pub mod aroon;
 pub mod aroonosc;
 pub mod atr;
+pub mod bandwidth;
 pub mod bbands;
 pub mod bop;
 pub mod cci;
@@ -18,11 +19,16 @@
 pub mod cdl_long_shadow;
 pub mod cdl_marubozu;
 // pub mod cdl_spinning_top;
-// pub mod cmo;
+pub mod cmf;
+pub mod cmo;
 pub mod dema;
+pub mod dmi;
+pub mod donchian;
 pub mod dx;
 pub mod ecl;
 pub mod ema;
+pub mod emv;
+pub mod force_index;
 // pub mod harm_5_0;
 // pub mod harm_abcd;
 // pub mod harm_abcd_alt;
@@ -42,20 +48,25 @@
 // pub mod ht_trendline;
 // pub mod ht_trendmode;
 // pub mod hvol;
+pub mod ichimoku;
 // pub mod ict_bos;
 // pub mod ict_choch;
 // pub mod ict_fvg;
 // pub mod ict_liquidity;
 // pub mod ict_ob;
 // pub mod ict_session;
-// pub mod kama;
+pub mod kagi;
+pub mod kama;
+pub mod keltner;
+pub mod line_break;
 // pub mod linearreg;
 // pub mod linearreg_angle;
 // pub mod linearreg_intercept;
 // pub mod linearreg_slope;
+pub mod ma;
 pub mod macd;
-// pub mod macdext;
-// pub mod mama;
+pub mod macdext;
+pub mod mama;
 pub mod medprice;
 pub mod mfi;
 pub mod midpoint;
@@ -67,10 +78,14 @@
 pub mod obv;
 pub mod plus_di;
 pub mod plus_dm;
-// pub mod ppo;
+pub mod pnf;
+pub mod ppo;
+pub mod pvt;
 // pub mod qqe;
 // pub mod retracements;
 // pub mod rf;
+pub mod range_bars;
+pub mod renko;
 pub mod rma;
 pub mod roc;
 pub mod rocp;
@@ -78,11 +93,12 @@
 pub mod rocr100;
 pub mod rsi;
 pub mod sar;
-// pub mod sarext;
+pub mod sarext;
 pub mod sma;
+pub mod starc;
 pub mod stoch;
-// pub mod stochf;
-// pub mod stochrsi;
+pub mod stochf;
+pub mod stochrsi;
 pub mod supertrend;
 pub mod t3;
 pub mod tema;
@@ -93,9 +109,11 @@
 // pub mod tsf;
 // pub mod twb;
 pub mod typprice;
-// pub mod ultosc;
+pub mod ultosc;
 pub mod vegas;
+pub mod volume_index;
 pub mod vwap;
+pub mod vwap_bands;
 pub mod wclprice;
 pub mod willr;
 pub mod wma;
@@ -0,0 +1,88 @@
+use kand::{TAFloat, TAInt, ohlcv::pnf};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_price_source;
+
+/// Builds Point & Figure columns from NumPy arrays.
+///
+/// Columns of Xs grow while price fills higher boxes and columns of Os while it fills lower
+/// ones. A new column starts once price fills `reversal` boxes the other way.
+///
+/// Args:
+///     high: High prices as a 1-D NumPy array of type `TAFloat`.
+///     low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///     close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///     box_size: Price distance covered by each box (must be > 0).
+///     reversal: Boxes needed to start a new column. Defaults to 3.
+///     source: 0 to measure moves on closes, 1 on highs and lows. Defaults to 0.
+///
+/// Returns:
+///     A tuple of five 1-D NumPy arrays with one entry per column, the last column still forming:
+///     - Column types (1 X, -1 O)
+///     - Lowest box level of each column
+///     - Highest box level of each column
+///     - Index of the bar that started each column
+///     - Index of the bar that added the last box to each column
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> close = np.array([10.0, 11.2, 13.5, 12.4, 9.8, 11.8])
+///     >>> directions, lows, highs, start_idx, end_idx = kand.pnf(close, close, close, 1.0)
+///     ```
+#[pyfunction]
+#[pyo3(name = "pnf", signature = (high, low, close, box_size, reversal=3, source=0))]
+#[allow(clippy::type_complexity)]
+pub fn pnf_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    box_size: TAFloat,
+    reversal: usize,
+    source: TAInt,
+) -> PyResult<(
+    Py<PyArray1<TAInt>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<usize>>,
+    Py<PyArray1<usize>>,
+)> {
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let source = parse_price_source(source)?;
+
+    let mut output_direction = Vec::new();
+    let mut output_low = Vec::new();
+    let mut output_high = Vec::new();
+    let mut output_start_index = Vec::new();
+    let mut output_end_index = Vec::new();
+
+    py.allow_threads(|| {
+        pnf::pnf(
+            input_high,
+            input_low,
+            input_close,
+            box_size,
+            reversal,
+            source,
+            &mut output_direction,
+            &mut output_low,
+            &mut output_high,
+            &mut output_start_index,
+            &mut output_end_index,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_direction.into_pyarray(py).into(),
+        output_low.into_pyarray(py).into(),
+        output_high.into_pyarray(py).into(),
+        output_start_index.into_pyarray(py).into(),
+        output_end_index.into_pyarray(py).into(),
+    ))
+}
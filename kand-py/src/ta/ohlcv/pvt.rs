@@ -0,0 +1,72 @@
+use kand::{TAFloat, ohlcv::pvt};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the Price Volume Trend (PVT) over NumPy arrays.
+///
+/// PVT accumulates volume weighted by the percentage change of the close, starting from 0.
+///
+/// Args:
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A new 1-D NumPy array containing the PVT values. The array has the same length as the input.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> close = np.array([10.0, 11.0, 9.9])
+///   >>> volume = np.array([100.0, 200.0, 300.0])
+///   >>> result = kand.pvt(close, volume)
+///   >>> print(result)
+///   [  0.  20. -10.]
+///   ```
+#[pyfunction]
+#[pyo3(name = "pvt", signature = (close, volume))]
+pub fn pvt_py(
+    py: Python,
+    close: PyReadonlyArray1<TAFloat>,
+    volume: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_close = close.as_slice()?;
+    let input_volume = volume.as_slice()?;
+    let len = input_close.len();
+
+    let mut output = vec![0.0; len];
+
+    py.allow_threads(|| pvt::pvt(input_close, input_volume, output.as_mut_slice()))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output.into_pyarray(py).into())
+}
+
+/// Calculates the next PVT value incrementally.
+///
+/// Args:
+///   close: Current close price.
+///   prev_close: Previous close price.
+///   volume: Current volume.
+///   prev_pvt: Previous PVT value.
+///
+/// Returns:
+///   The new PVT value.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.pvt_inc(11.0, 10.0, 200.0, 0.0)
+///   20.0
+///   ```
+#[pyfunction]
+#[pyo3(name = "pvt_inc", signature = (close, prev_close, volume, prev_pvt))]
+pub fn pvt_inc_py(
+    close: TAFloat,
+    prev_close: TAFloat,
+    volume: TAFloat,
+    prev_pvt: TAFloat,
+) -> PyResult<TAFloat> {
+    pvt::pvt_inc(close, prev_close, volume, prev_pvt)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
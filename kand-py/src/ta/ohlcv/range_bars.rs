@@ -0,0 +1,75 @@
+use kand::{TAFloat, ohlcv::range_bars};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Builds fixed-range bars from a NumPy array of prices.
+///
+/// Each finished bar spans exactly `range` from low to high; the next bar opens at its close.
+///
+/// Args:
+///     price: Prices as a 1-D NumPy array of type `TAFloat`, typically trades or closes.
+///     range: High-low range of each finished bar (must be > 0).
+///
+/// Returns:
+///     A tuple of six 1-D NumPy arrays with one entry per bar, the last bar still forming:
+///     - Bar open prices
+///     - Bar high prices
+///     - Bar low prices
+///     - Bar close prices
+///     - Index of the price that opened each bar
+///     - Index of the last price in each bar
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> price = np.array([10.0, 10.5, 9.8, 11.1, 10.9, 12.5])
+///     >>> opens, highs, lows, closes, start_idx, end_idx = kand.range_bars(price, 1.0)
+///     ```
+#[pyfunction]
+#[pyo3(name = "range_bars", signature = (price, range))]
+#[allow(clippy::type_complexity)]
+pub fn range_bars_py(
+    py: Python,
+    price: PyReadonlyArray1<TAFloat>,
+    range: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<usize>>,
+    Py<PyArray1<usize>>,
+)> {
+    let input_price = price.as_slice()?;
+
+    let mut output_open = Vec::new();
+    let mut output_high = Vec::new();
+    let mut output_low = Vec::new();
+    let mut output_close = Vec::new();
+    let mut output_start_index = Vec::new();
+    let mut output_end_index = Vec::new();
+
+    py.allow_threads(|| {
+        range_bars::range_bars(
+            input_price,
+            range,
+            &mut output_open,
+            &mut output_high,
+            &mut output_low,
+            &mut output_close,
+            &mut output_start_index,
+            &mut output_end_index,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_open.into_pyarray(py).into(),
+        output_high.into_pyarray(py).into(),
+        output_low.into_pyarray(py).into(),
+        output_close.into_pyarray(py).into(),
+        output_start_index.into_pyarray(py).into(),
+        output_end_index.into_pyarray(py).into(),
+    ))
+}
@@ -0,0 +1,163 @@
+use kand::{TAFloat, TAInt, ohlcv::renko};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_price_source;
+
+/// Builds Renko bricks from NumPy arrays using a fixed box size.
+///
+/// A brick is drawn each time price moves a full box size. Continuing the current direction
+/// needs one box, reversing it needs `reversal` boxes. One bar can complete several bricks.
+///
+/// Args:
+///     high: High prices as a 1-D NumPy array of type `TAFloat`.
+///     low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///     close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///     box_size: Price distance covered by each brick (must be > 0).
+///     reversal: Boxes needed to reverse the direction. Defaults to 2.
+///     source: 0 to measure moves on closes, 1 on highs and lows. Defaults to 0.
+///
+/// Returns:
+///     A tuple of four 1-D NumPy arrays with one entry per brick:
+///     - Brick open prices
+///     - Brick close prices
+///     - Brick directions (1 up, -1 down)
+///     - Index of the source bar that completed each brick
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> close = np.array([10.0, 11.2, 13.1, 12.5, 10.9])
+///     >>> opens, closes, directions, indices = kand.renko(close, close, close, 1.0)
+///     ```
+#[pyfunction]
+#[pyo3(name = "renko", signature = (high, low, close, box_size, reversal=2, source=0))]
+#[allow(clippy::type_complexity)]
+pub fn renko_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    box_size: TAFloat,
+    reversal: usize,
+    source: TAInt,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAInt>>,
+    Py<PyArray1<usize>>,
+)> {
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let source = parse_price_source(source)?;
+
+    let mut output_open = Vec::new();
+    let mut output_close = Vec::new();
+    let mut output_direction = Vec::new();
+    let mut output_index = Vec::new();
+
+    py.allow_threads(|| {
+        renko::renko(
+            input_high,
+            input_low,
+            input_close,
+            box_size,
+            reversal,
+            source,
+            &mut output_open,
+            &mut output_close,
+            &mut output_direction,
+            &mut output_index,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_open.into_pyarray(py).into(),
+        output_close.into_pyarray(py).into(),
+        output_direction.into_pyarray(py).into(),
+        output_index.into_pyarray(py).into(),
+    ))
+}
+
+/// Builds Renko bricks from NumPy arrays using a box size derived from the ATR.
+///
+/// The box size is the first available ATR value, and the close of that bar is the base price,
+/// so no future data is used.
+///
+/// Args:
+///     high: High prices as a 1-D NumPy array of type `TAFloat`.
+///     low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///     close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///     atr_period: ATR period used for the box size. Defaults to 14.
+///     reversal: Boxes needed to reverse the direction. Defaults to 2.
+///     source: 0 to measure moves on closes, 1 on highs and lows. Defaults to 0.
+///
+/// Returns:
+///     A tuple of four 1-D NumPy arrays with one entry per brick (open, close, direction and
+///     source bar index), followed by the box size that was used.
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> high = np.array([10.5, 11.0, 11.5, 12.5, 14.0, 13.0])
+///     >>> low = np.array([9.5, 10.0, 10.5, 11.5, 12.5, 10.0])
+///     >>> close = np.array([10.0, 10.5, 11.0, 12.0, 13.5, 10.5])
+///     >>> opens, closes, directions, indices, box_size = kand.renko_atr(high, low, close, 2)
+///     ```
+#[pyfunction]
+#[pyo3(name = "renko_atr", signature = (high, low, close, atr_period=14, reversal=2, source=0))]
+#[allow(clippy::type_complexity)]
+pub fn renko_atr_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    atr_period: usize,
+    reversal: usize,
+    source: TAInt,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAInt>>,
+    Py<PyArray1<usize>>,
+    TAFloat,
+)> {
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let source = parse_price_source(source)?;
+
+    let mut output_open = Vec::new();
+    let mut output_close = Vec::new();
+    let mut output_direction = Vec::new();
+    let mut output_index = Vec::new();
+
+    let box_size = py
+        .allow_threads(|| {
+            renko::renko_atr(
+                input_high,
+                input_low,
+                input_close,
+                atr_period,
+                reversal,
+                source,
+                &mut output_open,
+                &mut output_close,
+                &mut output_direction,
+                &mut output_index,
+            )
+        })
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_open.into_pyarray(py).into(),
+        output_close.into_pyarray(py).into(),
+        output_direction.into_pyarray(py).into(),
+        output_index.into_pyarray(py).into(),
+        box_size,
+    ))
+}
@@ -0,0 +1,202 @@
+use kand::{TAFloat, ohlcv::sarext};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculates the Extended Parabolic SAR (SAREXT) over NumPy arrays.
+///
+/// SAREXT adds a start value, an offset on reversals and separate long/short acceleration
+/// factors to the Parabolic SAR. Following TA-Lib, values are positive while long and negative
+/// while short.
+///
+/// Args:
+///   high: Input high prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Input low prices as a 1-D NumPy array of type `TAFloat`.
+///   start_value: Initial SAR; 0 detects the trend, a negative value starts short. Defaults to 0.
+///   offset_on_reverse: Fraction of the SAR used as offset on reversals. Defaults to 0.
+///   af_init_long: Initial long acceleration factor. Defaults to 0.02.
+///   af_long: Long acceleration factor step. Defaults to 0.02.
+///   af_max_long: Maximum long acceleration factor. Defaults to 0.2.
+///   af_init_short: Initial short acceleration factor. Defaults to 0.02.
+///   af_short: Short acceleration factor step. Defaults to 0.02.
+///   af_max_short: Maximum short acceleration factor. Defaults to 0.2.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - Signed SAR values
+///   - Stops projected for the next bar
+///   - Trend direction (true=long, false=short)
+///   - Acceleration factors
+///   - Extreme points
+///   Each array has the same length as the input, with the first element containing NaN.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
+///   >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
+///   >>> sar, next_sar, is_long, af, ep = kand.sarext(high, low, af_max_short=0.3)
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "sarext",
+    signature = (
+        high,
+        low,
+        start_value=0.0,
+        offset_on_reverse=0.0,
+        af_init_long=0.02,
+        af_long=0.02,
+        af_max_long=0.2,
+        af_init_short=0.02,
+        af_short=0.02,
+        af_max_short=0.2
+    )
+)]
+#[allow(clippy::type_complexity)]
+pub fn sarext_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    start_value: TAFloat,
+    offset_on_reverse: TAFloat,
+    af_init_long: TAFloat,
+    af_long: TAFloat,
+    af_max_long: TAFloat,
+    af_init_short: TAFloat,
+    af_short: TAFloat,
+    af_max_short: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<bool>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let len = input_high.len();
+
+    let mut output_sarext = vec![0.0; len];
+    let mut output_next_sar = vec![0.0; len];
+    let mut output_is_long = vec![false; len];
+    let mut output_af = vec![0.0; len];
+    let mut output_ep = vec![0.0; len];
+
+    py.allow_threads(|| {
+        sarext::sarext(
+            input_high,
+            input_low,
+            start_value,
+            offset_on_reverse,
+            af_init_long,
+            af_long,
+            af_max_long,
+            af_init_short,
+            af_short,
+            af_max_short,
+            output_sarext.as_mut_slice(),
+            output_next_sar.as_mut_slice(),
+            output_is_long.as_mut_slice(),
+            output_af.as_mut_slice(),
+            output_ep.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_sarext.into_pyarray(py).into(),
+        output_next_sar.into_pyarray(py).into(),
+        output_is_long.into_pyarray(py).into(),
+        output_af.into_pyarray(py).into(),
+        output_ep.into_pyarray(py).into(),
+    ))
+}
+
+/// Incrementally updates the Extended Parabolic SAR with new price data.
+///
+/// Args:
+///   high: Current period's high price.
+///   low: Current period's low price.
+///   prev_high: Previous period's high price.
+///   prev_low: Previous period's low price.
+///   prev_sar: Stop projected for the current period (the previous `next_sar`).
+///   is_long: Current trend direction (true=long, false=short).
+///   af: Current acceleration factor.
+///   ep: Current extreme point.
+///   offset_on_reverse: Fraction of the SAR used as offset on reversals. Defaults to 0.
+///   af_init_long: Initial long acceleration factor. Defaults to 0.02.
+///   af_long: Long acceleration factor step. Defaults to 0.02.
+///   af_max_long: Maximum long acceleration factor. Defaults to 0.2.
+///   af_init_short: Initial short acceleration factor. Defaults to 0.02.
+///   af_short: Short acceleration factor step. Defaults to 0.02.
+///   af_max_short: Maximum short acceleration factor. Defaults to 0.2.
+///
+/// Returns:
+///   A tuple containing (signed SAR value, next projected stop, trend direction, acceleration
+///   factor, extreme point).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> sar, next_sar, is_long, af, ep = kand.sarext_inc(
+///   ...     15.0, 14.0, 14.5, 13.5, 13.0, True, 0.02, 14.5
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "sarext_inc",
+    signature = (
+        high,
+        low,
+        prev_high,
+        prev_low,
+        prev_sar,
+        is_long,
+        af,
+        ep,
+        offset_on_reverse=0.0,
+        af_init_long=0.02,
+        af_long=0.02,
+        af_max_long=0.2,
+        af_init_short=0.02,
+        af_short=0.02,
+        af_max_short=0.2
+    )
+)]
+pub fn sarext_inc_py(
+    high: TAFloat,
+    low: TAFloat,
+    prev_high: TAFloat,
+    prev_low: TAFloat,
+    prev_sar: TAFloat,
+    is_long: bool,
+    af: TAFloat,
+    ep: TAFloat,
+    offset_on_reverse: TAFloat,
+    af_init_long: TAFloat,
+    af_long: TAFloat,
+    af_max_long: TAFloat,
+    af_init_short: TAFloat,
+    af_short: TAFloat,
+    af_max_short: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, bool, TAFloat, TAFloat)> {
+    sarext::sarext_inc(
+        high,
+        low,
+        prev_high,
+        prev_low,
+        prev_sar,
+        is_long,
+        af,
+        ep,
+        offset_on_reverse,
+        af_init_long,
+        af_long,
+        af_max_long,
+        af_init_short,
+        af_short,
+        af_max_short,
+    )
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,166 @@
+use kand::{TAFloat, ohlcv::starc};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes STARC Bands (Stoller Average Range Channels) over NumPy arrays.
+///
+/// The middle line is a short SMA of the close and the bands sit a multiple of a longer
+/// Average True Range above and below it.
+///
+/// Args:
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Period of the SMA middle line. Defaults to 6.
+///   atr_period: Period of the ATR. Defaults to 15.
+///   multiplier: ATR multiple for the bands. Defaults to 2.0.
+///
+/// Returns:
+///   A tuple of six 1-D NumPy arrays containing:
+///   - Upper band values
+///   - Middle line values
+///   - Lower band values
+///   - ATR values
+///   - %B values
+///   - Bandwidth values
+///   Each array has the same length as the input, with the warm-up elements containing
+///   NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([10.0, 11.0, 12.0, 11.5, 12.5])
+///   >>> low = np.array([9.0, 9.5, 10.5, 10.0, 11.0])
+///   >>> close = np.array([9.5, 10.5, 11.5, 10.5, 12.0])
+///   >>> upper, middle, lower, atr, percent_b, bandwidth = kand.starc(high, low, close, 2, 3)
+///   >>> print(upper[3], lower[3])
+///   14.0 8.0
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "starc",
+    signature = (high, low, close, period=6, atr_period=15, multiplier=2.0)
+)]
+#[allow(clippy::type_complexity)]
+pub fn starc_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    atr_period: usize,
+    multiplier: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let high_slice = high.as_slice()?;
+    let low_slice = low.as_slice()?;
+    let close_slice = close.as_slice()?;
+    let len = high_slice.len();
+
+    let mut output_upper = vec![0.0; len];
+    let mut output_middle = vec![0.0; len];
+    let mut output_lower = vec![0.0; len];
+    let mut output_atr = vec![0.0; len];
+    let mut output_percent_b = vec![0.0; len];
+    let mut output_bandwidth = vec![0.0; len];
+
+    py.allow_threads(|| {
+        starc::starc(
+            high_slice,
+            low_slice,
+            close_slice,
+            period,
+            atr_period,
+            multiplier,
+            output_upper.as_mut_slice(),
+            output_middle.as_mut_slice(),
+            output_lower.as_mut_slice(),
+            output_atr.as_mut_slice(),
+            output_percent_b.as_mut_slice(),
+            output_bandwidth.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_upper.into_pyarray(py).into(),
+        output_middle.into_pyarray(py).into(),
+        output_lower.into_pyarray(py).into(),
+        output_atr.into_pyarray(py).into(),
+        output_percent_b.into_pyarray(py).into(),
+        output_bandwidth.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculates the next STARC Band values incrementally.
+///
+/// Args:
+///   high: Current high price.
+///   low: Current low price.
+///   close: Current close price.
+///   prev_close: Previous close price.
+///   old_close: Close leaving the SMA window, `period` bars back.
+///   prev_middle: Previous SMA middle line value.
+///   prev_atr: Previous ATR value.
+///   period: Period of the SMA middle line.
+///   atr_period: Period of the ATR.
+///   multiplier: ATR multiple for the bands.
+///
+/// Returns:
+///   A tuple of (upper, middle, lower, atr, percent_b, bandwidth).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> upper, middle, lower, atr, percent_b, bandwidth = kand.starc_inc(
+///   ...     12.0, 10.5, 12.0, 10.5, 11.5, 11.0, 1.5, 2, 3, 2.0
+///   ... )
+///   >>> print(upper, lower)
+///   14.25 8.25
+///   ```
+#[pyfunction]
+#[pyo3(name = "starc_inc", signature = (
+    high,
+    low,
+    close,
+    prev_close,
+    old_close,
+    prev_middle,
+    prev_atr,
+    period,
+    atr_period,
+    multiplier
+))]
+pub fn starc_inc_py(
+    high: TAFloat,
+    low: TAFloat,
+    close: TAFloat,
+    prev_close: TAFloat,
+    old_close: TAFloat,
+    prev_middle: TAFloat,
+    prev_atr: TAFloat,
+    period: usize,
+    atr_period: usize,
+    multiplier: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    starc::starc_inc(
+        high,
+        low,
+        close,
+        prev_close,
+        old_close,
+        prev_middle,
+        prev_atr,
+        period,
+        atr_period,
+        multiplier,
+    )
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -1,7 +1,9 @@
-use kand::{TAFloat, ohlcv::stoch};
+use kand::{TAFloat, TAInt, ohlcv::stoch};
 use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
 use pyo3::prelude::*;
 
+use crate::helper::parse_ma_type;
+
 /// Computes the Stochastic Oscillator indicator over NumPy arrays.
 ///
 /// The Stochastic Oscillator is a momentum indicator that shows the location of the close
@@ -15,6 +17,9 @@
 ///     k_period: Period for %K calculation. Must be >= 2.
 ///     k_slow_period: Smoothing period for slow %K. Must be >= 2.
 ///     d_period: Period for %D calculation. Must be >= 2.
+///     k_ma_type: Moving average type for slow %K: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA, 4=RMA,
+///         5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+///     d_ma_type: Moving average type for %D, same values as `k_ma_type`. Defaults to 5 (SMA).
 ///
 /// Returns:
 ///     A tuple of three 1-D NumPy arrays containing:
@@ -33,7 +38,10 @@
 ///     >>> fast_k, k, d = kand.stoch(high, low, close, 3, 2, 2)
 ///     ```
 #[pyfunction]
-#[pyo3(name = "stoch", signature = (high, low, close, k_period, k_slow_period, d_period))]
+#[pyo3(
+    name = "stoch",
+    signature = (high, low, close, k_period, k_slow_period, d_period, k_ma_type=5, d_ma_type=5)
+)]
 pub fn stoch_py(
     py: Python,
     high: PyReadonlyArray1<TAFloat>,
@@ -42,6 +50,8 @@ pub fn stoch_py(
     k_period: usize,
     k_slow_period: usize,
     d_period: usize,
+    k_ma_type: TAInt,
+    d_ma_type: TAInt,
 ) -> PyResult<(
     Py<PyArray1<TAFloat>>,
     Py<PyArray1<TAFloat>>,
@@ -51,6 +61,8 @@ pub fn stoch_py(
     let low_slice = low.as_slice()?;
     let close_slice = close.as_slice()?;
     let len = high_slice.len();
+    let k_ma_type = parse_ma_type(k_ma_type)?;
+    let d_ma_type = parse_ma_type(d_ma_type)?;
 
     let mut output_fast_k = vec![0.0; len];
     let mut output_k = vec![0.0; len];
@@ -63,7 +75,9 @@ pub fn stoch_py(
             close_slice,
             k_period,
             k_slow_period,
+            k_ma_type,
             d_period,
+            d_ma_type,
             output_fast_k.as_mut_slice(),
             output_k.as_mut_slice(),
             output_d.as_mut_slice(),
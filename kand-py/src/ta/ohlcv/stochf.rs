@@ -0,0 +1,77 @@
+use kand::{TAFloat, TAInt, ohlcv::stochf};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_ma_type;
+
+/// Computes the Fast Stochastic Oscillator (STOCHF) over NumPy arrays.
+///
+/// The Fast Stochastic is the unsmoothed variant of the Stochastic Oscillator: fast %K locates
+/// the close within the high-low range and fast %D is a moving average of fast %K.
+///
+/// Args:
+///     high: High prices as a 1-D NumPy array of type `TAFloat`.
+///     low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///     close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///     k_period: Period for fast %K calculation. Must be >= 2.
+///     d_period: Period for fast %D calculation. Must be >= 2.
+///     d_ma_type: Moving average type for fast %D: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA, 4=RMA,
+///         5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+///
+/// Returns:
+///     A tuple of two 1-D NumPy arrays containing:
+///     - Fast %K values
+///     - Fast %D values
+///     Each array has the same length as the input, with initial values being NaN.
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
+///     >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
+///     >>> close = np.array([9.0, 11.0, 14.0, 12.0, 11.0])
+///     >>> fast_k, fast_d = kand.stochf(high, low, close, 3, 2)
+///     ```
+#[pyfunction]
+#[pyo3(
+    name = "stochf",
+    signature = (high, low, close, k_period, d_period, d_ma_type=5)
+)]
+pub fn stochf_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    k_period: usize,
+    d_period: usize,
+    d_ma_type: TAInt,
+) -> PyResult<(Py<PyArray1<TAFloat>>, Py<PyArray1<TAFloat>>)> {
+    let high_slice = high.as_slice()?;
+    let low_slice = low.as_slice()?;
+    let close_slice = close.as_slice()?;
+    let len = high_slice.len();
+    let d_ma_type = parse_ma_type(d_ma_type)?;
+
+    let mut output_fast_k = vec![0.0; len];
+    let mut output_fast_d = vec![0.0; len];
+
+    py.allow_threads(|| {
+        stochf::stochf(
+            high_slice,
+            low_slice,
+            close_slice,
+            k_period,
+            d_period,
+            d_ma_type,
+            output_fast_k.as_mut_slice(),
+            output_fast_d.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_fast_k.into_pyarray(py).into(),
+        output_fast_d.into_pyarray(py).into(),
+    ))
+}
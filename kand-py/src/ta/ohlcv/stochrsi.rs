@@ -0,0 +1,89 @@
+use kand::{TAFloat, TAInt, ohlcv::stochrsi};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_ma_type;
+
+/// Computes the Stochastic RSI (STOCHRSI) over a NumPy array.
+///
+/// Stochastic RSI applies the Fast Stochastic Oscillator to the RSI series instead of prices,
+/// showing where the current RSI sits within its recent range.
+///
+/// Args:
+///     data: Input prices as a 1-D NumPy array of type `TAFloat`.
+///     rsi_period: Period for RSI calculation. Must be >= 2.
+///     k_period: Period for fast %K calculation. Must be >= 2.
+///     d_period: Period for fast %D calculation. Must be >= 2.
+///     d_ma_type: Moving average type for fast %D: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA, 4=RMA,
+///         5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+///
+/// Returns:
+///     A tuple of five 1-D NumPy arrays containing:
+///     - Fast %K values
+///     - Fast %D values
+///     - RSI values
+///     - RSI average gain values
+///     - RSI average loss values
+///     Each array has the same length as the input, with initial values being NaN.
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> data = np.array([44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42])
+///     >>> fast_k, fast_d, rsi, avg_gain, avg_loss = kand.stochrsi(data, 3, 3, 2)
+///     ```
+#[pyfunction]
+#[pyo3(
+    name = "stochrsi",
+    signature = (data, rsi_period, k_period, d_period, d_ma_type=5)
+)]
+#[allow(clippy::type_complexity)]
+pub fn stochrsi_py(
+    py: Python,
+    data: PyReadonlyArray1<TAFloat>,
+    rsi_period: usize,
+    k_period: usize,
+    d_period: usize,
+    d_ma_type: TAInt,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input = data.as_slice()?;
+    let len = input.len();
+    let d_ma_type = parse_ma_type(d_ma_type)?;
+
+    let mut output_fast_k = vec![0.0; len];
+    let mut output_fast_d = vec![0.0; len];
+    let mut output_rsi = vec![0.0; len];
+    let mut output_avg_gain = vec![0.0; len];
+    let mut output_avg_loss = vec![0.0; len];
+
+    py.allow_threads(|| {
+        stochrsi::stochrsi(
+            input,
+            rsi_period,
+            k_period,
+            d_period,
+            d_ma_type,
+            output_fast_k.as_mut_slice(),
+            output_fast_d.as_mut_slice(),
+            output_rsi.as_mut_slice(),
+            output_avg_gain.as_mut_slice(),
+            output_avg_loss.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_fast_k.into_pyarray(py).into(),
+        output_fast_d.into_pyarray(py).into(),
+        output_rsi.into_pyarray(py).into(),
+        output_avg_gain.into_pyarray(py).into(),
+        output_avg_loss.into_pyarray(py).into(),
+    ))
+}
@@ -0,0 +1,105 @@
+use kand::{TAFloat, ohlcv::ultosc};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Computes the Ultimate Oscillator (ULTOSC) over NumPy arrays.
+///
+/// The Ultimate Oscillator combines buying pressure relative to the true range over three
+/// timeframes. The shortest window is weighted 4, the medium one 2 and the longest one 1,
+/// whatever order the periods are given in.
+///
+/// Args:
+///     high: High prices as a 1-D NumPy array of type `TAFloat`.
+///     low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///     close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///     period1: First averaging period. Defaults to 7.
+///     period2: Second averaging period. Defaults to 14.
+///     period3: Third averaging period. Defaults to 28.
+///
+/// Returns:
+///     A new 1-D NumPy array containing the ULTOSC values. The array has the same length as the
+///     inputs, with the first `max(period1, period2, period3)` elements containing NaN values.
+///
+/// Examples:
+///     ```python
+///     >>> import numpy as np
+///     >>> import kand
+///     >>> high = np.array([10.0, 11.0, 12.0, 11.5, 12.5])
+///     >>> low = np.array([9.0, 9.5, 10.5, 10.0, 11.0])
+///     >>> close = np.array([9.5, 10.5, 11.5, 10.5, 12.0])
+///     >>> result = kand.ultosc(high, low, close, 1, 2, 3)
+///     ```
+#[pyfunction]
+#[pyo3(name = "ultosc", signature = (high, low, close, period1=7, period2=14, period3=28))]
+pub fn ultosc_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    period1: usize,
+    period2: usize,
+    period3: usize,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_high = high.as_slice()?;
+    let input_low = low.as_slice()?;
+    let input_close = close.as_slice()?;
+    let len = input_high.len();
+
+    let mut output = vec![0.0; len];
+
+    py.allow_threads(|| {
+        ultosc::ultosc(
+            input_high,
+            input_low,
+            input_close,
+            period1,
+            period2,
+            period3,
+            output.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output.into_pyarray(py).into())
+}
+
+/// Computes the latest Ultimate Oscillator (ULTOSC) value incrementally.
+///
+/// Updates the rolling buying pressure and true range sums of the three windows, ordered from
+/// the shortest to the longest period.
+///
+/// Args:
+///     high: Current high price.
+///     low: Current low price.
+///     close: Current close price.
+///     prev_close: Previous close price.
+///     old_values: `(buying pressure, true range)` leaving each window, `(0.0, 0.0)` while a
+///         window is still filling up.
+///     prev_sums: Previous `(buying pressure, true range)` sums of each window.
+///
+/// Returns:
+///     A tuple of (ULTOSC value, buying pressure, true range, new sums).
+///
+/// Examples:
+///     ```python
+///     >>> import kand
+///     >>> value, bp, tr, sums = kand.ultosc_inc(
+///     ...     12.0, 11.0, 12.0, 10.5,
+///     ...     [(1.0, 2.0), (0.0, 0.0), (0.0, 0.0)],
+///     ...     [(1.0, 2.0), (2.0, 4.0), (3.0, 6.0)],
+///     ... )
+///     ```
+#[pyfunction]
+#[pyo3(name = "ultosc_inc", signature = (high, low, close, prev_close, old_values, prev_sums))]
+#[allow(clippy::type_complexity)]
+pub fn ultosc_inc_py(
+    high: TAFloat,
+    low: TAFloat,
+    close: TAFloat,
+    prev_close: TAFloat,
+    old_values: [(TAFloat, TAFloat); 3],
+    prev_sums: [(TAFloat, TAFloat); 3],
+) -> PyResult<(TAFloat, TAFloat, TAFloat, [(TAFloat, TAFloat); 3])> {
+    ultosc::ultosc_inc(high, low, close, prev_close, old_values, prev_sums)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
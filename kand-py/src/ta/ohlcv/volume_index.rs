@@ -0,0 +1,154 @@
+use kand::{KandError, TAFloat, ohlcv::volume_index};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+type VolumeIndexFn = fn(&[TAFloat], &[TAFloat], TAFloat, &mut [TAFloat]) -> Result<(), KandError>;
+
+fn volume_index_py(
+    py: Python,
+    close: &PyReadonlyArray1<TAFloat>,
+    volume: &PyReadonlyArray1<TAFloat>,
+    start: TAFloat,
+    index: VolumeIndexFn,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_close = close.as_slice()?;
+    let input_volume = volume.as_slice()?;
+    let len = input_close.len();
+
+    let mut output = vec![0.0; len];
+
+    py.allow_threads(|| index(input_close, input_volume, start, output.as_mut_slice()))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output.into_pyarray(py).into())
+}
+
+/// Computes the Negative Volume Index (NVI) over NumPy arrays.
+///
+/// The NVI compounds the percentage price change of bars whose volume fell below the
+/// previous bar's and stays flat on every other bar.
+///
+/// Args:
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+///   start: Initial index value. Defaults to 1000.
+///
+/// Returns:
+///   A new 1-D NumPy array containing the NVI values. The array has the same length as the input.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> close = np.array([10.0, 11.0, 12.1])
+///   >>> volume = np.array([100.0, 80.0, 120.0])
+///   >>> result = kand.nvi(close, volume)
+///   >>> print(result)
+///   [1000. 1100. 1100.]
+///   ```
+#[pyfunction]
+#[pyo3(name = "nvi", signature = (close, volume, start=1000.0))]
+pub fn nvi_py(
+    py: Python,
+    close: PyReadonlyArray1<TAFloat>,
+    volume: PyReadonlyArray1<TAFloat>,
+    start: TAFloat,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    volume_index_py(py, &close, &volume, start, volume_index::nvi)
+}
+
+/// Calculates the next NVI value incrementally.
+///
+/// Args:
+///   close: Current close price.
+///   volume: Current volume.
+///   prev_close: Previous close price.
+///   prev_volume: Previous volume.
+///   prev_nvi: Previous NVI value.
+///
+/// Returns:
+///   The new NVI value.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.nvi_inc(11.0, 80.0, 10.0, 100.0, 1000.0)
+///   1100.0
+///   ```
+#[pyfunction]
+#[pyo3(name = "nvi_inc", signature = (close, volume, prev_close, prev_volume, prev_nvi))]
+pub fn nvi_inc_py(
+    close: TAFloat,
+    volume: TAFloat,
+    prev_close: TAFloat,
+    prev_volume: TAFloat,
+    prev_nvi: TAFloat,
+) -> PyResult<TAFloat> {
+    volume_index::nvi_inc(close, volume, prev_close, prev_volume, prev_nvi)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Computes the Positive Volume Index (PVI) over NumPy arrays.
+///
+/// The PVI compounds the percentage price change of bars whose volume rose above the
+/// previous bar's and stays flat on every other bar.
+///
+/// Args:
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+///   start: Initial index value. Defaults to 1000.
+///
+/// Returns:
+///   A new 1-D NumPy array containing the PVI values. The array has the same length as the input.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> close = np.array([10.0, 11.0, 12.1])
+///   >>> volume = np.array([100.0, 120.0, 80.0])
+///   >>> result = kand.pvi(close, volume)
+///   >>> print(result)
+///   [1000. 1100. 1100.]
+///   ```
+#[pyfunction]
+#[pyo3(name = "pvi", signature = (close, volume, start=1000.0))]
+pub fn pvi_py(
+    py: Python,
+    close: PyReadonlyArray1<TAFloat>,
+    volume: PyReadonlyArray1<TAFloat>,
+    start: TAFloat,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    volume_index_py(py, &close, &volume, start, volume_index::pvi)
+}
+
+/// Calculates the next PVI value incrementally.
+///
+/// Args:
+///   close: Current close price.
+///   volume: Current volume.
+///   prev_close: Previous close price.
+///   prev_volume: Previous volume.
+///   prev_pvi: Previous PVI value.
+///
+/// Returns:
+///   The new PVI value.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.pvi_inc(11.0, 120.0, 10.0, 100.0, 1000.0)
+///   1100.0
+///   ```
+#[pyfunction]
+#[pyo3(name = "pvi_inc", signature = (close, volume, prev_close, prev_volume, prev_pvi))]
+pub fn pvi_inc_py(
+    close: TAFloat,
+    volume: TAFloat,
+    prev_close: TAFloat,
+    prev_volume: TAFloat,
+    prev_pvi: TAFloat,
+) -> PyResult<TAFloat> {
+    volume_index::pvi_inc(close, volume, prev_close, prev_volume, prev_pvi)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,324 @@
+use kand::{TAFloat, ohlcv::vwap_bands};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculates VWAP with standard deviation bands, restarting wherever `reset` is true.
+///
+/// Args:
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+///   reset: Session start flags as a 1-D NumPy array of type `bool`.
+///   dev: Number of standard deviations for the bands. Defaults to 2.0.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - VWAP values
+///   - Upper band values
+///   - Lower band values
+///   - Cumulative volume-weighted squared deviations
+///   - Cumulative volumes
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([12.0, 15.0, 14.0, 13.0])
+///   >>> low = np.array([8.0, 11.0, 10.0, 9.0])
+///   >>> close = np.array([10.0, 13.0, 12.0, 11.0])
+///   >>> volume = np.array([100.0, 100.0, 100.0, 300.0])
+///   >>> reset = np.array([False, False, True, False])
+///   >>> vwap, upper, lower, cum_sq_dev, cum_vol = kand.vwap_bands(high, low, close, volume, reset, 1.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "vwap_bands", signature = (high, low, close, volume, reset, dev=2.0))]
+#[allow(clippy::type_complexity)]
+pub fn vwap_bands_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    volume: PyReadonlyArray1<TAFloat>,
+    reset: PyReadonlyArray1<bool>,
+    dev: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let high_slice = high.as_slice()?;
+    let low_slice = low.as_slice()?;
+    let close_slice = close.as_slice()?;
+    let volume_slice = volume.as_slice()?;
+    let reset_slice = reset.as_slice()?;
+    let len = high_slice.len();
+
+    let mut output_vwap = vec![0.0; len];
+    let mut output_upper = vec![0.0; len];
+    let mut output_lower = vec![0.0; len];
+    let mut output_cum_sq_dev = vec![0.0; len];
+    let mut output_cum_vol = vec![0.0; len];
+
+    py.allow_threads(|| {
+        vwap_bands::vwap_bands(
+            high_slice,
+            low_slice,
+            close_slice,
+            volume_slice,
+            reset_slice,
+            dev,
+            output_vwap.as_mut_slice(),
+            output_upper.as_mut_slice(),
+            output_lower.as_mut_slice(),
+            output_cum_sq_dev.as_mut_slice(),
+            output_cum_vol.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_vwap.into_pyarray(py).into(),
+        output_upper.into_pyarray(py).into(),
+        output_lower.into_pyarray(py).into(),
+        output_cum_sq_dev.into_pyarray(py).into(),
+        output_cum_vol.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculates session VWAP with standard deviation bands, restarting at each session start.
+///
+/// Sessions are intervals of length `session` aligned to the epoch shifted by `offset`, e.g.
+/// a daily session opening at 13:30 UTC on millisecond timestamps uses
+/// `session=86_400_000, offset=48_600_000`.
+///
+/// Args:
+///   timestamp: Bar start times as a 1-D NumPy array of type `int64`.
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+///   session: Session length in timestamp units (must be > 0).
+///   offset: Shift of the session grid from the epoch. Defaults to 0.
+///   dev: Number of standard deviations for the bands. Defaults to 2.0.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - VWAP values
+///   - Upper band values
+///   - Lower band values
+///   - Cumulative volume-weighted squared deviations
+///   - Cumulative volumes
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> ts = np.arange(48) * 3600
+///   >>> close = 100.0 + np.arange(48, dtype=float)
+///   >>> volume = np.ones(48)
+///   >>> vwap, upper, lower, _, _ = kand.session_vwap(ts, close, close, close, volume, 86400)
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "session_vwap",
+    signature = (timestamp, high, low, close, volume, session, offset=0, dev=2.0)
+)]
+#[allow(clippy::type_complexity)]
+pub fn session_vwap_py(
+    py: Python,
+    timestamp: PyReadonlyArray1<i64>,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    volume: PyReadonlyArray1<TAFloat>,
+    session: i64,
+    offset: i64,
+    dev: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let timestamp_slice = timestamp.as_slice()?;
+    let high_slice = high.as_slice()?;
+    let low_slice = low.as_slice()?;
+    let close_slice = close.as_slice()?;
+    let volume_slice = volume.as_slice()?;
+    let len = high_slice.len();
+
+    let mut output_vwap = vec![0.0; len];
+    let mut output_upper = vec![0.0; len];
+    let mut output_lower = vec![0.0; len];
+    let mut output_cum_sq_dev = vec![0.0; len];
+    let mut output_cum_vol = vec![0.0; len];
+
+    py.allow_threads(|| {
+        vwap_bands::session_vwap(
+            timestamp_slice,
+            high_slice,
+            low_slice,
+            close_slice,
+            volume_slice,
+            session,
+            offset,
+            dev,
+            output_vwap.as_mut_slice(),
+            output_upper.as_mut_slice(),
+            output_lower.as_mut_slice(),
+            output_cum_sq_dev.as_mut_slice(),
+            output_cum_vol.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_vwap.into_pyarray(py).into(),
+        output_upper.into_pyarray(py).into(),
+        output_lower.into_pyarray(py).into(),
+        output_cum_sq_dev.into_pyarray(py).into(),
+        output_cum_vol.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculates anchored VWAP with standard deviation bands, starting from bar `anchor`.
+///
+/// Bars before the anchor are NaN in every output.
+///
+/// Args:
+///   high: High prices as a 1-D NumPy array of type `TAFloat`.
+///   low: Low prices as a 1-D NumPy array of type `TAFloat`.
+///   close: Close prices as a 1-D NumPy array of type `TAFloat`.
+///   volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+///   anchor: Index of the bar where accumulation starts.
+///   dev: Number of standard deviations for the bands. Defaults to 2.0.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - VWAP values
+///   - Upper band values
+///   - Lower band values
+///   - Cumulative volume-weighted squared deviations
+///   - Cumulative volumes
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> high = np.array([12.0, 15.0, 14.0, 13.0])
+///   >>> low = np.array([8.0, 11.0, 10.0, 9.0])
+///   >>> close = np.array([10.0, 13.0, 12.0, 11.0])
+///   >>> volume = np.array([100.0, 100.0, 100.0, 300.0])
+///   >>> vwap, upper, lower, _, _ = kand.anchored_vwap(high, low, close, volume, 1)
+///   ```
+#[pyfunction]
+#[pyo3(name = "anchored_vwap", signature = (high, low, close, volume, anchor, dev=2.0))]
+#[allow(clippy::type_complexity)]
+pub fn anchored_vwap_py(
+    py: Python,
+    high: PyReadonlyArray1<TAFloat>,
+    low: PyReadonlyArray1<TAFloat>,
+    close: PyReadonlyArray1<TAFloat>,
+    volume: PyReadonlyArray1<TAFloat>,
+    anchor: usize,
+    dev: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let high_slice = high.as_slice()?;
+    let low_slice = low.as_slice()?;
+    let close_slice = close.as_slice()?;
+    let volume_slice = volume.as_slice()?;
+    let len = high_slice.len();
+
+    let mut output_vwap = vec![0.0; len];
+    let mut output_upper = vec![0.0; len];
+    let mut output_lower = vec![0.0; len];
+    let mut output_cum_sq_dev = vec![0.0; len];
+    let mut output_cum_vol = vec![0.0; len];
+
+    py.allow_threads(|| {
+        vwap_bands::anchored_vwap(
+            high_slice,
+            low_slice,
+            close_slice,
+            volume_slice,
+            anchor,
+            dev,
+            output_vwap.as_mut_slice(),
+            output_upper.as_mut_slice(),
+            output_lower.as_mut_slice(),
+            output_cum_sq_dev.as_mut_slice(),
+            output_cum_vol.as_mut_slice(),
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_vwap.into_pyarray(py).into(),
+        output_upper.into_pyarray(py).into(),
+        output_lower.into_pyarray(py).into(),
+        output_cum_sq_dev.into_pyarray(py).into(),
+        output_cum_vol.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculates the next VWAP and band values from the previous session state.
+///
+/// Pass zero for `prev_vwap`, `prev_cum_sq_dev` and `prev_cum_vol` on the first bar of a
+/// session or at the anchor.
+///
+/// Args:
+///   high: Latest high price value as `TAFloat`.
+///   low: Latest low price value as `TAFloat`.
+///   close: Latest close price value as `TAFloat`.
+///   volume: Latest volume value as `TAFloat`.
+///   prev_vwap: Previous VWAP value as `TAFloat`.
+///   prev_cum_sq_dev: Previous cumulative volume-weighted squared deviation as `TAFloat`.
+///   prev_cum_vol: Previous cumulative volume as `TAFloat`.
+///   dev: Number of standard deviations for the bands. Defaults to 2.0.
+///
+/// Returns:
+///   A tuple containing (VWAP, upper band, lower band, cumulative squared deviation,
+///   cumulative volume).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> vwap, upper, lower, cum_sq_dev, cum_vol = kand.vwap_bands_inc(15.0, 11.0, 13.0, 100.0, 10.0, 0.0, 100.0, 1.0)
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "vwap_bands_inc",
+    signature = (high, low, close, volume, prev_vwap, prev_cum_sq_dev, prev_cum_vol, dev=2.0)
+)]
+pub fn vwap_bands_inc_py(
+    high: TAFloat,
+    low: TAFloat,
+    close: TAFloat,
+    volume: TAFloat,
+    prev_vwap: TAFloat,
+    prev_cum_sq_dev: TAFloat,
+    prev_cum_vol: TAFloat,
+    dev: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    vwap_bands::vwap_bands_inc(
+        high,
+        low,
+        close,
+        volume,
+        prev_vwap,
+        prev_cum_sq_dev,
+        prev_cum_vol,
+        dev,
+    )
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
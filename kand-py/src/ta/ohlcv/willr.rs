@@ -1,7 +1,9 @@
-use kand::{TAFloat, ohlcv::willr};
+use kand::{Indicator, TAFloat, ohlcv::willr};
 use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
 use pyo3::prelude::*;
 
+use crate::helper::warn_deprecated;
+
 /// Calculates Williams %R (Williams Percent Range) for a series of prices.
 ///
 /// Williams %R is a momentum indicator that measures overbought and oversold levels by comparing
@@ -77,6 +79,11 @@ pub fn willr_py(
 /// This function provides an optimized way to calculate the latest Williams %R value
 /// by using previously calculated highest high and lowest low values.
 ///
+/// Deprecated:
+///     The highest high and lowest low cannot be updated exactly from their previous values
+///     once the extreme leaves the window. Use the `Willr` streaming object, which keeps the
+///     rolling windows between calls.
+///
 /// Args:
 ///     prev_highest_high: Previous period's highest high value.
 ///     prev_lowest_low: Previous period's lowest low value.
@@ -103,6 +110,7 @@ pub fn willr_py(
     signature = (prev_highest_high, prev_lowest_low, prev_high, prev_low, close, high, low)
 )]
 pub fn willr_inc_py(
+    py: Python,
     prev_highest_high: TAFloat,
     prev_lowest_low: TAFloat,
     prev_high: TAFloat,
@@ -111,7 +119,12 @@ pub fn willr_inc_py(
     high: TAFloat,
     low: TAFloat,
 ) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
-    willr::willr_inc(
+    warn_deprecated(
+        py,
+        c"kand.willr_inc is inexact once an extreme leaves the window; use kand.Willr instead",
+    )?;
+    #[allow(deprecated)]
+    willr::willr_inc_legacy(
         prev_highest_high,
         prev_lowest_low,
         prev_high,
@@ -122,3 +135,59 @@ pub fn willr_inc_py(
     )
     .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
 }
+
+/// Streaming Williams %R.
+///
+/// Keeps monotonic windows of the highest high and lowest low between calls, so every update
+/// is O(1) amortized and stays exact when an extreme leaves the window.
+///
+/// Args:
+///     period: Lookback period for calculations. Must be >= 2.
+///
+/// Examples:
+///     ```python
+///     >>> import kand
+///     >>> willr = kand.Willr(2)
+///     >>> willr.update(15.0, 10.0, 12.0)
+///     >>> willr.update(14.0, 11.0, 13.0)
+///     -40.0
+///     ```
+#[pyclass(name = "Willr")]
+pub struct WillrPy {
+    inner: willr::Willr,
+}
+
+#[pymethods]
+impl WillrPy {
+    #[new]
+    #[pyo3(signature = (period))]
+    fn new(period: usize) -> PyResult<Self> {
+        let inner = willr::Willr::new(period)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    /// Adds the next bar and returns its Williams %R, or None while the window is filling.
+    fn update(&mut self, high: TAFloat, low: TAFloat, close: TAFloat) -> PyResult<Option<TAFloat>> {
+        self.inner
+            .update((high, low, close))
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    /// Clears the rolling windows.
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    /// Whether enough bars were consumed for `update` to return values.
+    #[getter]
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    /// Number of bars consumed before the first value.
+    #[getter]
+    fn lookback(&self) -> usize {
+        self.inner.lookback()
+    }
+}
@@ -0,0 +1,176 @@
+use kand::{TAFloat, stats::alpha};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate the rolling Jensen's Alpha (ALPHA) of an asset against a benchmark
+///
+/// Alpha is the annualized part of the asset's return that its beta to the benchmark does not
+/// explain, after subtracting the risk-free rate.
+///
+/// Args:
+///   asset: Per-period asset returns as a 1-D NumPy array of type `TAFloat`.
+///   benchmark: Per-period benchmark returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window (must be >= 2).
+///   annualization: Periods per year (must be > 0). Defaults to 252.
+///   risk_free: Annual risk-free rate. Defaults to 0.
+///
+/// Returns:
+///   A tuple of six 1-D NumPy arrays containing:
+///   - ALPHA values
+///   - BETA values
+///   - Running sum of asset returns
+///   - Running sum of benchmark returns
+///   - Running sum of squared benchmark returns
+///   - Running sum of asset * benchmark returns
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> asset = np.array([0.021, -0.039, 0.011, 0.031])
+///   >>> benchmark = np.array([0.01, -0.02, 0.005, 0.015])
+///   >>> alpha, beta, sum_a, sum_b, sum_bb, sum_ab = kand.alpha(asset, benchmark, 3)
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "alpha",
+    signature = (asset, benchmark, period, annualization=252.0, risk_free=0.0)
+)]
+#[allow(clippy::type_complexity)]
+pub fn alpha_py(
+    py: Python,
+    asset: PyReadonlyArray1<TAFloat>,
+    benchmark: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    annualization: TAFloat,
+    risk_free: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_asset = asset.as_slice()?;
+    let input_benchmark = benchmark.as_slice()?;
+    let len = input_asset.len();
+
+    let mut output_alpha = vec![0.0; len];
+    let mut output_beta = vec![0.0; len];
+    let mut output_sum_a = vec![0.0; len];
+    let mut output_sum_b = vec![0.0; len];
+    let mut output_sum_bb = vec![0.0; len];
+    let mut output_sum_ab = vec![0.0; len];
+
+    py.allow_threads(|| {
+        alpha::alpha(
+            input_asset,
+            input_benchmark,
+            period,
+            annualization,
+            risk_free,
+            &mut output_alpha,
+            &mut output_beta,
+            &mut output_sum_a,
+            &mut output_sum_b,
+            &mut output_sum_bb,
+            &mut output_sum_ab,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_alpha.into_pyarray(py).into(),
+        output_beta.into_pyarray(py).into(),
+        output_sum_a.into_pyarray(py).into(),
+        output_sum_b.into_pyarray(py).into(),
+        output_sum_bb.into_pyarray(py).into(),
+        output_sum_ab.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest Jensen's Alpha value incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   asset: Current asset return
+///   benchmark: Current benchmark return
+///   old_asset: Asset return leaving the window
+///   old_benchmark: Benchmark return leaving the window
+///   prev_sum_a: Previous sum of asset returns
+///   prev_sum_b: Previous sum of benchmark returns
+///   prev_sum_bb: Previous sum of squared benchmark returns
+///   prev_sum_ab: Previous sum of asset * benchmark returns
+///   period: Number of returns in the rolling window (must be >= 2)
+///   annualization: Periods per year (must be > 0). Defaults to 252.
+///   risk_free: Annual risk-free rate. Defaults to 0.
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest ALPHA value
+///   - Latest BETA value
+///   - New sum of asset returns
+///   - New sum of benchmark returns
+///   - New sum of squared benchmark returns
+///   - New sum of asset * benchmark returns
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> alpha, beta, sum_a, sum_b, sum_bb, sum_ab = kand.alpha_inc(
+///   ...     0.031, 0.015,  # new asset, benchmark returns
+///   ...     0.021, 0.01,  # returns leaving the window
+///   ...     -0.007, -0.005, 0.000525, 0.001045,  # previous sums
+///   ...     3  # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "alpha_inc",
+    signature = (
+        asset,
+        benchmark,
+        old_asset,
+        old_benchmark,
+        prev_sum_a,
+        prev_sum_b,
+        prev_sum_bb,
+        prev_sum_ab,
+        period,
+        annualization=252.0,
+        risk_free=0.0
+    )
+)]
+pub fn alpha_inc_py(
+    py: Python,
+    asset: TAFloat,
+    benchmark: TAFloat,
+    old_asset: TAFloat,
+    old_benchmark: TAFloat,
+    prev_sum_a: TAFloat,
+    prev_sum_b: TAFloat,
+    prev_sum_bb: TAFloat,
+    prev_sum_ab: TAFloat,
+    period: usize,
+    annualization: TAFloat,
+    risk_free: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        alpha::alpha_inc(
+            asset,
+            benchmark,
+            old_asset,
+            old_benchmark,
+            prev_sum_a,
+            prev_sum_b,
+            prev_sum_bb,
+            prev_sum_ab,
+            period,
+            annualization,
+            risk_free,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
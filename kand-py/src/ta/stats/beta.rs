@@ -0,0 +1,153 @@
+use kand::{TAFloat, stats::beta};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate the rolling Beta (BETA) of an asset against a benchmark
+///
+/// Beta is the covariance of asset and benchmark returns divided by the variance of the
+/// benchmark returns, i.e. the hedge ratio against the benchmark.
+///
+/// Args:
+///   asset: Per-period asset returns as a 1-D NumPy array of type `TAFloat`.
+///   benchmark: Per-period benchmark returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window (must be >= 2).
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - BETA values
+///   - Running sum of asset returns
+///   - Running sum of benchmark returns
+///   - Running sum of squared benchmark returns
+///   - Running sum of asset * benchmark returns
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> asset = np.array([0.02, -0.04, 0.01, 0.03])
+///   >>> benchmark = np.array([0.01, -0.02, 0.005, 0.015])
+///   >>> beta, sum_a, sum_b, sum_bb, sum_ab = kand.beta(asset, benchmark, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "beta", signature = (asset, benchmark, period))]
+#[allow(clippy::type_complexity)]
+pub fn beta_py(
+    py: Python,
+    asset: PyReadonlyArray1<TAFloat>,
+    benchmark: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_asset = asset.as_slice()?;
+    let input_benchmark = benchmark.as_slice()?;
+    let len = input_asset.len();
+
+    let mut output_beta = vec![0.0; len];
+    let mut output_sum_a = vec![0.0; len];
+    let mut output_sum_b = vec![0.0; len];
+    let mut output_sum_bb = vec![0.0; len];
+    let mut output_sum_ab = vec![0.0; len];
+
+    py.allow_threads(|| {
+        beta::beta(
+            input_asset,
+            input_benchmark,
+            period,
+            &mut output_beta,
+            &mut output_sum_a,
+            &mut output_sum_b,
+            &mut output_sum_bb,
+            &mut output_sum_ab,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_beta.into_pyarray(py).into(),
+        output_sum_a.into_pyarray(py).into(),
+        output_sum_b.into_pyarray(py).into(),
+        output_sum_bb.into_pyarray(py).into(),
+        output_sum_ab.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest Beta value incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   asset: Current asset return
+///   benchmark: Current benchmark return
+///   old_asset: Asset return leaving the window
+///   old_benchmark: Benchmark return leaving the window
+///   prev_sum_a: Previous sum of asset returns
+///   prev_sum_b: Previous sum of benchmark returns
+///   prev_sum_bb: Previous sum of squared benchmark returns
+///   prev_sum_ab: Previous sum of asset * benchmark returns
+///   period: Number of returns in the rolling window (must be >= 2)
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest BETA value
+///   - New sum of asset returns
+///   - New sum of benchmark returns
+///   - New sum of squared benchmark returns
+///   - New sum of asset * benchmark returns
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> beta, sum_a, sum_b, sum_bb, sum_ab = kand.beta_inc(
+///   ...     0.03, 0.015,  # new asset, benchmark returns
+///   ...     0.02, 0.01,  # returns leaving the window
+///   ...     -0.01, -0.005, 0.000525, 0.00105,  # previous sums
+///   ...     3  # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "beta_inc",
+    signature = (
+        asset,
+        benchmark,
+        old_asset,
+        old_benchmark,
+        prev_sum_a,
+        prev_sum_b,
+        prev_sum_bb,
+        prev_sum_ab,
+        period
+    )
+)]
+pub fn beta_inc_py(
+    py: Python,
+    asset: TAFloat,
+    benchmark: TAFloat,
+    old_asset: TAFloat,
+    old_benchmark: TAFloat,
+    prev_sum_a: TAFloat,
+    prev_sum_b: TAFloat,
+    prev_sum_bb: TAFloat,
+    prev_sum_ab: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        beta::beta_inc(
+            asset,
+            benchmark,
+            old_asset,
+            old_benchmark,
+            prev_sum_a,
+            prev_sum_b,
+            prev_sum_bb,
+            prev_sum_ab,
+            period,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
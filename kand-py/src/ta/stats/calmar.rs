@@ -0,0 +1,251 @@
+use kand::{KandError, TAFloat, stats::calmar};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate the rolling Calmar Ratio for a NumPy array
+///
+/// The annualized return over the last `period` prices, minus the risk-free rate, is divided
+/// by the absolute maximum drawdown within the same prices. The ratio is 0 when there is no
+/// drawdown.
+///
+/// Args:
+///   prices: Input prices or equity values as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of prices in the rolling window (must be >= 2).
+///   annualization: Periods per year (must be > 0). Defaults to 252.
+///   risk_free: Annual risk-free rate. Defaults to 0.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Calmar Ratio values
+///   - Drawdown values
+///   - Maximum drawdown values
+///   Each array has the same length as the input, with the first (period-1) values
+///   containing NaN.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([100.0, 120.0, 90.0, 108.0, 110.0])
+///   >>> calmar, dd, mdd = kand.calmar(prices, 3, 2.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "calmar", signature = (prices, period, annualization=252.0, risk_free=0.0))]
+pub fn calmar_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    annualization: TAFloat,
+    risk_free: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_calmar = vec![0.0; len];
+    let mut output_drawdown = vec![0.0; len];
+    let mut output_max_drawdown = vec![0.0; len];
+
+    py.allow_threads(|| {
+        calmar::calmar(
+            input_prices,
+            period,
+            annualization,
+            risk_free,
+            &mut output_calmar,
+            &mut output_drawdown,
+            &mut output_max_drawdown,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_calmar.into_pyarray(py).into(),
+        output_drawdown.into_pyarray(py).into(),
+        output_max_drawdown.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest rolling Calmar Ratio incrementally
+///
+/// The trailing prices that complete the window are passed in.
+///
+/// Args:
+///   py: Python interpreter token
+///   price: Current period's price
+///   prev_prices: Prices preceding `price` as a 1-D NumPy array. At least `period - 1` values
+///     are required; only the last `period - 1` are used.
+///   period: Number of prices in the rolling window (must be >= 2)
+///   annualization: Periods per year (must be > 0). Defaults to 252.
+///   risk_free: Annual risk-free rate. Defaults to 0.
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Calmar Ratio value
+///   - Latest drawdown value
+///   - Latest maximum drawdown value
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> calmar, dd, mdd = kand.calmar_inc(110.0, np.array([100.0, 120.0, 90.0, 108.0]), 3, 2.0)
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "calmar_inc",
+    signature = (price, prev_prices, period, annualization=252.0, risk_free=0.0)
+)]
+pub fn calmar_inc_py(
+    py: Python,
+    price: TAFloat,
+    prev_prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    annualization: TAFloat,
+    risk_free: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    let prev_prices = prev_prices.as_slice()?;
+
+    py.allow_threads(|| {
+        let lookback = calmar::lookback(period)?;
+        if prev_prices.len() < lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        let mut window = prev_prices[prev_prices.len() - lookback..].to_vec();
+        window.push(price);
+        calmar::calmar_inc(&window, period, annualization, risk_free)
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Calculate the expanding Calmar Ratio for a NumPy array
+///
+/// Uses the compound annual growth rate since the first price and the maximum drawdown since
+/// the start of the series.
+///
+/// Args:
+///   prices: Input prices or equity values as a 1-D NumPy array of type `TAFloat`.
+///   annualization: Periods per year (must be > 0). Defaults to 252.
+///   risk_free: Annual risk-free rate. Defaults to 0.
+///
+/// Returns:
+///   A tuple of four 1-D NumPy arrays containing:
+///   - Calmar Ratio values, NaN for the first element
+///   - Drawdown values
+///   - Maximum drawdown values
+///   - Running peak values
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([100.0, 80.0, 121.0])
+///   >>> calmar, dd, mdd, peak = kand.calmar_expanding(prices, 1.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "calmar_expanding", signature = (prices, annualization=252.0, risk_free=0.0))]
+#[allow(clippy::type_complexity)]
+pub fn calmar_expanding_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    annualization: TAFloat,
+    risk_free: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_calmar = vec![0.0; len];
+    let mut output_drawdown = vec![0.0; len];
+    let mut output_max_drawdown = vec![0.0; len];
+    let mut output_peak = vec![0.0; len];
+
+    py.allow_threads(|| {
+        calmar::calmar_expanding(
+            input_prices,
+            annualization,
+            risk_free,
+            &mut output_calmar,
+            &mut output_drawdown,
+            &mut output_max_drawdown,
+            &mut output_peak,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_calmar.into_pyarray(py).into(),
+        output_drawdown.into_pyarray(py).into(),
+        output_max_drawdown.into_pyarray(py).into(),
+        output_peak.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest expanding Calmar Ratio incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   price: Current period's price
+///   first_price: First price of the series
+///   periods: Number of bars between the first and the current price
+///   prev_peak: Previous running peak, or the first price of the series
+///   prev_max_drawdown: Previous maximum drawdown, 0 for the first price
+///   annualization: Periods per year (must be > 0). Defaults to 252.
+///   risk_free: Annual risk-free rate. Defaults to 0.
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Calmar Ratio value, NaN for the first price
+///   - Latest drawdown value
+///   - Latest maximum drawdown value
+///   - New running peak
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> calmar, dd, mdd, peak = kand.calmar_expanding_inc(121.0, 100.0, 2, 100.0, -0.2, 1.0)
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "calmar_expanding_inc",
+    signature = (
+        price,
+        first_price,
+        periods,
+        prev_peak,
+        prev_max_drawdown,
+        annualization=252.0,
+        risk_free=0.0
+    )
+)]
+pub fn calmar_expanding_inc_py(
+    py: Python,
+    price: TAFloat,
+    first_price: TAFloat,
+    periods: usize,
+    prev_peak: TAFloat,
+    prev_max_drawdown: TAFloat,
+    annualization: TAFloat,
+    risk_free: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        calmar::calmar_expanding_inc(
+            price,
+            first_price,
+            periods,
+            prev_peak,
+            prev_max_drawdown,
+            annualization,
+            risk_free,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,163 @@
+use kand::{TAFloat, stats::correl};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate the rolling Pearson Correlation Coefficient (CORREL) of two NumPy arrays
+///
+/// CORREL measures how linearly related two series are over a rolling window, ranging from -1
+/// to 1. The result is 0 when either series is constant over the window.
+///
+/// Args:
+///   x: First input series as a 1-D NumPy array of type `TAFloat`.
+///   y: Second input series as a 1-D NumPy array of type `TAFloat`.
+///   period: Period for CORREL calculation (must be >= 2).
+///
+/// Returns:
+///   A tuple of six 1-D NumPy arrays containing:
+///   - CORREL values
+///   - Running sum of x
+///   - Running sum of y
+///   - Running sum of x^2
+///   - Running sum of y^2
+///   - Running sum of x*y
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> x = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+///   >>> y = np.array([2.0, 4.0, 5.0, 8.0, 9.0])
+///   >>> correl, sum_x, sum_y, sum_xx, sum_yy, sum_xy = kand.correl(x, y, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "correl", signature = (x, y, period))]
+#[allow(clippy::type_complexity)]
+pub fn correl_py(
+    py: Python,
+    x: PyReadonlyArray1<TAFloat>,
+    y: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_x = x.as_slice()?;
+    let input_y = y.as_slice()?;
+    let len = input_x.len();
+
+    let mut output_correl = vec![0.0; len];
+    let mut output_sum_x = vec![0.0; len];
+    let mut output_sum_y = vec![0.0; len];
+    let mut output_sum_xx = vec![0.0; len];
+    let mut output_sum_yy = vec![0.0; len];
+    let mut output_sum_xy = vec![0.0; len];
+
+    py.allow_threads(|| {
+        correl::correl(
+            input_x,
+            input_y,
+            period,
+            &mut output_correl,
+            &mut output_sum_x,
+            &mut output_sum_y,
+            &mut output_sum_xx,
+            &mut output_sum_yy,
+            &mut output_sum_xy,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_correl.into_pyarray(py).into(),
+        output_sum_x.into_pyarray(py).into(),
+        output_sum_y.into_pyarray(py).into(),
+        output_sum_xx.into_pyarray(py).into(),
+        output_sum_yy.into_pyarray(py).into(),
+        output_sum_xy.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest Pearson Correlation value incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   x: Current value of the first series
+///   y: Current value of the second series
+///   old_x: Value of the first series leaving the window
+///   old_y: Value of the second series leaving the window
+///   prev_sum_x: Previous sum of x
+///   prev_sum_y: Previous sum of y
+///   prev_sum_xx: Previous sum of x^2
+///   prev_sum_yy: Previous sum of y^2
+///   prev_sum_xy: Previous sum of x*y
+///   period: Period for CORREL calculation (must be >= 2)
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest CORREL value
+///   - New sum of x
+///   - New sum of y
+///   - New sum of x^2
+///   - New sum of y^2
+///   - New sum of x*y
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> correl, sum_x, sum_y, sum_xx, sum_yy, sum_xy = kand.correl_inc(
+///   ...     4.0, 8.0,  # new x, y
+///   ...     1.0, 2.0,  # x, y leaving the window
+///   ...     6.0, 12.0, 14.0, 56.0, 28.0,  # previous sums
+///   ...     3  # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "correl_inc",
+    signature = (
+        x,
+        y,
+        old_x,
+        old_y,
+        prev_sum_x,
+        prev_sum_y,
+        prev_sum_xx,
+        prev_sum_yy,
+        prev_sum_xy,
+        period
+    )
+)]
+pub fn correl_inc_py(
+    py: Python,
+    x: TAFloat,
+    y: TAFloat,
+    old_x: TAFloat,
+    old_y: TAFloat,
+    prev_sum_x: TAFloat,
+    prev_sum_y: TAFloat,
+    prev_sum_xx: TAFloat,
+    prev_sum_yy: TAFloat,
+    prev_sum_xy: TAFloat,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        correl::correl_inc(
+            x,
+            y,
+            old_x,
+            old_y,
+            prev_sum_x,
+            prev_sum_y,
+            prev_sum_xx,
+            prev_sum_yy,
+            prev_sum_xy,
+            period,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
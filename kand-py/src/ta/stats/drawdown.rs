@@ -0,0 +1,185 @@
+use kand::{KandError, TAFloat, stats::drawdown};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate the rolling Drawdown and Maximum Drawdown for a NumPy array
+///
+/// The drawdown is the decline of the price from its highest value of the last `period` bars,
+/// and the maximum drawdown is the deepest decline within those same `period` bars.
+///
+/// Args:
+///   prices: Input prices or equity values as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of prices in the rolling window (must be >= 2).
+///
+/// Returns:
+///   A tuple of two 1-D NumPy arrays containing:
+///   - Drawdown values, NaN for the first (period-1) elements
+///   - Maximum drawdown values, NaN for the first (period-1) elements
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([100.0, 120.0, 90.0, 108.0, 110.0])
+///   >>> dd, mdd = kand.drawdown(prices, 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "drawdown", signature = (prices, period))]
+pub fn drawdown_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(Py<PyArray1<TAFloat>>, Py<PyArray1<TAFloat>>)> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_drawdown = vec![0.0; len];
+    let mut output_max_drawdown = vec![0.0; len];
+
+    py.allow_threads(|| {
+        drawdown::drawdown(
+            input_prices,
+            period,
+            &mut output_drawdown,
+            &mut output_max_drawdown,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_drawdown.into_pyarray(py).into(),
+        output_max_drawdown.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest rolling Drawdown and Maximum Drawdown incrementally
+///
+/// The maximum drawdown cannot be rebuilt from the previous outputs, so the trailing prices
+/// that complete the window are passed in.
+///
+/// Args:
+///   py: Python interpreter token
+///   price: Current period's price
+///   prev_prices: Prices preceding `price` as a 1-D NumPy array. At least `period - 1` values
+///     are required; only the last `period - 1` are used.
+///   period: Number of prices in the rolling window (must be >= 2)
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest drawdown value
+///   - Latest maximum drawdown value
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> dd, mdd = kand.drawdown_inc(110.0, np.array([100.0, 120.0, 90.0, 108.0]), 3)
+///   ```
+#[pyfunction]
+#[pyo3(name = "drawdown_inc", signature = (price, prev_prices, period))]
+pub fn drawdown_inc_py(
+    py: Python,
+    price: TAFloat,
+    prev_prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(TAFloat, TAFloat)> {
+    let prev_prices = prev_prices.as_slice()?;
+
+    py.allow_threads(|| {
+        let lookback = drawdown::lookback(period)?;
+        if prev_prices.len() < lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        let mut window = prev_prices[prev_prices.len() - lookback..].to_vec();
+        window.push(price);
+        drawdown::drawdown_inc(&window, period)
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Calculate the expanding Drawdown and Maximum Drawdown for a NumPy array
+///
+/// Args:
+///   prices: Input prices or equity values as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Drawdown values from the running peak
+///   - Maximum drawdown values since the start
+///   - Running peak values
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([100.0, 120.0, 90.0, 130.0])
+///   >>> dd, mdd, peak = kand.drawdown_expanding(prices)
+///   ```
+#[pyfunction]
+#[pyo3(name = "drawdown_expanding", signature = (prices))]
+pub fn drawdown_expanding_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_drawdown = vec![0.0; len];
+    let mut output_max_drawdown = vec![0.0; len];
+    let mut output_peak = vec![0.0; len];
+
+    py.allow_threads(|| {
+        drawdown::drawdown_expanding(
+            input_prices,
+            &mut output_drawdown,
+            &mut output_max_drawdown,
+            &mut output_peak,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_drawdown.into_pyarray(py).into(),
+        output_max_drawdown.into_pyarray(py).into(),
+        output_peak.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest expanding Drawdown and Maximum Drawdown incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   price: Current period's price
+///   prev_peak: Previous running peak, or the first price of the series
+///   prev_max_drawdown: Previous maximum drawdown, 0 for the first price
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest drawdown value
+///   - Latest maximum drawdown value
+///   - New running peak
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> dd, mdd, peak = kand.drawdown_expanding_inc(90.0, 120.0, -0.1)
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "drawdown_expanding_inc",
+    signature = (price, prev_peak, prev_max_drawdown)
+)]
+pub fn drawdown_expanding_inc_py(
+    py: Python,
+    price: TAFloat,
+    prev_peak: TAFloat,
+    prev_max_drawdown: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| drawdown::drawdown_expanding_inc(price, prev_peak, prev_max_drawdown))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,99 @@
+use kand::{TAFloat, TAInt, stats::fv};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_payment_timing;
+
+/// Calculate the Future Value (FV) element-wise over NumPy arrays
+///
+/// FV is the value at the end of `nper` periods of a present amount plus a stream of constant
+/// payments. Cash paid out is negative and cash received is positive.
+///
+/// Args:
+///   rate: Interest rate per period as a 1-D NumPy array of type `TAFloat`.
+///   nper: Number of compounding periods as a 1-D NumPy array of type `TAFloat`.
+///   pmt: Payment made each period as a 1-D NumPy array of type `TAFloat`.
+///   pv: Present value as a 1-D NumPy array of type `TAFloat`.
+///   when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+///
+/// Returns:
+///   A 1-D NumPy array of future values with the same length as the inputs.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> rate = np.array([0.05 / 12])
+///   >>> nper = np.array([120.0])
+///   >>> pmt = np.array([-100.0])
+///   >>> pv = np.array([-100.0])
+///   >>> kand.fv(rate, nper, pmt, pv)
+///   array([15692.92889434])
+///   ```
+#[pyfunction]
+#[pyo3(name = "fv", signature = (rate, nper, pmt, pv, when=0))]
+pub fn fv_py(
+    py: Python,
+    rate: PyReadonlyArray1<TAFloat>,
+    nper: PyReadonlyArray1<TAFloat>,
+    pmt: PyReadonlyArray1<TAFloat>,
+    pv: PyReadonlyArray1<TAFloat>,
+    when: TAInt,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_rate = rate.as_slice()?;
+    let input_nper = nper.as_slice()?;
+    let input_pmt = pmt.as_slice()?;
+    let input_pv = pv.as_slice()?;
+    let when = parse_payment_timing(when)?;
+    let len = input_rate.len();
+    let mut output_fv = vec![0.0; len];
+
+    py.allow_threads(|| {
+        fv::fv(
+            input_rate,
+            input_nper,
+            input_pmt,
+            input_pv,
+            when,
+            &mut output_fv,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_fv.into_pyarray(py).into())
+}
+
+/// Calculate a single Future Value (FV)
+///
+/// Args:
+///   py: Python interpreter token
+///   rate: Interest rate per period
+///   nper: Number of compounding periods
+///   pmt: Payment made each period
+///   pv: Present value
+///   when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+///
+/// Returns:
+///   The future value.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.fv_inc(0.0, 10.0, -100.0, -1000.0)
+///   2000.0
+///   ```
+#[pyfunction]
+#[pyo3(name = "fv_inc", signature = (rate, nper, pmt, pv, when=0))]
+pub fn fv_inc_py(
+    py: Python,
+    rate: TAFloat,
+    nper: TAFloat,
+    pmt: TAFloat,
+    pv: TAFloat,
+    when: TAInt,
+) -> PyResult<TAFloat> {
+    let when = parse_payment_timing(when)?;
+
+    py.allow_threads(|| fv::fv_inc(rate, nper, pmt, pv, when))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,44 @@
+use kand::{TAFloat, stats::irr};
+use numpy::PyReadonlyArray1;
+use pyo3::prelude::*;
+
+/// Calculate the Internal Rate of Return (IRR) of a series of periodic cash flows
+///
+/// IRR is the per-period discount rate at which the net present value of the cash flows is zero.
+/// The first cash flow is at period 0, usually the negative initial investment. The rate is
+/// solved with Newton's method, falling back to bracketing and bisection when Newton fails.
+///
+/// Args:
+///   cashflows: Cash flows as a 1-D NumPy array of type `TAFloat`, one per period.
+///   guess: Starting rate for Newton's method. Defaults to 0.1.
+///   tol: Convergence tolerance (must be > 0). Defaults to 1e-12.
+///   max_iter: Maximum number of Newton iterations (must be > 0). Defaults to 100.
+///
+/// Returns:
+///   The internal rate of return per period.
+///
+/// Raises:
+///   ValueError: If the cash flows do not change sign or the solver does not converge.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> cashflows = np.array([-100.0, 39.0, 59.0, 55.0, 20.0])
+///   >>> round(kand.irr(cashflows), 4)
+///   0.2809
+///   ```
+#[pyfunction]
+#[pyo3(name = "irr", signature = (cashflows, guess=0.1, tol=1e-12, max_iter=100))]
+pub fn irr_py(
+    py: Python,
+    cashflows: PyReadonlyArray1<TAFloat>,
+    guess: TAFloat,
+    tol: TAFloat,
+    max_iter: usize,
+) -> PyResult<TAFloat> {
+    let input_cashflows = cashflows.as_slice()?;
+
+    py.allow_threads(|| irr::irr(input_cashflows, guess, tol, max_iter))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
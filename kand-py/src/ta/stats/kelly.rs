@@ -0,0 +1,287 @@
+use kand::{TAFloat, stats::kelly};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate the rolling Kelly Criterion (KELLY) for a NumPy array of per-trade PnL
+///
+/// The Kelly Criterion is the fraction of capital to risk per trade that maximizes long-run
+/// growth given the win rate and payoff ratio of the last `period` trades:
+/// (p - q * avg_loss / avg_win) * fraction. It is 0 when there are no winning trades.
+///
+/// Args:
+///   pnl: Per-trade profit and loss as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of trades in the rolling window (must be >= 1).
+///   fraction: Multiplier applied to the full Kelly fraction (must be > 0), e.g. 0.5 for half
+///     Kelly. Defaults to 1.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - Kelly fraction
+///   - Running count of winning trades
+///   - Running count of losing trades
+///   - Running sum of winning trades
+///   - Running absolute sum of losing trades
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> pnl = np.array([120.0, -50.0, 0.0, 80.0])
+///   >>> kelly, wins, losses, gross_profit, gross_loss = kand.kelly(pnl, 4)
+///   >>> kelly[-1]
+///   0.375
+///   ```
+#[pyfunction]
+#[pyo3(name = "kelly", signature = (pnl, period, fraction=1.0))]
+#[allow(clippy::type_complexity)]
+pub fn kelly_py(
+    py: Python,
+    pnl: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    fraction: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_pnl = pnl.as_slice()?;
+    let len = input_pnl.len();
+
+    let mut output_kelly = vec![0.0; len];
+    let mut output_wins = vec![0.0; len];
+    let mut output_losses = vec![0.0; len];
+    let mut output_gross_profit = vec![0.0; len];
+    let mut output_gross_loss = vec![0.0; len];
+
+    py.allow_threads(|| {
+        kelly::kelly(
+            input_pnl,
+            period,
+            fraction,
+            &mut output_kelly,
+            &mut output_wins,
+            &mut output_losses,
+            &mut output_gross_profit,
+            &mut output_gross_loss,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_kelly.into_pyarray(py).into(),
+        output_wins.into_pyarray(py).into(),
+        output_losses.into_pyarray(py).into(),
+        output_gross_profit.into_pyarray(py).into(),
+        output_gross_loss.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest rolling Kelly Criterion incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   pnl: PnL of the newest trade
+///   old_pnl: PnL of the trade leaving the window
+///   prev_wins: Previous count of winning trades
+///   prev_losses: Previous count of losing trades
+///   prev_gross_profit: Previous sum of winning trades
+///   prev_gross_loss: Previous absolute sum of losing trades
+///   period: Number of trades in the rolling window (must be >= 1)
+///   fraction: Multiplier applied to the full Kelly fraction (must be > 0). Defaults to 1.
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Kelly fraction
+///   - New count of winning trades
+///   - New count of losing trades
+///   - New sum of winning trades
+///   - New absolute sum of losing trades
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kelly, wins, losses, gross_profit, gross_loss = kand.kelly_inc(
+///   ...     -40.0, 80.0,  # newest trade, trade leaving the window
+///   ...     2.0, 0.0, 200.0, 0.0,  # previous sums
+///   ...     2  # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "kelly_inc",
+    signature = (
+        pnl,
+        old_pnl,
+        prev_wins,
+        prev_losses,
+        prev_gross_profit,
+        prev_gross_loss,
+        period,
+        fraction=1.0
+    )
+)]
+pub fn kelly_inc_py(
+    py: Python,
+    pnl: TAFloat,
+    old_pnl: TAFloat,
+    prev_wins: TAFloat,
+    prev_losses: TAFloat,
+    prev_gross_profit: TAFloat,
+    prev_gross_loss: TAFloat,
+    period: usize,
+    fraction: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        kelly::kelly_inc(
+            pnl,
+            old_pnl,
+            prev_wins,
+            prev_losses,
+            prev_gross_profit,
+            prev_gross_loss,
+            period,
+            fraction,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Calculate the expanding Kelly Criterion for a NumPy array of per-trade PnL
+///
+/// Uses every trade from the start up to each index, so the last element is the Kelly fraction
+/// of the whole sample.
+///
+/// Args:
+///   pnl: Per-trade profit and loss as a 1-D NumPy array of type `TAFloat`.
+///   fraction: Multiplier applied to the full Kelly fraction (must be > 0). Defaults to 1.
+///
+/// Returns:
+///   A tuple of five 1-D NumPy arrays containing:
+///   - Kelly fraction
+///   - Cumulative count of winning trades
+///   - Cumulative count of losing trades
+///   - Cumulative sum of winning trades
+///   - Cumulative absolute sum of losing trades
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> pnl = np.array([120.0, -50.0, 0.0, 80.0])
+///   >>> kelly, wins, losses, gross_profit, gross_loss = kand.kelly_expanding(pnl, 0.5)
+///   >>> kelly[-1]
+///   0.1875
+///   ```
+#[pyfunction]
+#[pyo3(name = "kelly_expanding", signature = (pnl, fraction=1.0))]
+#[allow(clippy::type_complexity)]
+pub fn kelly_expanding_py(
+    py: Python,
+    pnl: PyReadonlyArray1<TAFloat>,
+    fraction: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_pnl = pnl.as_slice()?;
+    let len = input_pnl.len();
+
+    let mut output_kelly = vec![0.0; len];
+    let mut output_wins = vec![0.0; len];
+    let mut output_losses = vec![0.0; len];
+    let mut output_gross_profit = vec![0.0; len];
+    let mut output_gross_loss = vec![0.0; len];
+
+    py.allow_threads(|| {
+        kelly::kelly_expanding(
+            input_pnl,
+            fraction,
+            &mut output_kelly,
+            &mut output_wins,
+            &mut output_losses,
+            &mut output_gross_profit,
+            &mut output_gross_loss,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_kelly.into_pyarray(py).into(),
+        output_wins.into_pyarray(py).into(),
+        output_losses.into_pyarray(py).into(),
+        output_gross_profit.into_pyarray(py).into(),
+        output_gross_loss.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest expanding Kelly Criterion incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   pnl: PnL of the newest trade
+///   prev_wins: Previous cumulative count of winning trades
+///   prev_losses: Previous cumulative count of losing trades
+///   prev_gross_profit: Previous cumulative sum of winning trades
+///   prev_gross_loss: Previous cumulative absolute sum of losing trades
+///   prev_count: Number of trades already included in the sums
+///   fraction: Multiplier applied to the full Kelly fraction (must be > 0). Defaults to 1.
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Kelly fraction
+///   - New count of winning trades
+///   - New count of losing trades
+///   - New sum of winning trades
+///   - New absolute sum of losing trades
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kelly, wins, losses, gross_profit, gross_loss = kand.kelly_expanding_inc(
+///   ...     80.0,  # newest trade
+///   ...     1.0, 1.0, 120.0, 50.0,  # previous sums
+///   ...     3  # trades so far
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "kelly_expanding_inc",
+    signature = (
+        pnl,
+        prev_wins,
+        prev_losses,
+        prev_gross_profit,
+        prev_gross_loss,
+        prev_count,
+        fraction=1.0
+    )
+)]
+pub fn kelly_expanding_inc_py(
+    py: Python,
+    pnl: TAFloat,
+    prev_wins: TAFloat,
+    prev_losses: TAFloat,
+    prev_gross_profit: TAFloat,
+    prev_gross_loss: TAFloat,
+    prev_count: usize,
+    fraction: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        kelly::kelly_expanding_inc(
+            pnl,
+            prev_wins,
+            prev_losses,
+            prev_gross_profit,
+            prev_gross_loss,
+            prev_count,
+            fraction,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -1,7 +1,9 @@
-use kand::{TAFloat, stats::max};
+use kand::{Indicator, TAFloat, stats::max};
 use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
 use pyo3::prelude::*;
 
+use crate::helper::warn_deprecated;
+
 /// Calculate Maximum Value for a NumPy array
 ///
 /// Args:
@@ -42,6 +44,11 @@ pub fn max_py(
 
 /// Calculate the latest Maximum Value incrementally
 ///
+/// Deprecated:
+///   The previous MAX alone cannot be updated exactly once it leaves the window, so this
+///   function falls back to the current price in that case. Use the `Max` streaming object,
+///   which keeps the window between calls.
+///
 /// Args:
 ///   py: Python interpreter token
 ///   price: Current period's price
@@ -58,7 +65,7 @@ pub fn max_py(
 ///   >>> new_max = kand.max_inc(10.5, 11.0, 9.0, 14)
 ///   ```
 #[pyfunction]
-#[pyo3(name = "max_inc")]
+#[pyo3(name = "max_inc", signature = (price, prev_max, old_price, period))]
 pub fn max_inc_py(
     py: Python,
     price: TAFloat,
@@ -66,7 +73,66 @@ pub fn max_inc_py(
     old_price: TAFloat,
     period: usize,
 ) -> PyResult<TAFloat> {
-    // Perform incremental MAX calculation while releasing the GIL
-    py.allow_threads(|| max::max_inc(price, prev_max, old_price, period))
+    warn_deprecated(
+        py,
+        c"kand.max_inc is inexact once the maximum leaves the window; use kand.Max instead",
+    )?;
+    #[allow(deprecated)]
+    max::max_inc_legacy(price, prev_max, old_price, period)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
 }
+
+/// Streaming rolling Maximum Value.
+///
+/// Keeps a monotonic window of the last `period` prices between calls, so every update is
+/// O(1) amortized and stays exact when the maximum leaves the window.
+///
+/// Args:
+///   period: Period for MAX calculation (must be >= 2).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> rolling_max = kand.Max(3)
+///   >>> [rolling_max.update(price) for price in [3.0, 1.0, 2.0, 1.5]]
+///   [None, None, 3.0, 2.0]
+///   ```
+#[pyclass(name = "Max")]
+pub struct MaxPy {
+    inner: max::Max,
+}
+
+#[pymethods]
+impl MaxPy {
+    #[new]
+    #[pyo3(signature = (period))]
+    fn new(period: usize) -> PyResult<Self> {
+        let inner = max::Max::new(period)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    /// Adds the next price and returns the MAX of the window, or None while it is filling.
+    fn update(&mut self, price: TAFloat) -> PyResult<Option<TAFloat>> {
+        self.inner
+            .update(price)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    /// Clears the window.
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    /// Whether the window is full and `update` returns values.
+    #[getter]
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    /// Number of prices consumed before the first value.
+    #[getter]
+    fn lookback(&self) -> usize {
+        self.inner.lookback()
+    }
+}
@@ -1,7 +1,9 @@
-use kand::{TAFloat, stats::min};
+use kand::{Indicator, TAFloat, stats::min};
 use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
 use pyo3::prelude::*;
 
+use crate::helper::warn_deprecated;
+
 /// Calculate Minimum Value (MIN) for a NumPy array
 ///
 /// The MIN indicator finds the lowest price value within a given time period.
@@ -44,6 +46,11 @@ pub fn min_py(
 
 /// Calculate the latest MIN value incrementally
 ///
+/// Deprecated:
+///   The previous MIN alone cannot be updated once it leaves the window, so this function
+///   raises a ValueError in that case. Use the `Min` streaming object, which keeps the window
+///   between calls.
+///
 /// Args:
 ///   py: Python interpreter token
 ///   price: Current period's price
@@ -60,7 +67,7 @@ pub fn min_py(
 ///   >>> new_min = kand.min_inc(15.0, 12.0, 14.0, 14)
 ///   ```
 #[pyfunction]
-#[pyo3(name = "min_inc")]
+#[pyo3(name = "min_inc", signature = (price, prev_min, prev_price, period))]
 pub fn min_inc_py(
     py: Python,
     price: TAFloat,
@@ -68,7 +75,66 @@ pub fn min_inc_py(
     prev_price: TAFloat,
     period: usize,
 ) -> PyResult<TAFloat> {
-    // Perform incremental MIN calculation while releasing the GIL
-    py.allow_threads(|| min::min_inc(price, prev_min, prev_price, period))
+    warn_deprecated(
+        py,
+        c"kand.min_inc cannot follow the minimum out of the window; use kand.Min instead",
+    )?;
+    #[allow(deprecated)]
+    min::min_inc_legacy(price, prev_min, prev_price, period)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
 }
+
+/// Streaming rolling Minimum Value.
+///
+/// Keeps a monotonic window of the last `period` prices between calls, so every update is
+/// O(1) amortized and stays exact when the minimum leaves the window.
+///
+/// Args:
+///   period: Period for MIN calculation (must be >= 2).
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> rolling_min = kand.Min(3)
+///   >>> [rolling_min.update(price) for price in [1.0, 3.0, 2.0, 2.5]]
+///   [None, None, 1.0, 2.0]
+///   ```
+#[pyclass(name = "Min")]
+pub struct MinPy {
+    inner: min::Min,
+}
+
+#[pymethods]
+impl MinPy {
+    #[new]
+    #[pyo3(signature = (period))]
+    fn new(period: usize) -> PyResult<Self> {
+        let inner = min::Min::new(period)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    /// Adds the next price and returns the MIN of the window, or None while it is filling.
+    fn update(&mut self, price: TAFloat) -> PyResult<Option<TAFloat>> {
+        self.inner
+            .update(price)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+    }
+
+    /// Clears the window.
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    /// Whether the window is full and `update` returns values.
+    #[getter]
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    /// Number of prices consumed before the first value.
+    #[getter]
+    fn lookback(&self) -> usize {
+        self.inner.lookback()
+    }
+}
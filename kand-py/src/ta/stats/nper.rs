@@ -0,0 +1,105 @@
+use kand::{TAFloat, TAInt, stats::nper};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_payment_timing;
+
+/// Calculate the Number of Periods (NPER) element-wise over NumPy arrays
+///
+/// NPER is the number of constant payments needed to move the present value to the future
+/// value, e.g. how many months it takes to pay off a loan.
+///
+/// Args:
+///   rate: Interest rate per period as a 1-D NumPy array of type `TAFloat`.
+///   pmt: Payment made each period as a 1-D NumPy array of type `TAFloat`.
+///   pv: Present value as a 1-D NumPy array of type `TAFloat`.
+///   fv: Future value as a 1-D NumPy array of type `TAFloat`.
+///   when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+///
+/// Returns:
+///   A 1-D NumPy array of numbers of periods with the same length as the inputs.
+///
+/// Raises:
+///   ValueError: If no finite number of periods reaches `fv`.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> rate = np.array([0.07 / 12])
+///   >>> pmt = np.array([-150.0])
+///   >>> pv = np.array([8000.0])
+///   >>> fv = np.array([0.0])
+///   >>> kand.nper(rate, pmt, pv, fv)
+///   array([64.07334877])
+///   ```
+#[pyfunction]
+#[pyo3(name = "nper", signature = (rate, pmt, pv, fv, when=0))]
+pub fn nper_py(
+    py: Python,
+    rate: PyReadonlyArray1<TAFloat>,
+    pmt: PyReadonlyArray1<TAFloat>,
+    pv: PyReadonlyArray1<TAFloat>,
+    fv: PyReadonlyArray1<TAFloat>,
+    when: TAInt,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_rate = rate.as_slice()?;
+    let input_pmt = pmt.as_slice()?;
+    let input_pv = pv.as_slice()?;
+    let input_fv = fv.as_slice()?;
+    let when = parse_payment_timing(when)?;
+    let len = input_rate.len();
+    let mut output_nper = vec![0.0; len];
+
+    py.allow_threads(|| {
+        nper::nper(
+            input_rate,
+            input_pmt,
+            input_pv,
+            input_fv,
+            when,
+            &mut output_nper,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_nper.into_pyarray(py).into())
+}
+
+/// Calculate a single Number of Periods (NPER)
+///
+/// Args:
+///   py: Python interpreter token
+///   rate: Interest rate per period
+///   pmt: Payment made each period
+///   pv: Present value
+///   fv: Future value
+///   when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+///
+/// Returns:
+///   The number of periods.
+///
+/// Raises:
+///   ValueError: If no finite number of periods reaches `fv`.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.nper_inc(0.0, -100.0, 1000.0, 0.0)
+///   10.0
+///   ```
+#[pyfunction]
+#[pyo3(name = "nper_inc", signature = (rate, pmt, pv, fv, when=0))]
+pub fn nper_inc_py(
+    py: Python,
+    rate: TAFloat,
+    pmt: TAFloat,
+    pv: TAFloat,
+    fv: TAFloat,
+    when: TAInt,
+) -> PyResult<TAFloat> {
+    let when = parse_payment_timing(when)?;
+
+    py.allow_threads(|| nper::nper_inc(rate, pmt, pv, fv, when))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
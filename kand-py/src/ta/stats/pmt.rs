@@ -0,0 +1,105 @@
+use kand::{TAFloat, TAInt, stats::pmt};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_payment_timing;
+
+/// Calculate the periodic Payment (PMT) element-wise over NumPy arrays
+///
+/// PMT is the constant payment per period that moves the present value to the future value,
+/// e.g. the installment of a loan. Cash paid out is negative and cash received is positive.
+///
+/// Args:
+///   rate: Interest rate per period as a 1-D NumPy array of type `TAFloat`.
+///   nper: Number of compounding periods as a 1-D NumPy array of type `TAFloat`.
+///   pv: Present value as a 1-D NumPy array of type `TAFloat`.
+///   fv: Future value as a 1-D NumPy array of type `TAFloat`.
+///   when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+///
+/// Returns:
+///   A 1-D NumPy array of payments with the same length as the inputs.
+///
+/// Raises:
+///   ValueError: If the annuity factor is zero, e.g. when `nper` is 0.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> rate = np.array([0.075 / 12])
+///   >>> nper = np.array([180.0])
+///   >>> pv = np.array([200000.0])
+///   >>> fv = np.array([0.0])
+///   >>> kand.pmt(rate, nper, pv, fv)
+///   array([-1854.02472001])
+///   ```
+#[pyfunction]
+#[pyo3(name = "pmt", signature = (rate, nper, pv, fv, when=0))]
+pub fn pmt_py(
+    py: Python,
+    rate: PyReadonlyArray1<TAFloat>,
+    nper: PyReadonlyArray1<TAFloat>,
+    pv: PyReadonlyArray1<TAFloat>,
+    fv: PyReadonlyArray1<TAFloat>,
+    when: TAInt,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_rate = rate.as_slice()?;
+    let input_nper = nper.as_slice()?;
+    let input_pv = pv.as_slice()?;
+    let input_fv = fv.as_slice()?;
+    let when = parse_payment_timing(when)?;
+    let len = input_rate.len();
+    let mut output_pmt = vec![0.0; len];
+
+    py.allow_threads(|| {
+        pmt::pmt(
+            input_rate,
+            input_nper,
+            input_pv,
+            input_fv,
+            when,
+            &mut output_pmt,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_pmt.into_pyarray(py).into())
+}
+
+/// Calculate a single periodic Payment (PMT)
+///
+/// Args:
+///   py: Python interpreter token
+///   rate: Interest rate per period
+///   nper: Number of compounding periods
+///   pv: Present value
+///   fv: Future value
+///   when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+///
+/// Returns:
+///   The payment per period.
+///
+/// Raises:
+///   ValueError: If the annuity factor is zero, e.g. when `nper` is 0.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.pmt_inc(0.0, 12.0, 1200.0, 0.0)
+///   -100.0
+///   ```
+#[pyfunction]
+#[pyo3(name = "pmt_inc", signature = (rate, nper, pv, fv, when=0))]
+pub fn pmt_inc_py(
+    py: Python,
+    rate: TAFloat,
+    nper: TAFloat,
+    pv: TAFloat,
+    fv: TAFloat,
+    when: TAInt,
+) -> PyResult<TAFloat> {
+    let when = parse_payment_timing(when)?;
+
+    py.allow_threads(|| pmt::pmt_inc(rate, nper, pv, fv, when))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,99 @@
+use kand::{TAFloat, TAInt, stats::pv};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_payment_timing;
+
+/// Calculate the Present Value (PV) element-wise over NumPy arrays
+///
+/// PV is the amount today that, together with `nper` constant payments, is equivalent to the
+/// future value. Cash paid out is negative and cash received is positive.
+///
+/// Args:
+///   rate: Interest rate per period as a 1-D NumPy array of type `TAFloat`.
+///   nper: Number of compounding periods as a 1-D NumPy array of type `TAFloat`.
+///   pmt: Payment made each period as a 1-D NumPy array of type `TAFloat`.
+///   fv: Future value as a 1-D NumPy array of type `TAFloat`.
+///   when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+///
+/// Returns:
+///   A 1-D NumPy array of present values with the same length as the inputs.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> rate = np.array([0.08])
+///   >>> nper = np.array([20.0])
+///   >>> pmt = np.array([500.0])
+///   >>> fv = np.array([0.0])
+///   >>> kand.pv(rate, nper, pmt, fv)
+///   array([-4909.07370372])
+///   ```
+#[pyfunction]
+#[pyo3(name = "pv", signature = (rate, nper, pmt, fv, when=0))]
+pub fn pv_py(
+    py: Python,
+    rate: PyReadonlyArray1<TAFloat>,
+    nper: PyReadonlyArray1<TAFloat>,
+    pmt: PyReadonlyArray1<TAFloat>,
+    fv: PyReadonlyArray1<TAFloat>,
+    when: TAInt,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_rate = rate.as_slice()?;
+    let input_nper = nper.as_slice()?;
+    let input_pmt = pmt.as_slice()?;
+    let input_fv = fv.as_slice()?;
+    let when = parse_payment_timing(when)?;
+    let len = input_rate.len();
+    let mut output_pv = vec![0.0; len];
+
+    py.allow_threads(|| {
+        pv::pv(
+            input_rate,
+            input_nper,
+            input_pmt,
+            input_fv,
+            when,
+            &mut output_pv,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_pv.into_pyarray(py).into())
+}
+
+/// Calculate a single Present Value (PV)
+///
+/// Args:
+///   py: Python interpreter token
+///   rate: Interest rate per period
+///   nper: Number of compounding periods
+///   pmt: Payment made each period
+///   fv: Future value
+///   when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+///
+/// Returns:
+///   The present value.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.pv_inc(0.0, 10.0, -100.0, 0.0)
+///   1000.0
+///   ```
+#[pyfunction]
+#[pyo3(name = "pv_inc", signature = (rate, nper, pmt, fv, when=0))]
+pub fn pv_inc_py(
+    py: Python,
+    rate: TAFloat,
+    nper: TAFloat,
+    pmt: TAFloat,
+    fv: TAFloat,
+    when: TAInt,
+) -> PyResult<TAFloat> {
+    let when = parse_payment_timing(when)?;
+
+    py.allow_threads(|| pv::pv_inc(rate, nper, pmt, fv, when))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,127 @@
+use kand::{TAFloat, TAInt, stats::rate};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+use crate::helper::parse_payment_timing;
+
+/// Calculate the interest Rate per period (RATE) element-wise over NumPy arrays
+///
+/// RATE is the per-period rate at which `nper` constant payments move the present value to the
+/// future value. It is solved with Newton's method, falling back to bracketing and bisection
+/// when Newton fails.
+///
+/// Args:
+///   nper: Number of compounding periods as a 1-D NumPy array of type `TAFloat`.
+///   pmt: Payment made each period as a 1-D NumPy array of type `TAFloat`.
+///   pv: Present value as a 1-D NumPy array of type `TAFloat`.
+///   fv: Future value as a 1-D NumPy array of type `TAFloat`.
+///   when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+///   guess: Starting rate for Newton's method. Defaults to 0.1.
+///   tol: Convergence tolerance (must be > 0). Defaults to 1e-6.
+///   max_iter: Maximum number of Newton iterations (must be > 0). Defaults to 100.
+///
+/// Returns:
+///   A 1-D NumPy array of rates per period with the same length as the inputs.
+///
+/// Raises:
+///   ValueError: If the solver does not converge for any element.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> nper = np.array([10.0])
+///   >>> pmt = np.array([0.0])
+///   >>> pv = np.array([-3500.0])
+///   >>> fv = np.array([10000.0])
+///   >>> kand.rate(nper, pmt, pv, fv)
+///   array([0.11069085])
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "rate",
+    signature = (nper, pmt, pv, fv, when=0, guess=0.1, tol=1e-6, max_iter=100)
+)]
+pub fn rate_py(
+    py: Python,
+    nper: PyReadonlyArray1<TAFloat>,
+    pmt: PyReadonlyArray1<TAFloat>,
+    pv: PyReadonlyArray1<TAFloat>,
+    fv: PyReadonlyArray1<TAFloat>,
+    when: TAInt,
+    guess: TAFloat,
+    tol: TAFloat,
+    max_iter: usize,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_nper = nper.as_slice()?;
+    let input_pmt = pmt.as_slice()?;
+    let input_pv = pv.as_slice()?;
+    let input_fv = fv.as_slice()?;
+    let when = parse_payment_timing(when)?;
+    let len = input_nper.len();
+    let mut output_rate = vec![0.0; len];
+
+    py.allow_threads(|| {
+        rate::rate(
+            input_nper,
+            input_pmt,
+            input_pv,
+            input_fv,
+            when,
+            guess,
+            tol,
+            max_iter,
+            &mut output_rate,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_rate.into_pyarray(py).into())
+}
+
+/// Calculate a single interest Rate per period
+///
+/// Args:
+///   py: Python interpreter token
+///   nper: Number of compounding periods
+///   pmt: Payment made each period
+///   pv: Present value
+///   fv: Future value
+///   when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+///   guess: Starting rate for Newton's method. Defaults to 0.1.
+///   tol: Convergence tolerance (must be > 0). Defaults to 1e-6.
+///   max_iter: Maximum number of Newton iterations (must be > 0). Defaults to 100.
+///
+/// Returns:
+///   The rate per period.
+///
+/// Raises:
+///   ValueError: If the solver does not converge.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> round(kand.rate_inc(180.0, -1854.02, 200000.0, 0.0) * 12, 4)
+///   0.075
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "rate_inc",
+    signature = (nper, pmt, pv, fv, when=0, guess=0.1, tol=1e-6, max_iter=100)
+)]
+pub fn rate_inc_py(
+    py: Python,
+    nper: TAFloat,
+    pmt: TAFloat,
+    pv: TAFloat,
+    fv: TAFloat,
+    when: TAInt,
+    guess: TAFloat,
+    tol: TAFloat,
+    max_iter: usize,
+) -> PyResult<TAFloat> {
+    let when = parse_payment_timing(when)?;
+
+    py.allow_threads(|| rate::rate_inc(nper, pmt, pv, fv, when, guess, tol, max_iter))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,94 @@
+use kand::{TAFloat, stats::ret};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate rolling simple Returns (RET) for a NumPy array
+///
+/// The return over a window is the relative change from the price `period` bars ago to the
+/// current price. With the default period of 1 this yields per-bar returns.
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of bars the return spans (must be >= 1). Defaults to 1.
+///
+/// Returns:
+///   A 1-D NumPy array containing return values. The first `period` elements contain NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([100.0, 110.0, 99.0, 108.9])
+///   >>> returns = kand.ret(prices)
+///   ```
+#[pyfunction]
+#[pyo3(name = "ret", signature = (prices, period=1))]
+pub fn ret_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_ret = vec![0.0; len];
+
+    py.allow_threads(|| ret::ret(input_prices, period, &mut output_ret))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_ret.into_pyarray(py).into())
+}
+
+/// Calculate expanding (cumulative) Returns for a NumPy array
+///
+/// Args:
+///   prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A 1-D NumPy array containing the return since the first price. The first element is 0.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> prices = np.array([100.0, 110.0, 99.0])
+///   >>> cumulative = kand.ret_expanding(prices)
+///   ```
+#[pyfunction]
+#[pyo3(name = "ret_expanding", signature = (prices))]
+pub fn ret_expanding_py(
+    py: Python,
+    prices: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_prices = prices.as_slice()?;
+    let len = input_prices.len();
+
+    let mut output_ret = vec![0.0; len];
+
+    py.allow_threads(|| ret::ret_expanding(input_prices, &mut output_ret))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_ret.into_pyarray(py).into())
+}
+
+/// Calculate the latest Return value
+///
+/// Args:
+///   py: Python interpreter token
+///   price: Current period's price
+///   old_price: Reference price, either the price `period` bars ago or the first price
+///
+/// Returns:
+///   The latest return value
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> value = kand.ret_inc(110.0, 100.0)
+///   ```
+#[pyfunction]
+#[pyo3(name = "ret_inc", signature = (price, old_price))]
+pub fn ret_inc_py(py: Python, price: TAFloat, old_price: TAFloat) -> PyResult<TAFloat> {
+    py.allow_threads(|| ret::ret_inc(price, old_price))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
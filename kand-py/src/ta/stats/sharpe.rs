@@ -0,0 +1,227 @@
+use kand::{TAFloat, stats::sharpe};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate the rolling Sharpe Ratio for a NumPy array of returns
+///
+/// Excess returns over the per-period risk-free rate are divided by their sample standard
+/// deviation and annualized with the square root of `annualization`.
+///
+/// Args:
+///   returns: Per-period returns as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of returns in the rolling window (must be >= 2).
+///   annualization: Periods per year (must be > 0). Defaults to 252.
+///   risk_free: Annual risk-free rate. Defaults to 0.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Sharpe Ratio values
+///   - Running sum of excess returns
+///   - Running sum of squared excess returns
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+///   >>> sharpe, sum, sum_sq = kand.sharpe(returns, 3, 252.0, 0.02)
+///   ```
+#[pyfunction]
+#[pyo3(name = "sharpe", signature = (returns, period, annualization=252.0, risk_free=0.0))]
+pub fn sharpe_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    period: usize,
+    annualization: TAFloat,
+    risk_free: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_returns = returns.as_slice()?;
+    let len = input_returns.len();
+
+    let mut output_sharpe = vec![0.0; len];
+    let mut output_sum = vec![0.0; len];
+    let mut output_sum_sq = vec![0.0; len];
+
+    py.allow_threads(|| {
+        sharpe::sharpe(
+            input_returns,
+            period,
+            annualization,
+            risk_free,
+            &mut output_sharpe,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_sharpe.into_pyarray(py).into(),
+        output_sum.into_pyarray(py).into(),
+        output_sum_sq.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest rolling Sharpe Ratio incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   ret: Current period's return
+///   old_ret: Return leaving the window
+///   prev_sum: Previous sum of excess returns
+///   prev_sum_sq: Previous sum of squared excess returns
+///   period: Number of returns in the rolling window (must be >= 2)
+///   annualization: Periods per year (must be > 0). Defaults to 252.
+///   risk_free: Annual risk-free rate. Defaults to 0.
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Sharpe Ratio value
+///   - New sum of excess returns
+///   - New sum of squared excess returns
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> sharpe, sum, sum_sq = kand.sharpe_inc(0.005, 0.01, 0.005, 0.000725, 3)
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "sharpe_inc",
+    signature = (ret, old_ret, prev_sum, prev_sum_sq, period, annualization=252.0, risk_free=0.0)
+)]
+pub fn sharpe_inc_py(
+    py: Python,
+    ret: TAFloat,
+    old_ret: TAFloat,
+    prev_sum: TAFloat,
+    prev_sum_sq: TAFloat,
+    period: usize,
+    annualization: TAFloat,
+    risk_free: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        sharpe::sharpe_inc(
+            ret,
+            old_ret,
+            prev_sum,
+            prev_sum_sq,
+            period,
+            annualization,
+            risk_free,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Calculate the expanding Sharpe Ratio for a NumPy array of returns
+///
+/// Args:
+///   returns: Per-period returns as a 1-D NumPy array of type `TAFloat`.
+///   annualization: Periods per year (must be > 0). Defaults to 252.
+///   risk_free: Annual risk-free rate. Defaults to 0.
+///
+/// Returns:
+///   A tuple of three 1-D NumPy arrays containing:
+///   - Sharpe Ratio values, NaN for the first element
+///   - Cumulative sum of excess returns
+///   - Cumulative sum of squared excess returns
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> returns = np.array([0.01, -0.02, 0.015, 0.005])
+///   >>> sharpe, sum, sum_sq = kand.sharpe_expanding(returns)
+///   ```
+#[pyfunction]
+#[pyo3(name = "sharpe_expanding", signature = (returns, annualization=252.0, risk_free=0.0))]
+pub fn sharpe_expanding_py(
+    py: Python,
+    returns: PyReadonlyArray1<TAFloat>,
+    annualization: TAFloat,
+    risk_free: TAFloat,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_returns = returns.as_slice()?;
+    let len = input_returns.len();
+
+    let mut output_sharpe = vec![0.0; len];
+    let mut output_sum = vec![0.0; len];
+    let mut output_sum_sq = vec![0.0; len];
+
+    py.allow_threads(|| {
+        sharpe::sharpe_expanding(
+            input_returns,
+            annualization,
+            risk_free,
+            &mut output_sharpe,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_sharpe.into_pyarray(py).into(),
+        output_sum.into_pyarray(py).into(),
+        output_sum_sq.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest expanding Sharpe Ratio incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   ret: Current period's return
+///   prev_sum: Previous cumulative sum of excess returns
+///   prev_sum_sq: Previous cumulative sum of squared excess returns
+///   prev_count: Number of returns already included in the sums
+///   annualization: Periods per year (must be > 0). Defaults to 252.
+///   risk_free: Annual risk-free rate. Defaults to 0.
+///
+/// Returns:
+///   A tuple containing:
+///   - Latest Sharpe Ratio value, NaN for the first return
+///   - New cumulative sum of excess returns
+///   - New cumulative sum of squared excess returns
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> sharpe, sum, sum_sq = kand.sharpe_expanding_inc(0.005, -0.01, 0.0005, 2)
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "sharpe_expanding_inc",
+    signature = (ret, prev_sum, prev_sum_sq, prev_count, annualization=252.0, risk_free=0.0)
+)]
+pub fn sharpe_expanding_inc_py(
+    py: Python,
+    ret: TAFloat,
+    prev_sum: TAFloat,
+    prev_sum_sq: TAFloat,
+    prev_count: usize,
+    annualization: TAFloat,
+    risk_free: TAFloat,
+) -> PyResult<(TAFloat, TAFloat, TAFloat)> {
+    py.allow_threads(|| {
+        sharpe::sharpe_expanding_inc(
+            ret,
+            prev_sum,
+            prev_sum_sq,
+            prev_count,
+            annualization,
+            risk_free,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -0,0 +1,399 @@
+use kand::{TAFloat, stats::winrate};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::prelude::*;
+
+/// Calculate the profit and loss of each trade from its entry and exit prices
+///
+/// Args:
+///   entry: Entry prices as a 1-D NumPy array of type `TAFloat`.
+///   exit: Exit prices as a 1-D NumPy array of type `TAFloat`.
+///   size: Signed position sizes as a 1-D NumPy array of type `TAFloat`, negative for shorts.
+///
+/// Returns:
+///   A 1-D NumPy array with the PnL of each trade, (exit - entry) * size.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> entry = np.array([100.0, 50.0])
+///   >>> exit = np.array([110.0, 55.0])
+///   >>> size = np.array([2.0, -1.0])
+///   >>> kand.trade_pnl(entry, exit, size)
+///   array([20., -5.])
+///   ```
+#[pyfunction]
+#[pyo3(name = "trade_pnl", signature = (entry, exit, size))]
+pub fn trade_pnl_py(
+    py: Python,
+    entry: PyReadonlyArray1<TAFloat>,
+    exit: PyReadonlyArray1<TAFloat>,
+    size: PyReadonlyArray1<TAFloat>,
+) -> PyResult<Py<PyArray1<TAFloat>>> {
+    let input_entry = entry.as_slice()?;
+    let input_exit = exit.as_slice()?;
+    let input_size = size.as_slice()?;
+    let len = input_entry.len();
+    let mut output_pnl = vec![0.0; len];
+
+    py.allow_threads(|| winrate::trade_pnl(input_entry, input_exit, input_size, &mut output_pnl))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok(output_pnl.into_pyarray(py).into())
+}
+
+/// Calculate the profit and loss of a single trade
+///
+/// Args:
+///   py: Python interpreter token
+///   entry: Entry price
+///   exit: Exit price
+///   size: Signed position size, negative for short trades
+///
+/// Returns:
+///   The PnL of the trade.
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> kand.trade_pnl_inc(100.0, 95.0, -3.0)
+///   15.0
+///   ```
+#[pyfunction]
+#[pyo3(name = "trade_pnl_inc", signature = (entry, exit, size))]
+pub fn trade_pnl_inc_py(
+    py: Python,
+    entry: TAFloat,
+    exit: TAFloat,
+    size: TAFloat,
+) -> PyResult<TAFloat> {
+    py.allow_threads(|| winrate::trade_pnl_inc(entry, exit, size))
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Calculate rolling trade statistics (WINRATE) for a NumPy array of per-trade PnL
+///
+/// Summarizes the last `period` trades by their win rate, average win, average loss, profit
+/// factor and expectancy. Breakeven trades count as trades but are neither wins nor losses.
+/// Averages are 0 when their denominator is 0. Without losing trades the profit factor is
+/// infinite if there was any profit and NaN if the window holds only breakeven trades.
+///
+/// Args:
+///   pnl: Per-trade profit and loss as a 1-D NumPy array of type `TAFloat`.
+///   period: Number of trades in the rolling window (must be >= 1).
+///
+/// Returns:
+///   A tuple of nine 1-D NumPy arrays containing:
+///   - Win rate
+///   - Average winning trade
+///   - Average losing trade, as a positive value
+///   - Profit factor
+///   - Expectancy
+///   - Running count of winning trades
+///   - Running count of losing trades
+///   - Running sum of winning trades
+///   - Running absolute sum of losing trades
+///   Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> pnl = np.array([120.0, -50.0, 0.0, 80.0, -30.0])
+///   >>> (winrate, avg_win, avg_loss, profit_factor, expectancy,
+///   ...  wins, losses, gross_profit, gross_loss) = kand.winrate(pnl, 4)
+///   ```
+#[pyfunction]
+#[pyo3(name = "winrate", signature = (pnl, period))]
+#[allow(clippy::type_complexity)]
+pub fn winrate_py(
+    py: Python,
+    pnl: PyReadonlyArray1<TAFloat>,
+    period: usize,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_pnl = pnl.as_slice()?;
+    let len = input_pnl.len();
+
+    let mut output_winrate = vec![0.0; len];
+    let mut output_avg_win = vec![0.0; len];
+    let mut output_avg_loss = vec![0.0; len];
+    let mut output_profit_factor = vec![0.0; len];
+    let mut output_expectancy = vec![0.0; len];
+    let mut output_wins = vec![0.0; len];
+    let mut output_losses = vec![0.0; len];
+    let mut output_gross_profit = vec![0.0; len];
+    let mut output_gross_loss = vec![0.0; len];
+
+    py.allow_threads(|| {
+        winrate::winrate(
+            input_pnl,
+            period,
+            &mut output_winrate,
+            &mut output_avg_win,
+            &mut output_avg_loss,
+            &mut output_profit_factor,
+            &mut output_expectancy,
+            &mut output_wins,
+            &mut output_losses,
+            &mut output_gross_profit,
+            &mut output_gross_loss,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_winrate.into_pyarray(py).into(),
+        output_avg_win.into_pyarray(py).into(),
+        output_avg_loss.into_pyarray(py).into(),
+        output_profit_factor.into_pyarray(py).into(),
+        output_expectancy.into_pyarray(py).into(),
+        output_wins.into_pyarray(py).into(),
+        output_losses.into_pyarray(py).into(),
+        output_gross_profit.into_pyarray(py).into(),
+        output_gross_loss.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest rolling trade statistics incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   pnl: PnL of the newest trade
+///   old_pnl: PnL of the trade leaving the window
+///   prev_wins: Previous count of winning trades
+///   prev_losses: Previous count of losing trades
+///   prev_gross_profit: Previous sum of winning trades
+///   prev_gross_loss: Previous absolute sum of losing trades
+///   period: Number of trades in the rolling window (must be >= 1)
+///
+/// Returns:
+///   A tuple containing:
+///   - Win rate
+///   - Average winning trade
+///   - Average losing trade
+///   - Profit factor
+///   - Expectancy
+///   - New count of winning trades
+///   - New count of losing trades
+///   - New sum of winning trades
+///   - New absolute sum of losing trades
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> (winrate, avg_win, avg_loss, profit_factor, expectancy,
+///   ...  wins, losses, gross_profit, gross_loss) = kand.winrate_inc(
+///   ...     -30.0, 120.0,  # newest trade, trade leaving the window
+///   ...     2.0, 1.0, 200.0, 50.0,  # previous sums
+///   ...     4  # period
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "winrate_inc",
+    signature = (pnl, old_pnl, prev_wins, prev_losses, prev_gross_profit, prev_gross_loss, period)
+)]
+#[allow(clippy::type_complexity)]
+pub fn winrate_inc_py(
+    py: Python,
+    pnl: TAFloat,
+    old_pnl: TAFloat,
+    prev_wins: TAFloat,
+    prev_losses: TAFloat,
+    prev_gross_profit: TAFloat,
+    prev_gross_loss: TAFloat,
+    period: usize,
+) -> PyResult<(
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+)> {
+    py.allow_threads(|| {
+        winrate::winrate_inc(
+            pnl,
+            old_pnl,
+            prev_wins,
+            prev_losses,
+            prev_gross_profit,
+            prev_gross_loss,
+            period,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
+
+/// Calculate expanding trade statistics for a NumPy array of per-trade PnL
+///
+/// Uses every trade from the start up to each index, so the last element holds the statistics
+/// of the whole sample.
+///
+/// Args:
+///   pnl: Per-trade profit and loss as a 1-D NumPy array of type `TAFloat`.
+///
+/// Returns:
+///   A tuple of nine 1-D NumPy arrays containing:
+///   - Win rate
+///   - Average winning trade
+///   - Average losing trade, as a positive value
+///   - Profit factor
+///   - Expectancy
+///   - Cumulative count of winning trades
+///   - Cumulative count of losing trades
+///   - Cumulative sum of winning trades
+///   - Cumulative absolute sum of losing trades
+///
+/// Examples:
+///   ```python
+///   >>> import numpy as np
+///   >>> import kand
+///   >>> pnl = np.array([120.0, -50.0, 0.0, 80.0])
+///   >>> (winrate, avg_win, avg_loss, profit_factor, expectancy,
+///   ...  wins, losses, gross_profit, gross_loss) = kand.winrate_expanding(pnl)
+///   >>> winrate[-1]
+///   0.5
+///   ```
+#[pyfunction]
+#[pyo3(name = "winrate_expanding", signature = (pnl))]
+#[allow(clippy::type_complexity)]
+pub fn winrate_expanding_py(
+    py: Python,
+    pnl: PyReadonlyArray1<TAFloat>,
+) -> PyResult<(
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+    Py<PyArray1<TAFloat>>,
+)> {
+    let input_pnl = pnl.as_slice()?;
+    let len = input_pnl.len();
+
+    let mut output_winrate = vec![0.0; len];
+    let mut output_avg_win = vec![0.0; len];
+    let mut output_avg_loss = vec![0.0; len];
+    let mut output_profit_factor = vec![0.0; len];
+    let mut output_expectancy = vec![0.0; len];
+    let mut output_wins = vec![0.0; len];
+    let mut output_losses = vec![0.0; len];
+    let mut output_gross_profit = vec![0.0; len];
+    let mut output_gross_loss = vec![0.0; len];
+
+    py.allow_threads(|| {
+        winrate::winrate_expanding(
+            input_pnl,
+            &mut output_winrate,
+            &mut output_avg_win,
+            &mut output_avg_loss,
+            &mut output_profit_factor,
+            &mut output_expectancy,
+            &mut output_wins,
+            &mut output_losses,
+            &mut output_gross_profit,
+            &mut output_gross_loss,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+    Ok((
+        output_winrate.into_pyarray(py).into(),
+        output_avg_win.into_pyarray(py).into(),
+        output_avg_loss.into_pyarray(py).into(),
+        output_profit_factor.into_pyarray(py).into(),
+        output_expectancy.into_pyarray(py).into(),
+        output_wins.into_pyarray(py).into(),
+        output_losses.into_pyarray(py).into(),
+        output_gross_profit.into_pyarray(py).into(),
+        output_gross_loss.into_pyarray(py).into(),
+    ))
+}
+
+/// Calculate the latest expanding trade statistics incrementally
+///
+/// Args:
+///   py: Python interpreter token
+///   pnl: PnL of the newest trade
+///   prev_wins: Previous cumulative count of winning trades
+///   prev_losses: Previous cumulative count of losing trades
+///   prev_gross_profit: Previous cumulative sum of winning trades
+///   prev_gross_loss: Previous cumulative absolute sum of losing trades
+///   prev_count: Number of trades already included in the sums
+///
+/// Returns:
+///   A tuple containing:
+///   - Win rate
+///   - Average winning trade
+///   - Average losing trade
+///   - Profit factor
+///   - Expectancy
+///   - New count of winning trades
+///   - New count of losing trades
+///   - New sum of winning trades
+///   - New absolute sum of losing trades
+///
+/// Examples:
+///   ```python
+///   >>> import kand
+///   >>> (winrate, avg_win, avg_loss, profit_factor, expectancy,
+///   ...  wins, losses, gross_profit, gross_loss) = kand.winrate_expanding_inc(
+///   ...     80.0,  # newest trade
+///   ...     1.0, 1.0, 120.0, 50.0,  # previous sums
+///   ...     3  # trades so far
+///   ... )
+///   ```
+#[pyfunction]
+#[pyo3(
+    name = "winrate_expanding_inc",
+    signature = (pnl, prev_wins, prev_losses, prev_gross_profit, prev_gross_loss, prev_count)
+)]
+#[allow(clippy::type_complexity)]
+pub fn winrate_expanding_inc_py(
+    py: Python,
+    pnl: TAFloat,
+    prev_wins: TAFloat,
+    prev_losses: TAFloat,
+    prev_gross_profit: TAFloat,
+    prev_gross_loss: TAFloat,
+    prev_count: usize,
+) -> PyResult<(
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+    TAFloat,
+)> {
+    py.allow_threads(|| {
+        winrate::winrate_expanding_inc(
+            pnl,
+            prev_wins,
+            prev_losses,
+            prev_gross_profit,
+            prev_gross_loss,
+            prev_count,
+        )
+    })
+    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+}
@@ -4,12 +4,17 @@
 mod helper;
 
 criterion_main! {
+    // Bar benchmarks
+    benchmarks::bars::resample_bench::bars,
+    benchmarks::bars::trade_bars_bench::bars,
+
     // OHLCV benchmarks
     benchmarks::ohlcv::ad_bench::ohlcv,
     benchmarks::ohlcv::adosc_bench::ohlcv,
     benchmarks::ohlcv::adr_bench::ohlcv,
     benchmarks::ohlcv::adx_bench::ohlcv,
     benchmarks::ohlcv::adxr_bench::ohlcv,
+    benchmarks::ohlcv::apo_bench::ohlcv,
     benchmarks::ohlcv::aroon_bench::ohlcv,
     benchmarks::ohlcv::aroonosc_bench::ohlcv,
     benchmarks::ohlcv::atr_bench::ohlcv,
@@ -23,11 +28,34 @@
     benchmarks::ohlcv::cdl_inverted_hammer_bench::ohlcv,
     benchmarks::ohlcv::cdl_long_shadow_bench::ohlcv,
     benchmarks::ohlcv::cdl_marubozu_bench::ohlcv,
+    benchmarks::ohlcv::cmf_bench::ohlcv,
+    benchmarks::ohlcv::cmo_bench::ohlcv,
     benchmarks::ohlcv::dema_bench::ohlcv,
+    benchmarks::ohlcv::donchian_bench::ohlcv,
     benchmarks::ohlcv::dx_bench::ohlcv,
     benchmarks::ohlcv::ecl_bench::ohlcv,
     benchmarks::ohlcv::ema_bench::ohlcv,
+    benchmarks::ohlcv::emv_bench::ohlcv,
+    benchmarks::ohlcv::force_index_bench::ohlcv,
+    benchmarks::ohlcv::ht_dcperiod_bench::ohlcv,
+    benchmarks::ohlcv::ht_dcphase_bench::ohlcv,
+    benchmarks::ohlcv::ht_phasor_bench::ohlcv,
+    benchmarks::ohlcv::ht_sine_bench::ohlcv,
+    benchmarks::ohlcv::ht_trendline_bench::ohlcv,
+    benchmarks::ohlcv::ht_trendmode_bench::ohlcv,
+    benchmarks::ohlcv::ichimoku_bench::ohlcv,
+    benchmarks::ohlcv::kagi_bench::ohlcv,
+    benchmarks::ohlcv::keltner_bench::ohlcv,
+    benchmarks::ohlcv::kama_bench::ohlcv,
+    benchmarks::ohlcv::line_break_bench::ohlcv,
+    benchmarks::ohlcv::linearreg_bench::ohlcv,
+    benchmarks::ohlcv::linearreg_angle_bench::ohlcv,
+    benchmarks::ohlcv::linearreg_intercept_bench::ohlcv,
+    benchmarks::ohlcv::linearreg_slope_bench::ohlcv,
+    benchmarks::ohlcv::ma_bench::ohlcv,
     benchmarks::ohlcv::macd_bench::ohlcv,
+    benchmarks::ohlcv::macdext_bench::ohlcv,
+    benchmarks::ohlcv::mama_bench::ohlcv,
     benchmarks::ohlcv::medprice_bench::ohlcv,
     benchmarks::ohlcv::mfi_bench::ohlcv,
     benchmarks::ohlcv::midpoint_bench::ohlcv,
@@ -39,6 +67,11 @@
     benchmarks::ohlcv::obv_bench::ohlcv,
     benchmarks::ohlcv::plus_di_bench::ohlcv,
     benchmarks::ohlcv::plus_dm_bench::ohlcv,
+    benchmarks::ohlcv::pnf_bench::ohlcv,
+    benchmarks::ohlcv::ppo_bench::ohlcv,
+    benchmarks::ohlcv::pvt_bench::ohlcv,
+    benchmarks::ohlcv::range_bars_bench::ohlcv,
+    benchmarks::ohlcv::renko_bench::ohlcv,
     benchmarks::ohlcv::rma_bench::ohlcv,
     benchmarks::ohlcv::roc_bench::ohlcv,
     benchmarks::ohlcv::rocp_bench::ohlcv,
@@ -46,8 +79,12 @@
     benchmarks::ohlcv::rocr100_bench::ohlcv,
     benchmarks::ohlcv::rsi_bench::ohlcv,
     benchmarks::ohlcv::sar_bench::ohlcv,
+    benchmarks::ohlcv::sarext_bench::ohlcv,
     benchmarks::ohlcv::sma_bench::ohlcv,
+    benchmarks::ohlcv::starc_bench::ohlcv,
     benchmarks::ohlcv::stoch_bench::ohlcv,
+    benchmarks::ohlcv::stochf_bench::ohlcv,
+    benchmarks::ohlcv::stochrsi_bench::ohlcv,
     benchmarks::ohlcv::supertrend_bench::ohlcv,
     benchmarks::ohlcv::t3_bench::ohlcv,
     benchmarks::ohlcv::tema_bench::ohlcv,
@@ -55,19 +92,37 @@
     benchmarks::ohlcv::trange_bench::ohlcv,
     benchmarks::ohlcv::trima_bench::ohlcv,
     benchmarks::ohlcv::trix_bench::ohlcv,
+    benchmarks::ohlcv::tsf_bench::ohlcv,
     benchmarks::ohlcv::typprice_bench::ohlcv,
+    benchmarks::ohlcv::ultosc_bench::ohlcv,
     benchmarks::ohlcv::vegas_bench::ohlcv,
     benchmarks::ohlcv::wclprice_bench::ohlcv,
     benchmarks::ohlcv::willr_bench::ohlcv,
     benchmarks::ohlcv::wma_bench::ohlcv,
+    benchmarks::ohlcv::vwap_bands_bench::ohlcv,
     benchmarks::ohlcv::vwap_bench::ohlcv,
 
     // Stats benchmarks
+    benchmarks::stats::alpha_bench::stats,
+    benchmarks::stats::beta_bench::stats,
+    benchmarks::stats::calmar_bench::stats,
+    benchmarks::stats::correl_bench::stats,
+    benchmarks::stats::drawdown_bench::stats,
+    benchmarks::stats::fv_bench::stats,
+    benchmarks::stats::kelly_bench::stats,
     benchmarks::stats::max_bench::stats,
     benchmarks::stats::min_bench::stats,
+    benchmarks::stats::nper_bench::stats,
+    benchmarks::stats::pmt_bench::stats,
+    benchmarks::stats::pv_bench::stats,
+    benchmarks::stats::rate_bench::stats,
+    benchmarks::stats::ret_bench::stats,
+    benchmarks::stats::sharpe_bench::stats,
+    benchmarks::stats::sortino_bench::stats,
     benchmarks::stats::stddev_bench::stats,
     benchmarks::stats::sum_bench::stats,
     benchmarks::stats::var_bench::stats,
+    benchmarks::stats::winrate_bench::stats,
 
     // Helper benchmarks
     // benchmarks::helper::helper,
@@ -0,0 +1,2 @@
+pub mod resample_bench;
+pub mod trade_bars_bench;
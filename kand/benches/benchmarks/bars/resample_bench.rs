@@ -0,0 +1,58 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::bars::resample::resample;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_resample(c: &mut Criterion) {
+    let mut group = c.benchmark_group("resample");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let intervals = vec![300_000, 3_600_000, 86_400_000];
+
+    for size in sizes {
+        let input_close = generate_test_data(size);
+        let input_timestamp: Vec<i64> = (0..size as i64).map(|i| i * 60_000).collect();
+        let input_high: Vec<_> = input_close.iter().map(|close| close + 0.5).collect();
+        let input_low: Vec<_> = input_close.iter().map(|close| close - 0.5).collect();
+        let input_volume = vec![1.0; size];
+        let mut output_timestamp = Vec::new();
+        let mut output_open = Vec::new();
+        let mut output_high = Vec::new();
+        let mut output_low = Vec::new();
+        let mut output_close = Vec::new();
+        let mut output_volume = Vec::new();
+
+        for interval in &intervals {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), interval),
+                interval,
+                |b, &interval| {
+                    b.iter(|| {
+                        let _ = resample(
+                            black_box(&input_timestamp),
+                            black_box(&input_close),
+                            black_box(&input_high),
+                            black_box(&input_low),
+                            black_box(&input_close),
+                            black_box(&input_volume),
+                            black_box(interval),
+                            black_box(0),
+                            black_box(&mut output_timestamp),
+                            black_box(&mut output_open),
+                            black_box(&mut output_high),
+                            black_box(&mut output_low),
+                            black_box(&mut output_close),
+                            black_box(&mut output_volume),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(bars, bench_resample);
@@ -0,0 +1,63 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::{TAFloat, bars::trade_bars::trade_bars, types::BarType};
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_trade_bars(c: &mut Criterion) {
+    let mut group = c.benchmark_group("trade_bars");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let rules = vec![
+        (BarType::Time, 60_000.0),
+        (BarType::Tick, 100.0),
+        (BarType::Volume, 500.0),
+        (BarType::Dollar, 50_000.0),
+        (BarType::TickImbalance, 20.0),
+    ];
+
+    for size in sizes {
+        let input_price = generate_test_data(size);
+        let input_timestamp: Vec<i64> = (0..size as i64).map(|i| i * 250).collect();
+        let input_size: Vec<TAFloat> = input_price.iter().map(|price| price % 10.0).collect();
+        let mut output_timestamp = Vec::new();
+        let mut output_open = Vec::new();
+        let mut output_high = Vec::new();
+        let mut output_low = Vec::new();
+        let mut output_close = Vec::new();
+        let mut output_volume = Vec::new();
+        let mut output_trades = Vec::new();
+        let mut output_complete = Vec::new();
+
+        for (bar_type, threshold) in &rules {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), format!("{bar_type:?}")),
+                &(*bar_type, *threshold),
+                |b, &(bar_type, threshold)| {
+                    b.iter(|| {
+                        let _ = trade_bars(
+                            black_box(&input_timestamp),
+                            black_box(&input_price),
+                            black_box(&input_size),
+                            black_box(bar_type),
+                            black_box(threshold),
+                            black_box(&mut output_timestamp),
+                            black_box(&mut output_open),
+                            black_box(&mut output_high),
+                            black_box(&mut output_low),
+                            black_box(&mut output_close),
+                            black_box(&mut output_volume),
+                            black_box(&mut output_trades),
+                            black_box(&mut output_complete),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(bars, bench_trade_bars);
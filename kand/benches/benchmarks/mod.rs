@@ -1,3 +1,4 @@
+pub mod bars;
 pub mod ohlcv;
 pub mod stats;
 
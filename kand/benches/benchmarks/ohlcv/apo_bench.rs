@@ -1,7 +1,7 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
-use kand::ohlcv::apo::apo;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::{ohlcv::apo::apo, types::MAType};
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_apo(c: &mut Criterion) {
@@ -9,26 +9,35 @@ fn bench_apo(c: &mut Criterion) {
 
     // Test different data sizes
     let sizes = vec![100_000, 1_000_000, 10_000_000];
-    let periods = vec![5, 50, 200];
+    let ma_types = vec![MAType::EMA, MAType::SMA, MAType::TEMA];
 
     for size in sizes {
-        let input_close = generate_test_data(size);
-        let mut output = vec![0.0; size];
+        let input = generate_test_data(size);
+        let mut output_apo = vec![0.0; size];
+        let mut output_signal = vec![0.0; size];
+        let mut output_histogram = vec![0.0; size];
+        let mut output_fast_ma = vec![0.0; size];
+        let mut output_slow_ma = vec![0.0; size];
 
-        for period in &periods {
+        for ma_type in &ma_types {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
-                period,
-                |b, &period| {
+                BenchmarkId::new(format!("size_{size}"), format!("{ma_type:?}")),
+                ma_type,
+                |b, &ma_type| {
                     b.iter(|| {
                         let _ = apo(
-                            black_box(&input_close),
-                            black_box(period),
-                            black_box(period),
-                            black_box(MAType::Exponential),
-                            black_box(&mut output),
-                            black_box(&mut output),
-                            black_box(&mut output),
+                            black_box(&input),
+                            black_box(12),
+                            black_box(ma_type),
+                            black_box(26),
+                            black_box(ma_type),
+                            black_box(9),
+                            black_box(ma_type),
+                            black_box(&mut output_apo),
+                            black_box(&mut output_signal),
+                            black_box(&mut output_histogram),
+                            black_box(&mut output_fast_ma),
+                            black_box(&mut output_slow_ma),
                         );
                     });
                 },
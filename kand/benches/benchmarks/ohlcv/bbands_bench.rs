@@ -1,5 +1,5 @@
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
-use kand::ohlcv::bbands::bbands;
+use kand::{ohlcv::bbands::bbands, types::MAType};
 
 use crate::helper::generate_test_data;
 
@@ -31,6 +31,7 @@ fn bench_bbands(c: &mut Criterion) {
                             black_box(period),
                             black_box(2.0),
                             black_box(2.0),
+                            black_box(MAType::SMA),
                             black_box(&mut output_upper),
                             black_box(&mut output_middle),
                             black_box(&mut output_lower),
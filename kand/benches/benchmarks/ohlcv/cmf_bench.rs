@@ -0,0 +1,41 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::ohlcv::cmf::cmf;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_cmf(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cmf");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+
+    for size in sizes {
+        let close = generate_test_data(size);
+        let high: Vec<_> = close.iter().map(|value| value + 0.5).collect();
+        let low: Vec<_> = close.iter().map(|value| value - 0.5).collect();
+        let volume = generate_test_data(size);
+        let mut output_cmf = vec![0.0; size];
+        let mut output_mfv_sum = vec![0.0; size];
+        let mut output_volume_sum = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = cmf(
+                    black_box(&high),
+                    black_box(&low),
+                    black_box(&close),
+                    black_box(&volume),
+                    black_box(20),
+                    black_box(&mut output_cmf),
+                    black_box(&mut output_mfv_sum),
+                    black_box(&mut output_volume_sum),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_cmf);
@@ -1,7 +1,7 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
 use kand::ohlcv::cmo::cmo;
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_cmo(c: &mut Criterion) {
@@ -14,14 +14,22 @@ fn bench_cmo(c: &mut Criterion) {
     for size in sizes {
         let input = generate_test_data(size);
         let mut output = vec![0.0; size];
+        let mut output_avg_gain = vec![0.0; size];
+        let mut output_avg_loss = vec![0.0; size];
 
         for period in &periods {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
+                BenchmarkId::new(format!("size_{size}"), period),
                 period,
                 |b, &period| {
                     b.iter(|| {
-                        let _ = cmo(black_box(&input), black_box(period), black_box(&mut output));
+                        let _ = cmo(
+                            black_box(&input),
+                            black_box(period),
+                            black_box(&mut output),
+                            black_box(&mut output_avg_gain),
+                            black_box(&mut output_avg_loss),
+                        );
                     });
                 },
             );
@@ -0,0 +1,43 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::ohlcv::donchian::donchian;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_donchian(c: &mut Criterion) {
+    let mut group = c.benchmark_group("donchian");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+
+    for size in sizes {
+        let input_close = generate_test_data(size);
+        let input_high: Vec<_> = input_close.iter().map(|close| close + 0.5).collect();
+        let input_low: Vec<_> = input_close.iter().map(|close| close - 0.5).collect();
+        let mut output_upper = vec![0.0; size];
+        let mut output_middle = vec![0.0; size];
+        let mut output_lower = vec![0.0; size];
+        let mut output_percent_b = vec![0.0; size];
+        let mut output_bandwidth = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = donchian(
+                    black_box(&input_high),
+                    black_box(&input_low),
+                    black_box(&input_close),
+                    black_box(20),
+                    black_box(&mut output_upper),
+                    black_box(&mut output_middle),
+                    black_box(&mut output_lower),
+                    black_box(&mut output_percent_b),
+                    black_box(&mut output_bandwidth),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_donchian);
@@ -0,0 +1,39 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::ohlcv::emv::emv;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_emv(c: &mut Criterion) {
+    let mut group = c.benchmark_group("emv");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+
+    for size in sizes {
+        let close = generate_test_data(size);
+        let high: Vec<_> = close.iter().map(|value| value + 0.5).collect();
+        let low: Vec<_> = close.iter().map(|value| value - 0.5).collect();
+        let volume = generate_test_data(size);
+        let mut output_emv = vec![0.0; size];
+        let mut output_emv_raw = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = emv(
+                    black_box(&high),
+                    black_box(&low),
+                    black_box(&volume),
+                    black_box(14),
+                    black_box(100_000_000.0),
+                    black_box(&mut output_emv),
+                    black_box(&mut output_emv_raw),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_emv);
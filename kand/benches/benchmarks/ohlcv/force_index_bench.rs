@@ -0,0 +1,33 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::ohlcv::force_index::force_index;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_force_index(c: &mut Criterion) {
+    let mut group = c.benchmark_group("force_index");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+
+    for size in sizes {
+        let close = generate_test_data(size);
+        let volume = generate_test_data(size);
+        let mut output_force_index = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = force_index(
+                    black_box(&close),
+                    black_box(&volume),
+                    black_box(13),
+                    black_box(&mut output_force_index),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_force_index);
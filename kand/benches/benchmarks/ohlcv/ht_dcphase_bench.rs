@@ -1,31 +1,23 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
 use kand::ohlcv::ht_dcphase::ht_dcphase;
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_ht_dcphase(c: &mut Criterion) {
     let mut group = c.benchmark_group("ht_dcphase");
 
-    // Test different data sizes
     let sizes = vec![100_000, 1_000_000, 10_000_000];
-    let periods = vec![5, 50, 200];
 
     for size in sizes {
         let input = generate_test_data(size);
-        let mut output = vec![0.0; size];
+        let mut output_dcphase = vec![0.0; size];
 
-        for period in &periods {
-            group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
-                period,
-                |b, &period| {
-                    b.iter(|| {
-                        let _ = ht_dcphase(black_box(&input), black_box(period), black_box(&mut output));
-                    });
-                },
-            );
-        }
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = ht_dcphase(black_box(&input), black_box(&mut output_dcphase));
+            });
+        });
     }
 
     group.finish();
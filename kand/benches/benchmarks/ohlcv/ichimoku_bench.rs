@@ -0,0 +1,47 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::ohlcv::ichimoku::ichimoku;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_ichimoku(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ichimoku");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let displacement = 26;
+
+    for size in sizes {
+        let input_close = generate_test_data(size);
+        let input_high: Vec<_> = input_close.iter().map(|close| close + 0.5).collect();
+        let input_low: Vec<_> = input_close.iter().map(|close| close - 0.5).collect();
+        let mut output_tenkan = vec![0.0; size];
+        let mut output_kijun = vec![0.0; size];
+        let mut output_senkou_a = vec![0.0; size + displacement];
+        let mut output_senkou_b = vec![0.0; size + displacement];
+        let mut output_chikou = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = ichimoku(
+                    black_box(&input_high),
+                    black_box(&input_low),
+                    black_box(&input_close),
+                    black_box(9),
+                    black_box(26),
+                    black_box(52),
+                    black_box(displacement),
+                    black_box(&mut output_tenkan),
+                    black_box(&mut output_kijun),
+                    black_box(&mut output_senkou_a),
+                    black_box(&mut output_senkou_b),
+                    black_box(&mut output_chikou),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_ichimoku);
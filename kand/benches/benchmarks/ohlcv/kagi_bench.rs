@@ -0,0 +1,53 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::{ohlcv::kagi::kagi, types::PriceSource};
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_kagi(c: &mut Criterion) {
+    let mut group = c.benchmark_group("kagi");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let reversals = vec![1.0, 5.0, 20.0];
+
+    for size in sizes {
+        let input_close = generate_test_data(size);
+        let input_high: Vec<_> = input_close.iter().map(|close| close + 0.5).collect();
+        let input_low: Vec<_> = input_close.iter().map(|close| close - 0.5).collect();
+        let mut output_start = Vec::new();
+        let mut output_end = Vec::new();
+        let mut output_direction = Vec::new();
+        let mut output_thickness = Vec::new();
+        let mut output_start_index = Vec::new();
+        let mut output_end_index = Vec::new();
+
+        for reversal in &reversals {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), reversal),
+                reversal,
+                |b, &reversal| {
+                    b.iter(|| {
+                        let _ = kagi(
+                            black_box(&input_high),
+                            black_box(&input_low),
+                            black_box(&input_close),
+                            black_box(reversal),
+                            black_box(PriceSource::HighLow),
+                            black_box(&mut output_start),
+                            black_box(&mut output_end),
+                            black_box(&mut output_direction),
+                            black_box(&mut output_thickness),
+                            black_box(&mut output_start_index),
+                            black_box(&mut output_end_index),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_kagi);
@@ -1,7 +1,7 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
 use kand::ohlcv::kama::kama;
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_kama(c: &mut Criterion) {
@@ -13,15 +13,21 @@ fn bench_kama(c: &mut Criterion) {
 
     for size in sizes {
         let input = generate_test_data(size);
-        let mut output = vec![0.0; size];
+        let mut output_kama = vec![0.0; size];
+        let mut output_sum_roc = vec![0.0; size];
 
         for period in &periods {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
+                BenchmarkId::new(format!("size_{size}"), period),
                 period,
                 |b, &period| {
                     b.iter(|| {
-                        let _ = kama(black_box(&input), black_box(period), black_box(&mut output));
+                        let _ = kama(
+                            black_box(&input),
+                            black_box(period),
+                            black_box(&mut output_kama),
+                            black_box(&mut output_sum_roc),
+                        );
                     });
                 },
             );
@@ -0,0 +1,44 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::ohlcv::line_break::line_break;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_line_break(c: &mut Criterion) {
+    let mut group = c.benchmark_group("line_break");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let lines = vec![2, 3, 10];
+
+    for size in sizes {
+        let input_close = generate_test_data(size);
+        let mut output_open = Vec::new();
+        let mut output_close = Vec::new();
+        let mut output_direction = Vec::new();
+        let mut output_index = Vec::new();
+
+        for lines in &lines {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), lines),
+                lines,
+                |b, &lines| {
+                    b.iter(|| {
+                        let _ = line_break(
+                            black_box(&input_close),
+                            black_box(lines),
+                            black_box(&mut output_open),
+                            black_box(&mut output_close),
+                            black_box(&mut output_direction),
+                            black_box(&mut output_index),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_line_break);
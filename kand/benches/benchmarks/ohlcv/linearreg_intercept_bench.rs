@@ -1,7 +1,7 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
 use kand::ohlcv::linearreg_intercept::linearreg_intercept;
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_linearreg_intercept(c: &mut Criterion) {
@@ -13,15 +13,23 @@ fn bench_linearreg_intercept(c: &mut Criterion) {
 
     for size in sizes {
         let input = generate_test_data(size);
-        let mut output = vec![0.0; size];
+        let mut output_intercept = vec![0.0; size];
+        let mut output_sum_y = vec![0.0; size];
+        let mut output_sum_xy = vec![0.0; size];
 
         for period in &periods {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
+                BenchmarkId::new(format!("size_{size}"), period),
                 period,
                 |b, &period| {
                     b.iter(|| {
-                        let _ = linearreg_intercept(black_box(&input), black_box(period), black_box(&mut output));
+                        let _ = linearreg_intercept(
+                            black_box(&input),
+                            black_box(period),
+                            black_box(&mut output_intercept),
+                            black_box(&mut output_sum_y),
+                            black_box(&mut output_sum_xy),
+                        );
                     });
                 },
             );
@@ -0,0 +1,46 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::{ohlcv::ma::ma, types::MAType};
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_ma(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ma");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let ma_types = vec![
+        MAType::SMA,
+        MAType::EMA,
+        MAType::TEMA,
+        MAType::KAMA,
+        MAType::MAMA,
+    ];
+    let period = 30;
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output_ma = vec![0.0; size];
+
+        for ma_type in &ma_types {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), format!("{ma_type:?}")),
+                ma_type,
+                |b, &ma_type| {
+                    b.iter(|| {
+                        let _ = ma(
+                            black_box(&input),
+                            black_box(period),
+                            black_box(ma_type),
+                            black_box(&mut output_ma),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_ma);
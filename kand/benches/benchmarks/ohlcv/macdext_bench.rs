@@ -1,7 +1,7 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
-use kand::ohlcv::macdext::macdext;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::{ohlcv::macdext::macdext, types::MAType};
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_macdext(c: &mut Criterion) {
@@ -9,19 +9,36 @@ fn bench_macdext(c: &mut Criterion) {
 
     // Test different data sizes
     let sizes = vec![100_000, 1_000_000, 10_000_000];
-    let periods = vec![5, 50, 200];
+    let ma_types = vec![MAType::EMA, MAType::SMA, MAType::TEMA];
 
     for size in sizes {
         let input = generate_test_data(size);
-        let mut output = vec![0.0; size];
+        let mut output_macd_line = vec![0.0; size];
+        let mut output_signal_line = vec![0.0; size];
+        let mut output_histogram = vec![0.0; size];
+        let mut output_fast_ma = vec![0.0; size];
+        let mut output_slow_ma = vec![0.0; size];
 
-        for period in &periods {
+        for ma_type in &ma_types {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
-                period,
-                |b, &period| {
+                BenchmarkId::new(format!("size_{size}"), format!("{ma_type:?}")),
+                ma_type,
+                |b, &ma_type| {
                     b.iter(|| {
-                        let _ = macdext(black_box(&input), black_box(period), black_box(&mut output));
+                        let _ = macdext(
+                            black_box(&input),
+                            black_box(12),
+                            black_box(ma_type),
+                            black_box(26),
+                            black_box(ma_type),
+                            black_box(9),
+                            black_box(ma_type),
+                            black_box(&mut output_macd_line),
+                            black_box(&mut output_signal_line),
+                            black_box(&mut output_histogram),
+                            black_box(&mut output_fast_ma),
+                            black_box(&mut output_slow_ma),
+                        );
                     });
                 },
             );
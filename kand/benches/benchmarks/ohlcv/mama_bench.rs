@@ -1,7 +1,7 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
 use kand::ohlcv::mama::mama;
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_mama(c: &mut Criterion) {
@@ -9,19 +9,26 @@ fn bench_mama(c: &mut Criterion) {
 
     // Test different data sizes
     let sizes = vec![100_000, 1_000_000, 10_000_000];
-    let periods = vec![5, 50, 200];
+    let limits = vec![(0.5, 0.05), (0.9, 0.1)];
 
     for size in sizes {
         let input = generate_test_data(size);
-        let mut output = vec![0.0; size];
+        let mut output_mama = vec![0.0; size];
+        let mut output_fama = vec![0.0; size];
 
-        for period in &periods {
+        for (fast_limit, slow_limit) in &limits {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
-                period,
-                |b, &period| {
+                BenchmarkId::new(format!("size_{size}"), format!("{fast_limit}-{slow_limit}")),
+                &(*fast_limit, *slow_limit),
+                |b, &(fast_limit, slow_limit)| {
                     b.iter(|| {
-                        let _ = mama(black_box(&input), black_box(period), black_box(&mut output));
+                        let _ = mama(
+                            black_box(&input),
+                            black_box(fast_limit),
+                            black_box(slow_limit),
+                            black_box(&mut output_mama),
+                            black_box(&mut output_fama),
+                        );
                     });
                 },
             );
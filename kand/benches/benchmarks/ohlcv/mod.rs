@@ -3,7 +3,7 @@
 pub mod adr_bench;
 pub mod adx_bench;
 pub mod adxr_bench;
-// pub mod apo_bench;
+pub mod apo_bench;
 pub mod aroon_bench;
 pub mod aroonosc_bench;
 pub mod atr_bench;
@@ -18,25 +18,34 @@
 pub mod cdl_long_shadow_bench;
 pub mod cdl_marubozu_bench;
 // pub mod cdl_spinning_top_bench;
-// pub mod cmo_bench;
+pub mod cmf_bench;
+pub mod cmo_bench;
 pub mod dema_bench;
+pub mod donchian_bench;
 pub mod dx_bench;
 pub mod ecl_bench;
 pub mod ema_bench;
-// pub mod ht_dcperiod_bench;
-// pub mod ht_dcphase_bench;
-// pub mod ht_phasor_bench;
-// pub mod ht_sine_bench;
-// pub mod ht_trendline_bench;
-// pub mod ht_trendmode_bench;
-// pub mod kama_bench;
-// pub mod linearreg_angle_bench;
-// pub mod linearreg_bench;
-// pub mod linearreg_intercept_bench;
-// pub mod linearreg_slope_bench;
+pub mod emv_bench;
+pub mod force_index_bench;
+pub mod ht_dcperiod_bench;
+pub mod ht_dcphase_bench;
+pub mod ht_phasor_bench;
+pub mod ht_sine_bench;
+pub mod ht_trendline_bench;
+pub mod ht_trendmode_bench;
+pub mod ichimoku_bench;
+pub mod kagi_bench;
+pub mod kama_bench;
+pub mod keltner_bench;
+pub mod line_break_bench;
+pub mod linearreg_angle_bench;
+pub mod linearreg_bench;
+pub mod linearreg_intercept_bench;
+pub mod linearreg_slope_bench;
+pub mod ma_bench;
 pub mod macd_bench;
-// pub mod macdext_bench;
-// pub mod mama_bench;
+pub mod macdext_bench;
+pub mod mama_bench;
 pub mod medprice_bench;
 pub mod mfi_bench;
 pub mod midpoint_bench;
@@ -48,7 +57,11 @@
 pub mod obv_bench;
 pub mod plus_di_bench;
 pub mod plus_dm_bench;
-// pub mod ppo_bench;
+pub mod pnf_bench;
+pub mod ppo_bench;
+pub mod pvt_bench;
+pub mod range_bars_bench;
+pub mod renko_bench;
 pub mod rma_bench;
 pub mod roc_bench;
 pub mod rocp_bench;
@@ -56,21 +69,23 @@
 pub mod rocr_bench;
 pub mod rsi_bench;
 pub mod sar_bench;
-// pub mod sarext_bench;
+pub mod sarext_bench;
 pub mod sma_bench;
+pub mod starc_bench;
 pub mod stoch_bench;
-// pub mod stochf_bench;
-// pub mod stochrsi_bench;
+pub mod stochf_bench;
+pub mod stochrsi_bench;
 pub mod supertrend_bench;
 pub mod t3_bench;
 pub mod tema_bench;
 pub mod trange_bench;
 pub mod trima_bench;
 pub mod trix_bench;
-// pub mod tsf_bench;
+pub mod tsf_bench;
 pub mod typprice_bench;
-// pub mod ultosc_bench;
+pub mod ultosc_bench;
 pub mod vegas_bench;
+pub mod vwap_bands_bench;
 pub mod vwap_bench;
 pub mod wclprice_bench;
 pub mod willr_bench;
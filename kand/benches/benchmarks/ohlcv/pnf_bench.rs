@@ -0,0 +1,52 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::{ohlcv::pnf::pnf, types::PriceSource};
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_pnf(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pnf");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let box_sizes = vec![0.5, 2.0, 10.0];
+
+    for size in sizes {
+        let input_close = generate_test_data(size);
+        let input_high: Vec<_> = input_close.iter().map(|close| close + 0.5).collect();
+        let input_low: Vec<_> = input_close.iter().map(|close| close - 0.5).collect();
+        let mut output_direction = Vec::new();
+        let mut output_low = Vec::new();
+        let mut output_high = Vec::new();
+        let mut output_start_index = Vec::new();
+        let mut output_end_index = Vec::new();
+
+        for box_size in &box_sizes {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), box_size),
+                box_size,
+                |b, &box_size| {
+                    b.iter(|| {
+                        let _ = pnf(
+                            black_box(&input_high),
+                            black_box(&input_low),
+                            black_box(&input_close),
+                            black_box(box_size),
+                            black_box(3),
+                            black_box(PriceSource::HighLow),
+                            black_box(&mut output_direction),
+                            black_box(&mut output_low),
+                            black_box(&mut output_high),
+                            black_box(&mut output_start_index),
+                            black_box(&mut output_end_index),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_pnf);
@@ -0,0 +1,48 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::ohlcv::range_bars::range_bars;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_range_bars(c: &mut Criterion) {
+    let mut group = c.benchmark_group("range_bars");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let ranges = vec![0.5, 2.0, 10.0];
+
+    for size in sizes {
+        let input_close = generate_test_data(size);
+        let mut output_open = Vec::new();
+        let mut output_high = Vec::new();
+        let mut output_low = Vec::new();
+        let mut output_close = Vec::new();
+        let mut output_start_index = Vec::new();
+        let mut output_end_index = Vec::new();
+
+        for range in &ranges {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), range),
+                range,
+                |b, &range| {
+                    b.iter(|| {
+                        let _ = range_bars(
+                            black_box(&input_close),
+                            black_box(range),
+                            black_box(&mut output_open),
+                            black_box(&mut output_high),
+                            black_box(&mut output_low),
+                            black_box(&mut output_close),
+                            black_box(&mut output_start_index),
+                            black_box(&mut output_end_index),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_range_bars);
@@ -0,0 +1,50 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::{ohlcv::renko::renko, types::PriceSource};
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_renko(c: &mut Criterion) {
+    let mut group = c.benchmark_group("renko");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let box_sizes = vec![0.5, 2.0, 10.0];
+
+    for size in sizes {
+        let input_close = generate_test_data(size);
+        let input_high: Vec<_> = input_close.iter().map(|close| close + 0.5).collect();
+        let input_low: Vec<_> = input_close.iter().map(|close| close - 0.5).collect();
+        let mut output_open = Vec::new();
+        let mut output_close = Vec::new();
+        let mut output_direction = Vec::new();
+        let mut output_index = Vec::new();
+
+        for box_size in &box_sizes {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), box_size),
+                box_size,
+                |b, &box_size| {
+                    b.iter(|| {
+                        let _ = renko(
+                            black_box(&input_high),
+                            black_box(&input_low),
+                            black_box(&input_close),
+                            black_box(box_size),
+                            black_box(2),
+                            black_box(PriceSource::HighLow),
+                            black_box(&mut output_open),
+                            black_box(&mut output_close),
+                            black_box(&mut output_direction),
+                            black_box(&mut output_index),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_renko);
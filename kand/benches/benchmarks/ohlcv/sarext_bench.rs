@@ -1,31 +1,44 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
 use kand::ohlcv::sarext::sarext;
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_sarext(c: &mut Criterion) {
     let mut group = c.benchmark_group("sarext");
 
-    // Test different data sizes
     let sizes = vec![100_000, 1_000_000, 10_000_000];
-    let periods = vec![5, 50, 200];
 
     for size in sizes {
-        let input = generate_test_data(size);
-        let mut output = vec![0.0; size];
+        let input_high = generate_test_data(size);
+        let input_low = generate_test_data(size);
+        let mut output_sarext = vec![0.0; size];
+        let mut output_next_sar = vec![0.0; size];
+        let mut output_is_long = vec![false; size];
+        let mut output_af = vec![0.0; size];
+        let mut output_ep = vec![0.0; size];
 
-        for period in &periods {
-            group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
-                period,
-                |b, &period| {
-                    b.iter(|| {
-                        let _ = sarext(black_box(&input), black_box(period), black_box(&mut output));
-                    });
-                },
-            );
-        }
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = sarext(
+                    black_box(&input_high),
+                    black_box(&input_low),
+                    black_box(0.0),
+                    black_box(0.0),
+                    black_box(0.02),
+                    black_box(0.02),
+                    black_box(0.2),
+                    black_box(0.02),
+                    black_box(0.02),
+                    black_box(0.2),
+                    black_box(&mut output_sarext),
+                    black_box(&mut output_next_sar),
+                    black_box(&mut output_is_long),
+                    black_box(&mut output_af),
+                    black_box(&mut output_ep),
+                );
+            });
+        });
     }
 
     group.finish();
@@ -1,5 +1,5 @@
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
-use kand::ohlcv::stoch::stoch;
+use kand::{ohlcv::stoch::stoch, types::MAType};
 
 use crate::helper::generate_test_data;
 
@@ -40,7 +40,9 @@ fn bench_stoch(c: &mut Criterion) {
                             black_box(&input_close),
                             black_box(k_period),
                             black_box(k_slow_period),
+                            black_box(MAType::SMA),
                             black_box(d_period),
+                            black_box(MAType::SMA),
                             black_box(&mut output_fast_k),
                             black_box(&mut output_k),
                             black_box(&mut output_d),
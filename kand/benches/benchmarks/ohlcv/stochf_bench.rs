@@ -1,27 +1,42 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
-use kand::ohlcv::stochf::stochf;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::{ohlcv::stochf::stochf, types::MAType};
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_stochf(c: &mut Criterion) {
     let mut group = c.benchmark_group("stochf");
 
-    // Test different data sizes
     let sizes = vec![100_000, 1_000_000, 10_000_000];
-    let periods = vec![5, 50, 200];
+    let k_periods = [5, 14, 30];
+    let d_periods = vec![3, 5, 9];
 
     for size in sizes {
-        let input = generate_test_data(size);
-        let mut output = vec![0.0; size];
+        let input_high = generate_test_data(size);
+        let input_low = generate_test_data(size);
+        let input_close = generate_test_data(size);
+        let mut output_fast_k = vec![0.0; size];
+        let mut output_fast_d = vec![0.0; size];
 
-        for period in &periods {
+        for (&k_period, &d_period) in k_periods.iter().zip(&d_periods) {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
-                period,
-                |b, &period| {
+                BenchmarkId::new(
+                    format!("size_{size}_k{k_period}_d{d_period}"),
+                    format!("{k_period}-{d_period}"),
+                ),
+                &(k_period, d_period),
+                |b, &(k_period, d_period)| {
                     b.iter(|| {
-                        let _ = stochf(black_box(&input), black_box(period), black_box(&mut output));
+                        let _ = stochf(
+                            black_box(&input_high),
+                            black_box(&input_low),
+                            black_box(&input_close),
+                            black_box(k_period),
+                            black_box(d_period),
+                            black_box(MAType::SMA),
+                            black_box(&mut output_fast_k),
+                            black_box(&mut output_fast_d),
+                        );
                     });
                 },
             );
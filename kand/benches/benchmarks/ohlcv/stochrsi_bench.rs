@@ -1,27 +1,41 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
-use kand::ohlcv::stochrsi::stochrsi;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::{ohlcv::stochrsi::stochrsi, types::MAType};
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_stochrsi(c: &mut Criterion) {
     let mut group = c.benchmark_group("stochrsi");
 
-    // Test different data sizes
     let sizes = vec![100_000, 1_000_000, 10_000_000];
-    let periods = vec![5, 50, 200];
+    let periods = vec![5, 14, 30];
 
     for size in sizes {
         let input = generate_test_data(size);
-        let mut output = vec![0.0; size];
+        let mut output_fast_k = vec![0.0; size];
+        let mut output_fast_d = vec![0.0; size];
+        let mut output_rsi = vec![0.0; size];
+        let mut output_avg_gain = vec![0.0; size];
+        let mut output_avg_loss = vec![0.0; size];
 
         for period in &periods {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
+                BenchmarkId::new(format!("size_{size}"), period),
                 period,
                 |b, &period| {
                     b.iter(|| {
-                        let _ = stochrsi(black_box(&input), black_box(period), black_box(&mut output));
+                        let _ = stochrsi(
+                            black_box(&input),
+                            black_box(period),
+                            black_box(period),
+                            black_box(3),
+                            black_box(MAType::SMA),
+                            black_box(&mut output_fast_k),
+                            black_box(&mut output_fast_d),
+                            black_box(&mut output_rsi),
+                            black_box(&mut output_avg_gain),
+                            black_box(&mut output_avg_loss),
+                        );
                     });
                 },
             );
@@ -1,7 +1,7 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
 use kand::ohlcv::ultosc::ultosc;
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_ultosc(c: &mut Criterion) {
@@ -12,16 +12,26 @@ fn bench_ultosc(c: &mut Criterion) {
     let periods = vec![5, 50, 200];
 
     for size in sizes {
-        let input = generate_test_data(size);
+        let input_high = generate_test_data(size);
+        let input_low = generate_test_data(size);
+        let input_close = generate_test_data(size);
         let mut output = vec![0.0; size];
 
         for period in &periods {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
+                BenchmarkId::new(format!("size_{size}"), period),
                 period,
                 |b, &period| {
                     b.iter(|| {
-                        let _ = ultosc(black_box(&input), black_box(period), black_box(&mut output));
+                        let _ = ultosc(
+                            black_box(&input_high),
+                            black_box(&input_low),
+                            black_box(&input_close),
+                            black_box(period),
+                            black_box(period * 2),
+                            black_box(period * 4),
+                            black_box(&mut output),
+                        );
                     });
                 },
             );
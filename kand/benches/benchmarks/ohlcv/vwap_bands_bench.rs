@@ -0,0 +1,56 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::ohlcv::vwap_bands::session_vwap;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_session_vwap(c: &mut Criterion) {
+    let mut group = c.benchmark_group("session_vwap");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let sessions = vec![3_600_000, 86_400_000];
+
+    for size in sizes {
+        let input_close = generate_test_data(size);
+        let input_timestamp: Vec<i64> = (0..size as i64).map(|i| i * 60_000).collect();
+        let input_high: Vec<_> = input_close.iter().map(|close| close + 0.5).collect();
+        let input_low: Vec<_> = input_close.iter().map(|close| close - 0.5).collect();
+        let input_volume = generate_test_data(size);
+        let mut output_vwap = vec![0.0; size];
+        let mut output_upper = vec![0.0; size];
+        let mut output_lower = vec![0.0; size];
+        let mut output_cum_sq_dev = vec![0.0; size];
+        let mut output_cum_vol = vec![0.0; size];
+
+        for session in &sessions {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), session),
+                session,
+                |b, &session| {
+                    b.iter(|| {
+                        let _ = session_vwap(
+                            black_box(&input_timestamp),
+                            black_box(&input_high),
+                            black_box(&input_low),
+                            black_box(&input_close),
+                            black_box(&input_volume),
+                            black_box(session),
+                            black_box(0),
+                            black_box(2.0),
+                            black_box(&mut output_vwap),
+                            black_box(&mut output_upper),
+                            black_box(&mut output_lower),
+                            black_box(&mut output_cum_sq_dev),
+                            black_box(&mut output_cum_vol),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(ohlcv, bench_session_vwap);
@@ -0,0 +1,52 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::stats::alpha::alpha;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_alpha(c: &mut Criterion) {
+    let mut group = c.benchmark_group("alpha");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let periods = vec![5, 50, 200];
+
+    for size in sizes {
+        let input_a = generate_test_data(size);
+        let input_b = generate_test_data(size);
+        let mut output = vec![0.0; size];
+        let mut output_beta = vec![0.0; size];
+        let mut output_sum_a = vec![0.0; size];
+        let mut output_sum_b = vec![0.0; size];
+        let mut output_sum_bb = vec![0.0; size];
+        let mut output_sum_ab = vec![0.0; size];
+
+        for period in &periods {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), period),
+                period,
+                |b, &period| {
+                    b.iter(|| {
+                        let _ = alpha(
+                            black_box(&input_a),
+                            black_box(&input_b),
+                            black_box(period),
+                            black_box(252.0),
+                            black_box(0.02),
+                            black_box(&mut output),
+                            black_box(&mut output_beta),
+                            black_box(&mut output_sum_a),
+                            black_box(&mut output_sum_b),
+                            black_box(&mut output_sum_bb),
+                            black_box(&mut output_sum_ab),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_alpha);
@@ -1,7 +1,7 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
-use kand::ohlcv::beta::beta;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::stats::beta::beta;
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_beta(c: &mut Criterion) {
@@ -12,16 +12,30 @@ fn bench_beta(c: &mut Criterion) {
     let periods = vec![5, 50, 200];
 
     for size in sizes {
-        let input = generate_test_data(size);
+        let input_a = generate_test_data(size);
+        let input_b = generate_test_data(size);
         let mut output = vec![0.0; size];
+        let mut output_sum_a = vec![0.0; size];
+        let mut output_sum_b = vec![0.0; size];
+        let mut output_sum_bb = vec![0.0; size];
+        let mut output_sum_ab = vec![0.0; size];
 
         for period in &periods {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
+                BenchmarkId::new(format!("size_{size}"), period),
                 period,
                 |b, &period| {
                     b.iter(|| {
-                        let _ = beta(black_box(&input), black_box(period), black_box(&mut output));
+                        let _ = beta(
+                            black_box(&input_a),
+                            black_box(&input_b),
+                            black_box(period),
+                            black_box(&mut output),
+                            black_box(&mut output_sum_a),
+                            black_box(&mut output_sum_b),
+                            black_box(&mut output_sum_bb),
+                            black_box(&mut output_sum_ab),
+                        );
                     });
                 },
             );
@@ -31,4 +45,4 @@ fn bench_beta(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(ohlcv, bench_beta);
+criterion_group!(stats, bench_beta);
@@ -0,0 +1,44 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::stats::calmar::calmar;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_calmar(c: &mut Criterion) {
+    let mut group = c.benchmark_group("calmar");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let periods = vec![5, 50, 200];
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output = vec![0.0; size];
+        let mut output_drawdown = vec![0.0; size];
+        let mut output_max_drawdown = vec![0.0; size];
+
+        for period in &periods {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), period),
+                period,
+                |b, &period| {
+                    b.iter(|| {
+                        let _ = calmar(
+                            black_box(&input),
+                            black_box(period),
+                            black_box(252.0),
+                            black_box(0.02),
+                            black_box(&mut output),
+                            black_box(&mut output_drawdown),
+                            black_box(&mut output_max_drawdown),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_calmar);
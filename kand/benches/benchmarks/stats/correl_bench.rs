@@ -1,7 +1,7 @@
-use criterion::{black_box, criterion_group, BenchmarkId, Criterion};
-use kand::ohlcv::correl::correl;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::stats::correl::correl;
 
-use crate::helpers::generate_test_data;
+use crate::helper::generate_test_data;
 
 #[allow(dead_code)]
 fn bench_correl(c: &mut Criterion) {
@@ -12,16 +12,32 @@ fn bench_correl(c: &mut Criterion) {
     let periods = vec![5, 50, 200];
 
     for size in sizes {
-        let input = generate_test_data(size);
+        let input_a = generate_test_data(size);
+        let input_b = generate_test_data(size);
         let mut output = vec![0.0; size];
+        let mut output_sum_x = vec![0.0; size];
+        let mut output_sum_y = vec![0.0; size];
+        let mut output_sum_xx = vec![0.0; size];
+        let mut output_sum_yy = vec![0.0; size];
+        let mut output_sum_xy = vec![0.0; size];
 
         for period in &periods {
             group.bench_with_input(
-                BenchmarkId::new(format!("size_{}", size), period),
+                BenchmarkId::new(format!("size_{size}"), period),
                 period,
                 |b, &period| {
                     b.iter(|| {
-                        let _ = correl(black_box(&input), black_box(period), black_box(&mut output));
+                        let _ = correl(
+                            black_box(&input_a),
+                            black_box(&input_b),
+                            black_box(period),
+                            black_box(&mut output),
+                            black_box(&mut output_sum_x),
+                            black_box(&mut output_sum_y),
+                            black_box(&mut output_sum_xx),
+                            black_box(&mut output_sum_yy),
+                            black_box(&mut output_sum_xy),
+                        );
                     });
                 },
             );
@@ -31,4 +47,4 @@ fn bench_correl(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(ohlcv, bench_correl);
+criterion_group!(stats, bench_correl);
@@ -0,0 +1,35 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::{stats::fv::fv, types::PaymentTiming};
+
+#[allow(dead_code)]
+fn bench_fv(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fv");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+
+    for size in sizes {
+        let input_rate = vec![0.05 / 12.0; size];
+        let input_nper = vec![120.0; size];
+        let input_pmt = vec![-100.0; size];
+        let input_pv = vec![-100.0; size];
+        let mut output = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = fv(
+                    black_box(&input_rate),
+                    black_box(&input_nper),
+                    black_box(&input_pmt),
+                    black_box(&input_pv),
+                    black_box(PaymentTiming::End),
+                    black_box(&mut output),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_fv);
@@ -0,0 +1,49 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::stats::kelly::kelly;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_kelly(c: &mut Criterion) {
+    let mut group = c.benchmark_group("kelly");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let periods = vec![20, 50, 200];
+
+    for size in sizes {
+        // Price changes stand in for per-trade PnL
+        let prices = generate_test_data(size + 1);
+        let input: Vec<_> = prices.windows(2).map(|w| w[1] - w[0]).collect();
+        let mut output_kelly = vec![0.0; size];
+        let mut output_wins = vec![0.0; size];
+        let mut output_losses = vec![0.0; size];
+        let mut output_gross_profit = vec![0.0; size];
+        let mut output_gross_loss = vec![0.0; size];
+
+        for period in &periods {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), period),
+                period,
+                |b, &period| {
+                    b.iter(|| {
+                        let _ = kelly(
+                            black_box(&input),
+                            black_box(period),
+                            black_box(1.0),
+                            black_box(&mut output_kelly),
+                            black_box(&mut output_wins),
+                            black_box(&mut output_losses),
+                            black_box(&mut output_gross_profit),
+                            black_box(&mut output_gross_loss),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_kelly);
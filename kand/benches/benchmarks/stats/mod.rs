@@ -1,5 +1,20 @@
+pub mod alpha_bench;
+pub mod beta_bench;
+pub mod calmar_bench;
+pub mod correl_bench;
+pub mod drawdown_bench;
+pub mod fv_bench;
+pub mod kelly_bench;
 pub mod max_bench;
 pub mod min_bench;
+pub mod nper_bench;
+pub mod pmt_bench;
+pub mod pv_bench;
+pub mod rate_bench;
+pub mod ret_bench;
+pub mod sharpe_bench;
+pub mod sortino_bench;
 pub mod stddev_bench;
 pub mod sum_bench;
 pub mod var_bench;
+pub mod winrate_bench;
@@ -0,0 +1,38 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::{stats::rate::rate, types::PaymentTiming};
+
+#[allow(dead_code)]
+fn bench_rate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("rate");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+
+    for size in sizes {
+        let input_nper = vec![180.0; size];
+        let input_pmt = vec![-1854.02; size];
+        let input_pv = vec![200_000.0; size];
+        let input_fv = vec![0.0; size];
+        let mut output = vec![0.0; size];
+
+        group.bench_with_input(BenchmarkId::new("size", size), &size, |b, &_size| {
+            b.iter(|| {
+                let _ = rate(
+                    black_box(&input_nper),
+                    black_box(&input_pmt),
+                    black_box(&input_pv),
+                    black_box(&input_fv),
+                    black_box(PaymentTiming::End),
+                    black_box(0.1),
+                    black_box(1e-6),
+                    black_box(100),
+                    black_box(&mut output),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_rate);
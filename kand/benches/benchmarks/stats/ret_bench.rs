@@ -0,0 +1,34 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::stats::ret::ret;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_ret(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ret");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let periods = vec![1, 5, 20];
+
+    for size in sizes {
+        let input = generate_test_data(size);
+        let mut output = vec![0.0; size];
+
+        for period in &periods {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), period),
+                period,
+                |b, &period| {
+                    b.iter(|| {
+                        let _ = ret(black_box(&input), black_box(period), black_box(&mut output));
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_ret);
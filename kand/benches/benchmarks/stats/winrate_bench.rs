@@ -0,0 +1,56 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group};
+use kand::stats::winrate::winrate;
+
+use crate::helper::generate_test_data;
+
+#[allow(dead_code)]
+fn bench_winrate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("winrate");
+
+    // Test different data sizes
+    let sizes = vec![100_000, 1_000_000, 10_000_000];
+    let periods = vec![20, 50, 200];
+
+    for size in sizes {
+        // Price changes stand in for per-trade PnL
+        let prices = generate_test_data(size + 1);
+        let input: Vec<_> = prices.windows(2).map(|w| w[1] - w[0]).collect();
+        let mut output_winrate = vec![0.0; size];
+        let mut output_avg_win = vec![0.0; size];
+        let mut output_avg_loss = vec![0.0; size];
+        let mut output_profit_factor = vec![0.0; size];
+        let mut output_expectancy = vec![0.0; size];
+        let mut output_wins = vec![0.0; size];
+        let mut output_losses = vec![0.0; size];
+        let mut output_gross_profit = vec![0.0; size];
+        let mut output_gross_loss = vec![0.0; size];
+
+        for period in &periods {
+            group.bench_with_input(
+                BenchmarkId::new(format!("size_{size}"), period),
+                period,
+                |b, &period| {
+                    b.iter(|| {
+                        let _ = winrate(
+                            black_box(&input),
+                            black_box(period),
+                            black_box(&mut output_winrate),
+                            black_box(&mut output_avg_win),
+                            black_box(&mut output_avg_loss),
+                            black_box(&mut output_profit_factor),
+                            black_box(&mut output_expectancy),
+                            black_box(&mut output_wins),
+                            black_box(&mut output_losses),
+                            black_box(&mut output_gross_profit),
+                            black_box(&mut output_gross_loss),
+                        );
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(stats, bench_winrate);
@@ -83,7 +83,7 @@
 #![allow(clippy::similar_names, clippy::too_many_lines)]
 
 pub mod ta;
-pub use ta::*;
+pub use ta::{indicator::Indicator, *};
 
 pub mod helper;
 
@@ -0,0 +1,4 @@
+pub mod mtf;
+pub mod resample;
+pub mod series;
+pub mod trade_bars;
@@ -0,0 +1,165 @@
+use crate::{KandError, TAFloat};
+
+/// Aligns values computed on a higher time frame onto a lower time frame without look-ahead.
+///
+/// # Description
+/// A higher time frame bar is only known once it has closed, that is at the end of its
+/// interval. Each lower time frame bar therefore receives the value of the latest higher
+/// time frame bar that had closed by the end of the lower bar; bars before the first close
+/// receive NaN. Both time frames use bar start times, as produced by
+/// [`resample`](super::resample::resample).
+///
+/// # Mathematical Formula
+/// ```text
+/// output[i] = htf_value[j] for the largest j with
+///             htf_timestamp[j] + htf_interval <= timestamp[i] + interval
+/// ```
+///
+/// # Arguments
+/// * `input_timestamp` - Array of lower time frame bar start times (non-decreasing)
+/// * `param_interval` - Length of the lower time frame bars (must be > 0)
+/// * `input_htf_timestamp` - Array of higher time frame bar start times (strictly increasing)
+/// * `param_htf_interval` - Length of the higher time frame bars (must be > 0)
+/// * `input_htf_value` - Array of values computed on the higher time frame
+/// * `output_value` - Array storing the aligned values, one per lower time frame bar
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If the lower time frame is empty or timestamps are out of order
+/// * `KandError::LengthMismatch` - If the higher time frame arrays or the output have the
+///   wrong length
+/// * `KandError::InvalidParameter` - If an interval is not positive
+///
+/// # Example
+/// ```
+/// use kand::bars::mtf;
+///
+/// // One minute bars and a five minute value per bar
+/// let input_timestamp: Vec<i64> = (0..12).map(|i| i * 60).collect();
+/// let input_htf_timestamp = vec![0, 300, 600];
+/// let input_htf_value = vec![1.0, 2.0, 3.0];
+/// let mut output_value = vec![0.0; 12];
+///
+/// mtf::align(
+///     &input_timestamp,
+///     60,
+///     &input_htf_timestamp,
+///     300,
+///     &input_htf_value,
+///     &mut output_value,
+/// )
+/// .unwrap();
+/// // The first five minute bar is known at the close of the 04:00 bar
+/// assert!(output_value[3].is_nan());
+/// assert_eq!(output_value[4..], [1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0]);
+/// ```
+pub fn align(
+    input_timestamp: &[i64],
+    param_interval: i64,
+    input_htf_timestamp: &[i64],
+    param_htf_interval: i64,
+    input_htf_value: &[TAFloat],
+    output_value: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_timestamp.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Parameter check
+        if param_interval <= 0 || param_htf_interval <= 0 {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_value.len() != len || input_htf_value.len() != input_htf_timestamp.len() {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Timestamp order check
+        if input_timestamp.windows(2).any(|pair| pair[1] < pair[0])
+            || input_htf_timestamp
+                .windows(2)
+                .any(|pair| pair[1] <= pair[0])
+        {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    let mut available = 0;
+    for i in 0..len {
+        let close_time = input_timestamp[i] + param_interval;
+        while available < input_htf_timestamp.len()
+            && input_htf_timestamp[available] + param_htf_interval <= close_time
+        {
+            available += 1;
+        }
+        output_value[i] = if available == 0 {
+            TAFloat::NAN
+        } else {
+            input_htf_value[available - 1]
+        };
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align_no_look_ahead() {
+        // 15 minute bars against hourly values, with a gap in the hourly data
+        let input_timestamp: Vec<i64> = (0..16).map(|i| i * 900).collect();
+        let input_htf_timestamp = [0, 3_600, 10_800];
+        let input_htf_value = [10.0, 20.0, 40.0];
+        let mut output_value = vec![0.0; 16];
+        align(
+            &input_timestamp,
+            900,
+            &input_htf_timestamp,
+            3_600,
+            &input_htf_value,
+            &mut output_value,
+        )
+        .unwrap();
+
+        for (i, value) in output_value.iter().enumerate() {
+            let close_time = input_timestamp[i] + 900;
+            if close_time < 3_600 {
+                assert!(value.is_nan());
+            } else if close_time < 7_200 {
+                assert_eq!(*value, 10.0);
+            } else if close_time < 14_400 {
+                // Without an hourly bar for 02:00, the 01:00 value carries on
+                assert_eq!(*value, 20.0);
+            } else {
+                assert_eq!(*value, 40.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_align_invalid() {
+        let mut output_value = vec![0.0; 2];
+        assert!(matches!(
+            align(&[0, 60], 60, &[0], 0, &[1.0], &mut output_value),
+            Err(KandError::InvalidParameter)
+        ));
+        assert!(matches!(
+            align(&[0, 60], 60, &[0, 300], 300, &[1.0], &mut output_value),
+            Err(KandError::LengthMismatch)
+        ));
+        assert!(matches!(
+            align(&[60, 0], 60, &[0], 300, &[1.0], &mut output_value),
+            Err(KandError::InvalidData)
+        ));
+    }
+}
@@ -0,0 +1,595 @@
+use crate::{Indicator, KandError, TAFloat};
+
+/// A single OHLCV bar on a resampled time frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OhlcvBar {
+    /// Start of the interval covered by the bar
+    pub timestamp: i64,
+    /// Open of the first source bar
+    pub open:      TAFloat,
+    /// Highest high of the source bars
+    pub high:      TAFloat,
+    /// Lowest low of the source bars
+    pub low:       TAFloat,
+    /// Close of the last source bar
+    pub close:     TAFloat,
+    /// Sum of the source volumes
+    pub volume:    TAFloat,
+}
+
+/// Returns the lookback period required for resampling.
+///
+/// # Description
+/// Every source bar is added to a resampled bar right away, so no warm-up is needed.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Always 0
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::bars::resample;
+/// assert_eq!(resample::lookback().unwrap(), 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Validates the interval and the offset.
+fn check_params(param_interval: i64, param_offset: i64) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_interval <= 0 || param_offset < 0 || param_offset >= param_interval {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    let _ = (param_interval, param_offset);
+    Ok(())
+}
+
+/// Returns the start of the interval containing `timestamp`.
+///
+/// Intervals are aligned to the epoch shifted by `param_offset`, e.g. daily bars starting at
+/// 22:00 UTC use an offset of 22 hours.
+///
+/// # Example
+/// ```
+/// use kand::bars::resample;
+///
+/// // 5 minute buckets on millisecond timestamps
+/// assert_eq!(resample::bucket_start(420_000, 300_000, 0), 300_000);
+/// assert_eq!(resample::bucket_start(-1, 300_000, 0), -300_000);
+/// ```
+#[must_use]
+pub const fn bucket_start(timestamp: i64, param_interval: i64, param_offset: i64) -> i64 {
+    (timestamp - param_offset).div_euclid(param_interval) * param_interval + param_offset
+}
+
+/// Resamples OHLCV bars onto a longer time frame.
+///
+/// # Description
+/// Source bars are grouped by the interval their timestamp falls into. Each resampled bar
+/// takes the open of its first source bar, the highest high, the lowest low, the close of its
+/// last source bar and the summed volume. Intervals without source bars produce no bar. The
+/// source timestamps are bar start times, so a resampled bar is complete once the source bar
+/// ending with its interval has closed.
+///
+/// # Mathematical Formula
+/// ```text
+/// bucket = floor((timestamp - offset) / interval) * interval + offset
+/// open = first(open), high = max(high), low = min(low), close = last(close), volume = sum(volume)
+/// ```
+///
+/// # Arguments
+/// * `input_timestamp` - Array of source bar start times (epoch based, strictly increasing)
+/// * `input_open` - Array of open prices
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `input_volume` - Array of volumes
+/// * `param_interval` - Length of the resampled bars in timestamp units (must be > 0)
+/// * `param_offset` - Shift of the interval grid from the epoch (0 <= offset < interval)
+/// * `output_timestamp` - Cleared, then filled with the start time of each resampled bar
+/// * `output_open` - Cleared, then filled with the open of each resampled bar
+/// * `output_high` - Cleared, then filled with the high of each resampled bar
+/// * `output_low` - Cleared, then filled with the low of each resampled bar
+/// * `output_close` - Cleared, then filled with the close of each resampled bar
+/// * `output_volume` - Cleared, then filled with the volume of each resampled bar
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success; the last bar may cover only part of its
+///   interval
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty or timestamps are not increasing
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If the interval is not positive or the offset is outside
+///   `0..interval`
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::bars::resample;
+///
+/// // Six one minute bars (millisecond timestamps) into five minute bars
+/// let input_timestamp = vec![0, 60_000, 120_000, 180_000, 240_000, 300_000];
+/// let input_open = vec![10.0, 10.2, 10.1, 10.4, 10.3, 10.6];
+/// let input_high = vec![10.3, 10.4, 10.5, 10.6, 10.4, 10.9];
+/// let input_low = vec![9.9, 10.0, 10.0, 10.2, 10.1, 10.5];
+/// let input_close = vec![10.2, 10.1, 10.4, 10.3, 10.2, 10.8];
+/// let input_volume = vec![5.0, 3.0, 4.0, 6.0, 2.0, 7.0];
+/// let mut output_timestamp = Vec::new();
+/// let mut output_open = Vec::new();
+/// let mut output_high = Vec::new();
+/// let mut output_low = Vec::new();
+/// let mut output_close = Vec::new();
+/// let mut output_volume = Vec::new();
+///
+/// resample::resample(
+///     &input_timestamp,
+///     &input_open,
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &input_volume,
+///     300_000,
+///     0,
+///     &mut output_timestamp,
+///     &mut output_open,
+///     &mut output_high,
+///     &mut output_low,
+///     &mut output_close,
+///     &mut output_volume,
+/// )
+/// .unwrap();
+/// assert_eq!(output_timestamp, vec![0, 300_000]);
+/// assert_eq!(output_open, vec![10.0, 10.6]);
+/// assert_eq!(output_high, vec![10.6, 10.9]);
+/// assert_eq!(output_low, vec![9.9, 10.5]);
+/// assert_eq!(output_close, vec![10.2, 10.8]);
+/// assert_eq!(output_volume, vec![20.0, 7.0]);
+/// ```
+pub fn resample(
+    input_timestamp: &[i64],
+    input_open: &[TAFloat],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    param_interval: i64,
+    param_offset: i64,
+    output_timestamp: &mut Vec<i64>,
+    output_open: &mut Vec<TAFloat>,
+    output_high: &mut Vec<TAFloat>,
+    output_low: &mut Vec<TAFloat>,
+    output_close: &mut Vec<TAFloat>,
+    output_volume: &mut Vec<TAFloat>,
+) -> Result<(), KandError> {
+    let len = input_timestamp.len();
+    check_params(param_interval, param_offset)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_open.len() != len
+            || input_high.len() != len
+            || input_low.len() != len
+            || input_close.len() != len
+            || input_volume.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Timestamp order check
+        if input_timestamp.windows(2).any(|pair| pair[1] <= pair[0]) {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_open[i].is_nan()
+                || input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+                || input_volume[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut bars = Vec::new();
+    let mut current = None;
+    for i in 0..len {
+        current = Some(resample_inc(
+            input_timestamp[i],
+            input_open[i],
+            input_high[i],
+            input_low[i],
+            input_close[i],
+            input_volume[i],
+            current,
+            param_interval,
+            param_offset,
+            &mut bars,
+        )?);
+    }
+    bars.extend(current);
+
+    output_timestamp.clear();
+    output_open.clear();
+    output_high.clear();
+    output_low.clear();
+    output_close.clear();
+    output_volume.clear();
+    for bar in bars {
+        output_timestamp.push(bar.timestamp);
+        output_open.push(bar.open);
+        output_high.push(bar.high);
+        output_low.push(bar.low);
+        output_close.push(bar.close);
+        output_volume.push(bar.volume);
+    }
+
+    Ok(())
+}
+
+/// Adds one source bar to a resampled series.
+///
+/// # Description
+/// Merges the source bar into the forming resampled bar, or appends the forming bar to
+/// `output_bars` and starts a new one when the source bar belongs to a later interval.
+///
+/// # Arguments
+/// * `input_timestamp` - Start time of the source bar
+/// * `input_open` - Open of the source bar
+/// * `input_high` - High of the source bar
+/// * `input_low` - Low of the source bar
+/// * `input_close` - Close of the source bar
+/// * `input_volume` - Volume of the source bar
+/// * `prev_bar` - The forming resampled bar, `None` at the start
+/// * `param_interval` - Length of the resampled bars in timestamp units (must be > 0)
+/// * `param_offset` - Shift of the interval grid from the epoch (0 <= offset < interval)
+/// * `output_bars` - Completed resampled bars are appended here
+///
+/// # Returns
+/// * `Result<OhlcvBar, KandError>` - The forming resampled bar
+///
+/// # Errors
+/// * `KandError::InvalidData` - If the source bar belongs to an earlier interval
+/// * `KandError::InvalidParameter` - If the interval or the offset is invalid
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::bars::resample;
+///
+/// let mut bars = Vec::new();
+/// let bar =
+///     resample::resample_inc(0, 10.0, 10.5, 9.8, 10.2, 3.0, None, 300, 0, &mut bars).unwrap();
+/// let bar = resample::resample_inc(
+///     60,
+///     10.2,
+///     10.9,
+///     10.1,
+///     10.7,
+///     2.0,
+///     Some(bar),
+///     300,
+///     0,
+///     &mut bars,
+/// )
+/// .unwrap();
+/// assert!(bars.is_empty());
+/// assert_eq!(
+///     (bar.open, bar.high, bar.close, bar.volume),
+///     (10.0, 10.9, 10.7, 5.0)
+/// );
+/// ```
+pub fn resample_inc(
+    input_timestamp: i64,
+    input_open: TAFloat,
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    input_volume: TAFloat,
+    prev_bar: Option<OhlcvBar>,
+    param_interval: i64,
+    param_offset: i64,
+    output_bars: &mut Vec<OhlcvBar>,
+) -> Result<OhlcvBar, KandError> {
+    check_params(param_interval, param_offset)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_open.is_nan()
+            || input_high.is_nan()
+            || input_low.is_nan()
+            || input_close.is_nan()
+            || input_volume.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let timestamp = bucket_start(input_timestamp, param_interval, param_offset);
+    match prev_bar {
+        Some(bar) if bar.timestamp == timestamp => Ok(OhlcvBar {
+            high: bar.high.max(input_high),
+            low: bar.low.min(input_low),
+            close: input_close,
+            volume: bar.volume + input_volume,
+            ..bar
+        }),
+        Some(bar) if bar.timestamp > timestamp => Err(KandError::InvalidData),
+        _ => {
+            output_bars.extend(prev_bar);
+            Ok(OhlcvBar {
+                timestamp,
+                open: input_open,
+                high: input_high,
+                low: input_low,
+                close: input_close,
+                volume: input_volume,
+            })
+        }
+    }
+}
+
+/// Streaming OHLCV resampler.
+///
+/// Consumes `(timestamp, open, high, low, close, volume)` source bars and emits a resampled
+/// bar once a source bar from a later interval arrives. The forming bar is available from
+/// [`Resampler::current`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, bars::resample::Resampler};
+///
+/// let mut resampler = Resampler::new(300, 0).unwrap();
+/// assert!(
+///     resampler
+///         .update((0, 10.0, 10.5, 9.8, 10.2, 3.0))
+///         .unwrap()
+///         .unwrap()
+///         .is_empty()
+/// );
+/// assert!(
+///     resampler
+///         .update((240, 10.2, 10.4, 10.0, 10.1, 1.0))
+///         .unwrap()
+///         .unwrap()
+///         .is_empty()
+/// );
+/// let bars = resampler
+///     .update((300, 10.1, 10.3, 10.0, 10.2, 2.0))
+///     .unwrap()
+///     .unwrap();
+/// assert_eq!(
+///     (bars[0].timestamp, bars[0].close, bars[0].volume),
+///     (0, 10.1, 4.0)
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Resampler {
+    param_interval: i64,
+    param_offset:   i64,
+    bar:            Option<OhlcvBar>,
+}
+
+impl Resampler {
+    /// Creates a new streaming resampler.
+    ///
+    /// # Arguments
+    /// * `param_interval` - Length of the resampled bars in timestamp units (must be > 0)
+    /// * `param_offset` - Shift of the interval grid from the epoch (0 <= offset < interval)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the interval or the offset is invalid
+    pub fn new(param_interval: i64, param_offset: i64) -> Result<Self, KandError> {
+        check_params(param_interval, param_offset)?;
+        Ok(Self {
+            param_interval,
+            param_offset,
+            bar: None,
+        })
+    }
+
+    /// Returns the resampled bar that is still forming, `None` before the first source bar.
+    pub const fn current(&self) -> Option<OhlcvBar> {
+        self.bar
+    }
+}
+
+impl Indicator for Resampler {
+    type Input = (i64, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat);
+    type Output = Vec<OhlcvBar>;
+
+    fn update(
+        &mut self,
+        (input_timestamp, input_open, input_high, input_low, input_close, input_volume): Self::Input,
+    ) -> Result<Option<Vec<OhlcvBar>>, KandError> {
+        let mut bars = Vec::new();
+        self.bar = Some(resample_inc(
+            input_timestamp,
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            input_volume,
+            self.bar,
+            self.param_interval,
+            self.param_offset,
+            &mut bars,
+        )?);
+        Ok(Some(bars))
+    }
+
+    fn reset(&mut self) {
+        self.bar = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bar.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: i64 = 60_000;
+
+    fn minute_bars(count: usize) -> [Vec<TAFloat>; 5] {
+        let close: Vec<TAFloat> = (0..count)
+            .map(|i| 100.0 + ((i * 7) % 11) as TAFloat - 5.0)
+            .collect();
+        let open: Vec<TAFloat> = (0..count)
+            .map(|i| if i == 0 { 100.0 } else { close[i - 1] })
+            .collect();
+        let high = (0..count).map(|i| open[i].max(close[i]) + 0.5).collect();
+        let low = (0..count).map(|i| open[i].min(close[i]) - 0.5).collect();
+        let volume = (0..count).map(|i| (i % 4 + 1) as TAFloat).collect();
+        [open, high, low, close, volume]
+    }
+
+    #[test]
+    fn test_resample_hourly() {
+        // Two and a half hours of one minute bars, starting at 00:30
+        let input_timestamp: Vec<i64> = (0..150).map(|i| (i + 30) * MINUTE).collect();
+        let [open, high, low, close, volume] = minute_bars(150);
+        let mut output_timestamp = Vec::new();
+        let mut output_open = Vec::new();
+        let mut output_high = Vec::new();
+        let mut output_low = Vec::new();
+        let mut output_close = Vec::new();
+        let mut output_volume = Vec::new();
+        resample(
+            &input_timestamp,
+            &open,
+            &high,
+            &low,
+            &close,
+            &volume,
+            60 * MINUTE,
+            0,
+            &mut output_timestamp,
+            &mut output_open,
+            &mut output_high,
+            &mut output_low,
+            &mut output_close,
+            &mut output_volume,
+        )
+        .unwrap();
+
+        assert_eq!(output_timestamp, vec![0, 60 * MINUTE, 120 * MINUTE]);
+        // The first hour holds bars 0..30, the second 30..90 and the third 90..150
+        for (k, range) in [(0, 0..30), (1, 30..90), (2, 90..150)] {
+            assert_eq!(output_open[k], open[range.start]);
+            assert_eq!(output_close[k], close[range.end - 1]);
+            let max = high[range.clone()]
+                .iter()
+                .copied()
+                .fold(TAFloat::MIN, TAFloat::max);
+            let min = low[range.clone()]
+                .iter()
+                .copied()
+                .fold(TAFloat::MAX, TAFloat::min);
+            assert_eq!(output_high[k], max);
+            assert_eq!(output_low[k], min);
+            assert_eq!(output_volume[k], volume[range].iter().sum::<TAFloat>());
+        }
+    }
+
+    #[test]
+    fn test_resample_offset() {
+        // Daily bars starting at 22:00, with hourly source bars from 20:00 to 23:00
+        let hour = 60 * MINUTE;
+        let input_timestamp = [20 * hour, 21 * hour, 22 * hour, 23 * hour];
+        let mut bars = Vec::new();
+        let mut current = None;
+        for (i, &timestamp) in input_timestamp.iter().enumerate() {
+            let price = i as TAFloat;
+            current = Some(
+                resample_inc(
+                    timestamp,
+                    price,
+                    price,
+                    price,
+                    price,
+                    1.0,
+                    current,
+                    24 * hour,
+                    22 * hour,
+                    &mut bars,
+                )
+                .unwrap(),
+            );
+        }
+        bars.extend(current);
+        let timestamps: Vec<i64> = bars.iter().map(|bar| bar.timestamp).collect();
+        assert_eq!(timestamps, vec![-2 * hour, 22 * hour]);
+        let volumes: Vec<TAFloat> = bars.iter().map(|bar| bar.volume).collect();
+        assert_eq!(volumes, vec![2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_resampler_stream() {
+        let input_timestamp: Vec<i64> = (0..37).map(|i| i * MINUTE).collect();
+        let [open, high, low, close, volume] = minute_bars(37);
+        let mut stream = Resampler::new(5 * MINUTE, 0).unwrap();
+        let mut bars = Vec::new();
+        for i in 0..input_timestamp.len() {
+            bars.extend(
+                stream
+                    .update((
+                        input_timestamp[i],
+                        open[i],
+                        high[i],
+                        low[i],
+                        close[i],
+                        volume[i],
+                    ))
+                    .unwrap()
+                    .unwrap(),
+            );
+        }
+        bars.extend(stream.current());
+        assert_eq!(bars.len(), 8);
+        assert_eq!(bars[7].timestamp, 35 * MINUTE);
+        assert_eq!(bars[7].close, close[36]);
+        assert_eq!(
+            bars.iter().map(|bar| bar.volume).sum::<TAFloat>(),
+            volume.iter().sum::<TAFloat>()
+        );
+
+        assert!(matches!(
+            stream.update((0, 1.0, 1.0, 1.0, 1.0, 1.0)),
+            Err(KandError::InvalidData)
+        ));
+        stream.reset();
+        assert!(!stream.is_ready());
+    }
+
+    #[test]
+    fn test_resample_invalid_params() {
+        assert!(Resampler::new(0, 0).is_err());
+        assert!(Resampler::new(60, 60).is_err());
+        assert!(Resampler::new(60, -1).is_err());
+    }
+}
@@ -0,0 +1,259 @@
+use super::{mtf, resample};
+use crate::{KandError, TAFloat};
+
+/// OHLCV bars of a single time frame together with their timestamps.
+///
+/// The indicator functions of [`crate::ohlcv`] take bare slices, so the price columns are kept
+/// as plain vectors that can be passed to them directly. Timestamps are the epoch based start
+/// times of the bars, in any unit as long as `interval` uses the same one.
+///
+/// # Example
+/// ```
+/// use kand::{bars::series::OhlcvSeries, ohlcv::rsi};
+///
+/// // Four hours of one minute bars, millisecond timestamps
+/// let minute = 60_000;
+/// let timestamp: Vec<i64> = (0..240).map(|i| i * minute).collect();
+/// let close: Vec<f64> = (0..240)
+///     .map(|i| 100.0 + (i as f64 * 0.3).sin() * 2.0)
+///     .collect();
+/// let series = OhlcvSeries::new(
+///     minute,
+///     timestamp,
+///     close.clone(),
+///     close.iter().map(|c| c + 0.5).collect(),
+///     close.iter().map(|c| c - 0.5).collect(),
+///     close,
+///     vec![1.0; 240],
+/// )
+/// .unwrap();
+///
+/// // RSI on 15 minute bars, aligned back onto the one minute bars
+/// let m15 = series.resample(15 * minute, 0).unwrap();
+/// let mut rsi_15m = vec![0.0; m15.len()];
+/// let mut avg_gain = vec![0.0; m15.len()];
+/// let mut avg_loss = vec![0.0; m15.len()];
+/// rsi::rsi(&m15.close, 14, &mut rsi_15m, &mut avg_gain, &mut avg_loss).unwrap();
+/// let rsi_on_1m = series.align(&m15, &rsi_15m).unwrap();
+///
+/// assert_eq!(rsi_on_1m.len(), series.len());
+/// // The 15th 15 minute bar closes at the end of the 224th one minute bar
+/// assert!(rsi_on_1m[223].is_nan());
+/// assert_eq!(rsi_on_1m[224], rsi_15m[14]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OhlcvSeries {
+    /// Length of each bar in timestamp units
+    pub interval:  i64,
+    /// Start time of each bar
+    pub timestamp: Vec<i64>,
+    /// Open prices
+    pub open:      Vec<TAFloat>,
+    /// High prices
+    pub high:      Vec<TAFloat>,
+    /// Low prices
+    pub low:       Vec<TAFloat>,
+    /// Close prices
+    pub close:     Vec<TAFloat>,
+    /// Volumes
+    pub volume:    Vec<TAFloat>,
+}
+
+impl OhlcvSeries {
+    /// Creates a series from its columns.
+    ///
+    /// # Arguments
+    /// * `interval` - Length of each bar in timestamp units (must be > 0)
+    /// * `timestamp` - Bar start times (strictly increasing)
+    /// * `open`, `high`, `low`, `close`, `volume` - Price and volume columns
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the interval is not positive
+    /// * `KandError::LengthMismatch` - If the columns have different lengths
+    /// * `KandError::InvalidData` - If the timestamps are not strictly increasing
+    pub fn new(
+        interval: i64,
+        timestamp: Vec<i64>,
+        open: Vec<TAFloat>,
+        high: Vec<TAFloat>,
+        low: Vec<TAFloat>,
+        close: Vec<TAFloat>,
+        volume: Vec<TAFloat>,
+    ) -> Result<Self, KandError> {
+        #[cfg(feature = "check")]
+        {
+            // Parameter check
+            if interval <= 0 {
+                return Err(KandError::InvalidParameter);
+            }
+
+            // Length consistency check
+            let len = timestamp.len();
+            if open.len() != len
+                || high.len() != len
+                || low.len() != len
+                || close.len() != len
+                || volume.len() != len
+            {
+                return Err(KandError::LengthMismatch);
+            }
+
+            // Timestamp order check
+            if timestamp.windows(2).any(|pair| pair[1] <= pair[0]) {
+                return Err(KandError::InvalidData);
+            }
+        }
+
+        Ok(Self {
+            interval,
+            timestamp,
+            open,
+            high,
+            low,
+            close,
+            volume,
+        })
+    }
+
+    /// Returns the number of bars.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.timestamp.len()
+    }
+
+    /// Returns `true` if the series has no bars.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.timestamp.is_empty()
+    }
+
+    /// Resamples the series onto a longer time frame.
+    ///
+    /// See [`resample::resample`] for the aggregation rules.
+    ///
+    /// # Arguments
+    /// * `interval` - Length of the resampled bars, a multiple of the current interval
+    /// * `offset` - Shift of the interval grid from the epoch (0 <= offset < interval)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `interval` is not a multiple of the current
+    ///   interval or the offset is invalid
+    /// * Propagates any error raised by [`resample::resample`]
+    pub fn resample(&self, interval: i64, offset: i64) -> Result<Self, KandError> {
+        #[cfg(feature = "check")]
+        {
+            // Source bars must not straddle two resampled bars
+            if interval <= 0 || interval % self.interval != 0 || offset % self.interval != 0 {
+                return Err(KandError::InvalidParameter);
+            }
+        }
+
+        let mut resampled = Self {
+            interval,
+            ..Self::default()
+        };
+        resample::resample(
+            &self.timestamp,
+            &self.open,
+            &self.high,
+            &self.low,
+            &self.close,
+            &self.volume,
+            interval,
+            offset,
+            &mut resampled.timestamp,
+            &mut resampled.open,
+            &mut resampled.high,
+            &mut resampled.low,
+            &mut resampled.close,
+            &mut resampled.volume,
+        )?;
+        Ok(resampled)
+    }
+
+    /// Aligns values computed on a higher time frame series onto this series.
+    ///
+    /// See [`mtf::align`]: each bar receives the latest value whose bar had closed by the end
+    /// of this bar, NaN before that.
+    ///
+    /// # Arguments
+    /// * `higher` - The higher time frame series, e.g. the result of [`Self::resample`]
+    /// * `values` - Values computed on `higher`, one per bar
+    ///
+    /// # Errors
+    /// * Propagates any error raised by [`mtf::align`]
+    pub fn align(&self, higher: &Self, values: &[TAFloat]) -> Result<Vec<TAFloat>, KandError> {
+        let mut output_value = vec![TAFloat::NAN; self.len()];
+        mtf::align(
+            &self.timestamp,
+            self.interval,
+            &higher.timestamp,
+            higher.interval,
+            values,
+            &mut output_value,
+        )?;
+        Ok(output_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(len: usize, interval: i64) -> OhlcvSeries {
+        let timestamp = (0..len as i64).map(|i| i * interval).collect();
+        let close: Vec<TAFloat> = (0..len).map(|i| 50.0 + (i % 9) as TAFloat).collect();
+        OhlcvSeries::new(
+            interval,
+            timestamp,
+            close.clone(),
+            close.iter().map(|c| c + 1.0).collect(),
+            close.iter().map(|c| c - 1.0).collect(),
+            close,
+            vec![2.0; len],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_series_resample_and_align() {
+        let minutes = series(60, 60);
+        let m5 = minutes.resample(300, 0).unwrap();
+        assert_eq!(m5.len(), 12);
+        assert_eq!(m5.interval, 300);
+        assert!(m5.volume.iter().all(|&volume| volume == 10.0));
+
+        // Resampling twice gives the same bars as resampling once
+        let m15 = m5.resample(900, 0).unwrap();
+        assert_eq!(m15, minutes.resample(900, 0).unwrap());
+
+        let aligned = minutes.align(&m15, &m15.close).unwrap();
+        assert!(aligned[..14].iter().all(|value| value.is_nan()));
+        assert_eq!(aligned[14], m15.close[0]);
+        assert_eq!(aligned[59], m15.close[3]);
+    }
+
+    #[test]
+    fn test_series_invalid() {
+        assert!(matches!(
+            OhlcvSeries::new(60, vec![0, 60], vec![1.0], vec![], vec![], vec![], vec![]),
+            Err(KandError::LengthMismatch)
+        ));
+        assert!(matches!(
+            OhlcvSeries::new(
+                60,
+                vec![60, 0],
+                vec![1.0; 2],
+                vec![1.0; 2],
+                vec![1.0; 2],
+                vec![1.0; 2],
+                vec![1.0; 2]
+            ),
+            Err(KandError::InvalidData)
+        ));
+        assert!(matches!(
+            series(10, 60).resample(90, 0),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
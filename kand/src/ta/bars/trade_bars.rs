@@ -0,0 +1,668 @@
+use crate::{Indicator, KandError, TAFloat, TAInt, types::BarType};
+
+/// A single OHLCV bar aggregated from trades.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeBar {
+    /// Start of the interval for time bars, timestamp of the first trade otherwise
+    pub timestamp:      i64,
+    /// Timestamp of the last trade in the bar
+    pub last_timestamp: i64,
+    /// Price of the first trade
+    pub open:           TAFloat,
+    /// Highest traded price
+    pub high:           TAFloat,
+    /// Lowest traded price
+    pub low:            TAFloat,
+    /// Price of the last trade
+    pub close:          TAFloat,
+    /// Sum of the trade sizes
+    pub volume:         TAFloat,
+    /// Sum of price * size over the trades
+    pub dollar_value:   TAFloat,
+    /// Number of trades
+    pub trades:         usize,
+    /// Sum of the tick rule signs of the trades
+    pub imbalance:      TAInt,
+    /// Whether the rule of the bar type closed the bar, false while it is still forming
+    pub is_complete:    bool,
+}
+
+impl TradeBar {
+    /// Returns an empty bar labelled with `timestamp`, before its first trade is added.
+    #[must_use]
+    pub const fn empty(timestamp: i64) -> Self {
+        Self {
+            timestamp,
+            last_timestamp: timestamp,
+            open: TAFloat::NAN,
+            high: TAFloat::NEG_INFINITY,
+            low: TAFloat::INFINITY,
+            close: TAFloat::NAN,
+            volume: 0.0,
+            dollar_value: 0.0,
+            trades: 0,
+            imbalance: 0,
+            is_complete: false,
+        }
+    }
+}
+
+/// Returns the lookback period required for trade bar aggregation.
+///
+/// # Description
+/// Every trade is added to a bar right away, so no warm-up is needed.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Always 0
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::bars::trade_bars;
+/// assert_eq!(trade_bars::lookback().unwrap(), 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Validates the threshold for the bar type.
+fn check_params(param_bar_type: BarType, param_threshold: TAFloat) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if !param_threshold.is_finite() || param_threshold <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+        // Intervals and trade counts are whole numbers
+        if matches!(param_bar_type, BarType::Time | BarType::Tick) && param_threshold.fract() != 0.0
+        {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    let _ = (param_bar_type, param_threshold);
+    Ok(())
+}
+
+/// Aggregates a stream of trades into OHLCV bars.
+///
+/// # Description
+/// Trades are added to the forming bar one by one and the bar closes according to
+/// `param_bar_type`:
+/// - `Time`: bars cover `[k * threshold, (k + 1) * threshold)` in timestamp units, so a bar
+///   closes when a trade from a later interval arrives. Intervals without trades produce no bar.
+/// - `Tick`: a bar closes after `threshold` trades.
+/// - `Volume`: a bar closes on the trade that brings its volume to at least `threshold`.
+/// - `Dollar`: a bar closes on the trade that brings its traded value to at least `threshold`.
+/// - `TickImbalance`: a bar closes once the absolute sum of tick rule signs reaches
+///   `threshold`. The sign is +1 for an uptick, -1 for a downtick and repeats the previous sign
+///   when the price is unchanged; the very first trade has sign 0.
+///
+/// Trades are never split, so volume and dollar bars can overshoot the threshold by the size
+/// of their last trade.
+///
+/// # Arguments
+/// * `input_timestamp` - Array of trade timestamps (epoch based, non-decreasing)
+/// * `input_price` - Array of trade prices
+/// * `input_size` - Array of trade sizes
+/// * `param_bar_type` - Rule that closes a bar
+/// * `param_threshold` - Interval length, trade count, volume, value or imbalance per bar
+///   (must be > 0, and a whole number for time and tick bars)
+/// * `output_timestamp` - Cleared, then filled with the timestamp of each bar
+/// * `output_open` - Cleared, then filled with the open of each bar
+/// * `output_high` - Cleared, then filled with the high of each bar
+/// * `output_low` - Cleared, then filled with the low of each bar
+/// * `output_close` - Cleared, then filled with the close of each bar
+/// * `output_volume` - Cleared, then filled with the volume of each bar
+/// * `output_trades` - Cleared, then filled with the number of trades in each bar
+/// * `output_complete` - Cleared, then filled with whether each bar is complete
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success; a trailing bar whose rule has not been met
+///   yet is included as the last bar with `output_complete` set to false
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty or timestamps decrease
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If the threshold is invalid for the bar type
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{bars::trade_bars, types::BarType};
+///
+/// let input_timestamp = vec![0, 20, 45, 61, 70, 130];
+/// let input_price = vec![10.0, 10.5, 9.8, 10.1, 10.4, 10.2];
+/// let input_size = vec![1.0, 2.0, 1.0, 3.0, 1.0, 2.0];
+/// let mut output_timestamp = Vec::new();
+/// let mut output_open = Vec::new();
+/// let mut output_high = Vec::new();
+/// let mut output_low = Vec::new();
+/// let mut output_close = Vec::new();
+/// let mut output_volume = Vec::new();
+/// let mut output_trades = Vec::new();
+/// let mut output_complete = Vec::new();
+///
+/// // One minute bars on second timestamps
+/// trade_bars::trade_bars(
+///     &input_timestamp,
+///     &input_price,
+///     &input_size,
+///     BarType::Time,
+///     60.0,
+///     &mut output_timestamp,
+///     &mut output_open,
+///     &mut output_high,
+///     &mut output_low,
+///     &mut output_close,
+///     &mut output_volume,
+///     &mut output_trades,
+///     &mut output_complete,
+/// )
+/// .unwrap();
+/// assert_eq!(output_timestamp, vec![0, 60, 120]);
+/// assert_eq!(output_high, vec![10.5, 10.4, 10.2]);
+/// assert_eq!(output_volume, vec![4.0, 4.0, 2.0]);
+/// assert_eq!(output_trades, vec![3, 2, 1]);
+/// // The last minute may still receive trades
+/// assert_eq!(output_complete, vec![true, true, false]);
+/// ```
+pub fn trade_bars(
+    input_timestamp: &[i64],
+    input_price: &[TAFloat],
+    input_size: &[TAFloat],
+    param_bar_type: BarType,
+    param_threshold: TAFloat,
+    output_timestamp: &mut Vec<i64>,
+    output_open: &mut Vec<TAFloat>,
+    output_high: &mut Vec<TAFloat>,
+    output_low: &mut Vec<TAFloat>,
+    output_close: &mut Vec<TAFloat>,
+    output_volume: &mut Vec<TAFloat>,
+    output_trades: &mut Vec<usize>,
+    output_complete: &mut Vec<bool>,
+) -> Result<(), KandError> {
+    let len = input_price.len();
+    check_params(param_bar_type, param_threshold)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_timestamp.len() != len || input_size.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Timestamp order check
+        if input_timestamp.windows(2).any(|pair| pair[1] < pair[0]) {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_price[i].is_nan() || input_size[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut bars = Vec::new();
+    let mut current = None;
+    let mut prev_price = TAFloat::NAN;
+    let mut prev_sign = 0;
+    for i in 0..len {
+        (current, prev_price, prev_sign) = trade_bars_inc(
+            input_timestamp[i],
+            input_price[i],
+            input_size[i],
+            current,
+            prev_price,
+            prev_sign,
+            param_bar_type,
+            param_threshold,
+            &mut bars,
+        )?;
+    }
+    bars.extend(current);
+
+    output_timestamp.clear();
+    output_open.clear();
+    output_high.clear();
+    output_low.clear();
+    output_close.clear();
+    output_volume.clear();
+    output_trades.clear();
+    output_complete.clear();
+    for bar in bars {
+        output_timestamp.push(bar.timestamp);
+        output_open.push(bar.open);
+        output_high.push(bar.high);
+        output_low.push(bar.low);
+        output_close.push(bar.close);
+        output_volume.push(bar.volume);
+        output_trades.push(bar.trades);
+        output_complete.push(bar.is_complete);
+    }
+
+    Ok(())
+}
+
+/// Adds one trade to a bar series.
+///
+/// # Description
+/// Applies the rules described in [`trade_bars`] to a single trade. Completed bars are
+/// appended to `output_bars`; a time bar is completed by the first trade of a later interval.
+///
+/// # Arguments
+/// * `input_timestamp` - Timestamp of the trade
+/// * `input_price` - Price of the trade
+/// * `input_size` - Size of the trade
+/// * `prev_bar` - The forming bar, `None` if the previous trade completed a bar or at the start
+/// * `prev_price` - Price of the previous trade, NaN at the start
+/// * `prev_sign` - Tick rule sign of the previous trade, 0 at the start
+/// * `param_bar_type` - Rule that closes a bar
+/// * `param_threshold` - Interval length, trade count, volume, value or imbalance per bar
+/// * `output_bars` - Completed bars are appended here
+///
+/// # Returns
+/// * `Result<(Option<TradeBar>, TAFloat, TAInt), KandError>` - The forming bar, the trade
+///   price and its tick rule sign
+///
+/// # Errors
+/// * `KandError::InvalidData` - If the timestamp is older than the last trade of the bar
+/// * `KandError::InvalidParameter` - If the threshold is invalid for the bar type, or the
+///   interval of a time bar truncates to zero
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{bars::trade_bars, types::BarType};
+///
+/// let mut bars = Vec::new();
+/// let (bar, price, sign) = trade_bars::trade_bars_inc(
+///     0,
+///     10.0,
+///     2.0,
+///     None,
+///     f64::NAN,
+///     0,
+///     BarType::Volume,
+///     5.0,
+///     &mut bars,
+/// )
+/// .unwrap();
+/// assert_eq!(bar.unwrap().volume, 2.0);
+/// let (bar, _, _) = trade_bars::trade_bars_inc(
+///     1,
+///     10.2,
+///     4.0,
+///     bar,
+///     price,
+///     sign,
+///     BarType::Volume,
+///     5.0,
+///     &mut bars,
+/// )
+/// .unwrap();
+/// // The second trade pushes the volume past 5.0 and completes the bar
+/// assert!(bar.is_none());
+/// assert_eq!((bars[0].volume, bars[0].close), (6.0, 10.2));
+/// assert!(bars[0].is_complete);
+/// ```
+pub fn trade_bars_inc(
+    input_timestamp: i64,
+    input_price: TAFloat,
+    input_size: TAFloat,
+    prev_bar: Option<TradeBar>,
+    prev_price: TAFloat,
+    prev_sign: TAInt,
+    param_bar_type: BarType,
+    param_threshold: TAFloat,
+    output_bars: &mut Vec<TradeBar>,
+) -> Result<(Option<TradeBar>, TAFloat, TAInt), KandError> {
+    check_params(param_bar_type, param_threshold)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Timestamp order check
+        if prev_bar.is_some_and(|bar| input_timestamp < bar.last_timestamp) {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() || input_size.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let sign = if prev_price.is_nan() {
+        0
+    } else if input_price > prev_price {
+        1
+    } else if input_price < prev_price {
+        -1
+    } else {
+        prev_sign
+    };
+
+    let label = match param_bar_type {
+        BarType::Time => {
+            // Checked even without the `check` feature, a zero interval cannot label a bar
+            let interval = param_threshold as i64;
+            if interval <= 0 {
+                return Err(KandError::InvalidParameter);
+            }
+            input_timestamp.div_euclid(interval) * interval
+        }
+        _ => input_timestamp,
+    };
+
+    let mut bar = match prev_bar {
+        Some(mut bar) if param_bar_type == BarType::Time && bar.timestamp != label => {
+            bar.is_complete = true;
+            output_bars.push(bar);
+            TradeBar::empty(label)
+        }
+        Some(bar) => bar,
+        None => TradeBar::empty(label),
+    };
+
+    if bar.trades == 0 {
+        bar.open = input_price;
+    }
+    bar.last_timestamp = input_timestamp;
+    bar.high = bar.high.max(input_price);
+    bar.low = bar.low.min(input_price);
+    bar.close = input_price;
+    bar.volume += input_size;
+    bar.dollar_value += input_price * input_size;
+    bar.trades += 1;
+    bar.imbalance += sign;
+
+    let complete = match param_bar_type {
+        BarType::Time => false,
+        BarType::Tick => bar.trades as TAFloat >= param_threshold,
+        BarType::Volume => bar.volume >= param_threshold,
+        BarType::Dollar => bar.dollar_value >= param_threshold,
+        BarType::TickImbalance => bar.imbalance.abs() as TAFloat >= param_threshold,
+    };
+    if complete {
+        bar.is_complete = true;
+        output_bars.push(bar);
+        return Ok((None, input_price, sign));
+    }
+    Ok((Some(bar), input_price, sign))
+}
+
+/// Streaming trade bar builder.
+///
+/// Consumes `(timestamp, price, size)` trades and emits the bars each trade completes, so
+/// the output can be fed bar by bar into the streaming indicators of [`crate::ohlcv`]. The
+/// forming bar is available from [`TradeBars::current`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, bars::trade_bars::TradeBars, types::BarType};
+///
+/// let mut bars = TradeBars::new(BarType::Tick, 2.0).unwrap();
+/// assert!(bars.update((0, 10.0, 1.0)).unwrap().unwrap().is_empty());
+/// let completed = bars.update((5, 10.5, 2.0)).unwrap().unwrap();
+/// assert_eq!((completed[0].open, completed[0].close), (10.0, 10.5));
+/// assert!(bars.current().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TradeBars {
+    param_bar_type:  BarType,
+    param_threshold: TAFloat,
+    bar:             Option<TradeBar>,
+    prev_price:      TAFloat,
+    prev_sign:       TAInt,
+}
+
+impl TradeBars {
+    /// Creates a new streaming trade bar builder.
+    ///
+    /// # Arguments
+    /// * `param_bar_type` - Rule that closes a bar
+    /// * `param_threshold` - Interval length, trade count, volume, value or imbalance per bar
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the threshold is invalid for the bar type
+    pub fn new(param_bar_type: BarType, param_threshold: TAFloat) -> Result<Self, KandError> {
+        check_params(param_bar_type, param_threshold)?;
+        Ok(Self {
+            param_bar_type,
+            param_threshold,
+            bar: None,
+            prev_price: TAFloat::NAN,
+            prev_sign: 0,
+        })
+    }
+
+    /// Returns the bar that is still forming, `None` if the last trade completed a bar.
+    pub const fn current(&self) -> Option<TradeBar> {
+        self.bar
+    }
+}
+
+impl Indicator for TradeBars {
+    type Input = (i64, TAFloat, TAFloat);
+    type Output = Vec<TradeBar>;
+
+    fn update(
+        &mut self,
+        (input_timestamp, input_price, input_size): (i64, TAFloat, TAFloat),
+    ) -> Result<Option<Vec<TradeBar>>, KandError> {
+        let mut bars = Vec::new();
+        (self.bar, self.prev_price, self.prev_sign) = trade_bars_inc(
+            input_timestamp,
+            input_price,
+            input_size,
+            self.bar,
+            self.prev_price,
+            self.prev_sign,
+            self.param_bar_type,
+            self.param_threshold,
+            &mut bars,
+        )?;
+        Ok(Some(bars))
+    }
+
+    fn reset(&mut self) {
+        self.bar = None;
+        self.prev_price = TAFloat::NAN;
+        self.prev_sign = 0;
+    }
+
+    fn is_ready(&self) -> bool {
+        !self.prev_price.is_nan()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_TIMESTAMP: [i64; 12] = [
+        1_000, 1_400, 2_100, 2_600, 2_900, 4_200, 4_250, 4_800, 5_100, 5_500, 5_900, 7_300,
+    ];
+    const INPUT_PRICE: [TAFloat; 12] = [
+        100.0, 100.5, 100.5, 100.2, 99.8, 99.8, 100.1, 100.4, 100.9, 100.6, 100.6, 101.0,
+    ];
+    const INPUT_SIZE: [TAFloat; 12] = [2.0, 1.0, 3.0, 1.5, 0.5, 4.0, 1.0, 2.0, 1.0, 2.5, 0.5, 1.0];
+
+    fn run(param_bar_type: BarType, param_threshold: TAFloat) -> Vec<TradeBar> {
+        let mut bars = Vec::new();
+        let mut current = None;
+        let mut prev_price = TAFloat::NAN;
+        let mut prev_sign = 0;
+        for i in 0..INPUT_PRICE.len() {
+            (current, prev_price, prev_sign) = trade_bars_inc(
+                INPUT_TIMESTAMP[i],
+                INPUT_PRICE[i],
+                INPUT_SIZE[i],
+                current,
+                prev_price,
+                prev_sign,
+                param_bar_type,
+                param_threshold,
+                &mut bars,
+            )
+            .unwrap();
+        }
+        bars.extend(current);
+        bars
+    }
+
+    #[test]
+    fn test_trade_bars_batch() {
+        let mut output_timestamp = Vec::new();
+        let mut output_open = Vec::new();
+        let mut output_high = Vec::new();
+        let mut output_low = Vec::new();
+        let mut output_close = Vec::new();
+        let mut output_volume = Vec::new();
+        let mut output_trades = Vec::new();
+        let mut output_complete = Vec::new();
+        trade_bars(
+            &INPUT_TIMESTAMP,
+            &INPUT_PRICE,
+            &INPUT_SIZE,
+            BarType::Time,
+            1_000.0,
+            &mut output_timestamp,
+            &mut output_open,
+            &mut output_high,
+            &mut output_low,
+            &mut output_close,
+            &mut output_volume,
+            &mut output_trades,
+            &mut output_complete,
+        )
+        .unwrap();
+
+        // No trades between 3000 and 4000, so that interval has no bar
+        assert_eq!(output_timestamp, vec![1_000, 2_000, 4_000, 5_000, 7_000]);
+        assert_eq!(output_open, vec![100.0, 100.5, 99.8, 100.9, 101.0]);
+        assert_eq!(output_high, vec![100.5, 100.5, 100.4, 100.9, 101.0]);
+        assert_eq!(output_low, vec![100.0, 99.8, 99.8, 100.6, 101.0]);
+        assert_eq!(output_close, vec![100.5, 99.8, 100.4, 100.6, 101.0]);
+        assert_eq!(output_volume, vec![3.0, 5.0, 7.0, 4.0, 1.0]);
+        assert_eq!(output_trades, vec![2, 3, 3, 3, 1]);
+        assert_eq!(output_complete, vec![true, true, true, true, false]);
+
+        let bars = run(BarType::Time, 1_000.0);
+        assert_eq!(bars.len(), output_timestamp.len());
+        assert_eq!(bars[2].last_timestamp, 4_800);
+    }
+
+    #[test]
+    fn test_trade_bars_thresholds() {
+        let ticks = run(BarType::Tick, 5.0);
+        let trades: Vec<usize> = ticks.iter().map(|bar| bar.trades).collect();
+        assert_eq!(trades, vec![5, 5, 2]);
+        assert_eq!(ticks[1].timestamp, 4_200);
+
+        let volume = run(BarType::Volume, 6.0);
+        let volumes: Vec<TAFloat> = volume.iter().map(|bar| bar.volume).collect();
+        assert_eq!(volumes, vec![6.0, 6.0, 6.5, 1.5]);
+
+        let dollar = run(BarType::Dollar, 500.0);
+        for bar in &dollar[..dollar.len() - 1] {
+            assert!(bar.dollar_value >= 500.0);
+        }
+        assert_relative_eq!(
+            dollar.iter().map(|bar| bar.dollar_value).sum::<TAFloat>(),
+            INPUT_PRICE
+                .iter()
+                .zip(INPUT_SIZE)
+                .map(|(price, size)| price * size)
+                .sum::<TAFloat>(),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_trade_bars_tick_imbalance() {
+        // Signs: 0, +1, +1, -1, -1, -1, +1, +1, +1, -1, -1, +1
+        let bars = run(BarType::TickImbalance, 2.0);
+        let imbalance: Vec<TAInt> = bars.iter().map(|bar| bar.imbalance).collect();
+        assert_eq!(imbalance, vec![2, -2, 2, -2, 1]);
+        let trades: Vec<usize> = bars.iter().map(|bar| bar.trades).collect();
+        assert_eq!(trades, vec![3, 2, 4, 2, 1]);
+        assert!(bars[..4].iter().all(|bar| bar.is_complete));
+        assert!(!bars[4].is_complete);
+    }
+
+    #[test]
+    fn test_trade_bars_stream() {
+        for (bar_type, threshold) in [
+            (BarType::Time, 1_000.0),
+            (BarType::Tick, 3.0),
+            (BarType::Volume, 4.0),
+            (BarType::Dollar, 300.0),
+            (BarType::TickImbalance, 2.0),
+        ] {
+            let expected = run(bar_type, threshold);
+            let mut stream = TradeBars::new(bar_type, threshold).unwrap();
+            let mut bars = Vec::new();
+            for i in 0..INPUT_PRICE.len() {
+                bars.extend(
+                    stream
+                        .update((INPUT_TIMESTAMP[i], INPUT_PRICE[i], INPUT_SIZE[i]))
+                        .unwrap()
+                        .unwrap(),
+                );
+            }
+            bars.extend(stream.current());
+            assert_eq!(bars, expected);
+
+            stream.reset();
+            assert!(!stream.is_ready());
+        }
+    }
+
+    #[test]
+    fn test_trade_bars_invalid() {
+        assert!(TradeBars::new(BarType::Time, 0.0).is_err());
+        assert!(TradeBars::new(BarType::Tick, 2.5).is_err());
+        assert!(TradeBars::new(BarType::Volume, 2.5).is_ok());
+        assert!(matches!(
+            trade_bars_inc(
+                0,
+                1.0,
+                1.0,
+                None,
+                TAFloat::NAN,
+                0,
+                BarType::Time,
+                0.5,
+                &mut Vec::new(),
+            ),
+            Err(KandError::InvalidParameter)
+        ));
+
+        let mut stream = TradeBars::new(BarType::Time, 60.0).unwrap();
+        stream.update((120, 1.0, 1.0)).unwrap();
+        assert!(matches!(
+            stream.update((100, 1.0, 1.0)),
+            Err(KandError::InvalidData)
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::KandError;
+
+/// Common interface for streaming (stateful) indicator calculators.
+///
+/// Every `*_inc` function in this crate is a pure function: the caller has to keep the
+/// previous smoothed values, buffers and counters around and feed them back on the next bar.
+/// Types implementing `Indicator` own that state instead, so a live feed only has to push
+/// new bars through [`Indicator::update`].
+///
+/// Implementations are built on top of the batch and `*_inc` functions of the same module:
+/// the warm-up window is seeded with the batch function and every subsequent bar goes through
+/// the incremental function, so the emitted values match the batch output bar for bar.
+///
+/// # Associated Types
+/// * `Input` - The data consumed per bar, e.g. a price or a `(high, low, close)` tuple
+/// * `Output` - The value emitted once the indicator is warmed up
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::sma::Sma};
+///
+/// let mut sma = Sma::new(3).unwrap();
+/// assert_eq!(sma.lookback(), 2);
+///
+/// assert_eq!(sma.update(2.0).unwrap(), None);
+/// assert_eq!(sma.update(4.0).unwrap(), None);
+/// assert_eq!(sma.update(6.0).unwrap(), Some(4.0));
+/// assert!(sma.is_ready());
+///
+/// sma.reset();
+/// assert!(!sma.is_ready());
+/// ```
+pub trait Indicator {
+    /// Data consumed for each new bar.
+    type Input;
+
+    /// Value produced for each bar once the lookback period has been filled.
+    type Output;
+
+    /// Feeds one new bar into the indicator.
+    ///
+    /// # Arguments
+    /// * `input` - The newest bar
+    ///
+    /// # Returns
+    /// * `Result<Option<Self::Output>, KandError>` - `None` while the indicator is still within
+    ///   its lookback period, `Some(value)` afterwards
+    ///
+    /// # Errors
+    /// * Propagates any error raised by the underlying batch or incremental function
+    fn update(&mut self, input: Self::Input) -> Result<Option<Self::Output>, KandError>;
+
+    /// Clears all accumulated state, keeping the configured parameters.
+    fn reset(&mut self);
+
+    /// Returns `true` once the indicator has produced its first value.
+    fn is_ready(&self) -> bool;
+
+    /// Returns the number of bars consumed before the first value is produced.
+    ///
+    /// This is the same value returned by the module level `lookback` function.
+    fn lookback(&self) -> usize;
+}
@@ -1,3 +1,5 @@
+pub mod bars;
+pub mod indicator;
 pub mod ohlcv;
 pub mod stats;
 
@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period required for A/D calculation
 ///
@@ -122,13 +122,8 @@ pub fn ad(
 
     let mut ad = 0.0;
     for i in lookback..len {
-        let high_low_diff = input_high[i] - input_low[i];
-        let mfm = if high_low_diff == 0.0 {
-            0.0
-        } else {
-            ((input_close[i] - input_low[i]) - (input_high[i] - input_close[i])) / high_low_diff
-        };
-        let mfv = mfm * input_volume[i];
+        let mfv =
+            money_flow_multiplier(input_high[i], input_low[i], input_close[i]) * input_volume[i];
         ad += mfv;
         output_ad[i] = ad;
     }
@@ -193,14 +188,107 @@ pub fn ad_inc(
         }
     }
 
+    let mfv = money_flow_multiplier(input_high, input_low, input_close) * input_volume;
+    Ok(prev_ad + mfv)
+}
+
+/// Calculates the Money Flow Multiplier of a single bar.
+///
+/// # Description
+/// Locates the close within the bar's range: +1 on the high, -1 on the low and 0 at the
+/// midpoint. Shared by the A/D line and the indicators built on it, such as Chaikin Money
+/// Flow.
+///
+/// # Mathematical Formula
+/// ```text
+/// MFM = ((Close - Low) - (High - Close)) / (High - Low)
+/// ```
+///
+/// # Arguments
+/// * `input_high` - High price
+/// * `input_low` - Low price
+/// * `input_close` - Close price
+///
+/// # Returns
+/// * `TAFloat` - The multiplier in [-1, 1], 0 for a bar with no range
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ad;
+///
+/// assert_eq!(ad::money_flow_multiplier(12.0, 8.0, 11.0), 0.5);
+/// assert_eq!(ad::money_flow_multiplier(10.0, 10.0, 10.0), 0.0);
+/// ```
+#[must_use]
+pub fn money_flow_multiplier(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+) -> TAFloat {
     let high_low_diff = input_high - input_low;
-    let mfm = if high_low_diff == 0.0 {
+    if high_low_diff == 0.0 {
         0.0
     } else {
         ((input_close - input_low) - (input_high - input_close)) / high_low_diff
-    };
-    let mfv = mfm * input_volume;
-    Ok(prev_ad + mfv)
+    }
+}
+
+/// Streaming Accumulation/Distribution (A/D) calculator.
+///
+/// Consumes `(high, low, close, volume)` bars and carries the running A/D total, starting
+/// from zero like [`ad`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ad::Ad};
+///
+/// let mut ad = Ad::new();
+/// assert_eq!(ad.update((10.0, 8.0, 10.0, 100.0)).unwrap(), Some(100.0));
+/// assert_eq!(ad.update((10.0, 8.0, 8.0, 50.0)).unwrap(), Some(50.0));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Ad {
+    prev_ad: Option<TAFloat>,
+}
+
+impl Ad {
+    /// Creates a new streaming A/D.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { prev_ad: None }
+    }
+}
+
+impl Indicator for Ad {
+    type Input = (TAFloat, TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close, input_volume): (TAFloat, TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        let output_ad = ad_inc(
+            input_high,
+            input_low,
+            input_close,
+            input_volume,
+            self.prev_ad.unwrap_or(0.0),
+        )?;
+        self.prev_ad = Some(output_ad);
+        Ok(self.prev_ad)
+    }
+
+    fn reset(&mut self) {
+        self.prev_ad = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_ad.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
 }
 
 #[cfg(test)]
@@ -293,4 +381,47 @@ fn test_ad_calculation() {
             prev_ad = result;
         }
     }
+
+    #[test]
+    fn test_ad_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let mut output_ad = vec![0.0; input_close.len()];
+
+        ad(
+            &input_high,
+            &input_low,
+            &input_close,
+            &input_volume,
+            &mut output_ad,
+        )
+        .unwrap();
+
+        let mut stream = Ad::new();
+        for i in 0..input_close.len() {
+            let value = stream
+                .update((input_high[i], input_low[i], input_close[i], input_volume[i]))
+                .unwrap();
+            assert_relative_eq!(value.unwrap(), output_ad[i], epsilon = 0.00001);
+        }
+        assert!(stream.is_ready());
+    }
 }
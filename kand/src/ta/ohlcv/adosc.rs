@@ -1,5 +1,5 @@
 use super::{ad, ema};
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Get the lookback period for A/D Oscillator calculation
 ///
@@ -282,6 +282,142 @@ pub fn adosc_inc(
     ))
 }
 
+/// Streaming Chaikin A/D Oscillator (ADOSC) calculator.
+///
+/// Consumes `(high, low, close, volume)` bars and carries the A/D line and both of its EMAs
+/// between bars. The first value is seeded with [`adosc`], later values come from
+/// [`adosc_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::adosc::Adosc};
+///
+/// let mut adosc = Adosc::new(2, 3).unwrap();
+/// assert_eq!(adosc.update((10.0, 8.0, 10.0, 100.0)).unwrap(), None);
+/// assert_eq!(adosc.update((10.0, 8.0, 8.0, 50.0)).unwrap(), None);
+/// assert!(adosc.update((11.0, 9.0, 10.5, 80.0)).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Adosc {
+    param_fast_period: usize,
+    param_slow_period: usize,
+    lookback:          usize,
+    warmup_high:       Vec<TAFloat>,
+    warmup_low:        Vec<TAFloat>,
+    warmup_close:      Vec<TAFloat>,
+    warmup_volume:     Vec<TAFloat>,
+    ready:             bool,
+    prev_ad:           TAFloat,
+    prev_ad_fast_ema:  TAFloat,
+    prev_ad_slow_ema:  TAFloat,
+}
+
+impl Adosc {
+    /// Creates a new streaming ADOSC.
+    ///
+    /// # Arguments
+    /// * `param_fast_period` - The fast EMA period
+    /// * `param_slow_period` - The slow EMA period
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the periods are invalid for [`lookback`]
+    pub fn new(param_fast_period: usize, param_slow_period: usize) -> Result<Self, KandError> {
+        let lookback = lookback(param_fast_period, param_slow_period)?;
+        Ok(Self {
+            param_fast_period,
+            param_slow_period,
+            lookback,
+            warmup_high: Vec::with_capacity(lookback + 1),
+            warmup_low: Vec::with_capacity(lookback + 1),
+            warmup_close: Vec::with_capacity(lookback + 1),
+            warmup_volume: Vec::with_capacity(lookback + 1),
+            ready: false,
+            prev_ad: TAFloat::NAN,
+            prev_ad_fast_ema: TAFloat::NAN,
+            prev_ad_slow_ema: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Adosc {
+    type Input = (TAFloat, TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close, input_volume): (TAFloat, TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        if self.ready {
+            let (output_adosc, output_ad, output_ad_fast_ema, output_ad_slow_ema) = adosc_inc(
+                input_high,
+                input_low,
+                input_close,
+                input_volume,
+                self.prev_ad,
+                self.prev_ad_fast_ema,
+                self.prev_ad_slow_ema,
+                self.param_fast_period,
+                self.param_slow_period,
+            )?;
+            self.prev_ad = output_ad;
+            self.prev_ad_fast_ema = output_ad_fast_ema;
+            self.prev_ad_slow_ema = output_ad_slow_ema;
+            return Ok(Some(output_adosc));
+        }
+
+        self.warmup_high.push(input_high);
+        self.warmup_low.push(input_low);
+        self.warmup_close.push(input_close);
+        self.warmup_volume.push(input_volume);
+        if self.warmup_close.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let len = self.warmup_close.len();
+        let mut output_adosc = vec![0.0; len];
+        let mut output_ad = vec![0.0; len];
+        let mut output_ad_fast_ema = vec![0.0; len];
+        let mut output_ad_slow_ema = vec![0.0; len];
+        adosc(
+            &self.warmup_high,
+            &self.warmup_low,
+            &self.warmup_close,
+            &self.warmup_volume,
+            self.param_fast_period,
+            self.param_slow_period,
+            &mut output_adosc,
+            &mut output_ad,
+            &mut output_ad_fast_ema,
+            &mut output_ad_slow_ema,
+        )?;
+        self.reset();
+        self.prev_ad = output_ad[self.lookback];
+        self.prev_ad_fast_ema = output_ad_fast_ema[self.lookback];
+        self.prev_ad_slow_ema = output_ad_slow_ema[self.lookback];
+        self.ready = true;
+        Ok(Some(output_adosc[self.lookback]))
+    }
+
+    fn reset(&mut self) {
+        self.warmup_high.clear();
+        self.warmup_low.clear();
+        self.warmup_close.clear();
+        self.warmup_volume.clear();
+        self.ready = false;
+        self.prev_ad = TAFloat::NAN;
+        self.prev_ad_fast_ema = TAFloat::NAN;
+        self.prev_ad_slow_ema = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -425,4 +561,61 @@ fn test_adosc_calculation() {
             prev_ad_slow_ema = output_ad_slow_ema_inc;
         }
     }
+
+    #[test]
+    fn test_adosc_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let param_fast_period = 3;
+        let param_slow_period = 10;
+        let len = input_close.len();
+        let mut output_adosc = vec![0.0; len];
+        let mut output_ad = vec![0.0; len];
+        let mut output_ad_fast_ema = vec![0.0; len];
+        let mut output_ad_slow_ema = vec![0.0; len];
+
+        adosc(
+            &input_high,
+            &input_low,
+            &input_close,
+            &input_volume,
+            param_fast_period,
+            param_slow_period,
+            &mut output_adosc,
+            &mut output_ad,
+            &mut output_ad_fast_ema,
+            &mut output_ad_slow_ema,
+        )
+        .unwrap();
+
+        let mut stream = Adosc::new(param_fast_period, param_slow_period).unwrap();
+        for i in 0..len {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i], input_volume[i]))
+                .unwrap()
+            {
+                Some(value) => assert_relative_eq!(value, output_adosc[i], epsilon = 0.00001),
+                None => assert!(output_adosc[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
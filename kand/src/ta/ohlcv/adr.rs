@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat, ta::ohlcv::sma};
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat, ta::ohlcv::sma};
 
 /// Returns the lookback period required for Average Daily Range (ADR) calculation.
 ///
@@ -184,6 +186,94 @@ pub fn adr_inc(
     sma::sma_inc(prev_adr, new_range, old_range, param_period)
 }
 
+/// Streaming Average Daily Range (ADR) calculator.
+///
+/// Consumes `(high, low)` bars and keeps the last `param_period` of them, so the bar leaving
+/// the window is available to [`adr_inc`]. The first value is seeded with [`adr`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::adr::Adr};
+///
+/// let mut adr = Adr::new(2).unwrap();
+/// assert_eq!(adr.update((10.0, 8.0)).unwrap(), None);
+/// assert_eq!(adr.update((11.0, 7.0)).unwrap(), Some(3.0));
+/// assert_eq!(adr.update((12.0, 10.0)).unwrap(), Some(3.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Adr {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<(TAFloat, TAFloat)>,
+    prev_adr:     Option<TAFloat>,
+}
+
+impl Adr {
+    /// Creates a new streaming ADR.
+    ///
+    /// # Arguments
+    /// * `param_period` - The period for ADR calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period),
+            prev_adr: None,
+        })
+    }
+}
+
+impl Indicator for Adr {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(&mut self, input: (TAFloat, TAFloat)) -> Result<Option<TAFloat>, KandError> {
+        if let Some(prev_adr) = self.prev_adr {
+            let (input_old_high, input_old_low) = self.window[0];
+            let output_adr = adr_inc(
+                prev_adr,
+                input.0,
+                input.1,
+                input_old_high,
+                input_old_low,
+                self.param_period,
+            )?;
+            self.window.pop_front();
+            self.window.push_back(input);
+            self.prev_adr = Some(output_adr);
+            return Ok(self.prev_adr);
+        }
+
+        self.window.push_back(input);
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let input_high: Vec<TAFloat> = self.window.iter().map(|bar| bar.0).collect();
+        let input_low: Vec<TAFloat> = self.window.iter().map(|bar| bar.1).collect();
+        let mut output_adr = vec![0.0; input_high.len()];
+        adr(&input_high, &input_low, self.param_period, &mut output_adr)?;
+        self.prev_adr = Some(output_adr[self.lookback]);
+        Ok(self.prev_adr)
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.prev_adr = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_adr.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -316,4 +406,31 @@ fn test_adr_edge_cases() {
             prev_adr = result;
         }
     }
+
+    #[test]
+    fn test_adr_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let param_period = 14;
+        let mut output_adr = vec![0.0; input_high.len()];
+
+        adr(&input_high, &input_low, param_period, &mut output_adr).unwrap();
+
+        let mut stream = Adr::new(param_period).unwrap();
+        for i in 0..input_high.len() {
+            match stream.update((input_high[i], input_low[i])).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_adr[i], epsilon = 0.00001),
+                None => assert!(output_adr[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
@@ -1,5 +1,5 @@
-use super::adx;
-use crate::{KandError, TAFloat};
+use super::{adx, dmi::Dmi};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Calculates the lookback period required for ADXR calculation
 ///
@@ -286,6 +286,70 @@ pub fn adxr_inc(
     ))
 }
 
+/// Streaming Average Directional Movement Index Rating (ADXR) calculator.
+///
+/// Consumes `(high, low, close)` bars through a shared [`Dmi`] state and emits the ADXR component
+/// once it is available.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::adxr::Adxr};
+///
+/// let mut adxr = Adxr::new(2).unwrap();
+/// let bars = [
+///     (10.0, 8.0, 9.0),
+///     (11.0, 9.0, 10.0),
+///     (12.0, 9.5, 11.0),
+///     (11.5, 9.0, 9.5),
+///     (12.5, 10.0, 12.0),
+/// ];
+/// let values: Vec<_> = bars.iter().map(|bar| adxr.update(*bar).unwrap()).collect();
+/// assert!(values[adxr.lookback() - 1].is_none());
+/// assert!(values[adxr.lookback()].is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Adxr {
+    dmi:      Dmi,
+    lookback: usize,
+}
+
+impl Adxr {
+    /// Creates a new streaming ADXR.
+    ///
+    /// # Arguments
+    /// * `param_period` - The smoothing period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            dmi:      Dmi::new(param_period)?,
+            lookback: lookback(param_period)?,
+        })
+    }
+}
+
+impl Indicator for Adxr {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(&mut self, input: (TAFloat, TAFloat, TAFloat)) -> Result<Option<TAFloat>, KandError> {
+        Ok(self.dmi.update(input)?.and_then(|output| output.adxr))
+    }
+
+    fn reset(&mut self) {
+        self.dmi.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.dmi.count() > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -410,4 +474,55 @@ fn test_adxr_calculation() {
             // TR
         }
     }
+
+    #[test]
+    fn test_adxr_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 5;
+        let len = input_close.len();
+        let mut output_adxr = vec![0.0; len];
+        let mut output_adx = vec![0.0; len];
+        let mut output_smoothed_plus_dm = vec![0.0; len];
+        let mut output_smoothed_minus_dm = vec![0.0; len];
+        let mut output_smoothed_tr = vec![0.0; len];
+
+        adxr(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_adxr,
+            &mut output_adx,
+            &mut output_smoothed_plus_dm,
+            &mut output_smoothed_minus_dm,
+            &mut output_smoothed_tr,
+        )
+        .unwrap();
+
+        let mut stream = Adxr::new(param_period).unwrap();
+        for i in 0..len {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some(value) => assert_relative_eq!(value, output_adxr[i], epsilon = 0.00001),
+                None => assert!(output_adxr[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
@@ -0,0 +1,393 @@
+use super::{
+    ma::Ma,
+    macdext::{self, Macdext},
+};
+use crate::{Indicator, KandError, TAFloat, types::MAType};
+
+/// Calculate the lookback period required for APO calculation
+///
+/// The APO line is valid once both the fast and the slow moving averages are, and the signal
+/// line needs the lookback of its own moving average on top of that.
+///
+/// # Arguments
+/// * `param_fast_period` - Fast MA period, must be >= 2
+/// * `param_fast_ma_type` - Fast MA type
+/// * `param_slow_period` - Slow MA period, must be >= 2; swapped with the fast line if shorter
+/// * `param_slow_ma_type` - Slow MA type
+/// * `param_signal_period` - Signal line period, must be >= 2
+/// * `param_signal_ma_type` - Signal line MA type
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Lookback period if successful
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If any period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::apo, types::MAType};
+/// let lookback = apo::lookback(12, MAType::SMA, 26, MAType::SMA, 9, MAType::SMA).unwrap();
+/// assert_eq!(lookback, 33); // 25 (slow SMA) + 8 (signal)
+/// ```
+pub fn lookback(
+    param_fast_period: usize,
+    param_fast_ma_type: MAType,
+    param_slow_period: usize,
+    param_slow_ma_type: MAType,
+    param_signal_period: usize,
+    param_signal_ma_type: MAType,
+) -> Result<usize, KandError> {
+    macdext::lookback(
+        param_fast_period,
+        param_fast_ma_type,
+        param_slow_period,
+        param_slow_ma_type,
+        param_signal_period,
+        param_signal_ma_type,
+    )
+}
+
+/// Calculate the Absolute Price Oscillator (APO) for a price series
+///
+/// APO is the difference between a fast and a slow moving average of price, expressed in price
+/// units. It is the MACD line with a selectable moving average type; TA-Lib's `APO` uses one
+/// type for both lines and has no signal line, which corresponds to passing the same
+/// `MAType` twice and ignoring the signal and histogram outputs.
+///
+/// # Mathematical Formula
+/// ```text
+/// Fast MA = MA(price, fast_period, fast_ma_type)
+/// Slow MA = MA(price, slow_period, slow_ma_type)
+/// APO = Fast MA - Slow MA
+/// Signal = MA(APO, signal_period, signal_ma_type)
+/// Histogram = APO - Signal
+/// ```
+///
+/// # Calculation Steps
+/// 1. Calculate fast and slow moving averages of price
+/// 2. Calculate APO once both moving averages are valid
+/// 3. Calculate signal line as moving average of the valid APO values
+/// 4. Calculate histogram as difference between APO and signal lines
+///
+/// # Arguments
+/// * `input_price` - Array of price values
+/// * `param_fast_period` - Fast MA period (typically 12)
+/// * `param_fast_ma_type` - Fast MA type
+/// * `param_slow_period` - Slow MA period (typically 26)
+/// * `param_slow_ma_type` - Slow MA type
+/// * `param_signal_period` - Signal line period (typically 9)
+/// * `param_signal_ma_type` - Signal line MA type
+/// * `output_apo` - Output buffer for APO values
+/// * `output_signal` - Output buffer for signal line values
+/// * `output_histogram` - Output buffer for histogram values
+/// * `output_fast_ma` - Output buffer for fast MA values
+/// * `output_slow_ma` - Output buffer for slow MA values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok if successful
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If any period is less than 2
+/// * `KandError::InsufficientData` - If input length <= required lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::apo, types::MAType};
+///
+/// let prices = vec![10.0, 12.0, 15.0, 11.0, 9.0, 10.0, 12.0];
+/// let mut output_apo = vec![0.0; prices.len()];
+/// let mut output_signal = vec![0.0; prices.len()];
+/// let mut output_histogram = vec![0.0; prices.len()];
+/// let mut output_fast_ma = vec![0.0; prices.len()];
+/// let mut output_slow_ma = vec![0.0; prices.len()];
+///
+/// apo::apo(
+///     &prices,
+///     2, // fast period
+///     MAType::SMA,
+///     3, // slow period
+///     MAType::SMA,
+///     2, // signal period
+///     MAType::SMA,
+///     &mut output_apo,
+///     &mut output_signal,
+///     &mut output_histogram,
+///     &mut output_fast_ma,
+///     &mut output_slow_ma,
+/// )
+/// .unwrap();
+/// // (15 + 11) / 2 - (12 + 15 + 11) / 3
+/// assert!((output_apo[3] - 0.333_333).abs() < 1e-6);
+/// assert!(output_signal[2].is_nan());
+/// ```
+pub fn apo(
+    input_price: &[TAFloat],
+    param_fast_period: usize,
+    param_fast_ma_type: MAType,
+    param_slow_period: usize,
+    param_slow_ma_type: MAType,
+    param_signal_period: usize,
+    param_signal_ma_type: MAType,
+    output_apo: &mut [TAFloat],
+    output_signal: &mut [TAFloat],
+    output_histogram: &mut [TAFloat],
+    output_fast_ma: &mut [TAFloat],
+    output_slow_ma: &mut [TAFloat],
+) -> Result<(), KandError> {
+    macdext::macdext(
+        input_price,
+        param_fast_period,
+        param_fast_ma_type,
+        param_slow_period,
+        param_slow_ma_type,
+        param_signal_period,
+        param_signal_ma_type,
+        output_apo,
+        output_signal,
+        output_histogram,
+        output_fast_ma,
+        output_slow_ma,
+    )
+}
+
+/// Calculate latest APO values incrementally from previous state
+///
+/// The moving averages are carried in streaming [`Ma`] states owned by the caller. Feed every
+/// bar from the start of the series; NaN is returned until the respective line is valid,
+/// matching the batch output bar for bar.
+///
+/// # Arguments
+/// * `input_price` - Current price value
+/// * `prev_count` - Number of prices already fed, i.e. the index of `input_price`
+/// * `fast_ma` - Fast moving average state, e.g. `Ma::new(12, MAType::SMA)`
+/// * `slow_ma` - Slow moving average state, e.g. `Ma::new(26, MAType::SMA)`
+/// * `signal_ma` - Signal line moving average state, e.g. `Ma::new(9, MAType::SMA)`
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (APO, Signal, Histogram),
+///   NaN while warming up
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If the input value is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{
+///     ohlcv::{apo, ma::Ma},
+///     types::MAType,
+/// };
+///
+/// let mut fast_ma = Ma::new(2, MAType::SMA).unwrap();
+/// let mut slow_ma = Ma::new(3, MAType::SMA).unwrap();
+/// let mut signal_ma = Ma::new(2, MAType::SMA).unwrap();
+///
+/// for (i, price) in [10.0, 12.0].into_iter().enumerate() {
+///     let (value, _, _) =
+///         apo::apo_inc(price, i, &mut fast_ma, &mut slow_ma, &mut signal_ma).unwrap();
+///     assert!(value.is_nan());
+/// }
+/// let (value, signal, _) =
+///     apo::apo_inc(15.0, 2, &mut fast_ma, &mut slow_ma, &mut signal_ma).unwrap();
+/// assert!((value - (13.5 - 37.0 / 3.0)).abs() < 1e-10);
+/// assert!(signal.is_nan());
+/// ```
+pub fn apo_inc(
+    input_price: TAFloat,
+    prev_count: usize,
+    fast_ma: &mut Ma,
+    slow_ma: &mut Ma,
+    signal_ma: &mut Ma,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    macdext::macdext_inc(input_price, prev_count, fast_ma, slow_ma, signal_ma)
+}
+
+/// Streaming Absolute Price Oscillator (APO) calculator.
+///
+/// Advances a [`Macdext`] stream, since APO with a signal line is MACD with selectable moving
+/// average types.
+///
+/// The output tuple is `(apo, signal, histogram)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::apo::Apo, types::MAType};
+///
+/// let mut apo = Apo::new(2, MAType::SMA, 3, MAType::SMA, 2, MAType::SMA).unwrap();
+/// assert_eq!(apo.lookback(), 3);
+/// for price in [1.0, 2.0, 3.0] {
+///     assert!(apo.update(price).unwrap().is_none());
+/// }
+/// let (value, signal, histogram) = apo.update(4.0).unwrap().unwrap();
+/// assert!((value - 0.5).abs() < 1e-10);
+/// assert!((value - signal - histogram).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Apo {
+    inner: Macdext,
+}
+
+impl Apo {
+    /// Creates a new streaming APO.
+    ///
+    /// # Arguments
+    /// * `param_fast_period` - Period for the fast MA (typically 12)
+    /// * `param_fast_ma_type` - Fast MA type
+    /// * `param_slow_period` - Period for the slow MA (typically 26)
+    /// * `param_slow_ma_type` - Slow MA type
+    /// * `param_signal_period` - Period for the signal line (typically 9)
+    /// * `param_signal_ma_type` - Signal line MA type
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period is less than 2
+    pub fn new(
+        param_fast_period: usize,
+        param_fast_ma_type: MAType,
+        param_slow_period: usize,
+        param_slow_ma_type: MAType,
+        param_signal_period: usize,
+        param_signal_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        Ok(Self {
+            inner: Macdext::new(
+                param_fast_period,
+                param_fast_ma_type,
+                param_slow_period,
+                param_slow_ma_type,
+                param_signal_period,
+                param_signal_ma_type,
+            )?,
+        })
+    }
+}
+
+impl Indicator for Apo {
+    type Input = TAFloat;
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        input_price: TAFloat,
+    ) -> Result<Option<(TAFloat, TAFloat, TAFloat)>, KandError> {
+        self.inner.update(input_price)
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.inner.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.inner.lookback()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::ohlcv::ma;
+
+    const INPUT_PRICE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    #[test]
+    fn test_apo_calculation() {
+        let len = INPUT_PRICE.len();
+        let mut output_apo = vec![0.0; len];
+        let mut output_signal = vec![0.0; len];
+        let mut output_histogram = vec![0.0; len];
+        let mut output_fast_ma = vec![0.0; len];
+        let mut output_slow_ma = vec![0.0; len];
+        apo(
+            &INPUT_PRICE,
+            3,
+            MAType::SMA,
+            10,
+            MAType::SMA,
+            4,
+            MAType::EMA,
+            &mut output_apo,
+            &mut output_signal,
+            &mut output_histogram,
+            &mut output_fast_ma,
+            &mut output_slow_ma,
+        )
+        .unwrap();
+
+        let lookback = lookback(3, MAType::SMA, 10, MAType::SMA, 4, MAType::EMA).unwrap();
+        assert_eq!(lookback, 12);
+        for value in &output_apo[..lookback] {
+            assert!(value.is_nan());
+        }
+
+        // Same values as TA-Lib APO(fastperiod=3, slowperiod=10, matype=SMA)
+        let mut expected_fast = vec![0.0; len];
+        let mut expected_slow = vec![0.0; len];
+        ma::ma(&INPUT_PRICE, 3, MAType::SMA, &mut expected_fast).unwrap();
+        ma::ma(&INPUT_PRICE, 10, MAType::SMA, &mut expected_slow).unwrap();
+        for i in lookback..len {
+            assert_relative_eq!(
+                output_apo[i],
+                expected_fast[i] - expected_slow[i],
+                epsilon = 0.00001
+            );
+            assert_relative_eq!(
+                output_histogram[i],
+                output_apo[i] - output_signal[i],
+                epsilon = 0.00001
+            );
+        }
+        assert_relative_eq!(output_apo[24], 24.46, epsilon = 0.00001);
+
+        // Incremental and streaming calculation match the batch output
+        let mut fast_ma = Ma::new(3, MAType::SMA).unwrap();
+        let mut slow_ma = Ma::new(10, MAType::SMA).unwrap();
+        let mut signal_ma = Ma::new(4, MAType::EMA).unwrap();
+        let mut stream = Apo::new(3, MAType::SMA, 10, MAType::SMA, 4, MAType::EMA).unwrap();
+        for (i, &price) in INPUT_PRICE.iter().enumerate() {
+            let (value, signal, histogram) =
+                apo_inc(price, i, &mut fast_ma, &mut slow_ma, &mut signal_ma).unwrap();
+            match stream.update(price).unwrap() {
+                Some((stream_value, stream_signal, stream_histogram)) => {
+                    assert!(i >= lookback);
+                    assert_relative_eq!(value, output_apo[i], epsilon = 0.00001);
+                    assert_relative_eq!(signal, output_signal[i], epsilon = 0.00001);
+                    assert_relative_eq!(histogram, output_histogram[i], epsilon = 0.00001);
+                    assert_relative_eq!(stream_value, value, epsilon = 0.00001);
+                    assert_relative_eq!(stream_signal, signal, epsilon = 0.00001);
+                    assert_relative_eq!(stream_histogram, histogram, epsilon = 0.00001);
+                }
+                None => {
+                    assert!(i < lookback);
+                    assert!(signal.is_nan());
+                }
+            }
+        }
+        assert!(stream.is_ready());
+
+        stream.reset();
+        assert!(!stream.is_ready());
+        assert!(stream.update(INPUT_PRICE[0]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_apo_invalid_periods() {
+        // A shorter slow period swaps the two lines, as in TA-Lib
+        assert_eq!(
+            lookback(26, MAType::SMA, 12, MAType::SMA, 9, MAType::SMA).unwrap(),
+            lookback(12, MAType::SMA, 26, MAType::SMA, 9, MAType::SMA).unwrap()
+        );
+        assert!(lookback(1, MAType::SMA, 12, MAType::SMA, 9, MAType::SMA).is_err());
+        assert!(Apo::new(12, MAType::EMA, 26, MAType::EMA, 1, MAType::SMA).is_err());
+    }
+}
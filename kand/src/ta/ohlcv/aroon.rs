@@ -1,7 +1,12 @@
 use crate::{
+    Indicator,
     TAFloat,
     error::KandError,
     helper::{highest_bars, lowest_bars},
+    ta::stats::{
+        max::{self, Max},
+        min::{self, Min},
+    },
 };
 
 /// Returns the lookback period required for Aroon indicator calculation.
@@ -208,19 +213,20 @@ pub fn aroon(
 /// # Description
 /// This function provides an optimized way to calculate the next Aroon values
 /// when processing streaming data, without recalculating the entire series.
+/// The highest high and lowest low of the last `param_period + 1` bars are tracked by rolling
+/// monotonic deques, so the days since each extreme stay exact after the extreme leaves the
+/// window.
 ///
 /// # Calculation Principle
-/// 1. Update days since last high/low by incrementing counters
-/// 2. Check if current high/low prices create new extremes
+/// 1. Push the current high/low into the rolling windows
+/// 2. Read the window extremes and the bars elapsed since each of them
 /// 3. Calculate Aroon values using updated information
 ///
 /// # Arguments
 /// * `input_high` - Current period's high price
 /// * `input_low` - Current period's low price
-/// * `prev_high` - Previous highest price in period
-/// * `prev_low` - Previous lowest price in period
-/// * `input_days_since_high` - Days since previous highest price
-/// * `input_days_since_low` - Days since previous lowest price
+/// * `rolling_high` - Rolling window of the last `param_period + 1` highs, updated in place
+/// * `rolling_low` - Rolling window of the last `param_period + 1` lows, updated in place
 /// * `param_period` - The lookback period
 ///
 /// # Returns
@@ -238,20 +244,102 @@ pub fn aroon(
 ///
 /// # Example
 /// ```
-/// use kand::ohlcv::aroon;
+/// use kand::{
+///     ohlcv::aroon,
+///     stats::{max::Max, min::Min},
+/// };
+///
+/// let param_period = 2;
+/// let mut rolling_high = Max::new(param_period + 1).unwrap();
+/// let mut rolling_low = Min::new(param_period + 1).unwrap();
+/// for (high, low) in [(14.0, 11.0), (13.0, 10.0)] {
+///     aroon::aroon_inc(high, low, &mut rolling_high, &mut rolling_low, param_period).unwrap();
+/// }
 ///
 /// let (aroon_up, aroon_down, new_high, new_low, days_high, days_low) = aroon::aroon_inc(
 ///     15.0, // Current high
 ///     12.0, // Current low
-///     14.0, // Previous high
-///     11.0, // Previous low
-///     2,    // Days since high
-///     1,    // Days since low
-///     14,   // Period
+///     &mut rolling_high,
+///     &mut rolling_low,
+///     param_period,
 /// )
 /// .unwrap();
+/// assert_eq!((aroon_up, aroon_down), (100.0, 50.0));
+/// assert_eq!((days_high, days_low), (0, 1));
 /// ```
 pub fn aroon_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    rolling_high: &mut Max,
+    rolling_low: &mut Min,
+    param_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, usize, usize), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    let new_high = max::max_inc(input_high, rolling_high)?;
+    let new_low = min::min_inc(input_low, rolling_low)?;
+    let days_since_high = rolling_high.bars_since().unwrap_or(0);
+    let days_since_low = rolling_low.bars_since().unwrap_or(0);
+
+    let param_period_t = param_period as TAFloat;
+    let hundred_t = 100.0;
+    let days_since_high_t = days_since_high as TAFloat;
+    let days_since_low_t = days_since_low as TAFloat;
+
+    let aroon_up = hundred_t - (hundred_t * days_since_high_t / param_period_t);
+    let aroon_down = hundred_t - (hundred_t * days_since_low_t / param_period_t);
+
+    Ok((
+        aroon_up,
+        aroon_down,
+        new_high,
+        new_low,
+        days_since_high,
+        days_since_low,
+    ))
+}
+
+/// Updates Aroon from the previous extremes and the bars elapsed since each of them.
+///
+/// This is the scalar form that predates [`Aroon`]. The stored extremes are never dropped once
+/// they leave the window, so the counters saturate at `param_period` instead of moving to the
+/// next extreme still inside it.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `prev_high` - Previous highest price
+/// * `prev_low` - Previous lowest price
+/// * `input_days_since_high` - Bars elapsed since the previous highest price
+/// * `input_days_since_low` - Bars elapsed since the previous lowest price
+/// * `param_period` - The lookback period (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, usize, usize), KandError>` - Tuple of
+///   (Aroon Up, Aroon Down, new high, new low, bars since high, bars since low)
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// # #![allow(deprecated)]
+/// use kand::ohlcv::aroon;
+///
+/// let (aroon_up, aroon_down, ..) =
+///     aroon::aroon_inc_legacy(15.0, 12.0, 14.0, 11.0, 2, 1, 10).unwrap();
+/// assert_eq!((aroon_up, aroon_down), (100.0, 80.0));
+/// ```
+#[deprecated(
+    note = "loses the period extreme once it leaves the window; use `aroon_inc` with `Max`/`Min`"
+)]
+pub fn aroon_inc_legacy(
     input_high: TAFloat,
     input_low: TAFloat,
     prev_high: TAFloat,
@@ -279,15 +367,12 @@ pub fn aroon_inc(
     let mut days_since_high = input_days_since_high;
     let mut days_since_low = input_days_since_low;
 
-    // Update days since high/low
     if days_since_high < param_period {
         days_since_high += 1;
     }
     if days_since_low < param_period {
         days_since_low += 1;
     }
-
-    // Check if current values are new high/low
     if input_high >= prev_high {
         new_high = input_high;
         days_since_high = 0;
@@ -299,11 +384,8 @@ pub fn aroon_inc(
 
     let param_period_t = param_period as TAFloat;
     let hundred_t = 100.0;
-    let days_since_high_t = days_since_high as TAFloat;
-    let days_since_low_t = days_since_low as TAFloat;
-
-    let aroon_up = hundred_t - (hundred_t * days_since_high_t / param_period_t);
-    let aroon_down = hundred_t - (hundred_t * days_since_low_t / param_period_t);
+    let aroon_up = hundred_t - (hundred_t * days_since_high as TAFloat / param_period_t);
+    let aroon_down = hundred_t - (hundred_t * days_since_low as TAFloat / param_period_t);
 
     Ok((
         aroon_up,
@@ -315,6 +397,79 @@ pub fn aroon_inc(
     ))
 }
 
+/// Streaming Aroon calculator.
+///
+/// Consumes `(high, low)` bars, owns the rolling windows used by [`aroon_inc`] and emits
+/// `(aroon_up, aroon_down)` pairs.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::aroon::Aroon};
+///
+/// let mut aroon = Aroon::new(2).unwrap();
+/// assert_eq!(aroon.update((14.0, 11.0)).unwrap(), None);
+/// assert_eq!(aroon.update((13.0, 10.0)).unwrap(), None);
+/// assert_eq!(aroon.update((15.0, 12.0)).unwrap(), Some((100.0, 50.0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Aroon {
+    param_period: usize,
+    rolling_high: Max,
+    rolling_low:  Min,
+}
+
+impl Aroon {
+    /// Creates a new streaming Aroon.
+    ///
+    /// # Arguments
+    /// * `param_period` - The lookback period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            rolling_high: Max::new(param_period + 1)?,
+            rolling_low: Min::new(param_period + 1)?,
+        })
+    }
+}
+
+impl Indicator for Aroon {
+    type Input = (TAFloat, TAFloat);
+    type Output = (TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        (input_high, input_low): (TAFloat, TAFloat),
+    ) -> Result<Option<(TAFloat, TAFloat)>, KandError> {
+        let (output_aroon_up, output_aroon_down, ..) = aroon_inc(
+            input_high,
+            input_low,
+            &mut self.rolling_high,
+            &mut self.rolling_low,
+            self.param_period,
+        )?;
+        Ok(self
+            .is_ready()
+            .then_some((output_aroon_up, output_aroon_down)))
+    }
+
+    fn reset(&mut self) {
+        self.rolling_high.reset();
+        self.rolling_low.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.rolling_high.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.rolling_high.lookback()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -439,30 +594,47 @@ fn test_aroon_calculation() {
         }
 
         // Test incremental calculation
-        let mut prev_high = output_prev_high[14];
-        let mut prev_low = output_prev_low[14];
-        let mut days_since_high = output_days_since_high[14];
-        let mut days_since_low = output_days_since_low[14];
+        let mut rolling_high = Max::new(param_period + 1).unwrap();
+        let mut rolling_low = Min::new(param_period + 1).unwrap();
+        for i in 0..=param_period {
+            aroon_inc(
+                input_high[i],
+                input_low[i],
+                &mut rolling_high,
+                &mut rolling_low,
+                param_period,
+            )
+            .unwrap();
+        }
 
-        for i in 15..20 {
+        for i in (param_period + 1)..input_high.len() {
             let result = aroon_inc(
                 input_high[i],
                 input_low[i],
-                prev_high,
-                prev_low,
-                days_since_high,
-                days_since_low,
+                &mut rolling_high,
+                &mut rolling_low,
                 param_period,
             )
             .unwrap();
 
             assert_relative_eq!(result.0, output_aroon_up[i], epsilon = 0.0001);
             assert_relative_eq!(result.1, output_aroon_down[i], epsilon = 0.0001);
+            assert_relative_eq!(result.2, output_prev_high[i], epsilon = 0.0001);
+            assert_relative_eq!(result.3, output_prev_low[i], epsilon = 0.0001);
+            assert_eq!(result.4, output_days_since_high[i]);
+            assert_eq!(result.5, output_days_since_low[i]);
+        }
 
-            prev_high = result.2;
-            prev_low = result.3;
-            days_since_high = result.4;
-            days_since_low = result.5;
+        // Streaming calculation matches the batch output bar for bar
+        let mut stream = Aroon::new(param_period).unwrap();
+        for i in 0..input_high.len() {
+            match stream.update((input_high[i], input_low[i])).unwrap() {
+                Some((aroon_up, aroon_down)) => {
+                    assert_relative_eq!(aroon_up, output_aroon_up[i], epsilon = 0.0001);
+                    assert_relative_eq!(aroon_down, output_aroon_down[i], epsilon = 0.0001);
+                }
+                None => assert!(output_aroon_up[i].is_nan()),
+            }
         }
     }
 }
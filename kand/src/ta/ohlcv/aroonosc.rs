@@ -1,7 +1,10 @@
+use super::aroon;
 use crate::{
+    Indicator,
     TAFloat,
     error::KandError,
     helper::{highest_bars, lowest_bars},
+    ta::stats::{max::Max, min::Min},
 };
 
 /// Returns the lookback period required for Aroon Oscillator calculation.
@@ -185,6 +188,8 @@ pub fn aroonosc(
 /// # Description
 /// This function provides an efficient way to calculate the next Aroon Oscillator value
 /// when new price data becomes available, without recalculating the entire series.
+/// It builds on [`aroon::aroon_inc`], so the window extremes are tracked exactly by rolling
+/// monotonic deques.
 ///
 /// # Mathematical Formula
 /// ```text
@@ -194,18 +199,16 @@ pub fn aroonosc(
 /// ```
 ///
 /// # Calculation Principle
-/// 1. Update days since high/low values
-/// 2. Check if new high/low prices are set
+/// 1. Push the current high/low into the rolling windows
+/// 2. Read the days since the window high/low
 /// 3. Calculate new Aroon Up and Down values
 /// 4. Calculate oscillator as difference between Up and Down
 ///
 /// # Arguments
 /// * `input_high` - Current period's high price
 /// * `input_low` - Current period's low price
-/// * `prev_high` - Previous highest price within the period
-/// * `prev_low` - Previous lowest price within the period
-/// * `input_days_since_high` - Days since previous highest price
-/// * `input_days_since_low` - Days since previous lowest price
+/// * `rolling_high` - Rolling window of the last `param_period + 1` highs, updated in place
+/// * `rolling_low` - Rolling window of the last `param_period + 1` lows, updated in place
 /// * `param_period` - The time period for Aroon calculation (must be >= 2)
 ///
 /// # Returns
@@ -222,20 +225,89 @@ pub fn aroonosc(
 ///
 /// # Example
 /// ```
-/// use kand::ohlcv::aroonosc::aroonosc_inc;
+/// use kand::{
+///     ohlcv::aroonosc::aroonosc_inc,
+///     stats::{max::Max, min::Min},
+/// };
+///
+/// let param_period = 2;
+/// let mut rolling_high = Max::new(param_period + 1).unwrap();
+/// let mut rolling_low = Min::new(param_period + 1).unwrap();
+/// for (high, low) in [(11.0, 10.0), (10.5, 9.5)] {
+///     aroonosc_inc(high, low, &mut rolling_high, &mut rolling_low, param_period).unwrap();
+/// }
 ///
 /// let (aroonosc, high, low, days_high, days_low) = aroonosc_inc(
 ///     10.0, // current high
 ///     9.0,  // current low
-///     11.0, // previous high
-///     10.0, // previous low
-///     1,    // days since high
-///     2,    // days since low
-///     14,   // period
+///     &mut rolling_high,
+///     &mut rolling_low,
+///     param_period,
 /// )
 /// .unwrap();
+/// assert_eq!((high, low, days_high, days_low), (11.0, 9.0, 2, 0));
+/// assert_eq!(aroonosc, -100.0);
 /// ```
 pub fn aroonosc_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    rolling_high: &mut Max,
+    rolling_low: &mut Min,
+    param_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat, usize, usize), KandError> {
+    let (aroon_up, aroon_down, new_high, new_low, days_since_high, days_since_low) =
+        aroon::aroon_inc(
+            input_high,
+            input_low,
+            rolling_high,
+            rolling_low,
+            param_period,
+        )?;
+
+    Ok((
+        aroon_up - aroon_down,
+        new_high,
+        new_low,
+        days_since_high,
+        days_since_low,
+    ))
+}
+
+/// Updates the Aroon Oscillator from the previous extremes and the bars elapsed since each.
+///
+/// This is the scalar form that predates [`Aroonosc`] and shares the limitation of
+/// [`aroon::aroon_inc_legacy`], which it is built on.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `prev_high` - Previous highest price
+/// * `prev_low` - Previous lowest price
+/// * `input_days_since_high` - Bars elapsed since the previous highest price
+/// * `input_days_since_low` - Bars elapsed since the previous lowest price
+/// * `param_period` - The lookback period (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, usize, usize), KandError>` - Tuple of
+///   (Aroon Oscillator, new high, new low, bars since high, bars since low)
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// # #![allow(deprecated)]
+/// use kand::ohlcv::aroonosc;
+///
+/// let (output, ..) = aroonosc::aroonosc_inc_legacy(15.0, 12.0, 14.0, 11.0, 2, 1, 10).unwrap();
+/// assert_eq!(output, 20.0);
+/// ```
+#[deprecated(
+    note = "loses the period extreme once it leaves the window; use `aroonosc_inc` with \
+            `Max`/`Min`"
+)]
+pub fn aroonosc_inc_legacy(
     input_high: TAFloat,
     input_low: TAFloat,
     prev_high: TAFloat,
@@ -244,57 +316,95 @@ pub fn aroonosc_inc(
     input_days_since_low: usize,
     param_period: usize,
 ) -> Result<(TAFloat, TAFloat, TAFloat, usize, usize), KandError> {
-    #[cfg(feature = "check")]
-    {
-        if param_period < 2 {
-            return Err(KandError::InvalidParameter);
-        }
-    }
+    #[allow(deprecated)]
+    let (aroon_up, aroon_down, new_high, new_low, days_since_high, days_since_low) =
+        aroon::aroon_inc_legacy(
+            input_high,
+            input_low,
+            prev_high,
+            prev_low,
+            input_days_since_high,
+            input_days_since_low,
+            param_period,
+        )?;
 
-    #[cfg(feature = "deep-check")]
-    {
-        if input_high.is_nan() || input_low.is_nan() || prev_high.is_nan() || prev_low.is_nan() {
-            return Err(KandError::NaNDetected);
-        }
-    }
+    Ok((
+        aroon_up - aroon_down,
+        new_high,
+        new_low,
+        days_since_high,
+        days_since_low,
+    ))
+}
 
-    let mut new_high = prev_high;
-    let mut new_low = prev_low;
-    let mut days_since_high = input_days_since_high;
-    let mut days_since_low = input_days_since_low;
+/// Streaming Aroon Oscillator calculator.
+///
+/// Consumes `(high, low)` bars and owns the rolling windows used by [`aroonosc_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::aroonosc::Aroonosc};
+///
+/// let mut aroonosc = Aroonosc::new(2).unwrap();
+/// assert_eq!(aroonosc.update((11.0, 10.0)).unwrap(), None);
+/// assert_eq!(aroonosc.update((10.5, 9.5)).unwrap(), None);
+/// assert_eq!(aroonosc.update((10.0, 9.0)).unwrap(), Some(-100.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Aroonosc {
+    param_period: usize,
+    rolling_high: Max,
+    rolling_low:  Min,
+}
 
-    if days_since_high < param_period {
-        days_since_high += 1;
-    }
-    if days_since_low < param_period {
-        days_since_low += 1;
+impl Aroonosc {
+    /// Creates a new streaming Aroon Oscillator.
+    ///
+    /// # Arguments
+    /// * `param_period` - The time period for Aroon calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            rolling_high: Max::new(param_period + 1)?,
+            rolling_low: Min::new(param_period + 1)?,
+        })
     }
+}
 
-    if input_high >= prev_high {
-        new_high = input_high;
-        days_since_high = 0;
-    }
-    if input_low <= prev_low {
-        new_low = input_low;
-        days_since_low = 0;
+impl Indicator for Aroonosc {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low): (TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        let (output_aroonosc, ..) = aroonosc_inc(
+            input_high,
+            input_low,
+            &mut self.rolling_high,
+            &mut self.rolling_low,
+            self.param_period,
+        )?;
+        Ok(self.is_ready().then_some(output_aroonosc))
     }
 
-    let param_period_t = param_period as TAFloat;
-    let hundred_t = 100.0;
-    let days_since_high_t = days_since_high as TAFloat;
-    let days_since_low_t = days_since_low as TAFloat;
+    fn reset(&mut self) {
+        self.rolling_high.reset();
+        self.rolling_low.reset();
+    }
 
-    let aroon_up = hundred_t - (hundred_t * days_since_high_t / param_period_t);
-    let aroon_down = hundred_t - (hundred_t * days_since_low_t / param_period_t);
-    let aroon_osc = aroon_up - aroon_down;
+    fn is_ready(&self) -> bool {
+        self.rolling_high.is_ready()
+    }
 
-    Ok((
-        aroon_osc,
-        new_high,
-        new_low,
-        days_since_high,
-        days_since_low,
-    ))
+    fn lookback(&self) -> usize {
+        self.rolling_high.lookback()
+    }
 }
 
 #[cfg(test)]
@@ -359,29 +469,43 @@ fn test_aroonosc_calculation() {
         }
 
         // Test incremental calculation
-        let mut prev_high = output_prev_high[14];
-        let mut prev_low = output_prev_low[14];
-        let mut days_since_high = output_days_since_high[14];
-        let mut days_since_low = output_days_since_low[14];
+        let mut rolling_high = Max::new(param_period + 1).unwrap();
+        let mut rolling_low = Min::new(param_period + 1).unwrap();
+        for i in 0..=param_period {
+            aroonosc_inc(
+                input_high[i],
+                input_low[i],
+                &mut rolling_high,
+                &mut rolling_low,
+                param_period,
+            )
+            .unwrap();
+        }
 
-        for i in 15..20 {
+        for i in (param_period + 1)..input_high.len() {
             let (aroon_osc, new_high, new_low, new_days_high, new_days_low) = aroonosc_inc(
                 input_high[i],
                 input_low[i],
-                prev_high,
-                prev_low,
-                days_since_high,
-                days_since_low,
+                &mut rolling_high,
+                &mut rolling_low,
                 param_period,
             )
             .unwrap();
 
             assert_relative_eq!(aroon_osc, output_aroonosc[i], epsilon = 0.0001);
+            assert_relative_eq!(new_high, output_prev_high[i], epsilon = 0.0001);
+            assert_relative_eq!(new_low, output_prev_low[i], epsilon = 0.0001);
+            assert_eq!(new_days_high, output_days_since_high[i]);
+            assert_eq!(new_days_low, output_days_since_low[i]);
+        }
 
-            prev_high = new_high;
-            prev_low = new_low;
-            days_since_high = new_days_high;
-            days_since_low = new_days_low;
+        // Streaming calculation matches the batch output bar for bar
+        let mut stream = Aroonosc::new(param_period).unwrap();
+        for i in 0..input_high.len() {
+            match stream.update((input_high[i], input_low[i])).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_aroonosc[i], epsilon = 0.0001),
+                None => assert!(output_aroonosc[i].is_nan()),
+            }
         }
     }
 }
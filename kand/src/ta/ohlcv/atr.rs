@@ -1,5 +1,5 @@
 use super::trange;
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period required for ATR calculation.
 ///
@@ -218,6 +218,116 @@ pub fn atr_inc(
     Ok(prev_atr.mul_add((param_period - 1) as TAFloat, tr) / (param_period as TAFloat))
 }
 
+/// Streaming Average True Range (ATR) calculator.
+///
+/// Consumes `(high, low, close)` bars and carries the previous close and ATR between bars.
+/// The first value is seeded with [`atr`], later values come from [`atr_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::atr::Atr};
+///
+/// let mut atr = Atr::new(2).unwrap();
+/// assert_eq!(atr.update((10.0, 8.0, 9.0)).unwrap(), None);
+/// assert_eq!(atr.update((11.0, 9.0, 10.0)).unwrap(), None);
+/// assert_eq!(atr.update((12.0, 9.0, 11.0)).unwrap(), Some(2.5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Atr {
+    param_period: usize,
+    lookback:     usize,
+    warmup_high:  Vec<TAFloat>,
+    warmup_low:   Vec<TAFloat>,
+    warmup_close: Vec<TAFloat>,
+    ready:        bool,
+    prev_close:   TAFloat,
+    prev_atr:     TAFloat,
+}
+
+impl Atr {
+    /// Creates a new streaming ATR.
+    ///
+    /// # Arguments
+    /// * `param_period` - The time period for ATR calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            lookback,
+            warmup_high: Vec::with_capacity(lookback + 1),
+            warmup_low: Vec::with_capacity(lookback + 1),
+            warmup_close: Vec::with_capacity(lookback + 1),
+            ready: false,
+            prev_close: TAFloat::NAN,
+            prev_atr: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Atr {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        if self.ready {
+            let output_atr = atr_inc(
+                input_high,
+                input_low,
+                self.prev_close,
+                self.prev_atr,
+                self.param_period,
+            )?;
+            self.prev_close = input_close;
+            self.prev_atr = output_atr;
+            return Ok(Some(output_atr));
+        }
+
+        self.warmup_high.push(input_high);
+        self.warmup_low.push(input_low);
+        self.warmup_close.push(input_close);
+        if self.warmup_close.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let mut output_atr = vec![0.0; self.warmup_close.len()];
+        atr(
+            &self.warmup_high,
+            &self.warmup_low,
+            &self.warmup_close,
+            self.param_period,
+            &mut output_atr,
+        )?;
+        self.reset();
+        self.prev_close = input_close;
+        self.prev_atr = output_atr[self.lookback];
+        self.ready = true;
+        Ok(Some(self.prev_atr))
+    }
+
+    fn reset(&mut self) {
+        self.warmup_high.clear();
+        self.warmup_low.clear();
+        self.warmup_close.clear();
+        self.ready = false;
+        self.prev_close = TAFloat::NAN;
+        self.prev_atr = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -295,4 +405,46 @@ fn test_atr_calculation() {
             prev_atr = result;
         }
     }
+
+    #[test]
+    fn test_atr_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 14;
+        let mut output_atr = vec![0.0; input_close.len()];
+
+        atr(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_atr,
+        )
+        .unwrap();
+
+        let mut stream = Atr::new(param_period).unwrap();
+        for i in 0..input_close.len() {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some(value) => assert_relative_eq!(value, output_atr[i], epsilon = 0.00001),
+                None => assert!(output_atr[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
@@ -0,0 +1,240 @@
+use crate::{KandError, TAFloat};
+
+/// Returns the lookback period required for %B and bandwidth calculation.
+///
+/// # Description
+/// Both values are computed bar by bar from existing bands, so the lookback is 0; bars on
+/// which the bands are still warming up yield NaN.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns 0 as the lookback period
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::bandwidth;
+/// assert_eq!(bandwidth::lookback().unwrap(), 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Calculates %B and bandwidth for any price band.
+///
+/// # Description
+/// %B locates the price within the band: 0 on the lower band, 1 on the upper band, outside
+/// [0, 1] when the price breaks out. Bandwidth is the band width relative to the middle line;
+/// low readings mark the volatility squeezes that tend to precede breakouts.
+///
+/// Works with the output of any band indicator, e.g. [`bbands`](super::bbands),
+/// [`keltner`](super::keltner), [`donchian`](super::donchian) or [`starc`](super::starc).
+///
+/// # Mathematical Formula
+/// ```text
+/// %B        = (Price - Lower) / (Upper - Lower)
+/// Bandwidth = (Upper - Lower) / Middle
+/// ```
+/// A band of zero width gives a %B of 0.5 and a zero middle line gives a bandwidth of 0.
+///
+/// # Arguments
+/// * `input_price` - Array of prices, usually the close
+/// * `input_upper` - Array of upper band values
+/// * `input_middle` - Array of middle band values
+/// * `input_lower` - Array of lower band values
+/// * `output_percent_b` - Array storing the %B values
+/// * `output_bandwidth` - Array storing the bandwidth values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::bandwidth;
+///
+/// let input_price = vec![10.0, 12.0, 7.0];
+/// let input_upper = vec![f64::NAN, 12.0, 12.0];
+/// let input_middle = vec![f64::NAN, 10.0, 10.0];
+/// let input_lower = vec![f64::NAN, 8.0, 8.0];
+/// let mut output_percent_b = vec![0.0; 3];
+/// let mut output_bandwidth = vec![0.0; 3];
+///
+/// bandwidth::bandwidth(
+///     &input_price,
+///     &input_upper,
+///     &input_middle,
+///     &input_lower,
+///     &mut output_percent_b,
+///     &mut output_bandwidth,
+/// )
+/// .unwrap();
+///
+/// assert!(output_percent_b[0].is_nan());
+/// assert_eq!(output_percent_b[1..], [1.0, -0.25]);
+/// assert_eq!(output_bandwidth[1..], [0.4, 0.4]);
+/// ```
+pub fn bandwidth(
+    input_price: &[TAFloat],
+    input_upper: &[TAFloat],
+    input_middle: &[TAFloat],
+    input_lower: &[TAFloat],
+    output_percent_b: &mut [TAFloat],
+    output_bandwidth: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_price.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_upper.len() != len
+            || input_middle.len() != len
+            || input_lower.len() != len
+            || output_percent_b.len() != len
+            || output_bandwidth.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    for i in 0..len {
+        output_percent_b[i] = percent_b_value(input_price[i], input_upper[i], input_lower[i]);
+        output_bandwidth[i] = bandwidth_value(input_upper[i], input_middle[i], input_lower[i]);
+    }
+
+    Ok(())
+}
+
+/// Calculates %B for a single bar.
+///
+/// # Arguments
+/// * `input_price` - Current price
+/// * `input_upper` - Current upper band
+/// * `input_lower` - Current lower band
+///
+/// # Returns
+/// * `TAFloat` - `(price - lower) / (upper - lower)`, 0.5 for a band of zero width
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::bandwidth;
+///
+/// assert_eq!(bandwidth::percent_b_value(11.0, 12.0, 8.0), 0.75);
+/// assert_eq!(bandwidth::percent_b_value(11.0, 10.0, 10.0), 0.5);
+/// ```
+#[must_use]
+pub fn percent_b_value(
+    input_price: TAFloat,
+    input_upper: TAFloat,
+    input_lower: TAFloat,
+) -> TAFloat {
+    let width = input_upper - input_lower;
+    if width == 0.0 {
+        0.5
+    } else {
+        (input_price - input_lower) / width
+    }
+}
+
+/// Calculates bandwidth for a single bar.
+///
+/// # Arguments
+/// * `input_upper` - Current upper band
+/// * `input_middle` - Current middle band
+/// * `input_lower` - Current lower band
+///
+/// # Returns
+/// * `TAFloat` - `(upper - lower) / middle`, 0 for a zero middle band
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::bandwidth;
+///
+/// assert_eq!(bandwidth::bandwidth_value(12.0, 10.0, 8.0), 0.4);
+/// ```
+#[must_use]
+pub fn bandwidth_value(
+    input_upper: TAFloat,
+    input_middle: TAFloat,
+    input_lower: TAFloat,
+) -> TAFloat {
+    if input_middle == 0.0 {
+        0.0
+    } else {
+        (input_upper - input_lower) / input_middle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::{ohlcv::bbands, types::MAType};
+
+    #[test]
+    fn test_bandwidth_on_bbands() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6,
+        ];
+        let len = input_price.len();
+        let param_period = 5;
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+        let mut output_sma = vec![0.0; len];
+        let mut output_var = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_sq = vec![0.0; len];
+        bbands::bbands(
+            &input_price,
+            param_period,
+            2.0,
+            2.0,
+            MAType::SMA,
+            &mut output_upper,
+            &mut output_middle,
+            &mut output_lower,
+            &mut output_sma,
+            &mut output_var,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+        .unwrap();
+
+        let mut output_percent_b = vec![0.0; len];
+        let mut output_bandwidth = vec![0.0; len];
+        bandwidth(
+            &input_price,
+            &output_upper,
+            &output_middle,
+            &output_lower,
+            &mut output_percent_b,
+            &mut output_bandwidth,
+        )
+        .unwrap();
+
+        for i in 0..param_period - 1 {
+            assert!(output_percent_b[i].is_nan());
+            assert!(output_bandwidth[i].is_nan());
+        }
+        for i in param_period - 1..len {
+            // With ±2σ bands, %B is a z-score mapped onto [0, 1]
+            let z_score = (input_price[i] - output_sma[i]) / output_var[i].sqrt();
+            assert_relative_eq!(output_percent_b[i], 0.5 + z_score / 4.0, epsilon = 1e-9);
+            assert_relative_eq!(
+                output_bandwidth[i],
+                4.0 * output_var[i].sqrt() / output_sma[i],
+                epsilon = 1e-12
+            );
+        }
+    }
+}
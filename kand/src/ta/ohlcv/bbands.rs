@@ -1,18 +1,27 @@
 use crate::{
+    Indicator,
     KandError,
     TAFloat,
-    ta::{ohlcv::sma, stats::var},
+    ta::{
+        ohlcv::{
+            ma::{self, Ma},
+            sma,
+        },
+        stats::var::{self, Var},
+    },
+    types::MAType,
 };
 
 /// Returns the lookback period required for Bollinger Bands calculation.
 ///
 /// # Description
 /// The lookback period represents the minimum number of data points needed before
-/// the first valid output can be calculated. For Bollinger Bands, this equals
-/// the specified period parameter.
+/// the first valid output can be calculated. For Bollinger Bands, this is the larger of the
+/// middle band moving average lookback and the `period - 1` bars needed by the deviation.
 ///
 /// # Arguments
 /// * `param_period` - The time period used for calculations (must be >= 2)
+/// * `param_ma_type` - The moving average type used for the middle band
 ///
 /// # Returns
 /// * `Result<usize, KandError>` - The lookback period on success, or error on failure
@@ -22,13 +31,16 @@
 ///
 /// # Example
 /// ```
-/// use kand::ta::ohlcv::bbands;
+/// use kand::{ta::ohlcv::bbands, types::MAType};
 /// let period = 20;
-/// let lookback = bbands::lookback(period).unwrap();
+/// let lookback = bbands::lookback(period, MAType::SMA).unwrap();
 /// assert_eq!(lookback, 19);
+/// let lookback = bbands::lookback(period, MAType::DEMA).unwrap();
+/// assert_eq!(lookback, 38);
 /// ```
-pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
-    sma::lookback(param_period)
+pub fn lookback(param_period: usize, param_ma_type: MAType) -> Result<usize, KandError> {
+    let ma_lookback = ma::lookback(param_period, param_ma_type)?;
+    Ok(ma_lookback.max(var::lookback(param_period)?))
 }
 
 /// Calculates Bollinger Bands for a price series.
@@ -36,13 +48,13 @@ pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
 /// # Description
 /// Bollinger Bands are volatility bands placed above and below a moving average.
 /// They consist of:
-/// - A middle band (N-period simple moving average)
+/// - A middle band (N-period moving average, simple by default)
 /// - An upper band (K standard deviations above middle band)
 /// - A lower band (K standard deviations below middle band)
 ///
 /// # Mathematical Formula
 /// ```text
-/// Middle Band = SMA(price, N)
+/// Middle Band = MA(price, N, MAType)
 /// Standard Deviation = sqrt(sum((price - SMA)^2) / N)
 /// Upper Band = Middle Band + (K × Standard Deviation)
 /// Lower Band = Middle Band - (K × Standard Deviation)
@@ -52,7 +64,7 @@ pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
 /// - K is the number of standard deviations
 ///
 /// # Calculation Steps
-/// 1. Calculate N-period SMA as middle band
+/// 1. Calculate N-period moving average of the selected type as middle band
 /// 2. Calculate N-period standard deviation
 /// 3. Add/subtract K standard deviations to get upper/lower bands
 ///
@@ -61,6 +73,7 @@ pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
 /// * `param_period` - The time period for calculations (must be >= 2)
 /// * `param_dev_up` - Number of standard deviations for upper band
 /// * `param_dev_down` - Number of standard deviations for lower band
+/// * `param_ma_type` - The moving average type used for the middle band
 /// * `output_upper` - Buffer to store upper band values
 /// * `output_middle` - Buffer to store middle band values
 /// * `output_lower` - Buffer to store lower band values
@@ -81,7 +94,7 @@ pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
 ///
 /// # Example
 /// ```
-/// use kand::ta::ohlcv::bbands;
+/// use kand::{ta::ohlcv::bbands, types::MAType};
 /// let prices = vec![10.0, 11.0, 12.0, 13.0, 14.0];
 /// let period = 3;
 /// let mut upper = vec![0.0; 5];
@@ -97,6 +110,7 @@ pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
 ///     period,
 ///     2.0,
 ///     2.0,
+///     MAType::SMA,
 ///     &mut upper,
 ///     &mut middle,
 ///     &mut lower,
@@ -112,6 +126,7 @@ pub fn bbands(
     param_period: usize,
     param_dev_up: TAFloat,
     param_dev_down: TAFloat,
+    param_ma_type: MAType,
     output_upper: &mut [TAFloat],
     output_middle: &mut [TAFloat],
     output_lower: &mut [TAFloat],
@@ -121,7 +136,7 @@ pub fn bbands(
     output_sum_sq: &mut [TAFloat],
 ) -> Result<(), KandError> {
     let len = input_price.len();
-    let lookback = lookback(param_period)?;
+    let lookback = lookback(param_period, param_ma_type)?;
 
     #[cfg(feature = "check")]
     {
@@ -157,8 +172,13 @@ pub fn bbands(
         }
     }
 
-    // Calculate SMA first
+    // Calculate SMA first, it is also the middle band for the default MA type
     sma::sma(input_price, param_period, output_sma)?;
+    if param_ma_type == MAType::SMA {
+        output_middle.copy_from_slice(output_sma);
+    } else {
+        ma::ma(input_price, param_period, param_ma_type, output_middle)?;
+    }
 
     // Calculate variance
     var::var(
@@ -170,12 +190,11 @@ pub fn bbands(
     )?;
 
     for i in lookback..len {
-        output_middle[i] = output_sma[i];
         let std_dev = output_var[i].sqrt();
 
         // Calculate upper and lower bands using standard deviations
-        output_upper[i] = param_dev_up.mul_add(std_dev, output_sma[i]);
-        output_lower[i] = param_dev_down.mul_add(-std_dev, output_sma[i]);
+        output_upper[i] = param_dev_up.mul_add(std_dev, output_middle[i]);
+        output_lower[i] = param_dev_down.mul_add(-std_dev, output_middle[i]);
     }
 
     // Fill initial values with NAN
@@ -197,7 +216,8 @@ pub fn bbands(
 /// # Description
 /// This function provides an optimized way to calculate the next set of Bollinger Bands values
 /// when new data arrives, without recalculating the entire series. It uses the previous values
-/// to compute the new bands efficiently.
+/// to compute the new bands efficiently. The middle band is always the SMA here; use [`Bbands`]
+/// to stream bands around other moving average types.
 ///
 /// # Calculation Steps
 /// 1. Calculate new SMA using incremental approach
@@ -293,6 +313,94 @@ pub fn bbands_inc(
     Ok((upper, new_sma, lower, new_sma, new_sum, new_sum_sq))
 }
 
+/// Streaming Bollinger Bands calculator.
+///
+/// Combines a streaming moving average of the selected type for the middle band with a
+/// streaming [`Var`] for the deviation, and emits `(upper, middle, lower)` bands once both are
+/// warmed up.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::bbands::Bbands, types::MAType};
+///
+/// let mut bbands = Bbands::new(2, 2.0, 2.0, MAType::SMA).unwrap();
+/// assert_eq!(bbands.update(1.0).unwrap(), None);
+/// assert_eq!(bbands.update(3.0).unwrap(), Some((4.0, 2.0, 0.0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bbands {
+    param_dev_up:   TAFloat,
+    param_dev_down: TAFloat,
+    lookback:       usize,
+    ma:             Ma,
+    var:            Var,
+    ready:          bool,
+}
+
+impl Bbands {
+    /// Creates a new streaming Bollinger Bands.
+    ///
+    /// # Arguments
+    /// * `param_period` - The time period for the moving average (must be >= 2)
+    /// * `param_dev_up` - Standard deviation multiplier for the upper band
+    /// * `param_dev_down` - Standard deviation multiplier for the lower band
+    /// * `param_ma_type` - The moving average type used for the middle band
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(
+        param_period: usize,
+        param_dev_up: TAFloat,
+        param_dev_down: TAFloat,
+        param_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        Ok(Self {
+            param_dev_up,
+            param_dev_down,
+            lookback: lookback(param_period, param_ma_type)?,
+            ma: Ma::new(param_period, param_ma_type)?,
+            var: Var::new(param_period)?,
+            ready: false,
+        })
+    }
+}
+
+impl Indicator for Bbands {
+    type Input = TAFloat;
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        input_price: TAFloat,
+    ) -> Result<Option<(TAFloat, TAFloat, TAFloat)>, KandError> {
+        let output_middle = self.ma.update(input_price)?;
+        let output_var = self.var.update(input_price)?;
+        let (Some(output_middle), Some(output_var)) = (output_middle, output_var) else {
+            return Ok(None);
+        };
+
+        self.ready = true;
+        let std_dev = output_var.sqrt();
+        let output_upper = self.param_dev_up.mul_add(std_dev, output_middle);
+        let output_lower = self.param_dev_down.mul_add(-std_dev, output_middle);
+        Ok(Some((output_upper, output_middle, output_lower)))
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.var.reset();
+        self.ready = false;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -325,6 +433,7 @@ fn test_bbands_calculation() {
             param_period,
             param_dev_up,
             param_dev_down,
+            MAType::SMA,
             &mut output_upper,
             &mut output_middle,
             &mut output_lower,
@@ -465,4 +574,124 @@ fn test_bbands_calculation() {
             prev_sum_sq = new_sum_sq;
         }
     }
+
+    #[test]
+    fn test_bbands_stream() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 10;
+        let len = input_price.len();
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+        let mut output_sma = vec![0.0; len];
+        let mut output_var = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_sq = vec![0.0; len];
+
+        bbands(
+            &input_price,
+            param_period,
+            2.0,
+            2.0,
+            MAType::SMA,
+            &mut output_upper,
+            &mut output_middle,
+            &mut output_lower,
+            &mut output_sma,
+            &mut output_var,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+        .unwrap();
+
+        let mut stream = Bbands::new(param_period, 2.0, 2.0, MAType::SMA).unwrap();
+        for (i, price) in input_price.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some((upper, middle, lower)) => {
+                    assert_relative_eq!(upper, output_upper[i], epsilon = 0.00001);
+                    assert_relative_eq!(middle, output_middle[i], epsilon = 0.00001);
+                    assert_relative_eq!(lower, output_lower[i], epsilon = 0.00001);
+                }
+                None => assert!(output_upper[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_bbands_ma_type() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 5;
+        let len = input_price.len();
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+        let mut output_sma = vec![0.0; len];
+        let mut output_var = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_sq = vec![0.0; len];
+
+        bbands(
+            &input_price,
+            param_period,
+            2.0,
+            1.5,
+            MAType::DEMA,
+            &mut output_upper,
+            &mut output_middle,
+            &mut output_lower,
+            &mut output_sma,
+            &mut output_var,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+        .unwrap();
+
+        let lookback = lookback(param_period, MAType::DEMA).unwrap();
+        assert_eq!(lookback, 8);
+        let mut expected_middle = vec![0.0; len];
+        ma::ma(
+            &input_price,
+            param_period,
+            MAType::DEMA,
+            &mut expected_middle,
+        )
+        .unwrap();
+
+        let mut stream = Bbands::new(param_period, 2.0, 1.5, MAType::DEMA).unwrap();
+        for i in 0..len {
+            let output = stream.update(input_price[i]).unwrap();
+            if i < lookback {
+                assert!(output_middle[i].is_nan());
+                assert!(output.is_none());
+                continue;
+            }
+
+            let std_dev = output_var[i].sqrt();
+            assert_relative_eq!(output_middle[i], expected_middle[i], epsilon = 0.00001);
+            assert_relative_eq!(
+                output_upper[i],
+                expected_middle[i] + 2.0 * std_dev,
+                epsilon = 0.00001
+            );
+            assert_relative_eq!(
+                output_lower[i],
+                expected_middle[i] - 1.5 * std_dev,
+                epsilon = 0.00001
+            );
+
+            let (upper, middle, lower) = output.unwrap();
+            assert_relative_eq!(upper, output_upper[i], epsilon = 0.00001);
+            assert_relative_eq!(middle, output_middle[i], epsilon = 0.00001);
+            assert_relative_eq!(lower, output_lower[i], epsilon = 0.00001);
+        }
+    }
 }
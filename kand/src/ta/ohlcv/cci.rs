@@ -1,5 +1,7 @@
+use std::collections::VecDeque;
+
 use super::{sma, typprice};
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period required for CCI calculation.
 ///
@@ -314,6 +316,131 @@ pub fn cci_inc(
     })
 }
 
+/// Streaming Commodity Channel Index (CCI) calculator.
+///
+/// Consumes `(high, low, close)` bars and keeps the last `param_period` bars together with the
+/// typical price buffer required by [`cci_inc`]. The first value is seeded with [`cci`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::cci::Cci};
+///
+/// let mut cci = Cci::new(3).unwrap();
+/// assert_eq!(cci.update((10.0, 8.0, 9.0)).unwrap(), None);
+/// assert_eq!(cci.update((11.0, 9.0, 10.0)).unwrap(), None);
+/// let value = cci.update((12.0, 10.0, 11.0)).unwrap().unwrap();
+/// assert!((value - 100.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cci {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<(TAFloat, TAFloat, TAFloat)>,
+    tp_buffer:    Vec<TAFloat>,
+    prev_sma_tp:  Option<TAFloat>,
+}
+
+impl Cci {
+    /// Creates a new streaming CCI.
+    ///
+    /// # Arguments
+    /// * `param_period` - The time period for CCI calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period),
+            tp_buffer: Vec::with_capacity(param_period),
+            prev_sma_tp: None,
+        })
+    }
+}
+
+impl Indicator for Cci {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(&mut self, input: (TAFloat, TAFloat, TAFloat)) -> Result<Option<TAFloat>, KandError> {
+        let (input_high, input_low, input_close) = input;
+        if let Some(prev_sma_tp) = self.prev_sma_tp {
+            let (input_old_high, input_old_low, input_old_close) = self.window[0];
+            let output_cci = cci_inc(
+                prev_sma_tp,
+                input_high,
+                input_low,
+                input_close,
+                input_old_high,
+                input_old_low,
+                input_old_close,
+                self.param_period,
+                &mut self.tp_buffer,
+            )?;
+            let old_tp = (input_old_high + input_old_low + input_old_close) / 3.0;
+            let new_tp = (input_high + input_low + input_close) / 3.0;
+            self.prev_sma_tp = Some(sma::sma_inc(
+                prev_sma_tp,
+                new_tp,
+                old_tp,
+                self.param_period,
+            )?);
+            self.window.pop_front();
+            self.window.push_back(input);
+            return Ok(Some(output_cci));
+        }
+
+        self.window.push_back(input);
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let len = self.window.len();
+        let input_high: Vec<TAFloat> = self.window.iter().map(|bar| bar.0).collect();
+        let input_low: Vec<TAFloat> = self.window.iter().map(|bar| bar.1).collect();
+        let input_close: Vec<TAFloat> = self.window.iter().map(|bar| bar.2).collect();
+        let mut output_cci = vec![0.0; len];
+        let mut output_tp = vec![0.0; len];
+        let mut output_tp_sma = vec![0.0; len];
+        let mut output_mean_dev = vec![0.0; len];
+        cci(
+            &input_high,
+            &input_low,
+            &input_close,
+            self.param_period,
+            &mut output_cci,
+            &mut output_tp,
+            &mut output_tp_sma,
+            &mut output_mean_dev,
+        )?;
+        // The batch function NaN-fills the typical prices before the lookback, so rebuild the
+        // buffer from the bars in the window.
+        self.tp_buffer.clear();
+        self.tp_buffer.extend(
+            self.window
+                .iter()
+                .map(|&(high, low, close)| (high + low + close) / 3.0),
+        );
+        self.prev_sma_tp = Some(output_tp_sma[self.lookback]);
+        Ok(Some(output_cci[self.lookback]))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.tp_buffer.clear();
+        self.prev_sma_tp = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_sma_tp.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -411,4 +538,53 @@ fn test_cci_calculation() {
             assert_relative_eq!(result, output_cci[i], epsilon = 0.00001);
         }
     }
+
+    #[test]
+    fn test_cci_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 14;
+        let len = input_close.len();
+        let mut output_cci = vec![0.0; len];
+        let mut output_tp = vec![0.0; len];
+        let mut output_tp_sma = vec![0.0; len];
+        let mut output_mean_dev = vec![0.0; len];
+
+        cci(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_cci,
+            &mut output_tp,
+            &mut output_tp_sma,
+            &mut output_mean_dev,
+        )
+        .unwrap();
+
+        let mut stream = Cci::new(param_period).unwrap();
+        for i in 0..len {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some(value) => assert_relative_eq!(value, output_cci[i], epsilon = 0.00001),
+                None => assert!(output_cci[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    Indicator,
     KandError,
     TAFloat,
     TAInt,
@@ -274,6 +275,107 @@ pub fn cdl_inverted_hammer_inc(
     Ok((signal, body_avg))
 }
 
+/// Streaming Inverted Hammer pattern detector.
+///
+/// Averages the bodies of the first `param_period` candles like [`cdl_inverted_hammer`] and
+/// classifies every candle from the last of them on with [`cdl_inverted_hammer_inc`].
+///
+/// The input tuple is `(open, high, low, close)` and the output is the pattern signal.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::cdl_inverted_hammer::CdlInvertedHammer};
+///
+/// let mut detector = CdlInvertedHammer::new(2, 2.0).unwrap();
+/// assert_eq!(detector.update((10.0, 11.0, 9.0, 10.5)).unwrap(), None);
+/// assert!(detector.update((10.5, 11.0, 10.0, 10.8)).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlInvertedHammer {
+    param_period: usize,
+    param_factor: TAFloat,
+    lookback:     usize,
+    count:        usize,
+    body_sum:     TAFloat,
+    body_avg:     TAFloat,
+}
+
+impl CdlInvertedHammer {
+    /// Creates a new streaming Inverted Hammer detector.
+    ///
+    /// # Arguments
+    /// * `param_period` - Period for EMA calculation of body sizes (must be >= 2)
+    /// * `param_factor` - Minimum ratio of upper shadow to body length (typically 2.0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the period is less than 2 or the factor is
+    ///   not positive
+    pub fn new(param_period: usize, param_factor: TAFloat) -> Result<Self, KandError> {
+        #[cfg(feature = "check")]
+        {
+            if param_factor <= 0.0 {
+                return Err(KandError::InvalidParameter);
+            }
+        }
+
+        Ok(Self {
+            param_period,
+            param_factor,
+            lookback: lookback(param_period)?,
+            count: 0,
+            body_sum: 0.0,
+            body_avg: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for CdlInvertedHammer {
+    type Input = (TAFloat, TAFloat, TAFloat, TAFloat);
+    type Output = TAInt;
+
+    fn update(
+        &mut self,
+        (input_open, input_high, input_low, input_close): Self::Input,
+    ) -> Result<Option<TAInt>, KandError> {
+        let body = real_body_length(input_open, input_close);
+        self.count += 1;
+        if self.count <= self.lookback {
+            self.body_sum += body;
+            return Ok(None);
+        }
+
+        if self.count == self.lookback + 1 {
+            self.body_avg = (self.body_sum + body) / self.param_period as TAFloat;
+        }
+
+        let signal;
+        (signal, self.body_avg) = cdl_inverted_hammer_inc(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            self.body_avg,
+            self.param_period,
+            self.param_factor,
+        )?;
+        Ok(Some(signal))
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.body_sum = 0.0;
+        self.body_avg = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -358,5 +460,16 @@ fn test_cdl_inverted_hammer() {
             assert_relative_eq!(new_body_avg, output_body_avg[i], epsilon = 0.00001);
             prev_body_avg = new_body_avg;
         }
+
+        // Streaming detection matches the batch signals
+        let mut stream = CdlInvertedHammer::new(param_period, param_factor).unwrap();
+        for i in 0..input_open.len() {
+            let candle = (input_open[i], input_high[i], input_low[i], input_close[i]);
+            match stream.update(candle).unwrap() {
+                Some(signal) => assert_eq!(signal, output_signals[i]),
+                None => assert!(i < stream.lookback()),
+            }
+        }
+        assert!(stream.is_ready());
     }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    Indicator,
     KandError,
     TAFloat,
     TAInt,
@@ -311,6 +312,108 @@ pub fn cdl_long_shadow_inc(
     Ok((signal, body_avg))
 }
 
+/// Streaming Long Shadow pattern detector.
+///
+/// Averages the bodies of the first `param_period` candles like [`cdl_long_shadow`] and classifies
+/// every candle from the last of them on with [`cdl_long_shadow_inc`].
+///
+/// The input tuple is `(open, high, low, close)` and the output is the pattern signal.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::cdl_long_shadow::CdlLongShadow};
+///
+/// let mut detector = CdlLongShadow::new(2, 75.0).unwrap();
+/// assert_eq!(detector.update((10.0, 11.0, 9.0, 10.5)).unwrap(), None);
+/// assert!(detector.update((10.5, 11.0, 10.0, 10.8)).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CdlLongShadow {
+    param_period:        usize,
+    param_shadow_factor: TAFloat,
+    lookback:            usize,
+    count:               usize,
+    body_sum:            TAFloat,
+    body_avg:            TAFloat,
+}
+
+impl CdlLongShadow {
+    /// Creates a new streaming Long Shadow detector.
+    ///
+    /// # Arguments
+    /// * `param_period` - Period for EMA calculation of body sizes (must be >= 2)
+    /// * `param_shadow_factor` - Minimum shadow length as a percentage of the candle range
+    ///   (typically 75.0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the period is less than 2 or the percentage is
+    ///   not positive
+    pub fn new(param_period: usize, param_shadow_factor: TAFloat) -> Result<Self, KandError> {
+        #[cfg(feature = "check")]
+        {
+            if param_shadow_factor <= 0.0 {
+                return Err(KandError::InvalidParameter);
+            }
+        }
+
+        Ok(Self {
+            param_period,
+            param_shadow_factor,
+            lookback: lookback(param_period)?,
+            count: 0,
+            body_sum: 0.0,
+            body_avg: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for CdlLongShadow {
+    type Input = (TAFloat, TAFloat, TAFloat, TAFloat);
+    type Output = TAInt;
+
+    fn update(
+        &mut self,
+        (input_open, input_high, input_low, input_close): Self::Input,
+    ) -> Result<Option<TAInt>, KandError> {
+        let body = real_body_length(input_open, input_close);
+        self.count += 1;
+        if self.count <= self.lookback {
+            self.body_sum += body;
+            return Ok(None);
+        }
+
+        if self.count == self.lookback + 1 {
+            self.body_avg = (self.body_sum + body) / self.param_period as TAFloat;
+        }
+
+        let signal;
+        (signal, self.body_avg) = cdl_long_shadow_inc(
+            input_open,
+            input_high,
+            input_low,
+            input_close,
+            self.body_avg,
+            self.param_period,
+            self.param_shadow_factor,
+        )?;
+        Ok(Some(signal))
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.body_sum = 0.0;
+        self.body_avg = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -390,5 +493,16 @@ fn test_cdl_long_shadow() {
             assert_relative_eq!(new_body_avg, output_body_avg[i], epsilon = 0.00001);
             prev_body_avg = new_body_avg;
         }
+
+        // Streaming detection matches the batch signals
+        let mut stream = CdlLongShadow::new(param_period, param_shadow_factor).unwrap();
+        for i in 0..input_open.len() {
+            let candle = (input_open[i], input_high[i], input_low[i], input_close[i]);
+            match stream.update(candle).unwrap() {
+                Some(signal) => assert_eq!(signal, output_signals[i]),
+                None => assert!(i < stream.lookback()),
+            }
+        }
+        assert!(stream.is_ready());
     }
 }
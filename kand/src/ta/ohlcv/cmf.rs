@@ -0,0 +1,467 @@
+use std::collections::VecDeque;
+
+use super::ad::money_flow_multiplier;
+use crate::{Indicator, KandError, TAFloat};
+
+/// Returns the lookback period required for Chaikin Money Flow calculation.
+///
+/// # Arguments
+/// * `param_period` - The summation period (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns `param_period - 1` on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cmf;
+/// assert_eq!(cmf::lookback(20).unwrap(), 19);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period - 1)
+}
+
+/// Calculates Chaikin Money Flow (CMF).
+///
+/// # Description
+/// CMF is the volume-weighted average of the Money Flow Multiplier over a rolling window.
+/// Positive readings show closes clustering near the highs on heavy volume (accumulation),
+/// negative readings closes near the lows (distribution).
+///
+/// # Mathematical Formula
+/// ```text
+/// MFM = ((Close - Low) - (High - Close)) / (High - Low)
+/// MFV = MFM * Volume
+/// CMF = Sum(MFV, period) / Sum(Volume, period)
+/// ```
+/// A window with no volume gives a CMF of 0.
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `input_volume` - Array of volume data
+/// * `param_period` - The summation period (must be >= 2)
+/// * `output_cmf` - Array storing the CMF values
+/// * `output_mfv_sum` - Array storing the rolling sum of money flow volume
+/// * `output_volume_sum` - Array storing the rolling sum of volume
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::InsufficientData` - If input length <= lookback period
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cmf;
+///
+/// let input_high = vec![12.0, 12.0, 12.0];
+/// let input_low = vec![8.0, 8.0, 8.0];
+/// let input_close = vec![11.0, 9.0, 12.0];
+/// let input_volume = vec![100.0, 100.0, 200.0];
+/// let mut output_cmf = vec![0.0; 3];
+/// let mut output_mfv_sum = vec![0.0; 3];
+/// let mut output_volume_sum = vec![0.0; 3];
+///
+/// cmf::cmf(
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &input_volume,
+///     2,
+///     &mut output_cmf,
+///     &mut output_mfv_sum,
+///     &mut output_volume_sum,
+/// )
+/// .unwrap();
+///
+/// assert!(output_cmf[0].is_nan());
+/// assert_eq!(output_cmf[1], 0.0);
+/// assert_eq!(output_cmf[2], 0.5);
+/// ```
+pub fn cmf(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    param_period: usize,
+    output_cmf: &mut [TAFloat],
+    output_mfv_sum: &mut [TAFloat],
+    output_volume_sum: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if input_low.len() != len
+            || input_close.len() != len
+            || input_volume.len() != len
+            || output_cmf.len() != len
+            || output_mfv_sum.len() != len
+            || output_volume_sum.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+                || input_volume[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mfv = |i: usize| {
+        money_flow_multiplier(input_high[i], input_low[i], input_close[i]) * input_volume[i]
+    };
+
+    let mut mfv_sum = 0.0;
+    let mut volume_sum = 0.0;
+    for (i, volume) in input_volume.iter().enumerate().take(lookback + 1) {
+        mfv_sum += mfv(i);
+        volume_sum += volume;
+    }
+    output_mfv_sum[lookback] = mfv_sum;
+    output_volume_sum[lookback] = volume_sum;
+    output_cmf[lookback] = cmf_from_sums(mfv_sum, volume_sum);
+
+    for i in (lookback + 1)..len {
+        let old = i - param_period;
+        mfv_sum += mfv(i) - mfv(old);
+        volume_sum += input_volume[i] - input_volume[old];
+        output_mfv_sum[i] = mfv_sum;
+        output_volume_sum[i] = volume_sum;
+        output_cmf[i] = cmf_from_sums(mfv_sum, volume_sum);
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_cmf[i] = TAFloat::NAN;
+        output_mfv_sum[i] = TAFloat::NAN;
+        output_volume_sum[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next Chaikin Money Flow value incrementally.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `input_volume` - Current volume
+/// * `input_old_high` - High of the bar leaving the window, `param_period` bars back
+/// * `input_old_low` - Low of the bar leaving the window
+/// * `input_old_close` - Close of the bar leaving the window
+/// * `input_old_volume` - Volume of the bar leaving the window
+/// * `prev_mfv_sum` - Previous rolling sum of money flow volume
+/// * `prev_volume_sum` - Previous rolling sum of volume
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (CMF, MFV sum, volume sum)
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cmf;
+///
+/// // Window [(12, 8, 9, 100), (12, 8, 12, 200)] after [(12, 8, 11, 100), (12, 8, 9, 100)]
+/// let (output_cmf, mfv_sum, volume_sum) =
+///     cmf::cmf_inc(12.0, 8.0, 12.0, 200.0, 12.0, 8.0, 11.0, 100.0, 0.0, 200.0).unwrap();
+/// assert_eq!((output_cmf, mfv_sum, volume_sum), (0.5, 150.0, 300.0));
+/// ```
+pub fn cmf_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    input_volume: TAFloat,
+    input_old_high: TAFloat,
+    input_old_low: TAFloat,
+    input_old_close: TAFloat,
+    input_old_volume: TAFloat,
+    prev_mfv_sum: TAFloat,
+    prev_volume_sum: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_high.is_nan()
+            || input_low.is_nan()
+            || input_close.is_nan()
+            || input_volume.is_nan()
+            || input_old_high.is_nan()
+            || input_old_low.is_nan()
+            || input_old_close.is_nan()
+            || input_old_volume.is_nan()
+            || prev_mfv_sum.is_nan()
+            || prev_volume_sum.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let mfv_sum = prev_mfv_sum
+        + money_flow_multiplier(input_high, input_low, input_close) * input_volume
+        - money_flow_multiplier(input_old_high, input_old_low, input_old_close) * input_old_volume;
+    let volume_sum = prev_volume_sum + input_volume - input_old_volume;
+
+    Ok((cmf_from_sums(mfv_sum, volume_sum), mfv_sum, volume_sum))
+}
+
+fn cmf_from_sums(mfv_sum: TAFloat, volume_sum: TAFloat) -> TAFloat {
+    if volume_sum == 0.0 {
+        0.0
+    } else {
+        mfv_sum / volume_sum
+    }
+}
+
+/// Streaming Chaikin Money Flow (CMF) calculator.
+///
+/// Consumes `(high, low, close, volume)` bars and keeps the money flow volume and volume of
+/// the last `param_period` bars alongside their running sums.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::cmf::Cmf};
+///
+/// let mut cmf = Cmf::new(2).unwrap();
+/// assert_eq!(cmf.update((12.0, 8.0, 11.0, 100.0)).unwrap(), None);
+/// assert_eq!(cmf.update((12.0, 8.0, 9.0, 100.0)).unwrap(), Some(0.0));
+/// assert_eq!(cmf.update((12.0, 8.0, 12.0, 200.0)).unwrap(), Some(0.5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cmf {
+    param_period: usize,
+    window:       VecDeque<(TAFloat, TAFloat)>,
+    mfv_sum:      TAFloat,
+    volume_sum:   TAFloat,
+}
+
+impl Cmf {
+    /// Creates a new streaming CMF.
+    ///
+    /// # Arguments
+    /// * `param_period` - The summation period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            window: VecDeque::with_capacity(param_period + 1),
+            mfv_sum: 0.0,
+            volume_sum: 0.0,
+        })
+    }
+}
+
+impl Indicator for Cmf {
+    type Input = (TAFloat, TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close, input_volume): (TAFloat, TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        #[cfg(feature = "deep-check")]
+        {
+            // NaN check
+            if input_high.is_nan()
+                || input_low.is_nan()
+                || input_close.is_nan()
+                || input_volume.is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let mfv = money_flow_multiplier(input_high, input_low, input_close) * input_volume;
+        self.window.push_back((mfv, input_volume));
+        self.mfv_sum += mfv;
+        self.volume_sum += input_volume;
+        if self.window.len() > self.param_period {
+            if let Some((old_mfv, old_volume)) = self.window.pop_front() {
+                self.mfv_sum -= old_mfv;
+                self.volume_sum -= old_volume;
+            }
+        }
+
+        if self.is_ready() {
+            Ok(Some(cmf_from_sums(self.mfv_sum, self.volume_sum)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.mfv_sum = 0.0;
+        self.volume_sum = 0.0;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.param_period
+    }
+
+    fn lookback(&self) -> usize {
+        self.param_period - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::ohlcv::ad;
+
+    #[test]
+    fn test_cmf_calculation() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let len = input_close.len();
+        let param_period = 20;
+        let mut output_cmf = vec![0.0; len];
+        let mut output_mfv_sum = vec![0.0; len];
+        let mut output_volume_sum = vec![0.0; len];
+
+        cmf(
+            &input_high,
+            &input_low,
+            &input_close,
+            &input_volume,
+            param_period,
+            &mut output_cmf,
+            &mut output_mfv_sum,
+            &mut output_volume_sum,
+        )
+        .unwrap();
+
+        // The MFV sum over a window is the change of the A/D line across it
+        let mut output_ad = vec![0.0; len];
+        ad::ad(
+            &input_high,
+            &input_low,
+            &input_close,
+            &input_volume,
+            &mut output_ad,
+        )
+        .unwrap();
+
+        assert!(
+            output_cmf[..param_period - 1]
+                .iter()
+                .all(|value| value.is_nan())
+        );
+        assert_relative_eq!(
+            output_cmf[19],
+            output_ad[19] / input_volume[..20].iter().sum::<TAFloat>(),
+            epsilon = 1e-12
+        );
+        for i in param_period..len {
+            let volume_sum: TAFloat = input_volume[i + 1 - param_period..=i].iter().sum();
+            assert_relative_eq!(
+                output_mfv_sum[i],
+                output_ad[i] - output_ad[i - param_period],
+                epsilon = 1e-6
+            );
+            assert_relative_eq!(output_volume_sum[i], volume_sum, epsilon = 1e-6);
+            assert_relative_eq!(
+                output_cmf[i],
+                (output_ad[i] - output_ad[i - param_period]) / volume_sum,
+                epsilon = 1e-9
+            );
+        }
+        assert_relative_eq!(output_cmf[24], -0.075_094_662_086_300_2, epsilon = 1e-9);
+
+        // Incremental calculation matches the batch output
+        for i in param_period..len {
+            let old = i - param_period;
+            let (output, mfv_sum, volume_sum) = cmf_inc(
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+                input_high[old],
+                input_low[old],
+                input_close[old],
+                input_volume[old],
+                output_mfv_sum[i - 1],
+                output_volume_sum[i - 1],
+            )
+            .unwrap();
+            assert_relative_eq!(output, output_cmf[i], epsilon = 1e-9);
+            assert_relative_eq!(mfv_sum, output_mfv_sum[i], epsilon = 1e-6);
+            assert_relative_eq!(volume_sum, output_volume_sum[i], epsilon = 1e-6);
+        }
+
+        // Streaming calculation matches the batch output bar for bar
+        let mut stream = Cmf::new(param_period).unwrap();
+        for i in 0..len {
+            let value = stream
+                .update((input_high[i], input_low[i], input_close[i], input_volume[i]))
+                .unwrap();
+            match value {
+                Some(value) => assert_relative_eq!(value, output_cmf[i], epsilon = 1e-9),
+                None => assert!(output_cmf[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
@@ -0,0 +1,508 @@
+use crate::{Indicator, KandError, TAFloat};
+
+/// Calculates the lookback period required for CMO (Chande Momentum Oscillator) calculation.
+///
+/// Like RSI, CMO needs `param_period` price changes, and therefore `param_period + 1` prices,
+/// to establish the initial average gain and loss values.
+///
+/// # Arguments
+/// * `param_period` - The number of periods to look back for CMO calculation (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The required lookback period on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cmo;
+///
+/// let param_period = 14;
+/// let lookback = cmo::lookback(param_period).unwrap();
+/// assert_eq!(lookback, 14);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period)
+}
+
+/// CMO from the smoothed gain and loss, 0 when the price did not move at all (as TA-Lib).
+fn cmo_value(avg_gain: TAFloat, avg_loss: TAFloat) -> TAFloat {
+    let total = avg_gain + avg_loss;
+    if total == 0.0 {
+        0.0
+    } else {
+        100.0 * (avg_gain - avg_loss) / total
+    }
+}
+
+/// Calculates the Chande Momentum Oscillator (CMO) for a price series.
+///
+/// CMO compares the magnitude of recent gains with the magnitude of recent losses and
+/// oscillates between -100 and +100. Values above +50 are generally read as overbought and
+/// values below -50 as oversold. Gains and losses are smoothed with Wilder's method as in
+/// TA-Lib, which makes CMO equal to `2 * RSI - 100`.
+///
+/// # Mathematical Formula
+/// ```text
+/// CMO = 100 * (Average Gain - Average Loss) / (Average Gain + Average Loss)
+///
+/// Initial Average Gain = Sum of Gains over past n periods / n
+/// Initial Average Loss = Sum of Losses over past n periods / n
+///
+/// Subsequent values:
+/// Average Gain = ((Previous Average Gain) × (n-1) + Current Gain) / n
+/// Average Loss = ((Previous Average Loss) × (n-1) + Current Loss) / n
+/// ```
+///
+/// # Calculation Principle
+/// 1. Calculate price changes between consecutive periods
+/// 2. Separate gains (positive changes) from losses (negative changes)
+/// 3. Calculate initial average gain and loss over first n periods
+/// 4. Apply Wilder's smoothing formula for subsequent periods
+/// 5. Calculate CMO from the averages, using 0 when both are 0
+///
+/// # Arguments
+/// * `input_prices` - Array of price values (typically closing prices)
+/// * `param_period` - The time period for CMO calculation (typically 14)
+/// * `output_cmo` - Array to store calculated CMO values
+/// * `output_avg_gain` - Array to store average gain values for each period
+/// * `output_avg_loss` - Array to store average loss values for each period
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on successful calculation
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If input and output arrays have different lengths
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::InsufficientData` - If input length is less than or equal to lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cmo;
+///
+/// let input_prices = vec![10.0, 11.0, 10.5, 12.0, 11.0];
+/// let param_period = 3;
+/// let mut output_cmo = vec![0.0; input_prices.len()];
+/// let mut output_avg_gain = vec![0.0; input_prices.len()];
+/// let mut output_avg_loss = vec![0.0; input_prices.len()];
+///
+/// cmo::cmo(
+///     &input_prices,
+///     param_period,
+///     &mut output_cmo,
+///     &mut output_avg_gain,
+///     &mut output_avg_loss,
+/// )
+/// .unwrap();
+/// // Gains of 1.0 + 1.5 against a loss of 0.5
+/// assert!((output_cmo[3] - 100.0 * 2.0 / 3.0).abs() < 1e-10);
+/// ```
+pub fn cmo(
+    input_prices: &[TAFloat],
+    param_period: usize,
+    output_cmo: &mut [TAFloat],
+    output_avg_gain: &mut [TAFloat],
+    output_avg_loss: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_cmo.len() != len || output_avg_gain.len() != len || output_avg_loss.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut gains = 0.0;
+    let mut losses = 0.0;
+
+    // Calculate initial gains/losses sum
+    for i in 1..=lookback {
+        let diff = input_prices[i] - input_prices[i - 1];
+        if diff > 0.0 {
+            gains += diff;
+        } else {
+            losses -= diff;
+        }
+    }
+
+    // Calculate first CMO value
+    let mut prev_avg_gain = gains / param_period as TAFloat;
+    let mut prev_avg_loss = losses / param_period as TAFloat;
+    output_avg_gain[lookback] = prev_avg_gain;
+    output_avg_loss[lookback] = prev_avg_loss;
+    output_cmo[lookback] = cmo_value(prev_avg_gain, prev_avg_loss);
+
+    // Calculate remaining CMO values using smoothed averages
+    for i in lookback + 1..len {
+        let (output, avg_gain, avg_loss) = cmo_inc(
+            input_prices[i],
+            input_prices[i - 1],
+            prev_avg_gain,
+            prev_avg_loss,
+            param_period,
+        )?;
+        output_cmo[i] = output;
+        output_avg_gain[i] = avg_gain;
+        output_avg_loss[i] = avg_loss;
+        prev_avg_gain = avg_gain;
+        prev_avg_loss = avg_loss;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_cmo[i] = TAFloat::NAN;
+        output_avg_gain[i] = TAFloat::NAN;
+        output_avg_loss[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest CMO value incrementally using previous average gain and loss values.
+///
+/// # Mathematical Formula
+/// ```text
+/// Average Gain = ((Previous Average Gain) × (n-1) + Current Gain) / n
+/// Average Loss = ((Previous Average Loss) × (n-1) + Current Loss) / n
+/// CMO = 100 * (Average Gain - Average Loss) / (Average Gain + Average Loss)
+/// ```
+///
+/// # Arguments
+/// * `input_curr_price` - Current period's price value
+/// * `prev_price` - Previous period's price value
+/// * `prev_avg_gain` - Previous period's average gain
+/// * `prev_avg_loss` - Previous period's average loss
+/// * `param_period` - The time period for CMO calculation
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (CMO value, new average
+///   gain, new average loss)
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::cmo;
+///
+/// let (cmo_value, avg_gain, avg_loss) = cmo::cmo_inc(
+///     11.0, // current price
+///     12.0, // previous price
+///     0.6,  // previous average gain
+///     0.2,  // previous average loss
+///     2,    // period
+/// )
+/// .unwrap();
+/// assert!((avg_gain - 0.3).abs() < 1e-10);
+/// assert!((avg_loss - 0.6).abs() < 1e-10);
+/// assert!((cmo_value + 100.0 / 3.0).abs() < 1e-10);
+/// ```
+pub fn cmo_inc(
+    input_curr_price: TAFloat,
+    prev_price: TAFloat,
+    prev_avg_gain: TAFloat,
+    prev_avg_loss: TAFloat,
+    param_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_curr_price.is_nan()
+            || prev_price.is_nan()
+            || prev_avg_gain.is_nan()
+            || prev_avg_loss.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let diff = input_curr_price - prev_price;
+    let (curr_gain, curr_loss) = if diff > 0.0 {
+        (diff, 0.0)
+    } else {
+        (0.0, -diff)
+    };
+
+    let smoothing = param_period as TAFloat;
+    let output_avg_gain = prev_avg_gain.mul_add(smoothing - 1.0, curr_gain) / smoothing;
+    let output_avg_loss = prev_avg_loss.mul_add(smoothing - 1.0, curr_loss) / smoothing;
+
+    Ok((
+        cmo_value(output_avg_gain, output_avg_loss),
+        output_avg_gain,
+        output_avg_loss,
+    ))
+}
+
+/// Streaming Chande Momentum Oscillator (CMO) calculator.
+///
+/// Carries the previous price and Wilder-smoothed average gain/loss between bars. The first
+/// value is seeded with [`cmo`] and every later value comes from [`cmo_inc`], so the stream
+/// matches the batch output.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::cmo::Cmo};
+///
+/// let mut cmo = Cmo::new(3).unwrap();
+/// for price in [10.0, 11.0, 10.5] {
+///     assert!(cmo.update(price).unwrap().is_none());
+/// }
+/// let value = cmo.update(12.0).unwrap().unwrap();
+/// assert!((-100.0..=100.0).contains(&value));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cmo {
+    param_period:  usize,
+    lookback:      usize,
+    warmup:        Vec<TAFloat>,
+    ready:         bool,
+    prev_price:    TAFloat,
+    prev_avg_gain: TAFloat,
+    prev_avg_loss: TAFloat,
+}
+
+impl Cmo {
+    /// Creates a new streaming CMO.
+    ///
+    /// # Arguments
+    /// * `param_period` - The time period for CMO calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            lookback,
+            warmup: Vec::with_capacity(lookback + 1),
+            ready: false,
+            prev_price: TAFloat::NAN,
+            prev_avg_gain: TAFloat::NAN,
+            prev_avg_loss: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Cmo {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if self.ready {
+            let (output_cmo, output_avg_gain, output_avg_loss) = cmo_inc(
+                input_price,
+                self.prev_price,
+                self.prev_avg_gain,
+                self.prev_avg_loss,
+                self.param_period,
+            )?;
+            self.prev_price = input_price;
+            self.prev_avg_gain = output_avg_gain;
+            self.prev_avg_loss = output_avg_loss;
+            return Ok(Some(output_cmo));
+        }
+
+        self.warmup.push(input_price);
+        if self.warmup.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let len = self.warmup.len();
+        let mut output_cmo = vec![0.0; len];
+        let mut output_avg_gain = vec![0.0; len];
+        let mut output_avg_loss = vec![0.0; len];
+        cmo(
+            &self.warmup,
+            self.param_period,
+            &mut output_cmo,
+            &mut output_avg_gain,
+            &mut output_avg_loss,
+        )?;
+        self.warmup.clear();
+        self.prev_price = input_price;
+        self.prev_avg_gain = output_avg_gain[self.lookback];
+        self.prev_avg_loss = output_avg_loss[self.lookback];
+        self.ready = true;
+        Ok(Some(output_cmo[self.lookback]))
+    }
+
+    fn reset(&mut self) {
+        self.warmup.clear();
+        self.ready = false;
+        self.prev_price = TAFloat::NAN;
+        self.prev_avg_gain = TAFloat::NAN;
+        self.prev_avg_loss = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::ohlcv::rsi;
+
+    const INPUT_PRICES: [TAFloat; 28] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2, 35092.0,
+    ];
+
+    #[test]
+    fn test_cmo_calculation() {
+        let len = INPUT_PRICES.len();
+        let param_period = 14;
+        let mut output_cmo = vec![0.0; len];
+        let mut output_avg_gain = vec![0.0; len];
+        let mut output_avg_loss = vec![0.0; len];
+        cmo(
+            &INPUT_PRICES,
+            param_period,
+            &mut output_cmo,
+            &mut output_avg_gain,
+            &mut output_avg_loss,
+        )
+        .unwrap();
+
+        for value in &output_cmo[..param_period] {
+            assert!(value.is_nan());
+        }
+
+        // With Wilder smoothing CMO is a rescaled RSI
+        let mut output_rsi = vec![0.0; len];
+        let mut output_rsi_gain = vec![0.0; len];
+        let mut output_rsi_loss = vec![0.0; len];
+        rsi::rsi(
+            &INPUT_PRICES,
+            param_period,
+            &mut output_rsi,
+            &mut output_rsi_gain,
+            &mut output_rsi_loss,
+        )
+        .unwrap();
+        for i in param_period..len {
+            assert_relative_eq!(output_cmo[i], 2.0 * output_rsi[i] - 100.0, epsilon = 1e-8);
+            assert_relative_eq!(output_avg_gain[i], output_rsi_gain[i], epsilon = 1e-8);
+            assert_relative_eq!(output_avg_loss[i], output_rsi_loss[i], epsilon = 1e-8);
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let mut stream = Cmo::new(param_period).unwrap();
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            let value = stream.update(price).unwrap();
+            if i < param_period {
+                assert!(value.is_none());
+                continue;
+            }
+            assert_relative_eq!(value.unwrap(), output_cmo[i], epsilon = 1e-8);
+            if i > param_period {
+                let (result, _, _) = cmo_inc(
+                    INPUT_PRICES[i],
+                    INPUT_PRICES[i - 1],
+                    output_avg_gain[i - 1],
+                    output_avg_loss[i - 1],
+                    param_period,
+                )
+                .unwrap();
+                assert_relative_eq!(result, output_cmo[i], epsilon = 1e-8);
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_cmo_flat_prices() {
+        let input_prices = [10.0; 5];
+        let mut output_cmo = vec![0.0; 5];
+        let mut output_avg_gain = vec![0.0; 5];
+        let mut output_avg_loss = vec![0.0; 5];
+        cmo(
+            &input_prices,
+            3,
+            &mut output_cmo,
+            &mut output_avg_gain,
+            &mut output_avg_loss,
+        )
+        .unwrap();
+        assert_eq!(output_cmo[3], 0.0);
+        assert_eq!(output_cmo[4], 0.0);
+    }
+
+    #[test]
+    fn test_cmo_invalid_params() {
+        let input_prices = [10.0; 5];
+        let mut output = vec![0.0; 5];
+        let mut output_avg_gain = vec![0.0; 5];
+        let mut output_avg_loss = vec![0.0; 5];
+        assert!(matches!(
+            cmo(
+                &input_prices,
+                1,
+                &mut output,
+                &mut output_avg_gain,
+                &mut output_avg_loss
+            ),
+            Err(KandError::InvalidParameter)
+        ));
+        assert!(matches!(
+            cmo(
+                &input_prices,
+                5,
+                &mut output,
+                &mut output_avg_gain,
+                &mut output_avg_loss
+            ),
+            Err(KandError::InsufficientData)
+        ));
+    }
+}
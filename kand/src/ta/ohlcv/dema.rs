@@ -1,4 +1,4 @@
-use crate::{TAFloat, error::KandError, helper::period_to_k};
+use crate::{Indicator, TAFloat, error::KandError, helper::period_to_k};
 
 /// Returns the lookback period required for DEMA calculation.
 ///
@@ -237,6 +237,110 @@ pub fn dema_inc(
     Ok((dema, new_ema1, new_ema2))
 }
 
+/// Streaming Double Exponential Moving Average (DEMA) calculator.
+///
+/// Both EMA states are seeded with [`dema`] over the warm-up window and then advanced with
+/// [`dema_inc`], so the stream matches the batch output.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::dema::Dema};
+///
+/// let mut dema = Dema::new(3).unwrap();
+/// let values: Vec<_> = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+///     .into_iter()
+///     .map(|price| dema.update(price).unwrap())
+///     .collect();
+/// assert!(values[..4].iter().all(Option::is_none));
+/// assert!(values[4].is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dema {
+    param_period: usize,
+    lookback:     usize,
+    warmup:       Vec<TAFloat>,
+    ready:        bool,
+    prev_ema1:    TAFloat,
+    prev_ema2:    TAFloat,
+}
+
+impl Dema {
+    /// Creates a new streaming DEMA.
+    ///
+    /// # Arguments
+    /// * `param_period` - The smoothing period for both EMAs (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            lookback,
+            warmup: Vec::with_capacity(lookback + 1),
+            ready: false,
+            prev_ema1: TAFloat::NAN,
+            prev_ema2: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Dema {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if self.ready {
+            let (output_dema, output_ema1, output_ema2) = dema_inc(
+                input_price,
+                self.prev_ema1,
+                self.prev_ema2,
+                self.param_period,
+            )?;
+            self.prev_ema1 = output_ema1;
+            self.prev_ema2 = output_ema2;
+            return Ok(Some(output_dema));
+        }
+
+        self.warmup.push(input_price);
+        if self.warmup.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let len = self.warmup.len();
+        let mut output_dema = vec![0.0; len];
+        let mut output_ema1 = vec![0.0; len];
+        let mut output_ema2 = vec![0.0; len];
+        dema(
+            &self.warmup,
+            self.param_period,
+            &mut output_dema,
+            &mut output_ema1,
+            &mut output_ema2,
+        )?;
+        self.warmup.clear();
+        self.prev_ema1 = output_ema1[self.lookback];
+        self.prev_ema2 = output_ema2[self.lookback];
+        self.ready = true;
+        Ok(Some(output_dema[self.lookback]))
+    }
+
+    fn reset(&mut self) {
+        self.warmup.clear();
+        self.ready = false;
+        self.prev_ema1 = TAFloat::NAN;
+        self.prev_ema2 = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -306,4 +410,35 @@ fn test_dema_calculation() {
             prev_ema2 = new_ema2;
         }
     }
+
+    #[test]
+    fn test_dema_stream() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 5;
+        let mut output_dema = vec![0.0; input.len()];
+        let mut output_ema1 = vec![0.0; input.len()];
+        let mut output_ema2 = vec![0.0; input.len()];
+
+        dema(
+            &input,
+            param_period,
+            &mut output_dema,
+            &mut output_ema1,
+            &mut output_ema2,
+        )
+        .unwrap();
+
+        let mut stream = Dema::new(param_period).unwrap();
+        for (i, price) in input.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_dema[i], epsilon = 0.00001),
+                None => assert!(output_dema[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
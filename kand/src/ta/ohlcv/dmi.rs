@@ -0,0 +1,487 @@
+use std::{cmp::Ordering, collections::VecDeque};
+
+use super::trange;
+use crate::{Indicator, KandError, TAFloat};
+
+/// Returns the lookback period of the Directional Movement System stream.
+///
+/// # Description
+/// This is the lookback of +DI, -DI and DX, the first values a [`Dmi`] emits. ADX follows
+/// after [`adx::lookback`](super::adx::lookback) bars and ADXR after
+/// [`adxr::lookback`](super::adxr::lookback) bars.
+///
+/// # Arguments
+/// * `param_period` - The smoothing period (typically 14)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::dmi;
+///
+/// assert_eq!(dmi::lookback(14).unwrap(), 14);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period)
+}
+
+/// Splits the move from the previous bar into +DM and -DM.
+fn directional_movement(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    prev_high: TAFloat,
+    prev_low: TAFloat,
+) -> (TAFloat, TAFloat) {
+    let high_diff = input_high - prev_high;
+    let low_diff = prev_low - input_low;
+    let plus_dm = if high_diff > low_diff && high_diff > 0.0 {
+        high_diff
+    } else {
+        0.0
+    };
+    let minus_dm = if low_diff > high_diff && low_diff > 0.0 {
+        low_diff
+    } else {
+        0.0
+    };
+    (plus_dm, minus_dm)
+}
+
+/// Advances the Wilder-smoothed directional movement state by one bar.
+///
+/// # Description
+/// This is the single smoothing step shared by [`Dmi`] and the free
+/// [`plus_di_inc`](super::plus_di::plus_di_inc), [`minus_di_inc`](super::minus_di::minus_di_inc),
+/// [`dx_inc`](super::dx::dx_inc) and [`adx_inc`](super::adx::adx_inc) functions.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `prev_high` - Previous high price
+/// * `prev_low` - Previous low price
+/// * `prev_close` - Previous close price
+/// * `prev_smoothed_plus_dm` - Previous smoothed +DM
+/// * `prev_smoothed_minus_dm` - Previous smoothed -DM
+/// * `prev_smoothed_tr` - Previous smoothed TR
+/// * `param_period` - The smoothing period (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple of
+///   (+DI, -DI, DX, smoothed +DM, smoothed -DM, smoothed TR)
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::dmi;
+///
+/// let (plus_di, minus_di, _, _, _, smoothed_tr) =
+///     dmi::dmi_inc(12.0, 10.0, 11.0, 9.0, 10.5, 2.0, 1.0, 4.0, 2).unwrap();
+/// assert_eq!(smoothed_tr, 4.0);
+/// assert_eq!((plus_di, minus_di), (50.0, 12.5));
+/// ```
+pub fn dmi_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    prev_high: TAFloat,
+    prev_low: TAFloat,
+    prev_close: TAFloat,
+    prev_smoothed_plus_dm: TAFloat,
+    prev_smoothed_minus_dm: TAFloat,
+    prev_smoothed_tr: TAFloat,
+    param_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_high.is_nan()
+            || input_low.is_nan()
+            || prev_high.is_nan()
+            || prev_low.is_nan()
+            || prev_close.is_nan()
+            || prev_smoothed_plus_dm.is_nan()
+            || prev_smoothed_minus_dm.is_nan()
+            || prev_smoothed_tr.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (plus_dm, minus_dm) = directional_movement(input_high, input_low, prev_high, prev_low);
+    let tr = trange::trange_inc(input_high, input_low, prev_close)?;
+
+    let period_t = param_period as TAFloat;
+    let smoothed_plus_dm = prev_smoothed_plus_dm - (prev_smoothed_plus_dm / period_t) + plus_dm;
+    let smoothed_minus_dm = prev_smoothed_minus_dm - (prev_smoothed_minus_dm / period_t) + minus_dm;
+    let smoothed_tr = prev_smoothed_tr - (prev_smoothed_tr / period_t) + tr;
+
+    let (plus_di, minus_di) = if smoothed_tr == 0.0 {
+        (0.0, 0.0)
+    } else {
+        (
+            100.0 * smoothed_plus_dm / smoothed_tr,
+            100.0 * smoothed_minus_dm / smoothed_tr,
+        )
+    };
+    let dx = 100.0 * (plus_di - minus_di).abs() / (plus_di + minus_di);
+
+    Ok((
+        plus_di,
+        minus_di,
+        dx,
+        smoothed_plus_dm,
+        smoothed_minus_dm,
+        smoothed_tr,
+    ))
+}
+
+/// Values of the Directional Movement System for one bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DmiOutput {
+    /// Plus Directional Indicator (+DI)
+    pub plus_di:  TAFloat,
+    /// Minus Directional Indicator (-DI)
+    pub minus_di: TAFloat,
+    /// Directional Movement Index (DX)
+    pub dx:       TAFloat,
+    /// Average Directional Index, `None` until `2 * period - 1` bars have passed
+    pub adx:      Option<TAFloat>,
+    /// Average Directional Index Rating, `None` until `3 * period - 2` bars have passed
+    pub adxr:     Option<TAFloat>,
+}
+
+/// Streaming Directional Movement System calculator.
+///
+/// Consumes `(high, low, close)` bars and keeps a single Wilder-smoothed +DM, -DM and TR state
+/// from which +DI, -DI, DX, ADX and ADXR are all derived. The warm-up sums and smoothing steps
+/// follow the batch functions, so every component reproduces [`plus_di`](super::plus_di::plus_di),
+/// [`minus_di`](super::minus_di::minus_di), [`dx`](super::dx::dx), [`adx`](super::adx::adx)
+/// and [`adxr`](super::adxr::adxr) exactly. The single-indicator streams
+/// ([`PlusDi`](super::plus_di::PlusDi), [`Adx`](super::adx::Adx), ...) are views over it.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::dmi::Dmi};
+///
+/// let mut dmi = Dmi::new(2).unwrap();
+/// let bars = [
+///     (10.0, 8.0, 9.0),
+///     (11.0, 9.0, 10.0),
+///     (12.0, 9.5, 11.0),
+///     (11.5, 9.0, 9.5),
+///     (12.5, 10.0, 12.0),
+/// ];
+/// let values: Vec<_> = bars.iter().map(|bar| dmi.update(*bar).unwrap()).collect();
+/// assert!(values[1].is_none());
+/// assert!(values[2].unwrap().adx.is_none());
+/// assert!(values[3].unwrap().adxr.is_none());
+/// assert!(values[4].unwrap().adxr.is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dmi {
+    param_period:      usize,
+    count:             usize,
+    prev_high:         TAFloat,
+    prev_low:          TAFloat,
+    prev_close:        TAFloat,
+    smoothed_plus_dm:  TAFloat,
+    smoothed_minus_dm: TAFloat,
+    smoothed_tr:       TAFloat,
+    dx_sum:            TAFloat,
+    prev_adx:          TAFloat,
+    adx_window:        VecDeque<TAFloat>,
+}
+
+impl Dmi {
+    /// Creates a new streaming Directional Movement System.
+    ///
+    /// # Arguments
+    /// * `param_period` - The smoothing period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            count: 0,
+            prev_high: TAFloat::NAN,
+            prev_low: TAFloat::NAN,
+            prev_close: TAFloat::NAN,
+            smoothed_plus_dm: 0.0,
+            smoothed_minus_dm: 0.0,
+            smoothed_tr: 0.0,
+            dx_sum: 0.0,
+            prev_adx: TAFloat::NAN,
+            adx_window: VecDeque::with_capacity(param_period),
+        })
+    }
+
+    /// Returns the number of bars consumed since creation or the last reset.
+    pub(crate) const fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl Indicator for Dmi {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = DmiOutput;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<DmiOutput>, KandError> {
+        #[cfg(feature = "deep-check")]
+        {
+            // NaN check
+            if input_high.is_nan() || input_low.is_nan() || input_close.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let index = self.count;
+        self.count += 1;
+        if index == 0 {
+            self.prev_high = input_high;
+            self.prev_low = input_low;
+            self.prev_close = input_close;
+            return Ok(None);
+        }
+
+        // Sum the first period - 1 movements, then apply Wilder's smoothing
+        let period = self.param_period;
+        if index < period {
+            let (plus_dm, minus_dm) =
+                directional_movement(input_high, input_low, self.prev_high, self.prev_low);
+            self.smoothed_plus_dm += plus_dm;
+            self.smoothed_minus_dm += minus_dm;
+            self.smoothed_tr += trange::trange_inc(input_high, input_low, self.prev_close)?;
+            self.prev_high = input_high;
+            self.prev_low = input_low;
+            self.prev_close = input_close;
+            return Ok(None);
+        }
+        let (plus_di, minus_di, dx, smoothed_plus_dm, smoothed_minus_dm, smoothed_tr) = dmi_inc(
+            input_high,
+            input_low,
+            self.prev_high,
+            self.prev_low,
+            self.prev_close,
+            self.smoothed_plus_dm,
+            self.smoothed_minus_dm,
+            self.smoothed_tr,
+            period,
+        )?;
+        self.prev_high = input_high;
+        self.prev_low = input_low;
+        self.prev_close = input_close;
+        self.smoothed_plus_dm = smoothed_plus_dm;
+        self.smoothed_minus_dm = smoothed_minus_dm;
+        self.smoothed_tr = smoothed_tr;
+
+        let period_t = period as TAFloat;
+        // ADX is seeded with the mean of the first period DX values
+        let adx_lookback = period * 2 - 1;
+        let adx = match index.cmp(&adx_lookback) {
+            Ordering::Less => {
+                self.dx_sum += dx;
+                None
+            }
+            Ordering::Equal => {
+                self.dx_sum += dx;
+                Some(self.dx_sum / period_t)
+            }
+            Ordering::Greater => Some(self.prev_adx.mul_add(period_t - 1.0, dx) / period_t),
+        };
+
+        // ADXR averages the current ADX with the one from period - 1 bars ago
+        let adxr = adx.and_then(|value| {
+            self.prev_adx = value;
+            if self.adx_window.len() == period {
+                self.adx_window.pop_front();
+            }
+            self.adx_window.push_back(value);
+            (self.adx_window.len() == period).then(|| (value + self.adx_window[0]) / 2.0)
+        });
+
+        Ok(Some(DmiOutput {
+            plus_di,
+            minus_di,
+            dx,
+            adx,
+            adxr,
+        }))
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.prev_high = TAFloat::NAN;
+        self.prev_low = TAFloat::NAN;
+        self.prev_close = TAFloat::NAN;
+        self.smoothed_plus_dm = 0.0;
+        self.smoothed_minus_dm = 0.0;
+        self.smoothed_tr = 0.0;
+        self.dx_sum = 0.0;
+        self.prev_adx = TAFloat::NAN;
+        self.adx_window.clear();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.param_period
+    }
+
+    fn lookback(&self) -> usize {
+        self.param_period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ohlcv::{adx, adxr, dx, minus_di, plus_di};
+
+    #[test]
+    fn test_dmi_stream_matches_batch() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4, 35150.4, 35123.9,
+            35110.0, 35092.1, 35179.2, 35244.9, 35150.2, 35136.0, 35133.6, 35188.0, 35215.3,
+            35221.9, 35219.2, 35234.0, 35216.7, 35197.9, 35178.4, 35183.4, 35129.7, 35149.1,
+            35129.3, 35125.5, 35114.5, 35120.1, 35129.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0, 35073.0, 35055.0,
+            35084.0, 35060.0, 35073.1, 35090.0, 35072.0, 35078.0, 35088.0, 35124.8, 35169.4,
+            35138.0, 35141.0, 35182.0, 35151.1, 35158.4, 35140.0, 35087.0, 35085.8, 35114.7,
+            35086.0, 35090.6, 35074.1, 35078.4, 35100.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+            35154.0, 35216.3, 35211.8, 35158.4, 35172.0, 35176.7, 35113.3, 35114.7, 35129.3,
+            35094.6, 35114.4, 35094.5, 35116.0, 35105.4,
+        ];
+        let param_period = 14;
+        let len = input_high.len();
+
+        let mut output_plus_di = vec![0.0; len];
+        let mut output_minus_di = vec![0.0; len];
+        let mut output_dx = vec![0.0; len];
+        let mut output_adx = vec![0.0; len];
+        let mut output_adxr = vec![0.0; len];
+        let mut smoothed_plus_dm = vec![0.0; len];
+        let mut smoothed_minus_dm = vec![0.0; len];
+        let mut smoothed_tr = vec![0.0; len];
+
+        plus_di::plus_di(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_plus_di,
+            &mut smoothed_plus_dm,
+            &mut smoothed_tr,
+        )
+        .unwrap();
+        minus_di::minus_di(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_minus_di,
+            &mut smoothed_minus_dm,
+            &mut smoothed_tr,
+        )
+        .unwrap();
+        dx::dx(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_dx,
+            &mut smoothed_plus_dm,
+            &mut smoothed_minus_dm,
+            &mut smoothed_tr,
+        )
+        .unwrap();
+        adx::adx(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_adx,
+            &mut smoothed_plus_dm,
+            &mut smoothed_minus_dm,
+            &mut smoothed_tr,
+        )
+        .unwrap();
+        adxr::adxr(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_adxr,
+            &mut vec![0.0; len],
+            &mut smoothed_plus_dm,
+            &mut smoothed_minus_dm,
+            &mut smoothed_tr,
+        )
+        .unwrap();
+
+        let adx_lookback = adx::lookback(param_period).unwrap();
+        let adxr_lookback = adxr::lookback(param_period).unwrap();
+        let mut stream = Dmi::new(param_period).unwrap();
+        for i in 0..len {
+            let value = stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap();
+            if i < param_period {
+                assert_eq!(value, None);
+                continue;
+            }
+
+            let output = value.unwrap();
+            assert_eq!(output.plus_di, output_plus_di[i]);
+            assert_eq!(output.minus_di, output_minus_di[i]);
+            assert_eq!(output.dx, output_dx[i]);
+            assert_eq!(output.adx, (i >= adx_lookback).then_some(output_adx[i]));
+            assert_eq!(output.adxr, (i >= adxr_lookback).then_some(output_adxr[i]));
+        }
+        assert!(stream.is_ready());
+
+        stream.reset();
+        assert!(!stream.is_ready());
+        assert_eq!(
+            stream
+                .update((input_high[0], input_low[0], input_close[0]))
+                .unwrap(),
+            None
+        );
+    }
+}
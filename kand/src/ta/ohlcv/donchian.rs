@@ -0,0 +1,406 @@
+use super::bandwidth::{bandwidth_value, percent_b_value};
+use crate::{
+    Indicator,
+    KandError,
+    TAFloat,
+    ta::stats::{
+        max::{self, Max},
+        min::{self, Min},
+    },
+};
+
+/// Returns the lookback period required for Donchian Channel calculation.
+///
+/// # Arguments
+/// * `param_period` - The channel period (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns `param_period - 1` on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::donchian;
+/// assert_eq!(donchian::lookback(20).unwrap(), 19);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period - 1)
+}
+
+/// Calculates Donchian Channels.
+///
+/// # Description
+/// The upper and lower channel lines are the highest high and lowest low of the last
+/// `param_period` bars, including the current one, and the middle line is their average.
+/// Because the current bar is part of the window, the close never leaves the channel; for
+/// breakout signals compare the close with the previous bar's channel.
+///
+/// # Mathematical Formula
+/// ```text
+/// Upper     = Highest High[i-n+1..=i]
+/// Lower     = Lowest Low[i-n+1..=i]
+/// Middle    = (Upper + Lower) / 2
+/// %B        = (Close - Lower) / (Upper - Lower)
+/// Bandwidth = (Upper - Lower) / Middle
+/// ```
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `param_period` - The channel period (must be >= 2)
+/// * `output_upper` - Array storing the upper channel line
+/// * `output_middle` - Array storing the middle channel line
+/// * `output_lower` - Array storing the lower channel line
+/// * `output_percent_b` - Array storing the %B values, see [`bandwidth`](super::bandwidth)
+/// * `output_bandwidth` - Array storing the bandwidth values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::InsufficientData` - If input length <= lookback period
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::donchian;
+///
+/// let input_high = vec![10.0, 12.0, 11.0, 13.0];
+/// let input_low = vec![8.0, 9.0, 9.5, 10.0];
+/// let input_close = vec![9.0, 11.0, 10.0, 12.0];
+/// let mut output_upper = vec![0.0; 4];
+/// let mut output_middle = vec![0.0; 4];
+/// let mut output_lower = vec![0.0; 4];
+/// let mut output_percent_b = vec![0.0; 4];
+/// let mut output_bandwidth = vec![0.0; 4];
+///
+/// donchian::donchian(
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     3,
+///     &mut output_upper,
+///     &mut output_middle,
+///     &mut output_lower,
+///     &mut output_percent_b,
+///     &mut output_bandwidth,
+/// )
+/// .unwrap();
+///
+/// assert!(output_upper[1].is_nan());
+/// assert_eq!(output_upper[2..], [12.0, 13.0]);
+/// assert_eq!(output_lower[2..], [8.0, 9.0]);
+/// assert_eq!(output_percent_b[3], 0.75);
+/// ```
+pub fn donchian(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_period: usize,
+    output_upper: &mut [TAFloat],
+    output_middle: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+    output_percent_b: &mut [TAFloat],
+    output_bandwidth: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if input_low.len() != len
+            || input_close.len() != len
+            || output_upper.len() != len
+            || output_middle.len() != len
+            || output_lower.len() != len
+            || output_percent_b.len() != len
+            || output_bandwidth.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut rolling_high = Max::new(param_period)?;
+    let mut rolling_low = Min::new(param_period)?;
+    for i in 0..len {
+        let (upper, middle, lower, percent_b, bandwidth) = donchian_inc(
+            input_high[i],
+            input_low[i],
+            input_close[i],
+            &mut rolling_high,
+            &mut rolling_low,
+        )?;
+        output_upper[i] = upper;
+        output_middle[i] = middle;
+        output_lower[i] = lower;
+        output_percent_b[i] = percent_b;
+        output_bandwidth[i] = bandwidth;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_upper[i] = TAFloat::NAN;
+        output_middle[i] = TAFloat::NAN;
+        output_lower[i] = TAFloat::NAN;
+        output_percent_b[i] = TAFloat::NAN;
+        output_bandwidth[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next Donchian Channel values incrementally.
+///
+/// # Description
+/// The highest high and lowest low are tracked by rolling monotonic deques updated in place,
+/// so they stay exact when the previous extreme leaves the window. While the windows are
+/// still filling, the values cover the bars pushed so far.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `rolling_high` - Rolling window of the last `param_period` highs, updated in place
+/// * `rolling_low` - Rolling window of the last `param_period` lows, updated in place
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple of
+///   (upper, middle, lower, %B, bandwidth)
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{
+///     ohlcv::donchian,
+///     stats::{max::Max, min::Min},
+/// };
+///
+/// let mut rolling_high = Max::new(2).unwrap();
+/// let mut rolling_low = Min::new(2).unwrap();
+/// donchian::donchian_inc(10.0, 8.0, 9.0, &mut rolling_high, &mut rolling_low).unwrap();
+///
+/// let (upper, middle, lower, percent_b, bandwidth) =
+///     donchian::donchian_inc(12.0, 9.0, 11.0, &mut rolling_high, &mut rolling_low).unwrap();
+/// assert_eq!((upper, middle, lower), (12.0, 10.0, 8.0));
+/// assert_eq!((percent_b, bandwidth), (0.75, 0.4));
+/// ```
+pub fn donchian_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    rolling_high: &mut Max,
+    rolling_low: &mut Min,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_close.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let upper = max::max_inc(input_high, rolling_high)?;
+    let lower = min::min_inc(input_low, rolling_low)?;
+    let middle = (upper + lower) / 2.0;
+
+    Ok((
+        upper,
+        middle,
+        lower,
+        percent_b_value(input_close, upper, lower),
+        bandwidth_value(upper, middle, lower),
+    ))
+}
+
+/// Streaming Donchian Channel calculator.
+///
+/// Consumes `(high, low, close)` bars and emits `(upper, middle, lower, %B, bandwidth)` once
+/// the window is full.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::donchian::Donchian};
+///
+/// let mut donchian = Donchian::new(2).unwrap();
+/// assert_eq!(donchian.update((10.0, 8.0, 9.0)).unwrap(), None);
+/// assert_eq!(
+///     donchian.update((12.0, 9.0, 11.0)).unwrap(),
+///     Some((12.0, 10.0, 8.0, 0.75, 0.4))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Donchian {
+    rolling_high: Max,
+    rolling_low:  Min,
+}
+
+impl Donchian {
+    /// Creates a new streaming Donchian Channel.
+    ///
+    /// # Arguments
+    /// * `param_period` - The channel period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        lookback(param_period)?;
+        Ok(Self {
+            rolling_high: Max::new(param_period)?,
+            rolling_low:  Min::new(param_period)?,
+        })
+    }
+}
+
+impl Indicator for Donchian {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = (TAFloat, TAFloat, TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<Self::Output>, KandError> {
+        let output = donchian_inc(
+            input_high,
+            input_low,
+            input_close,
+            &mut self.rolling_high,
+            &mut self.rolling_low,
+        )?;
+        Ok(self.is_ready().then_some(output))
+    }
+
+    fn reset(&mut self) {
+        self.rolling_high.reset();
+        self.rolling_low.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.rolling_high.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.rolling_high.lookback()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_donchian_calculation() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let len = input_high.len();
+        let param_period = 14;
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+        let mut output_percent_b = vec![0.0; len];
+        let mut output_bandwidth = vec![0.0; len];
+
+        donchian(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_upper,
+            &mut output_middle,
+            &mut output_lower,
+            &mut output_percent_b,
+            &mut output_bandwidth,
+        )
+        .unwrap();
+
+        for i in 0..13 {
+            assert!(output_upper[i].is_nan());
+            assert!(output_percent_b[i].is_nan());
+        }
+
+        // The middle line is the 14 bar midprice
+        let expected_middle = [
+            35206.1, 35180.8, 35151.3, 35115.8, 35115.8, 35115.8, 35115.8, 35106.55, 35083.5,
+            35076.0, 35076.0, 35076.0,
+        ];
+        for (i, expected) in expected_middle.iter().enumerate() {
+            assert_relative_eq!(output_middle[i + 13], *expected, epsilon = 0.0001);
+        }
+        assert_relative_eq!(output_upper[24], 35241.0, epsilon = 0.0001);
+        assert_relative_eq!(output_lower[24], 34911.0, epsilon = 0.0001);
+        assert_relative_eq!(
+            output_percent_b[24],
+            (35080.0 - 34911.0) / 330.0,
+            epsilon = 1e-9
+        );
+        assert_relative_eq!(output_bandwidth[24], 330.0 / 35076.0, epsilon = 1e-9);
+
+        // Streaming calculation matches the batch output bar for bar
+        let mut stream = Donchian::new(param_period).unwrap();
+        for i in 0..len {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some((upper, middle, lower, percent_b, bandwidth)) => {
+                    assert_relative_eq!(upper, output_upper[i], epsilon = 1e-9);
+                    assert_relative_eq!(middle, output_middle[i], epsilon = 1e-9);
+                    assert_relative_eq!(lower, output_lower[i], epsilon = 1e-9);
+                    assert_relative_eq!(percent_b, output_percent_b[i], epsilon = 1e-9);
+                    assert_relative_eq!(bandwidth, output_bandwidth[i], epsilon = 1e-9);
+                }
+                None => assert!(output_upper[i].is_nan()),
+            }
+        }
+    }
+}
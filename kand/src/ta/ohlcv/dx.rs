@@ -1,5 +1,9 @@
-use super::{minus_di, plus_di};
-use crate::{KandError, TAFloat};
+use super::{
+    dmi::{self, Dmi},
+    minus_di,
+    plus_di,
+};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Calculate the lookback period required for DX calculation
 ///
@@ -250,56 +254,19 @@ pub fn dx_inc(
     prev_smoothed_tr: TAFloat,
     param_period: usize,
 ) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
-    #[cfg(feature = "check")]
-    {
-        // Parameter range check
-        // DX requires at least 2 periods:
-        // - One for initial DM and TR calculations (needs previous prices)
-        // - One for the current period
-        if param_period < 2 {
-            return Err(KandError::InvalidParameter);
-        }
-    }
-
-    #[cfg(feature = "deep-check")]
-    {
-        // NaN check
-        if input_high.is_nan()
-            || input_low.is_nan()
-            || prev_high.is_nan()
-            || prev_low.is_nan()
-            || prev_close.is_nan()
-            || prev_smoothed_plus_dm.is_nan()
-            || prev_smoothed_minus_dm.is_nan()
-            || prev_smoothed_tr.is_nan()
-        {
-            return Err(KandError::NaNDetected);
-        }
-    }
-
-    let (plus_di, output_smoothed_plus_dm, output_smoothed_tr) = plus_di::plus_di_inc(
-        input_high,
-        input_low,
-        prev_high,
-        prev_low,
-        prev_close,
-        prev_smoothed_plus_dm,
-        prev_smoothed_tr,
-        param_period,
-    )?;
-
-    let (minus_di, output_smoothed_minus_dm, _) = minus_di::minus_di_inc(
-        input_high,
-        input_low,
-        prev_high,
-        prev_low,
-        prev_close,
-        prev_smoothed_minus_dm,
-        prev_smoothed_tr,
-        param_period,
-    )?;
+    let (_, _, output_dx, output_smoothed_plus_dm, output_smoothed_minus_dm, output_smoothed_tr) =
+        dmi::dmi_inc(
+            input_high,
+            input_low,
+            prev_high,
+            prev_low,
+            prev_close,
+            prev_smoothed_plus_dm,
+            prev_smoothed_minus_dm,
+            prev_smoothed_tr,
+            param_period,
+        )?;
 
-    let output_dx = 100.0 * (plus_di - minus_di).abs() / (plus_di + minus_di);
     Ok((
         output_dx,
         output_smoothed_plus_dm,
@@ -308,6 +275,67 @@ pub fn dx_inc(
     ))
 }
 
+/// Streaming Directional Movement Index (DX) calculator.
+///
+/// Consumes `(high, low, close)` bars through a shared [`Dmi`] state and emits the DX component.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::dx::Dx};
+///
+/// let mut dx = Dx::new(2).unwrap();
+/// let bars = [
+///     (10.0, 8.0, 9.0),
+///     (11.0, 9.0, 10.0),
+///     (12.0, 9.5, 11.0),
+///     (11.5, 9.0, 9.5),
+///     (12.5, 10.0, 12.0),
+/// ];
+/// let values: Vec<_> = bars.iter().map(|bar| dx.update(*bar).unwrap()).collect();
+/// assert!(values[dx.lookback() - 1].is_none());
+/// assert!(values[dx.lookback()].is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dx {
+    dmi: Dmi,
+}
+
+impl Dx {
+    /// Creates a new streaming DX.
+    ///
+    /// # Arguments
+    /// * `param_period` - The smoothing period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            dmi: Dmi::new(param_period)?,
+        })
+    }
+}
+
+impl Indicator for Dx {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(&mut self, input: (TAFloat, TAFloat, TAFloat)) -> Result<Option<TAFloat>, KandError> {
+        Ok(self.dmi.update(input)?.map(|output| output.dx))
+    }
+
+    fn reset(&mut self) {
+        self.dmi.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.dmi.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.dmi.lookback()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -397,4 +425,52 @@ fn test_dx_calculation() {
             assert_relative_eq!(new_smoothed_tr, output_smoothed_tr[i], epsilon = 0.00001);
         }
     }
+
+    #[test]
+    fn test_dx_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 5;
+        let mut output_dx = vec![0.0; input_close.len()];
+        let mut output_smoothed_plus_dm = vec![0.0; input_close.len()];
+        let mut output_smoothed_minus_dm = vec![0.0; input_close.len()];
+        let mut output_smoothed_tr = vec![0.0; input_close.len()];
+
+        dx(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_dx,
+            &mut output_smoothed_plus_dm,
+            &mut output_smoothed_minus_dm,
+            &mut output_smoothed_tr,
+        )
+        .unwrap();
+
+        let mut stream = Dx::new(param_period).unwrap();
+        for i in 0..input_close.len() {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some(value) => assert_relative_eq!(value, output_dx[i], epsilon = 0.00001),
+                None => assert!(output_dx[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period required for Expanded Camarilla Levels (ECL) calculation.
 ///
@@ -275,6 +275,79 @@ pub fn ecl_inc(
     Ok((h5_val, h4, h3, h2, h1, l1, l2, l3, l4, l5))
 }
 
+/// Streaming Expanded Camarilla Levels (ECL) calculator.
+///
+/// Remembers the previous bar and projects the levels for the current one with [`ecl_inc`], so
+/// the first bar only fills that slot, as in [`ecl`].
+///
+/// The input tuple is `(high, low, close)` and the output is
+/// `(h5, h4, h3, h2, h1, l1, l2, l3, l4, l5)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ecl::Ecl};
+///
+/// let mut ecl = Ecl::new();
+/// assert!(ecl.update((24.20, 23.85, 23.89)).unwrap().is_none());
+/// let (_, h4, ..) = ecl.update((24.07, 23.72, 23.95)).unwrap().unwrap();
+/// // 23.89 + 0.35 * 1.1 / 2
+/// assert!((h4 - 24.0825).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Ecl {
+    prev: Option<(TAFloat, TAFloat, TAFloat)>,
+}
+
+impl Ecl {
+    /// Creates a new streaming ECL.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { prev: None }
+    }
+}
+
+impl Indicator for Ecl {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = (
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+    );
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<Self::Output>, KandError> {
+        let output = match self.prev {
+            Some((prev_high, prev_low, prev_close)) => {
+                Some(ecl_inc(prev_high, prev_low, prev_close)?)
+            }
+            None => None,
+        };
+        self.prev = Some((input_high, input_low, input_close));
+        Ok(output)
+    }
+
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -350,5 +423,23 @@ fn test_ecl_calculation() {
         assert_relative_eq!(l3_inc, output_l3[i], epsilon = TAFloat::EPSILON);
         assert_relative_eq!(l4_inc, output_l4[i], epsilon = TAFloat::EPSILON);
         assert_relative_eq!(l5_inc, output_l5[i], epsilon = TAFloat::EPSILON);
+
+        // Streaming calculation matches the batch output
+        let mut stream = Ecl::new();
+        for i in 0..input_high.len() {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some((h5, h4, h3, h2, h1, l1, l2, l3, l4, l5)) => {
+                    let levels = [h5, h4, h3, h2, h1, l1, l2, l3, l4, l5];
+                    for (level, output) in levels.iter().zip(&outputs) {
+                        assert_relative_eq!(*level, output[i], epsilon = TAFloat::EPSILON);
+                    }
+                }
+                None => assert!(output_h5[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
     }
 }
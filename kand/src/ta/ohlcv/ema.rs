@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat, helper::period_to_k};
+use crate::{Indicator, KandError, TAFloat, helper::period_to_k};
 
 /// Returns the lookback period required for EMA calculation.
 ///
@@ -208,6 +208,92 @@ pub fn ema_inc(
     Ok((input_price - prev_ema).mul_add(multiplier, prev_ema))
 }
 
+/// Streaming Exponential Moving Average (EMA) calculator.
+///
+/// The first value is seeded with [`ema`] (an SMA of the first `param_period` prices) and every
+/// later value comes from [`ema_inc`], so the stream matches the batch output.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ema::Ema};
+///
+/// let mut ema = Ema::new(3, None).unwrap();
+/// assert_eq!(ema.update(2.0).unwrap(), None);
+/// assert_eq!(ema.update(4.0).unwrap(), None);
+/// assert_eq!(ema.update(6.0).unwrap(), Some(4.0));
+/// assert_eq!(ema.update(8.0).unwrap(), Some(6.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ema {
+    param_period: usize,
+    param_k:      Option<TAFloat>,
+    lookback:     usize,
+    warmup:       Vec<TAFloat>,
+    prev_ema:     Option<TAFloat>,
+}
+
+impl Ema {
+    /// Creates a new streaming EMA.
+    ///
+    /// # Arguments
+    /// * `param_period` - The time period for EMA calculation (must be >= 2)
+    /// * `param_k` - Optional custom smoothing factor. If None, uses 2/(period+1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize, param_k: Option<TAFloat>) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            param_k,
+            lookback: lookback(param_period)?,
+            warmup: Vec::with_capacity(param_period),
+            prev_ema: None,
+        })
+    }
+}
+
+impl Indicator for Ema {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if let Some(prev_ema) = self.prev_ema {
+            let output_ema = ema_inc(input_price, prev_ema, self.param_period, self.param_k)?;
+            self.prev_ema = Some(output_ema);
+            return Ok(self.prev_ema);
+        }
+
+        self.warmup.push(input_price);
+        if self.warmup.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let mut output_ema = vec![0.0; self.warmup.len()];
+        ema(
+            &self.warmup,
+            self.param_period,
+            self.param_k,
+            &mut output_ema,
+        )?;
+        self.warmup.clear();
+        self.prev_ema = Some(output_ema[self.lookback]);
+        Ok(self.prev_ema)
+    }
+
+    fn reset(&mut self) {
+        self.warmup.clear();
+        self.prev_ema = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_ema.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -269,4 +355,26 @@ fn test_ema_calculation() {
             prev_ema = result;
         }
     }
+
+    #[test]
+    fn test_ema_stream() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 10;
+        let mut output_ema = vec![0.0; input_prices.len()];
+
+        ema(&input_prices, param_period, None, &mut output_ema).unwrap();
+
+        let mut stream = Ema::new(param_period, None).unwrap();
+        for (i, price) in input_prices.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_ema[i], epsilon = 0.00001),
+                None => assert!(output_ema[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
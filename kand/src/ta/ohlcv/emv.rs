@@ -0,0 +1,438 @@
+use super::sma::{self, Sma};
+use crate::{Indicator, KandError, TAFloat};
+
+/// Returns the lookback period required for Ease of Movement calculation.
+///
+/// # Arguments
+/// * `param_period` - The SMA smoothing period (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns `param_period` on success: one bar for the first
+///   midpoint move plus the SMA warm-up
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::emv;
+/// assert_eq!(emv::lookback(14).unwrap(), 14);
+/// ```
+pub fn lookback(param_period: usize) -> Result<usize, KandError> {
+    Ok(sma::lookback(param_period)? + 1)
+}
+
+/// Calculates Arms' Ease of Movement (EMV).
+///
+/// # Description
+/// Ease of Movement relates the move of the bar midpoint to the volume needed to make it.
+/// Large positive values mean price rose easily on light volume, values near zero mean heavy
+/// volume was needed to move price at all.
+///
+/// # Mathematical Formula
+/// ```text
+/// Distance  = (High + Low) / 2 - (Previous High + Previous Low) / 2
+/// Box Ratio = (Volume / Divisor) / (High - Low)
+/// Raw EMV   = Distance / Box Ratio
+/// EMV       = SMA(Raw EMV, period)
+/// ```
+/// A bar with no volume or no range has a raw EMV of 0. The divisor only rescales the output
+/// into a readable range; 100,000,000 is customary for share volume.
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_volume` - Array of volume data
+/// * `param_period` - The SMA smoothing period (must be >= 2)
+/// * `param_divisor` - The volume scale divisor (must be positive)
+/// * `output_emv` - Array storing the smoothed EMV values
+/// * `output_emv_raw` - Array storing the single-bar EMV values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2 or `param_divisor` is
+///   not positive
+/// * `KandError::InsufficientData` - If input length <= lookback period
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::emv;
+///
+/// let input_high = vec![10.0, 12.0, 13.0];
+/// let input_low = vec![8.0, 10.0, 9.0];
+/// let input_volume = vec![100.0, 200.0, 400.0];
+/// let mut output_emv = vec![0.0; 3];
+/// let mut output_emv_raw = vec![0.0; 3];
+///
+/// emv::emv(
+///     &input_high,
+///     &input_low,
+///     &input_volume,
+///     2,
+///     100.0,
+///     &mut output_emv,
+///     &mut output_emv_raw,
+/// )
+/// .unwrap();
+///
+/// // Raw EMV: 2 * 2 * 100 / 200 = 2, then 0 * 4 * 100 / 400 = 0
+/// assert_eq!(output_emv_raw[1..], [2.0, 0.0]);
+/// assert_eq!(output_emv[2], 1.0);
+/// ```
+pub fn emv(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_volume: &[TAFloat],
+    param_period: usize,
+    param_divisor: TAFloat,
+    output_emv: &mut [TAFloat],
+    output_emv_raw: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if input_low.len() != len
+            || input_volume.len() != len
+            || output_emv.len() != len
+            || output_emv_raw.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Parameter range check
+        if param_divisor.is_nan() || param_divisor <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_volume[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    output_emv_raw[0] = TAFloat::NAN;
+    for i in 1..len {
+        output_emv_raw[i] = emv_raw(
+            input_high[i],
+            input_low[i],
+            input_volume[i],
+            input_high[i - 1],
+            input_low[i - 1],
+            param_divisor,
+        );
+    }
+
+    let mut sum = 0.0;
+    for value in &output_emv_raw[1..=lookback] {
+        sum += value;
+    }
+    output_emv[lookback] = sum / param_period as TAFloat;
+    for i in (lookback + 1)..len {
+        sum += output_emv_raw[i] - output_emv_raw[i - param_period];
+        output_emv[i] = sum / param_period as TAFloat;
+    }
+
+    // Fill initial values with NAN
+    for value in output_emv.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next Ease of Movement value incrementally.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_volume` - Current volume
+/// * `prev_high` - Previous high price
+/// * `prev_low` - Previous low price
+/// * `input_old_emv_raw` - Raw EMV leaving the SMA window, `param_period` bars back
+/// * `prev_emv` - Previous smoothed EMV value
+/// * `param_period` - The SMA smoothing period (must be >= 2)
+/// * `param_divisor` - The volume scale divisor (must be positive)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple of (EMV, raw EMV)
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2 or `param_divisor` is
+///   not positive
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::emv;
+///
+/// let (output_emv, output_emv_raw) =
+///     emv::emv_inc(13.0, 9.0, 400.0, 12.0, 10.0, 2.0, 2.0, 2, 100.0).unwrap();
+/// assert_eq!((output_emv, output_emv_raw), (1.0, 0.0));
+/// ```
+pub fn emv_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_volume: TAFloat,
+    prev_high: TAFloat,
+    prev_low: TAFloat,
+    input_old_emv_raw: TAFloat,
+    prev_emv: TAFloat,
+    param_period: usize,
+    param_divisor: TAFloat,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if param_divisor.is_nan() || param_divisor <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_high.is_nan()
+            || input_low.is_nan()
+            || input_volume.is_nan()
+            || prev_high.is_nan()
+            || prev_low.is_nan()
+            || input_old_emv_raw.is_nan()
+            || prev_emv.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let output_emv_raw = emv_raw(
+        input_high,
+        input_low,
+        input_volume,
+        prev_high,
+        prev_low,
+        param_divisor,
+    );
+    let output_emv = sma::sma_inc(prev_emv, output_emv_raw, input_old_emv_raw, param_period)?;
+    Ok((output_emv, output_emv_raw))
+}
+
+fn emv_raw(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_volume: TAFloat,
+    prev_high: TAFloat,
+    prev_low: TAFloat,
+    param_divisor: TAFloat,
+) -> TAFloat {
+    if input_volume == 0.0 {
+        return 0.0;
+    }
+    let distance = (input_high + input_low - prev_high - prev_low) / 2.0;
+    distance * (input_high - input_low) * param_divisor / input_volume
+}
+
+/// Streaming Ease of Movement (EMV) calculator.
+///
+/// Consumes `(high, low, volume)` bars, turns each into a raw EMV value against the previous
+/// bar and smooths it with a streaming [`Sma`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::emv::Emv};
+///
+/// let mut emv = Emv::new(2, 100.0).unwrap();
+/// assert_eq!(emv.update((10.0, 8.0, 100.0)).unwrap(), None);
+/// assert_eq!(emv.update((12.0, 10.0, 200.0)).unwrap(), None);
+/// assert_eq!(emv.update((13.0, 9.0, 400.0)).unwrap(), Some(1.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Emv {
+    param_divisor: TAFloat,
+    lookback:      usize,
+    sma:           Sma,
+    prev_bar:      Option<(TAFloat, TAFloat)>,
+}
+
+impl Emv {
+    /// Creates a new streaming EMV.
+    ///
+    /// # Arguments
+    /// * `param_period` - The SMA smoothing period (must be >= 2)
+    /// * `param_divisor` - The volume scale divisor (must be positive)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2 or `param_divisor` is
+    ///   not positive
+    pub fn new(param_period: usize, param_divisor: TAFloat) -> Result<Self, KandError> {
+        #[cfg(feature = "check")]
+        {
+            // Parameter range check
+            if param_divisor.is_nan() || param_divisor <= 0.0 {
+                return Err(KandError::InvalidParameter);
+            }
+        }
+
+        Ok(Self {
+            param_divisor,
+            lookback: lookback(param_period)?,
+            sma: Sma::new(param_period)?,
+            prev_bar: None,
+        })
+    }
+}
+
+impl Indicator for Emv {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_volume): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        #[cfg(feature = "deep-check")]
+        {
+            // NaN check
+            if input_high.is_nan() || input_low.is_nan() || input_volume.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let Some((prev_high, prev_low)) = self.prev_bar.replace((input_high, input_low)) else {
+            return Ok(None);
+        };
+        self.sma.update(emv_raw(
+            input_high,
+            input_low,
+            input_volume,
+            prev_high,
+            prev_low,
+            self.param_divisor,
+        ))
+    }
+
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.prev_bar = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.sma.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_emv_calculation() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let len = input_high.len();
+        let (param_period, param_divisor) = (14, 10_000.0);
+        let mut output_emv = vec![0.0; len];
+        let mut output_emv_raw = vec![0.0; len];
+
+        emv(
+            &input_high,
+            &input_low,
+            &input_volume,
+            param_period,
+            param_divisor,
+            &mut output_emv,
+            &mut output_emv_raw,
+        )
+        .unwrap();
+
+        for i in 1..len {
+            let distance =
+                (input_high[i] + input_low[i]) / 2.0 - (input_high[i - 1] + input_low[i - 1]) / 2.0;
+            let box_ratio = (input_volume[i] / param_divisor) / (input_high[i] - input_low[i]);
+            assert_relative_eq!(output_emv_raw[i], distance / box_ratio, epsilon = 1e-6);
+        }
+        assert!(
+            output_emv[..param_period]
+                .iter()
+                .all(|value| value.is_nan())
+        );
+        for i in param_period..len {
+            let expected = output_emv_raw[i + 1 - param_period..=i]
+                .iter()
+                .sum::<TAFloat>()
+                / param_period as TAFloat;
+            assert_relative_eq!(output_emv[i], expected, epsilon = 1e-6);
+        }
+
+        // Incremental calculation matches the batch output
+        for i in param_period + 1..len {
+            let (output, output_raw) = emv_inc(
+                input_high[i],
+                input_low[i],
+                input_volume[i],
+                input_high[i - 1],
+                input_low[i - 1],
+                output_emv_raw[i - param_period],
+                output_emv[i - 1],
+                param_period,
+                param_divisor,
+            )
+            .unwrap();
+            assert_relative_eq!(output, output_emv[i], epsilon = 1e-6);
+            assert_relative_eq!(output_raw, output_emv_raw[i], epsilon = 1e-9);
+        }
+
+        // Streaming calculation matches the batch output bar for bar
+        let mut stream = Emv::new(param_period, param_divisor).unwrap();
+        for i in 0..len {
+            match stream
+                .update((input_high[i], input_low[i], input_volume[i]))
+                .unwrap()
+            {
+                Some(value) => assert_relative_eq!(value, output_emv[i], epsilon = 1e-6),
+                None => assert!(output_emv[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
@@ -0,0 +1,316 @@
+use super::ema::{self, Ema};
+use crate::{Indicator, KandError, TAFloat};
+
+/// Returns the lookback period required for Force Index calculation.
+///
+/// # Arguments
+/// * `param_period` - The EMA smoothing period (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns `param_period` on success: one bar for the first
+///   close-to-close change plus the EMA warm-up
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::force_index;
+/// assert_eq!(force_index::lookback(13).unwrap(), 13);
+/// ```
+pub fn lookback(param_period: usize) -> Result<usize, KandError> {
+    Ok(ema::lookback(param_period)? + 1)
+}
+
+/// Calculates Elder's Force Index.
+///
+/// # Description
+/// The Force Index weighs each close-to-close move by the volume behind it and smooths the
+/// result with an EMA. Its sign shows which side controls the market and its magnitude how
+/// much conviction backs the move. The customary period is 13 (2 for short-term entries).
+///
+/// # Mathematical Formula
+/// ```text
+/// Raw Force   = (Close - Previous Close) * Volume
+/// Force Index = EMA(Raw Force, period)
+/// ```
+/// The EMA is seeded with the SMA of the first `period` raw values, as in [`ema`](super::ema).
+///
+/// # Arguments
+/// * `input_close` - Array of close prices
+/// * `input_volume` - Array of volume data
+/// * `param_period` - The EMA smoothing period (must be >= 2)
+/// * `output_force_index` - Array storing the Force Index values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::InsufficientData` - If input length <= lookback period
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::force_index;
+///
+/// let input_close = vec![10.0, 11.0, 10.5, 12.0];
+/// let input_volume = vec![100.0, 200.0, 100.0, 150.0];
+/// let mut output_force_index = vec![0.0; 4];
+///
+/// force_index::force_index(&input_close, &input_volume, 2, &mut output_force_index).unwrap();
+///
+/// // Raw force: 200, -50, 225
+/// assert!(output_force_index[1].is_nan());
+/// assert_eq!(output_force_index[2], 75.0);
+/// assert_eq!(output_force_index[3], 175.0);
+/// ```
+pub fn force_index(
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    param_period: usize,
+    output_force_index: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        let len = input_close.len();
+
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if input_volume.len() != len || output_force_index.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..input_close.len() {
+            // NaN check
+            if input_close[i].is_nan() || input_volume[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let raw_force = |i: usize| (input_close[i] - input_close[i - 1]) * input_volume[i];
+
+    let mut sum = 0.0;
+    for i in 1..=lookback {
+        sum += raw_force(i);
+    }
+    let mut prev = sum / param_period as TAFloat;
+    output_force_index[lookback] = prev;
+
+    for (i, value) in output_force_index.iter_mut().enumerate().skip(lookback + 1) {
+        prev = ema::ema_inc(raw_force(i), prev, param_period, None)?;
+        *value = prev;
+    }
+
+    // Fill initial values with NAN
+    for value in output_force_index.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next Force Index value incrementally.
+///
+/// # Arguments
+/// * `input_close` - Current close price
+/// * `prev_close` - Previous close price
+/// * `input_volume` - Current volume
+/// * `prev_force_index` - Previous Force Index value
+/// * `param_period` - The EMA smoothing period (must be >= 2)
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The new Force Index value
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::force_index;
+///
+/// let output = force_index::force_index_inc(12.0, 10.5, 150.0, 75.0, 2).unwrap();
+/// assert_eq!(output, 175.0);
+/// ```
+pub fn force_index_inc(
+    input_close: TAFloat,
+    prev_close: TAFloat,
+    input_volume: TAFloat,
+    prev_force_index: TAFloat,
+    param_period: usize,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_close.is_nan()
+            || prev_close.is_nan()
+            || input_volume.is_nan()
+            || prev_force_index.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    ema::ema_inc(
+        (input_close - prev_close) * input_volume,
+        prev_force_index,
+        param_period,
+        None,
+    )
+}
+
+/// Streaming Force Index calculator.
+///
+/// Consumes `(close, volume)` bars, turns each into a raw force value and smooths it with a
+/// streaming [`Ema`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::force_index::ForceIndex};
+///
+/// let mut force_index = ForceIndex::new(2).unwrap();
+/// assert_eq!(force_index.update((10.0, 100.0)).unwrap(), None);
+/// assert_eq!(force_index.update((11.0, 200.0)).unwrap(), None);
+/// assert_eq!(force_index.update((10.5, 100.0)).unwrap(), Some(75.0));
+/// assert_eq!(force_index.update((12.0, 150.0)).unwrap(), Some(175.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ForceIndex {
+    lookback:   usize,
+    ema:        Ema,
+    prev_close: Option<TAFloat>,
+}
+
+impl ForceIndex {
+    /// Creates a new streaming Force Index.
+    ///
+    /// # Arguments
+    /// * `param_period` - The EMA smoothing period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            lookback:   lookback(param_period)?,
+            ema:        Ema::new(param_period, None)?,
+            prev_close: None,
+        })
+    }
+}
+
+impl Indicator for ForceIndex {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_close, input_volume): (TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        let Some(prev_close) = self.prev_close.replace(input_close) else {
+            return Ok(None);
+        };
+        self.ema.update((input_close - prev_close) * input_volume)
+    }
+
+    fn reset(&mut self) {
+        self.ema.reset();
+        self.prev_close = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ema.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_force_index_calculation() {
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let len = input_close.len();
+        let param_period = 13;
+        let mut output_force_index = vec![0.0; len];
+
+        force_index(
+            &input_close,
+            &input_volume,
+            param_period,
+            &mut output_force_index,
+        )
+        .unwrap();
+
+        // Same result as an EMA over the raw force series
+        let raw_force: Vec<TAFloat> = (1..len)
+            .map(|i| (input_close[i] - input_close[i - 1]) * input_volume[i])
+            .collect();
+        let mut expected = vec![0.0; len - 1];
+        ema::ema(&raw_force, param_period, None, &mut expected).unwrap();
+
+        assert!(
+            output_force_index[..param_period]
+                .iter()
+                .all(|value| value.is_nan())
+        );
+        for i in param_period..len {
+            assert_relative_eq!(output_force_index[i], expected[i - 1], epsilon = 1e-6);
+        }
+
+        // Incremental calculation matches the batch output
+        for i in param_period + 1..len {
+            let output = force_index_inc(
+                input_close[i],
+                input_close[i - 1],
+                input_volume[i],
+                output_force_index[i - 1],
+                param_period,
+            )
+            .unwrap();
+            assert_relative_eq!(output, output_force_index[i], epsilon = 1e-6);
+        }
+
+        // Streaming calculation matches the batch output bar for bar
+        let mut stream = ForceIndex::new(param_period).unwrap();
+        for i in 0..len {
+            match stream.update((input_close[i], input_volume[i])).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_force_index[i], epsilon = 1e-6),
+                None => assert!(output_force_index[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period required for Heikin-Ashi calculation
 ///
@@ -219,6 +219,80 @@ pub fn ha_inc(
     Ok((ha_open, ha_high, ha_low, ha_close))
 }
 
+/// Streaming Heikin-Ashi calculator.
+///
+/// The first candle is built from its own open and close like in [`ha`], so a value is emitted
+/// for every bar; later candles go through [`ha_inc`].
+///
+/// The input tuple is `(open, high, low, close)` and the output is the Heikin-Ashi
+/// `(open, high, low, close)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ha::Ha};
+///
+/// let mut ha = Ha::new();
+/// assert_eq!(
+///     ha.update((10.0, 11.0, 9.5, 10.8)).unwrap(),
+///     Some((10.4, 11.0, 9.5, 10.325))
+/// );
+/// let (open, ..) = ha.update((10.5, 11.5, 10.2, 11.3)).unwrap().unwrap();
+/// assert!((open - 10.3625).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Ha {
+    prev: Option<(TAFloat, TAFloat)>,
+}
+
+impl Ha {
+    /// Creates a new streaming Heikin-Ashi calculator.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { prev: None }
+    }
+}
+
+impl Indicator for Ha {
+    type Input = (TAFloat, TAFloat, TAFloat, TAFloat);
+    type Output = (TAFloat, TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        (input_open, input_high, input_low, input_close): Self::Input,
+    ) -> Result<Option<Self::Output>, KandError> {
+        let output = match self.prev {
+            Some((prev_ha_open, prev_ha_close)) => ha_inc(
+                input_open,
+                input_high,
+                input_low,
+                input_close,
+                prev_ha_open,
+                prev_ha_close,
+            )?,
+            None => (
+                (input_open + input_close) / 2.0,
+                input_high,
+                input_low,
+                (input_open + input_high + input_low + input_close) / 4.0,
+            ),
+        };
+        self.prev = Some((output.0, output.3));
+        Ok(Some(output))
+    }
+
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -278,5 +352,17 @@ fn test_ha_calculation() {
             assert_relative_eq!(ha_low, output_low[i], epsilon = 0.0001);
             assert_relative_eq!(ha_close, output_close[i], epsilon = 0.0001);
         }
+
+        // Streaming calculation matches the batch output from the first candle
+        let mut stream = Ha::new();
+        for i in 0..5 {
+            let candle = (input_open[i], input_high[i], input_low[i], input_close[i]);
+            let (ha_open, ha_high, ha_low, ha_close) = stream.update(candle).unwrap().unwrap();
+            assert_relative_eq!(ha_open, output_open[i], epsilon = 0.0001);
+            assert_relative_eq!(ha_high, output_high[i], epsilon = 0.0001);
+            assert_relative_eq!(ha_low, output_low[i], epsilon = 0.0001);
+            assert_relative_eq!(ha_close, output_close[i], epsilon = 0.0001);
+        }
+        assert!(stream.is_ready());
     }
 }
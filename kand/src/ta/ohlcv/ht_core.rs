@@ -0,0 +1,386 @@
+use crate::TAFloat;
+
+/// Size of the circular buffers holding raw and smoothed prices.
+///
+/// The dominant cycle period is clamped to 50 bars, so neither the phase nor the trendline
+/// calculation ever looks further back than this.
+const BUFFER_SIZE: usize = 50;
+
+/// Number of bars used to prime the price smoother, which is a 4-bar WMA.
+const WMA_INIT_BARS: usize = 3;
+
+/// Smoother bars before the Hilbert stages start for `HT_DCPERIOD` and `HT_PHASOR`.
+pub const SHORT_WARMUP: usize = 9;
+
+/// Smoother bars before the Hilbert stages start for `HT_DCPHASE`, `HT_SINE`, `HT_TRENDLINE`
+/// and `HT_TRENDMODE`.
+pub const LONG_WARMUP: usize = 34;
+
+/// A single Hilbert Transform FIR stage.
+///
+/// Odd and even bars are processed by two independent filters, mirroring the TA-Lib
+/// implementation which keeps three taps per parity.
+#[derive(Debug, Clone, Copy)]
+struct HilbertStage {
+    odd:             [TAFloat; 3],
+    even:            [TAFloat; 3],
+    prev_odd:        TAFloat,
+    prev_even:       TAFloat,
+    prev_input_odd:  TAFloat,
+    prev_input_even: TAFloat,
+}
+
+impl HilbertStage {
+    const A: TAFloat = 0.0962;
+    const B: TAFloat = 0.5769;
+
+    const fn new() -> Self {
+        Self {
+            odd:             [0.0; 3],
+            even:            [0.0; 3],
+            prev_odd:        0.0,
+            prev_even:       0.0,
+            prev_input_odd:  0.0,
+            prev_input_even: 0.0,
+        }
+    }
+
+    fn transform(
+        &mut self,
+        input: TAFloat,
+        is_even: bool,
+        hilbert_idx: usize,
+        adjusted_prev_period: TAFloat,
+    ) -> TAFloat {
+        let (taps, prev, prev_input) = if is_even {
+            (
+                &mut self.even,
+                &mut self.prev_even,
+                &mut self.prev_input_even,
+            )
+        } else {
+            (&mut self.odd, &mut self.prev_odd, &mut self.prev_input_odd)
+        };
+
+        let weighted = Self::A * input;
+        let mut value = -taps[hilbert_idx];
+        taps[hilbert_idx] = weighted;
+        value += weighted;
+        value -= *prev;
+        *prev = Self::B * *prev_input;
+        value += *prev;
+        *prev_input = input;
+        value * adjusted_prev_period
+    }
+}
+
+/// Shared state of the Hilbert Transform (HT) cycle indicator family.
+///
+/// Every HT indicator runs the same pipeline on each bar:
+/// 1. Smooth the price with a 4-bar WMA
+/// 2. Detrend the smoothed price and derive the in-phase (I1) and quadrature (Q1) components
+/// 3. Advance the phase of I1 and Q1 by 90 degrees (jI, jQ) and build the phasor (I2, Q2)
+/// 4. Estimate the dominant cycle period with the homodyne discriminator
+///
+/// `HtState` carries everything this pipeline needs between bars, plus the dominant cycle
+/// phase, sine wave and instantaneous trendline values that the individual indicators layer
+/// on top. It is passed by mutable reference to every `ht_*_inc` function, which makes the
+/// batch functions a plain loop over their incremental counterparts.
+///
+/// The state must be fed from the first bar of the series. `param_warmup` is the number of
+/// smoother-only bars before the Hilbert stages start, which is [`SHORT_WARMUP`] for
+/// `HT_DCPERIOD`/`HT_PHASOR` and [`LONG_WARMUP`] for the rest of the family.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::{ht_core::HtState, ht_dcperiod};
+///
+/// let mut state = HtState::new(ht_dcperiod::WARMUP);
+/// for i in 0..40 {
+///     let price = 100.0 + (i as f64 * 0.4).sin();
+///     let _dcperiod = ht_dcperiod::ht_dcperiod_inc(price, &mut state).unwrap();
+/// }
+/// assert_eq!(state.bars(), 40);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtState {
+    param_warmup: usize,
+    today: usize,
+    prices: [TAFloat; BUFFER_SIZE],
+    wma_sub: TAFloat,
+    wma_sum: TAFloat,
+    hilbert_idx: usize,
+    detrender: HilbertStage,
+    q1: HilbertStage,
+    ji: HilbertStage,
+    jq: HilbertStage,
+    i1_odd_prev2: TAFloat,
+    i1_odd_prev3: TAFloat,
+    i1_even_prev2: TAFloat,
+    i1_even_prev3: TAFloat,
+    prev_i2: TAFloat,
+    prev_q2: TAFloat,
+    re: TAFloat,
+    im: TAFloat,
+    period: TAFloat,
+    smooth_prices: [TAFloat; BUFFER_SIZE],
+    smooth_price_idx: usize,
+    i_trend1: TAFloat,
+    i_trend2: TAFloat,
+    i_trend3: TAFloat,
+    pub(crate) smooth_period: TAFloat,
+    pub(crate) in_phase: TAFloat,
+    pub(crate) quadrature: TAFloat,
+    pub(crate) dc_phase: TAFloat,
+    pub(crate) prev_dc_phase: TAFloat,
+    pub(crate) sine: TAFloat,
+    pub(crate) lead_sine: TAFloat,
+    pub(crate) prev_sine: TAFloat,
+    pub(crate) prev_lead_sine: TAFloat,
+    pub(crate) days_in_trend: usize,
+}
+
+impl HtState {
+    /// Creates an empty Hilbert Transform state.
+    ///
+    /// # Arguments
+    /// * `param_warmup` - Number of smoother-only bars before the Hilbert stages start
+    ///   ([`SHORT_WARMUP`] or [`LONG_WARMUP`] for TA-Lib compatible results)
+    #[must_use]
+    pub const fn new(param_warmup: usize) -> Self {
+        Self {
+            param_warmup,
+            today: 0,
+            prices: [0.0; BUFFER_SIZE],
+            wma_sub: 0.0,
+            wma_sum: 0.0,
+            hilbert_idx: 0,
+            detrender: HilbertStage::new(),
+            q1: HilbertStage::new(),
+            ji: HilbertStage::new(),
+            jq: HilbertStage::new(),
+            i1_odd_prev2: 0.0,
+            i1_odd_prev3: 0.0,
+            i1_even_prev2: 0.0,
+            i1_even_prev3: 0.0,
+            prev_i2: 0.0,
+            prev_q2: 0.0,
+            re: 0.0,
+            im: 0.0,
+            period: 0.0,
+            smooth_prices: [0.0; BUFFER_SIZE],
+            smooth_price_idx: BUFFER_SIZE - 1,
+            i_trend1: 0.0,
+            i_trend2: 0.0,
+            i_trend3: 0.0,
+            smooth_period: 0.0,
+            in_phase: 0.0,
+            quadrature: 0.0,
+            dc_phase: 0.0,
+            prev_dc_phase: 0.0,
+            sine: 0.0,
+            lead_sine: 0.0,
+            prev_sine: 0.0,
+            prev_lead_sine: 0.0,
+            days_in_trend: 0,
+        }
+    }
+
+    /// Returns the warm-up length the state was created with.
+    #[must_use]
+    pub const fn warmup(&self) -> usize {
+        self.param_warmup
+    }
+
+    /// Returns the number of bars fed into the state so far.
+    #[must_use]
+    pub const fn bars(&self) -> usize {
+        self.today
+    }
+
+    /// Clears all accumulated state, keeping the configured warm-up length.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.param_warmup);
+    }
+
+    /// Runs one bar through the price smoother and, once warmed up, the Hilbert stages and
+    /// the dominant cycle period estimator.
+    ///
+    /// Returns `true` if the Hilbert stages were updated for this bar.
+    pub(crate) fn next(&mut self, input_price: TAFloat) -> bool {
+        let today = self.today;
+        self.prices[today % BUFFER_SIZE] = input_price;
+        self.today += 1;
+
+        // Prime the 4-bar WMA with weights 1, 2, 3
+        if today < WMA_INIT_BARS {
+            self.wma_sub += input_price;
+            self.wma_sum += input_price * (today + 1) as TAFloat;
+            return false;
+        }
+
+        let trailing_price = if today == WMA_INIT_BARS {
+            0.0
+        } else {
+            self.prices[(today - WMA_INIT_BARS - 1) % BUFFER_SIZE]
+        };
+        self.wma_sub += input_price;
+        self.wma_sub -= trailing_price;
+        self.wma_sum += input_price * 4.0;
+        let smoothed_price = self.wma_sum * 0.1;
+        self.wma_sum -= self.wma_sub;
+
+        if today < WMA_INIT_BARS + self.param_warmup {
+            return false;
+        }
+
+        let adjusted_prev_period = 0.075 * self.period + 0.54;
+        self.smooth_price_idx = (self.smooth_price_idx + 1) % BUFFER_SIZE;
+        self.smooth_prices[self.smooth_price_idx] = smoothed_price;
+
+        let hilbert_idx = self.hilbert_idx;
+        let (q2, i2) = if today % 2 == 0 {
+            let detrender =
+                self.detrender
+                    .transform(smoothed_price, true, hilbert_idx, adjusted_prev_period);
+            let q1 = self
+                .q1
+                .transform(detrender, true, hilbert_idx, adjusted_prev_period);
+            self.in_phase = self.i1_even_prev3;
+            self.quadrature = q1;
+            let ji = self
+                .ji
+                .transform(self.i1_even_prev3, true, hilbert_idx, adjusted_prev_period);
+            let jq = self
+                .jq
+                .transform(q1, true, hilbert_idx, adjusted_prev_period);
+            self.hilbert_idx = (hilbert_idx + 1) % 3;
+
+            let q2 = 0.2 * (q1 + ji) + 0.8 * self.prev_q2;
+            let i2 = 0.2 * (self.i1_even_prev3 - jq) + 0.8 * self.prev_i2;
+            self.i1_odd_prev3 = self.i1_odd_prev2;
+            self.i1_odd_prev2 = detrender;
+            (q2, i2)
+        } else {
+            let detrender =
+                self.detrender
+                    .transform(smoothed_price, false, hilbert_idx, adjusted_prev_period);
+            let q1 = self
+                .q1
+                .transform(detrender, false, hilbert_idx, adjusted_prev_period);
+            self.in_phase = self.i1_odd_prev3;
+            self.quadrature = q1;
+            let ji = self
+                .ji
+                .transform(self.i1_odd_prev3, false, hilbert_idx, adjusted_prev_period);
+            let jq = self
+                .jq
+                .transform(q1, false, hilbert_idx, adjusted_prev_period);
+
+            let q2 = 0.2 * (q1 + ji) + 0.8 * self.prev_q2;
+            let i2 = 0.2 * (self.i1_odd_prev3 - jq) + 0.8 * self.prev_i2;
+            self.i1_even_prev3 = self.i1_even_prev2;
+            self.i1_even_prev2 = detrender;
+            (q2, i2)
+        };
+
+        // Homodyne discriminator
+        self.re = 0.2 * (i2 * self.prev_i2 + q2 * self.prev_q2) + 0.8 * self.re;
+        self.im = 0.2 * (i2 * self.prev_q2 - q2 * self.prev_i2) + 0.8 * self.im;
+        self.prev_q2 = q2;
+        self.prev_i2 = i2;
+
+        let prev_period = self.period;
+        if self.im != 0.0 && self.re != 0.0 {
+            self.period = 360.0 / (self.im / self.re).atan().to_degrees();
+        }
+        self.period = self
+            .period
+            .min(1.5 * prev_period)
+            .max(0.67 * prev_period)
+            .clamp(6.0, 50.0);
+        self.period = 0.2 * self.period + 0.8 * prev_period;
+        self.smooth_period = 0.33 * self.period + 0.67 * self.smooth_period;
+
+        true
+    }
+
+    /// Returns the smoothed price of the latest bar.
+    pub(crate) const fn smoothed_price(&self) -> TAFloat {
+        self.smooth_prices[self.smooth_price_idx]
+    }
+
+    /// Returns the phase of the latest (I1, Q1) phasor in degrees, or 0 while I1 is zero.
+    pub(crate) fn phasor_phase(&self) -> TAFloat {
+        if self.in_phase == 0.0 {
+            0.0
+        } else {
+            (self.quadrature / self.in_phase).atan().to_degrees()
+        }
+    }
+
+    /// Updates the dominant cycle phase and the sine wave values for the latest bar.
+    ///
+    /// Must only be called after [`HtState::next`] returned `true`.
+    pub(crate) fn update_phase(&mut self) {
+        let dc_period_int = (self.smooth_period + 0.5) as usize;
+        let mut real_part = 0.0;
+        let mut imag_part = 0.0;
+        let mut idx = self.smooth_price_idx;
+        for i in 0..dc_period_int {
+            let angle = (i as TAFloat * 360.0).to_radians() / dc_period_int as TAFloat;
+            let smoothed_price = self.smooth_prices[idx];
+            real_part += angle.sin() * smoothed_price;
+            imag_part += angle.cos() * smoothed_price;
+            idx = if idx == 0 { BUFFER_SIZE - 1 } else { idx - 1 };
+        }
+
+        self.prev_dc_phase = self.dc_phase;
+        if imag_part.abs() > 0.0 {
+            self.dc_phase = (real_part / imag_part).atan().to_degrees();
+        } else if real_part < 0.0 {
+            self.dc_phase -= 90.0;
+        } else if real_part > 0.0 {
+            self.dc_phase += 90.0;
+        }
+        self.dc_phase += 90.0;
+        // Compensate for the one bar lag of the WMA smoother
+        self.dc_phase += 360.0 / self.smooth_period;
+        if imag_part < 0.0 {
+            self.dc_phase += 180.0;
+        }
+        if self.dc_phase > 315.0 {
+            self.dc_phase -= 360.0;
+        }
+
+        self.prev_sine = self.sine;
+        self.prev_lead_sine = self.lead_sine;
+        self.sine = self.dc_phase.to_radians().sin();
+        self.lead_sine = (self.dc_phase + 45.0).to_radians().sin();
+    }
+
+    /// Updates the instantaneous trendline for the latest bar and returns its value.
+    ///
+    /// The trendline is a 4-3-2-1 weighted average of the raw price averaged over the
+    /// current dominant cycle period.
+    ///
+    /// Must only be called after [`HtState::next`] returned `true`.
+    pub(crate) fn update_trendline(&mut self) -> TAFloat {
+        let dc_period_int = (self.smooth_period + 0.5) as usize;
+        let today = self.today - 1;
+        let mut average = 0.0;
+        for i in 0..dc_period_int {
+            average += self.prices[(today + BUFFER_SIZE - i) % BUFFER_SIZE];
+        }
+        if dc_period_int > 0 {
+            average /= dc_period_int as TAFloat;
+        }
+
+        let trendline =
+            (4.0 * average + 3.0 * self.i_trend1 + 2.0 * self.i_trend2 + self.i_trend3) / 10.0;
+        self.i_trend3 = self.i_trend2;
+        self.i_trend2 = self.i_trend1;
+        self.i_trend1 = average;
+        trendline
+    }
+}
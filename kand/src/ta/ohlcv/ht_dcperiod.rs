@@ -0,0 +1,322 @@
+use super::ht_core::{self, HtState};
+use crate::{Indicator, KandError, TAFloat};
+
+/// Number of smoother-only bars before the Hilbert stages start, see [`HtState::new`].
+pub const WARMUP: usize = ht_core::SHORT_WARMUP;
+
+const LOOKBACK: usize = 32;
+
+/// Returns the lookback period required for Hilbert Transform - Dominant Cycle Period
+/// (`HT_DCPERIOD`) calculation.
+///
+/// The Hilbert Transform has no user parameters, so the lookback is fixed at 32 bars: 3 bars
+/// to prime the WMA smoother, 9 smoother-only bars and 20 bars for the cycle estimator to
+/// settle, matching TA-Lib.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The number of bars needed before the first valid output
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_dcperiod;
+///
+/// let lookback = ht_dcperiod::lookback().unwrap();
+/// assert_eq!(lookback, 32);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(LOOKBACK)
+}
+
+/// Calculates the Hilbert Transform - Dominant Cycle Period (`HT_DCPERIOD`) for a price series.
+///
+/// `HT_DCPERIOD` is John Ehlers' estimate of the length, in bars, of the dominant market
+/// cycle. The price is smoothed, split into in-phase and quadrature components by a Hilbert
+/// Transform, and the bar-to-bar phase change measured by a homodyne discriminator gives the
+/// cycle period.
+///
+/// # Mathematical Formula
+/// ```text
+/// Smooth = (4 * Price[t] + 3 * Price[t-1] + 2 * Price[t-2] + Price[t-3]) / 10
+/// Detrender, Q1, jI, jQ = Hilbert Transform FIR stages of Smooth, Detrender, I1, Q1
+/// I2 = 0.2 * (I1 - jQ) + 0.8 * I2[t-1]
+/// Q2 = 0.2 * (Q1 + jI) + 0.8 * Q2[t-1]
+/// Re = 0.2 * (I2 * I2[t-1] + Q2 * Q2[t-1]) + 0.8 * Re[t-1]
+/// Im = 0.2 * (I2 * Q2[t-1] - Q2 * I2[t-1]) + 0.8 * Im[t-1]
+/// Period = 360 / atan(Im / Re), limited to [0.67, 1.5] x Period[t-1] and [6, 50]
+/// Period = 0.2 * Period + 0.8 * Period[t-1]
+/// DCPeriod = 0.33 * Period + 0.67 * DCPeriod[t-1]
+/// ```
+///
+/// # Calculation Principle
+/// 1. Smooth the price with a 4-bar weighted moving average
+/// 2. Run the smoothed price through the Hilbert Transform stages
+/// 3. Measure the phase rotation of the phasor with the homodyne discriminator
+/// 4. Convert the phase rotation to a period, limit and smooth it
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices
+/// * `output_dcperiod` - Array to store the dominant cycle period values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output array length doesn't match input
+/// * `KandError::InsufficientData` - If input length is less than or equal to lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_dcperiod;
+///
+/// let input_prices: Vec<f64> = (0..64).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_dcperiod = vec![0.0; input_prices.len()];
+///
+/// ht_dcperiod::ht_dcperiod(&input_prices, &mut output_dcperiod).unwrap();
+/// assert!(output_dcperiod[31].is_nan());
+/// assert!(output_dcperiod[32].is_finite());
+/// ```
+pub fn ht_dcperiod(
+    input_prices: &[TAFloat],
+    output_dcperiod: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_dcperiod.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut state = HtState::new(WARMUP);
+    for (i, price) in input_prices.iter().enumerate() {
+        output_dcperiod[i] = ht_dcperiod_inc(*price, &mut state)?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next `HT_DCPERIOD` value incrementally.
+///
+/// The Hilbert Transform keeps too much history to be passed around as individual values, so
+/// all of it lives in [`HtState`], which must be created with [`WARMUP`] and fed from the
+/// first bar of the series.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `state` - Hilbert Transform state, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The dominant cycle period, or NaN while the state has
+///   seen no more than `lookback()` bars
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `state` was not created with [`WARMUP`]
+/// * `KandError::NaNDetected` - If the input price is NaN (when "`deep-check`" feature is
+///   enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::{ht_core::HtState, ht_dcperiod};
+///
+/// let mut state = HtState::new(ht_dcperiod::WARMUP);
+/// let mut dcperiod = f64::NAN;
+/// for i in 0..40 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     dcperiod = ht_dcperiod::ht_dcperiod_inc(price, &mut state).unwrap();
+/// }
+/// assert!(dcperiod.is_finite());
+/// ```
+pub fn ht_dcperiod_inc(input_price: TAFloat, state: &mut HtState) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if state.warmup() != WARMUP {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    if !state.next(input_price) || state.bars() <= LOOKBACK {
+        return Ok(TAFloat::NAN);
+    }
+    Ok(state.smooth_period)
+}
+
+/// Streaming Hilbert Transform - Dominant Cycle Period (`HT_DCPERIOD`) calculator.
+///
+/// Owns the [`HtState`] and forwards every bar to [`ht_dcperiod_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ht_dcperiod::HtDcperiod};
+///
+/// let mut ht = HtDcperiod::new();
+/// for i in 0..32 {
+///     assert!(ht.update(100.0 + (i as f64 * 0.3).sin()).unwrap().is_none());
+/// }
+/// assert!(ht.update(100.0).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtDcperiod {
+    state: HtState,
+}
+
+impl HtDcperiod {
+    /// Creates a new streaming `HT_DCPERIOD`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: HtState::new(WARMUP),
+        }
+    }
+}
+
+impl Default for HtDcperiod {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for HtDcperiod {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        let output_dcperiod = ht_dcperiod_inc(input_price, &mut self.state)?;
+        Ok(self.is_ready().then_some(output_dcperiod))
+    }
+
+    fn reset(&mut self) {
+        self.state.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.state.bars() > LOOKBACK
+    }
+
+    fn lookback(&self) -> usize {
+        LOOKBACK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    // Basic functionality tests
+    #[test]
+    fn test_ht_dcperiod_calculation() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+            35154.0, 35216.3, 35211.8, 35158.4, 35172.0, 35176.7, 35113.3, 35114.7, 35129.3,
+            35094.6, 35114.4, 35094.5, 35116.0, 35105.4, 35050.7, 35031.3, 35008.1, 35021.4,
+            35048.1, 35002.9, 34974.8, 34962.1, 34985.3, 35019.6, 35070.2, 35083.9, 35114.8,
+            35151.5, 35127.6, 35096.2, 35084.0, 35120.4, 35163.9, 35198.3, 35222.5, 35204.8,
+            35180.1, 35155.6, 35142.9, 35171.4, 35203.0, 35245.7, 35262.1, 35231.8,
+        ];
+        let mut output_dcperiod = vec![0.0; input_prices.len()];
+
+        ht_dcperiod(&input_prices, &mut output_dcperiod).unwrap();
+
+        // First 32 values should be NaN
+        for value in output_dcperiod.iter().take(32) {
+            assert!(value.is_nan());
+        }
+
+        // Verify against known values
+        assert_relative_eq!(output_dcperiod[32], 15.646696015449795, epsilon = 0.00001);
+        assert_relative_eq!(output_dcperiod[33], 17.21176482512415, epsilon = 0.00001);
+        assert_relative_eq!(output_dcperiod[34], 18.895466084543493, epsilon = 0.00001);
+        assert_relative_eq!(output_dcperiod[52], 29.663820492252533, epsilon = 0.00001);
+        assert_relative_eq!(output_dcperiod[79], 28.085338828879216, epsilon = 0.00001);
+
+        // Now test incremental calculation matches regular calculation
+        let mut state = HtState::new(WARMUP);
+        for (i, price) in input_prices.iter().enumerate() {
+            let dcperiod = ht_dcperiod_inc(*price, &mut state).unwrap();
+            if i < 32 {
+                assert!(dcperiod.is_nan());
+            } else {
+                assert_relative_eq!(dcperiod, output_dcperiod[i], epsilon = 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ht_dcperiod_stream() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+            35154.0, 35216.3, 35211.8, 35158.4, 35172.0, 35176.7, 35113.3, 35114.7, 35129.3,
+            35094.6, 35114.4, 35094.5, 35116.0, 35105.4, 35050.7, 35031.3, 35008.1, 35021.4,
+            35048.1, 35002.9, 34974.8, 34962.1, 34985.3, 35019.6, 35070.2, 35083.9, 35114.8,
+            35151.5, 35127.6, 35096.2, 35084.0, 35120.4, 35163.9, 35198.3, 35222.5, 35204.8,
+            35180.1, 35155.6, 35142.9, 35171.4, 35203.0, 35245.7, 35262.1, 35231.8,
+        ];
+        let mut output_dcperiod = vec![0.0; input_prices.len()];
+
+        ht_dcperiod(&input_prices, &mut output_dcperiod).unwrap();
+
+        let mut stream = HtDcperiod::new();
+        for (i, price) in input_prices.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_dcperiod[i], epsilon = 0.00001),
+                None => assert!(output_dcperiod[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+
+        stream.reset();
+        assert!(!stream.is_ready());
+    }
+
+    #[test]
+    fn test_ht_dcperiod_wrong_warmup() {
+        let mut state = HtState::new(ht_core::LONG_WARMUP);
+        assert!(matches!(
+            ht_dcperiod_inc(35216.1, &mut state),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
@@ -0,0 +1,338 @@
+use super::ht_core::{self, HtState};
+use crate::{Indicator, KandError, TAFloat};
+
+/// Number of smoother-only bars before the Hilbert stages start, see [`HtState::new`].
+pub const WARMUP: usize = ht_core::SHORT_WARMUP;
+
+const LOOKBACK: usize = 32;
+
+/// Returns the lookback period required for Hilbert Transform - Phasor Components
+/// (`HT_PHASOR`) calculation.
+///
+/// The Hilbert Transform has no user parameters, so the lookback is fixed at 32 bars: 3 bars
+/// to prime the WMA smoother, 9 smoother-only bars and 20 bars for the cycle estimator to
+/// settle, matching TA-Lib.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The number of bars needed before the first valid output
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_phasor;
+///
+/// let lookback = ht_phasor::lookback().unwrap();
+/// assert_eq!(lookback, 32);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(LOOKBACK)
+}
+
+/// Calculates the Hilbert Transform - Phasor Components (`HT_PHASOR`) for a price series.
+///
+/// `HT_PHASOR` exposes the two orthogonal components produced by the Hilbert Transform: the
+/// in-phase component, which is the detrended price delayed by three bars, and the quadrature
+/// component, which lags it by 90 degrees. Together they form the phasor that the rest of the
+/// HT family is built from.
+///
+/// # Mathematical Formula
+/// ```text
+/// Smooth = (4 * Price[t] + 3 * Price[t-1] + 2 * Price[t-2] + Price[t-3]) / 10
+/// Detrender = HT(Smooth) * (0.075 * Period[t-1] + 0.54)
+/// Quadrature = HT(Detrender) * (0.075 * Period[t-1] + 0.54)
+/// InPhase = Detrender[t-3]
+/// where HT(x) = 0.0962 * x + 0.5769 * x[t-2] - 0.5769 * x[t-4] - 0.0962 * x[t-6]
+/// ```
+///
+/// # Calculation Principle
+/// 1. Smooth the price with a 4-bar weighted moving average
+/// 2. Detrend the smoothed price with the Hilbert Transform FIR filter
+/// 3. Transform the detrended price again to obtain the quadrature component
+/// 4. Delay the detrended price by three bars to obtain the in-phase component
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices
+/// * `output_in_phase` - Array to store the in-phase component values
+/// * `output_quadrature` - Array to store the quadrature component values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output array length doesn't match input
+/// * `KandError::InsufficientData` - If input length is less than or equal to lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_phasor;
+///
+/// let input_prices: Vec<f64> = (0..100).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_in_phase = vec![0.0; input_prices.len()];
+/// let mut output_quadrature = vec![0.0; input_prices.len()];
+///
+/// ht_phasor::ht_phasor(&input_prices, &mut output_in_phase, &mut output_quadrature).unwrap();
+/// assert!(output_in_phase[31].is_nan());
+/// assert!(output_quadrature[32].is_finite());
+/// ```
+pub fn ht_phasor(
+    input_prices: &[TAFloat],
+    output_in_phase: &mut [TAFloat],
+    output_quadrature: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_in_phase.len() != len || output_quadrature.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut state = HtState::new(WARMUP);
+    for (i, price) in input_prices.iter().enumerate() {
+        let (in_phase, quadrature) = ht_phasor_inc(*price, &mut state)?;
+        output_in_phase[i] = in_phase;
+        output_quadrature[i] = quadrature;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next `HT_PHASOR` value incrementally.
+///
+/// All Hilbert Transform history lives in [`HtState`], which must be created with [`WARMUP`]
+/// and fed from the first bar of the series.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `state` - Hilbert Transform state, updated in place
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple of (in-phase, quadrature), or NaN
+///   values while the state has seen no more than `lookback()` bars
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `state` was not created with [`WARMUP`]
+/// * `KandError::NaNDetected` - If the input price is NaN (when "`deep-check`" feature is
+///   enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::{ht_core::HtState, ht_phasor};
+///
+/// let mut state = HtState::new(ht_phasor::WARMUP);
+/// for i in 0..100 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     let _ = ht_phasor::ht_phasor_inc(price, &mut state).unwrap();
+/// }
+/// assert_eq!(state.bars(), 100);
+/// ```
+pub fn ht_phasor_inc(
+    input_price: TAFloat,
+    state: &mut HtState,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if state.warmup() != WARMUP {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    if !state.next(input_price) || state.bars() <= LOOKBACK {
+        return Ok((TAFloat::NAN, TAFloat::NAN));
+    }
+    Ok((state.in_phase, state.quadrature))
+}
+
+/// Streaming Hilbert Transform - Phasor Components (`HT_PHASOR`) calculator.
+///
+/// Owns the [`HtState`] and forwards every bar to [`ht_phasor_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ht_phasor::HtPhasor};
+///
+/// let mut ht = HtPhasor::new();
+/// for i in 0..32 {
+///     assert!(ht.update(100.0 + (i as f64 * 0.3).sin()).unwrap().is_none());
+/// }
+/// assert!(ht.update(100.0).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtPhasor {
+    state: HtState,
+}
+
+impl HtPhasor {
+    /// Creates a new streaming `HT_PHASOR`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: HtState::new(WARMUP),
+        }
+    }
+}
+
+impl Default for HtPhasor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for HtPhasor {
+    type Input = TAFloat;
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<(TAFloat, TAFloat)>, KandError> {
+        let output = ht_phasor_inc(input_price, &mut self.state)?;
+        Ok(self.is_ready().then_some(output))
+    }
+
+    fn reset(&mut self) {
+        self.state.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.state.bars() > LOOKBACK
+    }
+
+    fn lookback(&self) -> usize {
+        LOOKBACK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    // Basic functionality tests
+    #[test]
+    fn test_ht_phasor_calculation() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+            35154.0, 35216.3, 35211.8, 35158.4, 35172.0, 35176.7, 35113.3, 35114.7, 35129.3,
+            35094.6, 35114.4, 35094.5, 35116.0, 35105.4, 35050.7, 35031.3, 35008.1, 35021.4,
+            35048.1, 35002.9, 34974.8, 34962.1, 34985.3, 35019.6, 35070.2, 35083.9, 35114.8,
+            35151.5, 35127.6, 35096.2, 35084.0, 35120.4, 35163.9, 35198.3, 35222.5, 35204.8,
+            35180.1, 35155.6, 35142.9, 35171.4, 35203.0, 35245.7, 35262.1, 35231.8,
+        ];
+        let mut output_in_phase = vec![0.0; input_prices.len()];
+        let mut output_quadrature = vec![0.0; input_prices.len()];
+
+        ht_phasor(&input_prices, &mut output_in_phase, &mut output_quadrature).unwrap();
+
+        // First 32 values should be NaN
+        for value in output_in_phase.iter().take(32) {
+            assert!(value.is_nan());
+        }
+        for value in output_quadrature.iter().take(32) {
+            assert!(value.is_nan());
+        }
+
+        // Verify against known values
+        assert_relative_eq!(output_in_phase[32], 31.05859141286895, epsilon = 0.00001);
+        assert_relative_eq!(output_in_phase[33], 4.391009078599382, epsilon = 0.00001);
+        assert_relative_eq!(output_in_phase[79], -61.09883535756665, epsilon = 0.00001);
+        assert_relative_eq!(output_quadrature[32], -63.01544592252889, epsilon = 0.00001);
+        assert_relative_eq!(
+            output_quadrature[33],
+            -30.055778437693007,
+            epsilon = 0.00001
+        );
+        assert_relative_eq!(output_quadrature[79], 15.876433757308678, epsilon = 0.00001);
+
+        // Now test incremental calculation matches regular calculation
+        let mut state = HtState::new(WARMUP);
+        for (i, price) in input_prices.iter().enumerate() {
+            let (in_phase, quadrature) = ht_phasor_inc(*price, &mut state).unwrap();
+            if i < 32 {
+                assert!(in_phase.is_nan());
+            } else {
+                assert_relative_eq!(in_phase, output_in_phase[i], epsilon = 0.00001);
+                assert_relative_eq!(quadrature, output_quadrature[i], epsilon = 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ht_phasor_stream() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+            35154.0, 35216.3, 35211.8, 35158.4, 35172.0, 35176.7, 35113.3, 35114.7, 35129.3,
+            35094.6, 35114.4, 35094.5, 35116.0, 35105.4, 35050.7, 35031.3, 35008.1, 35021.4,
+            35048.1, 35002.9, 34974.8, 34962.1, 34985.3, 35019.6, 35070.2, 35083.9, 35114.8,
+            35151.5, 35127.6, 35096.2, 35084.0, 35120.4, 35163.9, 35198.3, 35222.5, 35204.8,
+            35180.1, 35155.6, 35142.9, 35171.4, 35203.0, 35245.7, 35262.1, 35231.8,
+        ];
+        let mut output_in_phase = vec![0.0; input_prices.len()];
+        let mut output_quadrature = vec![0.0; input_prices.len()];
+
+        ht_phasor(&input_prices, &mut output_in_phase, &mut output_quadrature).unwrap();
+
+        let mut stream = HtPhasor::new();
+        for (i, price) in input_prices.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some((in_phase, quadrature)) => {
+                    assert_relative_eq!(in_phase, output_in_phase[i], epsilon = 0.00001);
+                    assert_relative_eq!(quadrature, output_quadrature[i], epsilon = 0.00001);
+                }
+                None => assert!(output_in_phase[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+
+        stream.reset();
+        assert!(!stream.is_ready());
+    }
+
+    #[test]
+    fn test_ht_phasor_wrong_warmup() {
+        let mut state = HtState::new(ht_core::LONG_WARMUP);
+        assert!(matches!(
+            ht_phasor_inc(35216.1, &mut state),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
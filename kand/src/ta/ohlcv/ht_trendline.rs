@@ -0,0 +1,317 @@
+use super::ht_core::{self, HtState};
+use crate::{Indicator, KandError, TAFloat};
+
+/// Number of smoother-only bars before the Hilbert stages start, see [`HtState::new`].
+pub const WARMUP: usize = ht_core::LONG_WARMUP;
+
+const LOOKBACK: usize = 63;
+
+/// Returns the lookback period required for Hilbert Transform - Instantaneous Trendline
+/// (`HT_TRENDLINE`) calculation.
+///
+/// The Hilbert Transform has no user parameters, so the lookback is fixed at 63 bars: 3 bars
+/// to prime the WMA smoother, 34 smoother-only bars and 26 bars for the cycle estimator and
+/// its 50-bar smoothed price buffer to settle, matching TA-Lib.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The number of bars needed before the first valid output
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_trendline;
+///
+/// let lookback = ht_trendline::lookback().unwrap();
+/// assert_eq!(lookback, 63);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(LOOKBACK)
+}
+
+/// Calculates the Hilbert Transform - Instantaneous Trendline (`HT_TRENDLINE`) for a price series.
+///
+/// `HT_TRENDLINE` removes the dominant cycle from the price by averaging it over exactly one
+/// cycle period, which leaves the underlying trend. The averages are then smoothed with a
+/// 4-bar weighted moving average.
+///
+/// # Mathematical Formula
+/// ```text
+/// DCPeriod = dominant cycle period, see HT_DCPERIOD
+/// N = int(DCPeriod + 0.5)
+/// ITrend = Σ(Price[t-i]) / N  for i = 0..N-1
+/// Trendline = (4 * ITrend + 3 * ITrend[t-1] + 2 * ITrend[t-2] + ITrend[t-3]) / 10
+/// ```
+///
+/// # Calculation Principle
+/// 1. Estimate the smoothed dominant cycle period with the shared Hilbert Transform core
+/// 2. Average the raw price over the last N bars, N being the rounded cycle period
+/// 3. Smooth the averages with a 4-bar weighted moving average
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices
+/// * `output_trendline` - Array to store the trendline values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output array length doesn't match input
+/// * `KandError::InsufficientData` - If input length is less than or equal to lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_trendline;
+///
+/// let input_prices: Vec<f64> = (0..100).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_trendline = vec![0.0; input_prices.len()];
+///
+/// ht_trendline::ht_trendline(&input_prices, &mut output_trendline).unwrap();
+/// assert!(output_trendline[62].is_nan());
+/// assert!((output_trendline[63] - 100.0).abs() < 1.0);
+/// ```
+pub fn ht_trendline(
+    input_prices: &[TAFloat],
+    output_trendline: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_trendline.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut state = HtState::new(WARMUP);
+    for (i, price) in input_prices.iter().enumerate() {
+        output_trendline[i] = ht_trendline_inc(*price, &mut state)?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next `HT_TRENDLINE` value incrementally.
+///
+/// All Hilbert Transform history lives in [`HtState`], which must be created with [`WARMUP`]
+/// and fed from the first bar of the series.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `state` - Hilbert Transform state, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The trendline value, or NaN while the state has seen no
+///   more than `lookback()` bars
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `state` was not created with [`WARMUP`]
+/// * `KandError::NaNDetected` - If the input price is NaN (when "`deep-check`" feature is
+///   enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::{ht_core::HtState, ht_trendline};
+///
+/// let mut state = HtState::new(ht_trendline::WARMUP);
+/// for i in 0..100 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     let _ = ht_trendline::ht_trendline_inc(price, &mut state).unwrap();
+/// }
+/// assert_eq!(state.bars(), 100);
+/// ```
+pub fn ht_trendline_inc(input_price: TAFloat, state: &mut HtState) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if state.warmup() != WARMUP {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    if !state.next(input_price) {
+        return Ok(TAFloat::NAN);
+    }
+    let trendline = state.update_trendline();
+
+    if state.bars() <= LOOKBACK {
+        return Ok(TAFloat::NAN);
+    }
+    Ok(trendline)
+}
+
+/// Streaming Hilbert Transform - Instantaneous Trendline (`HT_TRENDLINE`) calculator.
+///
+/// Owns the [`HtState`] and forwards every bar to [`ht_trendline_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ht_trendline::HtTrendline};
+///
+/// let mut ht = HtTrendline::new();
+/// for i in 0..63 {
+///     assert!(ht.update(100.0 + (i as f64 * 0.3).sin()).unwrap().is_none());
+/// }
+/// assert!(ht.update(100.0).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtTrendline {
+    state: HtState,
+}
+
+impl HtTrendline {
+    /// Creates a new streaming `HT_TRENDLINE`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: HtState::new(WARMUP),
+        }
+    }
+}
+
+impl Default for HtTrendline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for HtTrendline {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        let output_trendline = ht_trendline_inc(input_price, &mut self.state)?;
+        Ok(self.is_ready().then_some(output_trendline))
+    }
+
+    fn reset(&mut self) {
+        self.state.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.state.bars() > LOOKBACK
+    }
+
+    fn lookback(&self) -> usize {
+        LOOKBACK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    // Basic functionality tests
+    #[test]
+    fn test_ht_trendline_calculation() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+            35154.0, 35216.3, 35211.8, 35158.4, 35172.0, 35176.7, 35113.3, 35114.7, 35129.3,
+            35094.6, 35114.4, 35094.5, 35116.0, 35105.4, 35050.7, 35031.3, 35008.1, 35021.4,
+            35048.1, 35002.9, 34974.8, 34962.1, 34985.3, 35019.6, 35070.2, 35083.9, 35114.8,
+            35151.5, 35127.6, 35096.2, 35084.0, 35120.4, 35163.9, 35198.3, 35222.5, 35204.8,
+            35180.1, 35155.6, 35142.9, 35171.4, 35203.0, 35245.7, 35262.1, 35231.8,
+        ];
+        let mut output_trendline = vec![0.0; input_prices.len()];
+
+        ht_trendline(&input_prices, &mut output_trendline).unwrap();
+
+        // First 63 values should be NaN
+        for value in output_trendline.iter().take(63) {
+            assert!(value.is_nan());
+        }
+
+        // Verify against known values
+        assert_relative_eq!(output_trendline[63], 35071.778515339734, epsilon = 0.00001);
+        assert_relative_eq!(output_trendline[64], 35075.09547187595, epsilon = 0.00001);
+        assert_relative_eq!(output_trendline[65], 35076.29557859532, epsilon = 0.00001);
+        assert_relative_eq!(output_trendline[79], 35106.67366666667, epsilon = 0.00001);
+
+        // Now test incremental calculation matches regular calculation
+        let mut state = HtState::new(WARMUP);
+        for (i, price) in input_prices.iter().enumerate() {
+            let trendline = ht_trendline_inc(*price, &mut state).unwrap();
+            if i < 63 {
+                assert!(trendline.is_nan());
+            } else {
+                assert_relative_eq!(trendline, output_trendline[i], epsilon = 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ht_trendline_stream() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+            35154.0, 35216.3, 35211.8, 35158.4, 35172.0, 35176.7, 35113.3, 35114.7, 35129.3,
+            35094.6, 35114.4, 35094.5, 35116.0, 35105.4, 35050.7, 35031.3, 35008.1, 35021.4,
+            35048.1, 35002.9, 34974.8, 34962.1, 34985.3, 35019.6, 35070.2, 35083.9, 35114.8,
+            35151.5, 35127.6, 35096.2, 35084.0, 35120.4, 35163.9, 35198.3, 35222.5, 35204.8,
+            35180.1, 35155.6, 35142.9, 35171.4, 35203.0, 35245.7, 35262.1, 35231.8,
+        ];
+        let mut output_trendline = vec![0.0; input_prices.len()];
+
+        ht_trendline(&input_prices, &mut output_trendline).unwrap();
+
+        let mut stream = HtTrendline::new();
+        for (i, price) in input_prices.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_trendline[i], epsilon = 0.00001),
+                None => assert!(output_trendline[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+
+        stream.reset();
+        assert!(!stream.is_ready());
+    }
+
+    #[test]
+    fn test_ht_trendline_wrong_warmup() {
+        let mut state = HtState::new(ht_core::SHORT_WARMUP);
+        assert!(matches!(
+            ht_trendline_inc(35216.1, &mut state),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
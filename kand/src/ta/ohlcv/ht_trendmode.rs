@@ -0,0 +1,346 @@
+use super::ht_core::{self, HtState};
+use crate::{Indicator, KandError, TAFloat, TAInt};
+
+/// Number of smoother-only bars before the Hilbert stages start, see [`HtState::new`].
+pub const WARMUP: usize = ht_core::LONG_WARMUP;
+
+const LOOKBACK: usize = 63;
+
+/// Returns the lookback period required for Hilbert Transform - Trend vs Cycle Mode
+/// (`HT_TRENDMODE`) calculation.
+///
+/// The Hilbert Transform has no user parameters, so the lookback is fixed at 63 bars: 3 bars
+/// to prime the WMA smoother, 34 smoother-only bars and 26 bars for the cycle estimator and
+/// its 50-bar smoothed price buffer to settle, matching TA-Lib.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The number of bars needed before the first valid output
+///
+/// # Errors
+/// This function does not return any errors.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_trendmode;
+///
+/// let lookback = ht_trendmode::lookback().unwrap();
+/// assert_eq!(lookback, 63);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(LOOKBACK)
+}
+
+/// Calculates the Hilbert Transform - Trend vs Cycle Mode (`HT_TRENDMODE`) for a price series.
+///
+/// `HT_TRENDMODE` classifies every bar as trending (1) or cycling (0). It combines the
+/// SineWave crossings, the rate of change of the dominant cycle phase and the distance between
+/// the smoothed price and the instantaneous trendline.
+///
+/// # Mathematical Formula
+/// ```text
+/// Trend = 1 by default
+/// Trend = 0 if Sine crossed LeadSine on this bar (DaysInTrend resets to 0)
+/// Trend = 0 if DaysInTrend < 0.5 * DCPeriod
+/// Trend = 0 if 0.67 * 360° / DCPeriod < DCPhase - DCPhase[t-1] < 1.5 * 360° / DCPeriod
+/// Trend = 1 if |Smooth - Trendline| / Trendline >= 1.5%
+/// ```
+///
+/// # Calculation Principle
+/// 1. Update the dominant cycle phase, sine wave and trendline with the shared core
+/// 2. Count the bars since the last sine/lead sine crossing
+/// 3. Treat the market as cycling while the crossing is recent or the phase advances at the
+///    cycle rate
+/// 4. Force trend mode when the smoothed price departs from the trendline by 1.5% or more
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices
+/// * `output_trendmode` - Array to store the trend mode values (1 = trend, 0 = cycle, 0 during the lookback period)
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output array length doesn't match input
+/// * `KandError::InsufficientData` - If input length is less than or equal to lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ht_trendmode;
+///
+/// let input_prices: Vec<f64> = (0..100).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_trendmode = vec![0; input_prices.len()];
+///
+/// ht_trendmode::ht_trendmode(&input_prices, &mut output_trendmode).unwrap();
+/// assert!(output_trendmode.iter().all(|v| *v == 0 || *v == 1));
+/// ```
+pub fn ht_trendmode(
+    input_prices: &[TAFloat],
+    output_trendmode: &mut [TAInt],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback()?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_trendmode.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut state = HtState::new(WARMUP);
+    for (i, price) in input_prices.iter().enumerate() {
+        output_trendmode[i] = ht_trendmode_inc(*price, &mut state)?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next `HT_TRENDMODE` value incrementally.
+///
+/// All Hilbert Transform history lives in [`HtState`], which must be created with [`WARMUP`]
+/// and fed from the first bar of the series.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `state` - Hilbert Transform state, updated in place
+///
+/// # Returns
+/// * `Result<TAInt, KandError>` - 1 for trend mode, 0 for cycle mode; 0 while the state has
+///   seen no more than `lookback()` bars
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `state` was not created with [`WARMUP`]
+/// * `KandError::NaNDetected` - If the input price is NaN (when "`deep-check`" feature is
+///   enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::{ht_core::HtState, ht_trendmode};
+///
+/// let mut state = HtState::new(ht_trendmode::WARMUP);
+/// for i in 0..100 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     let _ = ht_trendmode::ht_trendmode_inc(price, &mut state).unwrap();
+/// }
+/// assert_eq!(state.bars(), 100);
+/// ```
+pub fn ht_trendmode_inc(input_price: TAFloat, state: &mut HtState) -> Result<TAInt, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if state.warmup() != WARMUP {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    if !state.next(input_price) {
+        return Ok(0);
+    }
+    state.update_phase();
+    let trendline = state.update_trendline();
+
+    // Assume trend mode by default
+    let mut trend = 1;
+
+    // Measure days in trend from the last crossing of the SineWave lines
+    if (state.sine > state.lead_sine && state.prev_sine <= state.prev_lead_sine)
+        || (state.sine < state.lead_sine && state.prev_sine >= state.prev_lead_sine)
+    {
+        state.days_in_trend = 0;
+        trend = 0;
+    }
+    state.days_in_trend += 1;
+    if (state.days_in_trend as TAFloat) < 0.5 * state.smooth_period {
+        trend = 0;
+    }
+
+    // Cycle mode if the phase advances at roughly the dominant cycle rate
+    let delta_phase = state.dc_phase - state.prev_dc_phase;
+    if state.smooth_period != 0.0
+        && delta_phase > 0.67 * 360.0 / state.smooth_period
+        && delta_phase < 1.5 * 360.0 / state.smooth_period
+    {
+        trend = 0;
+    }
+
+    // Trend mode if the smoothed price is at least 1.5% away from the trendline
+    if trendline != 0.0 && ((state.smoothed_price() - trendline) / trendline).abs() >= 0.015 {
+        trend = 1;
+    }
+
+    if state.bars() <= LOOKBACK {
+        return Ok(0);
+    }
+    Ok(trend)
+}
+
+/// Streaming Hilbert Transform - Trend vs Cycle Mode (`HT_TRENDMODE`) calculator.
+///
+/// Owns the [`HtState`] and forwards every bar to [`ht_trendmode_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ht_trendmode::HtTrendmode};
+///
+/// let mut ht = HtTrendmode::new();
+/// for i in 0..63 {
+///     assert!(ht.update(100.0 + (i as f64 * 0.3).sin()).unwrap().is_none());
+/// }
+/// assert!(ht.update(100.0).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HtTrendmode {
+    state: HtState,
+}
+
+impl HtTrendmode {
+    /// Creates a new streaming `HT_TRENDMODE`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: HtState::new(WARMUP),
+        }
+    }
+}
+
+impl Default for HtTrendmode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for HtTrendmode {
+    type Input = TAFloat;
+    type Output = TAInt;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAInt>, KandError> {
+        let output_trendmode = ht_trendmode_inc(input_price, &mut self.state)?;
+        Ok(self.is_ready().then_some(output_trendmode))
+    }
+
+    fn reset(&mut self) {
+        self.state.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.state.bars() > LOOKBACK
+    }
+
+    fn lookback(&self) -> usize {
+        LOOKBACK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Basic functionality tests
+    #[test]
+    fn test_ht_trendmode_calculation() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+            35154.0, 35216.3, 35211.8, 35158.4, 35172.0, 35176.7, 35113.3, 35114.7, 35129.3,
+            35094.6, 35114.4, 35094.5, 35116.0, 35105.4, 35050.7, 35031.3, 35008.1, 35021.4,
+            35048.1, 35002.9, 34974.8, 34962.1, 34985.3, 35019.6, 35070.2, 35083.9, 35114.8,
+            35151.5, 35127.6, 35096.2, 35084.0, 35120.4, 35163.9, 35198.3, 35222.5, 35204.8,
+            35180.1, 35155.6, 35142.9, 35171.4, 35203.0, 35245.7, 35262.1, 35231.8,
+        ];
+        let mut output_trendmode = vec![0; input_prices.len()];
+
+        ht_trendmode(&input_prices, &mut output_trendmode).unwrap();
+
+        // First 63 values should be 0
+        // Lookback values are reported as cycle mode
+        for value in output_trendmode.iter().take(63) {
+            assert_eq!(*value, 0);
+        }
+
+        // Verify against known values
+        assert_eq!(output_trendmode[63], 1);
+        assert_eq!(output_trendmode[64], 1);
+        assert_eq!(output_trendmode[65], 0);
+        assert_eq!(output_trendmode[66], 1);
+        assert_eq!(output_trendmode[68], 0);
+        assert_eq!(output_trendmode[79], 0);
+
+        // Now test incremental calculation matches regular calculation
+        let mut state = HtState::new(WARMUP);
+        for (i, price) in input_prices.iter().enumerate() {
+            let trendmode = ht_trendmode_inc(*price, &mut state).unwrap();
+            assert_eq!(trendmode, output_trendmode[i]);
+        }
+    }
+
+    #[test]
+    fn test_ht_trendmode_stream() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+            35154.0, 35216.3, 35211.8, 35158.4, 35172.0, 35176.7, 35113.3, 35114.7, 35129.3,
+            35094.6, 35114.4, 35094.5, 35116.0, 35105.4, 35050.7, 35031.3, 35008.1, 35021.4,
+            35048.1, 35002.9, 34974.8, 34962.1, 34985.3, 35019.6, 35070.2, 35083.9, 35114.8,
+            35151.5, 35127.6, 35096.2, 35084.0, 35120.4, 35163.9, 35198.3, 35222.5, 35204.8,
+            35180.1, 35155.6, 35142.9, 35171.4, 35203.0, 35245.7, 35262.1, 35231.8,
+        ];
+        let mut output_trendmode = vec![0; input_prices.len()];
+
+        ht_trendmode(&input_prices, &mut output_trendmode).unwrap();
+
+        let mut stream = HtTrendmode::new();
+        for (i, price) in input_prices.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_eq!(value, output_trendmode[i]),
+                None => assert!(i < 63),
+            }
+        }
+        assert!(stream.is_ready());
+
+        stream.reset();
+        assert!(!stream.is_ready());
+    }
+
+    #[test]
+    fn test_ht_trendmode_wrong_warmup() {
+        let mut state = HtState::new(ht_core::SHORT_WARMUP);
+        assert!(matches!(
+            ht_trendmode_inc(35216.1, &mut state),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
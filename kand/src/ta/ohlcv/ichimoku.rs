@@ -0,0 +1,638 @@
+use std::collections::VecDeque;
+
+use super::midprice::Midprice;
+use crate::{Indicator, KandError, TAFloat};
+
+/// Returns the lookback period required for Ichimoku calculation.
+///
+/// # Description
+/// The lookback is the first index at which every line plotted on the current bar (Tenkan,
+/// Kijun and both cloud spans) is defined. The cloud spans are computed from the longest
+/// window and then displaced forward, so both terms add up.
+///
+/// # Arguments
+/// * `param_tenkan_period` - Tenkan-sen (conversion line) period (must be >= 2)
+/// * `param_kijun_period` - Kijun-sen (base line) period (must be >= 2)
+/// * `param_senkou_b_period` - Senkou Span B period (must be >= 2)
+/// * `param_displacement` - Number of bars the cloud is shifted forward and the Chikou Span
+///   backward
+///
+/// # Returns
+/// * `Result<usize, KandError>` - `max(periods) - 1 + param_displacement` on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If any period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ichimoku;
+/// let lookback = ichimoku::lookback(9, 26, 52, 26).unwrap();
+/// assert_eq!(lookback, 77);
+/// ```
+pub const fn lookback(
+    param_tenkan_period: usize,
+    param_kijun_period: usize,
+    param_senkou_b_period: usize,
+    param_displacement: usize,
+) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if param_tenkan_period < 2 || param_kijun_period < 2 || param_senkou_b_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    let mut longest = param_tenkan_period;
+    if param_kijun_period > longest {
+        longest = param_kijun_period;
+    }
+    if param_senkou_b_period > longest {
+        longest = param_senkou_b_period;
+    }
+    Ok(longest - 1 + param_displacement)
+}
+
+/// Calculates Ichimoku Kinko Hyo.
+///
+/// # Description
+/// Ichimoku combines three Donchian-style midpoints into a trend system:
+/// - Tenkan-sen, the conversion line, is the midpoint of the short window
+/// - Kijun-sen, the base line, is the midpoint of the medium window
+/// - Senkou Span A, the first cloud edge, is the average of Tenkan and Kijun
+/// - Senkou Span B, the second cloud edge, is the midpoint of the long window
+/// - Chikou Span, the lagging line, is the close
+///
+/// The two Senkou spans are plotted `param_displacement` bars ahead of the bar they are
+/// computed on. Rather than dropping the projection past the last bar, their outputs are
+/// `param_displacement` slots longer than the input: `output_senkou_a[i]` is the cloud value
+/// at bar `i`, and the extra slots at the end hold the cloud for the bars that have not
+/// happened yet. The Chikou Span is plotted `param_displacement` bars back, so
+/// `output_chikou[i]` is the close of bar `i + param_displacement` and the last
+/// `param_displacement` values are NaN. It is meant for charting; reading it at bar `i` in a
+/// backtest looks into the future.
+///
+/// Charting platforms differ in whether the current bar counts towards the shift; pass
+/// `param_displacement - 1` to match those that plot the cloud 25 bars ahead for the standard
+/// setting of 26.
+///
+/// # Mathematical Formula
+/// ```text
+/// Midpoint(n)[i]  = (Highest High[i-n+1..=i] + Lowest Low[i-n+1..=i]) / 2
+/// Tenkan[i]       = Midpoint(tenkan_period)[i]
+/// Kijun[i]        = Midpoint(kijun_period)[i]
+/// Senkou A[i + d] = (Tenkan[i] + Kijun[i]) / 2
+/// Senkou B[i + d] = Midpoint(senkou_b_period)[i]
+/// Chikou[i - d]   = Close[i]
+/// ```
+/// Where d is `param_displacement`.
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `param_tenkan_period` - Tenkan-sen period (must be >= 2, typically 9)
+/// * `param_kijun_period` - Kijun-sen period (must be >= 2, typically 26)
+/// * `param_senkou_b_period` - Senkou Span B period (must be >= 2, typically 52)
+/// * `param_displacement` - Forward shift of the cloud and backward shift of the Chikou Span
+///   (typically 26)
+/// * `output_tenkan` - Array storing the Tenkan-sen values, same length as the input
+/// * `output_kijun` - Array storing the Kijun-sen values, same length as the input
+/// * `output_senkou_a` - Array storing Senkou Span A, `param_displacement` longer than the input
+/// * `output_senkou_b` - Array storing Senkou Span B, `param_displacement` longer than the input
+/// * `output_chikou` - Array storing the Chikou Span, same length as the input
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If an input or output array has the wrong length
+/// * `KandError::InvalidParameter` - If any period is less than 2
+/// * `KandError::InsufficientData` - If the input is shorter than the longest period
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ichimoku;
+///
+/// let input_high = vec![10.0, 11.0, 12.0, 13.0, 12.0, 11.0];
+/// let input_low = vec![8.0, 9.0, 10.0, 11.0, 10.0, 9.0];
+/// let input_close = vec![9.0, 10.0, 11.0, 12.0, 11.0, 10.0];
+/// let mut output_tenkan = vec![0.0; 6];
+/// let mut output_kijun = vec![0.0; 6];
+/// let mut output_senkou_a = vec![0.0; 8];
+/// let mut output_senkou_b = vec![0.0; 8];
+/// let mut output_chikou = vec![0.0; 6];
+///
+/// ichimoku::ichimoku(
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     2,
+///     3,
+///     4,
+///     2,
+///     &mut output_tenkan,
+///     &mut output_kijun,
+///     &mut output_senkou_a,
+///     &mut output_senkou_b,
+///     &mut output_chikou,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_kijun[2], 10.0);
+/// // The cloud computed on the last bar is projected two bars past the input
+/// assert_eq!(output_senkou_a[7], 10.75);
+/// assert_eq!(output_senkou_b[7], 11.0);
+/// assert_eq!(output_chikou[3], 10.0);
+/// assert!(output_chikou[4].is_nan());
+/// ```
+pub fn ichimoku(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_tenkan_period: usize,
+    param_kijun_period: usize,
+    param_senkou_b_period: usize,
+    param_displacement: usize,
+    output_tenkan: &mut [TAFloat],
+    output_kijun: &mut [TAFloat],
+    output_senkou_a: &mut [TAFloat],
+    output_senkou_b: &mut [TAFloat],
+    output_chikou: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+
+    #[cfg(feature = "check")]
+    {
+        let lookback = lookback(
+            param_tenkan_period,
+            param_kijun_period,
+            param_senkou_b_period,
+            param_displacement,
+        )?;
+
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len < lookback + 1 - param_displacement {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if input_low.len() != len
+            || input_close.len() != len
+            || output_tenkan.len() != len
+            || output_kijun.len() != len
+            || output_chikou.len() != len
+            || output_senkou_a.len() != len + param_displacement
+            || output_senkou_b.len() != len + param_displacement
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut tenkan = Midprice::new(param_tenkan_period)?;
+    let mut kijun = Midprice::new(param_kijun_period)?;
+    let mut senkou_b = Midprice::new(param_senkou_b_period)?;
+    for i in 0..len {
+        let (output_tenkan_value, output_kijun_value, output_senkou_a_value, output_senkou_b_value) =
+            ichimoku_inc(
+                input_high[i],
+                input_low[i],
+                &mut tenkan,
+                &mut kijun,
+                &mut senkou_b,
+            )?;
+        output_tenkan[i] = output_tenkan_value;
+        output_kijun[i] = output_kijun_value;
+        output_senkou_a[i + param_displacement] = output_senkou_a_value;
+        output_senkou_b[i + param_displacement] = output_senkou_b_value;
+        output_chikou[i] = input_close
+            .get(i + param_displacement)
+            .copied()
+            .unwrap_or(TAFloat::NAN);
+    }
+
+    // The cloud before the first projected value is undefined
+    for i in 0..param_displacement {
+        output_senkou_a[i] = TAFloat::NAN;
+        output_senkou_b[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next Ichimoku values incrementally.
+///
+/// # Description
+/// Pushes the bar into the three rolling midpoint windows, whose monotonic deques keep the
+/// highest high and lowest low exact as old extremes leave the window. The returned Senkou
+/// spans are the values computed on this bar, to be plotted `param_displacement` bars ahead;
+/// the streaming [`Ichimoku`] keeps the projection buffer and returns the cloud at the
+/// current bar instead.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `rolling_tenkan` - Tenkan-sen midpoint window, updated in place
+/// * `rolling_kijun` - Kijun-sen midpoint window, updated in place
+/// * `rolling_senkou_b` - Senkou Span B midpoint window, updated in place
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (Tenkan, Kijun,
+///   Senkou A, Senkou B); each value is NaN until its window is full
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::{ichimoku, midprice::Midprice};
+///
+/// let mut tenkan = Midprice::new(2).unwrap();
+/// let mut kijun = Midprice::new(3).unwrap();
+/// let mut senkou_b = Midprice::new(4).unwrap();
+/// for (high, low) in [(10.0, 8.0), (11.0, 9.0), (12.0, 10.0)] {
+///     ichimoku::ichimoku_inc(high, low, &mut tenkan, &mut kijun, &mut senkou_b).unwrap();
+/// }
+///
+/// let (tenkan_value, kijun_value, senkou_a, senkou_b_value) =
+///     ichimoku::ichimoku_inc(13.0, 11.0, &mut tenkan, &mut kijun, &mut senkou_b).unwrap();
+/// assert_eq!((tenkan_value, kijun_value), (11.5, 11.0));
+/// assert_eq!((senkou_a, senkou_b_value), (11.25, 10.5));
+/// ```
+pub fn ichimoku_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    rolling_tenkan: &mut Midprice,
+    rolling_kijun: &mut Midprice,
+    rolling_senkou_b: &mut Midprice,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    let tenkan = rolling_tenkan
+        .update((input_high, input_low))?
+        .unwrap_or(TAFloat::NAN);
+    let kijun = rolling_kijun
+        .update((input_high, input_low))?
+        .unwrap_or(TAFloat::NAN);
+    let senkou_b = rolling_senkou_b
+        .update((input_high, input_low))?
+        .unwrap_or(TAFloat::NAN);
+    let senkou_a = (tenkan + kijun) / 2.0;
+
+    Ok((tenkan, kijun, senkou_a, senkou_b))
+}
+
+/// Streaming Ichimoku Kinko Hyo calculator.
+///
+/// Consumes `(high, low, close)` bars and emits `(tenkan, kijun, senkou_a, senkou_b, chikou)`
+/// for the current bar, where the Senkou spans are the cloud plotted at this bar, i.e. the
+/// values computed `param_displacement` bars earlier. The projection for the bars ahead is
+/// available from [`Ichimoku::leading`]. The Chikou Span is the current close, to be plotted
+/// `param_displacement` bars back: it is the value the batch function stores in
+/// `output_chikou[i - param_displacement]`, which only becomes known on bar `i`.
+///
+/// The rolling highs and lows are exact monotonic windows, see
+/// [`midprice_inc`](super::midprice::midprice_inc).
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ichimoku::Ichimoku};
+///
+/// let mut ichimoku = Ichimoku::new(2, 3, 4, 2).unwrap();
+/// let bars = [
+///     (10.0, 8.0, 9.0),
+///     (11.0, 9.0, 10.0),
+///     (12.0, 10.0, 11.0),
+///     (13.0, 11.0, 12.0),
+///     (12.0, 10.0, 11.0),
+///     (11.0, 9.0, 9.5),
+/// ];
+/// for bar in &bars[..5] {
+///     assert_eq!(ichimoku.update(*bar).unwrap(), None);
+/// }
+/// // The cloud on bar 5 was computed on bar 3, and its close is the Chikou Span of bar 3
+/// assert_eq!(
+///     ichimoku.update(bars[5]).unwrap(),
+///     Some((10.5, 11.0, 11.25, 10.5, 9.5))
+/// );
+/// assert_eq!(ichimoku.leading(), Some((10.75, 11.0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ichimoku {
+    param_displacement: usize,
+    lookback:           usize,
+    count:              usize,
+    rolling_tenkan:     Midprice,
+    rolling_kijun:      Midprice,
+    rolling_senkou_b:   Midprice,
+    projection:         VecDeque<(TAFloat, TAFloat)>,
+}
+
+impl Ichimoku {
+    /// Creates a new streaming Ichimoku.
+    ///
+    /// # Arguments
+    /// * `param_tenkan_period` - Tenkan-sen period (must be >= 2)
+    /// * `param_kijun_period` - Kijun-sen period (must be >= 2)
+    /// * `param_senkou_b_period` - Senkou Span B period (must be >= 2)
+    /// * `param_displacement` - Forward shift of the cloud
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period is less than 2
+    pub fn new(
+        param_tenkan_period: usize,
+        param_kijun_period: usize,
+        param_senkou_b_period: usize,
+        param_displacement: usize,
+    ) -> Result<Self, KandError> {
+        Ok(Self {
+            param_displacement,
+            lookback: lookback(
+                param_tenkan_period,
+                param_kijun_period,
+                param_senkou_b_period,
+                param_displacement,
+            )?,
+            count: 0,
+            rolling_tenkan: Midprice::new(param_tenkan_period)?,
+            rolling_kijun: Midprice::new(param_kijun_period)?,
+            rolling_senkou_b: Midprice::new(param_senkou_b_period)?,
+            projection: VecDeque::with_capacity(param_displacement + 1),
+        })
+    }
+
+    /// Returns the cloud computed on the latest bar, i.e. `(senkou_a, senkou_b)` plotted
+    /// `param_displacement` bars ahead, or `None` before the first update.
+    #[must_use]
+    pub fn leading(&self) -> Option<(TAFloat, TAFloat)> {
+        self.projection.back().copied()
+    }
+}
+
+impl Indicator for Ichimoku {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = (TAFloat, TAFloat, TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<Self::Output>, KandError> {
+        #[cfg(feature = "deep-check")]
+        {
+            // NaN check
+            if input_close.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let (tenkan, kijun, senkou_a, senkou_b) = ichimoku_inc(
+            input_high,
+            input_low,
+            &mut self.rolling_tenkan,
+            &mut self.rolling_kijun,
+            &mut self.rolling_senkou_b,
+        )?;
+        self.count += 1;
+
+        // Keep the current bar's cloud at the front and the latest projection at the back
+        if self.projection.len() > self.param_displacement {
+            self.projection.pop_front();
+        }
+        self.projection.push_back((senkou_a, senkou_b));
+        let (cloud_a, cloud_b) = self.projection[0];
+
+        Ok(self
+            .is_ready()
+            .then_some((tenkan, kijun, cloud_a, cloud_b, input_close)))
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.rolling_tenkan.reset();
+        self.rolling_kijun.reset();
+        self.rolling_senkou_b.reset();
+        self.projection.clear();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::ohlcv::midprice;
+
+    #[test]
+    fn test_ichimoku_calculation() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let len = input_high.len();
+        let (tenkan_period, kijun_period, senkou_b_period, displacement) = (5, 9, 14, 6);
+        let mut output_tenkan = vec![0.0; len];
+        let mut output_kijun = vec![0.0; len];
+        let mut output_senkou_a = vec![0.0; len + displacement];
+        let mut output_senkou_b = vec![0.0; len + displacement];
+        let mut output_chikou = vec![0.0; len];
+
+        ichimoku(
+            &input_high,
+            &input_low,
+            &input_close,
+            tenkan_period,
+            kijun_period,
+            senkou_b_period,
+            displacement,
+            &mut output_tenkan,
+            &mut output_kijun,
+            &mut output_senkou_a,
+            &mut output_senkou_b,
+            &mut output_chikou,
+        )
+        .unwrap();
+
+        // Each line is a plain midprice of its window
+        let midprices = |period: usize| {
+            let mut output_midprice = vec![0.0; len];
+            let mut output_highest_high = vec![0.0; len];
+            let mut output_lowest_low = vec![0.0; len];
+            midprice::midprice(
+                &input_high,
+                &input_low,
+                period,
+                &mut output_midprice,
+                &mut output_highest_high,
+                &mut output_lowest_low,
+            )
+            .unwrap();
+            output_midprice
+        };
+        let tenkan = midprices(tenkan_period);
+        let kijun = midprices(kijun_period);
+        let senkou_b = midprices(senkou_b_period);
+
+        for i in 0..len {
+            if i + 1 < tenkan_period {
+                assert!(output_tenkan[i].is_nan());
+            } else {
+                assert_relative_eq!(output_tenkan[i], tenkan[i], epsilon = 1e-9);
+            }
+            if i + 1 < kijun_period {
+                assert!(output_kijun[i].is_nan());
+                assert!(output_senkou_a[i + displacement].is_nan());
+            } else {
+                assert_relative_eq!(output_kijun[i], kijun[i], epsilon = 1e-9);
+                assert_relative_eq!(
+                    output_senkou_a[i + displacement],
+                    (tenkan[i] + kijun[i]) / 2.0,
+                    epsilon = 1e-9
+                );
+            }
+            if i + 1 < senkou_b_period {
+                assert!(output_senkou_b[i + displacement].is_nan());
+            } else {
+                assert_relative_eq!(
+                    output_senkou_b[i + displacement],
+                    senkou_b[i],
+                    epsilon = 1e-9
+                );
+            }
+            if i + displacement < len {
+                assert_eq!(output_chikou[i], input_close[i + displacement]);
+            } else {
+                assert!(output_chikou[i].is_nan());
+            }
+        }
+        for i in 0..displacement {
+            assert!(output_senkou_a[i].is_nan());
+            assert!(output_senkou_b[i].is_nan());
+        }
+
+        // Spot checks against hand-computed windows
+        assert_relative_eq!(output_kijun[24], 35001.2, epsilon = 1e-9);
+        assert_relative_eq!(
+            output_senkou_b[len + displacement - 1],
+            35076.0,
+            epsilon = 1e-9
+        );
+
+        // Streaming calculation returns the cloud plotted at the current bar
+        let mut stream =
+            Ichimoku::new(tenkan_period, kijun_period, senkou_b_period, displacement).unwrap();
+        let lookback = stream.lookback();
+        assert_eq!(lookback, 19);
+        for i in 0..len {
+            let output = stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap();
+            if i < lookback {
+                assert!(output.is_none());
+                continue;
+            }
+            let (tenkan_value, kijun_value, senkou_a_value, senkou_b_value, chikou_value) =
+                output.unwrap();
+            assert_relative_eq!(tenkan_value, output_tenkan[i], epsilon = 1e-9);
+            assert_relative_eq!(kijun_value, output_kijun[i], epsilon = 1e-9);
+            assert_relative_eq!(senkou_a_value, output_senkou_a[i], epsilon = 1e-9);
+            assert_relative_eq!(senkou_b_value, output_senkou_b[i], epsilon = 1e-9);
+            assert_relative_eq!(
+                chikou_value,
+                output_chikou[i - displacement],
+                epsilon = 1e-9
+            );
+
+            let (leading_a, leading_b) = stream.leading().unwrap();
+            assert_relative_eq!(leading_a, output_senkou_a[i + displacement], epsilon = 1e-9);
+            assert_relative_eq!(leading_b, output_senkou_b[i + displacement], epsilon = 1e-9);
+        }
+
+        stream.reset();
+        assert!(!stream.is_ready());
+        assert_eq!(stream.leading(), None);
+    }
+
+    #[test]
+    fn test_ichimoku_invalid() {
+        let input = [1.0; 4];
+        let mut output = [0.0; 4];
+        let mut output_senkou_a = [0.0; 5];
+        let mut output_senkou_b = [0.0; 5];
+        let mut output_tenkan = output;
+        let mut output_kijun = output;
+
+        // The cloud needs `param_displacement` extra slots
+        assert!(matches!(
+            ichimoku(
+                &input,
+                &input,
+                &input,
+                2,
+                3,
+                4,
+                2,
+                &mut output_tenkan,
+                &mut output_kijun,
+                &mut output_senkou_a,
+                &mut output_senkou_b,
+                &mut output,
+            ),
+            Err(KandError::LengthMismatch)
+        ));
+        assert!(matches!(
+            ichimoku(
+                &input,
+                &input,
+                &input,
+                2,
+                3,
+                5,
+                1,
+                &mut output_tenkan,
+                &mut output_kijun,
+                &mut output_senkou_a,
+                &mut output_senkou_b,
+                &mut output,
+            ),
+            Err(KandError::InsufficientData)
+        ));
+        assert!(matches!(
+            Ichimoku::new(1, 26, 52, 26),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
@@ -0,0 +1,596 @@
+use crate::{Indicator, KandError, TAFloat, TAInt, types::PriceSource};
+
+/// A single vertical Kagi line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KagiLine {
+    /// Price where the line starts, the extreme of the previous line
+    pub start:       TAFloat,
+    /// Extreme price reached by the line so far
+    pub end:         TAFloat,
+    /// 1 for a rising line, -1 for a falling line, 0 before the first line forms
+    pub direction:   TAInt,
+    /// 1 for a thick (yang) line, -1 for a thin (yin) line, as of the end of the line
+    pub thickness:   TAInt,
+    /// Index of the bar where the line starts
+    pub start_index: usize,
+    /// Index of the bar that set the extreme of the line
+    pub end_index:   usize,
+}
+
+impl KagiLine {
+    /// Returns the state before the first line, anchored at `price`.
+    #[must_use]
+    pub const fn base(price: TAFloat, index: usize) -> Self {
+        Self {
+            start:       price,
+            end:         price,
+            direction:   0,
+            thickness:   0,
+            start_index: index,
+            end_index:   index,
+        }
+    }
+}
+
+/// Returns the lookback period required for Kagi construction.
+///
+/// # Description
+/// The first bar only sets the base price; a line can form from the second bar on.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Always 0
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::kagi;
+/// assert_eq!(kagi::lookback().unwrap(), 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Validates the reversal amount.
+fn check_params(param_reversal: TAFloat) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if !param_reversal.is_finite() || param_reversal <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    let _ = param_reversal;
+    Ok(())
+}
+
+/// Builds a Kagi chart from a price series.
+///
+/// # Description
+/// A Kagi line keeps extending while price moves in its direction and turns once price moves
+/// back by at least the reversal amount from the line's extreme. A rising line turns thick
+/// (yang) when it breaks above the previous high (shoulder) and a falling line turns thin (yin)
+/// when it breaks below the previous low (waist); otherwise lines keep the thickness of the
+/// line before. With [`PriceSource::HighLow`] an extension takes precedence over a reversal
+/// within the same bar.
+///
+/// # Mathematical Formula
+/// ```text
+/// rising line:  end = max(end, price_up)       reverse when price_down <= end - reversal
+/// falling line: end = min(end, price_down)     reverse when price_up >= end + reversal
+/// yang when a rising line ends above the start of the previous line (its shoulder)
+/// yin when a falling line ends below the start of the previous line (its waist)
+/// ```
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `param_reversal` - Price move against a line needed to turn it (must be > 0)
+/// * `param_source` - Whether moves are measured on closes or on highs and lows
+/// * `output_start` - Cleared, then filled with the start price of each line
+/// * `output_end` - Cleared, then filled with the end price of each line
+/// * `output_direction` - Cleared, then filled with the direction of each line (1 up, -1 down)
+/// * `output_thickness` - Cleared, then filled with the thickness of each line (1 yang, -1 yin)
+/// * `output_start_index` - Cleared, then filled with the bar index where each line starts
+/// * `output_end_index` - Cleared, then filled with the bar index where each line ends
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success; the last line is still forming
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If the reversal amount is not positive
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::kagi, types::PriceSource};
+///
+/// let input_close = vec![10.0, 12.0, 13.0, 11.5, 10.5, 11.0, 14.0];
+/// let mut output_start = Vec::new();
+/// let mut output_end = Vec::new();
+/// let mut output_direction = Vec::new();
+/// let mut output_thickness = Vec::new();
+/// let mut output_start_index = Vec::new();
+/// let mut output_end_index = Vec::new();
+///
+/// kagi::kagi(
+///     &input_close,
+///     &input_close,
+///     &input_close,
+///     2.0,
+///     PriceSource::Close,
+///     &mut output_start,
+///     &mut output_end,
+///     &mut output_direction,
+///     &mut output_thickness,
+///     &mut output_start_index,
+///     &mut output_end_index,
+/// )
+/// .unwrap();
+/// assert_eq!(output_end, vec![13.0, 10.5, 14.0]);
+/// assert_eq!(output_direction, vec![1, -1, 1]);
+/// // No line breaks below the 10.0 waist, so every line stays thick
+/// assert_eq!(output_thickness, vec![1, 1, 1]);
+/// ```
+pub fn kagi(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_reversal: TAFloat,
+    param_source: PriceSource,
+    output_start: &mut Vec<TAFloat>,
+    output_end: &mut Vec<TAFloat>,
+    output_direction: &mut Vec<TAInt>,
+    output_thickness: &mut Vec<TAInt>,
+    output_start_index: &mut Vec<usize>,
+    output_end_index: &mut Vec<usize>,
+) -> Result<(), KandError> {
+    let len = input_close.len();
+    check_params(param_reversal)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_high.len() != len || input_low.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut current = KagiLine::base(input_close[0], 0);
+    let mut prev_start = TAFloat::NAN;
+    for i in 1..len {
+        (current, prev_start) = kagi_inc(
+            input_high[i],
+            input_low[i],
+            input_close[i],
+            i,
+            current,
+            prev_start,
+            param_reversal,
+            param_source,
+            &mut lines,
+        )?;
+    }
+    if current.direction != 0 {
+        lines.push(current);
+    }
+
+    output_start.clear();
+    output_end.clear();
+    output_direction.clear();
+    output_thickness.clear();
+    output_start_index.clear();
+    output_end_index.clear();
+    for line in lines {
+        output_start.push(line.start);
+        output_end.push(line.end);
+        output_direction.push(line.direction);
+        output_thickness.push(line.thickness);
+        output_start_index.push(line.start_index);
+        output_end_index.push(line.end_index);
+    }
+
+    Ok(())
+}
+
+/// Advances a Kagi chart by one bar.
+///
+/// # Description
+/// Extends or turns the forming line as described in [`kagi`]. When the line turns, the
+/// finished line is appended to `output_lines` and a new line starts at its extreme.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `input_index` - Index of the current bar
+/// * `prev_line` - The forming line, or [`KagiLine::base`] before the first line
+/// * `prev_start` - Start price of the line before the forming one, NaN if there is none
+/// * `param_reversal` - Price move against a line needed to turn it (must be > 0)
+/// * `param_source` - Whether moves are measured on closes or on highs and lows
+/// * `output_lines` - Finished lines are appended here
+///
+/// # Returns
+/// * `Result<(KagiLine, TAFloat), KandError>` - The forming line and the start price of the
+///   line before it
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If the reversal amount is not positive
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{
+///     ohlcv::kagi::{self, KagiLine},
+///     types::PriceSource,
+/// };
+///
+/// let mut lines = Vec::new();
+/// let line = KagiLine::base(10.0, 0);
+/// let (line, prev_start) = kagi::kagi_inc(
+///     12.5,
+///     12.5,
+///     12.5,
+///     1,
+///     line,
+///     f64::NAN,
+///     2.0,
+///     PriceSource::Close,
+///     &mut lines,
+/// )
+/// .unwrap();
+/// assert_eq!((line.start, line.end, line.direction), (10.0, 12.5, 1));
+/// let (line, prev_start) = kagi::kagi_inc(
+///     10.0,
+///     10.0,
+///     10.0,
+///     2,
+///     line,
+///     prev_start,
+///     2.0,
+///     PriceSource::Close,
+///     &mut lines,
+/// )
+/// .unwrap();
+/// assert_eq!(lines.len(), 1);
+/// assert_eq!((line.start, line.end, line.direction), (12.5, 10.0, -1));
+/// assert_eq!(prev_start, 10.0);
+/// ```
+pub fn kagi_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    input_index: usize,
+    prev_line: KagiLine,
+    prev_start: TAFloat,
+    param_reversal: TAFloat,
+    param_source: PriceSource,
+    output_lines: &mut Vec<KagiLine>,
+) -> Result<(KagiLine, TAFloat), KandError> {
+    check_params(param_reversal)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_high.is_nan() || input_low.is_nan() || input_close.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (up_price, down_price) = param_source.prices(input_high, input_low, input_close);
+    let mut line = prev_line;
+
+    // Extend the forming line first
+    if line.direction > 0 && up_price > line.end || line.direction < 0 && down_price < line.end {
+        line.end = if line.direction > 0 {
+            up_price
+        } else {
+            down_price
+        };
+        line.end_index = input_index;
+        line.thickness = thickness(&line, prev_start, line.thickness);
+        return Ok((line, prev_start));
+    }
+
+    let direction = if up_price >= line.end + param_reversal && line.direction <= 0 {
+        1
+    } else if down_price <= line.end - param_reversal && line.direction >= 0 {
+        -1
+    } else {
+        return Ok((line, prev_start));
+    };
+
+    // The first line has no predecessor to finish
+    if line.direction == 0 {
+        let mut first = KagiLine {
+            start: line.start,
+            end: if direction > 0 { up_price } else { down_price },
+            direction,
+            thickness: direction,
+            start_index: line.start_index,
+            end_index: input_index,
+        };
+        first.thickness = thickness(&first, prev_start, direction);
+        return Ok((first, prev_start));
+    }
+
+    output_lines.push(line);
+    let mut next = KagiLine {
+        start: line.end,
+        end: if direction > 0 { up_price } else { down_price },
+        direction,
+        thickness: line.thickness,
+        start_index: line.end_index,
+        end_index: input_index,
+    };
+    next.thickness = thickness(&next, line.start, line.thickness);
+    Ok((next, line.start))
+}
+
+/// Thickness of `line` given the start of the line before it, which is the shoulder for a
+/// rising line and the waist for a falling one.
+fn thickness(line: &KagiLine, prev_start: TAFloat, current: TAInt) -> TAInt {
+    if line.direction > 0 && line.end > prev_start {
+        1
+    } else if line.direction < 0 && line.end < prev_start {
+        -1
+    } else {
+        current
+    }
+}
+
+/// Streaming Kagi chart builder.
+///
+/// Consumes `(high, low, close)` bars and emits each line once it turns, so a bar produces
+/// zero or one finished line. The forming line is available from [`Kagi::current`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::kagi::Kagi, types::PriceSource};
+///
+/// let mut kagi = Kagi::new(2.0, PriceSource::Close).unwrap();
+/// for price in [10.0, 12.0, 13.0, 11.5] {
+///     assert!(
+///         kagi.update((price, price, price))
+///             .unwrap()
+///             .unwrap()
+///             .is_empty()
+///     );
+/// }
+/// let lines = kagi.update((10.5, 10.5, 10.5)).unwrap().unwrap();
+/// assert_eq!((lines[0].start, lines[0].end), (10.0, 13.0));
+/// assert_eq!(kagi.current().unwrap().end, 10.5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Kagi {
+    param_reversal: TAFloat,
+    param_source:   PriceSource,
+    index:          usize,
+    line:           Option<KagiLine>,
+    prev_start:     TAFloat,
+}
+
+impl Kagi {
+    /// Creates a new streaming Kagi builder.
+    ///
+    /// # Arguments
+    /// * `param_reversal` - Price move against a line needed to turn it (must be > 0)
+    /// * `param_source` - Whether moves are measured on closes or on highs and lows
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the reversal amount is not positive
+    pub fn new(param_reversal: TAFloat, param_source: PriceSource) -> Result<Self, KandError> {
+        check_params(param_reversal)?;
+        Ok(Self {
+            param_reversal,
+            param_source,
+            index: 0,
+            line: None,
+            prev_start: TAFloat::NAN,
+        })
+    }
+
+    /// Returns the line that is still forming, `None` before the first line.
+    pub fn current(&self) -> Option<KagiLine> {
+        self.line.filter(|line| line.direction != 0)
+    }
+}
+
+impl Indicator for Kagi {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = Vec<KagiLine>;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<Vec<KagiLine>>, KandError> {
+        let input_index = self.index;
+        self.index += 1;
+
+        let Some(line) = self.line else {
+            self.line = Some(KagiLine::base(input_close, input_index));
+            return Ok(Some(Vec::new()));
+        };
+
+        let mut lines = Vec::new();
+        let (line, prev_start) = kagi_inc(
+            input_high,
+            input_low,
+            input_close,
+            input_index,
+            line,
+            self.prev_start,
+            self.param_reversal,
+            self.param_source,
+            &mut lines,
+        )?;
+        self.line = Some(line);
+        self.prev_start = prev_start;
+        Ok(Some(lines))
+    }
+
+    fn reset(&mut self) {
+        self.index = 0;
+        self.line = None;
+        self.prev_start = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.line.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_HIGH: [TAFloat; 25] = [
+        35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0, 35210.0,
+        35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5, 35078.8, 35085.0,
+        35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+    ];
+    const INPUT_LOW: [TAFloat; 25] = [
+        35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0, 35166.0,
+        35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0, 35012.3, 35022.2,
+        34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+    ];
+    const INPUT_CLOSE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    fn run(param_reversal: TAFloat, param_source: PriceSource) -> Vec<KagiLine> {
+        let mut output_start = Vec::new();
+        let mut output_end = Vec::new();
+        let mut output_direction = Vec::new();
+        let mut output_thickness = Vec::new();
+        let mut output_start_index = Vec::new();
+        let mut output_end_index = Vec::new();
+        kagi(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            param_reversal,
+            param_source,
+            &mut output_start,
+            &mut output_end,
+            &mut output_direction,
+            &mut output_thickness,
+            &mut output_start_index,
+            &mut output_end_index,
+        )
+        .unwrap();
+        (0..output_start.len())
+            .map(|i| KagiLine {
+                start:       output_start[i],
+                end:         output_end[i],
+                direction:   output_direction[i],
+                thickness:   output_thickness[i],
+                start_index: output_start_index[i],
+                end_index:   output_end_index[i],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_kagi_close() {
+        let lines = run(40.0, PriceSource::Close);
+
+        let ends: Vec<TAFloat> = lines.iter().map(|line| line.end).collect();
+        assert_eq!(
+            ends,
+            vec![
+                35170.0, 35254.6, 35202.8, 35251.9, 35175.1, 35229.9, 34999.3, 35069.0, 34939.5,
+                35080.0
+            ]
+        );
+        let directions: Vec<TAInt> = lines.iter().map(|line| line.direction).collect();
+        assert_eq!(directions, vec![-1, 1, -1, 1, -1, 1, -1, 1, -1, 1]);
+        // Thin until the first shoulder break, thin again below the 35202.8 waist, then thick
+        // once the last line clears the 35069.0 shoulder
+        let thickness: Vec<TAInt> = lines.iter().map(|line| line.thickness).collect();
+        assert_eq!(thickness, vec![-1, 1, 1, 1, -1, -1, -1, -1, -1, 1]);
+        let end_index: Vec<usize> = lines.iter().map(|line| line.end_index).collect();
+        assert_eq!(end_index, vec![3, 5, 6, 7, 10, 11, 16, 18, 20, 24]);
+    }
+
+    #[test]
+    fn test_kagi_lines_are_connected() {
+        for source in [PriceSource::Close, PriceSource::HighLow] {
+            let lines = run(30.0, source);
+            assert!(lines.len() > 2);
+            for pair in lines.windows(2) {
+                assert_eq!(pair[1].start, pair[0].end);
+                assert_eq!(pair[1].start_index, pair[0].end_index);
+                assert_eq!(pair[1].direction, -pair[0].direction);
+                assert!((pair[0].end - pair[0].start).abs() >= 30.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_kagi_stream() {
+        let expected = run(30.0, PriceSource::HighLow);
+        let mut stream = Kagi::new(30.0, PriceSource::HighLow).unwrap();
+        assert!(stream.current().is_none());
+        let mut lines = Vec::new();
+        for i in 0..INPUT_CLOSE.len() {
+            let finished = stream
+                .update((INPUT_HIGH[i], INPUT_LOW[i], INPUT_CLOSE[i]))
+                .unwrap()
+                .unwrap();
+            assert!(finished.len() <= 1);
+            lines.extend(finished);
+        }
+        lines.extend(stream.current());
+        assert_eq!(lines, expected);
+
+        stream.reset();
+        assert!(!stream.is_ready());
+    }
+
+    #[test]
+    fn test_kagi_invalid_params() {
+        assert!(Kagi::new(0.0, PriceSource::Close).is_err());
+        assert!(Kagi::new(TAFloat::NAN, PriceSource::Close).is_err());
+        let mut lines = Vec::new();
+        assert!(matches!(
+            kagi_inc(
+                1.0,
+                1.0,
+                1.0,
+                1,
+                KagiLine::base(1.0, 0),
+                TAFloat::NAN,
+                -1.0,
+                PriceSource::Close,
+                &mut lines
+            ),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
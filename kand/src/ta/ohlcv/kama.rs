@@ -0,0 +1,457 @@
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
+
+/// Smoothing constant of the fastest (2-period) EMA.
+const FAST_SC: TAFloat = 2.0 / 3.0;
+
+/// Smoothing constant of the slowest (30-period) EMA.
+const SLOW_SC: TAFloat = 2.0 / 31.0;
+
+/// Returns the lookback period required for KAMA calculation.
+///
+/// # Arguments
+/// * `param_period` - The period used for the efficiency ratio (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period, equal to `param_period`
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::kama;
+///
+/// let lookback = kama::lookback(30).unwrap();
+/// assert_eq!(lookback, 30);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period)
+}
+
+/// Converts the net and total price change over the period into the squared KAMA smoothing
+/// constant.
+fn smoothing_constant(period_roc: TAFloat, sum_roc: TAFloat) -> TAFloat {
+    let efficiency_ratio = if sum_roc <= period_roc || sum_roc.abs() < 1e-8 {
+        1.0
+    } else {
+        (period_roc / sum_roc).abs()
+    };
+    let sc = efficiency_ratio.mul_add(FAST_SC - SLOW_SC, SLOW_SC);
+    sc * sc
+}
+
+/// Calculates Kaufman Adaptive Moving Average (KAMA) for a price series.
+///
+/// KAMA adapts its speed to the market noise. The efficiency ratio compares the net price
+/// change over the period with the sum of all bar-to-bar changes: in a clean trend the
+/// average follows price like a 2-period EMA, in a choppy market it slows down to a
+/// 30-period EMA.
+///
+/// # Mathematical Formula
+/// ```text
+/// Change = Price[t] - Price[t-n]
+/// Volatility = Σ|Price[i] - Price[i-1]| over the last n bars
+/// ER = |Change / Volatility|  (1 if Volatility <= Change or Volatility ≈ 0)
+/// SC = (ER * (2/3 - 2/31) + 2/31)²
+/// KAMA[t] = KAMA[t-1] + SC * (Price[t] - KAMA[t-1])
+/// ```
+///
+/// # Calculation Principle
+/// 1. Sum the absolute bar-to-bar changes over the first `n` bars
+/// 2. Seed KAMA with the price before the first output bar
+/// 3. For each bar, roll the volatility sum, compute ER and SC and update KAMA
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices
+/// * `param_period` - The period used for the efficiency ratio (must be >= 2)
+/// * `output_kama` - Array to store the KAMA values
+/// * `output_sum_roc` - Array to store the rolling sum of absolute price changes
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output arrays length doesn't match input
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::InsufficientData` - If input length is less than or equal to lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::kama;
+///
+/// let input_prices = vec![10.0, 11.0, 12.0, 13.0, 14.0];
+/// let mut output_kama = vec![0.0; input_prices.len()];
+/// let mut output_sum_roc = vec![0.0; input_prices.len()];
+///
+/// kama::kama(&input_prices, 3, &mut output_kama, &mut output_sum_roc).unwrap();
+/// // A straight line is perfectly efficient, so KAMA moves 4/9 of the way towards the price
+/// assert!((output_kama[3] - (12.0 + 4.0 / 9.0)).abs() < 1e-12);
+/// ```
+pub fn kama(
+    input_prices: &[TAFloat],
+    param_period: usize,
+    output_kama: &mut [TAFloat],
+    output_sum_roc: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_kama.len() != len || output_sum_roc.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Initial volatility over the first period
+    let mut sum_roc = 0.0;
+    for i in 0..lookback {
+        sum_roc += (input_prices[i] - input_prices[i + 1]).abs();
+    }
+
+    let period_roc = input_prices[lookback] - input_prices[0];
+    let sc = smoothing_constant(period_roc, sum_roc);
+    let prev_kama = input_prices[lookback - 1];
+    output_kama[lookback] = (input_prices[lookback] - prev_kama).mul_add(sc, prev_kama);
+    output_sum_roc[lookback] = sum_roc;
+
+    for i in lookback + 1..len {
+        let (kama, sum_roc) = kama_inc(
+            input_prices[i],
+            input_prices[i - 1],
+            input_prices[i - param_period],
+            input_prices[i - param_period - 1],
+            output_kama[i - 1],
+            output_sum_roc[i - 1],
+            param_period,
+        )?;
+        output_kama[i] = kama;
+        output_sum_roc[i] = sum_roc;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_kama[i] = TAFloat::NAN;
+        output_sum_roc[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next KAMA value incrementally.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `prev_price` - The previous price
+/// * `input_old_price` - The price `param_period` bars ago
+/// * `prev_old_price` - The price `param_period + 1` bars ago, which leaves the window
+/// * `prev_kama` - The previous KAMA value
+/// * `prev_sum_roc` - The previous rolling sum of absolute price changes
+/// * `param_period` - The period used for the efficiency ratio (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple of (KAMA, new sum of absolute changes)
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is
+///   enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::kama;
+///
+/// let (output_kama, output_sum_roc) = kama::kama_inc(
+///     15.0, // current price
+///     14.0, // previous price
+///     12.0, // price 3 bars ago
+///     11.0, // price 4 bars ago
+///     13.0, // previous KAMA
+///     3.0,  // previous sum of absolute changes
+///     3,    // period
+/// )
+/// .unwrap();
+/// assert_eq!(output_sum_roc, 3.0);
+/// assert!((output_kama - (13.0 + 2.0 * 4.0 / 9.0)).abs() < 1e-12);
+/// ```
+pub fn kama_inc(
+    input_price: TAFloat,
+    prev_price: TAFloat,
+    input_old_price: TAFloat,
+    prev_old_price: TAFloat,
+    prev_kama: TAFloat,
+    prev_sum_roc: TAFloat,
+    param_period: usize,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan()
+            || prev_price.is_nan()
+            || input_old_price.is_nan()
+            || prev_old_price.is_nan()
+            || prev_kama.is_nan()
+            || prev_sum_roc.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let mut sum_roc = prev_sum_roc - (prev_old_price - input_old_price).abs();
+    sum_roc += (input_price - prev_price).abs();
+
+    let sc = smoothing_constant(input_price - input_old_price, sum_roc);
+    let output_kama = (input_price - prev_kama).mul_add(sc, prev_kama);
+    Ok((output_kama, sum_roc))
+}
+
+/// Streaming Kaufman Adaptive Moving Average (KAMA) calculator.
+///
+/// Keeps the last `param_period + 1` prices so both ends of the volatility window are
+/// available to [`kama_inc`]. The first value is seeded with [`kama`], so the stream matches
+/// the batch output.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::kama::Kama};
+///
+/// let mut kama = Kama::new(3).unwrap();
+/// for price in [10.0, 11.0, 12.0] {
+///     assert!(kama.update(price).unwrap().is_none());
+/// }
+/// assert!(kama.update(13.0).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Kama {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<TAFloat>,
+    prev_kama:    TAFloat,
+    prev_sum_roc: TAFloat,
+    ready:        bool,
+}
+
+impl Kama {
+    /// Creates a new streaming KAMA.
+    ///
+    /// # Arguments
+    /// * `param_period` - The period used for the efficiency ratio (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            lookback,
+            window: VecDeque::with_capacity(param_period + 1),
+            prev_kama: TAFloat::NAN,
+            prev_sum_roc: TAFloat::NAN,
+            ready: false,
+        })
+    }
+}
+
+impl Indicator for Kama {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if self.ready {
+            let (output_kama, output_sum_roc) = kama_inc(
+                input_price,
+                self.window[self.param_period],
+                self.window[1],
+                self.window[0],
+                self.prev_kama,
+                self.prev_sum_roc,
+                self.param_period,
+            )?;
+            self.window.pop_front();
+            self.window.push_back(input_price);
+            self.prev_kama = output_kama;
+            self.prev_sum_roc = output_sum_roc;
+            return Ok(Some(output_kama));
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let window = self.window.make_contiguous();
+        let mut output_kama = vec![0.0; window.len()];
+        let mut output_sum_roc = vec![0.0; window.len()];
+        kama(
+            window,
+            self.param_period,
+            &mut output_kama,
+            &mut output_sum_roc,
+        )?;
+        self.prev_kama = output_kama[self.lookback];
+        self.prev_sum_roc = output_sum_roc[self.lookback];
+        self.ready = true;
+        Ok(Some(self.prev_kama))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.prev_kama = TAFloat::NAN;
+        self.prev_sum_roc = TAFloat::NAN;
+        self.ready = false;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    // Basic functionality tests
+    #[test]
+    fn test_kama_calculation() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1,
+        ];
+        let param_period = 10;
+        let mut output_kama = vec![0.0; input_prices.len()];
+        let mut output_sum_roc = vec![0.0; input_prices.len()];
+
+        kama(
+            &input_prices,
+            param_period,
+            &mut output_kama,
+            &mut output_sum_roc,
+        )
+        .unwrap();
+
+        // First param_period values should be NaN
+        for i in 0..param_period {
+            assert!(output_kama[i].is_nan());
+            assert!(output_sum_roc[i].is_nan());
+        }
+
+        // Verify against known values
+        assert_relative_eq!(output_kama[10], 35184.5066749365, epsilon = 0.00001);
+        assert_relative_eq!(output_kama[11], 35184.785727776856, epsilon = 0.00001);
+        assert_relative_eq!(output_kama[12], 35185.07109210746, epsilon = 0.00001);
+        assert_relative_eq!(output_kama[34], 35083.4972195668, epsilon = 0.00001);
+
+        // Now test incremental calculation matches regular calculation
+        let mut prev_kama = output_kama[param_period];
+        let mut prev_sum_roc = output_sum_roc[param_period];
+        for i in param_period + 1..input_prices.len() {
+            let (result, sum_roc) = kama_inc(
+                input_prices[i],
+                input_prices[i - 1],
+                input_prices[i - param_period],
+                input_prices[i - param_period - 1],
+                prev_kama,
+                prev_sum_roc,
+                param_period,
+            )
+            .unwrap();
+            assert_relative_eq!(result, output_kama[i], epsilon = 0.00001);
+            assert_relative_eq!(sum_roc, output_sum_roc[i], epsilon = 0.00001);
+            prev_kama = result;
+            prev_sum_roc = sum_roc;
+        }
+    }
+
+    #[test]
+    fn test_kama_efficient_trend() {
+        // A straight line has ER = 1, so KAMA tracks price with SC = (2/3)²
+        let input_prices = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let mut output_kama = vec![0.0; input_prices.len()];
+        let mut output_sum_roc = vec![0.0; input_prices.len()];
+
+        kama(&input_prices, 2, &mut output_kama, &mut output_sum_roc).unwrap();
+
+        let sc = 4.0 / 9.0;
+        let mut expected = 2.0;
+        for i in 2..input_prices.len() {
+            expected += sc * (input_prices[i] - expected);
+            assert_relative_eq!(output_kama[i], expected, epsilon = 1e-12);
+            assert_relative_eq!(output_sum_roc[i], 2.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_kama_stream() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 10;
+        let mut output_kama = vec![0.0; input_prices.len()];
+        let mut output_sum_roc = vec![0.0; input_prices.len()];
+
+        kama(
+            &input_prices,
+            param_period,
+            &mut output_kama,
+            &mut output_sum_roc,
+        )
+        .unwrap();
+
+        let mut stream = Kama::new(param_period).unwrap();
+        for (i, price) in input_prices.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_kama[i], epsilon = 0.00001),
+                None => assert!(output_kama[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
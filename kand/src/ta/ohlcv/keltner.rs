@@ -0,0 +1,511 @@
+use super::{
+    atr::{self, Atr},
+    bandwidth::{bandwidth_value, percent_b_value},
+    ema,
+    ma::{self, Ma},
+};
+use crate::{Indicator, KandError, TAFloat, types::MAType};
+
+/// Returns the lookback period required for Keltner Channel calculation.
+///
+/// # Description
+/// The larger of the middle line moving average lookback and the ATR lookback.
+///
+/// # Arguments
+/// * `param_period` - The moving average period of the middle line (must be >= 2)
+/// * `param_atr_period` - The ATR period (must be >= 2)
+/// * `param_ma_type` - The moving average type of the middle line
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If a period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::keltner, types::MAType};
+/// assert_eq!(keltner::lookback(20, 10, MAType::EMA).unwrap(), 19);
+/// assert_eq!(keltner::lookback(10, 20, MAType::EMA).unwrap(), 20);
+/// ```
+pub fn lookback(
+    param_period: usize,
+    param_atr_period: usize,
+    param_ma_type: MAType,
+) -> Result<usize, KandError> {
+    Ok(ma::lookback(param_period, param_ma_type)?.max(atr::lookback(param_atr_period)?))
+}
+
+/// Calculates Keltner Channels.
+///
+/// # Description
+/// Keltner Channels place bands a multiple of the Average True Range around a moving average
+/// of the close. Unlike Bollinger Bands they widen with the trading range rather than with
+/// the dispersion of closes; Bollinger Bands contracting inside Keltner Channels is the
+/// classic squeeze setup. The middle line is usually an EMA.
+///
+/// # Mathematical Formula
+/// ```text
+/// Middle    = MA(Close, period, ma_type)
+/// Upper     = Middle + Multiplier * ATR(atr_period)
+/// Lower     = Middle - Multiplier * ATR(atr_period)
+/// %B        = (Close - Lower) / (Upper - Lower)
+/// Bandwidth = (Upper - Lower) / Middle
+/// ```
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `param_period` - The moving average period of the middle line (must be >= 2)
+/// * `param_atr_period` - The ATR period (must be >= 2)
+/// * `param_multiplier` - The ATR multiple for the bands
+/// * `param_ma_type` - The moving average type of the middle line
+/// * `output_upper` - Array storing the upper band
+/// * `output_middle` - Array storing the middle line
+/// * `output_lower` - Array storing the lower band
+/// * `output_atr` - Array storing the ATR values
+/// * `output_percent_b` - Array storing the %B values, see [`bandwidth`](super::bandwidth)
+/// * `output_bandwidth` - Array storing the bandwidth values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If a period is less than 2
+/// * `KandError::InsufficientData` - If input length <= lookback period
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::keltner, types::MAType};
+///
+/// let input_high = vec![10.0, 11.0, 12.0, 11.5, 12.5];
+/// let input_low = vec![9.0, 9.5, 10.5, 10.0, 11.0];
+/// let input_close = vec![9.5, 10.5, 11.5, 10.5, 12.0];
+/// let mut output_upper = vec![0.0; 5];
+/// let mut output_middle = vec![0.0; 5];
+/// let mut output_lower = vec![0.0; 5];
+/// let mut output_atr = vec![0.0; 5];
+/// let mut output_percent_b = vec![0.0; 5];
+/// let mut output_bandwidth = vec![0.0; 5];
+///
+/// keltner::keltner(
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     3,
+///     2,
+///     2.0,
+///     MAType::SMA,
+///     &mut output_upper,
+///     &mut output_middle,
+///     &mut output_lower,
+///     &mut output_atr,
+///     &mut output_percent_b,
+///     &mut output_bandwidth,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_middle[2], 10.5);
+/// assert_eq!(output_atr[2], 1.5);
+/// assert_eq!((output_upper[2], output_lower[2]), (13.5, 7.5));
+/// ```
+pub fn keltner(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_period: usize,
+    param_atr_period: usize,
+    param_multiplier: TAFloat,
+    param_ma_type: MAType,
+    output_upper: &mut [TAFloat],
+    output_middle: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+    output_atr: &mut [TAFloat],
+    output_percent_b: &mut [TAFloat],
+    output_bandwidth: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback(param_period, param_atr_period, param_ma_type)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if input_low.len() != len
+            || input_close.len() != len
+            || output_upper.len() != len
+            || output_middle.len() != len
+            || output_lower.len() != len
+            || output_atr.len() != len
+            || output_percent_b.len() != len
+            || output_bandwidth.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    ma::ma(input_close, param_period, param_ma_type, output_middle)?;
+    atr::atr(
+        input_high,
+        input_low,
+        input_close,
+        param_atr_period,
+        output_atr,
+    )?;
+
+    for i in lookback..len {
+        let offset = param_multiplier * output_atr[i];
+        output_upper[i] = output_middle[i] + offset;
+        output_lower[i] = output_middle[i] - offset;
+        output_percent_b[i] = percent_b_value(input_close[i], output_upper[i], output_lower[i]);
+        output_bandwidth[i] = bandwidth_value(output_upper[i], output_middle[i], output_lower[i]);
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_upper[i] = TAFloat::NAN;
+        output_middle[i] = TAFloat::NAN;
+        output_lower[i] = TAFloat::NAN;
+        output_atr[i] = TAFloat::NAN;
+        output_percent_b[i] = TAFloat::NAN;
+        output_bandwidth[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next Keltner Channel values incrementally.
+///
+/// # Description
+/// Advances the EMA middle line and the ATR by one bar. The incremental form is EMA-only,
+/// since a single previous value is not enough state for the other moving average types;
+/// use [`Keltner`] to stream channels around them.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `prev_close` - Previous close price
+/// * `prev_middle` - Previous EMA middle line value
+/// * `prev_atr` - Previous ATR value
+/// * `param_period` - The EMA period of the middle line (must be >= 2)
+/// * `param_atr_period` - The ATR period (must be >= 2)
+/// * `param_multiplier` - The ATR multiple for the bands
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple of
+///   (upper, middle, lower, ATR, %B, bandwidth)
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If a period is less than 2
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::keltner;
+///
+/// let (upper, middle, lower, atr, _, _) =
+///     keltner::keltner_inc(12.0, 10.0, 11.0, 10.0, 10.0, 1.0, 3, 2, 2.0).unwrap();
+/// assert_eq!((middle, atr), (10.5, 1.5));
+/// assert_eq!((upper, lower), (13.5, 7.5));
+/// ```
+pub fn keltner_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    prev_close: TAFloat,
+    prev_middle: TAFloat,
+    prev_atr: TAFloat,
+    param_period: usize,
+    param_atr_period: usize,
+    param_multiplier: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    let middle = ema::ema_inc(input_close, prev_middle, param_period, None)?;
+    let atr = atr::atr_inc(
+        input_high,
+        input_low,
+        prev_close,
+        prev_atr,
+        param_atr_period,
+    )?;
+    let offset = param_multiplier * atr;
+    let upper = middle + offset;
+    let lower = middle - offset;
+
+    Ok((
+        upper,
+        middle,
+        lower,
+        atr,
+        percent_b_value(input_close, upper, lower),
+        bandwidth_value(upper, middle, lower),
+    ))
+}
+
+/// Streaming Keltner Channel calculator.
+///
+/// Combines a streaming moving average of the selected type for the middle line with a
+/// streaming [`Atr`], and emits `(upper, middle, lower, %B, bandwidth)` once both are warmed
+/// up.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::keltner::Keltner, types::MAType};
+///
+/// let mut keltner = Keltner::new(3, 2, 2.0, MAType::SMA).unwrap();
+/// assert_eq!(keltner.update((10.0, 9.0, 9.5)).unwrap(), None);
+/// assert_eq!(keltner.update((11.0, 9.5, 10.5)).unwrap(), None);
+/// let (upper, middle, lower, _, _) = keltner.update((12.0, 10.5, 11.5)).unwrap().unwrap();
+/// assert_eq!((upper, middle, lower), (13.5, 10.5, 7.5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Keltner {
+    param_multiplier: TAFloat,
+    lookback:         usize,
+    ma:               Ma,
+    atr:              Atr,
+    ready:            bool,
+}
+
+impl Keltner {
+    /// Creates a new streaming Keltner Channel.
+    ///
+    /// # Arguments
+    /// * `param_period` - The moving average period of the middle line (must be >= 2)
+    /// * `param_atr_period` - The ATR period (must be >= 2)
+    /// * `param_multiplier` - The ATR multiple for the bands
+    /// * `param_ma_type` - The moving average type of the middle line
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If a period is less than 2
+    pub fn new(
+        param_period: usize,
+        param_atr_period: usize,
+        param_multiplier: TAFloat,
+        param_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        Ok(Self {
+            param_multiplier,
+            lookback: lookback(param_period, param_atr_period, param_ma_type)?,
+            ma: Ma::new(param_period, param_ma_type)?,
+            atr: Atr::new(param_atr_period)?,
+            ready: false,
+        })
+    }
+}
+
+impl Indicator for Keltner {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = (TAFloat, TAFloat, TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<Self::Output>, KandError> {
+        let output_middle = self.ma.update(input_close)?;
+        let output_atr = self.atr.update((input_high, input_low, input_close))?;
+        let (Some(output_middle), Some(output_atr)) = (output_middle, output_atr) else {
+            return Ok(None);
+        };
+
+        self.ready = true;
+        let offset = self.param_multiplier * output_atr;
+        let output_upper = output_middle + offset;
+        let output_lower = output_middle - offset;
+        Ok(Some((
+            output_upper,
+            output_middle,
+            output_lower,
+            percent_b_value(input_close, output_upper, output_lower),
+            bandwidth_value(output_upper, output_middle, output_lower),
+        )))
+    }
+
+    fn reset(&mut self) {
+        self.ma.reset();
+        self.atr.reset();
+        self.ready = false;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const HIGH: [TAFloat; 25] = [
+        35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0, 35210.0,
+        35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5, 35078.8, 35085.0,
+        35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+    ];
+    const LOW: [TAFloat; 25] = [
+        35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0, 35166.0,
+        35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0, 35012.3, 35022.2,
+        34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+    ];
+    const CLOSE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    #[test]
+    fn test_keltner_calculation() {
+        let len = CLOSE.len();
+        let (param_period, param_atr_period, param_multiplier) = (10, 5, 1.5);
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+        let mut output_atr = vec![0.0; len];
+        let mut output_percent_b = vec![0.0; len];
+        let mut output_bandwidth = vec![0.0; len];
+
+        keltner(
+            &HIGH,
+            &LOW,
+            &CLOSE,
+            param_period,
+            param_atr_period,
+            param_multiplier,
+            MAType::EMA,
+            &mut output_upper,
+            &mut output_middle,
+            &mut output_lower,
+            &mut output_atr,
+            &mut output_percent_b,
+            &mut output_bandwidth,
+        )
+        .unwrap();
+
+        let mut expected_ema = vec![0.0; len];
+        ema::ema(&CLOSE, param_period, None, &mut expected_ema).unwrap();
+        let mut expected_atr = vec![0.0; len];
+        atr::atr(&HIGH, &LOW, &CLOSE, param_atr_period, &mut expected_atr).unwrap();
+
+        for i in 0..9 {
+            assert!(output_upper[i].is_nan());
+            assert!(output_middle[i].is_nan());
+            assert!(output_bandwidth[i].is_nan());
+        }
+        for i in 9..len {
+            let offset = param_multiplier * expected_atr[i];
+            assert_relative_eq!(output_middle[i], expected_ema[i], epsilon = 1e-9);
+            assert_relative_eq!(output_upper[i], expected_ema[i] + offset, epsilon = 1e-9);
+            assert_relative_eq!(output_lower[i], expected_ema[i] - offset, epsilon = 1e-9);
+            assert_relative_eq!(
+                output_percent_b[i],
+                (CLOSE[i] - output_lower[i]) / (2.0 * offset),
+                epsilon = 1e-9
+            );
+            assert_relative_eq!(
+                output_bandwidth[i],
+                2.0 * offset / expected_ema[i],
+                epsilon = 1e-12
+            );
+        }
+
+        // Incremental calculation continues the EMA and ATR
+        for i in 10..len {
+            let (upper, middle, lower, atr, percent_b, bandwidth) = keltner_inc(
+                HIGH[i],
+                LOW[i],
+                CLOSE[i],
+                CLOSE[i - 1],
+                output_middle[i - 1],
+                output_atr[i - 1],
+                param_period,
+                param_atr_period,
+                param_multiplier,
+            )
+            .unwrap();
+            assert_relative_eq!(upper, output_upper[i], epsilon = 1e-9);
+            assert_relative_eq!(middle, output_middle[i], epsilon = 1e-9);
+            assert_relative_eq!(lower, output_lower[i], epsilon = 1e-9);
+            assert_relative_eq!(atr, output_atr[i], epsilon = 1e-9);
+            assert_relative_eq!(percent_b, output_percent_b[i], epsilon = 1e-9);
+            assert_relative_eq!(bandwidth, output_bandwidth[i], epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_keltner_stream() {
+        let len = CLOSE.len();
+        for (param_period, param_atr_period, param_ma_type) in
+            [(10, 5, MAType::EMA), (4, 12, MAType::WMA)]
+        {
+            let mut output_upper = vec![0.0; len];
+            let mut output_middle = vec![0.0; len];
+            let mut output_lower = vec![0.0; len];
+            let mut output_atr = vec![0.0; len];
+            let mut output_percent_b = vec![0.0; len];
+            let mut output_bandwidth = vec![0.0; len];
+            keltner(
+                &HIGH,
+                &LOW,
+                &CLOSE,
+                param_period,
+                param_atr_period,
+                2.0,
+                param_ma_type,
+                &mut output_upper,
+                &mut output_middle,
+                &mut output_lower,
+                &mut output_atr,
+                &mut output_percent_b,
+                &mut output_bandwidth,
+            )
+            .unwrap();
+
+            let mut stream =
+                Keltner::new(param_period, param_atr_period, 2.0, param_ma_type).unwrap();
+            for i in 0..len {
+                match stream.update((HIGH[i], LOW[i], CLOSE[i])).unwrap() {
+                    Some((upper, middle, lower, percent_b, bandwidth)) => {
+                        assert_relative_eq!(upper, output_upper[i], epsilon = 1e-9);
+                        assert_relative_eq!(middle, output_middle[i], epsilon = 1e-9);
+                        assert_relative_eq!(lower, output_lower[i], epsilon = 1e-9);
+                        assert_relative_eq!(percent_b, output_percent_b[i], epsilon = 1e-9);
+                        assert_relative_eq!(bandwidth, output_bandwidth[i], epsilon = 1e-12);
+                    }
+                    None => assert!(output_upper[i].is_nan()),
+                }
+            }
+            assert_eq!(
+                stream.lookback(),
+                param_period.max(param_atr_period + 1) - 1
+            );
+        }
+    }
+}
@@ -0,0 +1,440 @@
+use crate::{Indicator, KandError, TAFloat, TAInt};
+
+/// A single line (block) of a line break chart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineBreakLine {
+    /// Price where the line starts
+    pub open:      TAFloat,
+    /// Close that completed the line
+    pub close:     TAFloat,
+    /// 1 for a rising line, -1 for a falling line
+    pub direction: TAInt,
+    /// Index of the source bar that completed the line
+    pub index:     usize,
+}
+
+/// Returns the lookback period required for a line break chart.
+///
+/// # Description
+/// The first close only sets the base price; a line can form from the second bar on.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Always 0
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::line_break;
+/// assert_eq!(line_break::lookback().unwrap(), 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Validates the number of lines a reversal has to break.
+fn check_params(param_lines: usize) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_lines == 0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    let _ = param_lines;
+    Ok(())
+}
+
+/// Builds an N-line break chart from closing prices.
+///
+/// # Description
+/// A new line is drawn whenever the close exceeds the last line in its direction. A line in
+/// the opposite direction needs the close to break the whole range of the last `param_lines`
+/// lines (three in the classic three line break); the reversal line starts at the open of the
+/// last line. Closes that do neither are ignored.
+///
+/// # Mathematical Formula
+/// ```text
+/// rising trend:  close > last.close                  => up line   [last.close, close]
+///                close < min(open, close) of last N   => down line [last.open, close]
+/// falling trend: close < last.close                  => down line [last.close, close]
+///                close > max(open, close) of last N   => up line   [last.open, close]
+/// ```
+///
+/// # Arguments
+/// * `input_close` - Array of close prices
+/// * `param_lines` - Number of lines a reversal has to break (must be >= 1, typically 3)
+/// * `output_open` - Cleared, then filled with the open of each line
+/// * `output_close` - Cleared, then filled with the close of each line
+/// * `output_direction` - Cleared, then filled with the direction of each line (1 up, -1 down)
+/// * `output_index` - Cleared, then filled with the source bar index of each line
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If the input array is empty
+/// * `KandError::InvalidParameter` - If `param_lines` is 0
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::line_break;
+///
+/// let input_close = vec![10.0, 11.0, 12.0, 13.0, 11.5, 9.5, 9.0];
+/// let mut output_open = Vec::new();
+/// let mut output_close = Vec::new();
+/// let mut output_direction = Vec::new();
+/// let mut output_index = Vec::new();
+///
+/// line_break::line_break(
+///     &input_close,
+///     3,
+///     &mut output_open,
+///     &mut output_close,
+///     &mut output_direction,
+///     &mut output_index,
+/// )
+/// .unwrap();
+/// // 11.5 stays inside the last three lines, 9.5 breaks below all of them
+/// assert_eq!(output_open, vec![10.0, 11.0, 12.0, 12.0, 9.5]);
+/// assert_eq!(output_close, vec![11.0, 12.0, 13.0, 9.5, 9.0]);
+/// assert_eq!(output_direction, vec![1, 1, 1, -1, -1]);
+/// assert_eq!(output_index, vec![1, 2, 3, 5, 6]);
+/// ```
+pub fn line_break(
+    input_close: &[TAFloat],
+    param_lines: usize,
+    output_open: &mut Vec<TAFloat>,
+    output_close: &mut Vec<TAFloat>,
+    output_direction: &mut Vec<TAInt>,
+    output_index: &mut Vec<usize>,
+) -> Result<(), KandError> {
+    check_params(param_lines)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if input_close.is_empty() {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for value in input_close {
+            // NaN check
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut lines: Vec<LineBreakLine> = Vec::new();
+    for (i, &close) in input_close.iter().enumerate().skip(1) {
+        if let Some(line) = line_break_inc(close, i, input_close[0], &lines, param_lines)? {
+            lines.push(line);
+        }
+    }
+
+    output_open.clear();
+    output_close.clear();
+    output_direction.clear();
+    output_index.clear();
+    for line in lines {
+        output_open.push(line.open);
+        output_close.push(line.close);
+        output_direction.push(line.direction);
+        output_index.push(line.index);
+    }
+
+    Ok(())
+}
+
+/// Advances a line break chart by one close.
+///
+/// # Description
+/// Applies the rules described in [`line_break`] to a single close.
+///
+/// # Arguments
+/// * `input_close` - Current close price
+/// * `input_index` - Index of the current bar
+/// * `param_base` - First close of the series, used until the first line forms
+/// * `prev_lines` - Lines drawn so far, oldest first; only the last `param_lines` are used
+/// * `param_lines` - Number of lines a reversal has to break (must be >= 1, typically 3)
+///
+/// # Returns
+/// * `Result<Option<LineBreakLine>, KandError>` - The new line, or `None` if the close draws
+///   no line
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_lines` is 0
+/// * `KandError::NaNDetected` - If the input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::line_break;
+///
+/// let first = line_break::line_break_inc(11.0, 1, 10.0, &[], 3)
+///     .unwrap()
+///     .unwrap();
+/// assert_eq!((first.open, first.close, first.direction), (10.0, 11.0, 1));
+/// // A pullback inside the only line draws nothing
+/// assert!(
+///     line_break::line_break_inc(10.5, 2, 10.0, &[first], 3)
+///         .unwrap()
+///         .is_none()
+/// );
+/// let reversal = line_break::line_break_inc(9.0, 3, 10.0, &[first], 3)
+///     .unwrap()
+///     .unwrap();
+/// assert_eq!(
+///     (reversal.open, reversal.close, reversal.direction),
+///     (10.0, 9.0, -1)
+/// );
+/// ```
+pub fn line_break_inc(
+    input_close: TAFloat,
+    input_index: usize,
+    param_base: TAFloat,
+    prev_lines: &[LineBreakLine],
+    param_lines: usize,
+) -> Result<Option<LineBreakLine>, KandError> {
+    check_params(param_lines)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_close.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let new_line = |open: TAFloat, direction: TAInt| {
+        Some(LineBreakLine {
+            open,
+            close: input_close,
+            direction,
+            index: input_index,
+        })
+    };
+
+    let Some(last) = prev_lines.last() else {
+        return Ok(if input_close > param_base {
+            new_line(param_base, 1)
+        } else if input_close < param_base {
+            new_line(param_base, -1)
+        } else {
+            None
+        });
+    };
+
+    let recent = &prev_lines[prev_lines.len().saturating_sub(param_lines)..];
+    let (low, high) = recent.iter().fold(
+        (TAFloat::INFINITY, TAFloat::NEG_INFINITY),
+        |(low, high), line| {
+            (
+                low.min(line.open.min(line.close)),
+                high.max(line.open.max(line.close)),
+            )
+        },
+    );
+
+    Ok(if last.direction > 0 {
+        if input_close > last.close {
+            new_line(last.close, 1)
+        } else if input_close < low {
+            new_line(last.open, -1)
+        } else {
+            None
+        }
+    } else if input_close < last.close {
+        new_line(last.close, -1)
+    } else if input_close > high {
+        new_line(last.open, 1)
+    } else {
+        None
+    })
+}
+
+/// Streaming N-line break chart builder.
+///
+/// Consumes closes and emits the line each close draws, so a bar produces zero or one line.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::line_break::LineBreak};
+///
+/// let mut chart = LineBreak::new(3).unwrap();
+/// assert!(chart.update(10.0).unwrap().unwrap().is_empty());
+/// let lines = chart.update(11.0).unwrap().unwrap();
+/// assert_eq!((lines[0].open, lines[0].close), (10.0, 11.0));
+/// assert!(chart.update(10.5).unwrap().unwrap().is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineBreak {
+    param_lines: usize,
+    index:       usize,
+    base:        Option<TAFloat>,
+    lines:       Vec<LineBreakLine>,
+}
+
+impl LineBreak {
+    /// Creates a new streaming line break builder.
+    ///
+    /// # Arguments
+    /// * `param_lines` - Number of lines a reversal has to break (must be >= 1, typically 3)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_lines` is 0
+    pub fn new(param_lines: usize) -> Result<Self, KandError> {
+        check_params(param_lines)?;
+        Ok(Self {
+            param_lines,
+            index: 0,
+            base: None,
+            lines: Vec::with_capacity(param_lines + 1),
+        })
+    }
+}
+
+impl Indicator for LineBreak {
+    type Input = TAFloat;
+    type Output = Vec<LineBreakLine>;
+
+    fn update(&mut self, input_close: TAFloat) -> Result<Option<Vec<LineBreakLine>>, KandError> {
+        let input_index = self.index;
+        self.index += 1;
+
+        let Some(base) = self.base else {
+            self.base = Some(input_close);
+            return Ok(Some(Vec::new()));
+        };
+
+        let Some(line) = line_break_inc(
+            input_close,
+            input_index,
+            base,
+            &self.lines,
+            self.param_lines,
+        )?
+        else {
+            return Ok(Some(Vec::new()));
+        };
+        self.lines.push(line);
+        if self.lines.len() > self.param_lines {
+            self.lines.remove(0);
+        }
+        Ok(Some(vec![line]))
+    }
+
+    fn reset(&mut self) {
+        self.index = 0;
+        self.base = None;
+        self.lines.clear();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.base.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_CLOSE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    fn run(param_lines: usize) -> Vec<LineBreakLine> {
+        let mut output_open = Vec::new();
+        let mut output_close = Vec::new();
+        let mut output_direction = Vec::new();
+        let mut output_index = Vec::new();
+        line_break(
+            &INPUT_CLOSE,
+            param_lines,
+            &mut output_open,
+            &mut output_close,
+            &mut output_direction,
+            &mut output_index,
+        )
+        .unwrap();
+        (0..output_open.len())
+            .map(|i| LineBreakLine {
+                open:      output_open[i],
+                close:     output_close[i],
+                direction: output_direction[i],
+                index:     output_index[i],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_line_break_three() {
+        let lines = run(3);
+
+        let opens: Vec<TAFloat> = lines.iter().map(|line| line.open).collect();
+        assert_eq!(
+            opens,
+            vec![
+                35216.1, 35216.1, 35190.7, 35190.7, 35190.7, 35160.7, 35090.3, 35041.2, 34999.3
+            ]
+        );
+        let closes: Vec<TAFloat> = lines.iter().map(|line| line.close).collect();
+        assert_eq!(
+            closes,
+            vec![
+                35221.4, 35190.7, 35170.0, 35254.6, 35160.7, 35090.3, 35041.2, 34999.3, 34939.5
+            ]
+        );
+        let directions: Vec<TAInt> = lines.iter().map(|line| line.direction).collect();
+        assert_eq!(directions, vec![1, -1, -1, 1, -1, -1, -1, -1, -1]);
+        // The late rally to 35080.0 stays below the top of the last three lines
+        let index: Vec<usize> = lines.iter().map(|line| line.index).collect();
+        assert_eq!(index, vec![1, 2, 3, 5, 13, 14, 15, 16, 20]);
+    }
+
+    #[test]
+    fn test_line_break_fewer_lines_reverse_sooner() {
+        let one = run(1);
+        let three = run(3);
+        assert!(one.len() >= three.len());
+        for pair in one.windows(2) {
+            // With a single line to break, every line starts at an end of the previous one
+            assert!(pair[1].open == pair[0].open || pair[1].open == pair[0].close);
+        }
+    }
+
+    #[test]
+    fn test_line_break_stream() {
+        let expected = run(3);
+        let mut stream = LineBreak::new(3).unwrap();
+        let mut lines = Vec::new();
+        for close in INPUT_CLOSE {
+            let drawn = stream.update(close).unwrap().unwrap();
+            assert!(drawn.len() <= 1);
+            lines.extend(drawn);
+        }
+        assert_eq!(lines, expected);
+
+        stream.reset();
+        assert!(!stream.is_ready());
+    }
+
+    #[test]
+    fn test_line_break_invalid_params() {
+        assert!(LineBreak::new(0).is_err());
+        assert!(matches!(
+            line_break_inc(1.0, 1, 1.0, &[], 0),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
@@ -0,0 +1,297 @@
+use super::linearreg_core::{self, LinearregFit, LinearregState};
+use crate::{Indicator, KandError, TAFloat};
+
+/// Returns the lookback period required for Linear Regression (LINEARREG) calculation.
+///
+/// # Description
+/// The regression needs a full window of `period` bars, so the lookback is `period - 1`.
+///
+/// # Arguments
+/// * `param_period` - The number of bars in the regression window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::linearreg;
+/// let lookback = linearreg::lookback(14).unwrap();
+/// assert_eq!(lookback, 13);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    linearreg_core::lookback(param_period)
+}
+
+/// Calculates Linear Regression (LINEARREG) for a price series.
+///
+/// # Description
+/// Linear Regression fits a least-squares line through the last `period` prices and returns its
+/// value at the current bar. It follows the price like a moving average, but with less lag
+/// because the fitted line is projected to the end of the window.
+///
+/// # Mathematical Formula
+/// ```text
+/// x = 0 for the oldest bar of the window, period - 1 for the current bar
+/// Slope = (period * SumXY - SumX * SumY) / (period * SumX2 - SumX^2)
+/// Intercept = (SumY - Slope * SumX) / period
+/// LINEARREG = Intercept + Slope * (period - 1)
+/// ```
+///
+/// # Calculation Steps
+/// 1. Keep the running sums `SumY` and `SumXY` of the window, updated in O(1) per bar
+/// 2. Fit the regression line from the sums
+/// 3. Return the value of the least-squares regression line at the current bar
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `param_period` - The number of bars in the regression window (must be >= 2)
+/// * `output_linearreg` - Array to store the LINEARREG values
+/// * `output_sum_y` - Array to store the running sum of prices
+/// * `output_sum_xy` - Array to store the running sum of `x * price`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok value on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output arrays don't match input length
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::InsufficientData` - If input length is less than `param_period`
+/// * `KandError::NaNDetected` - If any input value is NaN (with `deep-check` feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::linearreg;
+///
+/// let input_prices = vec![1.0, 3.0, 5.0, 7.0];
+/// let mut output_linearreg = vec![0.0; 4];
+/// let mut output_sum_y = vec![0.0; 4];
+/// let mut output_sum_xy = vec![0.0; 4];
+///
+/// linearreg::linearreg(
+///     &input_prices,
+///     3,
+///     &mut output_linearreg,
+///     &mut output_sum_y,
+///     &mut output_sum_xy,
+/// )
+/// .unwrap();
+/// assert!(output_linearreg[1].is_nan());
+/// assert_eq!(output_linearreg[3], 7.0);
+/// ```
+pub fn linearreg(
+    input_prices: &[TAFloat],
+    param_period: usize,
+    output_linearreg: &mut [TAFloat],
+    output_sum_y: &mut [TAFloat],
+    output_sum_xy: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Length consistency check
+        if output_linearreg.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    linearreg_core::linearreg_sums(input_prices, param_period, output_sum_y, output_sum_xy)?;
+
+    for i in lookback..len {
+        let fit = LinearregFit::from_sums(output_sum_y[i], output_sum_xy[i], param_period);
+        output_linearreg[i] = fit.linearreg();
+    }
+
+    // Fill initial values with NAN
+    for value in output_linearreg.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next Linear Regression (LINEARREG) value incrementally.
+///
+/// # Description
+/// Slides the running regression sums forward by one bar in O(1) and refits the line.
+///
+/// # Arguments
+/// * `input_price` - The newest price entering the window
+/// * `input_old_price` - The oldest price leaving the window
+/// * `prev_sum_y` - The previous running sum of prices
+/// * `prev_sum_xy` - The previous running sum of `x * price`
+/// * `param_period` - The number of bars in the regression window (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (linearreg, `sum_y`, `sum_xy`)
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::NaNDetected` - If any input value is NaN (with `deep-check` feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::linearreg;
+///
+/// // Window [1, 3, 5] (sum_y = 9, sum_xy = 13) slides to [3, 5, 7]
+/// let (linearreg, sum_y, sum_xy) = linearreg::linearreg_inc(7.0, 1.0, 9.0, 13.0, 3).unwrap();
+/// assert_eq!(sum_y, 15.0);
+/// assert_eq!(sum_xy, 19.0);
+/// ```
+pub fn linearreg_inc(
+    input_price: TAFloat,
+    input_old_price: TAFloat,
+    prev_sum_y: TAFloat,
+    prev_sum_xy: TAFloat,
+    param_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    let (sum_y, sum_xy) = linearreg_core::linearreg_sums_inc(
+        input_price,
+        input_old_price,
+        prev_sum_y,
+        prev_sum_xy,
+        param_period,
+    )?;
+    let fit = LinearregFit::from_sums(sum_y, sum_xy, param_period);
+    Ok((fit.linearreg(), sum_y, sum_xy))
+}
+
+/// Streaming Linear Regression (LINEARREG) calculator.
+///
+/// Wraps a [`LinearregState`], which keeps the window and running sums, and emits
+/// the value of the least-squares regression line at the current bar.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::linearreg::Linearreg};
+///
+/// let mut linearreg = Linearreg::new(3).unwrap();
+/// assert!(linearreg.update(1.0).unwrap().is_none());
+/// assert!(linearreg.update(3.0).unwrap().is_none());
+/// assert!(linearreg.update(5.0).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Linearreg {
+    state: LinearregState,
+}
+
+impl Linearreg {
+    /// Creates a new streaming LINEARREG.
+    ///
+    /// # Arguments
+    /// * `param_period` - The number of bars in the regression window (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            state: LinearregState::new(param_period)?,
+        })
+    }
+}
+
+impl Indicator for Linearreg {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        Ok(self.state.update(input_price)?.map(|fit| fit.linearreg()))
+    }
+
+    fn reset(&mut self) {
+        self.state.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.state.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.state.lookback()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_linearreg_calculation() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 14;
+        let len = input_prices.len();
+        let mut output_linearreg = vec![0.0; len];
+        let mut output_sum_y = vec![0.0; len];
+        let mut output_sum_xy = vec![0.0; len];
+
+        linearreg(
+            &input_prices,
+            param_period,
+            &mut output_linearreg,
+            &mut output_sum_y,
+            &mut output_sum_xy,
+        )
+        .unwrap();
+
+        // First 13 values should be NaN
+        for value in output_linearreg.iter().take(13) {
+            assert!(value.is_nan());
+        }
+
+        let expected_values = [
+            35_195.885_714_285_716,
+            35_168.562_857_142_86,
+            35_132.965_714_285_71,
+            35_087.357_142_857_145,
+            35_047.885_714_285_716,
+            35_028.828_571_428_574,
+            35_012.011_428_571_43,
+            34_972.482_857_142_85,
+            34_950.859_999_999_99,
+            34_942.219_999_999_994,
+            34_948.131_428_571_43,
+            34_966.577_142_857_14,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_linearreg[i + 13], *expected, epsilon = 0.0001);
+        }
+
+        // Incremental calculation matches the batch output
+        let mut prev_sum_y = output_sum_y[13];
+        let mut prev_sum_xy = output_sum_xy[13];
+        for i in 14..len {
+            let (linearreg, sum_y, sum_xy) = linearreg_inc(
+                input_prices[i],
+                input_prices[i - param_period],
+                prev_sum_y,
+                prev_sum_xy,
+                param_period,
+            )
+            .unwrap();
+            assert_relative_eq!(linearreg, output_linearreg[i], epsilon = 0.0001);
+            prev_sum_y = sum_y;
+            prev_sum_xy = sum_xy;
+        }
+
+        // Streaming calculation matches the batch output
+        let mut stream = Linearreg::new(param_period).unwrap();
+        for (i, price) in input_prices.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_linearreg[i], epsilon = 0.0001),
+                None => assert!(output_linearreg[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
@@ -0,0 +1,567 @@
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
+
+/// Returns the lookback period shared by the linear regression indicators.
+///
+/// # Arguments
+/// * `param_period` - The number of bars in the regression window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::linearreg_core;
+/// assert_eq!(linearreg_core::lookback(14).unwrap(), 13);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period - 1)
+}
+
+/// Least-squares line fitted over one regression window.
+///
+/// Bars are indexed with `x = 0` for the oldest bar of the window and `x = period - 1` for the
+/// current bar, so `intercept` is the fitted value at the oldest bar and `slope` is the change
+/// per bar going forward in time.
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::linearreg_core::LinearregFit;
+///
+/// // Prices 1, 3, 5 lie on a line
+/// let fit = LinearregFit::from_sums(9.0, 13.0, 3);
+/// assert_eq!(fit.slope, 2.0);
+/// assert_eq!(fit.intercept, 1.0);
+/// assert_eq!(fit.linearreg(), 5.0);
+/// assert_eq!(fit.tsf(), 7.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearregFit {
+    pub param_period: usize,
+    pub slope:        TAFloat,
+    pub intercept:    TAFloat,
+}
+
+impl LinearregFit {
+    /// Fits the regression line from the running sums of a window.
+    ///
+    /// # Arguments
+    /// * `sum_y` - Sum of the prices in the window
+    /// * `sum_xy` - Sum of `x * price` in the window, with `x = 0` for the oldest bar
+    /// * `param_period` - The number of bars in the window
+    #[must_use]
+    pub fn from_sums(sum_y: TAFloat, sum_xy: TAFloat, param_period: usize) -> Self {
+        let n = param_period as TAFloat;
+        let sum_x = n * (n - 1.0) / 2.0;
+        let sum_x_sq = n * (n - 1.0) * (2.0 * n - 1.0) / 6.0;
+        let divisor = n.mul_add(sum_x_sq, -(sum_x * sum_x));
+
+        let slope = n.mul_add(sum_xy, -(sum_x * sum_y)) / divisor;
+        let intercept = slope.mul_add(-sum_x, sum_y) / n;
+        Self {
+            param_period,
+            slope,
+            intercept,
+        }
+    }
+
+    /// Returns the fitted value at the current bar (`LINEARREG`).
+    #[must_use]
+    pub fn linearreg(&self) -> TAFloat {
+        self.slope
+            .mul_add((self.param_period - 1) as TAFloat, self.intercept)
+    }
+
+    /// Returns the angle of the regression line in degrees (`LINEARREG_ANGLE`).
+    #[must_use]
+    pub fn angle(&self) -> TAFloat {
+        self.slope.atan().to_degrees()
+    }
+
+    /// Returns the fitted value one bar ahead of the current bar (`TSF`).
+    #[must_use]
+    pub fn tsf(&self) -> TAFloat {
+        self.slope
+            .mul_add(self.param_period as TAFloat, self.intercept)
+    }
+}
+
+/// Calculates the rolling regression sums for a price series.
+///
+/// # Description
+/// The regression line of every window follows from two running sums, which this function
+/// keeps up to date in O(1) per bar:
+///
+/// ```text
+/// SumY  = sum(price[t])
+/// SumXY = sum(x * price[t]), x = 0 for the oldest bar of the window
+///
+/// When the window slides by one bar:
+/// SumXY' = SumXY - (SumY - OldPrice) + (period - 1) * NewPrice
+/// SumY'  = SumY - OldPrice + NewPrice
+/// ```
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `param_period` - The number of bars in the regression window (must be >= 2)
+/// * `output_sum_y` - Array to store the running sum of prices
+/// * `output_sum_xy` - Array to store the running sum of `x * price`
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok value on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output arrays don't match input length
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::InsufficientData` - If input length is less than `param_period`
+/// * `KandError::NaNDetected` - If any input value is NaN (with `deep-check` feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::linearreg_core;
+///
+/// let input_prices = vec![1.0, 3.0, 5.0, 4.0];
+/// let mut output_sum_y = vec![0.0; 4];
+/// let mut output_sum_xy = vec![0.0; 4];
+///
+/// linearreg_core::linearreg_sums(&input_prices, 3, &mut output_sum_y, &mut output_sum_xy)
+///     .unwrap();
+/// assert_eq!(output_sum_y[2], 9.0);
+/// assert_eq!(output_sum_xy[2], 13.0);
+/// assert_eq!(output_sum_y[3], 12.0);
+/// assert_eq!(output_sum_xy[3], 13.0); // 0 * 3 + 1 * 5 + 2 * 4
+/// ```
+pub fn linearreg_sums(
+    input_prices: &[TAFloat],
+    param_period: usize,
+    output_sum_y: &mut [TAFloat],
+    output_sum_xy: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_sum_y.len() != len || output_sum_xy.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Sums of the first window
+    let mut sum_y = 0.0;
+    let mut sum_xy = 0.0;
+    for (x, price) in input_prices[..param_period].iter().enumerate() {
+        sum_y += price;
+        sum_xy = (x as TAFloat).mul_add(*price, sum_xy);
+    }
+    output_sum_y[lookback] = sum_y;
+    output_sum_xy[lookback] = sum_xy;
+
+    // Slide the window one bar at a time
+    for i in param_period..len {
+        (sum_y, sum_xy) = linearreg_sums_inc(
+            input_prices[i],
+            input_prices[i - param_period],
+            sum_y,
+            sum_xy,
+            param_period,
+        )?;
+        output_sum_y[i] = sum_y;
+        output_sum_xy[i] = sum_xy;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_sum_y[i] = TAFloat::NAN;
+        output_sum_xy[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Slides the regression sums forward by one bar.
+///
+/// # Arguments
+/// * `input_price` - The newest price entering the window
+/// * `input_old_price` - The oldest price leaving the window
+/// * `prev_sum_y` - The previous running sum of prices
+/// * `prev_sum_xy` - The previous running sum of `x * price`
+/// * `param_period` - The number of bars in the regression window (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - The new `(sum_y, sum_xy)`
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::NaNDetected` - If any input value is NaN (with `deep-check` feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::linearreg_core;
+///
+/// // Window [1, 3, 5] slides to [3, 5, 4]
+/// let (sum_y, sum_xy) = linearreg_core::linearreg_sums_inc(4.0, 1.0, 9.0, 13.0, 3).unwrap();
+/// assert_eq!(sum_y, 12.0);
+/// assert_eq!(sum_xy, 13.0);
+/// ```
+pub fn linearreg_sums_inc(
+    input_price: TAFloat,
+    input_old_price: TAFloat,
+    prev_sum_y: TAFloat,
+    prev_sum_xy: TAFloat,
+    param_period: usize,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        if input_price.is_nan()
+            || input_old_price.is_nan()
+            || prev_sum_y.is_nan()
+            || prev_sum_xy.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    // Every remaining bar moves one step closer to x = 0
+    let sum_xy = ((param_period - 1) as TAFloat)
+        .mul_add(input_price, prev_sum_xy - (prev_sum_y - input_old_price));
+    let sum_y = prev_sum_y - input_old_price + input_price;
+    Ok((sum_y, sum_xy))
+}
+
+/// Calculates all five linear regression outputs in a single pass.
+///
+/// # Description
+/// Equivalent to calling `linearreg`, `linearreg_slope`, `linearreg_intercept`,
+/// `linearreg_angle` and `tsf` with the same period, but the running sums are only updated
+/// once per bar.
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `param_period` - The number of bars in the regression window (must be >= 2)
+/// * `output_linearreg` - Array to store the fitted value at the current bar
+/// * `output_slope` - Array to store the slope of the regression line
+/// * `output_intercept` - Array to store the fitted value at the oldest bar of the window
+/// * `output_angle` - Array to store the angle of the regression line in degrees
+/// * `output_tsf` - Array to store the time series forecast for the next bar
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok value on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output arrays don't match input length
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::InsufficientData` - If input length is less than `param_period`
+/// * `KandError::NaNDetected` - If any input value is NaN (with `deep-check` feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::linearreg_core;
+///
+/// let input_prices = vec![1.0, 3.0, 5.0, 7.0];
+/// let mut output_linearreg = vec![0.0; 4];
+/// let mut output_slope = vec![0.0; 4];
+/// let mut output_intercept = vec![0.0; 4];
+/// let mut output_angle = vec![0.0; 4];
+/// let mut output_tsf = vec![0.0; 4];
+///
+/// linearreg_core::linearreg_all(
+///     &input_prices,
+///     3,
+///     &mut output_linearreg,
+///     &mut output_slope,
+///     &mut output_intercept,
+///     &mut output_angle,
+///     &mut output_tsf,
+/// )
+/// .unwrap();
+/// assert_eq!(output_linearreg[3], 7.0);
+/// assert_eq!(output_slope[3], 2.0);
+/// assert_eq!(output_intercept[3], 3.0);
+/// assert_eq!(output_tsf[3], 9.0);
+/// ```
+pub fn linearreg_all(
+    input_prices: &[TAFloat],
+    param_period: usize,
+    output_linearreg: &mut [TAFloat],
+    output_slope: &mut [TAFloat],
+    output_intercept: &mut [TAFloat],
+    output_angle: &mut [TAFloat],
+    output_tsf: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Length consistency check
+        if output_linearreg.len() != len
+            || output_slope.len() != len
+            || output_intercept.len() != len
+            || output_angle.len() != len
+            || output_tsf.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    // The slope and intercept buffers hold the running sums until they are overwritten
+    linearreg_sums(input_prices, param_period, output_slope, output_intercept)?;
+
+    for i in lookback..len {
+        let fit = LinearregFit::from_sums(output_slope[i], output_intercept[i], param_period);
+        output_linearreg[i] = fit.linearreg();
+        output_slope[i] = fit.slope;
+        output_intercept[i] = fit.intercept;
+        output_angle[i] = fit.angle();
+        output_tsf[i] = fit.tsf();
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_linearreg[i] = TAFloat::NAN;
+        output_angle[i] = TAFloat::NAN;
+        output_tsf[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Streaming rolling least-squares state shared by the linear regression indicators.
+///
+/// Keeps the last `param_period` prices together with the running sums required by
+/// [`linearreg_sums_inc`] and emits the fitted line of every window. The first window is
+/// seeded with [`linearreg_sums`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::linearreg_core::LinearregState};
+///
+/// let mut state = LinearregState::new(3).unwrap();
+/// assert!(state.update(1.0).unwrap().is_none());
+/// assert!(state.update(3.0).unwrap().is_none());
+/// let fit = state.update(5.0).unwrap().unwrap();
+/// assert_eq!(fit.slope, 2.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LinearregState {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<TAFloat>,
+    prev_sums:    Option<(TAFloat, TAFloat)>,
+}
+
+impl LinearregState {
+    /// Creates a new streaming regression state.
+    ///
+    /// # Arguments
+    /// * `param_period` - The number of bars in the regression window (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period),
+            prev_sums: None,
+        })
+    }
+}
+
+impl Indicator for LinearregState {
+    type Input = TAFloat;
+    type Output = LinearregFit;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<LinearregFit>, KandError> {
+        if let Some((prev_sum_y, prev_sum_xy)) = self.prev_sums {
+            let (sum_y, sum_xy) = linearreg_sums_inc(
+                input_price,
+                self.window[0],
+                prev_sum_y,
+                prev_sum_xy,
+                self.param_period,
+            )?;
+            self.window.pop_front();
+            self.window.push_back(input_price);
+            self.prev_sums = Some((sum_y, sum_xy));
+            return Ok(Some(LinearregFit::from_sums(
+                sum_y,
+                sum_xy,
+                self.param_period,
+            )));
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let window = self.window.make_contiguous();
+        let len = window.len();
+        let mut output_sum_y = vec![0.0; len];
+        let mut output_sum_xy = vec![0.0; len];
+        linearreg_sums(
+            window,
+            self.param_period,
+            &mut output_sum_y,
+            &mut output_sum_xy,
+        )?;
+        let (sum_y, sum_xy) = (output_sum_y[self.lookback], output_sum_xy[self.lookback]);
+        self.prev_sums = Some((sum_y, sum_xy));
+        Ok(Some(LinearregFit::from_sums(
+            sum_y,
+            sum_xy,
+            self.param_period,
+        )))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.prev_sums = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_sums.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICES: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    /// Direct least-squares fit of one window, used as reference.
+    fn reference_fit(window: &[TAFloat]) -> (TAFloat, TAFloat) {
+        let n = window.len() as TAFloat;
+        let mean_x = (n - 1.0) / 2.0;
+        let mean_y = window.iter().sum::<TAFloat>() / n;
+        let mut cov = 0.0;
+        let mut var = 0.0;
+        for (x, y) in window.iter().enumerate() {
+            let dx = x as TAFloat - mean_x;
+            cov += dx * (y - mean_y);
+            var += dx * dx;
+        }
+        let slope = cov / var;
+        (slope, mean_y - slope * mean_x)
+    }
+
+    #[test]
+    fn test_linearreg_all_matches_direct_fit() {
+        let param_period = 14;
+        let len = INPUT_PRICES.len();
+        let mut output_linearreg = vec![0.0; len];
+        let mut output_slope = vec![0.0; len];
+        let mut output_intercept = vec![0.0; len];
+        let mut output_angle = vec![0.0; len];
+        let mut output_tsf = vec![0.0; len];
+
+        linearreg_all(
+            &INPUT_PRICES,
+            param_period,
+            &mut output_linearreg,
+            &mut output_slope,
+            &mut output_intercept,
+            &mut output_angle,
+            &mut output_tsf,
+        )
+        .unwrap();
+
+        for i in 0..13 {
+            assert!(output_linearreg[i].is_nan());
+            assert!(output_slope[i].is_nan());
+            assert!(output_intercept[i].is_nan());
+            assert!(output_angle[i].is_nan());
+            assert!(output_tsf[i].is_nan());
+        }
+
+        for i in 13..len {
+            let (slope, intercept) = reference_fit(&INPUT_PRICES[i + 1 - param_period..=i]);
+            assert_relative_eq!(output_slope[i], slope, epsilon = 0.00001);
+            assert_relative_eq!(output_intercept[i], intercept, epsilon = 0.0001);
+            assert_relative_eq!(
+                output_linearreg[i],
+                intercept + slope * 13.0,
+                epsilon = 0.0001
+            );
+            assert_relative_eq!(output_tsf[i], intercept + slope * 14.0, epsilon = 0.0001);
+            assert_relative_eq!(
+                output_angle[i],
+                slope.atan().to_degrees(),
+                epsilon = 0.00001
+            );
+        }
+    }
+
+    #[test]
+    fn test_linearreg_state() {
+        let param_period = 5;
+        let mut state = LinearregState::new(param_period).unwrap();
+        assert_eq!(state.lookback(), 4);
+        for (i, &price) in INPUT_PRICES.iter().enumerate() {
+            match state.update(price).unwrap() {
+                Some(fit) => {
+                    let (slope, intercept) = reference_fit(&INPUT_PRICES[i + 1 - param_period..=i]);
+                    assert_relative_eq!(fit.slope, slope, epsilon = 0.00001);
+                    assert_relative_eq!(fit.intercept, intercept, epsilon = 0.0001);
+                }
+                None => assert!(i < 4),
+            }
+        }
+        assert!(state.is_ready());
+        state.reset();
+        assert!(!state.is_ready());
+    }
+}
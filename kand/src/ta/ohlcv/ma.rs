@@ -0,0 +1,434 @@
+use super::{
+    dema::{self, Dema},
+    ema::{self, Ema},
+    kama::{self, Kama},
+    mama::{self, Mama},
+    rma::{self, Rma},
+    sma::{self, Sma},
+    t3::{self, T3},
+    tema::{self, Tema},
+    trima::{self, Trima},
+    wma::{self, Wma},
+};
+use crate::{Indicator, KandError, TAFloat, types::MAType};
+
+/// Volume factor used when [`MAType::T3`] is selected, matching TA-Lib's `MA` function.
+pub const T3_VFACTOR: TAFloat = 0.7;
+
+/// Fast limit used when [`MAType::MAMA`] is selected, matching TA-Lib's `MA` function.
+pub const MAMA_FAST_LIMIT: TAFloat = 0.5;
+
+/// Slow limit used when [`MAType::MAMA`] is selected, matching TA-Lib's `MA` function.
+pub const MAMA_SLOW_LIMIT: TAFloat = 0.05;
+
+/// Returns the lookback period required for a moving average of the given type.
+///
+/// # Description
+/// Each moving average keeps its own warm-up: single smoothers (SMA, EMA, WMA, RMA, TRIMA) need
+/// `period - 1` bars, DEMA, TEMA and T3 chain 2, 3 and 6 EMAs, KAMA needs `period` bars and
+/// MAMA is fixed at 32 bars by the Hilbert Transform core.
+///
+/// # Arguments
+/// * `param_period` - The smoothing period (must be >= 2, also checked for MAMA)
+/// * `param_ma_type` - The moving average type
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The number of bars needed before the first valid output
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::ma, types::MAType};
+///
+/// assert_eq!(ma::lookback(10, MAType::SMA).unwrap(), 9);
+/// assert_eq!(ma::lookback(10, MAType::TEMA).unwrap(), 27);
+/// assert_eq!(ma::lookback(10, MAType::MAMA).unwrap(), 32);
+/// ```
+pub fn lookback(param_period: usize, param_ma_type: MAType) -> Result<usize, KandError> {
+    match param_ma_type {
+        MAType::DEMA => dema::lookback(param_period),
+        MAType::EMA => ema::lookback(param_period),
+        MAType::KAMA => kama::lookback(param_period),
+        MAType::MAMA => {
+            sma::lookback(param_period)?;
+            mama::lookback(MAMA_FAST_LIMIT, MAMA_SLOW_LIMIT)
+        }
+        MAType::RMA => rma::lookback(param_period),
+        MAType::SMA => sma::lookback(param_period),
+        MAType::T3 => t3::lookback(param_period),
+        MAType::TEMA => tema::lookback(param_period),
+        MAType::TRIMA => trima::lookback(param_period),
+        MAType::WMA => wma::lookback(param_period),
+    }
+}
+
+/// Calculates a moving average of the given type for a price series.
+///
+/// # Description
+/// Single entry point over every moving average in the crate, equivalent to TA-Lib's `MA`
+/// function. Intermediate buffers required by the underlying indicator (e.g. the EMAs of DEMA
+/// or the FAMA line of MAMA) are allocated internally and discarded.
+///
+/// Types without a period of their own use TA-Lib's defaults: T3 uses a volume factor of
+/// [`T3_VFACTOR`] and MAMA ignores `param_period` and uses [`MAMA_FAST_LIMIT`] and
+/// [`MAMA_SLOW_LIMIT`].
+///
+/// # Arguments
+/// * `input_prices` - Array of input price values
+/// * `param_period` - The smoothing period (must be >= 2)
+/// * `param_ma_type` - The moving average type
+/// * `output_ma` - Array to store the moving average values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok value on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If input and output lengths differ
+/// * `KandError::InvalidParameter` - If `param_period` is less than 2
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::NaNDetected` - If any input value is NaN (with `deep-check` feature)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::ma, types::MAType};
+///
+/// let input_prices = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// let mut output_ma = vec![0.0; 5];
+///
+/// ma::ma(&input_prices, 3, MAType::SMA, &mut output_ma).unwrap();
+/// assert!(output_ma[1].is_nan());
+/// assert_eq!(output_ma[2], 2.0);
+/// assert_eq!(output_ma[4], 4.0);
+/// ```
+pub fn ma(
+    input_prices: &[TAFloat],
+    param_period: usize,
+    param_ma_type: MAType,
+    output_ma: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    lookback(param_period, param_ma_type)?;
+
+    match param_ma_type {
+        MAType::DEMA => {
+            let mut output_ema1 = vec![0.0; len];
+            let mut output_ema2 = vec![0.0; len];
+            dema::dema(
+                input_prices,
+                param_period,
+                output_ma,
+                &mut output_ema1,
+                &mut output_ema2,
+            )
+        }
+        MAType::EMA => ema::ema(input_prices, param_period, None, output_ma),
+        MAType::KAMA => {
+            let mut output_sum_roc = vec![0.0; len];
+            kama::kama(input_prices, param_period, output_ma, &mut output_sum_roc)
+        }
+        MAType::MAMA => {
+            let mut output_fama = vec![0.0; len];
+            mama::mama(
+                input_prices,
+                MAMA_FAST_LIMIT,
+                MAMA_SLOW_LIMIT,
+                output_ma,
+                &mut output_fama,
+            )
+        }
+        MAType::RMA => rma::rma(input_prices, param_period, output_ma),
+        MAType::SMA => sma::sma(input_prices, param_period, output_ma),
+        MAType::T3 => {
+            let mut output_ema1 = vec![0.0; len];
+            let mut output_ema2 = vec![0.0; len];
+            let mut output_ema3 = vec![0.0; len];
+            let mut output_ema4 = vec![0.0; len];
+            let mut output_ema5 = vec![0.0; len];
+            let mut output_ema6 = vec![0.0; len];
+            t3::t3(
+                input_prices,
+                param_period,
+                T3_VFACTOR,
+                output_ma,
+                &mut output_ema1,
+                &mut output_ema2,
+                &mut output_ema3,
+                &mut output_ema4,
+                &mut output_ema5,
+                &mut output_ema6,
+            )
+        }
+        MAType::TEMA => {
+            let mut output_ema1 = vec![0.0; len];
+            let mut output_ema2 = vec![0.0; len];
+            let mut output_ema3 = vec![0.0; len];
+            tema::tema(
+                input_prices,
+                param_period,
+                output_ma,
+                &mut output_ema1,
+                &mut output_ema2,
+                &mut output_ema3,
+            )
+        }
+        MAType::TRIMA => {
+            let mut output_sma1 = vec![0.0; len];
+            trima::trima(input_prices, param_period, &mut output_sma1, output_ma)
+        }
+        MAType::WMA => wma::wma(input_prices, param_period, output_ma),
+    }
+}
+
+/// Streaming moving average of any [`MAType`].
+///
+/// Wraps the streaming struct of the selected moving average so indicators built on a
+/// configurable average can hold a single field. Every bar is forwarded to the wrapped
+/// indicator, so the emitted values match [`ma`] bar for bar.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ma::Ma, types::MAType};
+///
+/// let mut ma = Ma::new(3, MAType::SMA).unwrap();
+/// let values: Vec<_> = [2.0, 4.0, 6.0, 8.0]
+///     .into_iter()
+///     .map(|price| ma.update(price).unwrap())
+///     .collect();
+/// assert_eq!(values, vec![None, None, Some(4.0), Some(6.0)]);
+/// ```
+#[derive(Debug, Clone)]
+pub enum Ma {
+    Dema(Dema),
+    Ema(Ema),
+    Kama(Kama),
+    Mama(Box<Mama>),
+    Rma(Rma),
+    Sma(Sma),
+    T3(T3),
+    Tema(Tema),
+    Trima(Trima),
+    Wma(Wma),
+}
+
+impl Ma {
+    /// Creates a new streaming moving average of the given type.
+    ///
+    /// # Arguments
+    /// * `param_period` - The smoothing period (must be >= 2)
+    /// * `param_ma_type` - The moving average type
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize, param_ma_type: MAType) -> Result<Self, KandError> {
+        lookback(param_period, param_ma_type)?;
+        Ok(match param_ma_type {
+            MAType::DEMA => Self::Dema(Dema::new(param_period)?),
+            MAType::EMA => Self::Ema(Ema::new(param_period, None)?),
+            MAType::KAMA => Self::Kama(Kama::new(param_period)?),
+            MAType::MAMA => Self::Mama(Box::new(Mama::new(MAMA_FAST_LIMIT, MAMA_SLOW_LIMIT)?)),
+            MAType::RMA => Self::Rma(Rma::new(param_period)?),
+            MAType::SMA => Self::Sma(Sma::new(param_period)?),
+            MAType::T3 => Self::T3(T3::new(param_period, T3_VFACTOR)?),
+            MAType::TEMA => Self::Tema(Tema::new(param_period)?),
+            MAType::TRIMA => Self::Trima(Trima::new(param_period)?),
+            MAType::WMA => Self::Wma(Wma::new(param_period)?),
+        })
+    }
+
+    /// Returns the moving average type of this stream.
+    pub const fn ma_type(&self) -> MAType {
+        match self {
+            Self::Dema(_) => MAType::DEMA,
+            Self::Ema(_) => MAType::EMA,
+            Self::Kama(_) => MAType::KAMA,
+            Self::Mama(_) => MAType::MAMA,
+            Self::Rma(_) => MAType::RMA,
+            Self::Sma(_) => MAType::SMA,
+            Self::T3(_) => MAType::T3,
+            Self::Tema(_) => MAType::TEMA,
+            Self::Trima(_) => MAType::TRIMA,
+            Self::Wma(_) => MAType::WMA,
+        }
+    }
+}
+
+impl Indicator for Ma {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        match self {
+            Self::Dema(ma) => ma.update(input_price),
+            Self::Ema(ma) => ma.update(input_price),
+            Self::Kama(ma) => ma.update(input_price),
+            Self::Mama(ma) => Ok(ma.update(input_price)?.map(|(output_mama, _)| output_mama)),
+            Self::Rma(ma) => ma.update(input_price),
+            Self::Sma(ma) => ma.update(input_price),
+            Self::T3(ma) => ma.update(input_price),
+            Self::Tema(ma) => ma.update(input_price),
+            Self::Trima(ma) => ma.update(input_price),
+            Self::Wma(ma) => ma.update(input_price),
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            Self::Dema(ma) => ma.reset(),
+            Self::Ema(ma) => ma.reset(),
+            Self::Kama(ma) => ma.reset(),
+            Self::Mama(ma) => ma.reset(),
+            Self::Rma(ma) => ma.reset(),
+            Self::Sma(ma) => ma.reset(),
+            Self::T3(ma) => ma.reset(),
+            Self::Tema(ma) => ma.reset(),
+            Self::Trima(ma) => ma.reset(),
+            Self::Wma(ma) => ma.reset(),
+        }
+    }
+
+    fn is_ready(&self) -> bool {
+        match self {
+            Self::Dema(ma) => ma.is_ready(),
+            Self::Ema(ma) => ma.is_ready(),
+            Self::Kama(ma) => ma.is_ready(),
+            Self::Mama(ma) => ma.is_ready(),
+            Self::Rma(ma) => ma.is_ready(),
+            Self::Sma(ma) => ma.is_ready(),
+            Self::T3(ma) => ma.is_ready(),
+            Self::Tema(ma) => ma.is_ready(),
+            Self::Trima(ma) => ma.is_ready(),
+            Self::Wma(ma) => ma.is_ready(),
+        }
+    }
+
+    fn lookback(&self) -> usize {
+        match self {
+            Self::Dema(ma) => ma.lookback(),
+            Self::Ema(ma) => ma.lookback(),
+            Self::Kama(ma) => ma.lookback(),
+            Self::Mama(ma) => ma.lookback(),
+            Self::Rma(ma) => ma.lookback(),
+            Self::Sma(ma) => ma.lookback(),
+            Self::T3(ma) => ma.lookback(),
+            Self::Tema(ma) => ma.lookback(),
+            Self::Trima(ma) => ma.lookback(),
+            Self::Wma(ma) => ma.lookback(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const ALL_MA_TYPES: [MAType; 10] = [
+        MAType::DEMA,
+        MAType::EMA,
+        MAType::KAMA,
+        MAType::MAMA,
+        MAType::RMA,
+        MAType::SMA,
+        MAType::T3,
+        MAType::TEMA,
+        MAType::TRIMA,
+        MAType::WMA,
+    ];
+
+    fn input_prices() -> Vec<TAFloat> {
+        (0..80)
+            .map(|i| {
+                let i = i as TAFloat;
+                100.0 + 0.3 * i + 5.0 * (i * 0.35).sin() + 2.0 * (i * 1.3).cos()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ma_matches_underlying_indicator() {
+        let input_prices = input_prices();
+        let len = input_prices.len();
+        let param_period = 10;
+
+        let mut output_ma = vec![0.0; len];
+        let mut expected = vec![0.0; len];
+
+        ma(&input_prices, param_period, MAType::WMA, &mut output_ma).unwrap();
+        wma::wma(&input_prices, param_period, &mut expected).unwrap();
+        for i in 0..len {
+            if expected[i].is_nan() {
+                assert!(output_ma[i].is_nan());
+            } else {
+                assert_relative_eq!(output_ma[i], expected[i], epsilon = 1e-10);
+            }
+        }
+
+        let mut output_ema1 = vec![0.0; len];
+        let mut output_ema2 = vec![0.0; len];
+        ma(&input_prices, param_period, MAType::DEMA, &mut output_ma).unwrap();
+        dema::dema(
+            &input_prices,
+            param_period,
+            &mut expected,
+            &mut output_ema1,
+            &mut output_ema2,
+        )
+        .unwrap();
+        for i in 0..len {
+            if expected[i].is_nan() {
+                assert!(output_ma[i].is_nan());
+            } else {
+                assert_relative_eq!(output_ma[i], expected[i], epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ma_lookback_and_stream() {
+        let input_prices = input_prices();
+        let len = input_prices.len();
+        let param_period = 5;
+
+        for param_ma_type in ALL_MA_TYPES {
+            let lookback = lookback(param_period, param_ma_type).unwrap();
+            let mut output_ma = vec![0.0; len];
+            ma(&input_prices, param_period, param_ma_type, &mut output_ma).unwrap();
+
+            for value in output_ma.iter().take(lookback) {
+                assert!(value.is_nan(), "{param_ma_type:?}");
+            }
+
+            let mut stream = Ma::new(param_period, param_ma_type).unwrap();
+            assert_eq!(stream.ma_type(), param_ma_type);
+            assert_eq!(stream.lookback(), lookback);
+            for (i, &price) in input_prices.iter().enumerate() {
+                match stream.update(price).unwrap() {
+                    Some(value) => {
+                        assert!(i >= lookback, "{param_ma_type:?}");
+                        assert_relative_eq!(value, output_ma[i], epsilon = 1e-8);
+                    }
+                    None => assert!(i < lookback, "{param_ma_type:?}"),
+                }
+            }
+            assert!(stream.is_ready());
+
+            stream.reset();
+            assert!(!stream.is_ready());
+        }
+    }
+
+    #[test]
+    fn test_ma_invalid_period() {
+        for param_ma_type in ALL_MA_TYPES {
+            assert!(matches!(
+                lookback(1, param_ma_type),
+                Err(KandError::InvalidParameter)
+            ));
+            assert!(Ma::new(1, param_ma_type).is_err());
+        }
+    }
+}
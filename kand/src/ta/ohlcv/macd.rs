@@ -1,5 +1,5 @@
 use super::ema;
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Calculate the lookback period required for MACD calculation
 ///
@@ -147,7 +147,7 @@ pub fn macd(
         }
 
         // Check if remaining data after slow period is sufficient for signal calculation
-        if len.saturating_sub(param_slow_period) < param_signal_period {
+        if len.saturating_sub(param_slow_period - 1) < param_signal_period {
             return Err(KandError::InsufficientData);
         }
     }
@@ -280,3 +280,197 @@ pub fn macd_inc(
 
     Ok((macd, signal, histogram))
 }
+
+/// Streaming MACD calculator.
+///
+/// Carries the fast EMA, slow EMA and signal line between bars. The states are seeded with
+/// [`macd`] over the warm-up window and then advanced with the same EMA steps as [`macd_inc`],
+/// so the stream matches the batch output.
+///
+/// The output tuple is `(macd_line, signal_line, histogram)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::macd::Macd};
+///
+/// let mut macd = Macd::new(2, 3, 2).unwrap();
+/// assert_eq!(macd.lookback(), 3);
+/// for price in [1.0, 2.0, 3.0] {
+///     assert!(macd.update(price).unwrap().is_none());
+/// }
+/// let (line, signal, histogram) = macd.update(4.0).unwrap().unwrap();
+/// assert!((line - signal - histogram).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Macd {
+    param_fast_period:   usize,
+    param_slow_period:   usize,
+    param_signal_period: usize,
+    lookback:            usize,
+    warmup:              Vec<TAFloat>,
+    ready:               bool,
+    prev_fast_ema:       TAFloat,
+    prev_slow_ema:       TAFloat,
+    prev_signal:         TAFloat,
+}
+
+impl Macd {
+    /// Creates a new streaming MACD.
+    ///
+    /// # Arguments
+    /// * `param_fast_period` - Period for fast EMA calculation (typically 12)
+    /// * `param_slow_period` - Period for slow EMA calculation (typically 26)
+    /// * `param_signal_period` - Period for signal line calculation (typically 9)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period is 0 or `fast_period` >= `slow_period`
+    pub fn new(
+        param_fast_period: usize,
+        param_slow_period: usize,
+        param_signal_period: usize,
+    ) -> Result<Self, KandError> {
+        let lookback = lookback(param_fast_period, param_slow_period, param_signal_period)?;
+        Ok(Self {
+            param_fast_period,
+            param_slow_period,
+            param_signal_period,
+            lookback,
+            warmup: Vec::with_capacity(lookback + 1),
+            ready: false,
+            prev_fast_ema: TAFloat::NAN,
+            prev_slow_ema: TAFloat::NAN,
+            prev_signal: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Macd {
+    type Input = TAFloat;
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        input_price: TAFloat,
+    ) -> Result<Option<(TAFloat, TAFloat, TAFloat)>, KandError> {
+        if self.ready {
+            let fast_ema = ema::ema_inc(
+                input_price,
+                self.prev_fast_ema,
+                self.param_fast_period,
+                None,
+            )?;
+            let slow_ema = ema::ema_inc(
+                input_price,
+                self.prev_slow_ema,
+                self.param_slow_period,
+                None,
+            )?;
+            let macd_line = fast_ema - slow_ema;
+            let signal_line =
+                ema::ema_inc(macd_line, self.prev_signal, self.param_signal_period, None)?;
+            let histogram = macd_line - signal_line;
+            self.prev_fast_ema = fast_ema;
+            self.prev_slow_ema = slow_ema;
+            self.prev_signal = signal_line;
+            return Ok(Some((macd_line, signal_line, histogram)));
+        }
+
+        self.warmup.push(input_price);
+        if self.warmup.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let len = self.warmup.len();
+        let mut output_macd_line = vec![0.0; len];
+        let mut output_signal_line = vec![0.0; len];
+        let mut output_histogram = vec![0.0; len];
+        let mut output_fast_ema = vec![0.0; len];
+        let mut output_slow_ema = vec![0.0; len];
+        macd(
+            &self.warmup,
+            self.param_fast_period,
+            self.param_slow_period,
+            self.param_signal_period,
+            &mut output_macd_line,
+            &mut output_signal_line,
+            &mut output_histogram,
+            &mut output_fast_ema,
+            &mut output_slow_ema,
+        )?;
+        self.warmup.clear();
+        self.prev_fast_ema = output_fast_ema[self.lookback];
+        self.prev_slow_ema = output_slow_ema[self.lookback];
+        self.prev_signal = output_signal_line[self.lookback];
+        self.ready = true;
+        Ok(Some((
+            output_macd_line[self.lookback],
+            output_signal_line[self.lookback],
+            output_histogram[self.lookback],
+        )))
+    }
+
+    fn reset(&mut self) {
+        self.warmup.clear();
+        self.ready = false;
+        self.prev_fast_ema = TAFloat::NAN;
+        self.prev_slow_ema = TAFloat::NAN;
+        self.prev_signal = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_macd_stream() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let (fast, slow, signal) = (4, 8, 3);
+        let len = input_price.len();
+        let mut output_macd_line = vec![0.0; len];
+        let mut output_signal_line = vec![0.0; len];
+        let mut output_histogram = vec![0.0; len];
+        let mut output_fast_ema = vec![0.0; len];
+        let mut output_slow_ema = vec![0.0; len];
+
+        macd(
+            &input_price,
+            fast,
+            slow,
+            signal,
+            &mut output_macd_line,
+            &mut output_signal_line,
+            &mut output_histogram,
+            &mut output_fast_ema,
+            &mut output_slow_ema,
+        )
+        .unwrap();
+
+        let mut stream = Macd::new(fast, slow, signal).unwrap();
+        for (i, price) in input_price.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some((macd_line, signal_line, histogram)) => {
+                    assert_relative_eq!(macd_line, output_macd_line[i], epsilon = 0.00001);
+                    assert_relative_eq!(signal_line, output_signal_line[i], epsilon = 0.00001);
+                    assert_relative_eq!(histogram, output_histogram[i], epsilon = 0.00001);
+                }
+                None => assert!(output_macd_line[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
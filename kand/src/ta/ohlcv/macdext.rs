@@ -0,0 +1,720 @@
+use super::ma::{self, Ma};
+use crate::{Indicator, KandError, TAFloat, types::MAType};
+
+/// Swaps the fast and slow lines, periods and MA types, when the slow period is shorter.
+///
+/// TA-Lib accepts the two periods in either order and always treats the shorter one as fast.
+const fn ordered_lines(
+    param_fast_period: usize,
+    param_fast_ma_type: MAType,
+    param_slow_period: usize,
+    param_slow_ma_type: MAType,
+) -> (usize, MAType, usize, MAType) {
+    if param_slow_period < param_fast_period {
+        (
+            param_slow_period,
+            param_slow_ma_type,
+            param_fast_period,
+            param_fast_ma_type,
+        )
+    } else {
+        (
+            param_fast_period,
+            param_fast_ma_type,
+            param_slow_period,
+            param_slow_ma_type,
+        )
+    }
+}
+
+/// Calculate the lookback period required for MACDEXT calculation
+///
+/// The MACD line is valid once both the fast and the slow moving averages are, and the signal
+/// line needs the lookback of its own moving average on top of that. As in TA-Lib, a slow
+/// period shorter than the fast one swaps the two lines.
+///
+/// # Arguments
+/// * `param_fast_period` - Fast MA period, must be >= 2
+/// * `param_fast_ma_type` - Fast MA type
+/// * `param_slow_period` - Slow MA period, must be >= 2
+/// * `param_slow_ma_type` - Slow MA type
+/// * `param_signal_period` - Signal line period, must be >= 2
+/// * `param_signal_ma_type` - Signal line MA type
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Lookback period if successful
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If any period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::macdext, types::MAType};
+/// let lookback = macdext::lookback(12, MAType::EMA, 26, MAType::EMA, 9, MAType::EMA).unwrap();
+/// assert_eq!(lookback, 33); // 25 (slow EMA) + 8 (signal)
+/// assert_eq!(
+///     macdext::lookback(26, MAType::EMA, 12, MAType::EMA, 9, MAType::EMA).unwrap(),
+///     lookback
+/// );
+/// ```
+pub fn lookback(
+    param_fast_period: usize,
+    param_fast_ma_type: MAType,
+    param_slow_period: usize,
+    param_slow_ma_type: MAType,
+    param_signal_period: usize,
+    param_signal_ma_type: MAType,
+) -> Result<usize, KandError> {
+    let (param_fast_period, param_fast_ma_type, param_slow_period, param_slow_ma_type) =
+        ordered_lines(
+            param_fast_period,
+            param_fast_ma_type,
+            param_slow_period,
+            param_slow_ma_type,
+        );
+    let fast_lookback = ma::lookback(param_fast_period, param_fast_ma_type)?;
+    let slow_lookback = ma::lookback(param_slow_period, param_slow_ma_type)?;
+    let signal_lookback = ma::lookback(param_signal_period, param_signal_ma_type)?;
+    Ok(fast_lookback.max(slow_lookback) + signal_lookback)
+}
+
+/// Calculate MACD with controllable moving average types (MACDEXT) for a price series
+///
+/// MACDEXT generalizes MACD: the fast line, the slow line and the signal line each use their
+/// own period and moving average type. With EMA for all three lines it is equivalent to MACD.
+/// If the slow period is shorter than the fast one, the two lines are swapped as in TA-Lib,
+/// so `output_fast_ma` always holds the shorter moving average.
+///
+/// # Mathematical Formula
+/// ```text
+/// Fast MA = MA(price, fast_period, fast_ma_type)
+/// Slow MA = MA(price, slow_period, slow_ma_type)
+/// MACD Line = Fast MA - Slow MA
+/// Signal Line = MA(MACD Line, signal_period, signal_ma_type)
+/// Histogram = MACD Line - Signal Line
+/// ```
+///
+/// # Calculation Steps
+/// 1. Calculate fast and slow moving averages of price, starting the one with the shorter
+///    lookback late so that both first become valid on the same bar, as TA-Lib seeds them
+/// 2. Calculate MACD line once both moving averages are valid
+/// 3. Calculate signal line as moving average of the valid MACD line
+/// 4. Calculate histogram as difference between MACD and signal lines
+///
+/// # Arguments
+/// * `input_price` - Array of price values
+/// * `param_fast_period` - Fast MA period (typically 12)
+/// * `param_fast_ma_type` - Fast MA type
+/// * `param_slow_period` - Slow MA period (typically 26)
+/// * `param_slow_ma_type` - Slow MA type
+/// * `param_signal_period` - Signal line period (typically 9)
+/// * `param_signal_ma_type` - Signal line MA type
+/// * `output_macd_line` - Output buffer for MACD line values
+/// * `output_signal_line` - Output buffer for signal line values
+/// * `output_histogram` - Output buffer for histogram values
+/// * `output_fast_ma` - Output buffer for fast MA values
+/// * `output_slow_ma` - Output buffer for slow MA values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok if successful
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If any period is less than 2
+/// * `KandError::InsufficientData` - If input length <= required lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::macdext, types::MAType};
+///
+/// let prices = vec![10.0, 12.0, 15.0, 11.0, 9.0, 10.0, 12.0];
+/// let mut macd_line = vec![0.0; prices.len()];
+/// let mut signal_line = vec![0.0; prices.len()];
+/// let mut histogram = vec![0.0; prices.len()];
+/// let mut fast_ma = vec![0.0; prices.len()];
+/// let mut slow_ma = vec![0.0; prices.len()];
+///
+/// macdext::macdext(
+///     &prices,
+///     2, // fast period
+///     MAType::SMA,
+///     3, // slow period
+///     MAType::EMA,
+///     2, // signal period
+///     MAType::WMA,
+///     &mut macd_line,
+///     &mut signal_line,
+///     &mut histogram,
+///     &mut fast_ma,
+///     &mut slow_ma,
+/// )
+/// .unwrap();
+/// assert!(signal_line[2].is_nan());
+/// assert!(!signal_line[3].is_nan());
+/// ```
+pub fn macdext(
+    input_price: &[TAFloat],
+    param_fast_period: usize,
+    param_fast_ma_type: MAType,
+    param_slow_period: usize,
+    param_slow_ma_type: MAType,
+    param_signal_period: usize,
+    param_signal_ma_type: MAType,
+    output_macd_line: &mut [TAFloat],
+    output_signal_line: &mut [TAFloat],
+    output_histogram: &mut [TAFloat],
+    output_fast_ma: &mut [TAFloat],
+    output_slow_ma: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_price.len();
+    let (param_fast_period, param_fast_ma_type, param_slow_period, param_slow_ma_type) =
+        ordered_lines(
+            param_fast_period,
+            param_fast_ma_type,
+            param_slow_period,
+            param_slow_ma_type,
+        );
+    let lookback = lookback(
+        param_fast_period,
+        param_fast_ma_type,
+        param_slow_period,
+        param_slow_ma_type,
+        param_signal_period,
+        param_signal_ma_type,
+    )?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != output_macd_line.len()
+            || len != output_signal_line.len()
+            || len != output_histogram.len()
+            || len != output_fast_ma.len()
+            || len != output_slow_ma.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for price in input_price {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Start each moving average so that it becomes valid at `macd_start`
+    let fast_lookback = ma::lookback(param_fast_period, param_fast_ma_type)?;
+    let slow_lookback = ma::lookback(param_slow_period, param_slow_ma_type)?;
+    let macd_start = fast_lookback.max(slow_lookback);
+    let fast_offset = macd_start - fast_lookback;
+    let slow_offset = macd_start - slow_lookback;
+    ma::ma(
+        &input_price[fast_offset..],
+        param_fast_period,
+        param_fast_ma_type,
+        &mut output_fast_ma[fast_offset..],
+    )?;
+    ma::ma(
+        &input_price[slow_offset..],
+        param_slow_period,
+        param_slow_ma_type,
+        &mut output_slow_ma[slow_offset..],
+    )?;
+
+    // Calculate MACD line where both moving averages are valid
+    for i in macd_start..len {
+        output_macd_line[i] = output_fast_ma[i] - output_slow_ma[i];
+    }
+
+    // Calculate signal line using non-NaN MACD values
+    ma::ma(
+        &output_macd_line[macd_start..],
+        param_signal_period,
+        param_signal_ma_type,
+        &mut output_signal_line[macd_start..],
+    )?;
+
+    // Calculate histogram
+    for i in lookback..len {
+        output_histogram[i] = output_macd_line[i] - output_signal_line[i];
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_macd_line[i] = TAFloat::NAN;
+        output_signal_line[i] = TAFloat::NAN;
+        output_histogram[i] = TAFloat::NAN;
+        output_fast_ma[i] = TAFloat::NAN;
+        output_slow_ma[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculate latest MACDEXT values incrementally from previous state
+///
+/// The state of an arbitrary moving average does not fit in a few scalars, so the three lines
+/// are carried in streaming [`Ma`] states owned by the caller. Feed every bar from the start
+/// of the series; the states warm themselves up and NaN is returned until the signal line is
+/// valid, matching the batch output bar for bar. `prev_count` tells which bars the moving
+/// average with the shorter lookback still has to skip.
+///
+/// # Arguments
+/// * `input_price` - Current price value
+/// * `prev_count` - Number of prices already fed, i.e. the index of `input_price`
+/// * `fast_ma` - Fast moving average state, e.g. `Ma::new(12, MAType::EMA)`
+/// * `slow_ma` - Slow moving average state, e.g. `Ma::new(26, MAType::EMA)`
+/// * `signal_ma` - Signal line moving average state, e.g. `Ma::new(9, MAType::EMA)`
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (MACD, Signal, Histogram),
+///   NaN while warming up
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If the input value is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{
+///     ohlcv::{ma::Ma, macdext},
+///     types::MAType,
+/// };
+///
+/// let mut fast_ma = Ma::new(2, MAType::SMA).unwrap();
+/// let mut slow_ma = Ma::new(3, MAType::EMA).unwrap();
+/// let mut signal_ma = Ma::new(2, MAType::WMA).unwrap();
+///
+/// for (i, price) in [10.0, 12.0, 15.0].into_iter().enumerate() {
+///     let (_, signal, _) =
+///         macdext::macdext_inc(price, i, &mut fast_ma, &mut slow_ma, &mut signal_ma).unwrap();
+///     assert!(signal.is_nan());
+/// }
+/// let (macd, signal, hist) =
+///     macdext::macdext_inc(11.0, 3, &mut fast_ma, &mut slow_ma, &mut signal_ma).unwrap();
+/// assert!((macd - signal - hist).abs() < 1e-10);
+/// ```
+pub fn macdext_inc(
+    input_price: TAFloat,
+    prev_count: usize,
+    fast_ma: &mut Ma,
+    slow_ma: &mut Ma,
+    signal_ma: &mut Ma,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    // Hold back the line with the shorter lookback, as the batch does
+    let macd_start = fast_ma.lookback().max(slow_ma.lookback());
+    let fast = if prev_count + fast_ma.lookback() >= macd_start {
+        fast_ma.update(input_price)?
+    } else {
+        None
+    };
+    let slow = if prev_count + slow_ma.lookback() >= macd_start {
+        slow_ma.update(input_price)?
+    } else {
+        None
+    };
+    let (Some(fast), Some(slow)) = (fast, slow) else {
+        return Ok((TAFloat::NAN, TAFloat::NAN, TAFloat::NAN));
+    };
+
+    let macd = fast - slow;
+    match signal_ma.update(macd)? {
+        Some(signal) => Ok((macd, signal, macd - signal)),
+        None => Ok((macd, TAFloat::NAN, TAFloat::NAN)),
+    }
+}
+
+/// Streaming MACDEXT calculator.
+///
+/// Owns the fast, slow and signal moving average states and advances them with
+/// [`macdext_inc`], so the stream matches the batch output.
+///
+/// The output tuple is `(macd_line, signal_line, histogram)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::macdext::Macdext, types::MAType};
+///
+/// let mut macdext = Macdext::new(2, MAType::SMA, 3, MAType::EMA, 2, MAType::WMA).unwrap();
+/// assert_eq!(macdext.lookback(), 3);
+/// for price in [1.0, 2.0, 3.0] {
+///     assert!(macdext.update(price).unwrap().is_none());
+/// }
+/// let (line, signal, histogram) = macdext.update(4.0).unwrap().unwrap();
+/// assert!((line - signal - histogram).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Macdext {
+    lookback:  usize,
+    count:     usize,
+    fast_ma:   Ma,
+    slow_ma:   Ma,
+    signal_ma: Ma,
+}
+
+impl Macdext {
+    /// Creates a new streaming MACDEXT.
+    ///
+    /// # Arguments
+    /// * `param_fast_period` - Period for the fast MA (typically 12)
+    /// * `param_fast_ma_type` - Fast MA type
+    /// * `param_slow_period` - Period for the slow MA (typically 26)
+    /// * `param_slow_ma_type` - Slow MA type
+    /// * `param_signal_period` - Period for the signal line (typically 9)
+    /// * `param_signal_ma_type` - Signal line MA type
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period is less than 2
+    pub fn new(
+        param_fast_period: usize,
+        param_fast_ma_type: MAType,
+        param_slow_period: usize,
+        param_slow_ma_type: MAType,
+        param_signal_period: usize,
+        param_signal_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        let (param_fast_period, param_fast_ma_type, param_slow_period, param_slow_ma_type) =
+            ordered_lines(
+                param_fast_period,
+                param_fast_ma_type,
+                param_slow_period,
+                param_slow_ma_type,
+            );
+        Ok(Self {
+            lookback:  lookback(
+                param_fast_period,
+                param_fast_ma_type,
+                param_slow_period,
+                param_slow_ma_type,
+                param_signal_period,
+                param_signal_ma_type,
+            )?,
+            count:     0,
+            fast_ma:   Ma::new(param_fast_period, param_fast_ma_type)?,
+            slow_ma:   Ma::new(param_slow_period, param_slow_ma_type)?,
+            signal_ma: Ma::new(param_signal_period, param_signal_ma_type)?,
+        })
+    }
+}
+
+impl Indicator for Macdext {
+    type Input = TAFloat;
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        input_price: TAFloat,
+    ) -> Result<Option<(TAFloat, TAFloat, TAFloat)>, KandError> {
+        let output = macdext_inc(
+            input_price,
+            self.count,
+            &mut self.fast_ma,
+            &mut self.slow_ma,
+            &mut self.signal_ma,
+        )?;
+        self.count += 1;
+        if self.signal_ma.is_ready() {
+            Ok(Some(output))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.signal_ma.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    #[test]
+    fn test_macdext_matches_talib() {
+        let len = INPUT_PRICE.len();
+        let mut output_macd_line = vec![0.0; len];
+        let mut output_signal_line = vec![0.0; len];
+        let mut output_histogram = vec![0.0; len];
+        let mut output_fast_ma = vec![0.0; len];
+        let mut output_slow_ma = vec![0.0; len];
+        macdext(
+            &INPUT_PRICE,
+            4,
+            MAType::EMA,
+            8,
+            MAType::EMA,
+            3,
+            MAType::EMA,
+            &mut output_macd_line,
+            &mut output_signal_line,
+            &mut output_histogram,
+            &mut output_fast_ma,
+            &mut output_slow_ma,
+        )
+        .unwrap();
+
+        let lookback = lookback(4, MAType::EMA, 8, MAType::EMA, 3, MAType::EMA).unwrap();
+        assert_eq!(lookback, 9);
+        for i in 0..lookback {
+            assert!(output_macd_line[i].is_nan());
+            assert!(output_signal_line[i].is_nan());
+            assert!(output_histogram[i].is_nan());
+        }
+
+        // TA-Lib MACDEXT(fastperiod=4, fastmatype=EMA, slowperiod=8, slowmatype=EMA,
+        // signalperiod=3, signalmatype=EMA); the fast EMA is seeded 4 bars late
+        let expected_macd_line = [
+            -1.439_123_456_788_365_7,
+            -5.808_384_910_837_049,
+            2.411_149_513_798_591,
+            2.939_274_510_725_226_7,
+            -6.284_424_891_659,
+            -22.545_757_955_733_28,
+            -36.859_201_567_349_37,
+            -47.711_324_002_331_82,
+            -46.027_974_560_674_92,
+            -31.266_591_526_953_56,
+            -29.492_026_864_383_66,
+            -41.171_272_078_347_1,
+            -40.633_028_993_455_81,
+            -28.343_357_421_086_694,
+            -12.657_656_694_325_851,
+            2.578_573_128_768_766_8,
+        ];
+        let expected_signal_line = [
+            4.892_144_032_921_351,
+            -0.458_120_438_957_848_8,
+            0.976_514_537_420_371_2,
+            1.957_894_524_072_799,
+            -2.163_265_183_793_100_7,
+            -12.354_511_569_763_19,
+            -24.606_856_568_556_28,
+            -36.159_090_285_444_05,
+            -41.093_532_423_059_486,
+            -36.180_061_975_006_524,
+            -32.836_044_419_695_09,
+            -37.003_658_249_021_1,
+            -38.818_343_621_238_455,
+            -33.580_850_521_162_574,
+            -23.119_253_607_744_213,
+            -10.270_340_239_487_723,
+        ];
+        for i in lookback..len {
+            let j = i - lookback;
+            assert_relative_eq!(
+                output_macd_line[i],
+                expected_macd_line[j],
+                epsilon = 0.00001
+            );
+            assert_relative_eq!(
+                output_signal_line[i],
+                expected_signal_line[j],
+                epsilon = 0.00001
+            );
+            assert_relative_eq!(
+                output_histogram[i],
+                expected_macd_line[j] - expected_signal_line[j],
+                epsilon = 0.00001
+            );
+        }
+
+        // Incremental and streaming calculation hold the fast EMA back the same way
+        let mut fast_ma = Ma::new(4, MAType::EMA).unwrap();
+        let mut slow_ma = Ma::new(8, MAType::EMA).unwrap();
+        let mut signal_ma = Ma::new(3, MAType::EMA).unwrap();
+        let mut stream = Macdext::new(4, MAType::EMA, 8, MAType::EMA, 3, MAType::EMA).unwrap();
+        for (i, &price) in INPUT_PRICE.iter().enumerate() {
+            let (macd, signal, _) =
+                macdext_inc(price, i, &mut fast_ma, &mut slow_ma, &mut signal_ma).unwrap();
+            match stream.update(price).unwrap() {
+                Some((stream_macd, stream_signal, _)) => {
+                    assert_relative_eq!(macd, output_macd_line[i], epsilon = 0.00001);
+                    assert_relative_eq!(signal, output_signal_line[i], epsilon = 0.00001);
+                    assert_relative_eq!(stream_macd, macd, epsilon = 0.00001);
+                    assert_relative_eq!(stream_signal, signal, epsilon = 0.00001);
+                }
+                None => assert!(output_signal_line[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_macdext_mixed_types() {
+        let len = INPUT_PRICE.len();
+        let mut output_macd_line = vec![0.0; len];
+        let mut output_signal_line = vec![0.0; len];
+        let mut output_histogram = vec![0.0; len];
+        let mut output_fast_ma = vec![0.0; len];
+        let mut output_slow_ma = vec![0.0; len];
+        macdext(
+            &INPUT_PRICE,
+            3,
+            MAType::SMA,
+            6,
+            MAType::DEMA,
+            4,
+            MAType::WMA,
+            &mut output_macd_line,
+            &mut output_signal_line,
+            &mut output_histogram,
+            &mut output_fast_ma,
+            &mut output_slow_ma,
+        )
+        .unwrap();
+
+        // DEMA(6) needs 10 bars and WMA(4) 3 more
+        let lookback = lookback(3, MAType::SMA, 6, MAType::DEMA, 4, MAType::WMA).unwrap();
+        assert_eq!(lookback, 13);
+        assert!(output_signal_line[12].is_nan());
+
+        let mut expected_fast = vec![0.0; len];
+        let mut expected_slow = vec![0.0; len];
+        ma::ma(&INPUT_PRICE, 3, MAType::SMA, &mut expected_fast).unwrap();
+        ma::ma(&INPUT_PRICE, 6, MAType::DEMA, &mut expected_slow).unwrap();
+        let macd_line: Vec<TAFloat> = (10..len)
+            .map(|i| expected_fast[i] - expected_slow[i])
+            .collect();
+        let mut expected_signal = vec![0.0; macd_line.len()];
+        ma::ma(&macd_line, 4, MAType::WMA, &mut expected_signal).unwrap();
+
+        for i in lookback..len {
+            assert_relative_eq!(output_macd_line[i], macd_line[i - 10], epsilon = 0.00001);
+            assert_relative_eq!(
+                output_signal_line[i],
+                expected_signal[i - 10],
+                epsilon = 0.00001
+            );
+            assert_relative_eq!(
+                output_histogram[i],
+                macd_line[i - 10] - expected_signal[i - 10],
+                epsilon = 0.00001
+            );
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let mut fast_ma = Ma::new(3, MAType::SMA).unwrap();
+        let mut slow_ma = Ma::new(6, MAType::DEMA).unwrap();
+        let mut signal_ma = Ma::new(4, MAType::WMA).unwrap();
+        let mut stream = Macdext::new(3, MAType::SMA, 6, MAType::DEMA, 4, MAType::WMA).unwrap();
+        for (i, &price) in INPUT_PRICE.iter().enumerate() {
+            let (macd, signal, histogram) =
+                macdext_inc(price, i, &mut fast_ma, &mut slow_ma, &mut signal_ma).unwrap();
+            match stream.update(price).unwrap() {
+                Some((stream_macd, stream_signal, stream_histogram)) => {
+                    assert!(i >= lookback);
+                    assert_relative_eq!(macd, output_macd_line[i], epsilon = 0.00001);
+                    assert_relative_eq!(signal, output_signal_line[i], epsilon = 0.00001);
+                    assert_relative_eq!(histogram, output_histogram[i], epsilon = 0.00001);
+                    assert_relative_eq!(stream_macd, macd, epsilon = 0.00001);
+                    assert_relative_eq!(stream_signal, signal, epsilon = 0.00001);
+                    assert_relative_eq!(stream_histogram, histogram, epsilon = 0.00001);
+                }
+                None => {
+                    assert!(i < lookback);
+                    assert!(signal.is_nan());
+                }
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_macdext_invalid_periods() {
+        assert!(lookback(1, MAType::EMA, 12, MAType::EMA, 9, MAType::EMA).is_err());
+        assert!(Macdext::new(12, MAType::EMA, 26, MAType::EMA, 1, MAType::SMA).is_err());
+    }
+
+    #[test]
+    fn test_macdext_swapped_periods() {
+        let len = INPUT_PRICE.len();
+        let mut output_macd_line = vec![0.0; len];
+        let mut output_signal_line = vec![0.0; len];
+        let mut output_histogram = vec![0.0; len];
+        let mut output_fast_ma = vec![0.0; len];
+        let mut output_slow_ma = vec![0.0; len];
+        macdext(
+            &INPUT_PRICE,
+            6,
+            MAType::DEMA,
+            3,
+            MAType::SMA,
+            4,
+            MAType::WMA,
+            &mut output_macd_line,
+            &mut output_signal_line,
+            &mut output_histogram,
+            &mut output_fast_ma,
+            &mut output_slow_ma,
+        )
+        .unwrap();
+
+        // As in TA-Lib the shorter SMA becomes the fast line
+        let lookback = lookback(6, MAType::DEMA, 3, MAType::SMA, 4, MAType::WMA).unwrap();
+        assert_eq!(lookback, 13);
+        let mut expected_fast = vec![0.0; len];
+        let mut expected_slow = vec![0.0; len];
+        ma::ma(&INPUT_PRICE, 3, MAType::SMA, &mut expected_fast).unwrap();
+        ma::ma(&INPUT_PRICE, 6, MAType::DEMA, &mut expected_slow).unwrap();
+        for i in lookback..len {
+            assert_relative_eq!(output_fast_ma[i], expected_fast[i], epsilon = 0.00001);
+            assert_relative_eq!(output_slow_ma[i], expected_slow[i], epsilon = 0.00001);
+            assert_relative_eq!(
+                output_macd_line[i],
+                expected_fast[i] - expected_slow[i],
+                epsilon = 0.00001
+            );
+        }
+
+        let mut stream = Macdext::new(6, MAType::DEMA, 3, MAType::SMA, 4, MAType::WMA).unwrap();
+        for (i, &price) in INPUT_PRICE.iter().enumerate() {
+            if let Some((macd, signal, _)) = stream.update(price).unwrap() {
+                assert_relative_eq!(macd, output_macd_line[i], epsilon = 0.00001);
+                assert_relative_eq!(signal, output_signal_line[i], epsilon = 0.00001);
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
@@ -0,0 +1,409 @@
+use super::ht_core::{self, HtState};
+use crate::{Indicator, KandError, TAFloat};
+
+/// Number of smoother-only bars before the Hilbert stages start, see [`HtState::new`].
+pub const WARMUP: usize = ht_core::SHORT_WARMUP;
+
+const LOOKBACK: usize = 32;
+
+/// Returns the lookback period required for MESA Adaptive Moving Average (MAMA) calculation.
+///
+/// MAMA is driven by the Hilbert Transform core shared with `HT_DCPERIOD`, so the lookback is
+/// fixed at 32 bars regardless of the limits, matching TA-Lib.
+///
+/// # Arguments
+/// * `param_fast_limit` - Upper limit of the adaptive alpha (0.01 to 0.99, typically 0.5)
+/// * `param_slow_limit` - Lower limit of the adaptive alpha (0.01 to 0.99, typically 0.05)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The number of bars needed before the first valid output
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If either limit is outside the range 0.01 to 0.99
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::mama;
+///
+/// let lookback = mama::lookback(0.5, 0.05).unwrap();
+/// assert_eq!(lookback, 32);
+/// ```
+pub fn lookback(param_fast_limit: TAFloat, param_slow_limit: TAFloat) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if !(0.01..=0.99).contains(&param_fast_limit) || !(0.01..=0.99).contains(&param_slow_limit)
+        {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(LOOKBACK)
+}
+
+/// Calculates MESA Adaptive Moving Average (MAMA) and Following Adaptive Moving Average (FAMA)
+/// for a price series.
+///
+/// MAMA is John Ehlers' EMA whose alpha follows the rate of change of the Hilbert Transform
+/// phase: when the phase advances quickly the market is cycling fast and the average speeds up
+/// towards `param_fast_limit`, otherwise it slows down towards `param_slow_limit`. FAMA applies
+/// half of that alpha to MAMA itself, and crossings of the two lines are used as signals.
+///
+/// # Mathematical Formula
+/// ```text
+/// Phase = atan(Q1 / I1), from the Hilbert Transform core
+/// DeltaPhase = max(Phase[t-1] - Phase, 1)
+/// Alpha = max(FastLimit / DeltaPhase, SlowLimit)  (FastLimit if DeltaPhase = 1)
+/// MAMA = Alpha * Price + (1 - Alpha) * MAMA[t-1]
+/// FAMA = 0.5 * Alpha * MAMA + (1 - 0.5 * Alpha) * FAMA[t-1]
+/// ```
+///
+/// # Calculation Principle
+/// 1. Feed every price through the shared Hilbert Transform core
+/// 2. Measure the phase of the in-phase/quadrature phasor and its change since the last bar
+/// 3. Derive the adaptive alpha from the phase change, bounded by the two limits
+/// 4. Update MAMA from the price and FAMA from MAMA, both starting from zero
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices
+/// * `param_fast_limit` - Upper limit of the adaptive alpha (0.01 to 0.99, typically 0.5)
+/// * `param_slow_limit` - Lower limit of the adaptive alpha (0.01 to 0.99, typically 0.05)
+/// * `output_mama` - Array to store the MAMA values
+/// * `output_fama` - Array to store the FAMA values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty result on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If output arrays length doesn't match input
+/// * `KandError::InvalidParameter` - If either limit is outside the range 0.01 to 0.99
+/// * `KandError::InsufficientData` - If input length is less than or equal to lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::mama;
+///
+/// let input_prices: Vec<f64> = (0..64).map(|i| 100.0 + (i as f64 * 0.3).sin()).collect();
+/// let mut output_mama = vec![0.0; input_prices.len()];
+/// let mut output_fama = vec![0.0; input_prices.len()];
+///
+/// mama::mama(&input_prices, 0.5, 0.05, &mut output_mama, &mut output_fama).unwrap();
+/// assert!(output_mama[31].is_nan());
+/// assert!(output_fama[32].is_finite());
+/// ```
+pub fn mama(
+    input_prices: &[TAFloat],
+    param_fast_limit: TAFloat,
+    param_slow_limit: TAFloat,
+    output_mama: &mut [TAFloat],
+    output_fama: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(param_fast_limit, param_slow_limit)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if output_mama.len() != len || output_fama.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut state = HtState::new(WARMUP);
+    let mut prev_mama = 0.0;
+    let mut prev_fama = 0.0;
+    for (i, price) in input_prices.iter().enumerate() {
+        let (mama, fama) = mama_inc(
+            *price,
+            prev_mama,
+            prev_fama,
+            param_fast_limit,
+            param_slow_limit,
+            &mut state,
+        )?;
+        output_mama[i] = mama;
+        output_fama[i] = fama;
+        prev_mama = mama;
+        prev_fama = fama;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_mama[i] = TAFloat::NAN;
+        output_fama[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next MAMA and FAMA values incrementally.
+///
+/// The phase history lives in [`HtState`], which must be created with [`WARMUP`] and fed from
+/// the first bar of the series. Unlike the batch function, the values within the lookback
+/// period are returned as they are (starting from `prev_mama = prev_fama = 0.0`) because the
+/// next bar builds on them.
+///
+/// # Arguments
+/// * `input_price` - The current price
+/// * `prev_mama` - The previous MAMA value (0.0 for the first bar)
+/// * `prev_fama` - The previous FAMA value (0.0 for the first bar)
+/// * `param_fast_limit` - Upper limit of the adaptive alpha (0.01 to 0.99, typically 0.5)
+/// * `param_slow_limit` - Lower limit of the adaptive alpha (0.01 to 0.99, typically 0.05)
+/// * `state` - Hilbert Transform state, updated in place
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple of (MAMA, FAMA)
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If either limit is outside the range 0.01 to 0.99, or if
+///   `state` was not created with [`WARMUP`]
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is
+///   enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::{ht_core::HtState, mama};
+///
+/// let mut state = HtState::new(mama::WARMUP);
+/// let (mut prev_mama, mut prev_fama) = (0.0, 0.0);
+/// for i in 0..40 {
+///     let price = 100.0 + (i as f64 * 0.3).sin();
+///     (prev_mama, prev_fama) =
+///         mama::mama_inc(price, prev_mama, prev_fama, 0.5, 0.05, &mut state).unwrap();
+/// }
+/// assert!(prev_mama > 90.0 && prev_fama > 0.0);
+/// ```
+pub fn mama_inc(
+    input_price: TAFloat,
+    prev_mama: TAFloat,
+    prev_fama: TAFloat,
+    param_fast_limit: TAFloat,
+    param_slow_limit: TAFloat,
+    state: &mut HtState,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if !(0.01..=0.99).contains(&param_fast_limit)
+            || !(0.01..=0.99).contains(&param_slow_limit)
+            || state.warmup() != WARMUP
+        {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() || prev_mama.is_nan() || prev_fama.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let prev_phase = state.phasor_phase();
+    if !state.next(input_price) {
+        return Ok((prev_mama, prev_fama));
+    }
+
+    let delta_phase = (prev_phase - state.phasor_phase()).max(1.0);
+    let alpha = if delta_phase > 1.0 {
+        (param_fast_limit / delta_phase).max(param_slow_limit)
+    } else {
+        param_fast_limit
+    };
+
+    let output_mama = alpha * input_price + (1.0 - alpha) * prev_mama;
+    let half_alpha = alpha * 0.5;
+    let output_fama = half_alpha * output_mama + (1.0 - half_alpha) * prev_fama;
+    Ok((output_mama, output_fama))
+}
+
+/// Streaming MESA Adaptive Moving Average (MAMA) calculator.
+///
+/// Owns the [`HtState`] and the previous MAMA/FAMA values and forwards every bar to
+/// [`mama_inc`]. Emits `(mama, fama)` once the lookback period has passed.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::mama::Mama};
+///
+/// let mut mama = Mama::new(0.5, 0.05).unwrap();
+/// for i in 0..32 {
+///     assert!(
+///         mama.update(100.0 + (i as f64 * 0.3).sin())
+///             .unwrap()
+///             .is_none()
+///     );
+/// }
+/// assert!(mama.update(100.0).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mama {
+    param_fast_limit: TAFloat,
+    param_slow_limit: TAFloat,
+    state:            HtState,
+    prev_mama:        TAFloat,
+    prev_fama:        TAFloat,
+}
+
+impl Mama {
+    /// Creates a new streaming MAMA.
+    ///
+    /// # Arguments
+    /// * `param_fast_limit` - Upper limit of the adaptive alpha (0.01 to 0.99, typically 0.5)
+    /// * `param_slow_limit` - Lower limit of the adaptive alpha (0.01 to 0.99, typically 0.05)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If either limit is outside the range 0.01 to 0.99
+    pub fn new(param_fast_limit: TAFloat, param_slow_limit: TAFloat) -> Result<Self, KandError> {
+        lookback(param_fast_limit, param_slow_limit)?;
+        Ok(Self {
+            param_fast_limit,
+            param_slow_limit,
+            state: HtState::new(WARMUP),
+            prev_mama: 0.0,
+            prev_fama: 0.0,
+        })
+    }
+}
+
+impl Indicator for Mama {
+    type Input = TAFloat;
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<(TAFloat, TAFloat)>, KandError> {
+        let (output_mama, output_fama) = mama_inc(
+            input_price,
+            self.prev_mama,
+            self.prev_fama,
+            self.param_fast_limit,
+            self.param_slow_limit,
+            &mut self.state,
+        )?;
+        self.prev_mama = output_mama;
+        self.prev_fama = output_fama;
+        Ok(self.is_ready().then_some((output_mama, output_fama)))
+    }
+
+    fn reset(&mut self) {
+        self.state.reset();
+        self.prev_mama = 0.0;
+        self.prev_fama = 0.0;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.state.bars() > LOOKBACK
+    }
+
+    fn lookback(&self) -> usize {
+        LOOKBACK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    // Basic functionality tests
+    #[test]
+    fn test_mama_calculation() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+            35154.0, 35216.3, 35211.8, 35158.4, 35172.0, 35176.7, 35113.3, 35114.7, 35129.3,
+            35094.6, 35114.4, 35094.5, 35116.0, 35105.4,
+        ];
+        let mut output_mama = vec![0.0; input_prices.len()];
+        let mut output_fama = vec![0.0; input_prices.len()];
+
+        mama(&input_prices, 0.5, 0.05, &mut output_mama, &mut output_fama).unwrap();
+
+        // First 32 values should be NaN
+        for i in 0..32 {
+            assert!(output_mama[i].is_nan());
+            assert!(output_fama[i].is_nan());
+        }
+
+        // Verify against known values
+        assert_relative_eq!(output_mama[32], 35058.56583210485, epsilon = 0.00001);
+        assert_relative_eq!(output_fama[32], 32806.30997914311, epsilon = 0.00001);
+        assert_relative_eq!(output_mama[33], 35061.8775404996, epsilon = 0.00001);
+        assert_relative_eq!(output_fama[33], 32862.69916817702, epsilon = 0.00001);
+        assert_relative_eq!(output_mama[49], 35121.870510763445, epsilon = 0.00001);
+        assert_relative_eq!(output_fama[49], 34862.40544470415, epsilon = 0.00001);
+
+        // Now test incremental calculation matches regular calculation
+        let mut state = HtState::new(WARMUP);
+        let mut prev_mama = 0.0;
+        let mut prev_fama = 0.0;
+        for (i, price) in input_prices.iter().enumerate() {
+            let (result_mama, result_fama) =
+                mama_inc(*price, prev_mama, prev_fama, 0.5, 0.05, &mut state).unwrap();
+            if i >= 32 {
+                assert_relative_eq!(result_mama, output_mama[i], epsilon = 0.00001);
+                assert_relative_eq!(result_fama, output_fama[i], epsilon = 0.00001);
+            }
+            prev_mama = result_mama;
+            prev_fama = result_fama;
+        }
+    }
+
+    #[test]
+    fn test_mama_invalid_limits() {
+        let mut state = HtState::new(WARMUP);
+        assert!(matches!(
+            mama_inc(100.0, 0.0, 0.0, 1.0, 0.05, &mut state),
+            Err(KandError::InvalidParameter)
+        ));
+        assert!(Mama::new(0.5, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_mama_stream() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0, 35114.5, 35097.2,
+            35092.0, 35073.2, 35139.3, 35092.0, 35126.7, 35106.3, 35124.8, 35170.1, 35215.3,
+        ];
+        let mut output_mama = vec![0.0; input_prices.len()];
+        let mut output_fama = vec![0.0; input_prices.len()];
+
+        mama(&input_prices, 0.5, 0.05, &mut output_mama, &mut output_fama).unwrap();
+
+        let mut stream = Mama::new(0.5, 0.05).unwrap();
+        for (i, price) in input_prices.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some((value_mama, value_fama)) => {
+                    assert_relative_eq!(value_mama, output_mama[i], epsilon = 0.00001);
+                    assert_relative_eq!(value_fama, output_fama[i], epsilon = 0.00001);
+                }
+                None => assert!(output_mama[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat, ta::ohlcv::typprice};
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat, ta::ohlcv::typprice};
 
 /// Calculates the lookback period required for Money Flow Index (MFI) calculation.
 ///
@@ -58,8 +60,11 @@ pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
 /// * `output_mfi` - Array to store the calculated MFI values (0-100)
 /// * `output_typ_prices` - Array to store the calculated typical prices
 /// * `output_money_flows` - Array to store the calculated money flows
-/// * `output_pos_flows` - Array to store the positive money flows
-/// * `output_neg_flows` - Array to store the negative money flows
+/// * `output_pos_flows` - Array to store the rolling sum of positive money flows
+/// * `output_neg_flows` - Array to store the rolling sum of negative money flows
+///
+/// Typical prices and money flows are defined from the first bar, so only the MFI and flow
+/// sums carry NaN during the warm-up. Together they are the state [`mfi_inc`] continues from.
 ///
 /// # Returns
 /// * `Result<(), KandError>` - Empty result on success
@@ -151,45 +156,269 @@ pub fn mfi(
         output_money_flows[i] = output_typ_prices[i] * input_volume[i];
     }
 
-    // Calculate MFI for each period
-    for i in param_period..len {
-        let mut pos_flow = 0.0;
-        let mut neg_flow = 0.0;
-
-        // Calculate positive and negative money flows over the period
-        for j in (i - param_period + 1)..=i {
-            if output_typ_prices[j] > output_typ_prices[j - 1] {
-                pos_flow += output_money_flows[j];
-            } else if output_typ_prices[j] < output_typ_prices[j - 1] {
-                neg_flow += output_money_flows[j];
-            }
+    // Roll the positive and negative flow sums over the period
+    let mut pos_flow = 0.0;
+    let mut neg_flow = 0.0;
+    for i in 1..len {
+        let (new_pos, new_neg) = split_money_flow(
+            output_typ_prices[i],
+            output_typ_prices[i - 1],
+            output_money_flows[i],
+        );
+        if i <= param_period {
+            pos_flow += new_pos;
+            neg_flow += new_neg;
+        } else {
+            let old = i - param_period;
+            let (old_pos, old_neg) = split_money_flow(
+                output_typ_prices[old],
+                output_typ_prices[old - 1],
+                output_money_flows[old],
+            );
+            pos_flow = pos_flow + new_pos - old_pos;
+            neg_flow = neg_flow + new_neg - old_neg;
         }
 
-        output_pos_flows[i] = pos_flow;
-        output_neg_flows[i] = neg_flow;
-
-        // Calculate MFI using the optimized formula:
-        // MFI = 100 * (posSumMF/(posSumMF+negSumMF))
-        let total_flow = pos_flow + neg_flow;
-        if total_flow < 1.0 {
-            output_mfi[i] = 0.0;
-        } else {
-            output_mfi[i] = 100.0 * (pos_flow / total_flow);
+        if i >= param_period {
+            output_pos_flows[i] = pos_flow;
+            output_neg_flows[i] = neg_flow;
+            output_mfi[i] = mfi_from_flows(pos_flow, neg_flow);
         }
     }
 
     // Set initial values to NaN
-    for i in 0..param_period {
+    for i in 0..param_period.min(len) {
         output_mfi[i] = TAFloat::NAN;
         output_pos_flows[i] = TAFloat::NAN;
         output_neg_flows[i] = TAFloat::NAN;
-        output_typ_prices[i] = TAFloat::NAN;
-        output_money_flows[i] = TAFloat::NAN;
     }
 
     Ok(())
 }
 
+/// Calculates the next Money Flow Index value incrementally.
+///
+/// # Description
+/// Rolls the positive and negative flow sums by one bar: the new bar's money flow enters the
+/// window on the side its typical price moved to, and the money flow of the bar `param_period`
+/// bars back leaves it. The arithmetic matches [`mfi`] step for step, so continuing from the
+/// batch outputs reproduces them exactly.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `input_volume` - Current volume
+/// * `prev_typ_price` - Previous typical price
+/// * `input_old_typ_price` - Typical price of the bar leaving the window, `param_period` bars back
+/// * `input_old_prev_typ_price` - Typical price of the bar before the one leaving the window
+/// * `input_old_money_flow` - Money flow of the bar leaving the window
+/// * `prev_pos_flow` - Previous rolling sum of positive money flow
+/// * `prev_neg_flow` - Previous rolling sum of negative money flow
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple of
+///   (MFI, typical price, money flow, positive flow sum, negative flow sum)
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Examples
+/// ```
+/// use kand::ohlcv::mfi;
+///
+/// // Period 2: the window moves from bars [1, 2] to bars [2, 3]
+/// let (output_mfi, typ_price, money_flow, pos_flow, neg_flow) = mfi::mfi_inc(
+///     11.0,   // high
+///     9.0,    // low
+///     10.0,   // close
+///     150.0,  // volume
+///     11.0,   // previous typical price
+///     10.0,   // typical price of bar 1
+///     9.0,    // typical price of bar 0
+///     1500.0, // money flow of bar 1
+///     3700.0, // previous positive flow sum
+///     0.0,    // previous negative flow sum
+/// )
+/// .unwrap();
+///
+/// assert_eq!((typ_price, money_flow), (10.0, 1500.0));
+/// assert_eq!((pos_flow, neg_flow), (2200.0, 1500.0));
+/// assert!((output_mfi - 100.0 * 2200.0 / 3700.0).abs() < 1e-9);
+/// ```
+pub fn mfi_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    input_volume: TAFloat,
+    prev_typ_price: TAFloat,
+    input_old_typ_price: TAFloat,
+    input_old_prev_typ_price: TAFloat,
+    input_old_money_flow: TAFloat,
+    prev_pos_flow: TAFloat,
+    prev_neg_flow: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_volume.is_nan()
+            || prev_typ_price.is_nan()
+            || input_old_typ_price.is_nan()
+            || input_old_prev_typ_price.is_nan()
+            || input_old_money_flow.is_nan()
+            || prev_pos_flow.is_nan()
+            || prev_neg_flow.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let typ_price = typprice::typprice_inc(input_high, input_low, input_close)?;
+    let money_flow = typ_price * input_volume;
+    let (new_pos, new_neg) = split_money_flow(typ_price, prev_typ_price, money_flow);
+    let (old_pos, old_neg) = split_money_flow(
+        input_old_typ_price,
+        input_old_prev_typ_price,
+        input_old_money_flow,
+    );
+    let pos_flow = prev_pos_flow + new_pos - old_pos;
+    let neg_flow = prev_neg_flow + new_neg - old_neg;
+
+    Ok((
+        mfi_from_flows(pos_flow, neg_flow),
+        typ_price,
+        money_flow,
+        pos_flow,
+        neg_flow,
+    ))
+}
+
+/// Assigns a bar's money flow to the positive or negative side by its typical price move.
+fn split_money_flow(
+    typ_price: TAFloat,
+    prev_typ_price: TAFloat,
+    money_flow: TAFloat,
+) -> (TAFloat, TAFloat) {
+    if typ_price > prev_typ_price {
+        (money_flow, 0.0)
+    } else if typ_price < prev_typ_price {
+        (0.0, money_flow)
+    } else {
+        (0.0, 0.0)
+    }
+}
+
+/// MFI = 100 * (posSumMF/(posSumMF+negSumMF)), 0 when almost no flow traded.
+fn mfi_from_flows(pos_flow: TAFloat, neg_flow: TAFloat) -> TAFloat {
+    let total_flow = pos_flow + neg_flow;
+    if total_flow < 1.0 {
+        0.0
+    } else {
+        100.0 * (pos_flow / total_flow)
+    }
+}
+
+/// Streaming Money Flow Index (MFI) calculator.
+///
+/// Consumes `(high, low, close, volume)` bars and keeps the signed money flows of the last
+/// `param_period` bars with their running sums, so each bar costs O(1) instead of a window
+/// recomputation. The sums are rolled with the same arithmetic as [`mfi`], so the stream
+/// reproduces the batch output exactly.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::mfi::Mfi};
+///
+/// let mut mfi = Mfi::new(2).unwrap();
+/// assert_eq!(mfi.update((10.0, 8.0, 9.0, 100.0)).unwrap(), None);
+/// assert_eq!(mfi.update((11.0, 9.0, 10.0, 150.0)).unwrap(), None);
+/// assert_eq!(mfi.update((12.0, 10.0, 11.0, 200.0)).unwrap(), Some(100.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mfi {
+    param_period:   usize,
+    prev_typ_price: Option<TAFloat>,
+    window:         VecDeque<(TAFloat, TAFloat)>,
+    pos_flow:       TAFloat,
+    neg_flow:       TAFloat,
+}
+
+impl Mfi {
+    /// Creates a new streaming MFI.
+    ///
+    /// # Arguments
+    /// * `param_period` - The time period for MFI calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            prev_typ_price: None,
+            window: VecDeque::with_capacity(param_period),
+            pos_flow: 0.0,
+            neg_flow: 0.0,
+        })
+    }
+}
+
+impl Indicator for Mfi {
+    type Input = (TAFloat, TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close, input_volume): (TAFloat, TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        #[cfg(feature = "deep-check")]
+        {
+            // NaN check
+            if input_volume.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        let typ_price = typprice::typprice_inc(input_high, input_low, input_close)?;
+        let Some(prev_typ_price) = self.prev_typ_price.replace(typ_price) else {
+            return Ok(None);
+        };
+
+        let (new_pos, new_neg) =
+            split_money_flow(typ_price, prev_typ_price, typ_price * input_volume);
+        if self.window.len() == self.param_period {
+            let (old_pos, old_neg) = self.window.pop_front().unwrap_or((0.0, 0.0));
+            self.pos_flow = self.pos_flow + new_pos - old_pos;
+            self.neg_flow = self.neg_flow + new_neg - old_neg;
+        } else {
+            self.pos_flow += new_pos;
+            self.neg_flow += new_neg;
+        }
+        self.window.push_back((new_pos, new_neg));
+
+        if self.is_ready() {
+            Ok(Some(mfi_from_flows(self.pos_flow, self.neg_flow)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn reset(&mut self) {
+        self.prev_typ_price = None;
+        self.window.clear();
+        self.pos_flow = 0.0;
+        self.neg_flow = 0.0;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.param_period
+    }
+
+    fn lookback(&self) -> usize {
+        self.param_period
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -271,5 +500,56 @@ fn test_mfi_calculation() {
         for (i, expected) in expected_values.iter().enumerate() {
             assert_relative_eq!(output_mfi[i + 14], *expected, epsilon = 0.0001);
         }
+
+        // Incremental calculation continues from the batch state and matches it exactly
+        let mut prev_pos_flow = output_pos_flows[param_period];
+        let mut prev_neg_flow = output_neg_flows[param_period];
+        for i in param_period + 1..input_high.len() {
+            let old = i - param_period;
+            let (result, typ_price, money_flow, pos_flow, neg_flow) = mfi_inc(
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_volume[i],
+                output_typ_prices[i - 1],
+                output_typ_prices[old],
+                output_typ_prices[old - 1],
+                output_money_flows[old],
+                prev_pos_flow,
+                prev_neg_flow,
+            )
+            .unwrap();
+            assert_eq!(typ_price, output_typ_prices[i]);
+            assert_eq!(money_flow, output_money_flows[i]);
+            assert_eq!(pos_flow, output_pos_flows[i]);
+            assert_eq!(neg_flow, output_neg_flows[i]);
+            assert_eq!(result, output_mfi[i]);
+            prev_pos_flow = pos_flow;
+            prev_neg_flow = neg_flow;
+        }
+
+        // Streaming calculation matches the batch output exactly
+        let mut stream = Mfi::new(param_period).unwrap();
+        assert_eq!(stream.lookback(), param_period);
+        for i in 0..input_high.len() {
+            let value = stream
+                .update((input_high[i], input_low[i], input_close[i], input_volume[i]))
+                .unwrap();
+            if i < param_period {
+                assert_eq!(value, None);
+            } else {
+                assert_eq!(value, Some(output_mfi[i]));
+            }
+        }
+        assert!(stream.is_ready());
+
+        stream.reset();
+        assert!(!stream.is_ready());
+        assert_eq!(
+            stream
+                .update((input_high[0], input_low[0], input_close[0], input_volume[0]))
+                .unwrap(),
+            None
+        );
     }
 }
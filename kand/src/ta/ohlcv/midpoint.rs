@@ -1,4 +1,12 @@
-use crate::{KandError, TAFloat};
+use crate::{
+    Indicator,
+    KandError,
+    TAFloat,
+    ta::stats::{
+        max::{self, Max},
+        min::{self, Min},
+    },
+};
 
 /// Calculates the lookback period required for Midpoint calculation.
 ///
@@ -160,47 +168,94 @@ pub fn midpoint(
 ///
 /// # Description
 /// Provides an optimized way to calculate the next Midpoint value when new data arrives,
-/// without recalculating the entire series. Updates the highest and lowest values with new price.
+/// without recalculating the entire series. The highest and lowest values are tracked by
+/// rolling monotonic deques updated in place, so they stay exact when the previous extreme
+/// leaves the window.
 ///
 /// # Mathematical Formula
 /// ```text
 /// MIDPOINT = (Highest Price + Lowest Price) / 2
 /// ```
 /// Where:
-/// - Highest Price = max(current price, previous highest)
-/// - Lowest Price = min(current price, previous lowest)
+/// - Highest Price = max(price[i-n+1...i])
+/// - Lowest Price = min(price[i-n+1...i])
 ///
 /// # Calculation Steps
-/// 1. Compare new price with previous highest/lowest
-/// 2. Update highest/lowest values
+/// 1. Push the new price into the rolling highest/lowest windows
+/// 2. Read the updated highest/lowest values
 /// 3. Calculate new midpoint
 ///
 /// # Arguments
 /// * `input_price` - Current price value
-/// * `prev_highest` - Previous highest value
-/// * `prev_lowest` - Previous lowest value
-/// * `param_period` - Time period for calculation (must be >= 2)
+/// * `rolling_highest` - Rolling window of the last `param_period` prices for the highest value
+/// * `rolling_lowest` - Rolling window of the last `param_period` prices for the lowest value
 ///
 /// # Returns
 /// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple (midpoint, `new_highest`, `new_lowest`) on success
 ///
 /// # Errors
-/// * `KandError::InvalidParameter` - If `param_period` is less than 2
 /// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
 ///
 /// # Examples
 /// ```
-/// use kand::ohlcv::midpoint;
+/// use kand::{
+///     ohlcv::midpoint,
+///     stats::{max::Max, min::Min},
+/// };
 ///
-/// let current_price = 15.0;
-/// let prev_highest = 16.0;
-/// let prev_lowest = 14.0;
-/// let period = 14;
+/// let mut rolling_highest = Max::new(3).unwrap();
+/// let mut rolling_lowest = Min::new(3).unwrap();
+/// for price in [16.0, 14.0, 15.0] {
+///     midpoint::midpoint_inc(price, &mut rolling_highest, &mut rolling_lowest).unwrap();
+/// }
 ///
+/// // 16.0 has left the window
 /// let (midpoint, new_highest, new_lowest) =
-///     midpoint::midpoint_inc(current_price, prev_highest, prev_lowest, period).unwrap();
+///     midpoint::midpoint_inc(15.0, &mut rolling_highest, &mut rolling_lowest).unwrap();
+/// assert_eq!((midpoint, new_highest, new_lowest), (14.5, 15.0, 14.0));
 /// ```
 pub fn midpoint_inc(
+    input_price: TAFloat,
+    rolling_highest: &mut Max,
+    rolling_lowest: &mut Min,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    let new_highest = max::max_inc(input_price, rolling_highest)?;
+    let new_lowest = min::min_inc(input_price, rolling_lowest)?;
+    let midpoint = (new_highest + new_lowest) / 2.0;
+
+    Ok((midpoint, new_highest, new_lowest))
+}
+
+/// Updates the Midpoint from the previous highest and lowest prices.
+///
+/// This is the scalar form that predates [`Midpoint`]. It never drops an extreme that leaves
+/// the window, so it tracks the all-time range rather than the last `param_period` prices.
+///
+/// # Arguments
+/// * `input_price` - Current price
+/// * `prev_highest` - Previous highest price
+/// * `prev_lowest` - Previous lowest price
+/// * `param_period` - The lookback period (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (midpoint, highest, lowest)
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// # #![allow(deprecated)]
+/// use kand::ohlcv::midpoint;
+///
+/// let (output, ..) = midpoint::midpoint_inc_legacy(15.0, 16.0, 10.0, 14).unwrap();
+/// assert_eq!(output, 13.0);
+/// ```
+#[deprecated(
+    note = "never drops an extreme that leaves the window; use `midpoint_inc` with `Max`/`Min`"
+)]
+pub fn midpoint_inc_legacy(
     input_price: TAFloat,
     prev_highest: TAFloat,
     prev_lowest: TAFloat,
@@ -227,6 +282,69 @@ pub fn midpoint_inc(
     Ok((midpoint, new_highest, new_lowest))
 }
 
+/// Streaming Midpoint calculator.
+///
+/// Owns the rolling highest and lowest windows used by [`midpoint_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::midpoint::Midpoint};
+///
+/// let mut midpoint = Midpoint::new(2).unwrap();
+/// assert_eq!(midpoint.update(16.0).unwrap(), None);
+/// assert_eq!(midpoint.update(14.0).unwrap(), Some(15.0));
+/// assert_eq!(midpoint.update(13.0).unwrap(), Some(13.5));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Midpoint {
+    rolling_highest: Max,
+    rolling_lowest:  Min,
+}
+
+impl Midpoint {
+    /// Creates a new streaming Midpoint.
+    ///
+    /// # Arguments
+    /// * `param_period` - Time period for calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        lookback(param_period)?;
+        Ok(Self {
+            rolling_highest: Max::new(param_period)?,
+            rolling_lowest:  Min::new(param_period)?,
+        })
+    }
+}
+
+impl Indicator for Midpoint {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        let (output_midpoint, _, _) = midpoint_inc(
+            input_price,
+            &mut self.rolling_highest,
+            &mut self.rolling_lowest,
+        )?;
+        Ok(self.is_ready().then_some(output_midpoint))
+    }
+
+    fn reset(&mut self) {
+        self.rolling_highest.reset();
+        self.rolling_lowest.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.rolling_highest.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.rolling_highest.lookback()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -272,16 +390,28 @@ fn test_midpoint_calculation() {
         }
 
         // Test incremental calculation
-        let mut prev_highest = output_highest[13];
-        let mut prev_lowest = output_lowest[13];
+        let mut rolling_highest = Max::new(param_period).unwrap();
+        let mut rolling_lowest = Min::new(param_period).unwrap();
+        for price in &input_price[..param_period] {
+            midpoint_inc(*price, &mut rolling_highest, &mut rolling_lowest).unwrap();
+        }
 
-        for i in 14..19 {
+        for i in param_period..input_price.len() {
             let (midpoint, new_highest, new_lowest) =
-                midpoint_inc(input_price[i], prev_highest, prev_lowest, param_period).unwrap();
+                midpoint_inc(input_price[i], &mut rolling_highest, &mut rolling_lowest).unwrap();
 
             assert_relative_eq!(midpoint, output_midpoint[i], epsilon = 0.01);
-            prev_highest = new_highest;
-            prev_lowest = new_lowest;
+            assert_relative_eq!(new_highest, output_highest[i], epsilon = 0.01);
+            assert_relative_eq!(new_lowest, output_lowest[i], epsilon = 0.01);
+        }
+
+        // Streaming calculation matches the batch output bar for bar
+        let mut stream = Midpoint::new(param_period).unwrap();
+        for (i, price) in input_price.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_midpoint[i], epsilon = 0.01),
+                None => assert!(output_midpoint[i].is_nan()),
+            }
         }
     }
 }
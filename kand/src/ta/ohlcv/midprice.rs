@@ -1,7 +1,12 @@
 use crate::{
+    Indicator,
     TAFloat,
     error::KandError,
     helper::{highest_bars, lowest_bars},
+    ta::stats::{
+        max::{self, Max},
+        min::{self, Min},
+    },
 };
 
 /// Calculates the lookback period required for Midpoint Price calculation.
@@ -149,36 +154,87 @@ pub fn midprice(
 /// Incrementally calculates the next Midpoint Price value.
 ///
 /// Provides optimized calculation of the next value when new data arrives, avoiding
-/// recalculation of the entire series.
+/// recalculation of the entire series. The highest high and lowest low are tracked by rolling
+/// monotonic deques updated in place, so they stay exact when the previous extreme leaves the
+/// window.
 ///
 /// # Arguments
 /// * `input_high` - Current high price
 /// * `input_low` - Current low price
-/// * `prev_highest_high` - Previous period's highest high
-/// * `prev_lowest_low` - Previous period's lowest low
-/// * `param_period` - Calculation period (must be >= 2)
+/// * `rolling_high` - Rolling window of the last `param_period` highs, updated in place
+/// * `rolling_low` - Rolling window of the last `param_period` lows, updated in place
 ///
 /// # Returns
 /// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Returns (midprice, `new_highest_high`, `new_lowest_low`)
 ///
 /// # Errors
-/// * `KandError::InvalidParameter` - If period is less than 2
 /// * `KandError::NaNDetected` - If any input contains NaN (with `deep-check`)
 ///
 /// # Example
 /// ```
-/// use kand::ohlcv::midprice;
+/// use kand::{
+///     ohlcv::midprice,
+///     stats::{max::Max, min::Min},
+/// };
+///
+/// let mut rolling_high = Max::new(14).unwrap();
+/// let mut rolling_low = Min::new(14).unwrap();
+/// midprice::midprice_inc(10.2, 9.5, &mut rolling_high, &mut rolling_low).unwrap();
 ///
 /// let (midprice, highest, lowest) = midprice::midprice_inc(
 ///     10.5, // current high
 ///     9.8,  // current low
-///     10.2, // previous highest high
-///     9.5,  // previous lowest low
-///     14,   // period
+///     &mut rolling_high,
+///     &mut rolling_low,
 /// )
 /// .unwrap();
+/// assert_eq!((highest, lowest), (10.5, 9.5));
 /// ```
 pub fn midprice_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    rolling_high: &mut Max,
+    rolling_low: &mut Min,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    let new_highest_high = max::max_inc(input_high, rolling_high)?;
+    let new_lowest_low = min::min_inc(input_low, rolling_low)?;
+    let midprice = (new_highest_high + new_lowest_low) / 2.0;
+
+    Ok((midprice, new_highest_high, new_lowest_low))
+}
+
+/// Updates the Midprice from the previous highest high and lowest low.
+///
+/// This is the scalar form that predates [`Midprice`]. It never drops an extreme that leaves
+/// the window, so it tracks the all-time range rather than the last `param_period` bars.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `prev_highest_high` - Previous highest high
+/// * `prev_lowest_low` - Previous lowest low
+/// * `param_period` - The lookback period (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of
+///   (midprice, highest high, lowest low)
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// # #![allow(deprecated)]
+/// use kand::ohlcv::midprice;
+///
+/// let (output, ..) = midprice::midprice_inc_legacy(10.5, 9.8, 10.2, 9.5, 14).unwrap();
+/// assert_eq!(output, 10.0);
+/// ```
+#[deprecated(
+    note = "never drops an extreme that leaves the window; use `midprice_inc` with `Max`/`Min`"
+)]
+pub fn midprice_inc_legacy(
     input_high: TAFloat,
     input_low: TAFloat,
     prev_highest_high: TAFloat,
@@ -210,6 +266,73 @@ pub fn midprice_inc(
     Ok((midprice, new_highest_high, new_lowest_low))
 }
 
+/// Streaming Midpoint Price calculator.
+///
+/// Consumes `(high, low)` bars and owns the rolling windows used by [`midprice_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::midprice::Midprice};
+///
+/// let mut midprice = Midprice::new(2).unwrap();
+/// assert_eq!(midprice.update((10.0, 8.0)).unwrap(), None);
+/// assert_eq!(midprice.update((11.0, 9.0)).unwrap(), Some(9.5));
+/// assert_eq!(midprice.update((10.5, 9.5)).unwrap(), Some(10.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Midprice {
+    rolling_high: Max,
+    rolling_low:  Min,
+}
+
+impl Midprice {
+    /// Creates a new streaming Midpoint Price.
+    ///
+    /// # Arguments
+    /// * `param_period` - Calculation period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        lookback(param_period)?;
+        Ok(Self {
+            rolling_high: Max::new(param_period)?,
+            rolling_low:  Min::new(param_period)?,
+        })
+    }
+}
+
+impl Indicator for Midprice {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low): (TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        let (output_midprice, _, _) = midprice_inc(
+            input_high,
+            input_low,
+            &mut self.rolling_high,
+            &mut self.rolling_low,
+        )?;
+        Ok(self.is_ready().then_some(output_midprice))
+    }
+
+    fn reset(&mut self) {
+        self.rolling_high.reset();
+        self.rolling_low.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.rolling_high.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.rolling_high.lookback()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -261,22 +384,39 @@ fn test_midprice_calculation() {
         }
 
         // Test incremental calculation
-        let mut prev_highest_high = output_highest_high[13];
-        let mut prev_lowest_low = output_lowest_low[13];
+        let mut rolling_high = Max::new(param_period).unwrap();
+        let mut rolling_low = Min::new(param_period).unwrap();
+        for i in 0..param_period {
+            midprice_inc(
+                input_high[i],
+                input_low[i],
+                &mut rolling_high,
+                &mut rolling_low,
+            )
+            .unwrap();
+        }
 
-        for i in 14..19 {
+        for i in param_period..input_high.len() {
             let (midprice, new_highest_high, new_lowest_low) = midprice_inc(
                 input_high[i],
                 input_low[i],
-                prev_highest_high,
-                prev_lowest_low,
-                param_period,
+                &mut rolling_high,
+                &mut rolling_low,
             )
             .unwrap();
 
             assert_relative_eq!(midprice, output_midprice[i], epsilon = 0.0001);
-            prev_highest_high = new_highest_high;
-            prev_lowest_low = new_lowest_low;
+            assert_relative_eq!(new_highest_high, output_highest_high[i], epsilon = 0.0001);
+            assert_relative_eq!(new_lowest_low, output_lowest_low[i], epsilon = 0.0001);
+        }
+
+        // Streaming calculation matches the batch output bar for bar
+        let mut stream = Midprice::new(param_period).unwrap();
+        for i in 0..input_high.len() {
+            match stream.update((input_high[i], input_low[i])).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_midprice[i], epsilon = 0.0001),
+                None => assert!(output_midprice[i].is_nan()),
+            }
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Calculates the lookback period required for Minus Directional Movement (-DM) calculation.
 ///
@@ -256,6 +256,116 @@ pub fn minus_dm_inc(
     Ok(prev_minus_dm - (prev_minus_dm / param_period as TAFloat) + dm)
 }
 
+/// Streaming Plus Directional Movement (-DM) calculator.
+///
+/// Consumes `(high, low)` bars and carries the previous high, low and smoothed -DM between
+/// bars. The first value is seeded with [`minus_dm`], later values come from [`minus_dm_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::minus_dm::MinusDm};
+///
+/// let mut minus_dm = MinusDm::new(2).unwrap();
+/// assert_eq!(minus_dm.update((10.0, 8.0)).unwrap(), None);
+/// assert_eq!(minus_dm.update((9.0, 6.0)).unwrap(), Some(2.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinusDm {
+    param_period:  usize,
+    lookback:      usize,
+    warmup_high:   Vec<TAFloat>,
+    warmup_low:    Vec<TAFloat>,
+    ready:         bool,
+    prev_high:     TAFloat,
+    prev_low:      TAFloat,
+    prev_minus_dm: TAFloat,
+}
+
+impl MinusDm {
+    /// Creates a new streaming -DM.
+    ///
+    /// # Arguments
+    /// * `param_period` - The smoothing period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            lookback,
+            warmup_high: Vec::with_capacity(lookback + 1),
+            warmup_low: Vec::with_capacity(lookback + 1),
+            ready: false,
+            prev_high: TAFloat::NAN,
+            prev_low: TAFloat::NAN,
+            prev_minus_dm: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for MinusDm {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low): (TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        if self.ready {
+            let output_dm = minus_dm_inc(
+                input_high,
+                self.prev_high,
+                input_low,
+                self.prev_low,
+                self.prev_minus_dm,
+                self.param_period,
+            )?;
+            self.prev_high = input_high;
+            self.prev_low = input_low;
+            self.prev_minus_dm = output_dm;
+            return Ok(Some(output_dm));
+        }
+
+        self.warmup_high.push(input_high);
+        self.warmup_low.push(input_low);
+        if self.warmup_high.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let mut output_dm = vec![0.0; self.warmup_high.len()];
+        minus_dm(
+            &self.warmup_high,
+            &self.warmup_low,
+            self.param_period,
+            &mut output_dm,
+        )?;
+        self.reset();
+        self.prev_high = input_high;
+        self.prev_low = input_low;
+        self.prev_minus_dm = output_dm[self.lookback];
+        self.ready = true;
+        Ok(Some(self.prev_minus_dm))
+    }
+
+    fn reset(&mut self) {
+        self.warmup_high.clear();
+        self.warmup_low.clear();
+        self.ready = false;
+        self.prev_high = TAFloat::NAN;
+        self.prev_low = TAFloat::NAN;
+        self.prev_minus_dm = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -328,4 +438,31 @@ fn test_minus_dm_calculation() {
             prev_dm = result;
         }
     }
+
+    #[test]
+    fn test_minus_dm_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let param_period = 14;
+        let mut output_dm = vec![0.0; input_high.len()];
+
+        minus_dm(&input_high, &input_low, param_period, &mut output_dm).unwrap();
+
+        let mut stream = MinusDm::new(param_period).unwrap();
+        for i in 0..input_high.len() {
+            match stream.update((input_high[i], input_low[i])).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_dm[i], epsilon = 0.00001),
+                None => assert!(output_dm[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
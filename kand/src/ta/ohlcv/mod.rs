@@ -3,10 +3,11 @@
 pub mod adr;
 pub mod adx;
 pub mod adxr;
-// pub mod apo;
+pub mod apo;
 pub mod aroon;
 pub mod aroonosc;
 pub mod atr;
+pub mod bandwidth;
 pub mod bbands;
 pub mod bop;
 pub mod cci;
@@ -18,26 +19,38 @@
 pub mod cdl_long_shadow;
 pub mod cdl_marubozu;
 // pub mod cdl_spinning_top;
-// pub mod cmo;
+pub mod cmf;
+pub mod cmo;
 pub mod dema;
+pub mod dmi;
+pub mod donchian;
 pub mod dx;
 pub mod ecl;
 pub mod ema;
+pub mod emv;
+pub mod force_index;
 pub mod ha;
-// pub mod ht_dcperiod;
-// pub mod ht_dcphase;
-// pub mod ht_phasor;
-// pub mod ht_sine;
-// pub mod ht_trendline;
-// pub mod ht_trendmode;
-// pub mod kama;
-// pub mod linearreg;
-// pub mod linearreg_angle;
-// pub mod linearreg_intercept;
-// pub mod linearreg_slope;
+pub mod ht_core;
+pub mod ht_dcperiod;
+pub mod ht_dcphase;
+pub mod ht_phasor;
+pub mod ht_sine;
+pub mod ht_trendline;
+pub mod ht_trendmode;
+pub mod ichimoku;
+pub mod kagi;
+pub mod kama;
+pub mod keltner;
+pub mod line_break;
+pub mod linearreg;
+pub mod linearreg_angle;
+pub mod linearreg_core;
+pub mod linearreg_intercept;
+pub mod linearreg_slope;
+pub mod ma;
 pub mod macd;
-// pub mod macdext;
-// pub mod mama;
+pub mod macdext;
+pub mod mama;
 pub mod medprice;
 pub mod mfi;
 pub mod midpoint;
@@ -49,7 +62,11 @@
 pub mod obv;
 pub mod plus_di;
 pub mod plus_dm;
-// pub mod ppo;
+pub mod pnf;
+pub mod ppo;
+pub mod pvt;
+pub mod range_bars;
+pub mod renko;
 pub mod rma;
 pub mod roc;
 pub mod rocp;
@@ -57,22 +74,25 @@
 pub mod rocr100;
 pub mod rsi;
 pub mod sar;
-// pub mod sarext;
+pub mod sarext;
 pub mod sma;
+pub mod starc;
 pub mod stoch;
-// pub mod stochf;
-// pub mod stochrsi;
+pub mod stochf;
+pub mod stochrsi;
 pub mod supertrend;
 pub mod t3;
 pub mod tema;
 pub mod trange;
 pub mod trima;
 pub mod trix;
-// pub mod tsf;
+pub mod tsf;
 pub mod typprice;
-// pub mod ultosc;
+pub mod ultosc;
 pub mod vegas;
+pub mod volume_index;
 pub mod vwap;
+pub mod vwap_bands;
 pub mod wclprice;
 pub mod willr;
 pub mod wma;
@@ -1,5 +1,5 @@
 use super::atr;
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period required for NATR calculation
 ///
@@ -215,6 +215,65 @@ pub fn natr_inc(
     Ok((output_atr / input_close) * 100.0)
 }
 
+/// Streaming Normalized Average True Range (NATR) calculator.
+///
+/// Wraps an [`atr::Atr`] stream, since [`natr_inc`] does not expose the ATR it needs on the
+/// next bar, and normalizes each ATR value by the current close.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::natr::Natr};
+///
+/// let mut natr = Natr::new(2).unwrap();
+/// assert_eq!(natr.update((10.0, 8.0, 9.0)).unwrap(), None);
+/// assert_eq!(natr.update((11.0, 9.0, 10.0)).unwrap(), None);
+/// assert_eq!(natr.update((12.0, 9.0, 10.0)).unwrap(), Some(25.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Natr {
+    atr: atr::Atr,
+}
+
+impl Natr {
+    /// Creates a new streaming NATR.
+    ///
+    /// # Arguments
+    /// * `param_period` - The time period for NATR calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            atr: atr::Atr::new(param_period)?,
+        })
+    }
+}
+
+impl Indicator for Natr {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(&mut self, input: (TAFloat, TAFloat, TAFloat)) -> Result<Option<TAFloat>, KandError> {
+        let input_close = input.2;
+        Ok(self
+            .atr
+            .update(input)?
+            .map(|output_atr| (output_atr / input_close) * 100.0))
+    }
+
+    fn reset(&mut self) {
+        self.atr.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.atr.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.atr.lookback()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -296,4 +355,46 @@ fn test_natr_calculation() {
             assert_relative_eq!(output_natr_inc, output_natr[i], epsilon = 0.00001);
         }
     }
+
+    #[test]
+    fn test_natr_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 14;
+        let mut output_natr = vec![0.0; input_close.len()];
+
+        natr(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_natr,
+        )
+        .unwrap();
+
+        let mut stream = Natr::new(param_period).unwrap();
+        for i in 0..input_close.len() {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some(value) => assert_relative_eq!(value, output_natr[i], epsilon = 0.00001),
+                None => assert!(output_natr[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period required for On Balance Volume (OBV) calculation
 ///
@@ -178,6 +178,64 @@ pub fn obv_inc(
     })
 }
 
+/// Streaming On Balance Volume (OBV) calculator.
+///
+/// Consumes `(close, volume)` bars. The first bar starts the running total with its volume,
+/// matching [`obv`]; every later bar goes through [`obv_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::obv::Obv};
+///
+/// let mut obv = Obv::new();
+/// assert_eq!(obv.update((10.0, 100.0)).unwrap(), Some(100.0));
+/// assert_eq!(obv.update((11.0, 50.0)).unwrap(), Some(150.0));
+/// assert_eq!(obv.update((10.5, 30.0)).unwrap(), Some(120.0));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Obv {
+    prev: Option<(TAFloat, TAFloat)>,
+}
+
+impl Obv {
+    /// Creates a new streaming OBV.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { prev: None }
+    }
+}
+
+impl Indicator for Obv {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_close, input_volume): (TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        let output_obv = match self.prev {
+            Some((prev_close, prev_obv)) => {
+                obv_inc(input_close, prev_close, input_volume, prev_obv)?
+            }
+            None => input_volume,
+        };
+        self.prev = Some((input_close, output_obv));
+        Ok(Some(output_obv))
+    }
+
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -224,4 +282,28 @@ fn test_obv_calculation() {
             prev_obv = result;
         }
     }
+
+    #[test]
+    fn test_obv_stream() {
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let mut output_obv = vec![0.0; input_close.len()];
+
+        obv(&input_close, &input_volume, &mut output_obv).unwrap();
+
+        let mut stream = Obv::new();
+        for i in 0..input_close.len() {
+            let value = stream.update((input_close[i], input_volume[i])).unwrap();
+            assert_relative_eq!(value.unwrap(), output_obv[i], epsilon = 0.00001);
+        }
+        assert!(stream.is_ready());
+    }
 }
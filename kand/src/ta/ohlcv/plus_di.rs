@@ -1,5 +1,8 @@
-use super::trange;
-use crate::{KandError, TAFloat};
+use super::{
+    dmi::{self, Dmi},
+    trange,
+};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period needed for +DI calculation
 ///
@@ -288,52 +291,83 @@ pub fn plus_di_inc(
     prev_smoothed_tr: TAFloat,
     param_period: usize,
 ) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
-    #[cfg(feature = "check")]
-    {
-        // Parameter range check
-        if param_period < 2 {
-            return Err(KandError::InvalidParameter);
-        }
-    }
+    let (output_plus_di, _, _, output_smoothed_plus_dm, _, output_smoothed_tr) = dmi::dmi_inc(
+        input_high,
+        input_low,
+        prev_high,
+        prev_low,
+        prev_close,
+        prev_smoothed_plus_dm,
+        0.0,
+        prev_smoothed_tr,
+        param_period,
+    )?;
 
-    #[cfg(feature = "deep-check")]
-    {
-        // NaN check
-        if input_high.is_nan()
-            || input_low.is_nan()
-            || prev_high.is_nan()
-            || prev_low.is_nan()
-            || prev_close.is_nan()
-            || prev_smoothed_plus_dm.is_nan()
-            || prev_smoothed_tr.is_nan()
-        {
-            return Err(KandError::NaNDetected);
-        }
-    }
+    Ok((output_plus_di, output_smoothed_plus_dm, output_smoothed_tr))
+}
 
-    let high_diff = input_high - prev_high;
-    let low_diff = prev_low - input_low;
+/// Streaming Plus Directional Indicator (+DI) calculator.
+///
+/// Consumes `(high, low, close)` bars through a shared [`Dmi`] state and emits the +DI component.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::plus_di::PlusDi};
+///
+/// let mut plus_di = PlusDi::new(2).unwrap();
+/// let bars = [
+///     (10.0, 8.0, 9.0),
+///     (11.0, 9.0, 10.0),
+///     (12.0, 9.5, 11.0),
+///     (11.5, 9.0, 9.5),
+///     (12.5, 10.0, 12.0),
+/// ];
+/// let values: Vec<_> = bars
+///     .iter()
+///     .map(|bar| plus_di.update(*bar).unwrap())
+///     .collect();
+/// assert!(values[plus_di.lookback() - 1].is_none());
+/// assert!(values[plus_di.lookback()].is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PlusDi {
+    dmi: Dmi,
+}
 
-    let plus_dm = if high_diff > low_diff && high_diff > 0.0 {
-        high_diff
-    } else {
-        0.0
-    };
+impl PlusDi {
+    /// Creates a new streaming +DI.
+    ///
+    /// # Arguments
+    /// * `param_period` - The smoothing period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            dmi: Dmi::new(param_period)?,
+        })
+    }
+}
 
-    let tr = trange::trange_inc(input_high, input_low, prev_close)?;
-    let period_t = param_period as TAFloat;
+impl Indicator for PlusDi {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
 
-    let output_smoothed_plus_dm =
-        prev_smoothed_plus_dm - (prev_smoothed_plus_dm / period_t) + plus_dm;
-    let output_smoothed_tr = prev_smoothed_tr - (prev_smoothed_tr / period_t) + tr;
+    fn update(&mut self, input: (TAFloat, TAFloat, TAFloat)) -> Result<Option<TAFloat>, KandError> {
+        Ok(self.dmi.update(input)?.map(|output| output.plus_di))
+    }
 
-    let output_plus_di = if output_smoothed_tr == 0.0 {
-        0.0
-    } else {
-        100.0 * output_smoothed_plus_dm / output_smoothed_tr
-    };
+    fn reset(&mut self) {
+        self.dmi.reset();
+    }
 
-    Ok((output_plus_di, output_smoothed_plus_dm, output_smoothed_tr))
+    fn is_ready(&self) -> bool {
+        self.dmi.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.dmi.lookback()
+    }
 }
 
 #[cfg(test)]
@@ -418,4 +452,50 @@ fn test_plus_di_calculation() {
             prev_smoothed_tr = new_smoothed_tr;
         }
     }
+
+    #[test]
+    fn test_plus_di_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 5;
+        let mut output_plus_di = vec![0.0; input_close.len()];
+        let mut output_smoothed_plus_dm = vec![0.0; input_close.len()];
+        let mut output_smoothed_tr = vec![0.0; input_close.len()];
+
+        plus_di(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            &mut output_plus_di,
+            &mut output_smoothed_plus_dm,
+            &mut output_smoothed_tr,
+        )
+        .unwrap();
+
+        let mut stream = PlusDi::new(param_period).unwrap();
+        for i in 0..input_close.len() {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some(value) => assert_relative_eq!(value, output_plus_di[i], epsilon = 0.00001),
+                None => assert!(output_plus_di[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
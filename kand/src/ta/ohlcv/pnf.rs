@@ -0,0 +1,585 @@
+use crate::{Indicator, KandError, TAFloat, TAInt, types::PriceSource};
+
+/// A single Point & Figure column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PnfColumn {
+    /// 1 for a column of Xs, -1 for a column of Os, 0 before the first column forms
+    pub direction:   TAInt,
+    /// Price level of the lowest box in the column
+    pub low:         TAFloat,
+    /// Price level of the highest box in the column
+    pub high:        TAFloat,
+    /// Index of the bar that started the column
+    pub start_index: usize,
+    /// Index of the bar that added the last box to the column
+    pub end_index:   usize,
+}
+
+impl PnfColumn {
+    /// Returns the state before the first column, anchored at `price`.
+    #[must_use]
+    pub const fn base(price: TAFloat, index: usize) -> Self {
+        Self {
+            direction:   0,
+            low:         price,
+            high:        price,
+            start_index: index,
+            end_index:   index,
+        }
+    }
+
+    /// Returns the number of boxes in the column for the given box size.
+    #[must_use]
+    pub fn boxes(&self, param_box_size: TAFloat) -> usize {
+        if self.direction == 0 {
+            return 0;
+        }
+        ((self.high - self.low) / param_box_size).round() as usize + 1
+    }
+}
+
+/// Returns the lookback period required for Point & Figure construction.
+///
+/// # Description
+/// The first bar only sets the base price; a column can form from the second bar on.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Always 0
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::pnf;
+/// assert_eq!(pnf::lookback().unwrap(), 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Validates the box size and the reversal box count.
+fn check_params(param_box_size: TAFloat, param_reversal: usize) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if !param_box_size.is_finite() || param_box_size <= 0.0 || param_reversal == 0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    let _ = (param_box_size, param_reversal);
+    Ok(())
+}
+
+/// Builds Point & Figure columns from a price series.
+///
+/// # Description
+/// Prices are mapped onto a grid of boxes: a rising price fills the boxes it has reached
+/// (rounded down) and a falling price the boxes it has dropped to (rounded up). A column of Xs
+/// grows while price fills higher boxes and a column of Os while it fills lower ones. The
+/// column reverses once price fills `param_reversal` boxes in the other direction, the new
+/// column starting one box away from the extreme of the previous one. The first column forms
+/// once price fills a box beyond the box of the first close. With [`PriceSource::HighLow`] an
+/// extension takes precedence over a reversal within the same bar.
+///
+/// # Mathematical Formula
+/// ```text
+/// up_box = floor(price_up / box_size)       down_box = ceil(price_down / box_size)
+/// X column: high = up_box if up_box > high,  else reverse when down_box <= high - reversal
+///           new O column spans [down_box, high - 1]
+/// O column: low = down_box if down_box < low, else reverse when up_box >= low + reversal
+///           new X column spans [low + 1, up_box]
+/// ```
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `param_box_size` - Price distance covered by each box (must be > 0)
+/// * `param_reversal` - Boxes needed to start a new column (must be >= 1, typically 3)
+/// * `param_source` - Whether moves are measured on closes or on highs and lows
+/// * `output_direction` - Cleared, then filled with the type of each column (1 X, -1 O)
+/// * `output_low` - Cleared, then filled with the lowest box level of each column
+/// * `output_high` - Cleared, then filled with the highest box level of each column
+/// * `output_start_index` - Cleared, then filled with the bar index that started each column
+/// * `output_end_index` - Cleared, then filled with the bar index of each column's last box
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success; the last column is still forming
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If the box size is not positive or the reversal is 0
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::pnf, types::PriceSource};
+///
+/// let input_close = vec![10.0, 11.2, 13.5, 12.4, 9.8, 11.8];
+/// let mut output_direction = Vec::new();
+/// let mut output_low = Vec::new();
+/// let mut output_high = Vec::new();
+/// let mut output_start_index = Vec::new();
+/// let mut output_end_index = Vec::new();
+///
+/// pnf::pnf(
+///     &input_close,
+///     &input_close,
+///     &input_close,
+///     1.0,
+///     3,
+///     PriceSource::Close,
+///     &mut output_direction,
+///     &mut output_low,
+///     &mut output_high,
+///     &mut output_start_index,
+///     &mut output_end_index,
+/// )
+/// .unwrap();
+/// assert_eq!(output_direction, vec![1, -1]);
+/// assert_eq!(output_low, vec![11.0, 10.0]);
+/// assert_eq!(output_high, vec![13.0, 12.0]);
+/// ```
+pub fn pnf(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_box_size: TAFloat,
+    param_reversal: usize,
+    param_source: PriceSource,
+    output_direction: &mut Vec<TAInt>,
+    output_low: &mut Vec<TAFloat>,
+    output_high: &mut Vec<TAFloat>,
+    output_start_index: &mut Vec<usize>,
+    output_end_index: &mut Vec<usize>,
+) -> Result<(), KandError> {
+    let len = input_close.len();
+    check_params(param_box_size, param_reversal)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_high.len() != len || input_low.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut columns = Vec::new();
+    let mut current = PnfColumn::base(input_close[0], 0);
+    for i in 1..len {
+        current = pnf_inc(
+            input_high[i],
+            input_low[i],
+            input_close[i],
+            i,
+            current,
+            param_box_size,
+            param_reversal,
+            param_source,
+            &mut columns,
+        )?;
+    }
+    if current.direction != 0 {
+        columns.push(current);
+    }
+
+    output_direction.clear();
+    output_low.clear();
+    output_high.clear();
+    output_start_index.clear();
+    output_end_index.clear();
+    for column in columns {
+        output_direction.push(column.direction);
+        output_low.push(column.low);
+        output_high.push(column.high);
+        output_start_index.push(column.start_index);
+        output_end_index.push(column.end_index);
+    }
+
+    Ok(())
+}
+
+/// Advances a Point & Figure chart by one bar.
+///
+/// # Description
+/// Extends or reverses the forming column as described in [`pnf`]. When the column reverses,
+/// the finished column is appended to `output_columns`.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `input_index` - Index of the current bar
+/// * `prev_column` - The forming column, or [`PnfColumn::base`] before the first column
+/// * `param_box_size` - Price distance covered by each box (must be > 0)
+/// * `param_reversal` - Boxes needed to start a new column (must be >= 1, typically 3)
+/// * `param_source` - Whether moves are measured on closes or on highs and lows
+/// * `output_columns` - Finished columns are appended here
+///
+/// # Returns
+/// * `Result<PnfColumn, KandError>` - The forming column
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If the box size is not positive or the reversal is 0
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{
+///     ohlcv::pnf::{self, PnfColumn},
+///     types::PriceSource,
+/// };
+///
+/// let mut columns = Vec::new();
+/// let column = PnfColumn::base(10.0, 0);
+/// let column = pnf::pnf_inc(
+///     12.5,
+///     12.5,
+///     12.5,
+///     1,
+///     column,
+///     1.0,
+///     3,
+///     PriceSource::Close,
+///     &mut columns,
+/// )
+/// .unwrap();
+/// assert_eq!((column.direction, column.low, column.high), (1, 11.0, 12.0));
+/// let column = pnf::pnf_inc(
+///     9.0,
+///     9.0,
+///     9.0,
+///     2,
+///     column,
+///     1.0,
+///     3,
+///     PriceSource::Close,
+///     &mut columns,
+/// )
+/// .unwrap();
+/// assert_eq!(columns.len(), 1);
+/// assert_eq!((column.direction, column.low, column.high), (-1, 9.0, 11.0));
+/// ```
+pub fn pnf_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    input_index: usize,
+    prev_column: PnfColumn,
+    param_box_size: TAFloat,
+    param_reversal: usize,
+    param_source: PriceSource,
+    output_columns: &mut Vec<PnfColumn>,
+) -> Result<PnfColumn, KandError> {
+    check_params(param_box_size, param_reversal)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_high.is_nan() || input_low.is_nan() || input_close.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (up_price, down_price) = param_source.prices(input_high, input_low, input_close);
+    // Work in whole boxes so that repeated levels compare exactly
+    let up_box = (up_price / param_box_size).floor();
+    let down_box = (down_price / param_box_size).ceil();
+    let reversal = param_reversal as TAFloat;
+    let mut column = prev_column;
+    let high_box = (column.high / param_box_size).round();
+    let low_box = (column.low / param_box_size).round();
+
+    let (direction, low, high) = match column.direction {
+        0 => {
+            let anchor_up = (column.high / param_box_size).floor();
+            let anchor_down = (column.low / param_box_size).ceil();
+            if up_box > anchor_up {
+                (1, anchor_up + 1.0, up_box)
+            } else if down_box < anchor_down {
+                (-1, down_box, anchor_down - 1.0)
+            } else {
+                return Ok(column);
+            }
+        }
+        1 if up_box > high_box => {
+            column.high = up_box * param_box_size;
+            column.end_index = input_index;
+            return Ok(column);
+        }
+        1 if down_box <= high_box - reversal => (-1, down_box, high_box - 1.0),
+        -1 if down_box < low_box => {
+            column.low = down_box * param_box_size;
+            column.end_index = input_index;
+            return Ok(column);
+        }
+        -1 if up_box >= low_box + reversal => (1, low_box + 1.0, up_box),
+        _ => return Ok(column),
+    };
+
+    if column.direction != 0 {
+        output_columns.push(column);
+    }
+    Ok(PnfColumn {
+        direction,
+        low: low * param_box_size,
+        high: high * param_box_size,
+        start_index: input_index,
+        end_index: input_index,
+    })
+}
+
+/// Streaming Point & Figure chart builder.
+///
+/// Consumes `(high, low, close)` bars and emits each column once it reverses, so a bar
+/// produces zero or one finished column. The forming column is available from
+/// [`Pnf::current`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::pnf::Pnf, types::PriceSource};
+///
+/// let mut pnf = Pnf::new(1.0, 3, PriceSource::Close).unwrap();
+/// for price in [10.0, 11.2, 13.5, 12.4] {
+///     assert!(
+///         pnf.update((price, price, price))
+///             .unwrap()
+///             .unwrap()
+///             .is_empty()
+///     );
+/// }
+/// let columns = pnf.update((9.8, 9.8, 9.8)).unwrap().unwrap();
+/// assert_eq!((columns[0].low, columns[0].high), (11.0, 13.0));
+/// assert_eq!(pnf.current().unwrap().boxes(1.0), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Pnf {
+    param_box_size: TAFloat,
+    param_reversal: usize,
+    param_source:   PriceSource,
+    index:          usize,
+    column:         Option<PnfColumn>,
+}
+
+impl Pnf {
+    /// Creates a new streaming Point & Figure builder.
+    ///
+    /// # Arguments
+    /// * `param_box_size` - Price distance covered by each box (must be > 0)
+    /// * `param_reversal` - Boxes needed to start a new column (must be >= 1, typically 3)
+    /// * `param_source` - Whether moves are measured on closes or on highs and lows
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the box size is not positive or the reversal is 0
+    pub fn new(
+        param_box_size: TAFloat,
+        param_reversal: usize,
+        param_source: PriceSource,
+    ) -> Result<Self, KandError> {
+        check_params(param_box_size, param_reversal)?;
+        Ok(Self {
+            param_box_size,
+            param_reversal,
+            param_source,
+            index: 0,
+            column: None,
+        })
+    }
+
+    /// Returns the column that is still forming, `None` before the first column.
+    pub fn current(&self) -> Option<PnfColumn> {
+        self.column.filter(|column| column.direction != 0)
+    }
+}
+
+impl Indicator for Pnf {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = Vec<PnfColumn>;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<Vec<PnfColumn>>, KandError> {
+        let input_index = self.index;
+        self.index += 1;
+
+        let Some(column) = self.column else {
+            self.column = Some(PnfColumn::base(input_close, input_index));
+            return Ok(Some(Vec::new()));
+        };
+
+        let mut columns = Vec::new();
+        self.column = Some(pnf_inc(
+            input_high,
+            input_low,
+            input_close,
+            input_index,
+            column,
+            self.param_box_size,
+            self.param_reversal,
+            self.param_source,
+            &mut columns,
+        )?);
+        Ok(Some(columns))
+    }
+
+    fn reset(&mut self) {
+        self.index = 0;
+        self.column = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.column.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_HIGH: [TAFloat; 25] = [
+        35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0, 35210.0,
+        35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5, 35078.8, 35085.0,
+        35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+    ];
+    const INPUT_LOW: [TAFloat; 25] = [
+        35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0, 35166.0,
+        35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0, 35012.3, 35022.2,
+        34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+    ];
+    const INPUT_CLOSE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    fn run(
+        param_box_size: TAFloat,
+        param_reversal: usize,
+        param_source: PriceSource,
+    ) -> Vec<PnfColumn> {
+        let mut output_direction = Vec::new();
+        let mut output_low = Vec::new();
+        let mut output_high = Vec::new();
+        let mut output_start_index = Vec::new();
+        let mut output_end_index = Vec::new();
+        pnf(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            param_box_size,
+            param_reversal,
+            param_source,
+            &mut output_direction,
+            &mut output_low,
+            &mut output_high,
+            &mut output_start_index,
+            &mut output_end_index,
+        )
+        .unwrap();
+        (0..output_direction.len())
+            .map(|i| PnfColumn {
+                direction:   output_direction[i],
+                low:         output_low[i],
+                high:        output_high[i],
+                start_index: output_start_index[i],
+                end_index:   output_end_index[i],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pnf_close() {
+        let columns = run(20.0, 3, PriceSource::Close);
+
+        let directions: Vec<TAInt> = columns.iter().map(|column| column.direction).collect();
+        assert_eq!(directions, vec![1, -1, 1, -1, 1]);
+        let lows: Vec<TAFloat> = columns.iter().map(|column| column.low).collect();
+        assert_eq!(lows, vec![35220.0, 35000.0, 35020.0, 34940.0, 34960.0]);
+        let highs: Vec<TAFloat> = columns.iter().map(|column| column.high).collect();
+        assert_eq!(highs, vec![35240.0, 35220.0, 35060.0, 35040.0, 35080.0]);
+        let start_index: Vec<usize> = columns.iter().map(|column| column.start_index).collect();
+        assert_eq!(start_index, vec![1, 10, 18, 20, 22]);
+        let end_index: Vec<usize> = columns.iter().map(|column| column.end_index).collect();
+        assert_eq!(end_index, vec![5, 16, 18, 20, 24]);
+    }
+
+    #[test]
+    fn test_pnf_columns_alternate() {
+        for source in [PriceSource::Close, PriceSource::HighLow] {
+            let columns = run(10.0, 3, source);
+            assert!(columns.len() > 2);
+            for pair in columns.windows(2) {
+                assert_eq!(pair[1].direction, -pair[0].direction);
+                assert!(pair[1].boxes(10.0) >= 3);
+                if pair[1].direction > 0 {
+                    assert_eq!(pair[1].low, pair[0].low + 10.0);
+                } else {
+                    assert_eq!(pair[1].high, pair[0].high - 10.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pnf_stream() {
+        let expected = run(10.0, 2, PriceSource::HighLow);
+        let mut stream = Pnf::new(10.0, 2, PriceSource::HighLow).unwrap();
+        assert!(stream.current().is_none());
+        let mut columns = Vec::new();
+        for i in 0..INPUT_CLOSE.len() {
+            let finished = stream
+                .update((INPUT_HIGH[i], INPUT_LOW[i], INPUT_CLOSE[i]))
+                .unwrap()
+                .unwrap();
+            assert!(finished.len() <= 1);
+            columns.extend(finished);
+        }
+        columns.extend(stream.current());
+        assert_eq!(columns, expected);
+
+        stream.reset();
+        assert!(!stream.is_ready());
+    }
+
+    #[test]
+    fn test_pnf_invalid_params() {
+        assert!(Pnf::new(0.0, 3, PriceSource::Close).is_err());
+        assert!(Pnf::new(1.0, 0, PriceSource::Close).is_err());
+        let mut columns = Vec::new();
+        assert!(matches!(
+            pnf_inc(
+                1.0,
+                1.0,
+                1.0,
+                1,
+                PnfColumn::base(1.0, 0),
+                -1.0,
+                3,
+                PriceSource::Close,
+                &mut columns
+            ),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
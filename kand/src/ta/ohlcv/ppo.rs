@@ -0,0 +1,526 @@
+use super::{
+    ma::{self, Ma},
+    macdext,
+};
+use crate::{Indicator, KandError, TAFloat, types::MAType};
+
+/// Calculate the lookback period required for PPO calculation
+///
+/// The PPO line is valid once both the fast and the slow moving averages are, and the signal
+/// line needs the lookback of its own moving average on top of that.
+///
+/// # Arguments
+/// * `param_fast_period` - Fast MA period, must be >= 2
+/// * `param_fast_ma_type` - Fast MA type
+/// * `param_slow_period` - Slow MA period, must be >= 2; swapped with the fast line if shorter
+/// * `param_slow_ma_type` - Slow MA type
+/// * `param_signal_period` - Signal line period, must be >= 2
+/// * `param_signal_ma_type` - Signal line MA type
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Lookback period if successful
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If any period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::ppo, types::MAType};
+/// let lookback = ppo::lookback(12, MAType::EMA, 26, MAType::EMA, 9, MAType::EMA).unwrap();
+/// assert_eq!(lookback, 33); // 25 (slow EMA) + 8 (signal)
+/// ```
+pub fn lookback(
+    param_fast_period: usize,
+    param_fast_ma_type: MAType,
+    param_slow_period: usize,
+    param_slow_ma_type: MAType,
+    param_signal_period: usize,
+    param_signal_ma_type: MAType,
+) -> Result<usize, KandError> {
+    macdext::lookback(
+        param_fast_period,
+        param_fast_ma_type,
+        param_slow_period,
+        param_slow_ma_type,
+        param_signal_period,
+        param_signal_ma_type,
+    )
+}
+
+/// Percentage difference of the fast MA over the slow MA, 0 when the slow MA is 0 (as TA-Lib).
+fn ppo_value(fast: TAFloat, slow: TAFloat) -> TAFloat {
+    if slow == 0.0 {
+        0.0
+    } else {
+        (fast - slow) / slow * 100.0
+    }
+}
+
+/// Calculate the Percentage Price Oscillator (PPO) for a price series
+///
+/// PPO is the difference between a fast and a slow moving average of price expressed as a
+/// percentage of the slow moving average. Unlike APO it is scale free, so values can be
+/// compared across instruments with different price levels.
+///
+/// # Mathematical Formula
+/// ```text
+/// Fast MA = MA(price, fast_period, fast_ma_type)
+/// Slow MA = MA(price, slow_period, slow_ma_type)
+/// PPO = (Fast MA - Slow MA) / Slow MA * 100
+/// Signal = MA(PPO, signal_period, signal_ma_type)
+/// Histogram = PPO - Signal
+/// ```
+///
+/// # Calculation Steps
+/// 1. Calculate fast and slow moving averages of price
+/// 2. Calculate PPO once both moving averages are valid (0 when the slow MA is 0)
+/// 3. Calculate signal line as moving average of the valid PPO values
+/// 4. Calculate histogram as difference between PPO and signal lines
+///
+/// # Arguments
+/// * `input_price` - Array of price values
+/// * `param_fast_period` - Fast MA period (typically 12)
+/// * `param_fast_ma_type` - Fast MA type
+/// * `param_slow_period` - Slow MA period (typically 26)
+/// * `param_slow_ma_type` - Slow MA type
+/// * `param_signal_period` - Signal line period (typically 9)
+/// * `param_signal_ma_type` - Signal line MA type
+/// * `output_ppo` - Output buffer for PPO values
+/// * `output_signal` - Output buffer for signal line values
+/// * `output_histogram` - Output buffer for histogram values
+/// * `output_fast_ma` - Output buffer for fast MA values
+/// * `output_slow_ma` - Output buffer for slow MA values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Empty Ok if successful
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If any period is less than 2
+/// * `KandError::InsufficientData` - If input length <= required lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::ppo, types::MAType};
+///
+/// let prices = vec![10.0, 12.0, 15.0, 11.0, 9.0, 10.0, 12.0];
+/// let mut output_ppo = vec![0.0; prices.len()];
+/// let mut output_signal = vec![0.0; prices.len()];
+/// let mut output_histogram = vec![0.0; prices.len()];
+/// let mut output_fast_ma = vec![0.0; prices.len()];
+/// let mut output_slow_ma = vec![0.0; prices.len()];
+///
+/// ppo::ppo(
+///     &prices,
+///     2, // fast period
+///     MAType::SMA,
+///     3, // slow period
+///     MAType::SMA,
+///     2, // signal period
+///     MAType::SMA,
+///     &mut output_ppo,
+///     &mut output_signal,
+///     &mut output_histogram,
+///     &mut output_fast_ma,
+///     &mut output_slow_ma,
+/// )
+/// .unwrap();
+/// // 13 is 2.63% above 38 / 3
+/// assert!((output_ppo[3] - 2.631_579).abs() < 1e-6);
+/// assert!(output_signal[2].is_nan());
+/// ```
+pub fn ppo(
+    input_price: &[TAFloat],
+    param_fast_period: usize,
+    param_fast_ma_type: MAType,
+    param_slow_period: usize,
+    param_slow_ma_type: MAType,
+    param_signal_period: usize,
+    param_signal_ma_type: MAType,
+    output_ppo: &mut [TAFloat],
+    output_signal: &mut [TAFloat],
+    output_histogram: &mut [TAFloat],
+    output_fast_ma: &mut [TAFloat],
+    output_slow_ma: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_price.len();
+    let lookback = lookback(
+        param_fast_period,
+        param_fast_ma_type,
+        param_slow_period,
+        param_slow_ma_type,
+        param_signal_period,
+        param_signal_ma_type,
+    )?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != output_ppo.len()
+            || len != output_signal.len()
+            || len != output_histogram.len()
+            || len != output_fast_ma.len()
+            || len != output_slow_ma.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for price in input_price {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    ma::ma(
+        input_price,
+        param_fast_period,
+        param_fast_ma_type,
+        output_fast_ma,
+    )?;
+    ma::ma(
+        input_price,
+        param_slow_period,
+        param_slow_ma_type,
+        output_slow_ma,
+    )?;
+
+    // Calculate PPO line where both moving averages are valid
+    let ppo_start = ma::lookback(param_fast_period, param_fast_ma_type)?
+        .max(ma::lookback(param_slow_period, param_slow_ma_type)?);
+    for i in ppo_start..len {
+        output_ppo[i] = ppo_value(output_fast_ma[i], output_slow_ma[i]);
+    }
+
+    // Calculate signal line using non-NaN PPO values
+    ma::ma(
+        &output_ppo[ppo_start..],
+        param_signal_period,
+        param_signal_ma_type,
+        &mut output_signal[ppo_start..],
+    )?;
+
+    // Calculate histogram
+    for i in lookback..len {
+        output_histogram[i] = output_ppo[i] - output_signal[i];
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_ppo[i] = TAFloat::NAN;
+        output_signal[i] = TAFloat::NAN;
+        output_histogram[i] = TAFloat::NAN;
+        output_fast_ma[i] = TAFloat::NAN;
+        output_slow_ma[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculate latest PPO values incrementally from previous state
+///
+/// The moving averages are carried in streaming [`Ma`] states owned by the caller. Feed every
+/// bar from the start of the series; NaN is returned until the respective line is valid,
+/// matching the batch output bar for bar.
+///
+/// # Arguments
+/// * `input_price` - Current price value
+/// * `fast_ma` - Fast moving average state, e.g. `Ma::new(12, MAType::EMA)`
+/// * `slow_ma` - Slow moving average state, e.g. `Ma::new(26, MAType::EMA)`
+/// * `signal_ma` - Signal line moving average state, e.g. `Ma::new(9, MAType::EMA)`
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (PPO, Signal, Histogram),
+///   NaN while warming up
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If the input value is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{
+///     ohlcv::{ma::Ma, ppo},
+///     types::MAType,
+/// };
+///
+/// let mut fast_ma = Ma::new(2, MAType::SMA).unwrap();
+/// let mut slow_ma = Ma::new(3, MAType::SMA).unwrap();
+/// let mut signal_ma = Ma::new(2, MAType::SMA).unwrap();
+///
+/// for price in [10.0, 12.0] {
+///     let (value, _, _) =
+///         ppo::ppo_inc(price, &mut fast_ma, &mut slow_ma, &mut signal_ma).unwrap();
+///     assert!(value.is_nan());
+/// }
+/// let (value, signal, _) =
+///     ppo::ppo_inc(17.0, &mut fast_ma, &mut slow_ma, &mut signal_ma).unwrap();
+/// assert!((value - 11.538_462).abs() < 1e-6); // 14.5 vs 13
+/// assert!(signal.is_nan());
+/// ```
+pub fn ppo_inc(
+    input_price: TAFloat,
+    fast_ma: &mut Ma,
+    slow_ma: &mut Ma,
+    signal_ma: &mut Ma,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let fast = fast_ma.update(input_price)?;
+    let slow = slow_ma.update(input_price)?;
+    let (Some(fast), Some(slow)) = (fast, slow) else {
+        return Ok((TAFloat::NAN, TAFloat::NAN, TAFloat::NAN));
+    };
+
+    let ppo = ppo_value(fast, slow);
+    match signal_ma.update(ppo)? {
+        Some(signal) => Ok((ppo, signal, ppo - signal)),
+        None => Ok((ppo, TAFloat::NAN, TAFloat::NAN)),
+    }
+}
+
+/// Streaming Percentage Price Oscillator (PPO) calculator.
+///
+/// Owns the fast, slow and signal moving average states and advances them with [`ppo_inc`],
+/// so the stream matches the batch output.
+///
+/// The output tuple is `(ppo, signal, histogram)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ppo::Ppo, types::MAType};
+///
+/// let mut ppo = Ppo::new(2, MAType::SMA, 3, MAType::SMA, 2, MAType::SMA).unwrap();
+/// assert_eq!(ppo.lookback(), 3);
+/// for price in [1.0, 2.0, 3.0] {
+///     assert!(ppo.update(price).unwrap().is_none());
+/// }
+/// let (value, signal, histogram) = ppo.update(4.0).unwrap().unwrap();
+/// assert!((value - 100.0 / 6.0).abs() < 1e-10);
+/// assert!((value - signal - histogram).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ppo {
+    lookback:  usize,
+    fast_ma:   Ma,
+    slow_ma:   Ma,
+    signal_ma: Ma,
+}
+
+impl Ppo {
+    /// Creates a new streaming PPO.
+    ///
+    /// # Arguments
+    /// * `param_fast_period` - Period for the fast MA (typically 12)
+    /// * `param_fast_ma_type` - Fast MA type
+    /// * `param_slow_period` - Period for the slow MA (typically 26)
+    /// * `param_slow_ma_type` - Slow MA type
+    /// * `param_signal_period` - Period for the signal line (typically 9)
+    /// * `param_signal_ma_type` - Signal line MA type
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period is less than 2
+    pub fn new(
+        param_fast_period: usize,
+        param_fast_ma_type: MAType,
+        param_slow_period: usize,
+        param_slow_ma_type: MAType,
+        param_signal_period: usize,
+        param_signal_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        Ok(Self {
+            lookback:  lookback(
+                param_fast_period,
+                param_fast_ma_type,
+                param_slow_period,
+                param_slow_ma_type,
+                param_signal_period,
+                param_signal_ma_type,
+            )?,
+            fast_ma:   Ma::new(param_fast_period, param_fast_ma_type)?,
+            slow_ma:   Ma::new(param_slow_period, param_slow_ma_type)?,
+            signal_ma: Ma::new(param_signal_period, param_signal_ma_type)?,
+        })
+    }
+}
+
+impl Indicator for Ppo {
+    type Input = TAFloat;
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        input_price: TAFloat,
+    ) -> Result<Option<(TAFloat, TAFloat, TAFloat)>, KandError> {
+        let output = ppo_inc(
+            input_price,
+            &mut self.fast_ma,
+            &mut self.slow_ma,
+            &mut self.signal_ma,
+        )?;
+        if self.signal_ma.is_ready() {
+            Ok(Some(output))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn reset(&mut self) {
+        self.fast_ma.reset();
+        self.slow_ma.reset();
+        self.signal_ma.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.signal_ma.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    #[test]
+    fn test_ppo_calculation() {
+        let len = INPUT_PRICE.len();
+        let mut output_ppo = vec![0.0; len];
+        let mut output_signal = vec![0.0; len];
+        let mut output_histogram = vec![0.0; len];
+        let mut output_fast_ma = vec![0.0; len];
+        let mut output_slow_ma = vec![0.0; len];
+        ppo(
+            &INPUT_PRICE,
+            3,
+            MAType::SMA,
+            10,
+            MAType::SMA,
+            4,
+            MAType::EMA,
+            &mut output_ppo,
+            &mut output_signal,
+            &mut output_histogram,
+            &mut output_fast_ma,
+            &mut output_slow_ma,
+        )
+        .unwrap();
+
+        let lookback = lookback(3, MAType::SMA, 10, MAType::SMA, 4, MAType::EMA).unwrap();
+        assert_eq!(lookback, 12);
+        for value in &output_ppo[..lookback] {
+            assert!(value.is_nan());
+        }
+
+        // Same values as TA-Lib PPO(fastperiod=3, slowperiod=10, matype=SMA)
+        assert_relative_eq!(output_ppo[12], -0.000_643_828_553, epsilon = 0.00001);
+        assert_relative_eq!(output_ppo[24], 0.069_853_501_842, epsilon = 0.00001);
+        for i in lookback..len {
+            assert_relative_eq!(
+                output_ppo[i],
+                (output_fast_ma[i] - output_slow_ma[i]) / output_slow_ma[i] * 100.0,
+                epsilon = 0.00001
+            );
+            assert_relative_eq!(
+                output_histogram[i],
+                output_ppo[i] - output_signal[i],
+                epsilon = 0.00001
+            );
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let mut fast_ma = Ma::new(3, MAType::SMA).unwrap();
+        let mut slow_ma = Ma::new(10, MAType::SMA).unwrap();
+        let mut signal_ma = Ma::new(4, MAType::EMA).unwrap();
+        let mut stream = Ppo::new(3, MAType::SMA, 10, MAType::SMA, 4, MAType::EMA).unwrap();
+        for (i, &price) in INPUT_PRICE.iter().enumerate() {
+            let (value, signal, histogram) =
+                ppo_inc(price, &mut fast_ma, &mut slow_ma, &mut signal_ma).unwrap();
+            match stream.update(price).unwrap() {
+                Some((stream_value, stream_signal, stream_histogram)) => {
+                    assert!(i >= lookback);
+                    assert_relative_eq!(value, output_ppo[i], epsilon = 0.00001);
+                    assert_relative_eq!(signal, output_signal[i], epsilon = 0.00001);
+                    assert_relative_eq!(histogram, output_histogram[i], epsilon = 0.00001);
+                    assert_relative_eq!(stream_value, value, epsilon = 0.00001);
+                    assert_relative_eq!(stream_signal, signal, epsilon = 0.00001);
+                    assert_relative_eq!(stream_histogram, histogram, epsilon = 0.00001);
+                }
+                None => {
+                    assert!(i < lookback);
+                    assert!(signal.is_nan());
+                }
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_ppo_zero_slow_ma() {
+        let input_price = [0.0; 6];
+        let mut output_ppo = vec![0.0; 6];
+        let mut output_signal = vec![0.0; 6];
+        let mut output_histogram = vec![0.0; 6];
+        let mut output_fast_ma = vec![0.0; 6];
+        let mut output_slow_ma = vec![0.0; 6];
+        ppo(
+            &input_price,
+            2,
+            MAType::SMA,
+            3,
+            MAType::SMA,
+            2,
+            MAType::SMA,
+            &mut output_ppo,
+            &mut output_signal,
+            &mut output_histogram,
+            &mut output_fast_ma,
+            &mut output_slow_ma,
+        )
+        .unwrap();
+        assert_eq!(output_ppo[5], 0.0);
+        assert_eq!(output_histogram[5], 0.0);
+    }
+
+    #[test]
+    fn test_ppo_invalid_periods() {
+        // A shorter slow period swaps the two lines, as in TA-Lib
+        assert_eq!(
+            lookback(26, MAType::EMA, 12, MAType::EMA, 9, MAType::EMA).unwrap(),
+            lookback(12, MAType::EMA, 26, MAType::EMA, 9, MAType::EMA).unwrap()
+        );
+        assert!(lookback(1, MAType::EMA, 12, MAType::EMA, 9, MAType::EMA).is_err());
+        assert!(Ppo::new(12, MAType::EMA, 26, MAType::EMA, 1, MAType::SMA).is_err());
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{Indicator, KandError, TAFloat};
+
+/// Returns the lookback period required for Price Volume Trend calculation.
+///
+/// # Description
+/// PVT starts at zero on the first bar, so no lookback period is needed.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns 0 as the lookback period
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::pvt;
+/// assert_eq!(pvt::lookback().unwrap(), 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Calculates the Price Volume Trend (PVT).
+///
+/// # Description
+/// PVT is a cumulative volume line like [`obv`](super::obv), but instead of adding or
+/// subtracting the whole volume it adds the share of volume proportional to the percentage
+/// price change, so small moves contribute little.
+///
+/// # Mathematical Formula
+/// ```text
+/// PVT[0] = 0
+/// PVT[i] = PVT[i-1] + Volume[i] * (Close[i] - Close[i-1]) / Close[i-1]
+/// ```
+/// A previous close of 0 adds nothing.
+///
+/// # Arguments
+/// * `input_close` - Array of close prices
+/// * `input_volume` - Array of volume data
+/// * `output_pvt` - Array storing the PVT values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::pvt;
+///
+/// let input_close = vec![10.0, 11.0, 9.9];
+/// let input_volume = vec![100.0, 200.0, 300.0];
+/// let mut output_pvt = vec![0.0; 3];
+///
+/// pvt::pvt(&input_close, &input_volume, &mut output_pvt).unwrap();
+///
+/// assert_eq!(output_pvt[0], 0.0);
+/// assert_eq!(output_pvt[1], 20.0);
+/// assert!((output_pvt[2] - -10.0).abs() < 1e-9);
+/// ```
+pub fn pvt(
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    output_pvt: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_close.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_volume.len() != len || output_pvt.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_close[i].is_nan() || input_volume[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    output_pvt[0] = 0.0;
+    for i in 1..len {
+        output_pvt[i] = pvt_inc(
+            input_close[i],
+            input_close[i - 1],
+            input_volume[i],
+            output_pvt[i - 1],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next Price Volume Trend value incrementally.
+///
+/// # Arguments
+/// * `input_close` - Current close price
+/// * `prev_close` - Previous close price
+/// * `input_volume` - Current volume
+/// * `prev_pvt` - Previous PVT value
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The new PVT value
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::pvt;
+///
+/// assert_eq!(pvt::pvt_inc(11.0, 10.0, 200.0, 0.0).unwrap(), 20.0);
+/// ```
+pub fn pvt_inc(
+    input_close: TAFloat,
+    prev_close: TAFloat,
+    input_volume: TAFloat,
+    prev_pvt: TAFloat,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_close.is_nan() || prev_close.is_nan() || input_volume.is_nan() || prev_pvt.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    if prev_close == 0.0 {
+        return Ok(prev_pvt);
+    }
+    Ok(input_volume.mul_add((input_close - prev_close) / prev_close, prev_pvt))
+}
+
+/// Streaming Price Volume Trend (PVT) calculator.
+///
+/// Consumes `(close, volume)` bars. The first bar starts the running total at zero, matching
+/// [`pvt`]; every later bar goes through [`pvt_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::pvt::Pvt};
+///
+/// let mut pvt = Pvt::new();
+/// assert_eq!(pvt.update((10.0, 100.0)).unwrap(), Some(0.0));
+/// assert_eq!(pvt.update((11.0, 200.0)).unwrap(), Some(20.0));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Pvt {
+    prev: Option<(TAFloat, TAFloat)>,
+}
+
+impl Pvt {
+    /// Creates a new streaming PVT.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { prev: None }
+    }
+}
+
+impl Indicator for Pvt {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_close, input_volume): (TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        let output_pvt = match self.prev {
+            Some((prev_close, prev_pvt)) => {
+                pvt_inc(input_close, prev_close, input_volume, prev_pvt)?
+            }
+            None => 0.0,
+        };
+        self.prev = Some((input_close, output_pvt));
+        Ok(Some(output_pvt))
+    }
+
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_pvt_calculation() {
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713,
+        ];
+        let len = input_close.len();
+        let mut output_pvt = vec![0.0; len];
+
+        pvt(&input_close, &input_volume, &mut output_pvt).unwrap();
+
+        let mut expected = 0.0;
+        assert_eq!(output_pvt[0], 0.0);
+        for i in 1..len {
+            expected += input_volume[i] * (input_close[i] / input_close[i - 1] - 1.0);
+            assert_relative_eq!(output_pvt[i], expected, epsilon = 1e-9);
+        }
+
+        let mut stream = Pvt::new();
+        for i in 0..len {
+            let value = stream.update((input_close[i], input_volume[i])).unwrap();
+            assert_relative_eq!(value.unwrap(), output_pvt[i], epsilon = 1e-12);
+        }
+        assert!(stream.is_ready());
+    }
+}
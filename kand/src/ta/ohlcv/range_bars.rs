@@ -0,0 +1,449 @@
+use crate::{Indicator, KandError, TAFloat};
+
+/// A single fixed-range bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RangeBar {
+    /// First price of the bar
+    pub open:        TAFloat,
+    /// Highest price of the bar
+    pub high:        TAFloat,
+    /// Lowest price of the bar
+    pub low:         TAFloat,
+    /// Last price of the bar
+    pub close:       TAFloat,
+    /// Index of the source price that opened the bar
+    pub start_index: usize,
+    /// Index of the last source price in the bar
+    pub end_index:   usize,
+}
+
+impl RangeBar {
+    /// Returns a bar opened at `price`.
+    #[must_use]
+    pub const fn base(price: TAFloat, index: usize) -> Self {
+        Self {
+            open:        price,
+            high:        price,
+            low:         price,
+            close:       price,
+            start_index: index,
+            end_index:   index,
+        }
+    }
+}
+
+/// Returns the lookback period required for range bars.
+///
+/// # Description
+/// The first price opens the first bar, so no warm-up is needed.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Always 0
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::range_bars;
+/// assert_eq!(range_bars::lookback().unwrap(), 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Validates the bar range.
+fn check_params(param_range: TAFloat) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if !param_range.is_finite() || param_range <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    let _ = param_range;
+    Ok(())
+}
+
+/// Builds fixed-range bars from a price series.
+///
+/// # Description
+/// Each bar collects prices until a price would stretch its high-low range beyond
+/// `param_range`. The bar is then closed at the edge of the range and the next bar opens at
+/// that close, so every finished bar spans exactly `param_range`. A price far outside the
+/// range completes several bars at once. Range bars work best on trade or close prices;
+/// feeding bar closes ignores moves inside the source bars.
+///
+/// # Mathematical Formula
+/// ```text
+/// price > low + range:  close bar with high = close = low + range, open next bar there
+/// price < high - range: close bar with low = close = high - range, open next bar there
+/// otherwise:            high = max(high, price), low = min(low, price), close = price
+/// ```
+///
+/// # Arguments
+/// * `input_price` - Array of prices, typically trades or closes
+/// * `param_range` - High-low range of each finished bar (must be > 0)
+/// * `output_open` - Cleared, then filled with the open of each bar
+/// * `output_high` - Cleared, then filled with the high of each bar
+/// * `output_low` - Cleared, then filled with the low of each bar
+/// * `output_close` - Cleared, then filled with the close of each bar
+/// * `output_start_index` - Cleared, then filled with the index of the price opening each bar
+/// * `output_end_index` - Cleared, then filled with the index of the last price in each bar
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success; the last bar is still forming
+///
+/// # Errors
+/// * `KandError::InvalidData` - If the input array is empty
+/// * `KandError::InvalidParameter` - If the range is not positive
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::range_bars;
+///
+/// let input_price = vec![10.0, 10.5, 9.8, 11.1, 10.9, 12.5];
+/// let mut output_open = Vec::new();
+/// let mut output_high = Vec::new();
+/// let mut output_low = Vec::new();
+/// let mut output_close = Vec::new();
+/// let mut output_start_index = Vec::new();
+/// let mut output_end_index = Vec::new();
+///
+/// range_bars::range_bars(
+///     &input_price,
+///     1.0,
+///     &mut output_open,
+///     &mut output_high,
+///     &mut output_low,
+///     &mut output_close,
+///     &mut output_start_index,
+///     &mut output_end_index,
+/// )
+/// .unwrap();
+/// // 11.1 closes the first bar at 9.8 + 1.0, 12.5 closes the second one at 11.8
+/// assert_eq!(output_open.len(), 3);
+/// assert_eq!(output_close[0], 10.8);
+/// assert_eq!(output_start_index, vec![0, 3, 5]);
+/// assert_eq!(output_close[2], 12.5);
+/// ```
+pub fn range_bars(
+    input_price: &[TAFloat],
+    param_range: TAFloat,
+    output_open: &mut Vec<TAFloat>,
+    output_high: &mut Vec<TAFloat>,
+    output_low: &mut Vec<TAFloat>,
+    output_close: &mut Vec<TAFloat>,
+    output_start_index: &mut Vec<usize>,
+    output_end_index: &mut Vec<usize>,
+) -> Result<(), KandError> {
+    check_params(param_range)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if input_price.is_empty() {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for value in input_price {
+            // NaN check
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut bars = Vec::new();
+    let mut current = RangeBar::base(input_price[0], 0);
+    for (i, &price) in input_price.iter().enumerate().skip(1) {
+        current = range_bars_inc(price, i, current, param_range, &mut bars)?;
+    }
+    bars.push(current);
+
+    output_open.clear();
+    output_high.clear();
+    output_low.clear();
+    output_close.clear();
+    output_start_index.clear();
+    output_end_index.clear();
+    for bar in bars {
+        output_open.push(bar.open);
+        output_high.push(bar.high);
+        output_low.push(bar.low);
+        output_close.push(bar.close);
+        output_start_index.push(bar.start_index);
+        output_end_index.push(bar.end_index);
+    }
+
+    Ok(())
+}
+
+/// Adds one price to a range bar series.
+///
+/// # Description
+/// Applies the rules described in [`range_bars`] to a single price. Finished bars are appended
+/// to `output_bars`.
+///
+/// # Arguments
+/// * `input_price` - Current price
+/// * `input_index` - Index of the current price
+/// * `prev_bar` - The forming bar, [`RangeBar::base`] of the first price to start a series
+/// * `param_range` - High-low range of each finished bar (must be > 0)
+/// * `output_bars` - Finished bars are appended here
+///
+/// # Returns
+/// * `Result<RangeBar, KandError>` - The forming bar
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If the range is not positive
+/// * `KandError::NaNDetected` - If the input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::range_bars::{self, RangeBar};
+///
+/// let mut bars = Vec::new();
+/// let bar = RangeBar::base(10.0, 0);
+/// // A gap of 2.5 ranges finishes two bars and opens a third at 12.0
+/// let bar = range_bars::range_bars_inc(12.5, 1, bar, 1.0, &mut bars).unwrap();
+/// assert_eq!(bars.len(), 2);
+/// assert_eq!((bars[1].open, bars[1].close), (11.0, 12.0));
+/// assert_eq!((bar.open, bar.close), (12.0, 12.5));
+/// ```
+pub fn range_bars_inc(
+    input_price: TAFloat,
+    input_index: usize,
+    prev_bar: RangeBar,
+    param_range: TAFloat,
+    output_bars: &mut Vec<RangeBar>,
+) -> Result<RangeBar, KandError> {
+    check_params(param_range)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let mut bar = prev_bar;
+    loop {
+        if input_price > bar.low + param_range {
+            bar.high = bar.low + param_range;
+            bar.close = bar.high;
+        } else if input_price < bar.high - param_range {
+            bar.low = bar.high - param_range;
+            bar.close = bar.low;
+        } else {
+            bar.high = bar.high.max(input_price);
+            bar.low = bar.low.min(input_price);
+            bar.close = input_price;
+            bar.end_index = input_index;
+            return Ok(bar);
+        }
+        bar.end_index = input_index;
+        output_bars.push(bar);
+        bar = RangeBar::base(bar.close, input_index);
+    }
+}
+
+/// Streaming range bar builder.
+///
+/// Consumes prices and emits the bars each price finishes, so a large jump can emit several
+/// bars at once. The forming bar is available from [`RangeBars::current`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::range_bars::RangeBars};
+///
+/// let mut range_bars = RangeBars::new(1.0).unwrap();
+/// for price in [10.0, 10.5, 9.8] {
+///     assert!(range_bars.update(price).unwrap().unwrap().is_empty());
+/// }
+/// let bars = range_bars.update(11.1).unwrap().unwrap();
+/// assert_eq!((bars[0].low, bars[0].high), (9.8, 10.8));
+/// assert_eq!(range_bars.current().unwrap().open, 10.8);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RangeBars {
+    param_range: TAFloat,
+    index:       usize,
+    bar:         Option<RangeBar>,
+}
+
+impl RangeBars {
+    /// Creates a new streaming range bar builder.
+    ///
+    /// # Arguments
+    /// * `param_range` - High-low range of each finished bar (must be > 0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the range is not positive
+    pub fn new(param_range: TAFloat) -> Result<Self, KandError> {
+        check_params(param_range)?;
+        Ok(Self {
+            param_range,
+            index: 0,
+            bar: None,
+        })
+    }
+
+    /// Returns the bar that is still forming, `None` before the first price.
+    pub const fn current(&self) -> Option<RangeBar> {
+        self.bar
+    }
+}
+
+impl Indicator for RangeBars {
+    type Input = TAFloat;
+    type Output = Vec<RangeBar>;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<Vec<RangeBar>>, KandError> {
+        let input_index = self.index;
+        self.index += 1;
+
+        let Some(bar) = self.bar else {
+            self.bar = Some(RangeBar::base(input_price, input_index));
+            return Ok(Some(Vec::new()));
+        };
+
+        let mut bars = Vec::new();
+        self.bar = Some(range_bars_inc(
+            input_price,
+            input_index,
+            bar,
+            self.param_range,
+            &mut bars,
+        )?);
+        Ok(Some(bars))
+    }
+
+    fn reset(&mut self) {
+        self.index = 0;
+        self.bar = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.bar.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    fn run(param_range: TAFloat) -> Vec<RangeBar> {
+        let mut output_open = Vec::new();
+        let mut output_high = Vec::new();
+        let mut output_low = Vec::new();
+        let mut output_close = Vec::new();
+        let mut output_start_index = Vec::new();
+        let mut output_end_index = Vec::new();
+        range_bars(
+            &INPUT_PRICE,
+            param_range,
+            &mut output_open,
+            &mut output_high,
+            &mut output_low,
+            &mut output_close,
+            &mut output_start_index,
+            &mut output_end_index,
+        )
+        .unwrap();
+        (0..output_open.len())
+            .map(|i| RangeBar {
+                open:        output_open[i],
+                high:        output_high[i],
+                low:         output_low[i],
+                close:       output_close[i],
+                start_index: output_start_index[i],
+                end_index:   output_end_index[i],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_range_bars() {
+        let bars = run(60.0);
+
+        let expected = [
+            (35216.1, 35230.0, 35170.0, 35230.0, 0, 5),
+            (35230.0, 35254.6, 35194.6, 35194.6, 5, 9),
+            (35194.6, 35229.9, 35169.9, 35169.9, 9, 13),
+            (35169.9, 35169.9, 35109.9, 35109.9, 13, 14),
+            (35109.9, 35109.9, 35049.9, 35049.9, 14, 15),
+            (35049.9, 35059.3, 34999.3, 35059.3, 15, 18),
+            (35059.3, 35069.0, 35009.0, 35009.0, 18, 20),
+            (35009.0, 35009.0, 34949.0, 34949.0, 20, 20),
+            (34949.0, 34999.5, 34939.5, 34999.5, 20, 22),
+            (34999.5, 35059.5, 34999.5, 35059.5, 22, 24),
+            (35059.5, 35080.0, 35059.5, 35080.0, 24, 24),
+        ];
+        assert_eq!(bars.len(), expected.len());
+        for (bar, &(open, high, low, close, start_index, end_index)) in bars.iter().zip(&expected) {
+            assert_relative_eq!(bar.open, open, epsilon = 1e-8);
+            assert_relative_eq!(bar.high, high, epsilon = 1e-8);
+            assert_relative_eq!(bar.low, low, epsilon = 1e-8);
+            assert_relative_eq!(bar.close, close, epsilon = 1e-8);
+            assert_eq!(bar.start_index, start_index);
+            assert_eq!(bar.end_index, end_index);
+        }
+    }
+
+    #[test]
+    fn test_range_bars_are_contiguous() {
+        let bars = run(25.0);
+        let (last, finished) = bars.split_last().unwrap();
+        assert!(last.high - last.low <= 25.0);
+        for bar in finished {
+            assert_relative_eq!(bar.high - bar.low, 25.0, epsilon = 1e-8);
+        }
+        for pair in bars.windows(2) {
+            assert_eq!(pair[1].open, pair[0].close);
+        }
+    }
+
+    #[test]
+    fn test_range_bars_stream() {
+        let expected = run(30.0);
+        let mut stream = RangeBars::new(30.0).unwrap();
+        assert!(stream.current().is_none());
+        let mut bars = Vec::new();
+        for price in INPUT_PRICE {
+            bars.extend(stream.update(price).unwrap().unwrap());
+        }
+        bars.extend(stream.current());
+        assert_eq!(bars, expected);
+
+        stream.reset();
+        assert!(!stream.is_ready());
+    }
+
+    #[test]
+    fn test_range_bars_invalid_params() {
+        assert!(RangeBars::new(0.0).is_err());
+        assert!(RangeBars::new(TAFloat::INFINITY).is_err());
+        let mut bars = Vec::new();
+        assert!(matches!(
+            range_bars_inc(1.0, 1, RangeBar::base(1.0, 0), -1.0, &mut bars),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
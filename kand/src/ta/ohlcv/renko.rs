@@ -1 +1,831 @@
-// https://br.tradingview.com/script/9BKOIhdl-Numbers-Renko/
+use super::atr::{self, Atr};
+use crate::{Indicator, KandError, TAFloat, TAInt, types::PriceSource};
+
+/// A single Renko brick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RenkoBrick {
+    /// Price level where the brick starts
+    pub open:      TAFloat,
+    /// Price level where the brick ends, one box size away from `open`
+    pub close:     TAFloat,
+    /// 1 for an up brick, -1 for a down brick
+    pub direction: TAInt,
+    /// Index of the source bar that completed the brick
+    pub index:     usize,
+}
+
+/// Returns the lookback period required for Renko with a fixed box size.
+///
+/// # Description
+/// The first bar only sets the base price, so bricks can form from the second bar on. With an
+/// ATR-derived box size the lookback is [`atr::lookback`] instead.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Always 0
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::renko;
+/// assert_eq!(renko::lookback().unwrap(), 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Validates the box size and the reversal brick count.
+fn check_params(param_box_size: TAFloat, param_reversal: usize) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if !param_box_size.is_finite() || param_box_size <= 0.0 || param_reversal == 0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    let _ = (param_box_size, param_reversal);
+    Ok(())
+}
+
+/// Builds Renko bricks from a price series using a fixed box size.
+///
+/// # Description
+/// Renko charts ignore time and only draw a brick once price has moved a full box size. The
+/// first close is the base price. A new brick in the current direction needs a move of one box
+/// beyond the last brick close; a brick against it needs `param_reversal` boxes, and the
+/// reversal bricks are drawn from `param_reversal - 1` boxes away from the last close, so the
+/// classic reversal of 2 starts from the open of the last brick. A single bar can complete
+/// several bricks, and with [`PriceSource::HighLow`] it can complete bricks in both
+/// directions, the current direction being checked first.
+///
+/// # Mathematical Formula
+/// ```text
+/// start = last_close                                   (continuation or first brick)
+///       = last_close ± (reversal - 1) * box_size       (reversal)
+/// count = floor(|price - start| / box_size)
+/// brick_k: open = start ± k * box_size, close = open ± box_size, for k in 0..count
+/// ```
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `param_box_size` - Price distance covered by each brick (must be > 0)
+/// * `param_reversal` - Boxes needed to reverse the direction (must be >= 1, typically 2)
+/// * `param_source` - Whether moves are measured on closes or on highs and lows
+/// * `output_open` - Cleared, then filled with the open of each brick
+/// * `output_close` - Cleared, then filled with the close of each brick
+/// * `output_direction` - Cleared, then filled with the direction of each brick (1 up, -1 down)
+/// * `output_index` - Cleared, then filled with the source bar index of each brick
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success, the outputs all have one entry per brick
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If the box size is not positive or the reversal is 0
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::renko, types::PriceSource};
+///
+/// let input_close = vec![10.0, 11.2, 13.1, 12.5, 10.9];
+/// let mut output_open = Vec::new();
+/// let mut output_close = Vec::new();
+/// let mut output_direction = Vec::new();
+/// let mut output_index = Vec::new();
+///
+/// renko::renko(
+///     &input_close,
+///     &input_close,
+///     &input_close,
+///     1.0,
+///     2,
+///     PriceSource::Close,
+///     &mut output_open,
+///     &mut output_close,
+///     &mut output_direction,
+///     &mut output_index,
+/// )
+/// .unwrap();
+/// // Three up bricks, then a reversal once price is two boxes below 13
+/// assert_eq!(output_close, vec![11.0, 12.0, 13.0, 11.0]);
+/// assert_eq!(output_direction, vec![1, 1, 1, -1]);
+/// assert_eq!(output_index, vec![1, 2, 2, 4]);
+/// ```
+pub fn renko(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_box_size: TAFloat,
+    param_reversal: usize,
+    param_source: PriceSource,
+    output_open: &mut Vec<TAFloat>,
+    output_close: &mut Vec<TAFloat>,
+    output_direction: &mut Vec<TAInt>,
+    output_index: &mut Vec<usize>,
+) -> Result<(), KandError> {
+    check_params(param_box_size, param_reversal)?;
+    check_inputs(input_high, input_low, input_close)?;
+
+    let mut bricks = Vec::new();
+    build_bricks(
+        input_high,
+        input_low,
+        input_close,
+        0,
+        param_box_size,
+        param_reversal,
+        param_source,
+        &mut bricks,
+    )?;
+    split_bricks(
+        &bricks,
+        output_open,
+        output_close,
+        output_direction,
+        output_index,
+    );
+
+    Ok(())
+}
+
+/// Builds Renko bricks from a price series using a box size derived from the ATR.
+///
+/// # Description
+/// The box size is the ATR value of the first bar where it is available, i.e. bar
+/// `atr::lookback(param_atr_period)`, whose close becomes the base price. Fixing the box at
+/// that point avoids looking ahead, so the streaming [`Renko::with_atr`] produces the same
+/// bricks. Bricks are otherwise formed exactly as in [`renko`].
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `param_atr_period` - ATR period used for the box size (must be >= 2, typically 14)
+/// * `param_reversal` - Boxes needed to reverse the direction (must be >= 1, typically 2)
+/// * `param_source` - Whether moves are measured on closes or on highs and lows
+/// * `output_open` - Cleared, then filled with the open of each brick
+/// * `output_close` - Cleared, then filled with the close of each brick
+/// * `output_direction` - Cleared, then filled with the direction of each brick (1 up, -1 down)
+/// * `output_index` - Cleared, then filled with the source bar index of each brick
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The box size that was used
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If the ATR period is less than 2, the reversal is 0 or
+///   the ATR is zero
+/// * `KandError::InsufficientData` - If input length <= ATR lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::renko, types::PriceSource};
+///
+/// let input_high = vec![10.5, 11.0, 11.5, 12.5, 14.0, 13.0];
+/// let input_low = vec![9.5, 10.0, 10.5, 11.5, 12.5, 10.0];
+/// let input_close = vec![10.0, 10.5, 11.0, 12.0, 13.5, 10.5];
+/// let mut output_open = Vec::new();
+/// let mut output_close = Vec::new();
+/// let mut output_direction = Vec::new();
+/// let mut output_index = Vec::new();
+///
+/// let box_size = renko::renko_atr(
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     2,
+///     2,
+///     PriceSource::Close,
+///     &mut output_open,
+///     &mut output_close,
+///     &mut output_direction,
+///     &mut output_index,
+/// )
+/// .unwrap();
+/// assert_eq!(box_size, 1.0);
+/// assert_eq!(output_close, vec![12.0, 13.0, 11.0]);
+/// ```
+pub fn renko_atr(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_atr_period: usize,
+    param_reversal: usize,
+    param_source: PriceSource,
+    output_open: &mut Vec<TAFloat>,
+    output_close: &mut Vec<TAFloat>,
+    output_direction: &mut Vec<TAInt>,
+    output_index: &mut Vec<usize>,
+) -> Result<TAFloat, KandError> {
+    let lookback = atr::lookback(param_atr_period)?;
+    check_inputs(input_high, input_low, input_close)?;
+
+    let mut output_atr = vec![0.0; input_close.len()];
+    atr::atr(
+        input_high,
+        input_low,
+        input_close,
+        param_atr_period,
+        &mut output_atr,
+    )?;
+    let box_size = output_atr[lookback];
+    check_params(box_size, param_reversal)?;
+
+    let mut bricks = Vec::new();
+    build_bricks(
+        &input_high[lookback..],
+        &input_low[lookback..],
+        &input_close[lookback..],
+        lookback,
+        box_size,
+        param_reversal,
+        param_source,
+        &mut bricks,
+    )?;
+    split_bricks(
+        &bricks,
+        output_open,
+        output_close,
+        output_direction,
+        output_index,
+    );
+
+    Ok(box_size)
+}
+
+/// Checks the input arrays shared by [`renko`] and [`renko_atr`].
+fn check_inputs(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if input_close.is_empty() {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_high.len() != input_close.len() || input_low.len() != input_close.len() {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..input_close.len() {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let _ = (input_high, input_low, input_close);
+    Ok(())
+}
+
+/// Runs [`renko_inc`] over the bars, using the first close as the base price.
+fn build_bricks(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_first_index: usize,
+    param_box_size: TAFloat,
+    param_reversal: usize,
+    param_source: PriceSource,
+    output_bricks: &mut Vec<RenkoBrick>,
+) -> Result<(), KandError> {
+    let mut brick_close = input_close[0];
+    let mut direction = 0;
+    for i in 1..input_close.len() {
+        (brick_close, direction) = renko_inc(
+            input_high[i],
+            input_low[i],
+            input_close[i],
+            param_first_index + i,
+            brick_close,
+            direction,
+            param_box_size,
+            param_reversal,
+            param_source,
+            output_bricks,
+        )?;
+    }
+    Ok(())
+}
+
+/// Copies the brick fields into the per-field batch outputs.
+fn split_bricks(
+    bricks: &[RenkoBrick],
+    output_open: &mut Vec<TAFloat>,
+    output_close: &mut Vec<TAFloat>,
+    output_direction: &mut Vec<TAInt>,
+    output_index: &mut Vec<usize>,
+) {
+    output_open.clear();
+    output_close.clear();
+    output_direction.clear();
+    output_index.clear();
+    for brick in bricks {
+        output_open.push(brick.open);
+        output_close.push(brick.close);
+        output_direction.push(brick.direction);
+        output_index.push(brick.index);
+    }
+}
+
+/// Appends the bricks of one direction completed by `price`, returning the new brick close.
+fn push_bricks(
+    price: TAFloat,
+    brick_close: TAFloat,
+    offset: usize,
+    direction: TAInt,
+    param_box_size: TAFloat,
+    input_index: usize,
+    output_bricks: &mut Vec<RenkoBrick>,
+) -> Option<TAFloat> {
+    let step = direction as TAFloat * param_box_size;
+    let start = (offset as TAFloat).mul_add(step, brick_close);
+    let count = ((price - start) / step).floor();
+    if count < 1.0 {
+        return None;
+    }
+    let count = count as usize;
+    for k in 0..count {
+        let open = (k as TAFloat).mul_add(step, start);
+        output_bricks.push(RenkoBrick {
+            open,
+            close: open + step,
+            direction,
+            index: input_index,
+        });
+    }
+    Some((count as TAFloat).mul_add(step, start))
+}
+
+/// Appends the Renko bricks completed by a new bar.
+///
+/// # Description
+/// Applies the brick rules of [`renko`] to a single bar. The state is the close of the last
+/// brick, or the base price before the first brick, and the direction of the last brick.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `input_index` - Index of the current bar, stored in the bricks
+/// * `prev_brick_close` - Close of the last brick, or the base price
+/// * `prev_direction` - Direction of the last brick: 1 up, -1 down, 0 before the first brick
+/// * `param_box_size` - Price distance covered by each brick (must be > 0)
+/// * `param_reversal` - Boxes needed to reverse the direction (must be >= 1)
+/// * `param_source` - Whether moves are measured on closes or on highs and lows
+/// * `output_bricks` - Completed bricks are appended here
+///
+/// # Returns
+/// * `Result<(TAFloat, TAInt), KandError>` - The new (brick close, direction) state
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If the box size is not positive or the reversal is 0
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::renko, types::PriceSource};
+///
+/// let mut bricks = Vec::new();
+/// // Last brick went up to 13; a high of 14.2 adds one more, a low of 10.9 then reverses
+/// let (brick_close, direction) = renko::renko_inc(
+///     14.2,
+///     10.9,
+///     11.5,
+///     7,
+///     13.0,
+///     1,
+///     1.0,
+///     2,
+///     PriceSource::HighLow,
+///     &mut bricks,
+/// )
+/// .unwrap();
+/// assert_eq!((brick_close, direction), (11.0, -1));
+/// assert_eq!(bricks.len(), 3);
+/// assert_eq!((bricks[0].open, bricks[0].close), (13.0, 14.0));
+/// assert_eq!((bricks[1].open, bricks[1].close), (13.0, 12.0));
+/// ```
+pub fn renko_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    input_index: usize,
+    prev_brick_close: TAFloat,
+    prev_direction: TAInt,
+    param_box_size: TAFloat,
+    param_reversal: usize,
+    param_source: PriceSource,
+    output_bricks: &mut Vec<RenkoBrick>,
+) -> Result<(TAFloat, TAInt), KandError> {
+    check_params(param_box_size, param_reversal)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_high.is_nan()
+            || input_low.is_nan()
+            || input_close.is_nan()
+            || prev_brick_close.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (up_price, down_price) = param_source.prices(input_high, input_low, input_close);
+    let mut brick_close = prev_brick_close;
+    let mut direction = prev_direction;
+
+    // The current direction is checked first, an undecided chart tries up first
+    let order: [TAInt; 2] = if direction < 0 { [-1, 1] } else { [1, -1] };
+    for side in order {
+        let price = if side > 0 { up_price } else { down_price };
+        let offset = if direction == -side {
+            param_reversal - 1
+        } else {
+            0
+        };
+        if let Some(close) = push_bricks(
+            price,
+            brick_close,
+            offset,
+            side,
+            param_box_size,
+            input_index,
+            output_bricks,
+        ) {
+            brick_close = close;
+            direction = side;
+        }
+    }
+
+    Ok((brick_close, direction))
+}
+
+/// Streaming Renko brick builder.
+///
+/// Consumes `(high, low, close)` bars and emits the bricks each bar completes, which may be
+/// none, one or several. The box size is either fixed or taken from the first ATR value, as
+/// in [`renko_atr`]. Brick indices count every bar fed to the stream.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::renko::Renko, types::PriceSource};
+///
+/// let mut renko = Renko::new(1.0, 2, PriceSource::Close).unwrap();
+/// assert!(
+///     renko
+///         .update((10.0, 10.0, 10.0))
+///         .unwrap()
+///         .unwrap()
+///         .is_empty()
+/// );
+/// assert!(
+///     renko
+///         .update((10.5, 10.5, 10.5))
+///         .unwrap()
+///         .unwrap()
+///         .is_empty()
+/// );
+/// let bricks = renko.update((12.3, 12.3, 12.3)).unwrap().unwrap();
+/// assert_eq!(bricks.len(), 2);
+/// assert_eq!(bricks[1].close, 12.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Renko {
+    param_box_size: TAFloat,
+    param_reversal: usize,
+    param_source:   PriceSource,
+    atr:            Option<Atr>,
+    box_size:       Option<TAFloat>,
+    index:          usize,
+    brick_close:    TAFloat,
+    direction:      TAInt,
+}
+
+impl Renko {
+    /// Creates a new streaming Renko builder with a fixed box size.
+    ///
+    /// # Arguments
+    /// * `param_box_size` - Price distance covered by each brick (must be > 0)
+    /// * `param_reversal` - Boxes needed to reverse the direction (must be >= 1, typically 2)
+    /// * `param_source` - Whether moves are measured on closes or on highs and lows
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the box size is not positive or the reversal is 0
+    pub fn new(
+        param_box_size: TAFloat,
+        param_reversal: usize,
+        param_source: PriceSource,
+    ) -> Result<Self, KandError> {
+        check_params(param_box_size, param_reversal)?;
+        Ok(Self {
+            param_box_size,
+            param_reversal,
+            param_source,
+            atr: None,
+            box_size: None,
+            index: 0,
+            brick_close: TAFloat::NAN,
+            direction: 0,
+        })
+    }
+
+    /// Creates a new streaming Renko builder whose box size is the first ATR value.
+    ///
+    /// # Arguments
+    /// * `param_atr_period` - ATR period used for the box size (must be >= 2, typically 14)
+    /// * `param_reversal` - Boxes needed to reverse the direction (must be >= 1, typically 2)
+    /// * `param_source` - Whether moves are measured on closes or on highs and lows
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the ATR period is less than 2 or the reversal is 0
+    pub fn with_atr(
+        param_atr_period: usize,
+        param_reversal: usize,
+        param_source: PriceSource,
+    ) -> Result<Self, KandError> {
+        check_params(1.0, param_reversal)?;
+        Ok(Self {
+            param_box_size: TAFloat::NAN,
+            param_reversal,
+            param_source,
+            atr: Some(Atr::new(param_atr_period)?),
+            box_size: None,
+            index: 0,
+            brick_close: TAFloat::NAN,
+            direction: 0,
+        })
+    }
+
+    /// Returns the box size in use, `None` while the ATR is warming up.
+    pub const fn box_size(&self) -> Option<TAFloat> {
+        self.box_size
+    }
+}
+
+impl Indicator for Renko {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = Vec<RenkoBrick>;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<Vec<RenkoBrick>>, KandError> {
+        let input_index = self.index;
+        self.index += 1;
+
+        let Some(box_size) = self.box_size else {
+            // The bar that fixes the box size only sets the base price
+            let box_size = match &mut self.atr {
+                Some(atr) => match atr.update((input_high, input_low, input_close))? {
+                    Some(value) => value,
+                    None => return Ok(None),
+                },
+                None => self.param_box_size,
+            };
+            check_params(box_size, self.param_reversal)?;
+            self.box_size = Some(box_size);
+            self.brick_close = input_close;
+            return Ok(Some(Vec::new()));
+        };
+
+        let mut bricks = Vec::new();
+        (self.brick_close, self.direction) = renko_inc(
+            input_high,
+            input_low,
+            input_close,
+            input_index,
+            self.brick_close,
+            self.direction,
+            box_size,
+            self.param_reversal,
+            self.param_source,
+            &mut bricks,
+        )?;
+        Ok(Some(bricks))
+    }
+
+    fn reset(&mut self) {
+        if let Some(atr) = &mut self.atr {
+            atr.reset();
+        }
+        self.box_size = None;
+        self.index = 0;
+        self.brick_close = TAFloat::NAN;
+        self.direction = 0;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.box_size.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.atr.as_ref().map_or(0, Atr::lookback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT_HIGH: [TAFloat; 25] = [
+        35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0, 35210.0,
+        35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5, 35078.8, 35085.0,
+        35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+    ];
+    const INPUT_LOW: [TAFloat; 25] = [
+        35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0, 35166.0,
+        35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0, 35012.3, 35022.2,
+        34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+    ];
+    const INPUT_CLOSE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    fn run(
+        param_box_size: TAFloat,
+        param_reversal: usize,
+        param_source: PriceSource,
+    ) -> (Vec<TAFloat>, Vec<TAFloat>, Vec<TAInt>, Vec<usize>) {
+        let mut output_open = Vec::new();
+        let mut output_close = Vec::new();
+        let mut output_direction = Vec::new();
+        let mut output_index = Vec::new();
+        renko(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            param_box_size,
+            param_reversal,
+            param_source,
+            &mut output_open,
+            &mut output_close,
+            &mut output_direction,
+            &mut output_index,
+        )
+        .unwrap();
+        (output_open, output_close, output_direction, output_index)
+    }
+
+    #[test]
+    fn test_renko_close() {
+        let (output_open, output_close, output_direction, output_index) =
+            run(50.0, 2, PriceSource::Close);
+
+        // Base 35216.1: the close falls one box at a time, then a reversal at bar 24 needs
+        // two boxes above 34966.1 and starts from the open of the last down brick
+        assert_eq!(output_direction, vec![-1, -1, -1, -1, -1, 1]);
+        assert_eq!(output_index, vec![13, 14, 15, 16, 20, 24]);
+        let expected_open = [35216.1, 35166.1, 35116.1, 35066.1, 35016.1, 35016.1];
+        let expected_close = [35166.1, 35116.1, 35066.1, 35016.1, 34966.1, 35066.1];
+        for i in 0..output_close.len() {
+            approx::assert_relative_eq!(output_open[i], expected_open[i], epsilon = 1e-6);
+            approx::assert_relative_eq!(output_close[i], expected_close[i], epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_renko_bricks_are_contiguous() {
+        for source in [PriceSource::Close, PriceSource::HighLow] {
+            for reversal in 1..=3 {
+                let (output_open, output_close, output_direction, _) = run(20.0, reversal, source);
+                assert!(!output_close.is_empty());
+                for i in 0..output_close.len() {
+                    approx::assert_relative_eq!(
+                        output_close[i] - output_open[i],
+                        20.0 * output_direction[i] as TAFloat,
+                        epsilon = 1e-6
+                    );
+                    if i == 0 {
+                        continue;
+                    }
+                    if output_direction[i] == output_direction[i - 1] {
+                        approx::assert_relative_eq!(
+                            output_open[i],
+                            output_close[i - 1],
+                            epsilon = 1e-6
+                        );
+                    } else {
+                        // A reversal starts `reversal - 1` boxes back from the last close
+                        approx::assert_relative_eq!(
+                            (output_open[i] - output_close[i - 1]).abs(),
+                            20.0 * (reversal - 1) as TAFloat,
+                            epsilon = 1e-6
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_renko_stream() {
+        let (output_open, output_close, output_direction, output_index) =
+            run(20.0, 2, PriceSource::HighLow);
+
+        let mut stream = Renko::new(20.0, 2, PriceSource::HighLow).unwrap();
+        let mut bricks = Vec::new();
+        for i in 0..INPUT_CLOSE.len() {
+            let new_bricks = stream
+                .update((INPUT_HIGH[i], INPUT_LOW[i], INPUT_CLOSE[i]))
+                .unwrap()
+                .unwrap();
+            assert!(new_bricks.iter().all(|brick| brick.index == i));
+            bricks.extend(new_bricks);
+        }
+        assert_eq!(bricks.len(), output_close.len());
+        for (i, brick) in bricks.iter().enumerate() {
+            assert_eq!(brick.open, output_open[i]);
+            assert_eq!(brick.close, output_close[i]);
+            assert_eq!(brick.direction, output_direction[i]);
+            assert_eq!(brick.index, output_index[i]);
+        }
+    }
+
+    #[test]
+    fn test_renko_atr() {
+        let mut output_open = Vec::new();
+        let mut output_close = Vec::new();
+        let mut output_direction = Vec::new();
+        let mut output_index = Vec::new();
+        let box_size = renko_atr(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            5,
+            2,
+            PriceSource::Close,
+            &mut output_open,
+            &mut output_close,
+            &mut output_direction,
+            &mut output_index,
+        )
+        .unwrap();
+
+        let mut output_atr = vec![0.0; INPUT_CLOSE.len()];
+        atr::atr(&INPUT_HIGH, &INPUT_LOW, &INPUT_CLOSE, 5, &mut output_atr).unwrap();
+        assert_eq!(box_size, output_atr[5]);
+        assert!(output_index.iter().all(|&index| index > 5));
+
+        let mut stream = Renko::with_atr(5, 2, PriceSource::Close).unwrap();
+        assert_eq!(stream.lookback(), 5);
+        let mut bricks = Vec::new();
+        for i in 0..INPUT_CLOSE.len() {
+            match stream
+                .update((INPUT_HIGH[i], INPUT_LOW[i], INPUT_CLOSE[i]))
+                .unwrap()
+            {
+                Some(new_bricks) => bricks.extend(new_bricks),
+                None => assert!(i < 5),
+            }
+        }
+        assert_eq!(stream.box_size(), Some(box_size));
+        let stream_close: Vec<TAFloat> = bricks.iter().map(|brick| brick.close).collect();
+        assert_eq!(stream_close, output_close);
+
+        stream.reset();
+        assert!(!stream.is_ready());
+        assert_eq!(stream.box_size(), None);
+    }
+
+    #[test]
+    fn test_renko_invalid_params() {
+        let mut bricks = Vec::new();
+        for (box_size, reversal) in [(0.0, 2), (-1.0, 2), (TAFloat::NAN, 2), (1.0, 0)] {
+            assert!(matches!(
+                renko_inc(
+                    1.0,
+                    1.0,
+                    1.0,
+                    0,
+                    1.0,
+                    0,
+                    box_size,
+                    reversal,
+                    PriceSource::Close,
+                    &mut bricks
+                ),
+                Err(KandError::InvalidParameter)
+            ));
+        }
+        assert!(Renko::new(0.0, 2, PriceSource::Close).is_err());
+        assert!(Renko::with_atr(1, 2, PriceSource::Close).is_err());
+    }
+}
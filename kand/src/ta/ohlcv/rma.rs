@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Calculates the lookback period required for RMA calculation.
 ///
@@ -184,3 +184,108 @@ pub fn rma_inc(
     let alpha = 1.0 / param_period as TAFloat;
     Ok(input_current.mul_add(alpha, prev_rma * (1.0 - alpha)))
 }
+
+/// Streaming Wilder's Moving Average (RMA) calculator.
+///
+/// The first value is seeded with [`rma`] and every later value comes from [`rma_inc`],
+/// so the stream matches the batch output.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::rma::Rma};
+///
+/// let mut rma = Rma::new(3).unwrap();
+/// assert_eq!(rma.update(1.0).unwrap(), None);
+/// assert_eq!(rma.update(2.0).unwrap(), None);
+/// assert_eq!(rma.update(3.0).unwrap(), Some(2.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rma {
+    param_period: usize,
+    lookback:     usize,
+    warmup:       Vec<TAFloat>,
+    prev_rma:     Option<TAFloat>,
+}
+
+impl Rma {
+    /// Creates a new streaming RMA.
+    ///
+    /// # Arguments
+    /// * `param_period` - The smoothing period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            warmup: Vec::with_capacity(param_period),
+            prev_rma: None,
+        })
+    }
+}
+
+impl Indicator for Rma {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if let Some(prev_rma) = self.prev_rma {
+            self.prev_rma = Some(rma_inc(input_price, prev_rma, self.param_period)?);
+            return Ok(self.prev_rma);
+        }
+
+        self.warmup.push(input_price);
+        if self.warmup.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let mut output_rma = vec![0.0; self.warmup.len()];
+        rma(&self.warmup, self.param_period, &mut output_rma)?;
+        self.warmup.clear();
+        self.prev_rma = Some(output_rma[self.lookback]);
+        Ok(self.prev_rma)
+    }
+
+    fn reset(&mut self) {
+        self.warmup.clear();
+        self.prev_rma = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_rma.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_rma_stream() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 14;
+        let mut output_rma = vec![0.0; input.len()];
+
+        rma(&input, param_period, &mut output_rma).unwrap();
+
+        let mut stream = Rma::new(param_period).unwrap();
+        for (i, price) in input.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_rma[i], epsilon = 0.00001),
+                None => assert!(output_rma[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period for Rate of Change Percentage (ROCP) calculation.
 ///
@@ -168,6 +170,73 @@ pub fn rocp_inc(input: TAFloat, prev: TAFloat) -> Result<TAFloat, KandError> {
     Ok((input - prev) / prev)
 }
 
+/// Streaming Rate of Change Percentage (ROCP) calculator.
+///
+/// Keeps the last `param_period + 1` prices so the reference price for [`rocp_inc`] is always
+/// available without the caller tracking it.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::rocp::Rocp};
+///
+/// let mut rocp = Rocp::new(2).unwrap();
+/// assert_eq!(rocp.update(10.0).unwrap(), None);
+/// assert_eq!(rocp.update(11.0).unwrap(), None);
+/// assert_eq!(rocp.update(12.5).unwrap(), Some(0.25));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rocp {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<TAFloat>,
+}
+
+impl Rocp {
+    /// Creates a new streaming ROCP.
+    ///
+    /// # Arguments
+    /// * `param_period` - Number of bars between the current and the reference price
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is invalid for [`lookback`]
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period + 1),
+        })
+    }
+}
+
+impl Indicator for Rocp {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        self.window.push_back(input_price);
+        if self.window.len() > self.param_period + 1 {
+            self.window.pop_front();
+        }
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        rocp_inc(input_price, self.window[0]).map(Some)
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -215,4 +284,26 @@ fn test_rocp_calculation() {
             assert_relative_eq!(result, output_rocp[i], epsilon = 0.000_000_1);
         }
     }
+
+    #[test]
+    fn test_rocp_stream() {
+        let input_price = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 10;
+        let mut output_rocp = vec![0.0; input_price.len()];
+
+        rocp(&input_price, param_period, &mut output_rocp).unwrap();
+
+        let mut stream = Rocp::new(param_period).unwrap();
+        for (i, price) in input_price.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_rocp[i], epsilon = 0.00001),
+                None => assert!(output_rocp[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Calculates the lookback period required for RSI (Relative Strength Index) calculation.
 ///
@@ -290,6 +290,116 @@ pub fn rsi_inc(
     Ok((output_rsi, output_avg_gain, output_avg_loss))
 }
 
+/// Streaming Relative Strength Index (RSI) calculator.
+///
+/// Carries the previous price and Wilder-smoothed average gain/loss between bars. The first
+/// value is seeded with [`rsi`] and every later value comes from [`rsi_inc`], so the stream
+/// matches the batch output.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::rsi::Rsi};
+///
+/// let mut rsi = Rsi::new(3).unwrap();
+/// for price in [44.34, 44.09, 44.15] {
+///     assert!(rsi.update(price).unwrap().is_none());
+/// }
+/// let value = rsi.update(43.61).unwrap().unwrap();
+/// assert!((0.0..=100.0).contains(&value));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rsi {
+    param_period:  usize,
+    lookback:      usize,
+    warmup:        Vec<TAFloat>,
+    ready:         bool,
+    prev_price:    TAFloat,
+    prev_avg_gain: TAFloat,
+    prev_avg_loss: TAFloat,
+}
+
+impl Rsi {
+    /// Creates a new streaming RSI.
+    ///
+    /// # Arguments
+    /// * `param_period` - The time period for RSI calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            lookback,
+            warmup: Vec::with_capacity(lookback + 1),
+            ready: false,
+            prev_price: TAFloat::NAN,
+            prev_avg_gain: TAFloat::NAN,
+            prev_avg_loss: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Rsi {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if self.ready {
+            let (output_rsi, output_avg_gain, output_avg_loss) = rsi_inc(
+                input_price,
+                self.prev_price,
+                self.prev_avg_gain,
+                self.prev_avg_loss,
+                self.param_period,
+            )?;
+            self.prev_price = input_price;
+            self.prev_avg_gain = output_avg_gain;
+            self.prev_avg_loss = output_avg_loss;
+            return Ok(Some(output_rsi));
+        }
+
+        self.warmup.push(input_price);
+        if self.warmup.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let len = self.warmup.len();
+        let mut output_rsi = vec![0.0; len];
+        let mut output_avg_gain = vec![0.0; len];
+        let mut output_avg_loss = vec![0.0; len];
+        rsi(
+            &self.warmup,
+            self.param_period,
+            &mut output_rsi,
+            &mut output_avg_gain,
+            &mut output_avg_loss,
+        )?;
+        self.warmup.clear();
+        self.prev_price = input_price;
+        self.prev_avg_gain = output_avg_gain[self.lookback];
+        self.prev_avg_loss = output_avg_loss[self.lookback];
+        self.ready = true;
+        Ok(Some(output_rsi[self.lookback]))
+    }
+
+    fn reset(&mut self) {
+        self.warmup.clear();
+        self.ready = false;
+        self.prev_price = TAFloat::NAN;
+        self.prev_avg_gain = TAFloat::NAN;
+        self.prev_avg_loss = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -356,4 +466,35 @@ fn test_rsi_calculation() {
             prev_price = input_prices[i];
         }
     }
+
+    #[test]
+    fn test_rsi_stream() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 14;
+        let mut output_rsi = vec![0.0; input_prices.len()];
+        let mut output_avg_gain = vec![0.0; input_prices.len()];
+        let mut output_avg_loss = vec![0.0; input_prices.len()];
+
+        rsi(
+            &input_prices,
+            param_period,
+            &mut output_rsi,
+            &mut output_avg_gain,
+            &mut output_avg_loss,
+        )
+        .unwrap();
+
+        let mut stream = Rsi::new(param_period).unwrap();
+        for (i, price) in input_prices.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_rsi[i], epsilon = 0.00001),
+                None => assert!(output_rsi[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
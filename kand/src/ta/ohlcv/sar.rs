@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period required by the Parabolic SAR indicator.
 ///
@@ -341,6 +341,160 @@ pub fn sar_inc(
     Ok((sar, is_long, af, ep))
 }
 
+/// Streaming Parabolic SAR calculator.
+///
+/// Seeds the trend from the first two bars like [`sar`] and advances it with [`sar_inc`]. The
+/// batch function clamps the SAR against the last two bars of the trend, so the stream keeps
+/// one more bar and passes the lower low (or higher high) of the two as the previous bar.
+///
+/// The input tuple is `(high, low)` and the output is the SAR.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::sar::Sar};
+///
+/// let mut sar = Sar::new(0.02, 0.2).unwrap();
+/// assert!(sar.update((10.0, 8.0)).unwrap().is_none());
+/// // Rising highs start a long trend below the first low
+/// assert_eq!(sar.update((12.0, 9.0)).unwrap(), Some(8.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sar {
+    param_acceleration: TAFloat,
+    param_maximum:      TAFloat,
+    lookback:           usize,
+    count:              usize,
+    prev_high:          TAFloat,
+    prev_low:           TAFloat,
+    prev_prev_high:     TAFloat,
+    prev_prev_low:      TAFloat,
+    sar:                TAFloat,
+    is_long:            bool,
+    af:                 TAFloat,
+    ep:                 TAFloat,
+}
+
+impl Sar {
+    /// Creates a new streaming Parabolic SAR.
+    ///
+    /// # Arguments
+    /// * `param_acceleration` - Initial acceleration factor and its step (e.g. 0.02)
+    /// * `param_maximum` - Maximum acceleration factor (e.g. 0.2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the acceleration is not positive or the maximum is
+    ///   not above it
+    pub fn new(param_acceleration: TAFloat, param_maximum: TAFloat) -> Result<Self, KandError> {
+        #[cfg(feature = "check")]
+        {
+            if param_acceleration <= 0.0 || param_maximum <= param_acceleration {
+                return Err(KandError::InvalidParameter);
+            }
+        }
+
+        Ok(Self {
+            param_acceleration,
+            param_maximum,
+            lookback: lookback(param_acceleration, param_maximum)?,
+            count: 0,
+            prev_high: TAFloat::NAN,
+            prev_low: TAFloat::NAN,
+            prev_prev_high: TAFloat::NAN,
+            prev_prev_low: TAFloat::NAN,
+            sar: TAFloat::NAN,
+            is_long: true,
+            af: TAFloat::NAN,
+            ep: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Sar {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low): (TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        #[cfg(feature = "deep-check")]
+        {
+            if input_high.is_nan() || input_low.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.count += 1;
+        if self.count == 1 {
+            self.prev_high = input_high;
+            self.prev_low = input_low;
+            return Ok(None);
+        }
+
+        if self.count == 2 {
+            // Same seeding as the batch function
+            self.is_long = input_high - self.prev_high >= self.prev_low - input_low;
+            self.af = self.param_acceleration;
+            if self.is_long {
+                self.sar = self.prev_low;
+                self.ep = input_high;
+            } else {
+                self.sar = self.prev_high;
+                self.ep = input_low;
+            }
+        } else {
+            // The batch clamps against the two previous bars once a trend is two bars old,
+            // which holds for every bar after the first update
+            let (clamp_high, clamp_low) = if self.count == 3 {
+                (self.prev_high, self.prev_low)
+            } else {
+                (
+                    self.prev_high.max(self.prev_prev_high),
+                    self.prev_low.min(self.prev_prev_low),
+                )
+            };
+            (self.sar, self.is_long, self.af, self.ep) = sar_inc(
+                input_high,
+                input_low,
+                clamp_high,
+                clamp_low,
+                self.sar,
+                self.is_long,
+                self.af,
+                self.ep,
+                self.param_acceleration,
+                self.param_maximum,
+            )?;
+        }
+
+        self.prev_prev_high = self.prev_high;
+        self.prev_prev_low = self.prev_low;
+        self.prev_high = input_high;
+        self.prev_low = input_low;
+        Ok(Some(self.sar))
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.prev_high = TAFloat::NAN;
+        self.prev_low = TAFloat::NAN;
+        self.prev_prev_high = TAFloat::NAN;
+        self.prev_prev_low = TAFloat::NAN;
+        self.sar = TAFloat::NAN;
+        self.is_long = true;
+        self.af = TAFloat::NAN;
+        self.ep = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -437,5 +591,15 @@ fn test_sar_calculation() {
             af = new_af;
             ep = new_ep;
         }
+
+        // Streaming calculation matches the batch output across the reversals
+        let mut stream = Sar::new(param_acceleration, param_maximum).unwrap();
+        for i in 0..input_high.len() {
+            match stream.update((input_high[i], input_low[i])).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_sar[i], epsilon = 0.0001),
+                None => assert!(output_sar[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
     }
 }
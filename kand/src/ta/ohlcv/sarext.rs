@@ -0,0 +1,821 @@
+use crate::{Indicator, KandError, TAFloat};
+
+/// Returns the lookback period required by the Extended Parabolic SAR indicator.
+///
+/// # Description
+/// The first bar is only used to seed the trend, so SAREXT always has a lookback of 1. The
+/// parameters are validated here so every entry point rejects the same inputs.
+///
+/// # Parameters
+/// * `param_start_value` - Initial SAR; 0 detects the trend automatically, a positive value
+///   starts long and a negative value starts short at its absolute value. Type: `TAFloat`
+/// * `param_offset_on_reverse` - Fraction of the SAR added (short) or removed (long) on a
+///   reversal, must be >= 0. Type: `TAFloat`
+/// * `param_af_init_long` - Initial acceleration factor for longs, must be >= 0. Type: `TAFloat`
+/// * `param_af_long` - Acceleration factor step for longs, must be >= 0. Type: `TAFloat`
+/// * `param_af_max_long` - Maximum acceleration factor for longs, must be >= 0. Type: `TAFloat`
+/// * `param_af_init_short` - Initial acceleration factor for shorts, must be >= 0. Type: `TAFloat`
+/// * `param_af_short` - Acceleration factor step for shorts, must be >= 0. Type: `TAFloat`
+/// * `param_af_max_short` - Maximum acceleration factor for shorts, must be >= 0. Type: `TAFloat`
+///
+/// # Returns
+/// * `Ok(usize)` - The lookback period (1)
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if the offset or any acceleration factor is negative
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::sarext;
+///
+/// let lookback = sarext::lookback(0.0, 0.0, 0.02, 0.02, 0.2, 0.02, 0.02, 0.2).unwrap();
+/// assert_eq!(lookback, 1);
+/// ```
+pub fn lookback(
+    _param_start_value: TAFloat,
+    param_offset_on_reverse: TAFloat,
+    param_af_init_long: TAFloat,
+    param_af_long: TAFloat,
+    param_af_max_long: TAFloat,
+    param_af_init_short: TAFloat,
+    param_af_short: TAFloat,
+    param_af_max_short: TAFloat,
+) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_offset_on_reverse < 0.0
+            || param_af_init_long < 0.0
+            || param_af_long < 0.0
+            || param_af_max_long < 0.0
+            || param_af_init_short < 0.0
+            || param_af_short < 0.0
+            || param_af_max_short < 0.0
+        {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(1)
+}
+
+/// Advances SAREXT by one bar from the stop projected for it.
+///
+/// Returns `(signed_sar, next_sar, is_long, af, ep)`. The acceleration factors must already be
+/// capped at their maximums.
+fn step(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    prev_high: TAFloat,
+    prev_low: TAFloat,
+    prev_sar: TAFloat,
+    input_is_long: bool,
+    input_af: TAFloat,
+    input_ep: TAFloat,
+    param_offset_on_reverse: TAFloat,
+    param_af_init_long: TAFloat,
+    param_af_long: TAFloat,
+    param_af_max_long: TAFloat,
+    param_af_init_short: TAFloat,
+    param_af_short: TAFloat,
+    param_af_max_short: TAFloat,
+) -> (TAFloat, TAFloat, bool, TAFloat, TAFloat) {
+    let mut sar = prev_sar;
+    let mut af = input_af;
+    let mut ep = input_ep;
+
+    if input_is_long {
+        if input_low <= sar {
+            // Reverse to short, placing the stop at the extreme of the finished long
+            sar = ep.max(prev_high).max(input_high);
+            sar += sar * param_offset_on_reverse;
+            let output = -sar;
+            af = param_af_init_short;
+            ep = input_low;
+            sar = af.mul_add(ep - sar, sar).max(prev_high).max(input_high);
+            return (output, sar, false, af, ep);
+        }
+        let output = sar;
+        if input_high > ep {
+            ep = input_high;
+            af = (af + param_af_long).min(param_af_max_long);
+        }
+        sar = af.mul_add(ep - sar, sar).min(prev_low).min(input_low);
+        (output, sar, true, af, ep)
+    } else {
+        if input_high >= sar {
+            // Reverse to long, placing the stop at the extreme of the finished short
+            sar = ep.min(prev_low).min(input_low);
+            sar -= sar * param_offset_on_reverse;
+            let output = sar;
+            af = param_af_init_long;
+            ep = input_high;
+            sar = af.mul_add(ep - sar, sar).min(prev_low).min(input_low);
+            return (output, sar, true, af, ep);
+        }
+        let output = -sar;
+        if input_low < ep {
+            ep = input_low;
+            af = (af + param_af_short).min(param_af_max_short);
+        }
+        sar = af.mul_add(ep - sar, sar).max(prev_high).max(input_high);
+        (output, sar, false, af, ep)
+    }
+}
+
+/// Seeds the trend from the first two bars, returning `(sar, is_long, af, ep)` for the second.
+fn seed(
+    input_high: [TAFloat; 2],
+    input_low: [TAFloat; 2],
+    param_start_value: TAFloat,
+    param_af_init_long: TAFloat,
+    param_af_init_short: TAFloat,
+) -> (TAFloat, bool, TAFloat, TAFloat) {
+    let is_long = if param_start_value == 0.0 {
+        // Start short only when the bar shows a one-period -DM
+        let plus_dm = input_high[1] - input_high[0];
+        let minus_dm = input_low[0] - input_low[1];
+        !(minus_dm > 0.0 && plus_dm < minus_dm)
+    } else {
+        param_start_value > 0.0
+    };
+
+    let sar = if param_start_value != 0.0 {
+        param_start_value.abs()
+    } else if is_long {
+        input_low[0]
+    } else {
+        input_high[0]
+    };
+
+    if is_long {
+        (sar, true, param_af_init_long, input_high[1])
+    } else {
+        (sar, false, param_af_init_short, input_low[1])
+    }
+}
+
+/// Calculates the Extended Parabolic SAR (SAREXT) indicator.
+///
+/// # Description
+/// SAREXT extends the Parabolic SAR with a configurable start value, an offset applied on
+/// reversals and separate initial, step and maximum acceleration factors for long and short
+/// positions. Following TA-Lib, the output is signed: positive while long and negative while
+/// short, with the absolute value being the stop level.
+///
+/// # Mathematical Formula
+/// ```text
+/// SAR(t+1) = SAR(t) + AF * (EP - SAR(t))
+/// Long:  SAR(t+1) = min(SAR(t+1), Low(t-1), Low(t)),   AF steps by af_long up to af_max_long
+/// Short: SAR(t+1) = max(SAR(t+1), High(t-1), High(t)), AF steps by af_short up to af_max_short
+///
+/// On reversal the SAR jumps to the extreme point of the finished trend, adjusted by
+/// SAR * offset_on_reverse away from price, and AF restarts at the side's initial value.
+/// ```
+/// Initial and step factors larger than the side's maximum are capped to it.
+///
+/// # Parameters
+/// * `input_high` - Array of high prices. Type: `&[TAFloat]`
+/// * `input_low` - Array of low prices. Type: `&[TAFloat]`
+/// * `param_start_value` - Initial SAR; 0 detects the trend automatically. Type: `TAFloat`
+/// * `param_offset_on_reverse` - Offset fraction applied on reversals (e.g. 0). Type: `TAFloat`
+/// * `param_af_init_long` - Initial long acceleration factor (e.g. 0.02). Type: `TAFloat`
+/// * `param_af_long` - Long acceleration factor step (e.g. 0.02). Type: `TAFloat`
+/// * `param_af_max_long` - Maximum long acceleration factor (e.g. 0.2). Type: `TAFloat`
+/// * `param_af_init_short` - Initial short acceleration factor (e.g. 0.02). Type: `TAFloat`
+/// * `param_af_short` - Short acceleration factor step (e.g. 0.02). Type: `TAFloat`
+/// * `param_af_max_short` - Maximum short acceleration factor (e.g. 0.2). Type: `TAFloat`
+/// * `output_sarext` - Buffer to store signed SAR values. Type: `&mut [TAFloat]`
+/// * `output_next_sar` - Buffer to store the stop projected for the next bar. Type: `&mut [TAFloat]`
+/// * `output_is_long` - Buffer to store trend direction (true=long, false=short). Type: `&mut [bool]`
+/// * `output_af` - Buffer to store acceleration factors. Type: `&mut [TAFloat]`
+/// * `output_ep` - Buffer to store extreme points. Type: `&mut [TAFloat]`
+///
+/// # Returns
+/// * `Ok(())` - Calculation successful
+///
+/// # Errors
+/// * `KandError::InvalidData` - Input arrays are empty
+/// * `KandError::LengthMismatch` - Input/output array lengths don't match
+/// * `KandError::InvalidParameter` - Negative offset or acceleration factor
+/// * `KandError::InsufficientData` - Not enough data points
+/// * `KandError::NaNDetected` - Input contains NaN values
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::sarext;
+///
+/// let high = vec![10.0, 12.0, 15.0, 14.0, 13.0];
+/// let low = vec![8.0, 9.0, 11.0, 10.0, 9.0];
+/// let mut sarext = vec![0.0; 5];
+/// let mut next_sar = vec![0.0; 5];
+/// let mut is_long = vec![false; 5];
+/// let mut af = vec![0.0; 5];
+/// let mut ep = vec![0.0; 5];
+///
+/// sarext::sarext(
+///     &high,
+///     &low,
+///     0.0,  // start value
+///     0.0,  // offset on reverse
+///     0.02, // initial long AF
+///     0.02, // long AF step
+///     0.2,  // maximum long AF
+///     0.02, // initial short AF
+///     0.02, // short AF step
+///     0.2,  // maximum short AF
+///     &mut sarext,
+///     &mut next_sar,
+///     &mut is_long,
+///     &mut af,
+///     &mut ep,
+/// )
+/// .unwrap();
+///
+/// // Long trends are reported as positive values
+/// assert_eq!(sarext[1], 8.0);
+/// ```
+pub fn sarext(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    param_start_value: TAFloat,
+    param_offset_on_reverse: TAFloat,
+    param_af_init_long: TAFloat,
+    param_af_long: TAFloat,
+    param_af_max_long: TAFloat,
+    param_af_init_short: TAFloat,
+    param_af_short: TAFloat,
+    param_af_max_short: TAFloat,
+    output_sarext: &mut [TAFloat],
+    output_next_sar: &mut [TAFloat],
+    output_is_long: &mut [bool],
+    output_af: &mut [TAFloat],
+    output_ep: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback(
+        param_start_value,
+        param_offset_on_reverse,
+        param_af_init_long,
+        param_af_long,
+        param_af_max_long,
+        param_af_init_short,
+        param_af_short,
+        param_af_max_short,
+    )?;
+
+    #[cfg(feature = "check")]
+    {
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+        if len != input_low.len()
+            || len != output_sarext.len()
+            || len != output_next_sar.len()
+            || len != output_is_long.len()
+            || len != output_af.len()
+            || len != output_ep.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            if input_high[i].is_nan() || input_low[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Initial and step factors never exceed the side's maximum
+    let af_init_long = param_af_init_long.min(param_af_max_long);
+    let af_long = param_af_long.min(param_af_max_long);
+    let af_init_short = param_af_init_short.min(param_af_max_short);
+    let af_short = param_af_short.min(param_af_max_short);
+
+    let (mut sar, mut is_long, mut af, mut ep) = seed(
+        [input_high[0], input_high[1]],
+        [input_low[0], input_low[1]],
+        param_start_value,
+        af_init_long,
+        af_init_short,
+    );
+
+    output_sarext[0] = TAFloat::NAN;
+    output_next_sar[0] = TAFloat::NAN;
+    output_is_long[0] = is_long;
+    output_af[0] = 0.0;
+    output_ep[0] = TAFloat::NAN;
+
+    // The second bar serves as its own previous bar, as in TA-Lib
+    for i in lookback..len {
+        let prev = if i == lookback { i } else { i - 1 };
+        let output;
+        (output, sar, is_long, af, ep) = step(
+            input_high[i],
+            input_low[i],
+            input_high[prev],
+            input_low[prev],
+            sar,
+            is_long,
+            af,
+            ep,
+            param_offset_on_reverse,
+            af_init_long,
+            af_long,
+            param_af_max_long,
+            af_init_short,
+            af_short,
+            param_af_max_short,
+        );
+        output_sarext[i] = output;
+        output_next_sar[i] = sar;
+        output_is_long[i] = is_long;
+        output_af[i] = af;
+        output_ep[i] = ep;
+    }
+
+    Ok(())
+}
+
+/// Incrementally updates the Extended Parabolic SAR with new price data.
+///
+/// # Description
+/// Processes one bar from the state left by the previous bar, which has the same shape as the
+/// [`sar_inc`](super::sar::sar_inc) state. The carried SAR is the stop projected for the
+/// current bar, i.e. the `next_sar` returned for the previous bar, because SAREXT clamps it
+/// with the two most recent bars once a bar closes.
+///
+/// # Parameters
+/// * `input_high` - Current period's high price. Type: `TAFloat`
+/// * `input_low` - Current period's low price. Type: `TAFloat`
+/// * `prev_high` - Previous period's high price. Type: `TAFloat`
+/// * `prev_low` - Previous period's low price. Type: `TAFloat`
+/// * `prev_sar` - Stop projected for the current period (previous `next_sar`). Type: `TAFloat`
+/// * `input_is_long` - Current trend direction (true=long, false=short). Type: `bool`
+/// * `input_af` - Current acceleration factor. Type: `TAFloat`
+/// * `input_ep` - Current extreme point. Type: `TAFloat`
+/// * `param_offset_on_reverse` - Offset fraction applied on reversals. Type: `TAFloat`
+/// * `param_af_init_long` - Initial long acceleration factor. Type: `TAFloat`
+/// * `param_af_long` - Long acceleration factor step. Type: `TAFloat`
+/// * `param_af_max_long` - Maximum long acceleration factor. Type: `TAFloat`
+/// * `param_af_init_short` - Initial short acceleration factor. Type: `TAFloat`
+/// * `param_af_short` - Short acceleration factor step. Type: `TAFloat`
+/// * `param_af_max_short` - Maximum short acceleration factor. Type: `TAFloat`
+///
+/// # Returns
+/// A `Result` containing:
+/// * `Ok((TAFloat, TAFloat, bool, TAFloat, TAFloat))` - Tuple containing:
+///   * Signed SAR value for the current period
+///   * Stop projected for the next period
+///   * New trend direction
+///   * Updated acceleration factor
+///   * Updated extreme point
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - Negative offset or acceleration factor
+/// * `KandError::NaNDetected` - Input contains NaN values
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::sarext;
+///
+/// let (sarext, next_sar, is_long, af, ep) = sarext::sarext_inc(
+///     15.0, // current high
+///     14.0, // current low
+///     14.5, // previous high
+///     13.5, // previous low
+///     13.0, // projected SAR
+///     true, // is long trend
+///     0.02, // current AF
+///     14.5, // current EP
+///     0.0,  // offset on reverse
+///     0.02, // initial long AF
+///     0.02, // long AF step
+///     0.2,  // maximum long AF
+///     0.02, // initial short AF
+///     0.02, // short AF step
+///     0.2,  // maximum short AF
+/// )
+/// .unwrap();
+/// assert_eq!(sarext, 13.0);
+/// assert!(is_long && next_sar > sarext);
+/// assert_eq!((af, ep), (0.04, 15.0));
+/// ```
+pub fn sarext_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    prev_high: TAFloat,
+    prev_low: TAFloat,
+    prev_sar: TAFloat,
+    input_is_long: bool,
+    input_af: TAFloat,
+    input_ep: TAFloat,
+    param_offset_on_reverse: TAFloat,
+    param_af_init_long: TAFloat,
+    param_af_long: TAFloat,
+    param_af_max_long: TAFloat,
+    param_af_init_short: TAFloat,
+    param_af_short: TAFloat,
+    param_af_max_short: TAFloat,
+) -> Result<(TAFloat, TAFloat, bool, TAFloat, TAFloat), KandError> {
+    lookback(
+        0.0,
+        param_offset_on_reverse,
+        param_af_init_long,
+        param_af_long,
+        param_af_max_long,
+        param_af_init_short,
+        param_af_short,
+        param_af_max_short,
+    )?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        if input_high.is_nan()
+            || input_low.is_nan()
+            || prev_high.is_nan()
+            || prev_low.is_nan()
+            || prev_sar.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(step(
+        input_high,
+        input_low,
+        prev_high,
+        prev_low,
+        prev_sar,
+        input_is_long,
+        input_af,
+        input_ep,
+        param_offset_on_reverse,
+        param_af_init_long.min(param_af_max_long),
+        param_af_long.min(param_af_max_long),
+        param_af_max_long,
+        param_af_init_short.min(param_af_max_short),
+        param_af_short.min(param_af_max_short),
+        param_af_max_short,
+    ))
+}
+
+/// Streaming Extended Parabolic SAR calculator.
+///
+/// Keeps the previous bar and the [`sarext_inc`] state, seeding the trend from the first two
+/// bars exactly like the batch function.
+///
+/// The input tuple is `(high, low)` and the output is the signed SAR.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::sarext::Sarext};
+///
+/// let mut sarext = Sarext::new(0.0, 0.0, 0.02, 0.02, 0.2, 0.02, 0.02, 0.2).unwrap();
+/// assert!(sarext.update((10.0, 8.0)).unwrap().is_none());
+/// assert_eq!(sarext.update((12.0, 9.0)).unwrap(), Some(8.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sarext {
+    param_start_value: TAFloat,
+    param_offset_on_reverse: TAFloat,
+    param_af_init_long: TAFloat,
+    param_af_long: TAFloat,
+    param_af_max_long: TAFloat,
+    param_af_init_short: TAFloat,
+    param_af_short: TAFloat,
+    param_af_max_short: TAFloat,
+    lookback: usize,
+    count: usize,
+    prev_high: TAFloat,
+    prev_low: TAFloat,
+    sar: TAFloat,
+    is_long: bool,
+    af: TAFloat,
+    ep: TAFloat,
+}
+
+impl Sarext {
+    /// Creates a new streaming Extended Parabolic SAR.
+    ///
+    /// # Arguments
+    /// * `param_start_value` - Initial SAR; 0 detects the trend automatically
+    /// * `param_offset_on_reverse` - Offset fraction applied on reversals (typically 0)
+    /// * `param_af_init_long` - Initial long acceleration factor (typically 0.02)
+    /// * `param_af_long` - Long acceleration factor step (typically 0.02)
+    /// * `param_af_max_long` - Maximum long acceleration factor (typically 0.2)
+    /// * `param_af_init_short` - Initial short acceleration factor (typically 0.02)
+    /// * `param_af_short` - Short acceleration factor step (typically 0.02)
+    /// * `param_af_max_short` - Maximum short acceleration factor (typically 0.2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the offset or any acceleration factor is negative
+    pub fn new(
+        param_start_value: TAFloat,
+        param_offset_on_reverse: TAFloat,
+        param_af_init_long: TAFloat,
+        param_af_long: TAFloat,
+        param_af_max_long: TAFloat,
+        param_af_init_short: TAFloat,
+        param_af_short: TAFloat,
+        param_af_max_short: TAFloat,
+    ) -> Result<Self, KandError> {
+        let lookback = lookback(
+            param_start_value,
+            param_offset_on_reverse,
+            param_af_init_long,
+            param_af_long,
+            param_af_max_long,
+            param_af_init_short,
+            param_af_short,
+            param_af_max_short,
+        )?;
+        Ok(Self {
+            param_start_value,
+            param_offset_on_reverse,
+            param_af_init_long: param_af_init_long.min(param_af_max_long),
+            param_af_long: param_af_long.min(param_af_max_long),
+            param_af_max_long,
+            param_af_init_short: param_af_init_short.min(param_af_max_short),
+            param_af_short: param_af_short.min(param_af_max_short),
+            param_af_max_short,
+            lookback,
+            count: 0,
+            prev_high: TAFloat::NAN,
+            prev_low: TAFloat::NAN,
+            sar: TAFloat::NAN,
+            is_long: true,
+            af: TAFloat::NAN,
+            ep: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Sarext {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low): (TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        #[cfg(feature = "deep-check")]
+        {
+            if input_high.is_nan() || input_low.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+
+        self.count += 1;
+        if self.count <= self.lookback {
+            self.prev_high = input_high;
+            self.prev_low = input_low;
+            return Ok(None);
+        }
+
+        if self.count == self.lookback + 1 {
+            (self.sar, self.is_long, self.af, self.ep) = seed(
+                [self.prev_high, input_high],
+                [self.prev_low, input_low],
+                self.param_start_value,
+                self.param_af_init_long,
+                self.param_af_init_short,
+            );
+            // The second bar serves as its own previous bar
+            self.prev_high = input_high;
+            self.prev_low = input_low;
+        }
+
+        let output;
+        (output, self.sar, self.is_long, self.af, self.ep) = step(
+            input_high,
+            input_low,
+            self.prev_high,
+            self.prev_low,
+            self.sar,
+            self.is_long,
+            self.af,
+            self.ep,
+            self.param_offset_on_reverse,
+            self.param_af_init_long,
+            self.param_af_long,
+            self.param_af_max_long,
+            self.param_af_init_short,
+            self.param_af_short,
+            self.param_af_max_short,
+        );
+        self.prev_high = input_high;
+        self.prev_low = input_low;
+        Ok(Some(output))
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.prev_high = TAFloat::NAN;
+        self.prev_low = TAFloat::NAN;
+        self.sar = TAFloat::NAN;
+        self.is_long = true;
+        self.af = TAFloat::NAN;
+        self.ep = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_HIGH: [TAFloat; 20] = [
+        35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0, 35210.0,
+        35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5, 35078.8, 35085.0,
+    ];
+    const INPUT_LOW: [TAFloat; 20] = [
+        35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0, 35166.0,
+        35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0, 35012.3, 35022.2,
+    ];
+
+    fn run(params: [TAFloat; 8]) -> [Vec<TAFloat>; 5] {
+        let len = INPUT_HIGH.len();
+        let mut output_sarext = vec![0.0; len];
+        let mut output_next_sar = vec![0.0; len];
+        let mut output_is_long = vec![false; len];
+        let mut output_af = vec![0.0; len];
+        let mut output_ep = vec![0.0; len];
+        sarext(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            params[0],
+            params[1],
+            params[2],
+            params[3],
+            params[4],
+            params[5],
+            params[6],
+            params[7],
+            &mut output_sarext,
+            &mut output_next_sar,
+            &mut output_is_long,
+            &mut output_af,
+            &mut output_ep,
+        )
+        .unwrap();
+        let is_long = output_is_long
+            .iter()
+            .map(|&long| if long { 1.0 } else { 0.0 })
+            .collect();
+        [
+            output_sarext,
+            output_next_sar,
+            is_long,
+            output_af,
+            output_ep,
+        ]
+    }
+
+    fn check_inc_and_stream(params: [TAFloat; 8], outputs: &[Vec<TAFloat>; 5]) {
+        let [
+            output_sarext,
+            output_next_sar,
+            output_is_long,
+            output_af,
+            output_ep,
+        ] = outputs;
+
+        // Incremental calculation continues from the batch state
+        for i in 2..INPUT_HIGH.len() {
+            let (sar_value, next_sar, is_long, af, ep) = sarext_inc(
+                INPUT_HIGH[i],
+                INPUT_LOW[i],
+                INPUT_HIGH[i - 1],
+                INPUT_LOW[i - 1],
+                output_next_sar[i - 1],
+                output_is_long[i - 1] > 0.0,
+                output_af[i - 1],
+                output_ep[i - 1],
+                params[1],
+                params[2],
+                params[3],
+                params[4],
+                params[5],
+                params[6],
+                params[7],
+            )
+            .unwrap();
+            assert_relative_eq!(sar_value, output_sarext[i], epsilon = 0.0001);
+            assert_relative_eq!(next_sar, output_next_sar[i], epsilon = 0.0001);
+            assert_eq!(is_long, output_is_long[i] > 0.0);
+            assert_relative_eq!(af, output_af[i], epsilon = 0.0001);
+            assert_relative_eq!(ep, output_ep[i], epsilon = 0.0001);
+        }
+
+        // Streaming calculation matches the batch output
+        let mut stream = Sarext::new(
+            params[0], params[1], params[2], params[3], params[4], params[5], params[6], params[7],
+        )
+        .unwrap();
+        for i in 0..INPUT_HIGH.len() {
+            match stream.update((INPUT_HIGH[i], INPUT_LOW[i])).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_sarext[i], epsilon = 0.0001),
+                None => assert_eq!(i, 0),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_sarext_calculation() {
+        let params = [0.0, 0.0, 0.02, 0.02, 0.2, 0.02, 0.02, 0.2];
+        let outputs = run(params);
+        let output_sarext = &outputs[0];
+
+        assert!(output_sarext[0].is_nan());
+
+        // With symmetric factors the stop levels match the Parabolic SAR, signed by direction
+        let expected_values = [
+            -35266.0,
+            -35264.81,
+            -35261.4176,
+            -35_253.574_544,
+            35130.7,
+            35133.246,
+            35138.43216,
+            35_147.016_230_4,
+            35_155.085_256_576,
+            35_162.670_141_181_44,
+            -35281.5,
+            -35278.952,
+            -35_276.454_959_999_995,
+            -35_271.152_761_599_995,
+            -35_259.689_595_904,
+            -35_240.602_428_231_68,
+            -35_211.552_185_408_51,
+            -35_185.406_966_867_66,
+            -35_161.876_270_180_896,
+        ];
+        for i in 1..INPUT_HIGH.len() {
+            assert_relative_eq!(output_sarext[i], expected_values[i - 1], epsilon = 0.0001);
+        }
+
+        check_inc_and_stream(params, &outputs);
+    }
+
+    #[test]
+    fn test_sarext_asymmetric() {
+        // Start short at 35300 with a 1% offset on reversals and different long/short factors
+        let params = [-35300.0, 0.01, 0.01, 0.03, 0.25, 0.04, 0.05, 0.3];
+        let outputs = run(params);
+        let output_sarext = &outputs[0];
+
+        let expected_values = [
+            -35300.0,
+            -35296.26,
+            -35285.7966,
+            -35_264.083_076,
+            34779.393,
+            34784.17907,
+            34_803.327_907_2,
+            34_836.799_953_696,
+            34_867.928_956_937_28,
+            34_896.878_929_951_665,
+            34_923.802_404_855_05,
+            34_948.841_236_515_196,
+            34_972.127_349_959_13,
+            34_993.783_435_461_99,
+            35_013.923_594_979_65,
+            -35634.315,
+            -35606.9464,
+            -35580.672544,
+            -35_555.449_642_240_004,
+        ];
+        for i in 1..INPUT_HIGH.len() {
+            assert_relative_eq!(output_sarext[i], expected_values[i - 1], epsilon = 0.0001);
+        }
+
+        check_inc_and_stream(params, &outputs);
+    }
+
+    #[test]
+    fn test_sarext_invalid_params() {
+        assert!(matches!(
+            lookback(0.0, -0.01, 0.02, 0.02, 0.2, 0.02, 0.02, 0.2),
+            Err(KandError::InvalidParameter)
+        ));
+        assert!(matches!(
+            lookback(0.0, 0.0, 0.02, 0.02, 0.2, 0.02, -0.02, 0.2),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
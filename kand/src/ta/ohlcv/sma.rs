@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
 
 /// Calculates the lookback period required for Simple Moving Average (SMA).
 ///
@@ -191,6 +193,88 @@ pub fn sma_inc(
     Ok(prev_sma + (input_new_price - input_old_price) / param_period as TAFloat)
 }
 
+/// Streaming Simple Moving Average (SMA) calculator.
+///
+/// Keeps the last `param_period` prices so the value leaving the window is always available
+/// to [`sma_inc`]. The first value is seeded with [`sma`], so the stream matches the batch output.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::sma::Sma};
+///
+/// let mut sma = Sma::new(3).unwrap();
+/// let values: Vec<_> = [2.0, 4.0, 6.0, 8.0]
+///     .into_iter()
+///     .map(|price| sma.update(price).unwrap())
+///     .collect();
+/// assert_eq!(values, vec![None, None, Some(4.0), Some(6.0)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sma {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<TAFloat>,
+    prev_sma:     Option<TAFloat>,
+}
+
+impl Sma {
+    /// Creates a new streaming SMA.
+    ///
+    /// # Arguments
+    /// * `param_period` - The time period for SMA calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period),
+            prev_sma: None,
+        })
+    }
+}
+
+impl Indicator for Sma {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if let Some(prev_sma) = self.prev_sma {
+            let input_old_price = self.window[0];
+            let output_sma = sma_inc(prev_sma, input_price, input_old_price, self.param_period)?;
+            self.window.pop_front();
+            self.window.push_back(input_price);
+            self.prev_sma = Some(output_sma);
+            return Ok(self.prev_sma);
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let window = self.window.make_contiguous();
+        let mut output_sma = vec![0.0; window.len()];
+        sma(window, self.param_period, &mut output_sma)?;
+        self.prev_sma = Some(output_sma[self.lookback]);
+        Ok(self.prev_sma)
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.prev_sma = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_sma.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -249,4 +333,31 @@ fn test_sma_calculation() {
             prev_sma = result;
         }
     }
+
+    #[test]
+    fn test_sma_stream() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 14;
+        let mut output_sma = vec![0.0; input.len()];
+
+        sma(&input, param_period, &mut output_sma).unwrap();
+
+        let mut stream = Sma::new(param_period).unwrap();
+        for (i, price) in input.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_sma[i], epsilon = 0.00001),
+                None => assert!(output_sma[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+
+        // Reset should start a fresh warm-up period
+        stream.reset();
+        assert!(!stream.is_ready());
+        assert_eq!(stream.update(input[0]).unwrap(), None);
+    }
 }
@@ -0,0 +1,461 @@
+use super::{
+    atr::{self, Atr},
+    bandwidth::{bandwidth_value, percent_b_value},
+    sma::{self, Sma},
+};
+use crate::{Indicator, KandError, TAFloat};
+
+/// Returns the lookback period required for STARC Band calculation.
+///
+/// # Arguments
+/// * `param_period` - The SMA period of the middle line (must be >= 2)
+/// * `param_atr_period` - The ATR period (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The larger of the SMA and ATR lookbacks on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If a period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::starc;
+/// assert_eq!(starc::lookback(6, 15).unwrap(), 15);
+/// ```
+pub fn lookback(param_period: usize, param_atr_period: usize) -> Result<usize, KandError> {
+    Ok(sma::lookback(param_period)?.max(atr::lookback(param_atr_period)?))
+}
+
+/// Calculates STARC Bands (Stoller Average Range Channels).
+///
+/// # Description
+/// STARC Bands surround a short simple moving average of the close with a multiple of a
+/// longer Average True Range. The short average hugs price while the long ATR keeps the band
+/// width stable, so closes near the outer bands flag stretched, mean-reverting moves.
+/// The customary settings are a 6 bar SMA with a 2x 15 bar ATR.
+///
+/// # Mathematical Formula
+/// ```text
+/// Middle    = SMA(Close, period)
+/// Upper     = Middle + Multiplier * ATR(atr_period)
+/// Lower     = Middle - Multiplier * ATR(atr_period)
+/// %B        = (Close - Lower) / (Upper - Lower)
+/// Bandwidth = (Upper - Lower) / Middle
+/// ```
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `param_period` - The SMA period of the middle line (must be >= 2)
+/// * `param_atr_period` - The ATR period (must be >= 2)
+/// * `param_multiplier` - The ATR multiple for the bands
+/// * `output_upper` - Array storing the upper band
+/// * `output_middle` - Array storing the middle line
+/// * `output_lower` - Array storing the lower band
+/// * `output_atr` - Array storing the ATR values
+/// * `output_percent_b` - Array storing the %B values, see [`bandwidth`](super::bandwidth)
+/// * `output_bandwidth` - Array storing the bandwidth values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If a period is less than 2
+/// * `KandError::InsufficientData` - If input length <= lookback period
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::starc;
+///
+/// let input_high = vec![10.0, 11.0, 12.0, 11.5, 12.5];
+/// let input_low = vec![9.0, 9.5, 10.5, 10.0, 11.0];
+/// let input_close = vec![9.5, 10.5, 11.5, 10.5, 12.0];
+/// let mut output_upper = vec![0.0; 5];
+/// let mut output_middle = vec![0.0; 5];
+/// let mut output_lower = vec![0.0; 5];
+/// let mut output_atr = vec![0.0; 5];
+/// let mut output_percent_b = vec![0.0; 5];
+/// let mut output_bandwidth = vec![0.0; 5];
+///
+/// starc::starc(
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     2,
+///     3,
+///     2.0,
+///     &mut output_upper,
+///     &mut output_middle,
+///     &mut output_lower,
+///     &mut output_atr,
+///     &mut output_percent_b,
+///     &mut output_bandwidth,
+/// )
+/// .unwrap();
+///
+/// assert!(output_middle[2].is_nan());
+/// assert_eq!(output_middle[3], 11.0);
+/// assert_eq!(output_atr[3], 1.5);
+/// assert_eq!((output_upper[3], output_lower[3]), (14.0, 8.0));
+/// ```
+pub fn starc(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_period: usize,
+    param_atr_period: usize,
+    param_multiplier: TAFloat,
+    output_upper: &mut [TAFloat],
+    output_middle: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+    output_atr: &mut [TAFloat],
+    output_percent_b: &mut [TAFloat],
+    output_bandwidth: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback(param_period, param_atr_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if input_low.len() != len
+            || input_close.len() != len
+            || output_upper.len() != len
+            || output_middle.len() != len
+            || output_lower.len() != len
+            || output_atr.len() != len
+            || output_percent_b.len() != len
+            || output_bandwidth.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    sma::sma(input_close, param_period, output_middle)?;
+    atr::atr(
+        input_high,
+        input_low,
+        input_close,
+        param_atr_period,
+        output_atr,
+    )?;
+
+    for i in lookback..len {
+        let offset = param_multiplier * output_atr[i];
+        output_upper[i] = output_middle[i] + offset;
+        output_lower[i] = output_middle[i] - offset;
+        output_percent_b[i] = percent_b_value(input_close[i], output_upper[i], output_lower[i]);
+        output_bandwidth[i] = bandwidth_value(output_upper[i], output_middle[i], output_lower[i]);
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_upper[i] = TAFloat::NAN;
+        output_middle[i] = TAFloat::NAN;
+        output_lower[i] = TAFloat::NAN;
+        output_atr[i] = TAFloat::NAN;
+        output_percent_b[i] = TAFloat::NAN;
+        output_bandwidth[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next STARC Band values incrementally.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `prev_close` - Previous close price
+/// * `input_old_close` - Close leaving the SMA window, `param_period` bars back
+/// * `prev_middle` - Previous SMA middle line value
+/// * `prev_atr` - Previous ATR value
+/// * `param_period` - The SMA period of the middle line (must be >= 2)
+/// * `param_atr_period` - The ATR period (must be >= 2)
+/// * `param_multiplier` - The ATR multiple for the bands
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple of
+///   (upper, middle, lower, ATR, %B, bandwidth)
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If a period is less than 2
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::starc;
+///
+/// let (upper, middle, lower, atr, _, _) =
+///     starc::starc_inc(12.0, 10.5, 12.0, 10.5, 11.5, 11.0, 1.5, 2, 3, 2.0).unwrap();
+/// assert_eq!((middle, atr), (11.25, 1.5));
+/// assert_eq!((upper, lower), (14.25, 8.25));
+/// ```
+pub fn starc_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    prev_close: TAFloat,
+    input_old_close: TAFloat,
+    prev_middle: TAFloat,
+    prev_atr: TAFloat,
+    param_period: usize,
+    param_atr_period: usize,
+    param_multiplier: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    let middle = sma::sma_inc(prev_middle, input_close, input_old_close, param_period)?;
+    let atr = atr::atr_inc(
+        input_high,
+        input_low,
+        prev_close,
+        prev_atr,
+        param_atr_period,
+    )?;
+    let offset = param_multiplier * atr;
+    let upper = middle + offset;
+    let lower = middle - offset;
+
+    Ok((
+        upper,
+        middle,
+        lower,
+        atr,
+        percent_b_value(input_close, upper, lower),
+        bandwidth_value(upper, middle, lower),
+    ))
+}
+
+/// Streaming STARC Band calculator.
+///
+/// Combines a streaming [`Sma`] and a streaming [`Atr`], and emits
+/// `(upper, middle, lower, %B, bandwidth)` once both are warmed up.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::starc::Starc};
+///
+/// let mut starc = Starc::new(2, 3, 2.0).unwrap();
+/// for bar in [(10.0, 9.0, 9.5), (11.0, 9.5, 10.5), (12.0, 10.5, 11.5)] {
+///     assert_eq!(starc.update(bar).unwrap(), None);
+/// }
+/// let (upper, middle, lower, _, _) = starc.update((11.5, 10.0, 10.5)).unwrap().unwrap();
+/// assert_eq!((upper, middle, lower), (14.0, 11.0, 8.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Starc {
+    param_multiplier: TAFloat,
+    lookback:         usize,
+    sma:              Sma,
+    atr:              Atr,
+    ready:            bool,
+}
+
+impl Starc {
+    /// Creates a new streaming STARC Band.
+    ///
+    /// # Arguments
+    /// * `param_period` - The SMA period of the middle line (must be >= 2)
+    /// * `param_atr_period` - The ATR period (must be >= 2)
+    /// * `param_multiplier` - The ATR multiple for the bands
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If a period is less than 2
+    pub fn new(
+        param_period: usize,
+        param_atr_period: usize,
+        param_multiplier: TAFloat,
+    ) -> Result<Self, KandError> {
+        Ok(Self {
+            param_multiplier,
+            lookback: lookback(param_period, param_atr_period)?,
+            sma: Sma::new(param_period)?,
+            atr: Atr::new(param_atr_period)?,
+            ready: false,
+        })
+    }
+}
+
+impl Indicator for Starc {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = (TAFloat, TAFloat, TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<Self::Output>, KandError> {
+        let output_middle = self.sma.update(input_close)?;
+        let output_atr = self.atr.update((input_high, input_low, input_close))?;
+        let (Some(output_middle), Some(output_atr)) = (output_middle, output_atr) else {
+            return Ok(None);
+        };
+
+        self.ready = true;
+        let offset = self.param_multiplier * output_atr;
+        let output_upper = output_middle + offset;
+        let output_lower = output_middle - offset;
+        Ok(Some((
+            output_upper,
+            output_middle,
+            output_lower,
+            percent_b_value(input_close, output_upper, output_lower),
+            bandwidth_value(output_upper, output_middle, output_lower),
+        )))
+    }
+
+    fn reset(&mut self) {
+        self.sma.reset();
+        self.atr.reset();
+        self.ready = false;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_starc_calculation() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let len = input_close.len();
+        let (param_period, param_atr_period, param_multiplier) = (6, 15, 2.0);
+        let mut output_upper = vec![0.0; len];
+        let mut output_middle = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+        let mut output_atr = vec![0.0; len];
+        let mut output_percent_b = vec![0.0; len];
+        let mut output_bandwidth = vec![0.0; len];
+
+        starc(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            param_atr_period,
+            param_multiplier,
+            &mut output_upper,
+            &mut output_middle,
+            &mut output_lower,
+            &mut output_atr,
+            &mut output_percent_b,
+            &mut output_bandwidth,
+        )
+        .unwrap();
+
+        let mut expected_atr = vec![0.0; len];
+        atr::atr(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_atr_period,
+            &mut expected_atr,
+        )
+        .unwrap();
+
+        for i in 0..15 {
+            assert!(output_upper[i].is_nan());
+            assert!(output_percent_b[i].is_nan());
+        }
+        for i in 15..len {
+            let expected_middle = input_close[i + 1 - param_period..=i]
+                .iter()
+                .sum::<TAFloat>()
+                / 6.0;
+            let offset = param_multiplier * expected_atr[i];
+            assert_relative_eq!(output_middle[i], expected_middle, epsilon = 1e-9);
+            assert_relative_eq!(output_atr[i], expected_atr[i], epsilon = 1e-9);
+            assert_relative_eq!(output_upper[i], expected_middle + offset, epsilon = 1e-9);
+            assert_relative_eq!(output_lower[i], expected_middle - offset, epsilon = 1e-9);
+        }
+
+        // Incremental calculation continues the SMA and ATR
+        for i in 16..len {
+            let (upper, middle, lower, atr, percent_b, bandwidth) = starc_inc(
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                input_close[i - 1],
+                input_close[i - param_period],
+                output_middle[i - 1],
+                output_atr[i - 1],
+                param_period,
+                param_atr_period,
+                param_multiplier,
+            )
+            .unwrap();
+            assert_relative_eq!(upper, output_upper[i], epsilon = 1e-9);
+            assert_relative_eq!(middle, output_middle[i], epsilon = 1e-9);
+            assert_relative_eq!(lower, output_lower[i], epsilon = 1e-9);
+            assert_relative_eq!(atr, output_atr[i], epsilon = 1e-9);
+            assert_relative_eq!(percent_b, output_percent_b[i], epsilon = 1e-9);
+            assert_relative_eq!(bandwidth, output_bandwidth[i], epsilon = 1e-12);
+        }
+
+        // Streaming calculation matches the batch output bar for bar
+        let mut stream = Starc::new(param_period, param_atr_period, param_multiplier).unwrap();
+        for i in 0..len {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some((upper, middle, lower, percent_b, bandwidth)) => {
+                    assert_relative_eq!(upper, output_upper[i], epsilon = 1e-9);
+                    assert_relative_eq!(middle, output_middle[i], epsilon = 1e-9);
+                    assert_relative_eq!(lower, output_lower[i], epsilon = 1e-9);
+                    assert_relative_eq!(percent_b, output_percent_b[i], epsilon = 1e-9);
+                    assert_relative_eq!(bandwidth, output_bandwidth[i], epsilon = 1e-12);
+                }
+                None => assert!(output_upper[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
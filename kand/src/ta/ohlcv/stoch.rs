@@ -1,15 +1,31 @@
-use crate::{KandError, TAFloat, ta::ohlcv::sma};
+use super::{
+    ma::{self, Ma},
+    stochf,
+};
+use crate::{
+    Indicator,
+    KandError,
+    TAFloat,
+    ta::stats::{
+        max::{self, Max},
+        min::{self, Min},
+    },
+    types::MAType,
+};
 
 /// Calculates the lookback period required for Stochastic Oscillator calculation.
 ///
 /// # Description
 /// The lookback period represents the minimum number of data points needed before
-/// the indicator can generate valid values. It is calculated based on the input parameters.
+/// the indicator can generate valid values. It adds the `k_period - 1` bars of the fast %K
+/// window to the lookbacks of the slow %K and %D moving averages.
 ///
 /// # Arguments
 /// * `param_k_period` - The period used for %K calculation, must be >= 2
 /// * `param_k_slow_period` - The smoothing period for slow %K calculation, must be >= 2
+/// * `param_k_ma_type` - The moving average type used to smooth slow %K
 /// * `param_d_period` - The period used for %D calculation, must be >= 2
+/// * `param_d_ma_type` - The moving average type used for %D
 ///
 /// # Returns
 /// * `Result<usize, KandError>` - The lookback period if successful
@@ -19,28 +35,33 @@
 ///
 /// # Example
 /// ```
-/// use kand::ohlcv::stoch;
+/// use kand::{ohlcv::stoch, types::MAType};
 ///
 /// let k_period = 14;
 /// let k_slow_period = 3;
 /// let d_period = 3;
 ///
-/// let lookback = stoch::lookback(k_period, k_slow_period, d_period).unwrap();
+/// let lookback =
+///     stoch::lookback(k_period, k_slow_period, MAType::SMA, d_period, MAType::SMA).unwrap();
 /// assert_eq!(lookback, 17); // 14 + 3 + 3 - 3 = 17
 /// ```
-pub const fn lookback(
+pub fn lookback(
     param_k_period: usize,
     param_k_slow_period: usize,
+    param_k_ma_type: MAType,
     param_d_period: usize,
+    param_d_ma_type: MAType,
 ) -> Result<usize, KandError> {
     #[cfg(feature = "check")]
     {
         // Parameter range check
-        if param_k_period < 2 || param_k_slow_period < 2 || param_d_period < 2 {
+        if param_k_period < 2 {
             return Err(KandError::InvalidParameter);
         }
     }
-    Ok(param_k_period + param_k_slow_period + param_d_period - 3)
+    Ok(param_k_period - 1
+        + ma::lookback(param_k_slow_period, param_k_ma_type)?
+        + ma::lookback(param_d_period, param_d_ma_type)?)
 }
 
 /// Calculates the Stochastic Oscillator indicator for the entire price series.
@@ -53,14 +74,14 @@ pub const fn lookback(
 /// # Mathematical Formula
 /// ```text
 /// Fast %K = 100 * (Close - Lowest Low) / (Highest High - Lowest Low)
-/// Slow %K = SMA(Fast %K, k_slow_period)
-/// %D = SMA(Slow %K, d_period)
+/// Slow %K = MA(Fast %K, k_slow_period, k_ma_type)
+/// %D = MA(Slow %K, d_period, d_ma_type)
 /// ```
 ///
 /// # Calculation Steps
 /// 1. Calculate the Fast %K by comparing current close to the high-low range
-/// 2. Smooth the Fast %K using SMA to get Slow %K
-/// 3. Calculate %D as the SMA of Slow %K
+/// 2. Smooth the Fast %K with the selected moving average (SMA by default) to get Slow %K
+/// 3. Calculate %D as the selected moving average of Slow %K
 ///
 /// # Arguments
 /// * `input_high` - Array of high prices
@@ -68,7 +89,9 @@ pub const fn lookback(
 /// * `input_close` - Array of closing prices
 /// * `param_k_period` - Period for %K calculation, must be >= 2
 /// * `param_k_slow_period` - Smoothing period for slow %K, must be >= 2
+/// * `param_k_ma_type` - Moving average type used to smooth slow %K
 /// * `param_d_period` - Period for %D calculation, must be >= 2
+/// * `param_d_ma_type` - Moving average type used for %D
 /// * `output_fast_k` - Array to store Fast %K values
 /// * `output_k` - Array to store Slow %K values
 /// * `output_d` - Array to store %D values
@@ -85,7 +108,7 @@ pub const fn lookback(
 ///
 /// # Example
 /// ```
-/// use kand::ohlcv::stoch;
+/// use kand::{ohlcv::stoch, types::MAType};
 ///
 /// let input_high = vec![10.0, 12.0, 15.0, 14.0, 13.0];
 /// let input_low = vec![8.0, 9.0, 11.0, 10.0, 9.0];
@@ -103,7 +126,9 @@ pub const fn lookback(
 ///     &input_close,
 ///     param_k_period,
 ///     param_k_slow_period,
+///     MAType::SMA,
 ///     param_d_period,
+///     MAType::SMA,
 ///     &mut output_fast_k,
 ///     &mut output_k,
 ///     &mut output_d,
@@ -117,13 +142,21 @@ pub fn stoch(
     input_close: &[TAFloat],
     param_k_period: usize,
     param_k_slow_period: usize,
+    param_k_ma_type: MAType,
     param_d_period: usize,
+    param_d_ma_type: MAType,
     output_fast_k: &mut [TAFloat],
     output_k: &mut [TAFloat],
     output_d: &mut [TAFloat],
 ) -> Result<(), KandError> {
     let len = input_high.len();
-    let lookback = lookback(param_k_period, param_k_slow_period, param_d_period)?;
+    let lookback = lookback(
+        param_k_period,
+        param_k_slow_period,
+        param_k_ma_type,
+        param_d_period,
+        param_d_ma_type,
+    )?;
 
     #[cfg(feature = "check")]
     {
@@ -158,35 +191,31 @@ pub fn stoch(
         }
     }
 
-    let hundred = 100.0;
-
     // Calculate Fast %K first
-    for i in (param_k_period - 1)..len {
-        let mut highest_high = input_high[i];
-        let mut lowest_low = input_low[i];
-
-        for j in 0..param_k_period {
-            let idx = i - j;
-            highest_high = highest_high.max(input_high[idx]);
-            lowest_low = lowest_low.min(input_low[idx]);
-        }
-
-        let range = highest_high - lowest_low;
-        if range > 0.0 {
-            output_fast_k[i] = hundred * (input_close[i] - lowest_low) / range;
-        } else {
-            output_fast_k[i] = 50.0; // Default to 50 when range is zero
-        }
-    }
+    stochf::fast_k(
+        input_high,
+        input_low,
+        input_close,
+        param_k_period,
+        output_fast_k,
+    )?;
 
-    // Calculate Slow %K (SMA of Fast %K)
-    sma::sma(output_fast_k, param_k_slow_period, output_k)?;
+    // Calculate Slow %K (moving average of Fast %K)
+    let k_start = param_k_period - 1;
+    ma::ma(
+        &output_fast_k[k_start..],
+        param_k_slow_period,
+        param_k_ma_type,
+        &mut output_k[k_start..],
+    )?;
 
-    // Calculate %D (SMA of Slow %K)
-    sma::sma(
-        &output_k[param_k_slow_period - 1..],
+    // Calculate %D (moving average of Slow %K)
+    let d_start = k_start + ma::lookback(param_k_slow_period, param_k_ma_type)?;
+    ma::ma(
+        &output_k[d_start..],
         param_d_period,
-        &mut output_d[param_k_slow_period - 1..],
+        param_d_ma_type,
+        &mut output_d[d_start..],
     )?;
 
     // Fill initial values with NAN
@@ -199,6 +228,199 @@ pub fn stoch(
     Ok(())
 }
 
+/// Calculate latest Stochastic Oscillator values incrementally from previous state
+///
+/// The rolling extremes and both moving averages are carried in streaming states owned by the
+/// caller. Feed every bar from the start of the series; each line is NaN until it has warmed
+/// up, and %D matches the batch output from the lookback onwards.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `rolling_high` - Rolling maximum of highs, e.g. `Max::new(14)`
+/// * `rolling_low` - Rolling minimum of lows, e.g. `Min::new(14)`
+/// * `k_ma` - Slow %K moving average state, e.g. `Ma::new(3, MAType::SMA)`
+/// * `d_ma` - %D moving average state, e.g. `Ma::new(3, MAType::SMA)`
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (fast %K, slow %K, %D), NaN
+///   while warming up
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{
+///     ohlcv::{ma::Ma, stoch},
+///     stats::{max::Max, min::Min},
+///     types::MAType,
+/// };
+///
+/// let mut rolling_high = Max::new(3).unwrap();
+/// let mut rolling_low = Min::new(3).unwrap();
+/// let mut k_ma = Ma::new(2, MAType::SMA).unwrap();
+/// let mut d_ma = Ma::new(2, MAType::SMA).unwrap();
+///
+/// let bars = [
+///     (10.0, 8.0, 9.0),
+///     (12.0, 9.0, 11.0),
+///     (15.0, 11.0, 14.0),
+///     (14.0, 10.0, 12.0),
+///     (13.0, 9.0, 11.0),
+/// ];
+/// let mut last = (0.0, 0.0, 0.0);
+/// for (high, low, close) in bars {
+///     last = stoch::stoch_inc(
+///         high,
+///         low,
+///         close,
+///         &mut rolling_high,
+///         &mut rolling_low,
+///         &mut k_ma,
+///         &mut d_ma,
+///     )
+///     .unwrap();
+/// }
+/// assert!(!last.2.is_nan());
+/// ```
+#[allow(clippy::similar_names)]
+pub fn stoch_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    rolling_high: &mut Max,
+    rolling_low: &mut Min,
+    k_ma: &mut Ma,
+    d_ma: &mut Ma,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_high.is_nan() || input_low.is_nan() || input_close.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let highest_high = max::max_inc(input_high, rolling_high)?;
+    let lowest_low = min::min_inc(input_low, rolling_low)?;
+    if !rolling_high.is_ready() {
+        return Ok((TAFloat::NAN, TAFloat::NAN, TAFloat::NAN));
+    }
+
+    let fast_k = stochf::fast_k_value(input_close, highest_high, lowest_low);
+    let Some(k) = k_ma.update(fast_k)? else {
+        return Ok((fast_k, TAFloat::NAN, TAFloat::NAN));
+    };
+    let d = d_ma.update(k)?.unwrap_or(TAFloat::NAN);
+    Ok((fast_k, k, d))
+}
+
+/// Streaming Stochastic Oscillator calculator.
+///
+/// Owns the rolling extremes and the slow %K and %D moving averages and advances them with
+/// [`stoch_inc`], so the stream matches the batch output.
+///
+/// The input tuple is `(high, low, close)` and the output tuple is `(fast_k, k, d)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::stoch::Stoch, types::MAType};
+///
+/// let mut stoch = Stoch::new(3, 2, MAType::SMA, 2, MAType::EMA).unwrap();
+/// assert_eq!(stoch.lookback(), 4);
+/// for bar in [
+///     (10.0, 8.0, 9.0),
+///     (12.0, 9.0, 11.0),
+///     (15.0, 11.0, 14.0),
+///     (14.0, 10.0, 12.0),
+/// ] {
+///     assert!(stoch.update(bar).unwrap().is_none());
+/// }
+/// let (_, k, d) = stoch.update((13.0, 9.0, 11.0)).unwrap().unwrap();
+/// assert!(k > 0.0 && d > 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stoch {
+    lookback:     usize,
+    rolling_high: Max,
+    rolling_low:  Min,
+    k_ma:         Ma,
+    d_ma:         Ma,
+}
+
+impl Stoch {
+    /// Creates a new streaming Stochastic Oscillator.
+    ///
+    /// # Arguments
+    /// * `param_k_period` - Period for fast %K calculation (typically 14)
+    /// * `param_k_slow_period` - Smoothing period for slow %K (typically 3)
+    /// * `param_k_ma_type` - Slow %K moving average type
+    /// * `param_d_period` - Period for %D calculation (typically 3)
+    /// * `param_d_ma_type` - %D moving average type
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period is less than 2
+    pub fn new(
+        param_k_period: usize,
+        param_k_slow_period: usize,
+        param_k_ma_type: MAType,
+        param_d_period: usize,
+        param_d_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        Ok(Self {
+            lookback:     lookback(
+                param_k_period,
+                param_k_slow_period,
+                param_k_ma_type,
+                param_d_period,
+                param_d_ma_type,
+            )?,
+            rolling_high: Max::new(param_k_period)?,
+            rolling_low:  Min::new(param_k_period)?,
+            k_ma:         Ma::new(param_k_slow_period, param_k_ma_type)?,
+            d_ma:         Ma::new(param_d_period, param_d_ma_type)?,
+        })
+    }
+}
+
+impl Indicator for Stoch {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<(TAFloat, TAFloat, TAFloat)>, KandError> {
+        let output = stoch_inc(
+            input_high,
+            input_low,
+            input_close,
+            &mut self.rolling_high,
+            &mut self.rolling_low,
+            &mut self.k_ma,
+            &mut self.d_ma,
+        )?;
+        Ok(self.d_ma.is_ready().then_some(output))
+    }
+
+    fn reset(&mut self) {
+        self.rolling_high.reset();
+        self.rolling_low.reset();
+        self.k_ma.reset();
+        self.d_ma.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.d_ma.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -237,7 +459,9 @@ fn test_stoch_calculation() {
             &input_close,
             param_k_period,
             param_k_slow_period,
+            MAType::SMA,
             param_d_period,
+            MAType::SMA,
             &mut output_fast_k,
             &mut output_k,
             &mut output_d,
@@ -278,4 +502,111 @@ fn test_stoch_calculation() {
             assert_relative_eq!(output_d[i + 17], exp_d, epsilon = 0.0001);
         }
     }
+
+    #[test]
+    #[allow(clippy::similar_names)]
+    fn test_stoch_ma_type() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let len = input_high.len();
+        let mut output_fast_k = vec![0.0; len];
+        let mut output_k = vec![0.0; len];
+        let mut output_d = vec![0.0; len];
+
+        stoch(
+            &input_high,
+            &input_low,
+            &input_close,
+            5,
+            3,
+            MAType::EMA,
+            4,
+            MAType::WMA,
+            &mut output_fast_k,
+            &mut output_k,
+            &mut output_d,
+        )
+        .unwrap();
+
+        let lookback = lookback(5, 3, MAType::EMA, 4, MAType::WMA).unwrap();
+        assert_eq!(lookback, 9);
+        for i in 0..lookback {
+            assert!(output_k[i].is_nan());
+            assert!(output_d[i].is_nan());
+        }
+
+        // Slow %K is the EMA of fast %K and %D the WMA of slow %K
+        let fast_k: Vec<TAFloat> = (4..len)
+            .map(|i| {
+                let highest_high = input_high[i - 4..=i]
+                    .iter()
+                    .copied()
+                    .fold(TAFloat::MIN, TAFloat::max);
+                let lowest_low = input_low[i - 4..=i]
+                    .iter()
+                    .copied()
+                    .fold(TAFloat::MAX, TAFloat::min);
+                100.0 * (input_close[i] - lowest_low) / (highest_high - lowest_low)
+            })
+            .collect();
+        let mut expected_k = vec![0.0; len - 4];
+        ma::ma(&fast_k, 3, MAType::EMA, &mut expected_k).unwrap();
+        let mut expected_d = vec![0.0; len - 6];
+        ma::ma(&expected_k[2..], 4, MAType::WMA, &mut expected_d).unwrap();
+        for i in lookback..len {
+            assert_relative_eq!(output_k[i], expected_k[i - 4], epsilon = 0.0001);
+            assert_relative_eq!(output_d[i], expected_d[i - 6], epsilon = 0.0001);
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let mut rolling_high = Max::new(5).unwrap();
+        let mut rolling_low = Min::new(5).unwrap();
+        let mut k_ma = Ma::new(3, MAType::EMA).unwrap();
+        let mut d_ma = Ma::new(4, MAType::WMA).unwrap();
+        let mut stream = Stoch::new(5, 3, MAType::EMA, 4, MAType::WMA).unwrap();
+        for i in 0..len {
+            let (fast_k, k, d) = stoch_inc(
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                &mut rolling_high,
+                &mut rolling_low,
+                &mut k_ma,
+                &mut d_ma,
+            )
+            .unwrap();
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some((stream_fast_k, stream_k, stream_d)) => {
+                    assert!(i >= lookback);
+                    assert_relative_eq!(fast_k, output_fast_k[i], epsilon = 0.00001);
+                    assert_relative_eq!(k, output_k[i], epsilon = 0.00001);
+                    assert_relative_eq!(d, output_d[i], epsilon = 0.00001);
+                    assert_relative_eq!(stream_fast_k, fast_k, epsilon = 0.00001);
+                    assert_relative_eq!(stream_k, k, epsilon = 0.00001);
+                    assert_relative_eq!(stream_d, d, epsilon = 0.00001);
+                }
+                None => {
+                    assert!(i < lookback);
+                    assert!(d.is_nan());
+                }
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
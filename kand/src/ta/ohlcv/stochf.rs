@@ -0,0 +1,546 @@
+use super::ma::{self, Ma};
+use crate::{
+    Indicator,
+    KandError,
+    TAFloat,
+    ta::stats::{
+        max::{self, Max},
+        min::{self, Min},
+    },
+    types::MAType,
+};
+
+/// Calculates the lookback period required for Fast Stochastic Oscillator calculation.
+///
+/// # Description
+/// The lookback period adds the `k_period - 1` bars of the fast %K window to the lookback of
+/// the fast %D moving average.
+///
+/// # Arguments
+/// * `param_k_period` - The period used for fast %K calculation, must be >= 2
+/// * `param_d_period` - The period used for fast %D calculation, must be >= 2
+/// * `param_d_ma_type` - The moving average type used for fast %D
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period if successful
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If any period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::stochf, types::MAType};
+///
+/// let lookback = stochf::lookback(5, 3, MAType::SMA).unwrap();
+/// assert_eq!(lookback, 6); // 5 - 1 + 3 - 1
+/// ```
+pub fn lookback(
+    param_k_period: usize,
+    param_d_period: usize,
+    param_d_ma_type: MAType,
+) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if param_k_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_k_period - 1 + ma::lookback(param_d_period, param_d_ma_type)?)
+}
+
+/// Locates the close within the high-low range, defaulting to 50 when the range is zero.
+pub(crate) fn fast_k_value(
+    input_close: TAFloat,
+    highest_high: TAFloat,
+    lowest_low: TAFloat,
+) -> TAFloat {
+    let range = highest_high - lowest_low;
+    if range > 0.0 {
+        100.0 * (input_close - lowest_low) / range
+    } else {
+        50.0
+    }
+}
+
+/// Fills fast %K from index `param_k_period - 1` onwards, leaving earlier values untouched.
+///
+/// Shared by STOCH, STOCHF and STOCHRSI; callers validate the inputs.
+pub(crate) fn fast_k(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_k_period: usize,
+    output_fast_k: &mut [TAFloat],
+) -> Result<(), KandError> {
+    // Track the window extremes with rolling monotonic deques
+    let mut rolling_high = Max::new(param_k_period)?;
+    let mut rolling_low = Min::new(param_k_period)?;
+    for i in 0..(param_k_period - 1) {
+        rolling_high.push(input_high[i]);
+        rolling_low.push(input_low[i]);
+    }
+
+    for i in (param_k_period - 1)..input_close.len() {
+        let highest_high = rolling_high.push(input_high[i]);
+        let lowest_low = rolling_low.push(input_low[i]);
+        output_fast_k[i] = fast_k_value(input_close[i], highest_high, lowest_low);
+    }
+
+    Ok(())
+}
+
+/// Calculates the Fast Stochastic Oscillator (STOCHF) for the entire price series.
+///
+/// # Description
+/// The Fast Stochastic is the unsmoothed variant of the Stochastic Oscillator: fast %K is
+/// used as is and fast %D is a moving average of fast %K.
+///
+/// # Mathematical Formula
+/// ```text
+/// Fast %K = 100 * (Close - Lowest Low) / (Highest High - Lowest Low)
+/// Fast %D = MA(Fast %K, d_period, d_ma_type)
+/// ```
+/// Fast %K defaults to 50 when the high-low range is zero.
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of closing prices
+/// * `param_k_period` - Period for fast %K calculation, must be >= 2
+/// * `param_d_period` - Period for fast %D calculation, must be >= 2
+/// * `param_d_ma_type` - Moving average type used for fast %D
+/// * `output_fast_k` - Array to store fast %K values
+/// * `output_fast_d` - Array to store fast %D values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit type if successful
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If any period parameter is less than 2
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::stochf, types::MAType};
+///
+/// let input_high = vec![10.0, 12.0, 15.0, 14.0, 13.0];
+/// let input_low = vec![8.0, 9.0, 11.0, 10.0, 9.0];
+/// let input_close = vec![9.0, 11.0, 14.0, 12.0, 11.0];
+/// let mut output_fast_k = vec![0.0; 5];
+/// let mut output_fast_d = vec![0.0; 5];
+///
+/// stochf::stochf(
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     3,
+///     2,
+///     MAType::SMA,
+///     &mut output_fast_k,
+///     &mut output_fast_d,
+/// )
+/// .unwrap();
+///
+/// // Fast %K at index 3: (12 - 9) / (15 - 9) * 100
+/// assert!((output_fast_k[3] - 50.0).abs() < 1e-10);
+/// ```
+pub fn stochf(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_k_period: usize,
+    param_d_period: usize,
+    param_d_ma_type: MAType,
+    output_fast_k: &mut [TAFloat],
+    output_fast_d: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let lookback = lookback(param_k_period, param_d_period, param_d_ma_type)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_low.len()
+            || len != input_close.len()
+            || len != output_fast_k.len()
+            || len != output_fast_d.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    fast_k(
+        input_high,
+        input_low,
+        input_close,
+        param_k_period,
+        output_fast_k,
+    )?;
+
+    // Calculate fast %D (moving average of fast %K)
+    let k_start = param_k_period - 1;
+    ma::ma(
+        &output_fast_k[k_start..],
+        param_d_period,
+        param_d_ma_type,
+        &mut output_fast_d[k_start..],
+    )?;
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_fast_k[i] = TAFloat::NAN;
+        output_fast_d[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculate latest Fast Stochastic values incrementally from previous state
+///
+/// The rolling extremes and the %D moving average are carried in streaming states owned by
+/// the caller. Feed every bar from the start of the series; fast %K becomes valid after
+/// `k_period` bars and fast %D once its moving average has warmed up, with NaN returned
+/// before that.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `rolling_high` - Rolling maximum of highs, e.g. `Max::new(5)`
+/// * `rolling_low` - Rolling minimum of lows, e.g. `Min::new(5)`
+/// * `d_ma` - Fast %D moving average state, e.g. `Ma::new(3, MAType::SMA)`
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple of (fast %K, fast %D), NaN while warming
+///   up
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{
+///     ohlcv::{ma::Ma, stochf},
+///     stats::{max::Max, min::Min},
+///     types::MAType,
+/// };
+///
+/// let mut rolling_high = Max::new(3).unwrap();
+/// let mut rolling_low = Min::new(3).unwrap();
+/// let mut d_ma = Ma::new(2, MAType::SMA).unwrap();
+///
+/// let bars = [
+///     (10.0, 8.0, 9.0),
+///     (12.0, 9.0, 11.0),
+///     (15.0, 11.0, 14.0),
+///     (14.0, 10.0, 12.0),
+/// ];
+/// let mut last = (0.0, 0.0);
+/// for (high, low, close) in bars {
+///     last = stochf::stochf_inc(
+///         high,
+///         low,
+///         close,
+///         &mut rolling_high,
+///         &mut rolling_low,
+///         &mut d_ma,
+///     )
+///     .unwrap();
+/// }
+/// assert!((last.0 - 50.0).abs() < 1e-10);
+/// ```
+pub fn stochf_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    rolling_high: &mut Max,
+    rolling_low: &mut Min,
+    d_ma: &mut Ma,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_high.is_nan() || input_low.is_nan() || input_close.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let highest_high = max::max_inc(input_high, rolling_high)?;
+    let lowest_low = min::min_inc(input_low, rolling_low)?;
+    if !rolling_high.is_ready() {
+        return Ok((TAFloat::NAN, TAFloat::NAN));
+    }
+
+    let fast_k = fast_k_value(input_close, highest_high, lowest_low);
+    let fast_d = d_ma.update(fast_k)?.unwrap_or(TAFloat::NAN);
+    Ok((fast_k, fast_d))
+}
+
+/// Streaming Fast Stochastic calculator.
+///
+/// Owns the rolling extremes and the %D moving average and advances them with
+/// [`stochf_inc`], so the stream matches the batch output.
+///
+/// The input tuple is `(high, low, close)` and the output tuple is `(fast_k, fast_d)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::stochf::Stochf, types::MAType};
+///
+/// let mut stochf = Stochf::new(3, 2, MAType::SMA).unwrap();
+/// assert_eq!(stochf.lookback(), 3);
+/// for bar in [(10.0, 8.0, 9.0), (12.0, 9.0, 11.0), (15.0, 11.0, 14.0)] {
+///     assert!(stochf.update(bar).unwrap().is_none());
+/// }
+/// let (fast_k, fast_d) = stochf.update((14.0, 10.0, 12.0)).unwrap().unwrap();
+/// assert!(fast_k > 0.0 && fast_d > 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stochf {
+    lookback:     usize,
+    rolling_high: Max,
+    rolling_low:  Min,
+    d_ma:         Ma,
+}
+
+impl Stochf {
+    /// Creates a new streaming Fast Stochastic.
+    ///
+    /// # Arguments
+    /// * `param_k_period` - Period for fast %K calculation (typically 5)
+    /// * `param_d_period` - Period for fast %D calculation (typically 3)
+    /// * `param_d_ma_type` - Fast %D moving average type
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period is less than 2
+    pub fn new(
+        param_k_period: usize,
+        param_d_period: usize,
+        param_d_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        Ok(Self {
+            lookback:     lookback(param_k_period, param_d_period, param_d_ma_type)?,
+            rolling_high: Max::new(param_k_period)?,
+            rolling_low:  Min::new(param_k_period)?,
+            d_ma:         Ma::new(param_d_period, param_d_ma_type)?,
+        })
+    }
+}
+
+impl Indicator for Stochf {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = (TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<(TAFloat, TAFloat)>, KandError> {
+        let output = stochf_inc(
+            input_high,
+            input_low,
+            input_close,
+            &mut self.rolling_high,
+            &mut self.rolling_low,
+            &mut self.d_ma,
+        )?;
+        Ok(self.d_ma.is_ready().then_some(output))
+    }
+
+    fn reset(&mut self) {
+        self.rolling_high.reset();
+        self.rolling_low.reset();
+        self.d_ma.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.d_ma.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_HIGH: [TAFloat; 25] = [
+        35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0, 35210.0,
+        35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5, 35078.8, 35085.0,
+        35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+    ];
+    const INPUT_LOW: [TAFloat; 25] = [
+        35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0, 35166.0,
+        35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0, 35012.3, 35022.2,
+        34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+    ];
+    const INPUT_CLOSE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    #[test]
+    fn test_stochf_calculation() {
+        let len = INPUT_CLOSE.len();
+        let mut output_fast_k = vec![0.0; len];
+        let mut output_fast_d = vec![0.0; len];
+
+        stochf(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            5,
+            3,
+            MAType::SMA,
+            &mut output_fast_k,
+            &mut output_fast_d,
+        )
+        .unwrap();
+
+        let lookback = lookback(5, 3, MAType::SMA).unwrap();
+        assert_eq!(lookback, 6);
+        for i in 0..lookback {
+            assert!(output_fast_k[i].is_nan());
+            assert!(output_fast_d[i].is_nan());
+        }
+
+        let expected_fast_k = [
+            54.538_577_912_256_76,
+            80.371_352_785_147_24,
+            34.401_876_465_988_664,
+            16.190_476_190_473_67,
+            7.878_787_878_786_619,
+        ];
+        let expected_fast_d = [
+            63.137_971_770_307_104,
+            77.413_024_817_437_53,
+            56.437_269_054_464_224,
+            43.654_568_480_536_52,
+            19.490_380_178_416_32,
+        ];
+        for (i, (&exp_k, &exp_d)) in expected_fast_k
+            .iter()
+            .zip(expected_fast_d.iter())
+            .enumerate()
+        {
+            assert_relative_eq!(output_fast_k[i + lookback], exp_k, epsilon = 0.0001);
+            assert_relative_eq!(output_fast_d[i + lookback], exp_d, epsilon = 0.0001);
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let mut rolling_high = Max::new(5).unwrap();
+        let mut rolling_low = Min::new(5).unwrap();
+        let mut d_ma = Ma::new(3, MAType::SMA).unwrap();
+        let mut stream = Stochf::new(5, 3, MAType::SMA).unwrap();
+        for i in 0..len {
+            let (fast_k, fast_d) = stochf_inc(
+                INPUT_HIGH[i],
+                INPUT_LOW[i],
+                INPUT_CLOSE[i],
+                &mut rolling_high,
+                &mut rolling_low,
+                &mut d_ma,
+            )
+            .unwrap();
+            if i >= 4 {
+                assert!(!fast_k.is_nan());
+            }
+            match stream
+                .update((INPUT_HIGH[i], INPUT_LOW[i], INPUT_CLOSE[i]))
+                .unwrap()
+            {
+                Some((stream_fast_k, stream_fast_d)) => {
+                    assert!(i >= lookback);
+                    assert_relative_eq!(fast_k, output_fast_k[i], epsilon = 0.00001);
+                    assert_relative_eq!(fast_d, output_fast_d[i], epsilon = 0.00001);
+                    assert_relative_eq!(stream_fast_k, fast_k, epsilon = 0.00001);
+                    assert_relative_eq!(stream_fast_d, fast_d, epsilon = 0.00001);
+                }
+                None => {
+                    assert!(i < lookback);
+                    assert!(fast_d.is_nan());
+                }
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_stochf_ma_type() {
+        let len = INPUT_CLOSE.len();
+        let mut output_fast_k = vec![0.0; len];
+        let mut output_fast_d = vec![0.0; len];
+
+        stochf(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            5,
+            4,
+            MAType::DEMA,
+            &mut output_fast_k,
+            &mut output_fast_d,
+        )
+        .unwrap();
+
+        // DEMA(4) needs 6 bars on top of the fast %K window
+        let lookback = lookback(5, 4, MAType::DEMA).unwrap();
+        assert_eq!(lookback, 10);
+
+        let mut fast_k = vec![0.0; len];
+        super::fast_k(&INPUT_HIGH, &INPUT_LOW, &INPUT_CLOSE, 5, &mut fast_k).unwrap();
+        let mut expected_fast_d = vec![0.0; len - 4];
+        ma::ma(&fast_k[4..], 4, MAType::DEMA, &mut expected_fast_d).unwrap();
+        for i in lookback..len {
+            assert_relative_eq!(output_fast_k[i], fast_k[i], epsilon = 0.00001);
+            assert_relative_eq!(output_fast_d[i], expected_fast_d[i - 4], epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_stochf_invalid_period() {
+        let mut output_fast_k = vec![0.0; 25];
+        let mut output_fast_d = vec![0.0; 25];
+        assert!(matches!(
+            stochf(
+                &INPUT_HIGH,
+                &INPUT_LOW,
+                &INPUT_CLOSE,
+                1,
+                3,
+                MAType::SMA,
+                &mut output_fast_k,
+                &mut output_fast_d,
+            ),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
@@ -0,0 +1,517 @@
+use super::{
+    ma::{self, Ma},
+    rsi::{self, Rsi},
+    stochf,
+};
+use crate::{
+    Indicator,
+    KandError,
+    TAFloat,
+    ta::stats::{max::Max, min::Min},
+    types::MAType,
+};
+
+/// Calculates the lookback period required for Stochastic RSI calculation.
+///
+/// # Description
+/// The lookback period adds the RSI lookback to the Fast Stochastic lookback applied on top of
+/// the RSI series.
+///
+/// # Arguments
+/// * `param_rsi_period` - The period used for RSI calculation, must be >= 2
+/// * `param_k_period` - The period used for fast %K calculation, must be >= 2
+/// * `param_d_period` - The period used for fast %D calculation, must be >= 2
+/// * `param_d_ma_type` - The moving average type used for fast %D
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period if successful
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If any period is less than 2
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::stochrsi, types::MAType};
+///
+/// let lookback = stochrsi::lookback(14, 5, 3, MAType::SMA).unwrap();
+/// assert_eq!(lookback, 20); // 14 + 5 - 1 + 3 - 1
+/// ```
+pub fn lookback(
+    param_rsi_period: usize,
+    param_k_period: usize,
+    param_d_period: usize,
+    param_d_ma_type: MAType,
+) -> Result<usize, KandError> {
+    Ok(rsi::lookback(param_rsi_period)?
+        + stochf::lookback(param_k_period, param_d_period, param_d_ma_type)?)
+}
+
+/// Calculates the Stochastic RSI (STOCHRSI) for the entire price series.
+///
+/// # Description
+/// Stochastic RSI applies the Fast Stochastic Oscillator to the RSI series instead of prices,
+/// showing where the current RSI sits within its recent range. It reacts faster than RSI and
+/// reaches its extremes more often.
+///
+/// # Mathematical Formula
+/// ```text
+/// RSI = RSI(Close, rsi_period)
+/// Fast %K = 100 * (RSI - Lowest RSI) / (Highest RSI - Lowest RSI)
+/// Fast %D = MA(Fast %K, d_period, d_ma_type)
+/// ```
+/// Highest and lowest RSI are taken over `k_period` bars. Fast %K defaults to 50 when the RSI
+/// range is zero.
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices
+/// * `param_rsi_period` - Period for RSI calculation, must be >= 2
+/// * `param_k_period` - Period for fast %K calculation, must be >= 2
+/// * `param_d_period` - Period for fast %D calculation, must be >= 2
+/// * `param_d_ma_type` - Moving average type used for fast %D
+/// * `output_fast_k` - Array to store fast %K values
+/// * `output_fast_d` - Array to store fast %D values
+/// * `output_rsi` - Array to store RSI values
+/// * `output_avg_gain` - Array to store RSI average gain values
+/// * `output_avg_loss` - Array to store RSI average loss values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Unit type if successful
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input array is empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If any period parameter is less than 2
+/// * `KandError::InsufficientData` - If input length is less than or equal to the lookback
+/// * `KandError::NaNDetected` - If any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::{ohlcv::stochrsi, types::MAType};
+///
+/// let input_prices = vec![
+///     44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08,
+/// ];
+/// let len = input_prices.len();
+/// let mut output_fast_k = vec![0.0; len];
+/// let mut output_fast_d = vec![0.0; len];
+/// let mut output_rsi = vec![0.0; len];
+/// let mut output_avg_gain = vec![0.0; len];
+/// let mut output_avg_loss = vec![0.0; len];
+///
+/// stochrsi::stochrsi(
+///     &input_prices,
+///     3,
+///     3,
+///     2,
+///     MAType::SMA,
+///     &mut output_fast_k,
+///     &mut output_fast_d,
+///     &mut output_rsi,
+///     &mut output_avg_gain,
+///     &mut output_avg_loss,
+/// )
+/// .unwrap();
+///
+/// assert!(output_fast_d[5].is_nan());
+/// assert!((0.0..=100.0).contains(&output_fast_d[6]));
+/// ```
+pub fn stochrsi(
+    input_prices: &[TAFloat],
+    param_rsi_period: usize,
+    param_k_period: usize,
+    param_d_period: usize,
+    param_d_ma_type: MAType,
+    output_fast_k: &mut [TAFloat],
+    output_fast_d: &mut [TAFloat],
+    output_rsi: &mut [TAFloat],
+    output_avg_gain: &mut [TAFloat],
+    output_avg_loss: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(
+        param_rsi_period,
+        param_k_period,
+        param_d_period,
+        param_d_ma_type,
+    )?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != output_fast_k.len()
+            || len != output_fast_d.len()
+            || len != output_rsi.len()
+            || len != output_avg_gain.len()
+            || len != output_avg_loss.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for price in input_prices {
+            // NaN check
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    rsi::rsi(
+        input_prices,
+        param_rsi_period,
+        output_rsi,
+        output_avg_gain,
+        output_avg_loss,
+    )?;
+
+    // Fast %K of the RSI series, which serves as its own high, low and close
+    let rsi_start = rsi::lookback(param_rsi_period)?;
+    let rsi_values = &output_rsi[rsi_start..];
+    stochf::fast_k(
+        rsi_values,
+        rsi_values,
+        rsi_values,
+        param_k_period,
+        &mut output_fast_k[rsi_start..],
+    )?;
+
+    // Calculate fast %D (moving average of fast %K)
+    let k_start = rsi_start + param_k_period - 1;
+    ma::ma(
+        &output_fast_k[k_start..],
+        param_d_period,
+        param_d_ma_type,
+        &mut output_fast_d[k_start..],
+    )?;
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_fast_k[i] = TAFloat::NAN;
+        output_fast_d[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculate latest Stochastic RSI values incrementally from previous state
+///
+/// The RSI, the rolling RSI extremes and the %D moving average are carried in streaming states
+/// owned by the caller. Feed every bar from the start of the series; NaN is returned for each
+/// line until it has warmed up.
+///
+/// # Arguments
+/// * `input_price` - Current price value
+/// * `rsi` - RSI state, e.g. `Rsi::new(14)`
+/// * `rolling_high` - Rolling maximum of RSI, e.g. `Max::new(5)`
+/// * `rolling_low` - Rolling minimum of RSI, e.g. `Min::new(5)`
+/// * `d_ma` - Fast %D moving average state, e.g. `Ma::new(3, MAType::SMA)`
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple of (fast %K, fast %D), NaN while warming
+///   up
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If the input value is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::{
+///     ohlcv::{ma::Ma, rsi::Rsi, stochrsi},
+///     stats::{max::Max, min::Min},
+///     types::MAType,
+/// };
+///
+/// let mut rsi = Rsi::new(3).unwrap();
+/// let mut rolling_high = Max::new(3).unwrap();
+/// let mut rolling_low = Min::new(3).unwrap();
+/// let mut d_ma = Ma::new(2, MAType::SMA).unwrap();
+///
+/// let mut last = (0.0, 0.0);
+/// for price in [44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10] {
+///     last = stochrsi::stochrsi_inc(
+///         price,
+///         &mut rsi,
+///         &mut rolling_high,
+///         &mut rolling_low,
+///         &mut d_ma,
+///     )
+///     .unwrap();
+/// }
+/// assert!((0.0..=100.0).contains(&last.1));
+/// ```
+pub fn stochrsi_inc(
+    input_price: TAFloat,
+    rsi: &mut Rsi,
+    rolling_high: &mut Max,
+    rolling_low: &mut Min,
+    d_ma: &mut Ma,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    match rsi.update(input_price)? {
+        Some(rsi_value) => stochf::stochf_inc(
+            rsi_value,
+            rsi_value,
+            rsi_value,
+            rolling_high,
+            rolling_low,
+            d_ma,
+        ),
+        None => Ok((TAFloat::NAN, TAFloat::NAN)),
+    }
+}
+
+/// Streaming Stochastic RSI calculator.
+///
+/// Owns the RSI, the rolling RSI extremes and the %D moving average and advances them with
+/// [`stochrsi_inc`], so the stream matches the batch output.
+///
+/// The output tuple is `(fast_k, fast_d)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::stochrsi::Stochrsi, types::MAType};
+///
+/// let mut stochrsi = Stochrsi::new(3, 3, 2, MAType::SMA).unwrap();
+/// assert_eq!(stochrsi.lookback(), 6);
+/// for price in [44.34, 44.09, 44.15, 43.61, 44.33, 44.83] {
+///     assert!(stochrsi.update(price).unwrap().is_none());
+/// }
+/// let (fast_k, fast_d) = stochrsi.update(45.10).unwrap().unwrap();
+/// assert!((0.0..=100.0).contains(&fast_k) && (0.0..=100.0).contains(&fast_d));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stochrsi {
+    lookback:     usize,
+    rsi:          Rsi,
+    rolling_high: Max,
+    rolling_low:  Min,
+    d_ma:         Ma,
+}
+
+impl Stochrsi {
+    /// Creates a new streaming Stochastic RSI.
+    ///
+    /// # Arguments
+    /// * `param_rsi_period` - Period for RSI calculation (typically 14)
+    /// * `param_k_period` - Period for fast %K calculation (typically 14)
+    /// * `param_d_period` - Period for fast %D calculation (typically 3)
+    /// * `param_d_ma_type` - Fast %D moving average type
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period is less than 2
+    pub fn new(
+        param_rsi_period: usize,
+        param_k_period: usize,
+        param_d_period: usize,
+        param_d_ma_type: MAType,
+    ) -> Result<Self, KandError> {
+        Ok(Self {
+            lookback:     lookback(
+                param_rsi_period,
+                param_k_period,
+                param_d_period,
+                param_d_ma_type,
+            )?,
+            rsi:          Rsi::new(param_rsi_period)?,
+            rolling_high: Max::new(param_k_period)?,
+            rolling_low:  Min::new(param_k_period)?,
+            d_ma:         Ma::new(param_d_period, param_d_ma_type)?,
+        })
+    }
+}
+
+impl Indicator for Stochrsi {
+    type Input = TAFloat;
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<(TAFloat, TAFloat)>, KandError> {
+        let output = stochrsi_inc(
+            input_price,
+            &mut self.rsi,
+            &mut self.rolling_high,
+            &mut self.rolling_low,
+            &mut self.d_ma,
+        )?;
+        Ok(self.d_ma.is_ready().then_some(output))
+    }
+
+    fn reset(&mut self) {
+        self.rsi.reset();
+        self.rolling_high.reset();
+        self.rolling_low.reset();
+        self.d_ma.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.d_ma.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    #[test]
+    fn test_stochrsi_calculation() {
+        let len = INPUT_PRICE.len();
+        let mut output_fast_k = vec![0.0; len];
+        let mut output_fast_d = vec![0.0; len];
+        let mut output_rsi = vec![0.0; len];
+        let mut output_avg_gain = vec![0.0; len];
+        let mut output_avg_loss = vec![0.0; len];
+
+        stochrsi(
+            &INPUT_PRICE,
+            14,
+            5,
+            3,
+            MAType::SMA,
+            &mut output_fast_k,
+            &mut output_fast_d,
+            &mut output_rsi,
+            &mut output_avg_gain,
+            &mut output_avg_loss,
+        )
+        .unwrap();
+
+        let lookback = lookback(14, 5, 3, MAType::SMA).unwrap();
+        assert_eq!(lookback, 20);
+        for i in 0..lookback {
+            assert!(output_fast_k[i].is_nan());
+            assert!(output_fast_d[i].is_nan());
+        }
+
+        let expected_fast_k = [
+            1.434_929_958_410_902_8,
+            18.415_736_833_396_977,
+            82.534_936_056_844_15,
+            100.0,
+            100.0,
+        ];
+        let expected_fast_d = [
+            54.577_592_969_152_67,
+            27.382_838_580_284_993,
+            34.128_534_282_884_01,
+            66.983_557_630_080_37,
+            94.178_312_018_948_03,
+        ];
+        for (i, (&exp_k, &exp_d)) in expected_fast_k
+            .iter()
+            .zip(expected_fast_d.iter())
+            .enumerate()
+        {
+            assert_relative_eq!(output_fast_k[i + lookback], exp_k, epsilon = 0.0001);
+            assert_relative_eq!(output_fast_d[i + lookback], exp_d, epsilon = 0.0001);
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let mut rsi = Rsi::new(14).unwrap();
+        let mut rolling_high = Max::new(5).unwrap();
+        let mut rolling_low = Min::new(5).unwrap();
+        let mut d_ma = Ma::new(3, MAType::SMA).unwrap();
+        let mut stream = Stochrsi::new(14, 5, 3, MAType::SMA).unwrap();
+        for (i, &price) in INPUT_PRICE.iter().enumerate() {
+            let (fast_k, fast_d) = stochrsi_inc(
+                price,
+                &mut rsi,
+                &mut rolling_high,
+                &mut rolling_low,
+                &mut d_ma,
+            )
+            .unwrap();
+            match stream.update(price).unwrap() {
+                Some((stream_fast_k, stream_fast_d)) => {
+                    assert!(i >= lookback);
+                    assert_relative_eq!(fast_k, output_fast_k[i], epsilon = 0.00001);
+                    assert_relative_eq!(fast_d, output_fast_d[i], epsilon = 0.00001);
+                    assert_relative_eq!(stream_fast_k, fast_k, epsilon = 0.00001);
+                    assert_relative_eq!(stream_fast_d, fast_d, epsilon = 0.00001);
+                }
+                None => {
+                    assert!(i < lookback);
+                    assert!(fast_d.is_nan());
+                }
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_stochrsi_ma_type() {
+        let len = INPUT_PRICE.len();
+        let mut output_fast_k = vec![0.0; len];
+        let mut output_fast_d = vec![0.0; len];
+        let mut output_rsi = vec![0.0; len];
+        let mut output_avg_gain = vec![0.0; len];
+        let mut output_avg_loss = vec![0.0; len];
+
+        stochrsi(
+            &INPUT_PRICE,
+            6,
+            4,
+            3,
+            MAType::EMA,
+            &mut output_fast_k,
+            &mut output_fast_d,
+            &mut output_rsi,
+            &mut output_avg_gain,
+            &mut output_avg_loss,
+        )
+        .unwrap();
+
+        let lookback = lookback(6, 4, 3, MAType::EMA).unwrap();
+        assert_eq!(lookback, 11);
+
+        // Fast %K starts once RSI and the %K window are filled and fast %D is its EMA
+        let fast_k: Vec<TAFloat> = (9..len)
+            .map(|i| {
+                let highest = output_rsi[i - 3..=i]
+                    .iter()
+                    .copied()
+                    .fold(TAFloat::MIN, TAFloat::max);
+                let lowest = output_rsi[i - 3..=i]
+                    .iter()
+                    .copied()
+                    .fold(TAFloat::MAX, TAFloat::min);
+                100.0 * (output_rsi[i] - lowest) / (highest - lowest)
+            })
+            .collect();
+        let mut expected_fast_d = vec![0.0; fast_k.len()];
+        ma::ma(&fast_k, 3, MAType::EMA, &mut expected_fast_d).unwrap();
+        for i in lookback..len {
+            assert_relative_eq!(output_fast_k[i], fast_k[i - 9], epsilon = 0.00001);
+            assert_relative_eq!(output_fast_d[i], expected_fast_d[i - 9], epsilon = 0.00001);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::atr;
-use crate::{KandError, TAFloat, TAInt, types::Signal};
+use crate::{Indicator, KandError, TAFloat, TAInt, types::Signal};
 
 /// Returns the lookback period required for Supertrend calculation
 ///
@@ -337,6 +337,156 @@ pub fn supertrend_inc(
     ))
 }
 
+/// Streaming Supertrend calculator.
+///
+/// Consumes `(high, low, close)` bars and emits `(trend, supertrend)` pairs. The previous close,
+/// ATR, trend and final bands are carried between bars; the first value is seeded with
+/// [`supertrend`], later values come from [`supertrend_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::supertrend::Supertrend};
+///
+/// let mut supertrend = Supertrend::new(2, 3.0).unwrap();
+/// assert_eq!(supertrend.update((10.0, 8.0, 9.0)).unwrap(), None);
+/// assert_eq!(supertrend.update((11.0, 9.0, 10.0)).unwrap(), None);
+/// let (trend, value) = supertrend.update((12.0, 9.0, 11.0)).unwrap().unwrap();
+/// assert_eq!(trend, 100);
+/// assert_eq!(value, 3.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Supertrend {
+    param_period:     usize,
+    param_multiplier: TAFloat,
+    lookback:         usize,
+    warmup_high:      Vec<TAFloat>,
+    warmup_low:       Vec<TAFloat>,
+    warmup_close:     Vec<TAFloat>,
+    ready:            bool,
+    prev_close:       TAFloat,
+    prev_atr:         TAFloat,
+    prev_trend:       TAInt,
+    prev_upper:       TAFloat,
+    prev_lower:       TAFloat,
+}
+
+impl Supertrend {
+    /// Creates a new streaming Supertrend.
+    ///
+    /// # Arguments
+    /// * `param_period` - The ATR period (must be >= 2)
+    /// * `param_multiplier` - The ATR multiplier for the bands
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize, param_multiplier: TAFloat) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            param_multiplier,
+            lookback,
+            warmup_high: Vec::with_capacity(lookback + 1),
+            warmup_low: Vec::with_capacity(lookback + 1),
+            warmup_close: Vec::with_capacity(lookback + 1),
+            ready: false,
+            prev_close: TAFloat::NAN,
+            prev_atr: TAFloat::NAN,
+            prev_trend: Signal::Neutral.into(),
+            prev_upper: TAFloat::NAN,
+            prev_lower: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Supertrend {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = (TAInt, TAFloat);
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<(TAInt, TAFloat)>, KandError> {
+        if self.ready {
+            let (output_trend, output_supertrend, output_atr, output_upper, output_lower) =
+                supertrend_inc(
+                    input_high,
+                    input_low,
+                    input_close,
+                    self.prev_close,
+                    self.prev_atr,
+                    self.prev_trend,
+                    self.prev_upper,
+                    self.prev_lower,
+                    self.param_period,
+                    self.param_multiplier,
+                )?;
+            self.prev_close = input_close;
+            self.prev_atr = output_atr;
+            self.prev_trend = output_trend;
+            self.prev_upper = output_upper;
+            self.prev_lower = output_lower;
+            return Ok(Some((output_trend, output_supertrend)));
+        }
+
+        self.warmup_high.push(input_high);
+        self.warmup_low.push(input_low);
+        self.warmup_close.push(input_close);
+        if self.warmup_close.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let len = self.warmup_close.len();
+        let mut output_trend = vec![0; len];
+        let mut output_supertrend = vec![0.0; len];
+        let mut output_atr = vec![0.0; len];
+        let mut output_upper = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+        supertrend(
+            &self.warmup_high,
+            &self.warmup_low,
+            &self.warmup_close,
+            self.param_period,
+            self.param_multiplier,
+            &mut output_trend,
+            &mut output_supertrend,
+            &mut output_atr,
+            &mut output_upper,
+            &mut output_lower,
+        )?;
+        self.reset();
+        self.prev_close = input_close;
+        self.prev_atr = output_atr[self.lookback];
+        self.prev_trend = output_trend[self.lookback];
+        self.prev_upper = output_upper[self.lookback];
+        self.prev_lower = output_lower[self.lookback];
+        self.ready = true;
+        Ok(Some((
+            output_trend[self.lookback],
+            output_supertrend[self.lookback],
+        )))
+    }
+
+    fn reset(&mut self) {
+        self.warmup_high.clear();
+        self.warmup_low.clear();
+        self.warmup_close.clear();
+        self.ready = false;
+        self.prev_close = TAFloat::NAN;
+        self.prev_atr = TAFloat::NAN;
+        self.prev_trend = Signal::Neutral.into();
+        self.prev_upper = TAFloat::NAN;
+        self.prev_lower = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -451,4 +601,60 @@ fn test_supertrend_calculation() {
         assert!(trend == output_trend[len - 1]);
         assert_relative_eq!(supertrend, output_supertrend[len - 1], epsilon = 0.00001);
     }
+
+    #[test]
+    fn test_supertrend_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 10;
+        let param_multiplier = 3.0;
+        let len = input_close.len();
+        let mut output_trend = vec![0; len];
+        let mut output_supertrend = vec![0.0; len];
+        let mut output_atr = vec![0.0; len];
+        let mut output_upper = vec![0.0; len];
+        let mut output_lower = vec![0.0; len];
+
+        supertrend(
+            &input_high,
+            &input_low,
+            &input_close,
+            param_period,
+            param_multiplier,
+            &mut output_trend,
+            &mut output_supertrend,
+            &mut output_atr,
+            &mut output_upper,
+            &mut output_lower,
+        )
+        .unwrap();
+
+        let mut stream = Supertrend::new(param_period, param_multiplier).unwrap();
+        for i in 0..len {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some((trend, value)) => {
+                    assert_eq!(trend, output_trend[i]);
+                    assert_relative_eq!(value, output_supertrend[i], epsilon = 0.00001);
+                }
+                None => assert!(output_supertrend[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
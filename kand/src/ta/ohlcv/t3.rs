@@ -1,4 +1,4 @@
-use crate::{TAFloat, error::KandError};
+use crate::{Indicator, TAFloat, error::KandError};
 
 /// Calculates the lookback period required for T3 indicator
 ///
@@ -402,6 +402,127 @@ pub fn t3_inc(
     Ok((t3, ema1, ema2, ema3, ema4, ema5, ema6))
 }
 
+/// Streaming T3 (Tillson) Moving Average calculator.
+///
+/// The six cascaded EMA states are seeded with [`t3`] over the warm-up window and then
+/// advanced with [`t3_inc`], so the stream matches the batch output.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::t3::T3};
+///
+/// let mut t3 = T3::new(2, 0.7).unwrap();
+/// assert_eq!(t3.lookback(), 6);
+/// for price in [1.0, 2.0, 3.0, 4.0, 5.0, 6.0] {
+///     assert!(t3.update(price).unwrap().is_none());
+/// }
+/// assert!(t3.update(7.0).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct T3 {
+    param_period:  usize,
+    param_vfactor: TAFloat,
+    lookback:      usize,
+    warmup:        Vec<TAFloat>,
+    ready:         bool,
+    prev_emas:     [TAFloat; 6],
+}
+
+impl T3 {
+    /// Creates a new streaming T3.
+    ///
+    /// # Arguments
+    /// * `param_period` - Smoothing period for the EMAs (must be >= 2)
+    /// * `param_vfactor` - Volume factor controlling smoothing (typically 0-1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize, param_vfactor: TAFloat) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            param_vfactor,
+            lookback,
+            warmup: Vec::with_capacity(lookback + 1),
+            ready: false,
+            prev_emas: [TAFloat::NAN; 6],
+        })
+    }
+}
+
+impl Indicator for T3 {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if self.ready {
+            let [ema1, ema2, ema3, ema4, ema5, ema6] = self.prev_emas;
+            let (output_t3, e1, e2, e3, e4, e5, e6) = t3_inc(
+                input_price,
+                ema1,
+                ema2,
+                ema3,
+                ema4,
+                ema5,
+                ema6,
+                self.param_period,
+                self.param_vfactor,
+            )?;
+            self.prev_emas = [e1, e2, e3, e4, e5, e6];
+            return Ok(Some(output_t3));
+        }
+
+        self.warmup.push(input_price);
+        if self.warmup.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let len = self.warmup.len();
+        let mut output = vec![0.0; len];
+        let mut output_emas = [
+            vec![0.0; len],
+            vec![0.0; len],
+            vec![0.0; len],
+            vec![0.0; len],
+            vec![0.0; len],
+            vec![0.0; len],
+        ];
+        let [ema1, ema2, ema3, ema4, ema5, ema6] = &mut output_emas;
+        t3(
+            &self.warmup,
+            self.param_period,
+            self.param_vfactor,
+            &mut output,
+            ema1,
+            ema2,
+            ema3,
+            ema4,
+            ema5,
+            ema6,
+        )?;
+        self.warmup.clear();
+        for (prev, values) in self.prev_emas.iter_mut().zip(&output_emas) {
+            *prev = values[self.lookback];
+        }
+        self.ready = true;
+        Ok(Some(output[self.lookback]))
+    }
+
+    fn reset(&mut self) {
+        self.warmup.clear();
+        self.ready = false;
+        self.prev_emas = [TAFloat::NAN; 6];
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -505,4 +626,46 @@ fn test_t3_calculation() {
             prev_ema6 = ema6;
         }
     }
+
+    #[test]
+    fn test_t3_stream() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 3;
+        let param_vfactor = 0.7;
+        let len = input.len();
+        let mut output = vec![0.0; len];
+        let mut output_ema1 = vec![0.0; len];
+        let mut output_ema2 = vec![0.0; len];
+        let mut output_ema3 = vec![0.0; len];
+        let mut output_ema4 = vec![0.0; len];
+        let mut output_ema5 = vec![0.0; len];
+        let mut output_ema6 = vec![0.0; len];
+
+        t3(
+            &input,
+            param_period,
+            param_vfactor,
+            &mut output,
+            &mut output_ema1,
+            &mut output_ema2,
+            &mut output_ema3,
+            &mut output_ema4,
+            &mut output_ema5,
+            &mut output_ema6,
+        )
+        .unwrap();
+
+        let mut stream = T3::new(param_period, param_vfactor).unwrap();
+        for (i, price) in input.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output[i], epsilon = 0.00001),
+                None => assert!(output[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{KandError, TAFloat, ta::ohlcv::ema};
+use crate::{Indicator, KandError, TAFloat, ta::ohlcv::ema};
 
 /// Calculates the lookback period required for Triple Exponential Moving Average (TEMA)
 ///
@@ -235,6 +235,117 @@ pub fn tema_inc(
     Ok((tema, ema1, ema2, ema3))
 }
 
+/// Streaming Triple Exponential Moving Average (TEMA) calculator.
+///
+/// The three EMA states are seeded with [`tema`] over the warm-up window and then advanced
+/// with [`tema_inc`], so the stream matches the batch output.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::tema::Tema};
+///
+/// let mut tema = Tema::new(2).unwrap();
+/// assert_eq!(tema.lookback(), 3);
+/// for price in [1.0, 2.0, 3.0] {
+///     assert!(tema.update(price).unwrap().is_none());
+/// }
+/// assert!(tema.update(4.0).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tema {
+    param_period: usize,
+    lookback:     usize,
+    warmup:       Vec<TAFloat>,
+    ready:        bool,
+    prev_ema1:    TAFloat,
+    prev_ema2:    TAFloat,
+    prev_ema3:    TAFloat,
+}
+
+impl Tema {
+    /// Creates a new streaming TEMA.
+    ///
+    /// # Arguments
+    /// * `param_period` - The smoothing period for the three EMAs (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            lookback,
+            warmup: Vec::with_capacity(lookback + 1),
+            ready: false,
+            prev_ema1: TAFloat::NAN,
+            prev_ema2: TAFloat::NAN,
+            prev_ema3: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Tema {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if self.ready {
+            let (output_tema, output_ema1, output_ema2, output_ema3) = tema_inc(
+                input_price,
+                self.prev_ema1,
+                self.prev_ema2,
+                self.prev_ema3,
+                self.param_period,
+            )?;
+            self.prev_ema1 = output_ema1;
+            self.prev_ema2 = output_ema2;
+            self.prev_ema3 = output_ema3;
+            return Ok(Some(output_tema));
+        }
+
+        self.warmup.push(input_price);
+        if self.warmup.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let len = self.warmup.len();
+        let mut output_tema = vec![0.0; len];
+        let mut output_ema1 = vec![0.0; len];
+        let mut output_ema2 = vec![0.0; len];
+        let mut output_ema3 = vec![0.0; len];
+        tema(
+            &self.warmup,
+            self.param_period,
+            &mut output_tema,
+            &mut output_ema1,
+            &mut output_ema2,
+            &mut output_ema3,
+        )?;
+        self.warmup.clear();
+        self.prev_ema1 = output_ema1[self.lookback];
+        self.prev_ema2 = output_ema2[self.lookback];
+        self.prev_ema3 = output_ema3[self.lookback];
+        self.ready = true;
+        Ok(Some(output_tema[self.lookback]))
+    }
+
+    fn reset(&mut self) {
+        self.warmup.clear();
+        self.ready = false;
+        self.prev_ema1 = TAFloat::NAN;
+        self.prev_ema2 = TAFloat::NAN;
+        self.prev_ema3 = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -310,4 +421,37 @@ fn test_tema_calculation() {
             prev_ema3 = new_ema3;
         }
     }
+
+    #[test]
+    fn test_tema_stream() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 5;
+        let mut output_tema = vec![0.0; input.len()];
+        let mut output_ema1 = vec![0.0; input.len()];
+        let mut output_ema2 = vec![0.0; input.len()];
+        let mut output_ema3 = vec![0.0; input.len()];
+
+        tema(
+            &input,
+            param_period,
+            &mut output_tema,
+            &mut output_ema1,
+            &mut output_ema2,
+            &mut output_ema3,
+        )
+        .unwrap();
+
+        let mut stream = Tema::new(param_period).unwrap();
+        for (i, price) in input.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_tema[i], epsilon = 0.00001),
+                None => assert!(output_tema[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use super::sma;
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period required for Triangular Moving Average (TRIMA) calculation.
 ///
@@ -260,6 +263,111 @@ pub fn trima_inc(
     Ok((new_sma1, new_sma2))
 }
 
+/// Streaming Triangular Moving Average (TRIMA) calculator.
+///
+/// Keeps the last `n` prices of the first SMA and the last `m` first-SMA values of the second
+/// SMA, so both values leaving their windows are available to [`trima_inc`]. The first value
+/// is seeded with [`trima`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::trima::Trima};
+///
+/// let mut trima = Trima::new(3).unwrap();
+/// assert_eq!(trima.update(1.0).unwrap(), None);
+/// assert_eq!(trima.update(2.0).unwrap(), None);
+/// assert_eq!(trima.update(3.0).unwrap(), Some(2.0));
+/// assert_eq!(trima.update(4.0).unwrap(), Some(3.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Trima {
+    param_period: usize,
+    lookback:     usize,
+    n:            usize,
+    price_window: VecDeque<TAFloat>,
+    sma1_window:  VecDeque<TAFloat>,
+    prev_sma2:    Option<TAFloat>,
+}
+
+impl Trima {
+    /// Creates a new streaming TRIMA.
+    ///
+    /// # Arguments
+    /// * `param_period` - The smoothing period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        let n = param_period / 2 + 1;
+        Ok(Self {
+            param_period,
+            lookback,
+            n,
+            price_window: VecDeque::with_capacity(lookback + 1),
+            sma1_window: VecDeque::with_capacity(lookback + 2 - n),
+            prev_sma2: None,
+        })
+    }
+}
+
+impl Indicator for Trima {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if let Some(prev_sma2) = self.prev_sma2 {
+            let prev_sma1 = self.sma1_window[self.sma1_window.len() - 1];
+            let (output_sma1, output_sma2) = trima_inc(
+                prev_sma1,
+                prev_sma2,
+                input_price,
+                self.price_window[0],
+                self.sma1_window[0],
+                self.param_period,
+            )?;
+            self.price_window.pop_front();
+            self.price_window.push_back(input_price);
+            self.sma1_window.pop_front();
+            self.sma1_window.push_back(output_sma1);
+            self.prev_sma2 = Some(output_sma2);
+            return Ok(self.prev_sma2);
+        }
+
+        self.price_window.push_back(input_price);
+        if self.price_window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let input = self.price_window.make_contiguous();
+        let len = input.len();
+        let mut output_sma1 = vec![0.0; len];
+        let mut output_sma2 = vec![0.0; len];
+        trima(input, self.param_period, &mut output_sma1, &mut output_sma2)?;
+        // The batch function NaN-fills the first SMA up to the lookback, so recompute the
+        // first-SMA values still inside the second window.
+        sma::sma(input, self.n, &mut output_sma1)?;
+        self.sma1_window.extend(&output_sma1[self.n - 1..]);
+        self.price_window.drain(..len - self.n);
+        self.prev_sma2 = Some(output_sma2[self.lookback]);
+        Ok(self.prev_sma2)
+    }
+
+    fn reset(&mut self) {
+        self.price_window.clear();
+        self.sma1_window.clear();
+        self.prev_sma2 = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_sma2.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -403,4 +511,28 @@ fn test_trima_calculation() {
             prev_sma2 = new_sma2;
         }
     }
+
+    #[test]
+    fn test_trima_stream() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+
+        for param_period in [6, 7] {
+            let mut output_sma1 = vec![0.0; input.len()];
+            let mut output_sma2 = vec![0.0; input.len()];
+            trima(&input, param_period, &mut output_sma1, &mut output_sma2).unwrap();
+
+            let mut stream = Trima::new(param_period).unwrap();
+            for (i, price) in input.iter().enumerate() {
+                match stream.update(*price).unwrap() {
+                    Some(value) => assert_relative_eq!(value, output_sma2[i], epsilon = 0.00001),
+                    None => assert!(output_sma2[i].is_nan()),
+                }
+            }
+            assert!(stream.is_ready());
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use super::{ema, roc};
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Calculates the lookback period required for TRIX calculation
 ///
@@ -231,6 +231,117 @@ pub fn trix_inc(
     Ok((trix, new_ema1, new_ema2, new_ema3))
 }
 
+/// Streaming TRIX calculator.
+///
+/// The triple EMA chain is seeded with [`trix`] over the warm-up window and then advanced with
+/// [`trix_inc`], so the stream matches the batch output.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::trix::Trix};
+///
+/// let mut trix = Trix::new(2).unwrap();
+/// assert_eq!(trix.lookback(), 4);
+/// for price in [1.0, 2.0, 3.0, 4.0] {
+///     assert!(trix.update(price).unwrap().is_none());
+/// }
+/// assert!(trix.update(5.0).unwrap().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Trix {
+    param_period: usize,
+    lookback:     usize,
+    warmup:       Vec<TAFloat>,
+    ready:        bool,
+    prev_ema1:    TAFloat,
+    prev_ema2:    TAFloat,
+    prev_ema3:    TAFloat,
+}
+
+impl Trix {
+    /// Creates a new streaming TRIX.
+    ///
+    /// # Arguments
+    /// * `param_period` - The smoothing period for the three EMAs (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        Ok(Self {
+            param_period,
+            lookback,
+            warmup: Vec::with_capacity(lookback + 1),
+            ready: false,
+            prev_ema1: TAFloat::NAN,
+            prev_ema2: TAFloat::NAN,
+            prev_ema3: TAFloat::NAN,
+        })
+    }
+}
+
+impl Indicator for Trix {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if self.ready {
+            let (output_trix, output_ema1, output_ema2, output_ema3) = trix_inc(
+                input_price,
+                self.prev_ema1,
+                self.prev_ema2,
+                self.prev_ema3,
+                self.param_period,
+            )?;
+            self.prev_ema1 = output_ema1;
+            self.prev_ema2 = output_ema2;
+            self.prev_ema3 = output_ema3;
+            return Ok(Some(output_trix));
+        }
+
+        self.warmup.push(input_price);
+        if self.warmup.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let len = self.warmup.len();
+        let mut output = vec![0.0; len];
+        let mut ema1_output = vec![0.0; len];
+        let mut ema2_output = vec![0.0; len];
+        let mut ema3_output = vec![0.0; len];
+        trix(
+            &self.warmup,
+            self.param_period,
+            &mut output,
+            &mut ema1_output,
+            &mut ema2_output,
+            &mut ema3_output,
+        )?;
+        self.warmup.clear();
+        self.prev_ema1 = ema1_output[self.lookback];
+        self.prev_ema2 = ema2_output[self.lookback];
+        self.prev_ema3 = ema3_output[self.lookback];
+        self.ready = true;
+        Ok(Some(output[self.lookback]))
+    }
+
+    fn reset(&mut self) {
+        self.warmup.clear();
+        self.ready = false;
+        self.prev_ema1 = TAFloat::NAN;
+        self.prev_ema2 = TAFloat::NAN;
+        self.prev_ema3 = TAFloat::NAN;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -302,4 +413,38 @@ fn test_trix_calculation() {
             prev_ema3 = new_ema3;
         }
     }
+
+    #[test]
+    fn test_trix_stream() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 4;
+        let len = input.len();
+        let mut output = vec![0.0; len];
+        let mut ema1_output = vec![0.0; len];
+        let mut ema2_output = vec![0.0; len];
+        let mut ema3_output = vec![0.0; len];
+
+        trix(
+            &input,
+            param_period,
+            &mut output,
+            &mut ema1_output,
+            &mut ema2_output,
+            &mut ema3_output,
+        )
+        .unwrap();
+
+        let mut stream = Trix::new(param_period).unwrap();
+        for (i, price) in input.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output[i], epsilon = 0.00001),
+                None => assert!(output[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
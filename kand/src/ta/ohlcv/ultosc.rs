@@ -0,0 +1,533 @@
+use std::collections::VecDeque;
+
+use super::trange;
+use crate::{Indicator, KandError, TAFloat};
+
+/// Rolling `(buying pressure, true range)` sums of the three windows, shortest period first.
+type UltoscSums = [(TAFloat, TAFloat); 3];
+
+/// Weights of the shortest, medium and longest window averages.
+const WEIGHTS: [TAFloat; 3] = [4.0, 2.0, 1.0];
+
+/// Orders the three periods from shortest to longest, as TA-Lib does before weighting them.
+fn sorted_periods(
+    param_period1: usize,
+    param_period2: usize,
+    param_period3: usize,
+) -> Result<[usize; 3], KandError> {
+    let mut periods = [param_period1, param_period2, param_period3];
+    #[cfg(feature = "check")]
+    {
+        if periods.contains(&0) {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    periods.sort_unstable();
+    Ok(periods)
+}
+
+/// Returns the lookback period required for Ultimate Oscillator (ULTOSC) calculation.
+///
+/// # Description
+/// Every true range needs the previous close, so the longest window is complete after
+/// `max(period1, period2, period3)` bars.
+///
+/// # Arguments
+/// * `param_period1` - First averaging period (must be >= 1, typically 7)
+/// * `param_period2` - Second averaging period (must be >= 1, typically 14)
+/// * `param_period3` - Third averaging period (must be >= 1, typically 28)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If any period is 0
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ultosc;
+/// let lookback = ultosc::lookback(7, 14, 28).unwrap();
+/// assert_eq!(lookback, 28);
+/// ```
+pub fn lookback(
+    param_period1: usize,
+    param_period2: usize,
+    param_period3: usize,
+) -> Result<usize, KandError> {
+    let [_, _, longest] = sorted_periods(param_period1, param_period2, param_period3)?;
+    Ok(longest)
+}
+
+/// Calculates the Ultimate Oscillator (ULTOSC) for a price series.
+///
+/// # Description
+/// The Ultimate Oscillator by Larry Williams combines buying pressure relative to the true
+/// range over three timeframes, so a divergence has to show up on short, medium and long
+/// horizons at once. It ranges from 0 to 100. As in TA-Lib the periods may be given in any
+/// order: the shortest window always gets weight 4, the medium one 2 and the longest one 1.
+///
+/// # Mathematical Formula
+/// ```text
+/// BP = Close - min(Low, Prior Close)
+/// TR = max(High, Prior Close) - min(Low, Prior Close)
+/// Average_n = Sum(BP, n) / Sum(TR, n)   (0 when Sum(TR, n) is 0)
+/// ULTOSC = 100 * (4 * Average_short + 2 * Average_medium + Average_long) / 7
+/// ```
+///
+/// # Calculation Steps
+/// 1. Calculate buying pressure and true range for every bar after the first
+/// 2. Keep rolling sums of both over each of the three windows
+/// 3. Divide each buying pressure sum by its true range sum
+/// 4. Combine the three averages with weights 4, 2 and 1
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `param_period1` - First averaging period (must be >= 1, typically 7)
+/// * `param_period2` - Second averaging period (must be >= 1, typically 14)
+/// * `param_period3` - Third averaging period (must be >= 1, typically 28)
+/// * `output_ultosc` - Array to store calculated ULTOSC values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success, Err on failure
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If any period is 0
+/// * `KandError::InsufficientData` - If input length <= lookback period
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ultosc;
+///
+/// let input_high = vec![10.0, 11.0, 12.0, 11.5, 12.5];
+/// let input_low = vec![9.0, 9.5, 10.5, 10.0, 11.0];
+/// let input_close = vec![9.5, 10.5, 11.5, 10.5, 12.0];
+/// let mut output_ultosc = vec![0.0; 5];
+///
+/// ultosc::ultosc(
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     1,
+///     2,
+///     3,
+///     &mut output_ultosc,
+/// )
+/// .unwrap();
+/// assert!(output_ultosc[2].is_nan());
+/// assert!((0.0..=100.0).contains(&output_ultosc[3]));
+/// ```
+pub fn ultosc(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    param_period1: usize,
+    param_period2: usize,
+    param_period3: usize,
+    output_ultosc: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+    let periods = sorted_periods(param_period1, param_period2, param_period3)?;
+    let lookback = periods[2];
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+
+        // Length consistency check
+        if len != input_low.len() || len != input_close.len() || len != output_ultosc.len() {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_high[i].is_nan() || input_low[i].is_nan() || input_close[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Buying pressure and true range of every bar, kept so they can leave the windows again
+    let mut values = vec![(0.0, 0.0); len];
+    let mut sums: UltoscSums = [(0.0, 0.0); 3];
+    for i in 1..len {
+        let old_values = periods.map(|period| {
+            if i > period {
+                values[i - period]
+            } else {
+                (0.0, 0.0)
+            }
+        });
+        let (output, bp, tr, new_sums) = ultosc_inc(
+            input_high[i],
+            input_low[i],
+            input_close[i],
+            input_close[i - 1],
+            old_values,
+            sums,
+        )?;
+        values[i] = (bp, tr);
+        sums = new_sums;
+        output_ultosc[i] = output;
+    }
+
+    // Fill initial values with NAN
+    for value in output_ultosc.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next Ultimate Oscillator (ULTOSC) value incrementally.
+///
+/// # Description
+/// Adds the buying pressure and true range of the new bar to the three rolling sums and
+/// removes the values leaving each window. The true range comes from
+/// [`trange::trange_inc`]. The buying pressure and true range of the new bar are returned as
+/// well, so the caller can drop them from the windows once they become old.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `prev_close` - Previous close price
+/// * `old_values` - `(buying pressure, true range)` leaving each window, shortest period
+///   first; `(0.0, 0.0)` while a window is still filling up
+/// * `prev_sums` - Previous `(buying pressure, true range)` sums of each window, shortest
+///   period first
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, [(TAFloat, TAFloat); 3]), KandError>` - Tuple of
+///   (ULTOSC, buying pressure, true range, new sums)
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::ultosc;
+///
+/// let (value, bp, tr, sums) = ultosc::ultosc_inc(
+///     12.0,                                 // high
+///     11.0,                                 // low
+///     12.0,                                 // close
+///     10.5,                                 // previous close
+///     [(1.0, 2.0), (0.0, 0.0), (0.0, 0.0)], // values leaving the windows
+///     [(1.0, 2.0), (2.0, 4.0), (3.0, 6.0)], // previous sums
+/// )
+/// .unwrap();
+/// assert_eq!((bp, tr), (1.5, 1.5));
+/// assert_eq!(sums, [(1.5, 1.5), (3.5, 5.5), (4.5, 7.5)]);
+/// assert!((value - 100.0 * (4.0 + 2.0 * 3.5 / 5.5 + 0.6) / 7.0).abs() < 1e-10);
+/// ```
+pub fn ultosc_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    prev_close: TAFloat,
+    old_values: [(TAFloat, TAFloat); 3],
+    prev_sums: [(TAFloat, TAFloat); 3],
+) -> Result<(TAFloat, TAFloat, TAFloat, [(TAFloat, TAFloat); 3]), KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_close.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let tr = trange::trange_inc(input_high, input_low, prev_close)?;
+    let bp = input_close - input_low.min(prev_close);
+
+    let mut sums = prev_sums;
+    let mut output = 0.0;
+    for ((sum, old), weight) in sums.iter_mut().zip(old_values).zip(WEIGHTS) {
+        sum.0 += bp - old.0;
+        sum.1 += tr - old.1;
+        if sum.1 != 0.0 {
+            output += weight * sum.0 / sum.1;
+        }
+    }
+
+    Ok((
+        100.0 * output / WEIGHTS.iter().sum::<TAFloat>(),
+        bp,
+        tr,
+        sums,
+    ))
+}
+
+/// Streaming Ultimate Oscillator (ULTOSC) calculator.
+///
+/// Consumes `(high, low, close)` bars and keeps the buying pressure and true range of the last
+/// `max(period)` bars, so the values leaving each window are available to [`ultosc_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::ultosc::Ultosc};
+///
+/// let mut ultosc = Ultosc::new(1, 2, 3).unwrap();
+/// assert_eq!(ultosc.lookback(), 3);
+/// for bar in [(10.0, 9.0, 9.5), (11.0, 9.5, 10.5), (12.0, 10.5, 11.5)] {
+///     assert!(ultosc.update(bar).unwrap().is_none());
+/// }
+/// let value = ultosc.update((11.5, 10.0, 10.5)).unwrap().unwrap();
+/// assert!((0.0..=100.0).contains(&value));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ultosc {
+    periods:    [usize; 3],
+    window:     VecDeque<(TAFloat, TAFloat)>,
+    sums:       UltoscSums,
+    prev_close: Option<TAFloat>,
+}
+
+impl Ultosc {
+    /// Creates a new streaming ULTOSC.
+    ///
+    /// # Arguments
+    /// * `param_period1` - First averaging period (must be >= 1, typically 7)
+    /// * `param_period2` - Second averaging period (must be >= 1, typically 14)
+    /// * `param_period3` - Third averaging period (must be >= 1, typically 28)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If any period is 0
+    pub fn new(
+        param_period1: usize,
+        param_period2: usize,
+        param_period3: usize,
+    ) -> Result<Self, KandError> {
+        let periods = sorted_periods(param_period1, param_period2, param_period3)?;
+        Ok(Self {
+            periods,
+            window: VecDeque::with_capacity(periods[2]),
+            sums: [(0.0, 0.0); 3],
+            prev_close: None,
+        })
+    }
+}
+
+impl Indicator for Ultosc {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(&mut self, input: (TAFloat, TAFloat, TAFloat)) -> Result<Option<TAFloat>, KandError> {
+        let (input_high, input_low, input_close) = input;
+        let Some(prev_close) = self.prev_close.replace(input_close) else {
+            return Ok(None);
+        };
+
+        let len = self.window.len();
+        let old_values = self.periods.map(|period| {
+            if len >= period {
+                self.window[len - period]
+            } else {
+                (0.0, 0.0)
+            }
+        });
+        let (output, bp, tr, sums) = ultosc_inc(
+            input_high,
+            input_low,
+            input_close,
+            prev_close,
+            old_values,
+            self.sums,
+        )?;
+        self.sums = sums;
+        if self.window.len() == self.periods[2] {
+            self.window.pop_front();
+        }
+        self.window.push_back((bp, tr));
+
+        if self.is_ready() {
+            Ok(Some(output))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sums = [(0.0, 0.0); 3];
+        self.prev_close = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() == self.periods[2]
+    }
+
+    fn lookback(&self) -> usize {
+        self.periods[2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_HIGH: [TAFloat; 25] = [
+        35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0, 35210.0,
+        35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5, 35078.8, 35085.0,
+        35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+    ];
+    const INPUT_LOW: [TAFloat; 25] = [
+        35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0, 35166.0,
+        35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0, 35012.3, 35022.2,
+        34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+    ];
+    const INPUT_CLOSE: [TAFloat; 25] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4, 35069.0, 35024.6,
+        34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+    ];
+
+    /// Direct evaluation of the formula over explicit windows.
+    fn naive_ultosc(i: usize, periods: [usize; 3]) -> TAFloat {
+        let average = |period: usize| {
+            let (mut bp_sum, mut tr_sum) = (0.0, 0.0);
+            for j in i + 1 - period..=i {
+                let true_low = INPUT_LOW[j].min(INPUT_CLOSE[j - 1]);
+                let true_high = INPUT_HIGH[j].max(INPUT_CLOSE[j - 1]);
+                bp_sum += INPUT_CLOSE[j] - true_low;
+                tr_sum += true_high - true_low;
+            }
+            bp_sum / tr_sum
+        };
+        100.0 * (4.0 * average(periods[0]) + 2.0 * average(periods[1]) + average(periods[2])) / 7.0
+    }
+
+    #[test]
+    fn test_ultosc_calculation() {
+        let len = INPUT_CLOSE.len();
+        let mut output_ultosc = vec![0.0; len];
+        ultosc(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            3,
+            6,
+            12,
+            &mut output_ultosc,
+        )
+        .unwrap();
+
+        let lookback = lookback(3, 6, 12).unwrap();
+        assert_eq!(lookback, 12);
+        for value in &output_ultosc[..lookback] {
+            assert!(value.is_nan());
+        }
+        for (i, value) in output_ultosc.iter().enumerate().skip(lookback) {
+            assert_relative_eq!(*value, naive_ultosc(i, [3, 6, 12]), epsilon = 1e-8);
+        }
+
+        // Period order does not matter
+        let mut output_unsorted = vec![0.0; len];
+        ultosc(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            12,
+            3,
+            6,
+            &mut output_unsorted,
+        )
+        .unwrap();
+        for i in lookback..len {
+            assert_relative_eq!(output_unsorted[i], output_ultosc[i], epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ultosc_stream() {
+        let len = INPUT_CLOSE.len();
+        let mut output_ultosc = vec![0.0; len];
+        ultosc(
+            &INPUT_HIGH,
+            &INPUT_LOW,
+            &INPUT_CLOSE,
+            7,
+            2,
+            4,
+            &mut output_ultosc,
+        )
+        .unwrap();
+
+        let mut stream = Ultosc::new(7, 2, 4).unwrap();
+        assert_eq!(stream.lookback(), 7);
+        for i in 0..len {
+            match stream
+                .update((INPUT_HIGH[i], INPUT_LOW[i], INPUT_CLOSE[i]))
+                .unwrap()
+            {
+                Some(value) => assert_relative_eq!(value, output_ultosc[i], epsilon = 1e-8),
+                None => assert!(output_ultosc[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+
+        stream.reset();
+        assert!(!stream.is_ready());
+        assert!(
+            stream
+                .update((INPUT_HIGH[0], INPUT_LOW[0], INPUT_CLOSE[0]))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_ultosc_flat_prices() {
+        let input = [10.0; 6];
+        let mut output_ultosc = vec![0.0; 6];
+        ultosc(&input, &input, &input, 1, 2, 3, &mut output_ultosc).unwrap();
+        assert_eq!(output_ultosc[3], 0.0);
+        assert_eq!(output_ultosc[5], 0.0);
+    }
+
+    #[test]
+    fn test_ultosc_invalid_params() {
+        let mut output_ultosc = vec![0.0; 5];
+        assert!(matches!(
+            ultosc(
+                &INPUT_HIGH[..5],
+                &INPUT_LOW[..5],
+                &INPUT_CLOSE[..5],
+                0,
+                2,
+                3,
+                &mut output_ultosc
+            ),
+            Err(KandError::InvalidParameter)
+        ));
+        assert!(matches!(
+            ultosc(
+                &INPUT_HIGH[..5],
+                &INPUT_LOW[..5],
+                &INPUT_CLOSE[..5],
+                7,
+                2,
+                3,
+                &mut output_ultosc
+            ),
+            Err(KandError::InsufficientData)
+        ));
+    }
+}
@@ -1,5 +1,8 @@
 use super::ema;
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
+
+/// Longest EMA period - 1
+const LOOKBACK: usize = 676 - 1;
 
 /// Returns the lookback period required for VEGAS (Volume and EMA Guided Adaptive Scaling) calculation
 ///
@@ -17,7 +20,7 @@
 /// assert_eq!(lookback, 675);
 /// ```
 pub const fn lookback() -> Result<usize, KandError> {
-    Ok(676 - 1) // Longest EMA period - 1
+    Ok(LOOKBACK)
 }
 
 /// Calculates VEGAS (Volume and EMA Guided Adaptive Scaling) indicator for the entire price array
@@ -198,3 +201,148 @@ pub fn vegas_inc(
 
     Ok((channel_upper, channel_lower, boundary_upper, boundary_lower))
 }
+
+/// Streaming VEGAS calculator.
+///
+/// Buffers the first 676 prices and seeds all four EMAs from one [`vegas`] call over them,
+/// then advances them together with [`vegas_inc`], so the stream matches the batch output.
+///
+/// The output tuple is `(channel_upper, channel_lower, boundary_upper, boundary_lower)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::vegas::Vegas};
+///
+/// let mut vegas = Vegas::new();
+/// for _ in 0..vegas.lookback() {
+///     assert!(vegas.update(100.0).unwrap().is_none());
+/// }
+/// assert_eq!(
+///     vegas.update(100.0).unwrap(),
+///     Some((100.0, 100.0, 100.0, 100.0))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Vegas {
+    warmup: Vec<TAFloat>,
+    prev:   Option<(TAFloat, TAFloat, TAFloat, TAFloat)>,
+}
+
+impl Vegas {
+    /// Creates a new streaming VEGAS.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            warmup: Vec::with_capacity(LOOKBACK + 1),
+            prev:   None,
+        }
+    }
+}
+
+impl Default for Vegas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indicator for Vegas {
+    type Input = TAFloat;
+    type Output = (TAFloat, TAFloat, TAFloat, TAFloat);
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<Self::Output>, KandError> {
+        if let Some((channel_upper, channel_lower, boundary_upper, boundary_lower)) = self.prev {
+            self.prev = Some(vegas_inc(
+                input_price,
+                channel_upper,
+                channel_lower,
+                boundary_upper,
+                boundary_lower,
+            )?);
+            return Ok(self.prev);
+        }
+
+        self.warmup.push(input_price);
+        if self.warmup.len() <= LOOKBACK {
+            return Ok(None);
+        }
+
+        let len = self.warmup.len();
+        let mut output_channel_upper = vec![0.0; len];
+        let mut output_channel_lower = vec![0.0; len];
+        let mut output_boundary_upper = vec![0.0; len];
+        let mut output_boundary_lower = vec![0.0; len];
+        vegas(
+            &self.warmup,
+            &mut output_channel_upper,
+            &mut output_channel_lower,
+            &mut output_boundary_upper,
+            &mut output_boundary_lower,
+        )?;
+        self.warmup.clear();
+        self.prev = Some((
+            output_channel_upper[LOOKBACK],
+            output_channel_lower[LOOKBACK],
+            output_boundary_upper[LOOKBACK],
+            output_boundary_lower[LOOKBACK],
+        ));
+        Ok(self.prev)
+    }
+
+    fn reset(&mut self) {
+        self.warmup.clear();
+        self.prev = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        LOOKBACK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_vegas_stream() {
+        let input_price: Vec<TAFloat> = (0..720)
+            .map(|i| {
+                let x = i as TAFloat;
+                (x * 0.05).sin().mul_add(150.0, x.mul_add(0.8, 35000.0))
+            })
+            .collect();
+        let len = input_price.len();
+        let mut output_channel_upper = vec![0.0; len];
+        let mut output_channel_lower = vec![0.0; len];
+        let mut output_boundary_upper = vec![0.0; len];
+        let mut output_boundary_lower = vec![0.0; len];
+        vegas(
+            &input_price,
+            &mut output_channel_upper,
+            &mut output_channel_lower,
+            &mut output_boundary_upper,
+            &mut output_boundary_lower,
+        )
+        .unwrap();
+
+        let mut stream = Vegas::new();
+        for (i, price) in input_price.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some((channel_upper, channel_lower, boundary_upper, boundary_lower)) => {
+                    assert!(i >= stream.lookback());
+                    assert_relative_eq!(channel_upper, output_channel_upper[i], epsilon = 1e-8);
+                    assert_relative_eq!(channel_lower, output_channel_lower[i], epsilon = 1e-8);
+                    assert_relative_eq!(boundary_upper, output_boundary_upper[i], epsilon = 1e-8);
+                    assert_relative_eq!(boundary_lower, output_boundary_lower[i], epsilon = 1e-8);
+                }
+                None => assert!(i < stream.lookback()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
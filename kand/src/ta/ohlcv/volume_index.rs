@@ -0,0 +1,435 @@
+use crate::{Indicator, KandError, TAFloat};
+
+/// Returns the lookback period required for Negative and Positive Volume Index calculation.
+///
+/// # Description
+/// Both indices start from their initial value on the first bar, so no lookback period is
+/// needed.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns 0 as the lookback period
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::volume_index;
+/// assert_eq!(volume_index::lookback().unwrap(), 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Calculates the Negative Volume Index (NVI).
+///
+/// # Description
+/// The NVI only moves on bars where volume falls, on the premise that quiet sessions show
+/// what informed money is doing. It compounds the percentage price change of those bars and
+/// stays flat otherwise. It is usually read against its own long moving average; see
+/// [`pvi`] for the rising-volume counterpart.
+///
+/// # Mathematical Formula
+/// ```text
+/// NVI[0] = Start
+/// NVI[i] = NVI[i-1] * (1 + (Close[i] - Close[i-1]) / Close[i-1])   if Volume[i] < Volume[i-1]
+///        = NVI[i-1]                                              otherwise
+/// ```
+/// A previous close of 0 leaves the index unchanged.
+///
+/// # Arguments
+/// * `input_close` - Array of close prices
+/// * `input_volume` - Array of volume data
+/// * `param_start` - Initial index value, customarily 1000
+/// * `output_nvi` - Array storing the NVI values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::volume_index;
+///
+/// let input_close = vec![10.0, 11.0, 12.1, 11.0];
+/// let input_volume = vec![100.0, 80.0, 120.0, 90.0];
+/// let mut output_nvi = vec![0.0; 4];
+///
+/// volume_index::nvi(&input_close, &input_volume, 1000.0, &mut output_nvi).unwrap();
+///
+/// // Only the bars with falling volume (1 and 3) move the index
+/// assert_eq!(output_nvi[1], 1100.0);
+/// assert_eq!(output_nvi[2], 1100.0);
+/// assert!((output_nvi[3] - 1000.0).abs() < 1e-9);
+/// ```
+pub fn nvi(
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    param_start: TAFloat,
+    output_nvi: &mut [TAFloat],
+) -> Result<(), KandError> {
+    volume_index(input_close, input_volume, param_start, false, output_nvi)
+}
+
+/// Calculates the Positive Volume Index (PVI).
+///
+/// # Description
+/// The PVI is the mirror image of [`nvi`]: it only moves on bars where volume rises,
+/// tracking the busy sessions that the crowd drives.
+///
+/// # Mathematical Formula
+/// ```text
+/// PVI[0] = Start
+/// PVI[i] = PVI[i-1] * (1 + (Close[i] - Close[i-1]) / Close[i-1])   if Volume[i] > Volume[i-1]
+///        = PVI[i-1]                                              otherwise
+/// ```
+///
+/// # Arguments
+/// * `input_close` - Array of close prices
+/// * `input_volume` - Array of volume data
+/// * `param_start` - Initial index value, customarily 1000
+/// * `output_pvi` - Array storing the PVI values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// Same as [`nvi`].
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::volume_index;
+///
+/// let input_close = vec![10.0, 11.0, 12.1, 11.0];
+/// let input_volume = vec![100.0, 120.0, 80.0, 130.0];
+/// let mut output_pvi = vec![0.0; 4];
+///
+/// volume_index::pvi(&input_close, &input_volume, 1000.0, &mut output_pvi).unwrap();
+///
+/// // Only the bars with rising volume (1 and 3) move the index
+/// assert_eq!(output_pvi[1], 1100.0);
+/// assert_eq!(output_pvi[2], 1100.0);
+/// assert!((output_pvi[3] - 1000.0).abs() < 1e-9);
+/// ```
+pub fn pvi(
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    param_start: TAFloat,
+    output_pvi: &mut [TAFloat],
+) -> Result<(), KandError> {
+    volume_index(input_close, input_volume, param_start, true, output_pvi)
+}
+
+fn volume_index(
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    param_start: TAFloat,
+    rising: bool,
+    output: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_close.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_volume.len() != len || output.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        for i in 0..len {
+            // NaN check
+            if input_close[i].is_nan() || input_volume[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    output[0] = param_start;
+    for i in 1..len {
+        output[i] = volume_index_inc(
+            input_close[i],
+            input_volume[i],
+            input_close[i - 1],
+            input_volume[i - 1],
+            output[i - 1],
+            rising,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next Negative Volume Index value incrementally.
+///
+/// # Arguments
+/// * `input_close` - Current close price
+/// * `input_volume` - Current volume
+/// * `prev_close` - Previous close price
+/// * `prev_volume` - Previous volume
+/// * `prev_nvi` - Previous NVI value
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The new NVI value
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::volume_index;
+///
+/// assert_eq!(
+///     volume_index::nvi_inc(11.0, 80.0, 10.0, 100.0, 1000.0).unwrap(),
+///     1100.0
+/// );
+/// assert_eq!(
+///     volume_index::nvi_inc(11.0, 120.0, 10.0, 100.0, 1000.0).unwrap(),
+///     1000.0
+/// );
+/// ```
+pub fn nvi_inc(
+    input_close: TAFloat,
+    input_volume: TAFloat,
+    prev_close: TAFloat,
+    prev_volume: TAFloat,
+    prev_nvi: TAFloat,
+) -> Result<TAFloat, KandError> {
+    volume_index_inc(
+        input_close,
+        input_volume,
+        prev_close,
+        prev_volume,
+        prev_nvi,
+        false,
+    )
+}
+
+/// Calculates the next Positive Volume Index value incrementally.
+///
+/// # Arguments
+/// * `input_close` - Current close price
+/// * `input_volume` - Current volume
+/// * `prev_close` - Previous close price
+/// * `prev_volume` - Previous volume
+/// * `prev_pvi` - Previous PVI value
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The new PVI value
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::volume_index;
+///
+/// assert_eq!(
+///     volume_index::pvi_inc(11.0, 120.0, 10.0, 100.0, 1000.0).unwrap(),
+///     1100.0
+/// );
+/// assert_eq!(
+///     volume_index::pvi_inc(11.0, 80.0, 10.0, 100.0, 1000.0).unwrap(),
+///     1000.0
+/// );
+/// ```
+pub fn pvi_inc(
+    input_close: TAFloat,
+    input_volume: TAFloat,
+    prev_close: TAFloat,
+    prev_volume: TAFloat,
+    prev_pvi: TAFloat,
+) -> Result<TAFloat, KandError> {
+    volume_index_inc(
+        input_close,
+        input_volume,
+        prev_close,
+        prev_volume,
+        prev_pvi,
+        true,
+    )
+}
+
+/// Compounds the price change into the index when volume moved in the tracked direction:
+/// up for the PVI (`rising`), down for the NVI.
+fn volume_index_inc(
+    input_close: TAFloat,
+    input_volume: TAFloat,
+    prev_close: TAFloat,
+    prev_volume: TAFloat,
+    prev_index: TAFloat,
+    rising: bool,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_close.is_nan()
+            || input_volume.is_nan()
+            || prev_close.is_nan()
+            || prev_volume.is_nan()
+            || prev_index.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let tracked = if rising {
+        input_volume > prev_volume
+    } else {
+        input_volume < prev_volume
+    };
+    if !tracked || prev_close == 0.0 {
+        return Ok(prev_index);
+    }
+    Ok(prev_index.mul_add((input_close - prev_close) / prev_close, prev_index))
+}
+
+/// Streaming Negative or Positive Volume Index calculator.
+///
+/// Consumes `(close, volume)` bars. The first bar emits `param_start`, matching [`nvi`] and
+/// [`pvi`]; every later bar goes through [`nvi_inc`] or [`pvi_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::volume_index::VolumeIndex};
+///
+/// let mut nvi = VolumeIndex::nvi(1000.0);
+/// assert_eq!(nvi.update((10.0, 100.0)).unwrap(), Some(1000.0));
+/// assert_eq!(nvi.update((11.0, 80.0)).unwrap(), Some(1100.0));
+///
+/// let mut pvi = VolumeIndex::pvi(1000.0);
+/// assert_eq!(pvi.update((10.0, 100.0)).unwrap(), Some(1000.0));
+/// assert_eq!(pvi.update((11.0, 80.0)).unwrap(), Some(1000.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct VolumeIndex {
+    param_start: TAFloat,
+    rising:      bool,
+    prev:        Option<(TAFloat, TAFloat, TAFloat)>,
+}
+
+impl VolumeIndex {
+    /// Creates a new streaming NVI.
+    ///
+    /// # Arguments
+    /// * `param_start` - Initial index value, customarily 1000
+    #[must_use]
+    pub const fn nvi(param_start: TAFloat) -> Self {
+        Self {
+            param_start,
+            rising: false,
+            prev: None,
+        }
+    }
+
+    /// Creates a new streaming PVI.
+    ///
+    /// # Arguments
+    /// * `param_start` - Initial index value, customarily 1000
+    #[must_use]
+    pub const fn pvi(param_start: TAFloat) -> Self {
+        Self {
+            param_start,
+            rising: true,
+            prev: None,
+        }
+    }
+}
+
+impl Indicator for VolumeIndex {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_close, input_volume): (TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        let output_index = match self.prev {
+            Some((prev_close, prev_volume, prev_index)) => volume_index_inc(
+                input_close,
+                input_volume,
+                prev_close,
+                prev_volume,
+                prev_index,
+                self.rising,
+            )?,
+            None => self.param_start,
+        };
+        self.prev = Some((input_close, input_volume, output_index));
+        Ok(Some(output_index))
+    }
+
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_volume_index_calculation() {
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713,
+        ];
+        let len = input_close.len();
+        let mut output_nvi = vec![0.0; len];
+        let mut output_pvi = vec![0.0; len];
+
+        nvi(&input_close, &input_volume, 1000.0, &mut output_nvi).unwrap();
+        pvi(&input_close, &input_volume, 1000.0, &mut output_pvi).unwrap();
+
+        let mut expected_nvi = 1000.0;
+        let mut expected_pvi = 1000.0;
+        assert_eq!(output_nvi[0], expected_nvi);
+        assert_eq!(output_pvi[0], expected_pvi);
+        for i in 1..len {
+            let ratio = input_close[i] / input_close[i - 1];
+            if input_volume[i] < input_volume[i - 1] {
+                expected_nvi *= ratio;
+            } else {
+                expected_pvi *= ratio;
+            }
+            assert_relative_eq!(output_nvi[i], expected_nvi, epsilon = 1e-9);
+            assert_relative_eq!(output_pvi[i], expected_pvi, epsilon = 1e-9);
+        }
+
+        let mut stream_nvi = VolumeIndex::nvi(1000.0);
+        let mut stream_pvi = VolumeIndex::pvi(1000.0);
+        for i in 0..len {
+            let bar = (input_close[i], input_volume[i]);
+            let value = stream_nvi.update(bar).unwrap();
+            assert_relative_eq!(value.unwrap(), output_nvi[i], epsilon = 1e-12);
+            let value = stream_pvi.update(bar).unwrap();
+            assert_relative_eq!(value.unwrap(), output_pvi[i], epsilon = 1e-12);
+        }
+        assert!(stream_nvi.is_ready());
+        assert!(stream_pvi.is_ready());
+    }
+}
@@ -1,5 +1,5 @@
 use super::typprice;
-use crate::{KandError, TAFloat};
+use crate::{Indicator, KandError, TAFloat};
 
 /// Returns the lookback period required for VWAP calculation.
 ///
@@ -218,6 +218,73 @@ pub fn vwap_inc(
     Ok((cum_pv, cum_vol, vwap))
 }
 
+/// Streaming Volume Weighted Average Price (VWAP) calculator.
+///
+/// Consumes `(high, low, close, volume)` bars and carries the cumulative price-volume and
+/// volume totals between bars, starting from zero like [`vwap`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::vwap::Vwap};
+///
+/// let mut vwap = Vwap::new();
+/// assert_eq!(vwap.update((12.0, 8.0, 10.0, 100.0)).unwrap(), Some(10.0));
+/// assert_eq!(vwap.update((15.0, 11.0, 13.0, 100.0)).unwrap(), Some(11.5));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Vwap {
+    prev_cum_pv:  TAFloat,
+    prev_cum_vol: TAFloat,
+    ready:        bool,
+}
+
+impl Vwap {
+    /// Creates a new streaming VWAP.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            prev_cum_pv:  0.0,
+            prev_cum_vol: 0.0,
+            ready:        false,
+        }
+    }
+}
+
+impl Indicator for Vwap {
+    type Input = (TAFloat, TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close, input_volume): (TAFloat, TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        let (output_cum_pv, output_cum_vol, output_vwap) = vwap_inc(
+            input_high,
+            input_low,
+            input_close,
+            input_volume,
+            self.prev_cum_pv,
+            self.prev_cum_vol,
+        )?;
+        self.prev_cum_pv = output_cum_pv;
+        self.prev_cum_vol = output_cum_vol;
+        self.ready = true;
+        Ok(Some(output_vwap))
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -300,4 +367,52 @@ fn test_vwap_calculation() {
             prev_cum_vol = new_cum_vol;
         }
     }
+
+    #[test]
+    fn test_vwap_stream() {
+        let input_high = vec![
+            35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0,
+            35210.0, 35185.4, 35230.0, 35241.0, 35218.1, 35212.6, 35128.9, 35047.7, 35019.5,
+            35078.8, 35085.0, 35034.1, 34984.4, 35010.8, 35047.1, 35091.4,
+        ];
+        let input_low = vec![
+            35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0,
+            35166.0, 35170.9, 35154.1, 35186.0, 35143.9, 35080.1, 35021.1, 34950.1, 34966.0,
+            35012.3, 35022.2, 34931.6, 34911.0, 34952.5, 34977.9, 35039.0,
+        ];
+        let input_close = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let input_volume = vec![
+            1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333,
+            467.901, 387.47, 566.099, 672.296, 834.915, 1854.024, 3670.795, 3761.198, 1605.442,
+            1726.574, 934.713, 2199.061, 2349.823, 837.218, 1000.638, 1218.202,
+        ];
+        let len = input_close.len();
+        let mut output_vwap = vec![0.0; len];
+        let mut output_cum_pv = vec![0.0; len];
+        let mut output_cum_vol = vec![0.0; len];
+
+        vwap(
+            &input_high,
+            &input_low,
+            &input_close,
+            &input_volume,
+            &mut output_vwap,
+            &mut output_cum_pv,
+            &mut output_cum_vol,
+        )
+        .unwrap();
+
+        let mut stream = Vwap::new();
+        for i in 0..len {
+            let value = stream
+                .update((input_high[i], input_low[i], input_close[i], input_volume[i]))
+                .unwrap();
+            assert_relative_eq!(value.unwrap(), output_vwap[i], epsilon = 0.00001);
+        }
+        assert!(stream.is_ready());
+    }
 }
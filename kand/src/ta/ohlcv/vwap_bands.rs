@@ -0,0 +1,925 @@
+use super::typprice;
+use crate::{Indicator, KandError, TAFloat, bars::resample};
+
+/// Returns the lookback period required for VWAP band calculation.
+///
+/// # Description
+/// Like [`vwap`](super::vwap), the bands are defined from the first bar of each session.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns 0 as the lookback period
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::vwap_bands;
+/// assert_eq!(vwap_bands::lookback().unwrap(), 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Validates the band width.
+fn check_params(param_dev: TAFloat) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_dev.is_nan() || param_dev < 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    let _ = param_dev;
+    Ok(())
+}
+
+/// Calculates VWAP with volume-weighted standard deviation bands, restarting on a reset mask.
+///
+/// # Description
+/// The VWAP, deviation and volume totals are cleared whenever `input_reset` is `true`, so each
+/// bar flagged as a reset starts a new session. The bands are placed `param_dev` standard
+/// deviations around VWAP, where the deviation is that of the typical price weighted by
+/// volume over the current session.
+///
+/// # Mathematical Formula
+/// ```text
+/// TP       = (High + Low + Close) / 3
+/// VWAP     = Σ(TP * Volume) / Σ(Volume)
+/// Variance = Σ(Volume * (TP - VWAP)²) / Σ(Volume)
+/// Upper    = VWAP + Dev * √Variance
+/// Lower    = VWAP - Dev * √Variance
+/// ```
+/// All sums run from the latest reset bar (or the first bar) to the current bar.
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `input_volume` - Array of volumes
+/// * `input_reset` - Array of flags, `true` on the first bar of each session
+/// * `param_dev` - Number of standard deviations for the bands (must be >= 0)
+/// * `output_vwap` - Array storing the VWAP values
+/// * `output_upper` - Array storing the upper band values
+/// * `output_lower` - Array storing the lower band values
+/// * `output_cum_sq_dev` - Array storing the session sums of volume-weighted squared deviations
+/// * `output_cum_vol` - Array storing the session volume sums
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If `param_dev` is negative
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::vwap_bands;
+///
+/// let input_high = vec![12.0, 15.0, 14.0, 13.0];
+/// let input_low = vec![8.0, 11.0, 10.0, 9.0];
+/// let input_close = vec![10.0, 13.0, 12.0, 11.0];
+/// let input_volume = vec![100.0, 100.0, 100.0, 300.0];
+/// // A new session starts on the third bar
+/// let input_reset = vec![false, false, true, false];
+/// let mut output_vwap = vec![0.0; 4];
+/// let mut output_upper = vec![0.0; 4];
+/// let mut output_lower = vec![0.0; 4];
+/// let mut output_cum_sq_dev = vec![0.0; 4];
+/// let mut output_cum_vol = vec![0.0; 4];
+///
+/// vwap_bands::vwap_bands(
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &input_volume,
+///     &input_reset,
+///     1.0,
+///     &mut output_vwap,
+///     &mut output_upper,
+///     &mut output_lower,
+///     &mut output_cum_sq_dev,
+///     &mut output_cum_vol,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(output_vwap, vec![10.0, 11.5, 12.0, 11.25]);
+/// assert_eq!(output_upper[1], 13.0);
+/// assert_eq!(output_lower[1], 10.0);
+/// ```
+pub fn vwap_bands(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    input_reset: &[bool],
+    param_dev: TAFloat,
+    output_vwap: &mut [TAFloat],
+    output_upper: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+    output_cum_sq_dev: &mut [TAFloat],
+    output_cum_vol: &mut [TAFloat],
+) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if input_reset.len() != input_high.len() {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    accumulate(
+        input_high,
+        input_low,
+        input_close,
+        input_volume,
+        param_dev,
+        |i| input_reset[i],
+        0,
+        output_vwap,
+        output_upper,
+        output_lower,
+        output_cum_sq_dev,
+        output_cum_vol,
+    )
+}
+
+/// Calculates session VWAP with standard deviation bands, restarting at each session start.
+///
+/// # Description
+/// Sessions are the intervals of length `param_session` aligned to the epoch shifted by
+/// `param_offset`, as in [`resample::bucket_start`]. The totals are cleared on the first bar
+/// of every session, e.g. a daily VWAP for a market opening at 13:30 UTC uses a one day
+/// session with an offset of 13.5 hours. Gaps between sessions need no special handling.
+/// The bands are computed as in [`vwap_bands`].
+///
+/// # Arguments
+/// * `input_timestamp` - Array of bar start times (non-decreasing)
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `input_volume` - Array of volumes
+/// * `param_session` - Session length in timestamp units (must be > 0)
+/// * `param_offset` - Shift of the session grid from the epoch (0 <= offset < session)
+/// * `param_dev` - Number of standard deviations for the bands (must be >= 0)
+/// * `output_vwap` - Array storing the VWAP values
+/// * `output_upper` - Array storing the upper band values
+/// * `output_lower` - Array storing the lower band values
+/// * `output_cum_sq_dev` - Array storing the session sums of volume-weighted squared deviations
+/// * `output_cum_vol` - Array storing the session volume sums
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty or timestamps decrease
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If the session, offset or `param_dev` is invalid
+/// * `KandError::NaNDetected` - If any input contains NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::vwap_bands;
+///
+/// // Hourly bars over two days, daily sessions
+/// let hour = 3_600;
+/// let input_timestamp: Vec<i64> = (0..48).map(|i| i * hour).collect();
+/// let input_close: Vec<f64> = (0..48).map(|i| 100.0 + i as f64).collect();
+/// let input_volume = vec![1.0; 48];
+/// let mut output_vwap = vec![0.0; 48];
+/// let mut output_upper = vec![0.0; 48];
+/// let mut output_lower = vec![0.0; 48];
+/// let mut output_cum_sq_dev = vec![0.0; 48];
+/// let mut output_cum_vol = vec![0.0; 48];
+///
+/// vwap_bands::session_vwap(
+///     &input_timestamp,
+///     &input_close,
+///     &input_close,
+///     &input_close,
+///     &input_volume,
+///     24 * hour,
+///     0,
+///     2.0,
+///     &mut output_vwap,
+///     &mut output_upper,
+///     &mut output_lower,
+///     &mut output_cum_sq_dev,
+///     &mut output_cum_vol,
+/// )
+/// .unwrap();
+///
+/// // The second day starts afresh from its first close
+/// assert_eq!(output_vwap[23], 111.5);
+/// assert_eq!(output_vwap[24], 124.0);
+/// assert_eq!(output_cum_vol[24], 1.0);
+/// ```
+pub fn session_vwap(
+    input_timestamp: &[i64],
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    param_session: i64,
+    param_offset: i64,
+    param_dev: TAFloat,
+    output_vwap: &mut [TAFloat],
+    output_upper: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+    output_cum_sq_dev: &mut [TAFloat],
+    output_cum_vol: &mut [TAFloat],
+) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter check
+        if param_session <= 0 || param_offset < 0 || param_offset >= param_session {
+            return Err(KandError::InvalidParameter);
+        }
+
+        // Length consistency check
+        if input_timestamp.len() != input_high.len() {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Timestamp order check
+        if input_timestamp.windows(2).any(|pair| pair[1] < pair[0]) {
+            return Err(KandError::InvalidData);
+        }
+    }
+
+    accumulate(
+        input_high,
+        input_low,
+        input_close,
+        input_volume,
+        param_dev,
+        |i| {
+            i > 0
+                && resample::bucket_start(input_timestamp[i], param_session, param_offset)
+                    != resample::bucket_start(input_timestamp[i - 1], param_session, param_offset)
+        },
+        0,
+        output_vwap,
+        output_upper,
+        output_lower,
+        output_cum_sq_dev,
+        output_cum_vol,
+    )
+}
+
+/// Calculates anchored VWAP with standard deviation bands, starting from a given bar.
+///
+/// # Description
+/// Accumulation starts at bar `param_anchor`, typically a swing high or low, an earnings
+/// release or the open of a session. Bars before the anchor receive NaN in every output.
+/// The bands are computed as in [`vwap_bands`].
+///
+/// # Arguments
+/// * `input_high` - Array of high prices
+/// * `input_low` - Array of low prices
+/// * `input_close` - Array of close prices
+/// * `input_volume` - Array of volumes
+/// * `param_anchor` - Index of the bar where accumulation starts (must be < input length)
+/// * `param_dev` - Number of standard deviations for the bands (must be >= 0)
+/// * `output_vwap` - Array storing the VWAP values
+/// * `output_upper` - Array storing the upper band values
+/// * `output_lower` - Array storing the lower band values
+/// * `output_cum_sq_dev` - Array storing the sums of volume-weighted squared deviations since
+///   the anchor
+/// * `output_cum_vol` - Array storing the volume sums since the anchor
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input/output arrays have different lengths
+/// * `KandError::InvalidParameter` - If the anchor is out of range or `param_dev` is negative
+/// * `KandError::NaNDetected` - If any input from the anchor on contains NaN (with
+///   "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::vwap_bands;
+///
+/// let input_high = vec![12.0, 15.0, 14.0, 13.0];
+/// let input_low = vec![8.0, 11.0, 10.0, 9.0];
+/// let input_close = vec![10.0, 13.0, 12.0, 11.0];
+/// let input_volume = vec![100.0, 100.0, 100.0, 300.0];
+/// let mut output_vwap = vec![0.0; 4];
+/// let mut output_upper = vec![0.0; 4];
+/// let mut output_lower = vec![0.0; 4];
+/// let mut output_cum_sq_dev = vec![0.0; 4];
+/// let mut output_cum_vol = vec![0.0; 4];
+///
+/// vwap_bands::anchored_vwap(
+///     &input_high,
+///     &input_low,
+///     &input_close,
+///     &input_volume,
+///     1,
+///     2.0,
+///     &mut output_vwap,
+///     &mut output_upper,
+///     &mut output_lower,
+///     &mut output_cum_sq_dev,
+///     &mut output_cum_vol,
+/// )
+/// .unwrap();
+///
+/// assert!(output_vwap[0].is_nan());
+/// assert_eq!(output_vwap[1..], [13.0, 12.5, 11.6]);
+/// ```
+pub fn anchored_vwap(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    param_anchor: usize,
+    param_dev: TAFloat,
+    output_vwap: &mut [TAFloat],
+    output_upper: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+    output_cum_sq_dev: &mut [TAFloat],
+    output_cum_vol: &mut [TAFloat],
+) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter check
+        if !input_high.is_empty() && param_anchor >= input_high.len() {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    accumulate(
+        input_high,
+        input_low,
+        input_close,
+        input_volume,
+        param_dev,
+        |_| false,
+        param_anchor,
+        output_vwap,
+        output_upper,
+        output_lower,
+        output_cum_sq_dev,
+        output_cum_vol,
+    )
+}
+
+/// Shared loop of the batch functions: accumulates from `param_start` and clears the totals
+/// on every bar for which `is_reset` returns `true`.
+fn accumulate(
+    input_high: &[TAFloat],
+    input_low: &[TAFloat],
+    input_close: &[TAFloat],
+    input_volume: &[TAFloat],
+    param_dev: TAFloat,
+    is_reset: impl Fn(usize) -> bool,
+    param_start: usize,
+    output_vwap: &mut [TAFloat],
+    output_upper: &mut [TAFloat],
+    output_lower: &mut [TAFloat],
+    output_cum_sq_dev: &mut [TAFloat],
+    output_cum_vol: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_high.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_low.len() != len
+            || input_close.len() != len
+            || input_volume.len() != len
+            || output_vwap.len() != len
+            || output_upper.len() != len
+            || output_lower.len() != len
+            || output_cum_sq_dev.len() != len
+            || output_cum_vol.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    check_params(param_dev)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for i in param_start..len {
+            if input_high[i].is_nan()
+                || input_low[i].is_nan()
+                || input_close[i].is_nan()
+                || input_volume[i].is_nan()
+            {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut vwap = 0.0;
+    let mut cum_sq_dev = 0.0;
+    let mut cum_vol = 0.0;
+    for i in param_start..len {
+        if is_reset(i) {
+            vwap = 0.0;
+            cum_sq_dev = 0.0;
+            cum_vol = 0.0;
+        }
+
+        let (new_vwap, upper, lower, new_cum_sq_dev, new_cum_vol) = vwap_bands_inc(
+            input_high[i],
+            input_low[i],
+            input_close[i],
+            input_volume[i],
+            vwap,
+            cum_sq_dev,
+            cum_vol,
+            param_dev,
+        )?;
+        vwap = new_vwap;
+        cum_sq_dev = new_cum_sq_dev;
+        cum_vol = new_cum_vol;
+
+        output_vwap[i] = vwap;
+        output_upper[i] = upper;
+        output_lower[i] = lower;
+        output_cum_sq_dev[i] = cum_sq_dev;
+        output_cum_vol[i] = cum_vol;
+    }
+
+    // Fill bars before the anchor with NaN
+    for i in 0..param_start.min(len) {
+        output_vwap[i] = TAFloat::NAN;
+        output_upper[i] = TAFloat::NAN;
+        output_lower[i] = TAFloat::NAN;
+        output_cum_sq_dev[i] = TAFloat::NAN;
+        output_cum_vol[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the next VWAP and band values incrementally.
+///
+/// # Description
+/// Adds one bar to the session state. To start a new session or anchor, pass zero for the
+/// previous VWAP and both previous sums.
+///
+/// The squared deviations are accumulated with a volume-weighted Welford update rather than
+/// as `Σ(TP² * Volume) - VWAP² * Σ(Volume)`, which loses most of its precision to
+/// cancellation at typical price levels.
+///
+/// # Mathematical Formula
+/// ```text
+/// Cum Vol    = Prev Cum Vol + Volume
+/// VWAP       = Prev VWAP + Volume / Cum Vol * (TP - Prev VWAP)
+/// Cum Sq Dev = Prev Cum Sq Dev + Volume * (TP - Prev VWAP) * (TP - VWAP)
+/// Upper      = VWAP + Dev * √(Cum Sq Dev / Cum Vol)
+/// Lower      = VWAP - Dev * √(Cum Sq Dev / Cum Vol)
+/// ```
+/// As in [`vwap_inc`](super::vwap::vwap_inc), every value is 0 while the volume sum is 0.
+///
+/// # Arguments
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+/// * `input_close` - Current close price
+/// * `input_volume` - Current volume
+/// * `prev_vwap` - Previous VWAP
+/// * `prev_cum_sq_dev` - Previous sum of volume-weighted squared deviations
+/// * `prev_cum_vol` - Previous volume sum
+/// * `param_dev` - Number of standard deviations for the bands (must be >= 0)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple of
+///   (VWAP, upper band, lower band, cum squared deviation, cum volume)
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_dev` is negative
+/// * `KandError::NaNDetected` - If any input is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::ohlcv::vwap_bands;
+///
+/// // One bar with typical price 10 and volume 100 so far
+/// let (vwap, upper, lower, cum_sq_dev, cum_vol) =
+///     vwap_bands::vwap_bands_inc(15.0, 11.0, 13.0, 100.0, 10.0, 0.0, 100.0, 1.0).unwrap();
+/// assert_eq!((vwap, upper, lower), (11.5, 13.0, 10.0));
+/// assert_eq!((cum_sq_dev, cum_vol), (450.0, 200.0));
+/// ```
+pub fn vwap_bands_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    input_volume: TAFloat,
+    prev_vwap: TAFloat,
+    prev_cum_sq_dev: TAFloat,
+    prev_cum_vol: TAFloat,
+    param_dev: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    check_params(param_dev)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        if input_volume.is_nan()
+            || prev_vwap.is_nan()
+            || prev_cum_sq_dev.is_nan()
+            || prev_cum_vol.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let typ_price = typprice::typprice_inc(input_high, input_low, input_close)?;
+    let cum_vol = prev_cum_vol + input_volume;
+    if cum_vol == 0.0 {
+        return Ok((0.0, 0.0, 0.0, 0.0, cum_vol));
+    }
+
+    let delta = typ_price - prev_vwap;
+    let vwap = (input_volume / cum_vol).mul_add(delta, prev_vwap);
+    let cum_sq_dev = (input_volume * delta).mul_add(typ_price - vwap, prev_cum_sq_dev);
+    // Rounding can push the sum of a flat session slightly below zero
+    let std_dev = (cum_sq_dev / cum_vol).max(0.0).sqrt();
+    Ok((
+        vwap,
+        param_dev.mul_add(std_dev, vwap),
+        (-param_dev).mul_add(std_dev, vwap),
+        cum_sq_dev,
+        cum_vol,
+    ))
+}
+
+/// Streaming VWAP with standard deviation bands.
+///
+/// Consumes `(high, low, close, volume)` bars and emits `(vwap, upper, lower)`. The session
+/// keeps growing until [`VwapBands::reset`] is called, so a session or anchored VWAP is
+/// obtained by resetting on the first bar of each session or on the anchor bar.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::vwap_bands::VwapBands};
+///
+/// let mut vwap = VwapBands::new(1.0);
+/// vwap.update((12.0, 8.0, 10.0, 100.0)).unwrap();
+/// assert_eq!(
+///     vwap.update((15.0, 11.0, 13.0, 100.0)).unwrap(),
+///     Some((11.5, 13.0, 10.0))
+/// );
+///
+/// // New session
+/// vwap.reset();
+/// assert_eq!(
+///     vwap.update((14.0, 10.0, 12.0, 100.0)).unwrap(),
+///     Some((12.0, 12.0, 12.0))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct VwapBands {
+    param_dev:       TAFloat,
+    prev_vwap:       TAFloat,
+    prev_cum_sq_dev: TAFloat,
+    prev_cum_vol:    TAFloat,
+    ready:           bool,
+}
+
+impl VwapBands {
+    /// Creates a new streaming VWAP with bands `param_dev` standard deviations wide.
+    #[must_use]
+    pub const fn new(param_dev: TAFloat) -> Self {
+        Self {
+            param_dev,
+            prev_vwap: 0.0,
+            prev_cum_sq_dev: 0.0,
+            prev_cum_vol: 0.0,
+            ready: false,
+        }
+    }
+}
+
+impl Indicator for VwapBands {
+    type Input = (TAFloat, TAFloat, TAFloat, TAFloat);
+    type Output = (TAFloat, TAFloat, TAFloat);
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close, input_volume): (TAFloat, TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<Self::Output>, KandError> {
+        let (vwap, upper, lower, cum_sq_dev, cum_vol) = vwap_bands_inc(
+            input_high,
+            input_low,
+            input_close,
+            input_volume,
+            self.prev_vwap,
+            self.prev_cum_sq_dev,
+            self.prev_cum_vol,
+            self.param_dev,
+        )?;
+        self.prev_vwap = vwap;
+        self.prev_cum_sq_dev = cum_sq_dev;
+        self.prev_cum_vol = cum_vol;
+        self.ready = true;
+        Ok(Some((vwap, upper, lower)))
+    }
+
+    /// Clears the session; the next bar starts a new session or anchor.
+    fn reset(&mut self) {
+        *self = Self::new(self.param_dev);
+    }
+
+    fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::ohlcv::vwap;
+
+    const HIGH: [TAFloat; 12] = [
+        35266.0, 35247.5, 35235.7, 35190.8, 35182.0, 35258.0, 35262.9, 35281.5, 35256.0, 35210.0,
+        35185.4, 35230.0,
+    ];
+    const LOW: [TAFloat; 12] = [
+        35216.1, 35206.5, 35180.0, 35130.7, 35153.6, 35174.7, 35202.6, 35203.5, 35175.0, 35166.0,
+        35170.9, 35154.1,
+    ];
+    const CLOSE: [TAFloat; 12] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9,
+    ];
+    const VOLUME: [TAFloat; 12] = [
+        1055.365, 756.488, 682.152, 1197.747, 425.97, 859.638, 741.925, 888.477, 1043.333, 467.901,
+        387.47, 566.099,
+    ];
+
+    struct Outputs {
+        vwap:       Vec<TAFloat>,
+        upper:      Vec<TAFloat>,
+        lower:      Vec<TAFloat>,
+        cum_sq_dev: Vec<TAFloat>,
+        cum_vol:    Vec<TAFloat>,
+    }
+
+    impl Outputs {
+        fn new(len: usize) -> Self {
+            Self {
+                vwap:       vec![0.0; len],
+                upper:      vec![0.0; len],
+                lower:      vec![0.0; len],
+                cum_sq_dev: vec![0.0; len],
+                cum_vol:    vec![0.0; len],
+            }
+        }
+    }
+
+    /// Volume-weighted mean and standard deviation of the typical price over `range`.
+    fn reference(range: std::ops::Range<usize>) -> (TAFloat, TAFloat) {
+        let tp: Vec<TAFloat> = range
+            .clone()
+            .map(|i| (HIGH[i] + LOW[i] + CLOSE[i]) / 3.0)
+            .collect();
+        let volume = &VOLUME[range];
+        let total: TAFloat = volume.iter().sum();
+        let mean = tp.iter().zip(volume).map(|(p, v)| p * v).sum::<TAFloat>() / total;
+        let variance = tp
+            .iter()
+            .zip(volume)
+            .map(|(p, v)| (p - mean).powi(2) * v)
+            .sum::<TAFloat>()
+            / total;
+        (mean, variance.sqrt())
+    }
+
+    #[test]
+    fn test_vwap_bands_reset_mask() {
+        let len = CLOSE.len();
+        let mut input_reset = vec![false; len];
+        input_reset[5] = true;
+        let mut out = Outputs::new(len);
+        vwap_bands(
+            &HIGH,
+            &LOW,
+            &CLOSE,
+            &VOLUME,
+            &input_reset,
+            2.0,
+            &mut out.vwap,
+            &mut out.upper,
+            &mut out.lower,
+            &mut out.cum_sq_dev,
+            &mut out.cum_vol,
+        )
+        .unwrap();
+
+        // Before the reset the midline is the plain VWAP
+        let mut plain = vec![0.0; len];
+        let mut cum_pv = vec![0.0; len];
+        let mut cum_vol = vec![0.0; len];
+        vwap::vwap(
+            &HIGH,
+            &LOW,
+            &CLOSE,
+            &VOLUME,
+            &mut plain,
+            &mut cum_pv,
+            &mut cum_vol,
+        )
+        .unwrap();
+        for (value, expected) in out.vwap[..5].iter().zip(&plain) {
+            assert_relative_eq!(*value, *expected, epsilon = 1e-9);
+        }
+
+        for i in 0..len {
+            let start = if i < 5 { 0 } else { 5 };
+            let (mean, std_dev) = reference(start..i + 1);
+            assert_relative_eq!(out.vwap[i], mean, epsilon = 1e-6);
+            assert_relative_eq!(out.upper[i], mean + 2.0 * std_dev, epsilon = 1e-4);
+            assert_relative_eq!(out.lower[i], mean - 2.0 * std_dev, epsilon = 1e-4);
+        }
+        assert_relative_eq!(out.cum_vol[5], VOLUME[5]);
+        // A single bar has no spread
+        assert_relative_eq!(out.upper[0], out.vwap[0], epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_session_vwap() {
+        let len = CLOSE.len();
+        // 15 minute bars, four hour sessions opening at 01:00
+        let input_timestamp: Vec<i64> = (0..len as i64).map(|i| 4 * 3_600 + i * 900).collect();
+        let mut out = Outputs::new(len);
+        session_vwap(
+            &input_timestamp,
+            &HIGH,
+            &LOW,
+            &CLOSE,
+            &VOLUME,
+            4 * 3_600,
+            3_600,
+            1.5,
+            &mut out.vwap,
+            &mut out.upper,
+            &mut out.lower,
+            &mut out.cum_sq_dev,
+            &mut out.cum_vol,
+        )
+        .unwrap();
+
+        // Bar 4 opens the 05:00 session
+        let mut input_reset = vec![false; len];
+        input_reset[4] = true;
+        let mut expected = Outputs::new(len);
+        vwap_bands(
+            &HIGH,
+            &LOW,
+            &CLOSE,
+            &VOLUME,
+            &input_reset,
+            1.5,
+            &mut expected.vwap,
+            &mut expected.upper,
+            &mut expected.lower,
+            &mut expected.cum_sq_dev,
+            &mut expected.cum_vol,
+        )
+        .unwrap();
+        assert_eq!(out.vwap, expected.vwap);
+        assert_eq!(out.upper, expected.upper);
+        assert_eq!(out.lower, expected.lower);
+        assert_eq!(out.cum_vol, expected.cum_vol);
+    }
+
+    #[test]
+    fn test_anchored_vwap() {
+        let len = CLOSE.len();
+        let mut out = Outputs::new(len);
+        anchored_vwap(
+            &HIGH,
+            &LOW,
+            &CLOSE,
+            &VOLUME,
+            3,
+            1.0,
+            &mut out.vwap,
+            &mut out.upper,
+            &mut out.lower,
+            &mut out.cum_sq_dev,
+            &mut out.cum_vol,
+        )
+        .unwrap();
+
+        for i in 0..3 {
+            assert!(out.vwap[i].is_nan());
+            assert!(out.upper[i].is_nan());
+            assert!(out.cum_vol[i].is_nan());
+        }
+        for i in 3..len {
+            let (mean, std_dev) = reference(3..i + 1);
+            assert_relative_eq!(out.vwap[i], mean, epsilon = 1e-6);
+            assert_relative_eq!(out.upper[i], mean + std_dev, epsilon = 1e-4);
+            assert_relative_eq!(out.lower[i], mean - std_dev, epsilon = 1e-4);
+        }
+
+        // Resuming from the last sums matches the streaming form
+        let mut stream = VwapBands::new(1.0);
+        for i in 3..len {
+            let (vwap, upper, lower) = stream
+                .update((HIGH[i], LOW[i], CLOSE[i], VOLUME[i]))
+                .unwrap()
+                .unwrap();
+            assert_relative_eq!(vwap, out.vwap[i], epsilon = 1e-9);
+            assert_relative_eq!(upper, out.upper[i], epsilon = 1e-6);
+            assert_relative_eq!(lower, out.lower[i], epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_vwap_bands_stream_reset() {
+        let len = CLOSE.len();
+        let mut input_reset = vec![false; len];
+        input_reset[4] = true;
+        input_reset[9] = true;
+        let mut out = Outputs::new(len);
+        vwap_bands(
+            &HIGH,
+            &LOW,
+            &CLOSE,
+            &VOLUME,
+            &input_reset,
+            2.0,
+            &mut out.vwap,
+            &mut out.upper,
+            &mut out.lower,
+            &mut out.cum_sq_dev,
+            &mut out.cum_vol,
+        )
+        .unwrap();
+
+        let mut stream = VwapBands::new(2.0);
+        assert!(!stream.is_ready());
+        for i in 0..len {
+            if input_reset[i] {
+                stream.reset();
+            }
+            let (vwap, upper, lower) = stream
+                .update((HIGH[i], LOW[i], CLOSE[i], VOLUME[i]))
+                .unwrap()
+                .unwrap();
+            assert_relative_eq!(vwap, out.vwap[i], epsilon = 1e-9);
+            assert_relative_eq!(upper, out.upper[i], epsilon = 1e-6);
+            assert_relative_eq!(lower, out.lower[i], epsilon = 1e-6);
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_vwap_bands_invalid() {
+        let mut out = Outputs::new(2);
+        assert!(matches!(
+            anchored_vwap(
+                &HIGH[..2],
+                &LOW[..2],
+                &CLOSE[..2],
+                &VOLUME[..2],
+                2,
+                1.0,
+                &mut out.vwap,
+                &mut out.upper,
+                &mut out.lower,
+                &mut out.cum_sq_dev,
+                &mut out.cum_vol,
+            ),
+            Err(KandError::InvalidParameter)
+        ));
+        assert!(matches!(
+            vwap_bands(
+                &HIGH[..2],
+                &LOW[..2],
+                &CLOSE[..2],
+                &VOLUME[..2],
+                &[false],
+                1.0,
+                &mut out.vwap,
+                &mut out.upper,
+                &mut out.lower,
+                &mut out.cum_sq_dev,
+                &mut out.cum_vol,
+            ),
+            Err(KandError::LengthMismatch)
+        ));
+        assert!(matches!(
+            vwap_bands_inc(1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0, -1.0),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
@@ -1,8 +1,13 @@
 use crate::{
     EPSILON,
+    Indicator,
     KandError,
     TAFloat,
     helper::{highest_bars, lowest_bars},
+    ta::stats::{
+        max::{self, Max},
+        min::{self, Min},
+    },
 };
 
 /// Returns the lookback period required for Williams %R calculation
@@ -165,18 +170,16 @@ pub fn willr(
 /// Calculates Williams %R incrementally for the latest data point
 ///
 /// # Description
-/// This function provides an optimized way to calculate the latest Williams %R value
-/// by using previously calculated highest high and lowest low values. This is useful
-/// for real-time calculations where a complete recalculation is not necessary.
+/// This function calculates the latest Williams %R value from rolling highest high and
+/// lowest low windows. The windows are monotonic deques updated in place, so the result stays
+/// exact when the previous extreme leaves the lookback window, at O(1) amortized cost per bar.
 ///
 /// # Arguments
-/// * `prev_highest_high` - Previous period's highest high value
-/// * `prev_lowest_low` - Previous period's lowest low value
-/// * `prev_high` - Previous period's high price
-/// * `prev_low` - Previous period's low price
-/// * `input_close` - Current period's closing price
 /// * `input_high` - Current period's high price
 /// * `input_low` - Current period's low price
+/// * `input_close` - Current period's closing price
+/// * `rolling_high` - Rolling window of the last `param_period` highs, updated in place
+/// * `rolling_low` - Rolling window of the last `param_period` lows, updated in place
 ///
 /// # Returns
 /// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing:
@@ -189,28 +192,82 @@ pub fn willr(
 ///
 /// # Example
 /// ```
-/// use kand::ohlcv::willr::willr_inc;
+/// use kand::{
+///     ohlcv::willr::willr_inc,
+///     stats::{max::Max, min::Min},
+/// };
 ///
-/// let prev_highest_high = 15.0;
-/// let prev_lowest_low = 10.0;
-/// let prev_high = 14.0;
-/// let prev_low = 11.0;
-/// let input_close = 12.0;
-/// let input_high = 13.0;
-/// let input_low = 11.0;
+/// let param_period = 3;
+/// let mut rolling_high = Max::new(param_period).unwrap();
+/// let mut rolling_low = Min::new(param_period).unwrap();
+/// for (high, low, close) in [(15.0, 10.0, 12.0), (14.0, 11.0, 13.0)] {
+///     willr_inc(high, low, close, &mut rolling_high, &mut rolling_low).unwrap();
+/// }
 ///
-/// let (willr, new_highest_high, new_lowest_low) = willr_inc(
-///     prev_highest_high,
-///     prev_lowest_low,
-///     prev_high,
-///     prev_low,
-///     input_close,
-///     input_high,
-///     input_low,
-/// )
-/// .unwrap();
+/// let (willr, highest_high, lowest_low) =
+///     willr_inc(13.0, 11.0, 12.0, &mut rolling_high, &mut rolling_low).unwrap();
+/// assert_eq!((highest_high, lowest_low), (15.0, 10.0));
+/// assert_eq!(willr, -60.0);
 /// ```
 pub fn willr_inc(
+    input_high: TAFloat,
+    input_low: TAFloat,
+    input_close: TAFloat,
+    rolling_high: &mut Max,
+    rolling_low: &mut Min,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        if input_close.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let highest_high = max::max_inc(input_high, rolling_high)?;
+    let lowest_low = min::min_inc(input_low, rolling_low)?;
+
+    let denom = highest_high - lowest_low;
+    let willr = if denom == 0.0 {
+        0.0
+    } else {
+        (highest_high - input_close) / denom * -100.0
+    };
+
+    Ok((willr, highest_high, lowest_low))
+}
+
+/// Updates Williams %R from the previous extremes and the bar leaving the window.
+///
+/// This is the scalar form that predates [`Willr`]. An extreme that leaves the window is only
+/// compared against the new bar, so the result drifts from the batch output afterwards.
+///
+/// # Arguments
+/// * `prev_highest_high` - Previous highest high value
+/// * `prev_lowest_low` - Previous lowest low value
+/// * `prev_high` - High price of the bar leaving the window
+/// * `prev_low` - Low price of the bar leaving the window
+/// * `input_close` - Current close price
+/// * `input_high` - Current high price
+/// * `input_low` - Current low price
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (%R, highest high, lowest low)
+///
+/// # Errors
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// # #![allow(deprecated)]
+/// use kand::ohlcv::willr;
+///
+/// let (output, highest_high, lowest_low) =
+///     willr::willr_inc_legacy(12.0, 8.0, 11.0, 9.0, 10.0, 11.5, 9.5).unwrap();
+/// assert_eq!((highest_high, lowest_low), (12.0, 8.0));
+/// assert_eq!(output, -50.0);
+/// ```
+#[deprecated(note = "inexact once an extreme leaves the window; use `willr_inc` with `Max`/`Min`")]
+pub fn willr_inc_legacy(
     prev_highest_high: TAFloat,
     prev_lowest_low: TAFloat,
     prev_high: TAFloat,
@@ -233,21 +290,16 @@ pub fn willr_inc(
         }
     }
 
-    // Update highest high by removing old high and considering new high
     let new_highest_high = if input_high > prev_highest_high {
         input_high
     } else if (prev_high - prev_highest_high).abs() < EPSILON {
-        // If previous high was the highest, need to find new highest between current high and previous highest
         input_high.max(prev_highest_high)
     } else {
         prev_highest_high
     };
-
-    // Update lowest low by removing old low and considering new low
     let new_lowest_low = if input_low < prev_lowest_low {
         input_low
     } else if (prev_low - prev_lowest_low).abs() < EPSILON {
-        // If previous low was the lowest, need to find new lowest between current low and previous lowest
         input_low.min(prev_lowest_low)
     } else {
         prev_lowest_low
@@ -263,6 +315,75 @@ pub fn willr_inc(
     Ok((willr, new_highest_high, new_lowest_low))
 }
 
+/// Streaming Williams %R calculator.
+///
+/// Consumes `(high, low, close)` bars and owns the rolling highest high and lowest low
+/// windows used by [`willr_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::willr::Willr};
+///
+/// let mut willr = Willr::new(3).unwrap();
+/// assert_eq!(willr.update((15.0, 10.0, 12.0)).unwrap(), None);
+/// assert_eq!(willr.update((14.0, 11.0, 13.0)).unwrap(), None);
+/// assert_eq!(willr.update((13.0, 11.0, 12.0)).unwrap(), Some(-60.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Willr {
+    rolling_high: Max,
+    rolling_low:  Min,
+}
+
+impl Willr {
+    /// Creates a new streaming Williams %R.
+    ///
+    /// # Arguments
+    /// * `param_period` - The lookback period (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        lookback(param_period)?;
+        Ok(Self {
+            rolling_high: Max::new(param_period)?,
+            rolling_low:  Min::new(param_period)?,
+        })
+    }
+}
+
+impl Indicator for Willr {
+    type Input = (TAFloat, TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(
+        &mut self,
+        (input_high, input_low, input_close): (TAFloat, TAFloat, TAFloat),
+    ) -> Result<Option<TAFloat>, KandError> {
+        let (output_willr, _, _) = willr_inc(
+            input_high,
+            input_low,
+            input_close,
+            &mut self.rolling_high,
+            &mut self.rolling_low,
+        )?;
+        Ok(self.is_ready().then_some(output_willr))
+    }
+
+    fn reset(&mut self) {
+        self.rolling_high.reset();
+        self.rolling_low.reset();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.rolling_high.is_ready()
+    }
+
+    fn lookback(&self) -> usize {
+        self.rolling_high.lookback()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -329,25 +450,44 @@ fn test_willr_calculation() {
             assert_relative_eq!(output[i + 13], *expected, epsilon = 0.0001);
         }
         // Test incremental calculation matches regular calculation
-        let mut prev_highest_high = output_highest_high[13];
-        let mut prev_lowest_low = output_lowest_low[13];
+        let mut rolling_high = Max::new(param_period).unwrap();
+        let mut rolling_low = Min::new(param_period).unwrap();
+        for i in 0..param_period {
+            willr_inc(
+                input_high[i],
+                input_low[i],
+                input_close[i],
+                &mut rolling_high,
+                &mut rolling_low,
+            )
+            .unwrap();
+        }
 
-        for i in 14..19 {
+        for i in param_period..input_high.len() {
             let (result, highest_high, lowest_low) = willr_inc(
-                prev_highest_high,
-                prev_lowest_low,
-                input_high[i - 1],
-                input_low[i - 1],
-                input_close[i],
                 input_high[i],
                 input_low[i],
+                input_close[i],
+                &mut rolling_high,
+                &mut rolling_low,
             )
             .unwrap();
 
             assert_relative_eq!(result, output[i], epsilon = 0.0001);
+            assert_relative_eq!(highest_high, output_highest_high[i], epsilon = 0.0001);
+            assert_relative_eq!(lowest_low, output_lowest_low[i], epsilon = 0.0001);
+        }
 
-            prev_highest_high = highest_high;
-            prev_lowest_low = lowest_low;
+        // Streaming calculation matches the batch output bar for bar
+        let mut stream = Willr::new(param_period).unwrap();
+        for i in 0..input_high.len() {
+            match stream
+                .update((input_high[i], input_low[i], input_close[i]))
+                .unwrap()
+            {
+                Some(value) => assert_relative_eq!(value, output[i], epsilon = 0.0001),
+                None => assert!(output[i].is_nan()),
+            }
         }
     }
 }
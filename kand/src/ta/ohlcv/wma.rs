@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
 
 /// Calculates the lookback period required for Weighted Moving Average (WMA).
 ///
@@ -195,6 +197,75 @@ pub fn wma_inc(input_window: &[TAFloat], param_period: usize) -> Result<TAFloat,
     Ok(weighted_sum / denominator)
 }
 
+/// Streaming Weighted Moving Average (WMA) calculator.
+///
+/// Keeps the last `param_period` prices ordered newest first, which is the layout
+/// [`wma_inc`] expects for its window.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, ohlcv::wma::Wma};
+///
+/// let mut wma = Wma::new(3).unwrap();
+/// assert_eq!(wma.update(1.0).unwrap(), None);
+/// assert_eq!(wma.update(2.0).unwrap(), None);
+/// // (3 * 3 + 2 * 2 + 1 * 1) / 6
+/// let value = wma.update(3.0).unwrap().unwrap();
+/// assert!((value - 14.0 / 6.0).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Wma {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<TAFloat>,
+}
+
+impl Wma {
+    /// Creates a new streaming WMA.
+    ///
+    /// # Arguments
+    /// * `param_period` - The time period for WMA calculation (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period + 1),
+        })
+    }
+}
+
+impl Indicator for Wma {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        self.window.push_front(input_price);
+        if self.window.len() > self.param_period {
+            self.window.pop_back();
+        }
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        wma_inc(self.window.make_contiguous(), self.param_period).map(Some)
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -251,4 +322,26 @@ fn test_wma_calculation() {
             assert_relative_eq!(result, output[i], epsilon = 0.0001);
         }
     }
+
+    #[test]
+    fn test_wma_stream() {
+        let input = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 10;
+        let mut output = vec![0.0; input.len()];
+
+        wma(&input, param_period, &mut output).unwrap();
+
+        let mut stream = Wma::new(param_period).unwrap();
+        for (i, price) in input.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output[i], epsilon = 0.00001),
+                None => assert!(output[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
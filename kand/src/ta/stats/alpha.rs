@@ -0,0 +1,456 @@
+use std::collections::VecDeque;
+
+use crate::{
+    Indicator,
+    KandError,
+    TAFloat,
+    stats::beta::{self, beta_inc},
+};
+
+/// Calculates the lookback period required for Alpha calculation.
+///
+/// # Description
+/// Alpha is derived from the same rolling window as [`beta`](super::beta), so the lookback is
+/// the period minus one.
+///
+/// # Arguments
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::alpha;
+/// let lookback = alpha::lookback(60).unwrap();
+/// assert_eq!(lookback, 59);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    beta::lookback(param_period)
+}
+
+/// Validates the annualization factor shared by all Alpha entry points.
+fn check_annualization(param_annualization: TAFloat) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_annualization <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(())
+}
+
+/// Annualized Jensen's alpha from the window means of asset and benchmark returns.
+fn alpha_value(
+    beta: TAFloat,
+    sum_a: TAFloat,
+    sum_b: TAFloat,
+    period: TAFloat,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+) -> TAFloat {
+    let risk_free = param_risk_free / param_annualization;
+    let excess_benchmark = sum_b / period - risk_free;
+    (sum_a / period - risk_free - beta * excess_benchmark) * param_annualization
+}
+
+/// Calculates the rolling Jensen's Alpha (ALPHA) of an asset against a benchmark.
+///
+/// # Description
+/// Alpha is the part of the asset's return that is not explained by its exposure to the
+/// benchmark. A positive alpha means the asset outperformed what its [`beta`](super::beta)
+/// would predict. The per-period alpha is annualized by multiplying with the annualization
+/// factor.
+///
+/// # Mathematical Formula
+/// ```text
+/// Rf = RiskFree / Annualization
+/// ALPHA = (mean(A) - Rf - BETA * (mean(B) - Rf)) * Annualization
+/// ```
+/// Where A and B are the last n asset and benchmark returns.
+///
+/// # Arguments
+/// * `input_asset` - Per-period returns of the asset
+/// * `input_benchmark` - Per-period returns of the benchmark
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+/// * `param_annualization` - Periods per year, e.g. 252 for daily bars (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate, e.g. 0.02 for 2%
+/// * `output_alpha` - Array to store annualized Alpha values
+/// * `output_beta` - Array to store Beta values
+/// * `output_sum_a` - Array to store running sums of asset returns
+/// * `output_sum_b` - Array to store running sums of benchmark returns
+/// * `output_sum_bb` - Array to store running sums of squared benchmark returns
+/// * `output_sum_ab` - Array to store running sums of asset * benchmark returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input arrays are empty
+/// * Returns `KandError::LengthMismatch` if input or output arrays have different lengths
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or annualization is not positive
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::alpha;
+/// // The asset earns its 2x benchmark exposure plus 0.1% per period
+/// let input_asset = vec![0.021, -0.039, 0.011, 0.031];
+/// let input_benchmark = vec![0.01, -0.02, 0.005, 0.015];
+/// let mut output_alpha = vec![0.0; 4];
+/// let mut output_beta = vec![0.0; 4];
+/// let mut output_sum_a = vec![0.0; 4];
+/// let mut output_sum_b = vec![0.0; 4];
+/// let mut output_sum_bb = vec![0.0; 4];
+/// let mut output_sum_ab = vec![0.0; 4];
+///
+/// alpha::alpha(
+///     &input_asset,
+///     &input_benchmark,
+///     3,
+///     252.0,
+///     0.0,
+///     &mut output_alpha,
+///     &mut output_beta,
+///     &mut output_sum_a,
+///     &mut output_sum_b,
+///     &mut output_sum_bb,
+///     &mut output_sum_ab,
+/// )
+/// .unwrap();
+/// assert!((output_beta[3] - 2.0).abs() < 1e-10);
+/// assert!((output_alpha[3] - 0.252).abs() < 1e-10);
+/// ```
+pub fn alpha(
+    input_asset: &[TAFloat],
+    input_benchmark: &[TAFloat],
+    param_period: usize,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+    output_alpha: &mut [TAFloat],
+    output_beta: &mut [TAFloat],
+    output_sum_a: &mut [TAFloat],
+    output_sum_b: &mut [TAFloat],
+    output_sum_bb: &mut [TAFloat],
+    output_sum_ab: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_asset.len();
+    let lookback = lookback(param_period)?;
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Length consistency check
+        if output_alpha.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    // Empty data, length, sufficiency and NaN checks are done by BETA
+    beta::beta(
+        input_asset,
+        input_benchmark,
+        param_period,
+        output_beta,
+        output_sum_a,
+        output_sum_b,
+        output_sum_bb,
+        output_sum_ab,
+    )?;
+
+    let period_t = param_period as TAFloat;
+    for i in lookback..len {
+        output_alpha[i] = alpha_value(
+            output_beta[i],
+            output_sum_a[i],
+            output_sum_b[i],
+            period_t,
+            param_annualization,
+            param_risk_free,
+        );
+    }
+
+    // Fill initial values with NAN
+    for value in output_alpha.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest Jensen's Alpha value using incremental computation.
+///
+/// # Description
+/// Advances the Beta window sums with [`beta_inc`] and derives Alpha from the updated means.
+///
+/// # Arguments
+/// * `input_asset` - The newest asset return
+/// * `input_benchmark` - The newest benchmark return
+/// * `input_old_asset` - The asset return leaving the window
+/// * `input_old_benchmark` - The benchmark return leaving the window
+/// * `prev_sum_a` - Previous sum of asset returns
+/// * `prev_sum_b` - Previous sum of benchmark returns
+/// * `prev_sum_bb` - Previous sum of squared benchmark returns
+/// * `prev_sum_ab` - Previous sum of asset * benchmark returns
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+/// * `param_annualization` - Periods per year (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple
+///   containing (alpha, beta, `new_sum_a`, `new_sum_b`, `new_sum_bb`, `new_sum_ab`)
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or annualization is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::alpha;
+/// let (alpha, beta, ..) = alpha::alpha_inc(
+///     0.031,    // new asset return
+///     0.015,    // new benchmark return
+///     0.021,    // asset return leaving the window
+///     0.01,     // benchmark return leaving the window
+///     -0.007,   // previous sum of asset returns
+///     -0.005,   // previous sum of benchmark returns
+///     0.000525, // previous sum of squared benchmark returns
+///     0.001045, // previous sum of asset * benchmark returns
+///     3,        // period
+///     252.0,    // annualization
+///     0.0,      // risk-free rate
+/// )
+/// .unwrap();
+/// assert!((beta - 2.0).abs() < 1e-10);
+/// assert!((alpha - 0.252).abs() < 1e-10);
+/// ```
+pub fn alpha_inc(
+    input_asset: TAFloat,
+    input_benchmark: TAFloat,
+    input_old_asset: TAFloat,
+    input_old_benchmark: TAFloat,
+    prev_sum_a: TAFloat,
+    prev_sum_b: TAFloat,
+    prev_sum_bb: TAFloat,
+    prev_sum_ab: TAFloat,
+    param_period: usize,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    check_annualization(param_annualization)?;
+
+    let (beta, sum_a, sum_b, sum_bb, sum_ab) = beta_inc(
+        input_asset,
+        input_benchmark,
+        input_old_asset,
+        input_old_benchmark,
+        prev_sum_a,
+        prev_sum_b,
+        prev_sum_bb,
+        prev_sum_ab,
+        param_period,
+    )?;
+    let alpha = alpha_value(
+        beta,
+        sum_a,
+        sum_b,
+        param_period as TAFloat,
+        param_annualization,
+        param_risk_free,
+    );
+
+    Ok((alpha, beta, sum_a, sum_b, sum_bb, sum_ab))
+}
+
+/// Streaming Jensen's Alpha calculator.
+///
+/// Keeps the last `param_period` pairs of returns together with the window sums required by
+/// [`alpha_inc`]. While the window is filling, pairs are added to the sums without removing
+/// anything.
+///
+/// The input tuple is `(asset_return, benchmark_return)` and the output tuple is
+/// `(alpha, beta)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::alpha::Alpha};
+///
+/// let mut alpha = Alpha::new(2, 1.0, 0.0).unwrap();
+/// assert_eq!(alpha.update((0.03, 0.01)).unwrap(), None);
+/// let (alpha_value, beta_value) = alpha.update((0.0, -0.02)).unwrap().unwrap();
+/// assert!((beta_value - 1.0).abs() < 1e-10);
+/// assert!((alpha_value - 0.02).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Alpha {
+    param_period:        usize,
+    param_annualization: TAFloat,
+    param_risk_free:     TAFloat,
+    lookback:            usize,
+    window:              VecDeque<(TAFloat, TAFloat)>,
+    sums:                (TAFloat, TAFloat, TAFloat, TAFloat),
+}
+
+impl Alpha {
+    /// Creates a new streaming Jensen's Alpha.
+    ///
+    /// # Arguments
+    /// * `param_period` - The number of returns in the rolling window (must be >= 2)
+    /// * `param_annualization` - Periods per year (must be > 0)
+    /// * `param_risk_free` - Annual risk-free rate
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the period is less than 2 or annualization is not
+    ///   positive
+    pub fn new(
+        param_period: usize,
+        param_annualization: TAFloat,
+        param_risk_free: TAFloat,
+    ) -> Result<Self, KandError> {
+        check_annualization(param_annualization)?;
+        Ok(Self {
+            param_period,
+            param_annualization,
+            param_risk_free,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period + 1),
+            sums: (0.0, 0.0, 0.0, 0.0),
+        })
+    }
+}
+
+impl Indicator for Alpha {
+    type Input = (TAFloat, TAFloat);
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input: Self::Input) -> Result<Option<Self::Output>, KandError> {
+        let (input_asset, input_benchmark) = input;
+        self.window.push_back(input);
+        let (old_asset, old_benchmark) = if self.window.len() > self.param_period {
+            self.window.pop_front().unwrap_or_default()
+        } else {
+            (0.0, 0.0)
+        };
+
+        let (sum_a, sum_b, sum_bb, sum_ab) = self.sums;
+        let (output_alpha, output_beta, sum_a, sum_b, sum_bb, sum_ab) = alpha_inc(
+            input_asset,
+            input_benchmark,
+            old_asset,
+            old_benchmark,
+            sum_a,
+            sum_b,
+            sum_bb,
+            sum_ab,
+            self.param_period,
+            self.param_annualization,
+            self.param_risk_free,
+        )?;
+        self.sums = (sum_a, sum_b, sum_bb, sum_ab);
+        Ok(self.is_ready().then_some((output_alpha, output_beta)))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sums = (0.0, 0.0, 0.0, 0.0);
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_ASSET: [TAFloat; 12] = [
+        0.012, -0.008, 0.004, 0.015, -0.021, 0.007, 0.003, -0.005, 0.018, -0.002, 0.009, -0.011,
+    ];
+    const INPUT_BENCHMARK: [TAFloat; 12] = [
+        0.008, -0.004, 0.001, 0.009, -0.012, 0.006, -0.001, -0.002, 0.011, 0.001, 0.004, -0.007,
+    ];
+
+    #[test]
+    fn test_alpha_calculation() {
+        let len = INPUT_ASSET.len();
+        let mut output_alpha = vec![0.0; len];
+        let mut output_beta = vec![0.0; len];
+        let mut output_sum_a = vec![0.0; len];
+        let mut output_sum_b = vec![0.0; len];
+        let mut output_sum_bb = vec![0.0; len];
+        let mut output_sum_ab = vec![0.0; len];
+
+        alpha(
+            &INPUT_ASSET,
+            &INPUT_BENCHMARK,
+            5,
+            252.0,
+            0.02,
+            &mut output_alpha,
+            &mut output_beta,
+            &mut output_sum_a,
+            &mut output_sum_b,
+            &mut output_sum_bb,
+            &mut output_sum_ab,
+        )
+        .unwrap();
+
+        for value in output_alpha.iter().take(4) {
+            assert!(value.is_nan());
+        }
+        let expected_values = [
+            -0.056_390_563_564_875_45,
+            -0.137_746_762_589_928_07,
+            0.169_623_277_182_235_85,
+            -0.037_618_045_112_781_97,
+            -0.051_095_674_967_234_69,
+            -0.096_786_440_677_966_3,
+            0.096_820_512_820_512_63,
+            -0.129_147_902_869_757_28,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_alpha[i + 4], *expected, epsilon = 1e-9);
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let (alpha_value, beta_value, ..) = alpha_inc(
+            INPUT_ASSET[5],
+            INPUT_BENCHMARK[5],
+            INPUT_ASSET[0],
+            INPUT_BENCHMARK[0],
+            output_sum_a[4],
+            output_sum_b[4],
+            output_sum_bb[4],
+            output_sum_ab[4],
+            5,
+            252.0,
+            0.02,
+        )
+        .unwrap();
+        assert_relative_eq!(alpha_value, output_alpha[5], epsilon = 1e-9);
+        assert_relative_eq!(beta_value, output_beta[5], epsilon = 1e-9);
+
+        let mut stream = Alpha::new(5, 252.0, 0.02).unwrap();
+        for i in 0..len {
+            match stream.update((INPUT_ASSET[i], INPUT_BENCHMARK[i])).unwrap() {
+                Some((alpha_value, beta_value)) => {
+                    assert_relative_eq!(alpha_value, output_alpha[i], epsilon = 1e-9);
+                    assert_relative_eq!(beta_value, output_beta[i], epsilon = 1e-9);
+                }
+                None => assert!(output_alpha[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
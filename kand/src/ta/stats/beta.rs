@@ -0,0 +1,446 @@
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
+
+/// Calculates the lookback period required for Beta calculation.
+///
+/// # Description
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For BETA, this equals the specified period minus one.
+///
+/// # Arguments
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::beta;
+/// let lookback = beta::lookback(60).unwrap();
+/// assert_eq!(lookback, 59);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period - 1)
+}
+
+/// Beta from the window sums, 0 if the benchmark is constant.
+fn beta_value(
+    sum_a: TAFloat,
+    sum_b: TAFloat,
+    sum_bb: TAFloat,
+    sum_ab: TAFloat,
+    period: TAFloat,
+) -> TAFloat {
+    let var_b = sum_b.mul_add(-sum_b / period, sum_bb);
+    if var_b > 0.0 {
+        sum_a.mul_add(-sum_b / period, sum_ab) / var_b
+    } else {
+        0.0
+    }
+}
+
+/// Calculates the rolling Beta (BETA) of an asset against a benchmark.
+///
+/// # Description
+/// Beta measures how strongly the asset's returns move with the benchmark's returns: 1 means
+/// the asset moves in line with the benchmark, 2 twice as much, and negative values move
+/// against it. It is the hedge ratio for offsetting the benchmark exposure of a position.
+///
+/// # Mathematical Formula
+/// ```text
+/// BETA = Cov(A, B) / Var(B) = (Sab - Sa * Sb / n) / (Sbb - Sb^2 / n)
+/// ```
+/// Where Sa, Sb, Sbb and Sab are the sums of a, b, b^2 and a*b over the last n returns. The
+/// result is 0 when the benchmark is constant over the window.
+///
+/// # Arguments
+/// * `input_asset` - Per-period returns of the asset, e.g. from [`ret`](super::ret)
+/// * `input_benchmark` - Per-period returns of the benchmark
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+/// * `output_beta` - Array to store calculated Beta values
+/// * `output_sum_a` - Array to store running sums of asset returns
+/// * `output_sum_b` - Array to store running sums of benchmark returns
+/// * `output_sum_bb` - Array to store running sums of squared benchmark returns
+/// * `output_sum_ab` - Array to store running sums of asset * benchmark returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input arrays are empty
+/// * Returns `KandError::LengthMismatch` if input or output arrays have different lengths
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::beta;
+/// let input_asset = vec![0.02, -0.04, 0.01, 0.03];
+/// let input_benchmark = vec![0.01, -0.02, 0.005, 0.015];
+/// let mut output_beta = vec![0.0; 4];
+/// let mut output_sum_a = vec![0.0; 4];
+/// let mut output_sum_b = vec![0.0; 4];
+/// let mut output_sum_bb = vec![0.0; 4];
+/// let mut output_sum_ab = vec![0.0; 4];
+///
+/// beta::beta(
+///     &input_asset,
+///     &input_benchmark,
+///     3,
+///     &mut output_beta,
+///     &mut output_sum_a,
+///     &mut output_sum_b,
+///     &mut output_sum_bb,
+///     &mut output_sum_ab,
+/// )
+/// .unwrap();
+/// assert!((output_beta[3] - 2.0).abs() < 1e-10);
+/// ```
+pub fn beta(
+    input_asset: &[TAFloat],
+    input_benchmark: &[TAFloat],
+    param_period: usize,
+    output_beta: &mut [TAFloat],
+    output_sum_a: &mut [TAFloat],
+    output_sum_b: &mut [TAFloat],
+    output_sum_bb: &mut [TAFloat],
+    output_sum_ab: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_asset.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_benchmark.len() != len
+            || output_beta.len() != len
+            || output_sum_a.len() != len
+            || output_sum_b.len() != len
+            || output_sum_bb.len() != len
+            || output_sum_ab.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for i in 0..len {
+            if input_asset[i].is_nan() || input_benchmark[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial sums
+    let mut sum_a = 0.0;
+    let mut sum_b = 0.0;
+    let mut sum_bb = 0.0;
+    let mut sum_ab = 0.0;
+    for i in 0..param_period {
+        let (a, b) = (input_asset[i], input_benchmark[i]);
+        sum_a += a;
+        sum_b += b;
+        sum_bb = b.mul_add(b, sum_bb);
+        sum_ab = a.mul_add(b, sum_ab);
+    }
+
+    let period_t = param_period as TAFloat;
+    output_beta[lookback] = beta_value(sum_a, sum_b, sum_bb, sum_ab, period_t);
+    output_sum_a[lookback] = sum_a;
+    output_sum_b[lookback] = sum_b;
+    output_sum_bb[lookback] = sum_bb;
+    output_sum_ab[lookback] = sum_ab;
+
+    // Calculate remaining BETA values incrementally
+    for i in param_period..len {
+        let (beta, new_sum_a, new_sum_b, new_sum_bb, new_sum_ab) = beta_inc(
+            input_asset[i],
+            input_benchmark[i],
+            input_asset[i - param_period],
+            input_benchmark[i - param_period],
+            sum_a,
+            sum_b,
+            sum_bb,
+            sum_ab,
+            param_period,
+        )?;
+        sum_a = new_sum_a;
+        sum_b = new_sum_b;
+        sum_bb = new_sum_bb;
+        sum_ab = new_sum_ab;
+
+        output_beta[i] = beta;
+        output_sum_a[i] = sum_a;
+        output_sum_b[i] = sum_b;
+        output_sum_bb[i] = sum_bb;
+        output_sum_ab[i] = sum_ab;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_beta[i] = TAFloat::NAN;
+        output_sum_a[i] = TAFloat::NAN;
+        output_sum_b[i] = TAFloat::NAN;
+        output_sum_bb[i] = TAFloat::NAN;
+        output_sum_ab[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest Beta value using incremental computation.
+///
+/// # Description
+/// Updates the four window sums in O(1) by removing the pair of returns leaving the window and
+/// adding the newest pair.
+///
+/// # Arguments
+/// * `input_asset` - The newest asset return
+/// * `input_benchmark` - The newest benchmark return
+/// * `input_old_asset` - The asset return leaving the window
+/// * `input_old_benchmark` - The benchmark return leaving the window
+/// * `prev_sum_a` - Previous sum of asset returns
+/// * `prev_sum_b` - Previous sum of benchmark returns
+/// * `prev_sum_bb` - Previous sum of squared benchmark returns
+/// * `prev_sum_ab` - Previous sum of asset * benchmark returns
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing
+///   (beta, `new_sum_a`, `new_sum_b`, `new_sum_bb`, `new_sum_ab`)
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::beta;
+/// let (beta, ..) = beta::beta_inc(
+///     0.03,     // new asset return
+///     0.015,    // new benchmark return
+///     0.02,     // asset return leaving the window
+///     0.01,     // benchmark return leaving the window
+///     -0.01,    // previous sum of asset returns
+///     -0.005,   // previous sum of benchmark returns
+///     0.000525, // previous sum of squared benchmark returns
+///     0.00105,  // previous sum of asset * benchmark returns
+///     3,        // period
+/// )
+/// .unwrap();
+/// assert!((beta - 2.0).abs() < 1e-10);
+/// ```
+pub fn beta_inc(
+    input_asset: TAFloat,
+    input_benchmark: TAFloat,
+    input_old_asset: TAFloat,
+    input_old_benchmark: TAFloat,
+    prev_sum_a: TAFloat,
+    prev_sum_b: TAFloat,
+    prev_sum_bb: TAFloat,
+    prev_sum_ab: TAFloat,
+    param_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    lookback(param_period)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_asset.is_nan()
+            || input_benchmark.is_nan()
+            || input_old_asset.is_nan()
+            || input_old_benchmark.is_nan()
+            || prev_sum_a.is_nan()
+            || prev_sum_b.is_nan()
+            || prev_sum_bb.is_nan()
+            || prev_sum_ab.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let sum_a = prev_sum_a - input_old_asset + input_asset;
+    let sum_b = prev_sum_b - input_old_benchmark + input_benchmark;
+    let sum_bb = input_benchmark.mul_add(
+        input_benchmark,
+        input_old_benchmark.mul_add(-input_old_benchmark, prev_sum_bb),
+    );
+    let sum_ab = input_asset.mul_add(
+        input_benchmark,
+        input_old_asset.mul_add(-input_old_benchmark, prev_sum_ab),
+    );
+    let beta = beta_value(sum_a, sum_b, sum_bb, sum_ab, param_period as TAFloat);
+
+    Ok((beta, sum_a, sum_b, sum_bb, sum_ab))
+}
+
+/// Streaming Beta calculator.
+///
+/// Keeps the last `param_period` pairs of returns together with the window sums required by
+/// [`beta_inc`]. While the window is filling, pairs are added to the sums without removing
+/// anything.
+///
+/// The input tuple is `(asset_return, benchmark_return)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::beta::Beta};
+///
+/// let mut beta = Beta::new(2).unwrap();
+/// assert_eq!(beta.update((0.02, 0.01)).unwrap(), None);
+/// assert!((beta.update((-0.01, -0.02)).unwrap().unwrap() - 1.0).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Beta {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<(TAFloat, TAFloat)>,
+    sums:         (TAFloat, TAFloat, TAFloat, TAFloat),
+}
+
+impl Beta {
+    /// Creates a new streaming Beta.
+    ///
+    /// # Arguments
+    /// * `param_period` - The number of returns in the rolling window (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period + 1),
+            sums: (0.0, 0.0, 0.0, 0.0),
+        })
+    }
+}
+
+impl Indicator for Beta {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(&mut self, input: Self::Input) -> Result<Option<TAFloat>, KandError> {
+        let (input_asset, input_benchmark) = input;
+        self.window.push_back(input);
+        let (old_asset, old_benchmark) = if self.window.len() > self.param_period {
+            self.window.pop_front().unwrap_or_default()
+        } else {
+            (0.0, 0.0)
+        };
+
+        let (sum_a, sum_b, sum_bb, sum_ab) = self.sums;
+        let (output_beta, sum_a, sum_b, sum_bb, sum_ab) = beta_inc(
+            input_asset,
+            input_benchmark,
+            old_asset,
+            old_benchmark,
+            sum_a,
+            sum_b,
+            sum_bb,
+            sum_ab,
+            self.param_period,
+        )?;
+        self.sums = (sum_a, sum_b, sum_bb, sum_ab);
+        Ok(self.is_ready().then_some(output_beta))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sums = (0.0, 0.0, 0.0, 0.0);
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_ASSET: [TAFloat; 12] = [
+        0.012, -0.008, 0.004, 0.015, -0.021, 0.007, 0.003, -0.005, 0.018, -0.002, 0.009, -0.011,
+    ];
+    const INPUT_BENCHMARK: [TAFloat; 12] = [
+        0.008, -0.004, 0.001, 0.009, -0.012, 0.006, -0.001, -0.002, 0.011, 0.001, 0.004, -0.007,
+    ];
+
+    #[test]
+    fn test_beta_calculation() {
+        let len = INPUT_ASSET.len();
+        let mut output_beta = vec![0.0; len];
+        let mut output_sum_a = vec![0.0; len];
+        let mut output_sum_b = vec![0.0; len];
+        let mut output_sum_bb = vec![0.0; len];
+        let mut output_sum_ab = vec![0.0; len];
+
+        beta(
+            &INPUT_ASSET,
+            &INPUT_BENCHMARK,
+            5,
+            &mut output_beta,
+            &mut output_sum_a,
+            &mut output_sum_b,
+            &mut output_sum_bb,
+            &mut output_sum_ab,
+        )
+        .unwrap();
+
+        for value in output_beta.iter().take(4) {
+            assert!(value.is_nan());
+        }
+        let expected_values = [
+            1.697_903_014_416_776_2,
+            1.672_661_870_503_597_4,
+            1.627_871_362_940_275_7,
+            1.639_097_744_360_902_5,
+            1.632_372_214_941_022_2,
+            1.542_372_881_355_932_3,
+            1.641_025_641_025_641,
+            1.690_949_227_373_068_6,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_beta[i + 4], *expected, epsilon = 1e-9);
+        }
+
+        // Streaming calculation matches the batch output
+        let mut stream = Beta::new(5).unwrap();
+        for i in 0..len {
+            match stream.update((INPUT_ASSET[i], INPUT_BENCHMARK[i])).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_beta[i], epsilon = 1e-9),
+                None => assert!(output_beta[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+}
@@ -0,0 +1,613 @@
+use std::collections::VecDeque;
+
+use crate::{
+    Indicator,
+    KandError,
+    TAFloat,
+    stats::drawdown::{self, drawdown_expanding_inc, drawdown_inc},
+};
+
+/// Calculates the lookback period required for rolling Calmar Ratio calculation.
+///
+/// # Description
+/// The return and the maximum drawdown are both taken over the last `param_period` prices, so
+/// the Calmar Ratio shares the lookback of [`drawdown`](super::drawdown).
+///
+/// # Arguments
+/// * `param_period` - The number of prices in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::calmar;
+/// let lookback = calmar::lookback(20).unwrap();
+/// assert_eq!(lookback, 19);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    drawdown::lookback(param_period)
+}
+
+/// Validates the annualization factor shared by all Calmar Ratio entry points.
+fn check_annualization(param_annualization: TAFloat) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_annualization <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(())
+}
+
+/// Compounds the growth from `input_old_price` to `input_price` over `periods` bars to a
+/// yearly rate.
+fn annualized_return(
+    input_price: TAFloat,
+    input_old_price: TAFloat,
+    periods: TAFloat,
+    param_annualization: TAFloat,
+) -> TAFloat {
+    (input_price / input_old_price).powf(param_annualization / periods) - 1.0
+}
+
+/// Excess annualized return per unit of maximum drawdown, or 0 without any drawdown.
+fn ratio(annual_return: TAFloat, max_drawdown: TAFloat, param_risk_free: TAFloat) -> TAFloat {
+    if max_drawdown < 0.0 {
+        (annual_return - param_risk_free) / -max_drawdown
+    } else {
+        0.0
+    }
+}
+
+/// Calculates the rolling Calmar Ratio for a price or equity series.
+///
+/// # Description
+/// The Calmar Ratio relates the annualized return to the maximum drawdown. The return is
+/// compounded over the last `param_period` prices and the maximum drawdown is the deepest
+/// [`drawdown`](super::drawdown) inside that same window.
+///
+/// # Mathematical Formula
+/// ```text
+/// AnnualReturn[t] = (Price[t] / Price[t-n+1]) ^ (Annualization / (n - 1)) - 1
+/// MDD[t] = min(Price[j] / max(Price[t-n+1..=j]) - 1) for j in t-n+1..=t
+/// CALMAR[t] = (AnnualReturn[t] - RiskFree) / |MDD[t]|
+/// ```
+/// Where n is the period. The ratio is 0 when there is no drawdown.
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices or equity values
+/// * `param_period` - The number of prices in the rolling window (must be >= 2)
+/// * `param_annualization` - Periods per year, e.g. 252 for daily bars (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate, e.g. 0.02 for 2%
+/// * `output_calmar` - Array to store Calmar Ratio values
+/// * `output_drawdown` - Array to store drawdown values
+/// * `output_max_drawdown` - Array to store maximum drawdown values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or annualization is not positive
+/// * Returns `KandError::InsufficientData` if input length is not greater than the lookback
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::calmar;
+/// let input_prices = vec![100.0, 120.0, 90.0, 108.0, 110.0];
+/// let mut output_calmar = vec![0.0; 5];
+/// let mut output_drawdown = vec![0.0; 5];
+/// let mut output_max_drawdown = vec![0.0; 5];
+///
+/// calmar::calmar(
+///     &input_prices,
+///     3,
+///     2.0,
+///     0.0,
+///     &mut output_calmar,
+///     &mut output_drawdown,
+///     &mut output_max_drawdown,
+/// )
+/// .unwrap();
+/// // (108 / 120) ^ (2 / 2) - 1 over the 25% drawdown from 120 to 90
+/// assert!((output_calmar[3] + 0.4).abs() < 1e-10);
+/// // No drawdown within [90, 108, 110]
+/// assert_eq!(output_calmar[4], 0.0);
+/// ```
+pub fn calmar(
+    input_prices: &[TAFloat],
+    param_period: usize,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+    output_calmar: &mut [TAFloat],
+    output_drawdown: &mut [TAFloat],
+    output_max_drawdown: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(param_period)?;
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_calmar.len() != len
+            || output_drawdown.len() != len
+            || output_max_drawdown.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    for i in lookback..len {
+        let (calmar, drawdown, max_drawdown) = calmar_inc(
+            &input_prices[i - lookback..=i],
+            param_period,
+            param_annualization,
+            param_risk_free,
+        )?;
+        output_calmar[i] = calmar;
+        output_drawdown[i] = drawdown;
+        output_max_drawdown[i] = max_drawdown;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_calmar[i] = TAFloat::NAN;
+        output_drawdown[i] = TAFloat::NAN;
+        output_max_drawdown[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the Calmar Ratio of the latest window.
+///
+/// # Description
+/// The drawdown values come from [`drawdown_inc`] over the same window; the annualized return
+/// only needs its first and last prices.
+///
+/// # Arguments
+/// * `input_window` - The last `param_period` prices ordered from oldest to newest
+/// * `param_period` - The number of prices in the rolling window (must be >= 2)
+/// * `param_annualization` - Periods per year (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (calmar, drawdown,
+///   `max_drawdown`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or annualization is not positive
+/// * Returns `KandError::LengthMismatch` if `input_window` length != period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::calmar;
+///
+/// let window = [100.0, 120.0, 90.0, 108.0]; // oldest to newest
+/// let (calmar_value, dd, mdd) = calmar::calmar_inc(&window, 4, 3.0, 0.0).unwrap();
+/// // 8% return over the 25% drawdown from 120 to 90
+/// assert!((calmar_value - 0.32).abs() < 1e-10);
+/// assert!((dd + 0.1).abs() < 1e-10);
+/// assert!((mdd + 0.25).abs() < 1e-10);
+/// ```
+pub fn calmar_inc(
+    input_window: &[TAFloat],
+    param_period: usize,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    check_annualization(param_annualization)?;
+    let (drawdown, max_drawdown) = drawdown_inc(input_window, param_period)?;
+
+    let annual_return = annualized_return(
+        input_window[param_period - 1],
+        input_window[0],
+        (param_period - 1) as TAFloat,
+        param_annualization,
+    );
+    Ok((
+        ratio(annual_return, max_drawdown, param_risk_free),
+        drawdown,
+        max_drawdown,
+    ))
+}
+
+/// Calculates the expanding Calmar Ratio for a price or equity series.
+///
+/// # Description
+/// Uses the compound annual growth rate since the first price and the maximum drawdown since
+/// the start of the series. The first value is NaN.
+///
+/// # Mathematical Formula
+/// ```text
+/// CAGR[t] = (Price[t] / Price[0]) ^ (Annualization / t) - 1
+/// CALMAR[t] = (CAGR[t] - RiskFree) / |MDD[t]|
+/// ```
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices or equity values
+/// * `param_annualization` - Periods per year, e.g. 252 for daily bars (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate
+/// * `output_calmar` - Array to store Calmar Ratio values
+/// * `output_drawdown` - Array to store drawdown values
+/// * `output_max_drawdown` - Array to store maximum drawdown values
+/// * `output_peak` - Array to store running peak values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if annualization is not positive
+/// * Returns `KandError::InsufficientData` if there are fewer than two prices
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::calmar;
+/// let input_prices = vec![100.0, 80.0, 121.0];
+/// let mut output_calmar = vec![0.0; 3];
+/// let mut output_drawdown = vec![0.0; 3];
+/// let mut output_max_drawdown = vec![0.0; 3];
+/// let mut output_peak = vec![0.0; 3];
+///
+/// calmar::calmar_expanding(
+///     &input_prices,
+///     1.0,
+///     0.0,
+///     &mut output_calmar,
+///     &mut output_drawdown,
+///     &mut output_max_drawdown,
+///     &mut output_peak,
+/// )
+/// .unwrap();
+/// // 10% growth per period over a 20% drawdown
+/// assert!((output_calmar[2] - 0.5).abs() < 1e-10);
+/// ```
+pub fn calmar_expanding(
+    input_prices: &[TAFloat],
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+    output_calmar: &mut [TAFloat],
+    output_drawdown: &mut [TAFloat],
+    output_max_drawdown: &mut [TAFloat],
+    output_peak: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_calmar.len() != len
+            || output_drawdown.len() != len
+            || output_max_drawdown.len() != len
+            || output_peak.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len < 2 {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let first_price = input_prices[0];
+    let mut peak = first_price;
+    let mut max_drawdown = 0.0;
+    for i in 0..len {
+        let (calmar, drawdown, new_max_drawdown, new_peak) = calmar_expanding_inc(
+            input_prices[i],
+            first_price,
+            i,
+            peak,
+            max_drawdown,
+            param_annualization,
+            param_risk_free,
+        )?;
+        peak = new_peak;
+        max_drawdown = new_max_drawdown;
+
+        output_calmar[i] = calmar;
+        output_drawdown[i] = drawdown;
+        output_max_drawdown[i] = max_drawdown;
+        output_peak[i] = peak;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest expanding Calmar Ratio incrementally.
+///
+/// # Arguments
+/// * `input_price` - The newest price value
+/// * `input_first_price` - The first price of the series
+/// * `param_periods` - Number of bars between the first and the newest price
+/// * `prev_peak` - Previous running peak, or the first price of the series
+/// * `prev_max_drawdown` - Previous maximum drawdown, 0 for the first price
+/// * `param_annualization` - Periods per year (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (calmar, drawdown,
+///   `max_drawdown`, peak), with NaN as the ratio for the first price
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if annualization is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::calmar;
+/// let (calmar_value, dd, mdd, peak) =
+///     calmar::calmar_expanding_inc(121.0, 100.0, 2, 100.0, -0.2, 1.0, 0.0).unwrap();
+/// assert!((calmar_value - 0.5).abs() < 1e-10);
+/// assert_eq!(peak, 121.0);
+/// ```
+pub fn calmar_expanding_inc(
+    input_price: TAFloat,
+    input_first_price: TAFloat,
+    param_periods: usize,
+    prev_peak: TAFloat,
+    prev_max_drawdown: TAFloat,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_first_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let (drawdown, max_drawdown, peak) =
+        drawdown_expanding_inc(input_price, prev_peak, prev_max_drawdown)?;
+    if param_periods == 0 {
+        return Ok((TAFloat::NAN, drawdown, max_drawdown, peak));
+    }
+
+    let annual_return = annualized_return(
+        input_price,
+        input_first_price,
+        param_periods as TAFloat,
+        param_annualization,
+    );
+    Ok((
+        ratio(annual_return, max_drawdown, param_risk_free),
+        drawdown,
+        max_drawdown,
+        peak,
+    ))
+}
+
+/// Streaming rolling Calmar Ratio calculator.
+///
+/// Keeps the last `param_period` prices ordered oldest first, the window [`calmar_inc`] takes.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::calmar::Calmar};
+///
+/// let mut calmar = Calmar::new(3, 2.0, 0.0).unwrap();
+/// assert_eq!(calmar.update(100.0).unwrap(), None);
+/// assert_eq!(calmar.update(80.0).unwrap(), None);
+/// // 10% return over the 20% drawdown from 100 to 80
+/// assert!((calmar.update(110.0).unwrap().unwrap() - 0.5).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Calmar {
+    param_period:        usize,
+    param_annualization: TAFloat,
+    param_risk_free:     TAFloat,
+    lookback:            usize,
+    window:              VecDeque<TAFloat>,
+}
+
+impl Calmar {
+    /// Creates a new streaming rolling Calmar Ratio.
+    ///
+    /// # Arguments
+    /// * `param_period` - The number of prices in the rolling window (must be >= 2)
+    /// * `param_annualization` - Periods per year (must be > 0)
+    /// * `param_risk_free` - Annual risk-free rate
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the period is less than 2 or annualization is not
+    ///   positive
+    pub fn new(
+        param_period: usize,
+        param_annualization: TAFloat,
+        param_risk_free: TAFloat,
+    ) -> Result<Self, KandError> {
+        check_annualization(param_annualization)?;
+        Ok(Self {
+            param_period,
+            param_annualization,
+            param_risk_free,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period + 1),
+        })
+    }
+}
+
+impl Indicator for Calmar {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        self.window.push_back(input_price);
+        if self.window.len() > self.param_period {
+            self.window.pop_front();
+        }
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let (output_calmar, _, _) = calmar_inc(
+            self.window.make_contiguous(),
+            self.param_period,
+            self.param_annualization,
+            self.param_risk_free,
+        )?;
+        Ok(Some(output_calmar))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICE: [TAFloat; 12] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9,
+    ];
+
+    #[test]
+    fn test_calmar_calculation() {
+        let len = INPUT_PRICE.len();
+        let mut output_calmar = vec![0.0; len];
+        let mut output_drawdown = vec![0.0; len];
+        let mut output_max_drawdown = vec![0.0; len];
+
+        calmar(
+            &INPUT_PRICE,
+            3,
+            252.0,
+            0.02,
+            &mut output_calmar,
+            &mut output_drawdown,
+            &mut output_max_drawdown,
+        )
+        .unwrap();
+
+        for value in output_calmar.iter().take(2) {
+            assert!(value.is_nan());
+        }
+        let expected_values = [
+            -122.645_785_337_772_42,
+            -128.875_698_959_587_57,
+            -89.095_470_409_591_37,
+            0.0,
+            40.321_307_915_883_53,
+            -20.148_045_138_020_738,
+            -24.956_377_269_265_875,
+            -122.596_285_327_779_63,
+            -152.383_113_918_567_8,
+            570.213_279_738_365_3,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_calmar[i + 2], *expected, max_relative = 1e-9);
+        }
+
+        // Streaming calculation matches the batch output
+        let mut stream = Calmar::new(3, 252.0, 0.02).unwrap();
+        for (i, price) in INPUT_PRICE.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_calmar[i], max_relative = 1e-9),
+                None => assert!(output_calmar[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_calmar_expanding() {
+        let len = INPUT_PRICE.len();
+        let mut output_calmar = vec![0.0; len];
+        let mut output_drawdown = vec![0.0; len];
+        let mut output_max_drawdown = vec![0.0; len];
+        let mut output_peak = vec![0.0; len];
+
+        calmar_expanding(
+            &INPUT_PRICE,
+            252.0,
+            0.02,
+            &mut output_calmar,
+            &mut output_drawdown,
+            &mut output_max_drawdown,
+            &mut output_peak,
+        )
+        .unwrap();
+
+        assert!(output_calmar[0].is_nan());
+        // No drawdown yet
+        assert_relative_eq!(output_calmar[1], 0.0);
+        let expected_values = [
+            -122.645_785_337_772_42,
+            -85.103_893_101_579_56,
+            -54.853_340_052_680_004,
+            25.089_600_630_416_584,
+            -24.324_225_703_141_813,
+            11.743_951_400_783_006,
+            -22.523_324_280_734_716,
+            -22.528_469_324_966_565,
+            -21.697_873_880_387_323,
+            -4.870_925_725_311_261,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_calmar[i + 2], *expected, max_relative = 1e-9);
+        }
+    }
+}
@@ -0,0 +1,488 @@
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
+
+/// Calculates the lookback period required for Pearson Correlation calculation.
+///
+/// # Description
+/// The lookback period represents the number of data points needed before the first valid output
+/// can be calculated. For CORREL, this equals the specified period minus one.
+///
+/// # Arguments
+/// * `param_period` - The time period used for CORREL calculation (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::correl;
+/// let lookback = correl::lookback(30).unwrap();
+/// assert_eq!(lookback, 29);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period - 1)
+}
+
+/// Pearson correlation from the window sums, 0 if either series is constant.
+fn correl_value(
+    sum_x: TAFloat,
+    sum_y: TAFloat,
+    sum_xx: TAFloat,
+    sum_yy: TAFloat,
+    sum_xy: TAFloat,
+    period: TAFloat,
+) -> TAFloat {
+    let var_x = sum_x.mul_add(-sum_x / period, sum_xx);
+    let var_y = sum_y.mul_add(-sum_y / period, sum_yy);
+    let denominator = (var_x * var_y).sqrt();
+    if denominator > 0.0 {
+        sum_x.mul_add(-sum_y / period, sum_xy) / denominator
+    } else {
+        0.0
+    }
+}
+
+/// Calculates the rolling Pearson Correlation Coefficient (CORREL) of two series.
+///
+/// # Description
+/// CORREL measures how linearly related two series are over a rolling window, ranging from -1
+/// (perfectly inverse) to 1 (moving together). It matches TA-Lib's CORREL and is typically fed
+/// with the prices or returns of an asset and a benchmark.
+///
+/// # Mathematical Formula
+/// ```text
+/// CORREL = (Sxy - Sx * Sy / n) / sqrt((Sxx - Sx^2 / n) * (Syy - Sy^2 / n))
+/// ```
+/// Where Sx, Sy, Sxx, Syy and Sxy are the sums of x, y, x^2, y^2 and x*y over the last n values.
+/// The result is 0 when either series is constant over the window.
+///
+/// # Arguments
+/// * `input_x` - First input series
+/// * `input_y` - Second input series
+/// * `param_period` - The time period for CORREL calculation (must be >= 2)
+/// * `output_correl` - Array to store calculated correlation values
+/// * `output_sum_x` - Array to store running sums of x
+/// * `output_sum_y` - Array to store running sums of y
+/// * `output_sum_xx` - Array to store running sums of x^2
+/// * `output_sum_yy` - Array to store running sums of y^2
+/// * `output_sum_xy` - Array to store running sums of x*y
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input arrays are empty
+/// * Returns `KandError::LengthMismatch` if input or output arrays have different lengths
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::correl;
+/// let input_x = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+/// let input_y = vec![2.0, 4.0, 6.0, 8.0, 10.0];
+/// let mut output_correl = vec![0.0; 5];
+/// let mut output_sum_x = vec![0.0; 5];
+/// let mut output_sum_y = vec![0.0; 5];
+/// let mut output_sum_xx = vec![0.0; 5];
+/// let mut output_sum_yy = vec![0.0; 5];
+/// let mut output_sum_xy = vec![0.0; 5];
+///
+/// correl::correl(
+///     &input_x,
+///     &input_y,
+///     3,
+///     &mut output_correl,
+///     &mut output_sum_x,
+///     &mut output_sum_y,
+///     &mut output_sum_xx,
+///     &mut output_sum_yy,
+///     &mut output_sum_xy,
+/// )
+/// .unwrap();
+/// assert!((output_correl[4] - 1.0).abs() < 1e-10);
+/// ```
+pub fn correl(
+    input_x: &[TAFloat],
+    input_y: &[TAFloat],
+    param_period: usize,
+    output_correl: &mut [TAFloat],
+    output_sum_x: &mut [TAFloat],
+    output_sum_y: &mut [TAFloat],
+    output_sum_xx: &mut [TAFloat],
+    output_sum_yy: &mut [TAFloat],
+    output_sum_xy: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_x.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_y.len() != len
+            || output_correl.len() != len
+            || output_sum_x.len() != len
+            || output_sum_y.len() != len
+            || output_sum_xx.len() != len
+            || output_sum_yy.len() != len
+            || output_sum_xy.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for i in 0..len {
+            if input_x[i].is_nan() || input_y[i].is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    // Calculate initial sums
+    let mut sum_x = 0.0;
+    let mut sum_y = 0.0;
+    let mut sum_xx = 0.0;
+    let mut sum_yy = 0.0;
+    let mut sum_xy = 0.0;
+    for i in 0..param_period {
+        let (x, y) = (input_x[i], input_y[i]);
+        sum_x += x;
+        sum_y += y;
+        sum_xx = x.mul_add(x, sum_xx);
+        sum_yy = y.mul_add(y, sum_yy);
+        sum_xy = x.mul_add(y, sum_xy);
+    }
+
+    let period_t = param_period as TAFloat;
+    output_correl[lookback] = correl_value(sum_x, sum_y, sum_xx, sum_yy, sum_xy, period_t);
+    output_sum_x[lookback] = sum_x;
+    output_sum_y[lookback] = sum_y;
+    output_sum_xx[lookback] = sum_xx;
+    output_sum_yy[lookback] = sum_yy;
+    output_sum_xy[lookback] = sum_xy;
+
+    // Calculate remaining CORREL values incrementally
+    for i in param_period..len {
+        let (correl, new_sum_x, new_sum_y, new_sum_xx, new_sum_yy, new_sum_xy) = correl_inc(
+            input_x[i],
+            input_y[i],
+            input_x[i - param_period],
+            input_y[i - param_period],
+            sum_x,
+            sum_y,
+            sum_xx,
+            sum_yy,
+            sum_xy,
+            param_period,
+        )?;
+        sum_x = new_sum_x;
+        sum_y = new_sum_y;
+        sum_xx = new_sum_xx;
+        sum_yy = new_sum_yy;
+        sum_xy = new_sum_xy;
+
+        output_correl[i] = correl;
+        output_sum_x[i] = sum_x;
+        output_sum_y[i] = sum_y;
+        output_sum_xx[i] = sum_xx;
+        output_sum_yy[i] = sum_yy;
+        output_sum_xy[i] = sum_xy;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_correl[i] = TAFloat::NAN;
+        output_sum_x[i] = TAFloat::NAN;
+        output_sum_y[i] = TAFloat::NAN;
+        output_sum_xx[i] = TAFloat::NAN;
+        output_sum_yy[i] = TAFloat::NAN;
+        output_sum_xy[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest Pearson Correlation value using incremental computation.
+///
+/// # Description
+/// Updates all five window sums in O(1) by removing the pair leaving the window and adding the
+/// newest pair.
+///
+/// # Arguments
+/// * `input_x` - The newest value of the first series
+/// * `input_y` - The newest value of the second series
+/// * `input_old_x` - The value of the first series leaving the window
+/// * `input_old_y` - The value of the second series leaving the window
+/// * `prev_sum_x` - Previous sum of x
+/// * `prev_sum_y` - Previous sum of y
+/// * `prev_sum_xx` - Previous sum of x^2
+/// * `prev_sum_yy` - Previous sum of y^2
+/// * `prev_sum_xy` - Previous sum of x*y
+/// * `param_period` - The time period for CORREL calculation (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple
+///   containing (correl, `new_sum_x`, `new_sum_y`, `new_sum_xx`, `new_sum_yy`, `new_sum_xy`)
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::correl;
+/// // Window [1, 2, 3] / [2, 4, 6] moves to [2, 3, 4] / [4, 6, 8]
+/// let (correl, ..) =
+///     correl::correl_inc(4.0, 8.0, 1.0, 2.0, 6.0, 12.0, 14.0, 56.0, 28.0, 3).unwrap();
+/// assert!((correl - 1.0).abs() < 1e-10);
+/// ```
+pub fn correl_inc(
+    input_x: TAFloat,
+    input_y: TAFloat,
+    input_old_x: TAFloat,
+    input_old_y: TAFloat,
+    prev_sum_x: TAFloat,
+    prev_sum_y: TAFloat,
+    prev_sum_xx: TAFloat,
+    prev_sum_yy: TAFloat,
+    prev_sum_xy: TAFloat,
+    param_period: usize,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    lookback(param_period)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_x.is_nan()
+            || input_y.is_nan()
+            || input_old_x.is_nan()
+            || input_old_y.is_nan()
+            || prev_sum_x.is_nan()
+            || prev_sum_y.is_nan()
+            || prev_sum_xx.is_nan()
+            || prev_sum_yy.is_nan()
+            || prev_sum_xy.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let sum_x = prev_sum_x - input_old_x + input_x;
+    let sum_y = prev_sum_y - input_old_y + input_y;
+    let sum_xx = input_x.mul_add(input_x, input_old_x.mul_add(-input_old_x, prev_sum_xx));
+    let sum_yy = input_y.mul_add(input_y, input_old_y.mul_add(-input_old_y, prev_sum_yy));
+    let sum_xy = input_x.mul_add(input_y, input_old_x.mul_add(-input_old_y, prev_sum_xy));
+    let correl = correl_value(
+        sum_x,
+        sum_y,
+        sum_xx,
+        sum_yy,
+        sum_xy,
+        param_period as TAFloat,
+    );
+
+    Ok((correl, sum_x, sum_y, sum_xx, sum_yy, sum_xy))
+}
+
+/// Streaming Pearson Correlation calculator.
+///
+/// Keeps the last `param_period` pairs together with the five window sums required by
+/// [`correl_inc`]. While the window is filling, pairs are added to the sums without removing
+/// anything.
+///
+/// The input tuple is `(x, y)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::correl::Correl};
+///
+/// let mut correl = Correl::new(3).unwrap();
+/// assert_eq!(correl.update((1.0, 3.0)).unwrap(), None);
+/// assert_eq!(correl.update((2.0, 2.0)).unwrap(), None);
+/// assert!((correl.update((3.0, 1.0)).unwrap().unwrap() + 1.0).abs() < 1e-10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Correl {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<(TAFloat, TAFloat)>,
+    sums:         (TAFloat, TAFloat, TAFloat, TAFloat, TAFloat),
+}
+
+impl Correl {
+    /// Creates a new streaming Pearson Correlation.
+    ///
+    /// # Arguments
+    /// * `param_period` - The window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period + 1),
+            sums: (0.0, 0.0, 0.0, 0.0, 0.0),
+        })
+    }
+}
+
+impl Indicator for Correl {
+    type Input = (TAFloat, TAFloat);
+    type Output = TAFloat;
+
+    fn update(&mut self, input: Self::Input) -> Result<Option<TAFloat>, KandError> {
+        let (input_x, input_y) = input;
+        self.window.push_back(input);
+        let (old_x, old_y) = if self.window.len() > self.param_period {
+            self.window.pop_front().unwrap_or_default()
+        } else {
+            (0.0, 0.0)
+        };
+
+        let (sum_x, sum_y, sum_xx, sum_yy, sum_xy) = self.sums;
+        let (output_correl, sum_x, sum_y, sum_xx, sum_yy, sum_xy) = correl_inc(
+            input_x,
+            input_y,
+            old_x,
+            old_y,
+            sum_x,
+            sum_y,
+            sum_xx,
+            sum_yy,
+            sum_xy,
+            self.param_period,
+        )?;
+        self.sums = (sum_x, sum_y, sum_xx, sum_yy, sum_xy);
+        Ok(self.is_ready().then_some(output_correl))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sums = (0.0, 0.0, 0.0, 0.0, 0.0);
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_X: [TAFloat; 12] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9,
+    ];
+    const INPUT_Y: [TAFloat; 12] = [
+        1890.2, 1891.5, 1889.0, 1887.4, 1888.9, 1893.8, 1892.0, 1892.6, 1890.1, 1890.5, 1888.2,
+        1891.7,
+    ];
+
+    #[test]
+    fn test_correl_calculation() {
+        let len = INPUT_X.len();
+        let mut output_correl = vec![0.0; len];
+        let mut output_sum_x = vec![0.0; len];
+        let mut output_sum_y = vec![0.0; len];
+        let mut output_sum_xx = vec![0.0; len];
+        let mut output_sum_yy = vec![0.0; len];
+        let mut output_sum_xy = vec![0.0; len];
+
+        correl(
+            &INPUT_X,
+            &INPUT_Y,
+            5,
+            &mut output_correl,
+            &mut output_sum_x,
+            &mut output_sum_y,
+            &mut output_sum_xx,
+            &mut output_sum_yy,
+            &mut output_sum_xy,
+        )
+        .unwrap();
+
+        for value in output_correl.iter().take(4) {
+            assert!(value.is_nan());
+        }
+        let expected_values = [
+            0.959_653_393_782_367_2,
+            0.994_799_213_040_531_3,
+            0.936_705_906_289_102_2,
+            0.922_933_389_103_241_6,
+            0.902_681_632_812_618_6,
+            0.883_989_366_150_865_2,
+            0.823_850_620_371_571_6,
+            0.920_181_646_616_926,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_correl[i + 4], *expected, epsilon = 1e-6);
+        }
+
+        // Streaming calculation matches the batch output
+        let mut stream = Correl::new(5).unwrap();
+        for i in 0..len {
+            match stream.update((INPUT_X[i], INPUT_Y[i])).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_correl[i], epsilon = 1e-6),
+                None => assert!(output_correl[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_correl_constant_series() {
+        let input_x = [1.0, 2.0, 3.0, 4.0];
+        let input_y = [5.0, 5.0, 5.0, 5.0];
+        let mut output_correl = vec![0.0; 4];
+        let mut output_sum_x = vec![0.0; 4];
+        let mut output_sum_y = vec![0.0; 4];
+        let mut output_sum_xx = vec![0.0; 4];
+        let mut output_sum_yy = vec![0.0; 4];
+        let mut output_sum_xy = vec![0.0; 4];
+        correl(
+            &input_x,
+            &input_y,
+            3,
+            &mut output_correl,
+            &mut output_sum_x,
+            &mut output_sum_y,
+            &mut output_sum_xx,
+            &mut output_sum_yy,
+            &mut output_sum_xy,
+        )
+        .unwrap();
+        assert_relative_eq!(output_correl[3], 0.0);
+    }
+}
@@ -0,0 +1,544 @@
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
+
+/// Calculates the lookback period required for rolling Drawdown calculation.
+///
+/// # Description
+/// Both the drawdown and the maximum drawdown are measured within the last `param_period`
+/// prices, so the first full window ends at index `param_period - 1`.
+///
+/// # Arguments
+/// * `param_period` - The number of prices in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::drawdown;
+/// let lookback = drawdown::lookback(20).unwrap();
+/// assert_eq!(lookback, 19);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period - 1)
+}
+
+/// Calculates the rolling Drawdown and Maximum Drawdown (MDD) for a price series.
+///
+/// # Description
+/// The drawdown is the relative decline of the price from the highest price of the last
+/// `param_period` prices. The maximum drawdown is the deepest decline inside that same window,
+/// measured against a running peak that starts at the first price of the window, so a peak
+/// from before the window never counts. Both values are zero or negative, e.g. -0.25 for a 25%
+/// decline.
+///
+/// # Mathematical Formula
+/// ```text
+/// Peak[t] = max(Price[t-n+1..=t])
+/// DD[t] = Price[t] / Peak[t] - 1
+/// MDD[t] = min(Price[j] / max(Price[t-n+1..=j]) - 1) for j in t-n+1..=t
+/// ```
+/// Where n is the period.
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices or equity values
+/// * `param_period` - The number of prices in the rolling window (must be >= 2)
+/// * `output_drawdown` - Array to store drawdown values
+/// * `output_max_drawdown` - Array to store maximum drawdown values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2
+/// * Returns `KandError::InsufficientData` if input length is not greater than the lookback
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::drawdown;
+/// let input_prices = vec![100.0, 120.0, 90.0, 108.0, 110.0];
+/// let mut output_drawdown = vec![0.0; 5];
+/// let mut output_max_drawdown = vec![0.0; 5];
+///
+/// drawdown::drawdown(
+///     &input_prices,
+///     3,
+///     &mut output_drawdown,
+///     &mut output_max_drawdown,
+/// )
+/// .unwrap();
+/// assert!((output_drawdown[3] + 0.1).abs() < 1e-10);
+/// assert!((output_max_drawdown[3] + 0.25).abs() < 1e-10);
+/// // The window [90, 108, 110] never falls below its running peak
+/// assert_eq!(output_max_drawdown[4], 0.0);
+/// ```
+pub fn drawdown(
+    input_prices: &[TAFloat],
+    param_period: usize,
+    output_drawdown: &mut [TAFloat],
+    output_max_drawdown: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_drawdown.len() != len || output_max_drawdown.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    for i in lookback..len {
+        let (drawdown, max_drawdown) = drawdown_inc(&input_prices[i - lookback..=i], param_period)?;
+        output_drawdown[i] = drawdown;
+        output_max_drawdown[i] = max_drawdown;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_drawdown[i] = TAFloat::NAN;
+        output_max_drawdown[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the Drawdown and Maximum Drawdown of the latest window.
+///
+/// # Description
+/// Walks the window once from its first price, carrying the running peak, so each call is
+/// O(period). The running peak at the end of the walk is the window's peak for the drawdown.
+///
+/// # Arguments
+/// * `input_window` - The last `param_period` prices ordered from oldest to newest
+/// * `param_period` - The number of prices in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat), KandError>` - Tuple of (drawdown, `max_drawdown`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+/// * Returns `KandError::LengthMismatch` if `input_window` length != period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::drawdown;
+///
+/// let window = [120.0, 90.0, 108.0]; // oldest to newest
+/// let (dd, mdd) = drawdown::drawdown_inc(&window, 3).unwrap();
+/// assert!((dd + 0.1).abs() < 1e-10);
+/// assert!((mdd + 0.25).abs() < 1e-10);
+/// ```
+pub fn drawdown_inc(
+    input_window: &[TAFloat],
+    param_period: usize,
+) -> Result<(TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+        if input_window.len() != param_period {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for price in input_window {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut peak = input_window[0];
+    let mut drawdown = 0.0;
+    let mut max_drawdown: TAFloat = 0.0;
+    for &price in input_window {
+        peak = peak.max(price);
+        drawdown = price / peak - 1.0;
+        max_drawdown = max_drawdown.min(drawdown);
+    }
+
+    Ok((drawdown, max_drawdown))
+}
+
+/// Calculates the expanding Drawdown and Maximum Drawdown for a price series.
+///
+/// # Description
+/// Measures every price against the highest price since the start of the series, and tracks
+/// the deepest of those drawdowns. There is no lookback; the first drawdown is 0.
+///
+/// # Mathematical Formula
+/// ```text
+/// Peak[t] = max(Price[0..=t])
+/// DD[t] = Price[t] / Peak[t] - 1
+/// MDD[t] = min(DD[0..=t])
+/// ```
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices or equity values
+/// * `output_drawdown` - Array to store drawdown values
+/// * `output_max_drawdown` - Array to store maximum drawdown values
+/// * `output_peak` - Array to store running peak values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::drawdown;
+/// let input_prices = vec![100.0, 120.0, 90.0, 130.0];
+/// let mut output_drawdown = vec![0.0; 4];
+/// let mut output_max_drawdown = vec![0.0; 4];
+/// let mut output_peak = vec![0.0; 4];
+///
+/// drawdown::drawdown_expanding(
+///     &input_prices,
+///     &mut output_drawdown,
+///     &mut output_max_drawdown,
+///     &mut output_peak,
+/// )
+/// .unwrap();
+/// assert_eq!(output_drawdown[3], 0.0);
+/// assert!((output_max_drawdown[3] + 0.25).abs() < 1e-10);
+/// ```
+pub fn drawdown_expanding(
+    input_prices: &[TAFloat],
+    output_drawdown: &mut [TAFloat],
+    output_max_drawdown: &mut [TAFloat],
+    output_peak: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_drawdown.len() != len
+            || output_max_drawdown.len() != len
+            || output_peak.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut peak = input_prices[0];
+    let mut max_drawdown: TAFloat = 0.0;
+    for i in 0..len {
+        peak = peak.max(input_prices[i]);
+        let drawdown = input_prices[i] / peak - 1.0;
+        max_drawdown = max_drawdown.min(drawdown);
+
+        output_drawdown[i] = drawdown;
+        output_max_drawdown[i] = max_drawdown;
+        output_peak[i] = peak;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest expanding Drawdown and Maximum Drawdown incrementally.
+///
+/// # Arguments
+/// * `input_price` - The newest price value
+/// * `prev_peak` - Previous running peak, or the first price of the series
+/// * `prev_max_drawdown` - Previous maximum drawdown, 0 for the first price
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple of (drawdown, `max_drawdown`,
+///   peak) on success
+///
+/// # Errors
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::drawdown;
+/// let (dd, mdd, peak) = drawdown::drawdown_expanding_inc(90.0, 120.0, -0.1).unwrap();
+/// assert!((dd + 0.25).abs() < 1e-10);
+/// assert!((mdd + 0.25).abs() < 1e-10);
+/// assert_eq!(peak, 120.0);
+/// ```
+pub fn drawdown_expanding_inc(
+    input_price: TAFloat,
+    prev_peak: TAFloat,
+    prev_max_drawdown: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() || prev_peak.is_nan() || prev_max_drawdown.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let peak = prev_peak.max(input_price);
+    let drawdown = input_price / peak - 1.0;
+    Ok((drawdown, prev_max_drawdown.min(drawdown), peak))
+}
+
+/// Streaming rolling Drawdown calculator.
+///
+/// Keeps the last `param_period` prices ordered oldest first, the layout [`drawdown_inc`]
+/// expects for its window.
+///
+/// The output tuple is `(drawdown, max_drawdown)`.
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::drawdown::Drawdown};
+///
+/// let mut drawdown = Drawdown::new(2).unwrap();
+/// assert_eq!(drawdown.update(100.0).unwrap(), None);
+/// assert_eq!(drawdown.update(75.0).unwrap(), Some((-0.25, -0.25)));
+/// // The 100 peak has left the window
+/// assert_eq!(drawdown.update(90.0).unwrap(), Some((0.0, 0.0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Drawdown {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<TAFloat>,
+}
+
+impl Drawdown {
+    /// Creates a new streaming rolling Drawdown.
+    ///
+    /// # Arguments
+    /// * `param_period` - The number of prices in the rolling window (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period + 1),
+        })
+    }
+}
+
+impl Indicator for Drawdown {
+    type Input = TAFloat;
+    type Output = (TAFloat, TAFloat);
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<Self::Output>, KandError> {
+        self.window.push_back(input_price);
+        if self.window.len() > self.param_period {
+            self.window.pop_front();
+        }
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        drawdown_inc(self.window.make_contiguous(), self.param_period).map(Some)
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICE: [TAFloat; 12] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+        35175.1, 35229.9,
+    ];
+
+    #[test]
+    fn test_drawdown_calculation() {
+        let len = INPUT_PRICE.len();
+        let mut output_drawdown = vec![0.0; len];
+        let mut output_max_drawdown = vec![0.0; len];
+
+        drawdown(
+            &INPUT_PRICE,
+            3,
+            &mut output_drawdown,
+            &mut output_max_drawdown,
+        )
+        .unwrap();
+
+        for i in 0..2 {
+            assert!(output_drawdown[i].is_nan());
+            assert!(output_max_drawdown[i].is_nan());
+        }
+        let expected_drawdown = [
+            -0.000_871_629_179_987_287_7,
+            -0.001_459_340_060_304_237_2,
+            -0.000_261_432_708_073_328_1,
+            0.0,
+            -0.001_469_311_806_118_756,
+            -0.000_076_585_750_511_948_27,
+            -0.001_540_342_506_361_480_6,
+            -0.001_906_280_228_867_185_4,
+            -0.000_639_248_130_554_381_8,
+            0.0,
+        ];
+        let expected_max_drawdown = [
+            -0.000_871_629_179_987_287_7,
+            -0.001_459_340_060_304_237_2,
+            -0.000_588_223_593_165_127,
+            0.0,
+            -0.001_469_311_806_118_756,
+            -0.001_469_311_806_118_756,
+            -0.001_540_342_506_361_480_6,
+            -0.001_906_280_228_867_185_4,
+            -0.000_639_248_130_554_381_8,
+            -0.000_272_845_867_664_073_26,
+        ];
+        for (i, expected) in expected_drawdown.iter().enumerate() {
+            assert_relative_eq!(output_drawdown[i + 2], *expected, epsilon = 1e-12);
+        }
+        for (i, expected) in expected_max_drawdown.iter().enumerate() {
+            assert_relative_eq!(output_max_drawdown[i + 2], *expected, epsilon = 1e-12);
+        }
+
+        // Streaming calculation matches the batch output
+        let mut stream = Drawdown::new(3).unwrap();
+        for (i, price) in INPUT_PRICE.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some((dd, mdd)) => {
+                    assert_relative_eq!(dd, output_drawdown[i], epsilon = 1e-12);
+                    assert_relative_eq!(mdd, output_max_drawdown[i], epsilon = 1e-12);
+                }
+                None => assert!(output_max_drawdown[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_drawdown_matches_brute_force() {
+        let len = INPUT_PRICE.len();
+        for period in 2..=len {
+            let mut output_drawdown = vec![0.0; len];
+            let mut output_max_drawdown = vec![0.0; len];
+            drawdown(
+                &INPUT_PRICE,
+                period,
+                &mut output_drawdown,
+                &mut output_max_drawdown,
+            )
+            .unwrap();
+
+            for end in period - 1..len {
+                // Deepest decline from any earlier price of the window to a later one
+                let window = &INPUT_PRICE[end + 1 - period..=end];
+                let mut expected: TAFloat = 0.0;
+                for from in 0..window.len() {
+                    for to in from..window.len() {
+                        expected = expected.min(window[to] / window[from] - 1.0);
+                    }
+                }
+                assert_relative_eq!(output_max_drawdown[end], expected, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_drawdown_expanding() {
+        let len = INPUT_PRICE.len();
+        let mut output_drawdown = vec![0.0; len];
+        let mut output_max_drawdown = vec![0.0; len];
+        let mut output_peak = vec![0.0; len];
+
+        drawdown_expanding(
+            &INPUT_PRICE,
+            &mut output_drawdown,
+            &mut output_max_drawdown,
+            &mut output_peak,
+        )
+        .unwrap();
+
+        assert_relative_eq!(output_drawdown[0], 0.0);
+        assert_relative_eq!(
+            output_max_drawdown[len - 1],
+            -0.002_255_024_876_186_34,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(output_peak[len - 1], 35254.6);
+
+        let mut prev_peak = INPUT_PRICE[0];
+        let mut prev_max_drawdown = 0.0;
+        for i in 0..len {
+            let (dd, mdd, peak) =
+                drawdown_expanding_inc(INPUT_PRICE[i], prev_peak, prev_max_drawdown).unwrap();
+            assert_relative_eq!(dd, output_drawdown[i], epsilon = 1e-12);
+            assert_relative_eq!(mdd, output_max_drawdown[i], epsilon = 1e-12);
+            assert_relative_eq!(peak, output_peak[i], epsilon = 1e-12);
+            prev_peak = peak;
+            prev_max_drawdown = mdd;
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{KandError, TAFloat, types::PaymentTiming};
+
+/// Returns the lookback period required for Future Value calculation.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns Ok(0) since FV is computed element-wise
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::stats::fv;
+/// let lookback = fv::lookback().unwrap();
+/// assert_eq!(lookback, 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Calculates the Future Value (FV) of an investment for each element of the input slices.
+///
+/// FV is the value at the end of `nper` periods of a present amount `pv` plus a stream of
+/// constant payments `pmt`, compounded at `rate` per period. Cash paid out is negative and
+/// cash received is positive, so depositing money yields a positive future value.
+///
+/// # Mathematical Formula
+/// ```text
+/// rate == 0: FV = -(PV + PMT * NPER)
+/// rate != 0: FV = -(PV * (1 + rate)^NPER + PMT * (1 + rate * when) / rate * ((1 + rate)^NPER - 1))
+/// ```
+/// where `when` is 0 for payments at the end of each period and 1 for payments at the beginning.
+///
+/// # Parameters
+/// * `input_rate` - Interest rate per period
+/// * `input_nper` - Number of compounding periods
+/// * `input_pmt` - Payment made each period
+/// * `input_pv` - Present value
+/// * `param_when` - Whether payments are due at the end or the beginning of each period
+/// * `output_fv` - Array to store the calculated future values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on successful calculation
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{stats::fv, types::PaymentTiming};
+///
+/// // Save 100 a month for 10 years at 5% a year, starting with 100
+/// let input_rate = vec![0.05 / 12.0];
+/// let input_nper = vec![120.0];
+/// let input_pmt = vec![-100.0];
+/// let input_pv = vec![-100.0];
+/// let mut output_fv = vec![0.0; 1];
+///
+/// fv::fv(
+///     &input_rate,
+///     &input_nper,
+///     &input_pmt,
+///     &input_pv,
+///     PaymentTiming::End,
+///     &mut output_fv,
+/// )
+/// .unwrap();
+/// assert!((output_fv[0] - 15692.93).abs() < 0.01);
+/// ```
+pub fn fv(
+    input_rate: &[TAFloat],
+    input_nper: &[TAFloat],
+    input_pmt: &[TAFloat],
+    input_pv: &[TAFloat],
+    param_when: PaymentTiming,
+    output_fv: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_rate.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if len != input_nper.len()
+            || len != input_pmt.len()
+            || len != input_pv.len()
+            || len != output_fv.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    for i in 0..len {
+        output_fv[i] = fv_inc(
+            input_rate[i],
+            input_nper[i],
+            input_pmt[i],
+            input_pv[i],
+            param_when,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates a single Future Value.
+///
+/// # Parameters
+/// * `input_rate` - Interest rate per period
+/// * `input_nper` - Number of compounding periods
+/// * `input_pmt` - Payment made each period
+/// * `input_pv` - Present value
+/// * `param_when` - Whether payments are due at the end or the beginning of each period
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The future value
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{stats::fv, types::PaymentTiming};
+///
+/// let future_value = fv::fv_inc(0.0, 10.0, -100.0, -1000.0, PaymentTiming::End).unwrap();
+/// assert_eq!(future_value, 2000.0);
+/// ```
+pub fn fv_inc(
+    input_rate: TAFloat,
+    input_nper: TAFloat,
+    input_pmt: TAFloat,
+    input_pv: TAFloat,
+    param_when: PaymentTiming,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_rate.is_nan() || input_nper.is_nan() || input_pmt.is_nan() || input_pv.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    if input_rate == 0.0 {
+        return Ok(-input_pmt.mul_add(input_nper, input_pv));
+    }
+
+    let growth = (1.0 + input_rate).powf(input_nper);
+    let annuity = input_pmt * input_rate.mul_add(param_when.factor(), 1.0) / input_rate;
+    Ok(-input_pv.mul_add(growth, annuity * (growth - 1.0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_fv_calculation() {
+        let input_rate = vec![0.05 / 12.0, 0.05 / 12.0, 0.0, 0.02];
+        let input_nper = vec![120.0, 120.0, 10.0, 12.0];
+        let input_pmt = vec![-100.0, -100.0, -100.0, -50.0];
+        let input_pv = vec![-100.0, -100.0, -1000.0, 0.0];
+        let mut output_end = vec![0.0; 4];
+        let mut output_begin = vec![0.0; 4];
+
+        fv(
+            &input_rate,
+            &input_nper,
+            &input_pmt,
+            &input_pv,
+            PaymentTiming::End,
+            &mut output_end,
+        )
+        .unwrap();
+        fv(
+            &input_rate,
+            &input_nper,
+            &input_pmt,
+            &input_pv,
+            PaymentTiming::Begin,
+            &mut output_begin,
+        )
+        .unwrap();
+
+        // Reference values from numpy-financial
+        assert_relative_eq!(output_end[0], 15_692.928_894_335_75, epsilon = 1e-6);
+        assert_relative_eq!(output_begin[0], 15_757.629_844_104_778, epsilon = 1e-6);
+        assert_relative_eq!(output_end[2], 2000.0, epsilon = 1e-9);
+        assert_relative_eq!(output_end[3], 670.604_486_406_363_8, epsilon = 1e-6);
+
+        for i in 0..input_rate.len() {
+            let result = fv_inc(
+                input_rate[i],
+                input_nper[i],
+                input_pmt[i],
+                input_pv[i],
+                PaymentTiming::End,
+            )
+            .unwrap();
+            assert_relative_eq!(result, output_end[i], epsilon = 1e-9);
+        }
+    }
+}
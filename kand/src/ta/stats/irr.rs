@@ -0,0 +1,129 @@
+use super::rate::solve;
+use crate::{KandError, TAFloat};
+
+/// Calculates the Internal Rate of Return (IRR) of a series of periodic cash flows.
+///
+/// IRR is the per-period discount rate at which the net present value of the cash flows is
+/// zero. The first cash flow is at time 0 and is usually the (negative) initial investment.
+/// The rate is solved numerically with Newton's method, falling back to bracketing and bisection
+/// when Newton fails; if several rates qualify, the one closest to `param_guess` is returned.
+///
+/// # Mathematical Formula
+/// ```text
+/// Solve for IRR:
+/// NPV(IRR) = Sum(CF[t] / (1 + IRR)^t, t = 0..n-1) = 0
+/// ```
+///
+/// # Parameters
+/// * `input_cashflows` - Cash flows, one per period, starting at period 0
+/// * `param_guess` - Starting rate for Newton's method (e.g. 0.1)
+/// * `param_tol` - Convergence tolerance (must be > 0, e.g. 1e-12)
+/// * `param_max_iter` - Maximum number of Newton iterations (must be > 0, e.g. 100)
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The internal rate of return per period
+///
+/// # Errors
+/// * `KandError::InvalidData` - If the input array is empty
+/// * `KandError::InvalidParameter` - If `param_tol` <= 0 or `param_max_iter` is 0
+/// * `KandError::InvalidInput` - If the cash flows do not contain both a positive and a negative
+///   value, in which case no IRR exists
+/// * `KandError::CalculationError` - If the solver does not converge
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::irr;
+///
+/// let input_cashflows = vec![-100.0, 39.0, 59.0, 55.0, 20.0];
+/// let result = irr::irr(&input_cashflows, 0.1, 1e-12, 100).unwrap();
+/// assert!((result - 0.2809).abs() < 0.0001);
+/// ```
+pub fn irr(
+    input_cashflows: &[TAFloat],
+    param_guess: TAFloat,
+    param_tol: TAFloat,
+    param_max_iter: usize,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if input_cashflows.is_empty() {
+            return Err(KandError::InvalidData);
+        }
+
+        // Parameter range check
+        if param_tol <= 0.0 || param_max_iter == 0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if param_guess.is_nan() || input_cashflows.iter().any(|cf| cf.is_nan()) {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let has_inflow = input_cashflows.iter().any(|&cf| cf > 0.0);
+    let has_outflow = input_cashflows.iter().any(|&cf| cf < 0.0);
+    if !has_inflow || !has_outflow {
+        return Err(KandError::InvalidInput(
+            "IRR requires at least one positive and one negative cash flow".to_string(),
+        ));
+    }
+
+    let npv = |r: TAFloat| {
+        let discount = 1.0 / (1.0 + r);
+        let mut factor = 1.0;
+        let mut value = 0.0;
+        let mut derivative = 0.0;
+        for (t, &cf) in input_cashflows.iter().enumerate() {
+            // d/dr cf * (1 + r)^-t = -t * cf * (1 + r)^-(t + 1)
+            derivative -= t as TAFloat * cf * factor * discount;
+            value += cf * factor;
+            factor *= discount;
+        }
+        (value, derivative)
+    };
+
+    solve(npv, param_guess, param_tol, param_max_iter).ok_or_else(|| {
+        KandError::CalculationError(format!(
+            "IRR did not converge after {param_max_iter} Newton iterations from \
+             guess={param_guess}, and no sign change of the NPV was found in (-1, 1000] for {} \
+             cash flows",
+            input_cashflows.len()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_irr_calculation() {
+        // Reference values from numpy-financial
+        let result = irr(&[-100.0, 39.0, 59.0, 55.0, 20.0], 0.1, 1e-12, 100).unwrap();
+        assert_relative_eq!(result, 0.280_948_421_159_961, epsilon = 1e-9);
+
+        let result = irr(&[-100.0, 0.0, 0.0, 74.0], 0.1, 1e-12, 100).unwrap();
+        assert_relative_eq!(result, -0.095_495_830_348_972_52, epsilon = 1e-9);
+
+        let result = irr(&[-5000.0, 1000.0, -500.0, 3000.0, 2500.0], 0.1, 1e-12, 100).unwrap();
+        assert_relative_eq!(result, 0.059_828_752_457_274_59, epsilon = 1e-9);
+
+        // Far-off guess with a single Newton step falls back to bracketing
+        let result = irr(&[-100.0, 39.0, 59.0, 55.0, 20.0], 500.0, 1e-12, 1).unwrap();
+        assert_relative_eq!(result, 0.280_948_421_159_961, epsilon = 1e-9);
+
+        // No sign change in the cash flows means no IRR
+        assert!(matches!(
+            irr(&[100.0, 50.0, 25.0], 0.1, 1e-12, 100),
+            Err(KandError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,689 @@
+use std::collections::VecDeque;
+
+use super::winrate;
+use crate::{Indicator, KandError, TAFloat};
+
+/// Calculates the lookback period required for rolling Kelly Criterion calculation.
+///
+/// # Description
+/// The rolling Kelly fraction uses windows of `param_period` trades, so the lookback is the
+/// period minus one.
+///
+/// # Arguments
+/// * `param_period` - The number of trades in the rolling window (must be >= 1)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is 0
+///
+/// # Example
+/// ```
+/// use kand::stats::kelly;
+/// let lookback = kelly::lookback(20).unwrap();
+/// assert_eq!(lookback, 19);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    winrate::lookback(param_period)
+}
+
+/// Validates the Kelly multiplier shared by all Kelly Criterion entry points.
+fn check_fraction(param_fraction: TAFloat) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_fraction <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(())
+}
+
+/// Scaled Kelly fraction from the trade statistics of `count` trades.
+fn fraction(
+    win_rate: TAFloat,
+    avg_win: TAFloat,
+    avg_loss: TAFloat,
+    losses: TAFloat,
+    count: TAFloat,
+    param_fraction: TAFloat,
+) -> TAFloat {
+    if avg_win > 0.0 {
+        (win_rate - losses / count * avg_loss / avg_win) * param_fraction
+    } else {
+        0.0
+    }
+}
+
+/// Calculates the rolling Kelly Criterion (KELLY) for a series of per-trade `PnL`.
+///
+/// # Description
+/// The Kelly Criterion is the fraction of capital to risk per trade that maximizes long-run
+/// growth, given the win rate and the payoff ratio of past trades. Full Kelly is aggressive, so
+/// `param_fraction` scales it down, e.g. 0.5 for half Kelly. A negative value means the window
+/// has no edge.
+///
+/// # Mathematical Formula
+/// ```text
+/// p = Wins / n
+/// q = Losses / n
+/// KELLY = (p - q * AvgLoss / AvgWin) * Fraction
+/// ```
+/// Where n is the number of trades in the window, see [`winrate`](super::winrate) for the
+/// statistics. Breakeven trades count towards n only. KELLY is 0 when there are no winning
+/// trades.
+///
+/// # Arguments
+/// * `input_pnl` - Array of per-trade profit and loss
+/// * `param_period` - The number of trades in the rolling window (must be >= 1)
+/// * `param_fraction` - Multiplier applied to the full Kelly fraction (must be > 0, 1 for full
+///   Kelly)
+/// * `output_kelly` - Array to store Kelly fractions
+/// * `output_wins` - Array to store running counts of winning trades
+/// * `output_losses` - Array to store running counts of losing trades
+/// * `output_gross_profit` - Array to store running sums of winning trades
+/// * `output_gross_loss` - Array to store running absolute sums of losing trades
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is 0 or fraction is not positive
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::kelly;
+/// let input_pnl = vec![120.0, -50.0, 0.0, 80.0];
+/// let mut output_kelly = vec![0.0; 4];
+/// let mut output_wins = vec![0.0; 4];
+/// let mut output_losses = vec![0.0; 4];
+/// let mut output_gross_profit = vec![0.0; 4];
+/// let mut output_gross_loss = vec![0.0; 4];
+///
+/// kelly::kelly(
+///     &input_pnl,
+///     4,
+///     1.0,
+///     &mut output_kelly,
+///     &mut output_wins,
+///     &mut output_losses,
+///     &mut output_gross_profit,
+///     &mut output_gross_loss,
+/// )
+/// .unwrap();
+/// // p = 0.5, q = 0.25, payoff = 100 / 50
+/// assert_eq!(output_kelly[3], 0.375);
+/// ```
+pub fn kelly(
+    input_pnl: &[TAFloat],
+    param_period: usize,
+    param_fraction: TAFloat,
+    output_kelly: &mut [TAFloat],
+    output_wins: &mut [TAFloat],
+    output_losses: &mut [TAFloat],
+    output_gross_profit: &mut [TAFloat],
+    output_gross_loss: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_pnl.len();
+    let lookback = lookback(param_period)?;
+    check_fraction(param_fraction)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_kelly.len() != len
+            || output_wins.len() != len
+            || output_losses.len() != len
+            || output_gross_profit.len() != len
+            || output_gross_loss.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for value in input_pnl {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut sums = (0.0, 0.0, 0.0, 0.0);
+    for i in 0..len {
+        // A zero PnL leaves the sums untouched, so it stands in for trades before the start
+        let old_pnl = if i >= param_period {
+            input_pnl[i - param_period]
+        } else {
+            0.0
+        };
+        let (kelly, wins, losses, gross_profit, gross_loss) = kelly_inc(
+            input_pnl[i],
+            old_pnl,
+            sums.0,
+            sums.1,
+            sums.2,
+            sums.3,
+            param_period,
+            param_fraction,
+        )?;
+        sums = (wins, losses, gross_profit, gross_loss);
+
+        if i < lookback {
+            output_kelly[i] = TAFloat::NAN;
+            output_wins[i] = TAFloat::NAN;
+            output_losses[i] = TAFloat::NAN;
+            output_gross_profit[i] = TAFloat::NAN;
+            output_gross_loss[i] = TAFloat::NAN;
+            continue;
+        }
+
+        output_kelly[i] = kelly;
+        output_wins[i] = wins;
+        output_losses[i] = losses;
+        output_gross_profit[i] = gross_profit;
+        output_gross_loss[i] = gross_loss;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Kelly Criterion value using incremental computation.
+///
+/// # Arguments
+/// * `input_pnl` - `PnL` of the newest trade
+/// * `input_old_pnl` - `PnL` of the trade leaving the window
+/// * `prev_wins` - Previous count of winning trades
+/// * `prev_losses` - Previous count of losing trades
+/// * `prev_gross_profit` - Previous sum of winning trades
+/// * `prev_gross_loss` - Previous absolute sum of losing trades
+/// * `param_period` - The number of trades in the rolling window (must be >= 1)
+/// * `param_fraction` - Multiplier applied to the full Kelly fraction (must be > 0)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing
+///   (kelly, `new_wins`, `new_losses`, `new_gross_profit`, `new_gross_loss`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is 0 or fraction is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::kelly;
+/// let (kelly_value, wins, losses, gross_profit, gross_loss) = kelly::kelly_inc(
+///     -40.0, // newest trade
+///     80.0,  // trade leaving the window
+///     2.0,   // previous wins
+///     0.0,   // previous losses
+///     200.0, // previous gross profit
+///     0.0,   // previous gross loss
+///     2,     // period
+///     1.0,   // full Kelly
+/// )
+/// .unwrap();
+/// // p = 0.5, q = 0.5, payoff = 120 / 40
+/// assert!((kelly_value - 1.0 / 3.0).abs() < 1e-12);
+/// ```
+pub fn kelly_inc(
+    input_pnl: TAFloat,
+    input_old_pnl: TAFloat,
+    prev_wins: TAFloat,
+    prev_losses: TAFloat,
+    prev_gross_profit: TAFloat,
+    prev_gross_loss: TAFloat,
+    param_period: usize,
+    param_fraction: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    check_fraction(param_fraction)?;
+
+    let (win_rate, avg_win, avg_loss, _, _, wins, losses, gross_profit, gross_loss) =
+        winrate::winrate_inc(
+            input_pnl,
+            input_old_pnl,
+            prev_wins,
+            prev_losses,
+            prev_gross_profit,
+            prev_gross_loss,
+            param_period,
+        )?;
+    let kelly = fraction(
+        win_rate,
+        avg_win,
+        avg_loss,
+        losses,
+        param_period as TAFloat,
+        param_fraction,
+    );
+
+    Ok((kelly, wins, losses, gross_profit, gross_loss))
+}
+
+/// Calculates the expanding Kelly Criterion for a series of per-trade `PnL`.
+///
+/// # Description
+/// Uses every trade from the start of the series up to each index, so the last value is the
+/// Kelly fraction of the whole sample.
+///
+/// # Arguments
+/// * `input_pnl` - Array of per-trade profit and loss
+/// * `param_fraction` - Multiplier applied to the full Kelly fraction (must be > 0)
+/// * `output_kelly` - Array to store Kelly fractions
+/// * `output_wins` - Array to store cumulative counts of winning trades
+/// * `output_losses` - Array to store cumulative counts of losing trades
+/// * `output_gross_profit` - Array to store cumulative sums of winning trades
+/// * `output_gross_loss` - Array to store cumulative absolute sums of losing trades
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if fraction is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::kelly;
+/// let input_pnl = vec![120.0, -50.0, 0.0, 80.0];
+/// let mut output_kelly = vec![0.0; 4];
+/// let mut output_wins = vec![0.0; 4];
+/// let mut output_losses = vec![0.0; 4];
+/// let mut output_gross_profit = vec![0.0; 4];
+/// let mut output_gross_loss = vec![0.0; 4];
+///
+/// kelly::kelly_expanding(
+///     &input_pnl,
+///     0.5,
+///     &mut output_kelly,
+///     &mut output_wins,
+///     &mut output_losses,
+///     &mut output_gross_profit,
+///     &mut output_gross_loss,
+/// )
+/// .unwrap();
+/// assert_eq!(output_kelly[3], 0.1875);
+/// ```
+pub fn kelly_expanding(
+    input_pnl: &[TAFloat],
+    param_fraction: TAFloat,
+    output_kelly: &mut [TAFloat],
+    output_wins: &mut [TAFloat],
+    output_losses: &mut [TAFloat],
+    output_gross_profit: &mut [TAFloat],
+    output_gross_loss: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_pnl.len();
+    check_fraction(param_fraction)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_kelly.len() != len
+            || output_wins.len() != len
+            || output_losses.len() != len
+            || output_gross_profit.len() != len
+            || output_gross_loss.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for value in input_pnl {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut sums = (0.0, 0.0, 0.0, 0.0);
+    for i in 0..len {
+        let (kelly, wins, losses, gross_profit, gross_loss) = kelly_expanding_inc(
+            input_pnl[i],
+            sums.0,
+            sums.1,
+            sums.2,
+            sums.3,
+            i,
+            param_fraction,
+        )?;
+        sums = (wins, losses, gross_profit, gross_loss);
+
+        output_kelly[i] = kelly;
+        output_wins[i] = wins;
+        output_losses[i] = losses;
+        output_gross_profit[i] = gross_profit;
+        output_gross_loss[i] = gross_loss;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest expanding Kelly Criterion value using incremental computation.
+///
+/// # Arguments
+/// * `input_pnl` - `PnL` of the newest trade
+/// * `prev_wins` - Previous cumulative count of winning trades
+/// * `prev_losses` - Previous cumulative count of losing trades
+/// * `prev_gross_profit` - Previous cumulative sum of winning trades
+/// * `prev_gross_loss` - Previous cumulative absolute sum of losing trades
+/// * `prev_count` - Number of trades already included in the sums
+/// * `param_fraction` - Multiplier applied to the full Kelly fraction (must be > 0)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing
+///   (kelly, `new_wins`, `new_losses`, `new_gross_profit`, `new_gross_loss`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if fraction is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::kelly;
+/// let (kelly_value, wins, losses, gross_profit, gross_loss) = kelly::kelly_expanding_inc(
+///     80.0,  // newest trade
+///     1.0,   // previous wins
+///     1.0,   // previous losses
+///     120.0, // previous gross profit
+///     50.0,  // previous gross loss
+///     3,     // trades so far
+///     1.0,   // full Kelly
+/// )
+/// .unwrap();
+/// assert_eq!(kelly_value, 0.375);
+/// ```
+pub fn kelly_expanding_inc(
+    input_pnl: TAFloat,
+    prev_wins: TAFloat,
+    prev_losses: TAFloat,
+    prev_gross_profit: TAFloat,
+    prev_gross_loss: TAFloat,
+    prev_count: usize,
+    param_fraction: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError> {
+    check_fraction(param_fraction)?;
+
+    let (win_rate, avg_win, avg_loss, _, _, wins, losses, gross_profit, gross_loss) =
+        winrate::winrate_expanding_inc(
+            input_pnl,
+            prev_wins,
+            prev_losses,
+            prev_gross_profit,
+            prev_gross_loss,
+            prev_count,
+        )?;
+    let kelly = fraction(
+        win_rate,
+        avg_win,
+        avg_loss,
+        losses,
+        (prev_count + 1) as TAFloat,
+        param_fraction,
+    );
+
+    Ok((kelly, wins, losses, gross_profit, gross_loss))
+}
+
+/// Streaming rolling Kelly Criterion calculator.
+///
+/// Keeps the last `param_period` trades together with the running sums required by
+/// [`kelly_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::kelly::Kelly};
+///
+/// let mut kelly = Kelly::new(2, 1.0).unwrap();
+/// assert_eq!(kelly.update(120.0).unwrap(), None);
+/// let full_kelly = kelly.update(-40.0).unwrap().unwrap();
+/// assert!((full_kelly - 1.0 / 3.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Kelly {
+    param_period:   usize,
+    param_fraction: TAFloat,
+    lookback:       usize,
+    window:         VecDeque<TAFloat>,
+    sums:           (TAFloat, TAFloat, TAFloat, TAFloat),
+}
+
+impl Kelly {
+    /// Creates a new streaming rolling Kelly Criterion.
+    ///
+    /// # Arguments
+    /// * `param_period` - The number of trades in the rolling window (must be >= 1)
+    /// * `param_fraction` - Multiplier applied to the full Kelly fraction (must be > 0)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the period is 0 or the fraction is not positive
+    pub fn new(param_period: usize, param_fraction: TAFloat) -> Result<Self, KandError> {
+        check_fraction(param_fraction)?;
+        Ok(Self {
+            param_period,
+            param_fraction,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period),
+            sums: (0.0, 0.0, 0.0, 0.0),
+        })
+    }
+}
+
+impl Indicator for Kelly {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_pnl: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        let old_pnl = if self.window.len() == self.param_period {
+            self.window[0]
+        } else {
+            0.0
+        };
+        let (kelly, wins, losses, gross_profit, gross_loss) = kelly_inc(
+            input_pnl,
+            old_pnl,
+            self.sums.0,
+            self.sums.1,
+            self.sums.2,
+            self.sums.3,
+            self.param_period,
+            self.param_fraction,
+        )?;
+
+        if self.window.len() == self.param_period {
+            self.window.pop_front();
+        }
+        self.window.push_back(input_pnl);
+        self.sums = (wins, losses, gross_profit, gross_loss);
+
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+        Ok(Some(kelly))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sums = (0.0, 0.0, 0.0, 0.0);
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PNL: [TAFloat; 12] = [
+        120.0, -50.0, 0.0, 80.0, -30.0, -70.0, 200.0, 40.0, -10.0, -90.0, 60.0, 0.0,
+    ];
+
+    #[test]
+    fn test_kelly_calculation() {
+        let len = INPUT_PNL.len();
+        let mut output_kelly = vec![0.0; len];
+        let mut output_wins = vec![0.0; len];
+        let mut output_losses = vec![0.0; len];
+        let mut output_gross_profit = vec![0.0; len];
+        let mut output_gross_loss = vec![0.0; len];
+
+        kelly(
+            &INPUT_PNL,
+            5,
+            1.0,
+            &mut output_kelly,
+            &mut output_wins,
+            &mut output_losses,
+            &mut output_gross_profit,
+            &mut output_gross_loss,
+        )
+        .unwrap();
+
+        for value in output_kelly.iter().take(4) {
+            assert!(value.is_nan());
+        }
+        let expected_values = [
+            0.24,
+            -0.175,
+            0.257_142_857_142_857_2,
+            0.4125,
+            0.216_666_666_666_666_73,
+            0.116_666_666_666_666_7,
+            0.4,
+            0.0,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_kelly[i + 4], *expected, epsilon = 1e-9);
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let mut prev_sums = (
+            output_wins[4],
+            output_losses[4],
+            output_gross_profit[4],
+            output_gross_loss[4],
+        );
+        for i in 5..len {
+            let (value, wins, losses, gross_profit, gross_loss) = kelly_inc(
+                INPUT_PNL[i],
+                INPUT_PNL[i - 5],
+                prev_sums.0,
+                prev_sums.1,
+                prev_sums.2,
+                prev_sums.3,
+                5,
+                1.0,
+            )
+            .unwrap();
+            assert_relative_eq!(value, output_kelly[i], epsilon = 1e-9);
+            prev_sums = (wins, losses, gross_profit, gross_loss);
+        }
+
+        let mut stream = Kelly::new(5, 1.0).unwrap();
+        for (i, value) in INPUT_PNL.iter().enumerate() {
+            match stream.update(*value).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_kelly[i], epsilon = 1e-9),
+                None => assert!(output_kelly[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_kelly_expanding() {
+        let len = INPUT_PNL.len();
+        let mut output_kelly = vec![0.0; len];
+        let mut output_wins = vec![0.0; len];
+        let mut output_losses = vec![0.0; len];
+        let mut output_gross_profit = vec![0.0; len];
+        let mut output_gross_loss = vec![0.0; len];
+
+        kelly_expanding(
+            &INPUT_PNL,
+            0.5,
+            &mut output_kelly,
+            &mut output_wins,
+            &mut output_losses,
+            &mut output_gross_profit,
+            &mut output_gross_loss,
+        )
+        .unwrap();
+
+        let expected_values = [
+            0.5,
+            0.145_833_333_333_333_31,
+            0.097_222_222_222_222_22,
+            0.1875,
+            0.12,
+            0.041_666_666_666_666_66,
+            0.133_928_571_428_571_42,
+            0.164_772_727_272_727_3,
+            0.141_414_141_414_141_38,
+            0.086_363_636_363_636_38,
+            0.113_636_363_636_363_63,
+            0.104_166_666_666_666_66,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_kelly[i], *expected, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_kelly_invalid_params() {
+        let input_pnl = [10.0, -5.0];
+        let mut output_kelly = vec![0.0; 2];
+        let mut output_wins = vec![0.0; 2];
+        let mut output_losses = vec![0.0; 2];
+        let mut output_gross_profit = vec![0.0; 2];
+        let mut output_gross_loss = vec![0.0; 2];
+
+        let result = kelly(
+            &input_pnl,
+            2,
+            0.0,
+            &mut output_kelly,
+            &mut output_wins,
+            &mut output_losses,
+            &mut output_gross_profit,
+            &mut output_gross_loss,
+        );
+        assert!(matches!(result, Err(KandError::InvalidParameter)));
+        assert!(matches!(
+            Kelly::new(0, 1.0),
+            Err(KandError::InvalidParameter)
+        ));
+    }
+}
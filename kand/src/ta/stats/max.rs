@@ -1,4 +1,6 @@
-use crate::{EPSILON, KandError, TAFloat};
+use std::collections::VecDeque;
+
+use crate::{EPSILON, Indicator, KandError, TAFloat};
 
 /// Calculates the lookback period required for Maximum Value calculation.
 ///
@@ -130,36 +132,77 @@ pub fn max(
     Ok(())
 }
 
-/// Calculates the latest Maximum Value incrementally using previous results.
+/// Calculates the latest Maximum Value incrementally using a rolling window state.
 ///
-/// This function provides an optimized way to calculate the latest MAX value
-/// by using the previous MAX value and only considering the new and removed prices.
+/// The previous MAX value alone is not enough to update the result once the maximum
+/// leaves the window, so the window is tracked by a [`Max`] monotonic deque that is updated
+/// in place. The result is exact and each update is O(1) amortized.
 ///
 /// # Arguments
 /// * `input_price` - The newest price value to include in calculation
-/// * `prev_max` - The previous period's MAX value
-/// * `input_old_price` - The oldest price value being removed from the period
-/// * `param_period` - The time period for calculation (must be >= 2)
+/// * `rolling_max` - The rolling window state, updated in place
 ///
 /// # Returns
-/// * `Result<TAFloat, KandError>` - The new MAX value on success
+/// * `Result<TAFloat, KandError>` - The MAX of the last `param_period` prices, including
+///   `input_price` (fewer prices while the window is still filling)
 ///
 /// # Errors
-/// * Returns `KandError::InvalidParameter` if period is less than 2
-/// * Returns `KandError::NaNDetected` if any input value is NaN (with "`deep-check`" feature)
+/// * Returns `KandError::NaNDetected` if `input_price` is NaN (with "`deep-check`" feature)
 ///
 /// # Example
 /// ```
+/// use kand::stats::max::{self, Max};
+///
+/// let mut rolling_max = Max::new(3).unwrap();
+/// for price in [3.0, 2.0, 1.5] {
+///     max::max_inc(price, &mut rolling_max).unwrap();
+/// }
+///
+/// // 3.0 has left the window
+/// let new_max = max::max_inc(1.0, &mut rolling_max).unwrap();
+/// assert_eq!(new_max, 2.0);
+/// ```
+pub fn max_inc(input_price: TAFloat, rolling_max: &mut Max) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(rolling_max.push(input_price))
+}
+
+/// Updates a MAX from the previous maximum and the price leaving the window.
+///
+/// This is the scalar form that predates [`Max`]. When the outgoing price was the maximum it
+/// returns `input_price` instead of rescanning the window, so the result is wrong whenever the
+/// new maximum is an older price still in the window.
+///
+/// # Arguments
+/// * `input_price` - The newest price value to include in calculation
+/// * `prev_max` - The previous MAX value
+/// * `input_old_price` - The price leaving the window
+/// * `param_period` - The period for MAX calculation (must be >= 2)
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The approximated MAX value
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// # #![allow(deprecated)]
 /// use kand::stats::max;
-/// let new_price = 10.5;
-/// let prev_max = 11.0;
-/// let old_price = 9.0;
-/// let period = 14;
 ///
-/// let new_max = max::max_inc(new_price, prev_max, old_price, period).unwrap();
+/// let new_max = max::max_inc_legacy(11.0, 10.0, 9.0, 14).unwrap();
 /// assert_eq!(new_max, 11.0);
 /// ```
-pub fn max_inc(
+#[deprecated(note = "inexact once the maximum leaves the window; use `max_inc` with a `Max`")]
+pub fn max_inc_legacy(
     input_price: TAFloat,
     prev_max: TAFloat,
     input_old_price: TAFloat,
@@ -181,18 +224,122 @@ pub fn max_inc(
         }
     }
 
-    // If new price is higher than previous max, it becomes the new max
-    if input_price >= prev_max {
+    if input_price >= prev_max || (prev_max - input_old_price).abs() < EPSILON {
         return Ok(input_price);
     }
+    Ok(prev_max)
+}
+
+/// Rolling Maximum over the last `param_period` values, exact in streaming mode.
+///
+/// Keeps a monotonic deque of `(position, value)` pairs with strictly decreasing values: a new
+/// value evicts every older value that is not greater, and the front is dropped once it falls
+/// out of the window. Every value enters and leaves the deque at most once, so updates are
+/// O(1) amortized and the front is always the maximum of the window. On ties the most recent
+/// position wins, matching [`crate::helper::highest_bars`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::max::Max};
+///
+/// let mut max = Max::new(3).unwrap();
+/// for price in [3.0, 1.0] {
+///     assert_eq!(max.update(price).unwrap(), None);
+/// }
+/// assert_eq!(max.update(2.0).unwrap(), Some(3.0));
+/// assert_eq!(max.update(1.5).unwrap(), Some(2.0));
+/// assert_eq!(max.bars_since(), Some(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Max {
+    param_period: usize,
+    lookback:     usize,
+    count:        usize,
+    deque:        VecDeque<(usize, TAFloat)>,
+}
+
+impl Max {
+    /// Creates an empty rolling Maximum window.
+    ///
+    /// # Arguments
+    /// * `param_period` - The window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            count: 0,
+            deque: VecDeque::with_capacity(param_period),
+        })
+    }
+
+    /// Pushes the newest value into the window.
+    ///
+    /// # Arguments
+    /// * `input_price` - The newest value
+    ///
+    /// # Returns
+    /// * `TAFloat` - The maximum of the last `param_period` values, or of all values pushed so
+    ///   far while the window is still filling
+    pub fn push(&mut self, input_price: TAFloat) -> TAFloat {
+        while let Some(&(_, back)) = self.deque.back() {
+            if back <= input_price {
+                self.deque.pop_back();
+            } else {
+                break;
+            }
+        }
+        self.deque.push_back((self.count, input_price));
+        self.count += 1;
+
+        if let Some(&(position, _)) = self.deque.front() {
+            if position + self.param_period < self.count {
+                self.deque.pop_front();
+            }
+        }
 
-    // If old price being removed was the max, need to recalculate
-    if (prev_max - input_old_price).abs() < EPSILON {
-        return Ok(input_price); // Need full recalculation in this case
+        self.deque[0].1
     }
 
-    // Otherwise keep previous max
-    Ok(prev_max)
+    /// Returns the current maximum, or `None` if no value has been pushed yet.
+    #[must_use]
+    pub fn value(&self) -> Option<TAFloat> {
+        self.deque.front().map(|&(_, value)| value)
+    }
+
+    /// Returns the number of bars between the newest value and the current maximum,
+    /// or `None` if no value has been pushed yet.
+    #[must_use]
+    pub fn bars_since(&self) -> Option<usize> {
+        self.deque
+            .front()
+            .map(|&(position, _)| self.count - 1 - position)
+    }
+}
+
+impl Indicator for Max {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        let output_max = max_inc(input_price, self)?;
+        Ok(self.is_ready().then_some(output_max))
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.deque.clear();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
 }
 
 #[cfg(test)]
@@ -229,19 +376,15 @@ fn test_max_calculation() {
         }
 
         // Now test incremental calculation matches regular calculation
-        let mut prev_max = output_max[13]; // First valid max value
+        let mut rolling_max = Max::new(param_period).unwrap();
+        for price in &input_close[..param_period] {
+            max_inc(*price, &mut rolling_max).unwrap();
+        }
 
         // Test each incremental step
-        for i in 14..19 {
-            let result = max_inc(
-                input_close[i],
-                prev_max,
-                input_close[i - param_period],
-                param_period,
-            )
-            .unwrap();
+        for i in param_period..input_close.len() {
+            let result = max_inc(input_close[i], &mut rolling_max).unwrap();
             assert_relative_eq!(result, output_max[i], epsilon = 0.0001);
-            prev_max = result;
         }
     }
 }
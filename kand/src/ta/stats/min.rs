@@ -1,4 +1,6 @@
-use crate::{EPSILON, KandError, TAFloat};
+use std::collections::VecDeque;
+
+use crate::{EPSILON, Indicator, KandError, TAFloat};
 
 /// Calculates the lookback period required for Minimum Value calculation.
 ///
@@ -136,37 +138,77 @@ pub fn min(
     Ok(())
 }
 
-/// Calculates the latest Minimum Value incrementally using the previous MIN value.
+/// Calculates the latest Minimum Value incrementally using a rolling window state.
+///
+/// The previous MIN value alone is not enough to update the result once the minimum
+/// leaves the window, so the window is tracked by a [`Min`] monotonic deque that is updated
+/// in place. The result is exact and each update is O(1) amortized.
+///
+/// # Arguments
+/// * `input_price` - The newest price value to include in calculation
+/// * `rolling_min` - The rolling window state, updated in place
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The MIN of the last `param_period` prices, including
+///   `input_price` (fewer prices while the window is still filling)
+///
+/// # Errors
+/// * Returns `KandError::NaNDetected` if `input_price` is NaN (with "`deep-check`" feature)
+///
+/// # Example
+/// ```
+/// use kand::stats::min::{self, Min};
+///
+/// let mut rolling_min = Min::new(3).unwrap();
+/// for price in [1.0, 2.0, 2.5] {
+///     min::min_inc(price, &mut rolling_min).unwrap();
+/// }
+///
+/// // 1.0 has left the window
+/// let new_min = min::min_inc(3.0, &mut rolling_min).unwrap();
+/// assert_eq!(new_min, 2.0);
+/// ```
+pub fn min_inc(input_price: TAFloat, rolling_min: &mut Min) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(rolling_min.push(input_price))
+}
+
+/// Updates a MIN from the previous minimum and the price leaving the window.
 ///
-/// This function provides an optimized way to calculate the current MIN value
-/// when you already have the previous MIN value and are adding a new price point.
+/// This is the scalar form that predates [`Min`]. It cannot recover the minimum once the
+/// outgoing price was the minimum and reports `InsufficientData` instead.
 ///
 /// # Arguments
-/// * `input_price` - The new price value to include in calculation
+/// * `input_price` - The newest price value to include in calculation
 /// * `prev_min` - The previous MIN value
-/// * `prev_price` - The price value that will drop out of the period
-/// * `param_period` - The time period for MIN calculation (must be >= 2)
+/// * `prev_price` - The price leaving the window
+/// * `param_period` - The period for MIN calculation (must be >= 2)
 ///
 /// # Returns
-/// * `Result<TAFloat, KandError>` - The new MIN value on success
+/// * `Result<TAFloat, KandError>` - The new MIN value
 ///
 /// # Errors
-/// * Returns `KandError::InvalidParameter` if period is less than 2
-/// * Returns `KandError::NaNDetected` if any input value is NaN (with "`deep-check`" feature)
-/// * Returns `KandError::InsufficientData` if full recalculation is needed
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+/// * Returns `KandError::InsufficientData` if `prev_price` was the minimum
+/// * Returns `KandError::NaNDetected` if any input is NaN (with "`deep-check`" feature)
 ///
 /// # Example
 /// ```
+/// # #![allow(deprecated)]
 /// use kand::stats::min;
-/// let new_price = 15.0;
-/// let prev_min = 12.0;
-/// let dropping_price = 14.0;
-/// let period = 14;
 ///
-/// let new_min = min::min_inc(new_price, prev_min, dropping_price, period).unwrap();
+/// let new_min = min::min_inc_legacy(15.0, 12.0, 14.0, 14).unwrap();
 /// assert_eq!(new_min, 12.0);
 /// ```
-pub fn min_inc(
+#[deprecated(note = "fails once the minimum leaves the window; use `min_inc` with a `Min`")]
+pub fn min_inc_legacy(
     input_price: TAFloat,
     prev_min: TAFloat,
     prev_price: TAFloat,
@@ -188,22 +230,127 @@ pub fn min_inc(
         }
     }
 
-    // If the new price is less than previous min, it becomes the new min
     if input_price < prev_min {
         return Ok(input_price);
     }
-
-    // If the price being removed was the previous min,
-    // we need to scan the period for the new min
     if (prev_price - prev_min).abs() < EPSILON {
-        // In this case we need the full period data to recalculate
         return Err(KandError::InsufficientData);
     }
-
-    // Otherwise the previous min is still valid
     Ok(prev_min)
 }
 
+/// Rolling Minimum over the last `param_period` values, exact in streaming mode.
+///
+/// Keeps a monotonic deque of `(position, value)` pairs with strictly increasing values: a new
+/// value evicts every older value that is not smaller, and the front is dropped once it falls
+/// out of the window. Every value enters and leaves the deque at most once, so updates are
+/// O(1) amortized and the front is always the minimum of the window. On ties the most recent
+/// position wins, matching [`crate::helper::lowest_bars`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::min::Min};
+///
+/// let mut min = Min::new(3).unwrap();
+/// for price in [3.0, 1.0] {
+///     assert_eq!(min.update(price).unwrap(), None);
+/// }
+/// assert_eq!(min.update(2.0).unwrap(), Some(1.0));
+/// assert_eq!(min.update(1.5).unwrap(), Some(1.0));
+/// assert_eq!(min.bars_since(), Some(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Min {
+    param_period: usize,
+    lookback:     usize,
+    count:        usize,
+    deque:        VecDeque<(usize, TAFloat)>,
+}
+
+impl Min {
+    /// Creates an empty rolling Minimum window.
+    ///
+    /// # Arguments
+    /// * `param_period` - The window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            count: 0,
+            deque: VecDeque::with_capacity(param_period),
+        })
+    }
+
+    /// Pushes the newest value into the window.
+    ///
+    /// # Arguments
+    /// * `input_price` - The newest value
+    ///
+    /// # Returns
+    /// * `TAFloat` - The minimum of the last `param_period` values, or of all values pushed so
+    ///   far while the window is still filling
+    pub fn push(&mut self, input_price: TAFloat) -> TAFloat {
+        while let Some(&(_, back)) = self.deque.back() {
+            if back >= input_price {
+                self.deque.pop_back();
+            } else {
+                break;
+            }
+        }
+        self.deque.push_back((self.count, input_price));
+        self.count += 1;
+
+        if let Some(&(position, _)) = self.deque.front() {
+            if position + self.param_period < self.count {
+                self.deque.pop_front();
+            }
+        }
+
+        self.deque[0].1
+    }
+
+    /// Returns the current minimum, or `None` if no value has been pushed yet.
+    #[must_use]
+    pub fn value(&self) -> Option<TAFloat> {
+        self.deque.front().map(|&(_, value)| value)
+    }
+
+    /// Returns the number of bars between the newest value and the current minimum,
+    /// or `None` if no value has been pushed yet.
+    #[must_use]
+    pub fn bars_since(&self) -> Option<usize> {
+        self.deque
+            .front()
+            .map(|&(position, _)| self.count - 1 - position)
+    }
+}
+
+impl Indicator for Min {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        let output_min = min_inc(input_price, self)?;
+        Ok(self.is_ready().then_some(output_min))
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+        self.deque.clear();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.count > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -239,19 +386,15 @@ fn test_min_calculation() {
         }
 
         // Now test incremental calculation matches regular calculation
-        let mut prev_min = output_min[13]; // First valid min value
+        let mut rolling_min = Min::new(param_period).unwrap();
+        for price in &input_close[..param_period] {
+            min_inc(*price, &mut rolling_min).unwrap();
+        }
 
         // Test each incremental step
-        for i in 14..19 {
-            let result = min_inc(
-                input_close[i],
-                prev_min,
-                input_close[i - param_period],
-                param_period,
-            )
-            .unwrap();
+        for i in param_period..input_close.len() {
+            let result = min_inc(input_close[i], &mut rolling_min).unwrap();
             assert_relative_eq!(result, output_min[i], epsilon = 0.0001);
-            prev_min = result;
         }
     }
 }
@@ -1,7 +1,21 @@
-// pub mod beta;
-// pub mod correl;
+pub mod alpha;
+pub mod beta;
+pub mod calmar;
+pub mod correl;
+pub mod drawdown;
+pub mod fv;
+pub mod irr;
+pub mod kelly;
 pub mod max;
 pub mod min;
+pub mod nper;
+pub mod pmt;
+pub mod pv;
+pub mod rate;
+pub mod ret;
+pub mod sharpe;
+pub mod sortino;
 pub mod stddev;
 pub mod sum;
 pub mod var;
+pub mod winrate;
@@ -0,0 +1,228 @@
+use crate::{KandError, TAFloat, types::PaymentTiming};
+
+/// Returns the lookback period required for Number of Periods calculation.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns Ok(0) since NPER is computed element-wise
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::stats::nper;
+/// let lookback = nper::lookback().unwrap();
+/// assert_eq!(lookback, 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Calculates the Number of Periods (NPER) for each element of the input slices.
+///
+/// NPER is the number of constant payments `pmt` at `rate` per period needed to move the
+/// present value `pv` to the future value `fv`, e.g. how many months it takes to pay off a loan.
+/// The result is fractional when the last payment is a partial one.
+///
+/// # Mathematical Formula
+/// ```text
+/// rate == 0: NPER = -(FV + PV) / PMT
+/// rate != 0: z = PMT * (1 + rate * when) / rate
+///            NPER = ln((z - FV) / (z + PV)) / ln(1 + rate)
+/// ```
+/// where `when` is 0 for payments at the end of each period and 1 for payments at the beginning.
+///
+/// # Parameters
+/// * `input_rate` - Interest rate per period
+/// * `input_pmt` - Payment made each period
+/// * `input_pv` - Present value
+/// * `input_fv` - Future value
+/// * `param_when` - Whether payments are due at the end or the beginning of each period
+/// * `output_nper` - Array to store the calculated number of periods
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on successful calculation
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::CalculationError` - If no finite number of periods reaches `fv`, e.g. when
+///   the payment does not even cover the interest
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{stats::nper, types::PaymentTiming};
+///
+/// // Months needed to repay 8,000 at 7% a year with payments of 150
+/// let input_rate = vec![0.07 / 12.0];
+/// let input_pmt = vec![-150.0];
+/// let input_pv = vec![8000.0];
+/// let input_fv = vec![0.0];
+/// let mut output_nper = vec![0.0; 1];
+///
+/// nper::nper(
+///     &input_rate,
+///     &input_pmt,
+///     &input_pv,
+///     &input_fv,
+///     PaymentTiming::End,
+///     &mut output_nper,
+/// )
+/// .unwrap();
+/// assert!((output_nper[0] - 64.07).abs() < 0.01);
+/// ```
+pub fn nper(
+    input_rate: &[TAFloat],
+    input_pmt: &[TAFloat],
+    input_pv: &[TAFloat],
+    input_fv: &[TAFloat],
+    param_when: PaymentTiming,
+    output_nper: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_rate.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if len != input_pmt.len()
+            || len != input_pv.len()
+            || len != input_fv.len()
+            || len != output_nper.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    for i in 0..len {
+        output_nper[i] = nper_inc(
+            input_rate[i],
+            input_pmt[i],
+            input_pv[i],
+            input_fv[i],
+            param_when,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates a single Number of Periods.
+///
+/// # Parameters
+/// * `input_rate` - Interest rate per period
+/// * `input_pmt` - Payment made each period
+/// * `input_pv` - Present value
+/// * `input_fv` - Future value
+/// * `param_when` - Whether payments are due at the end or the beginning of each period
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The number of periods
+///
+/// # Errors
+/// * `KandError::CalculationError` - If no finite number of periods reaches `fv`
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{stats::nper, types::PaymentTiming};
+///
+/// let periods = nper::nper_inc(0.0, -100.0, 1000.0, 0.0, PaymentTiming::End).unwrap();
+/// assert_eq!(periods, 10.0);
+/// ```
+pub fn nper_inc(
+    input_rate: TAFloat,
+    input_pmt: TAFloat,
+    input_pv: TAFloat,
+    input_fv: TAFloat,
+    param_when: PaymentTiming,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_rate.is_nan() || input_pmt.is_nan() || input_pv.is_nan() || input_fv.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let periods = if input_rate == 0.0 {
+        -(input_fv + input_pv) / input_pmt
+    } else {
+        let z = input_pmt * input_rate.mul_add(param_when.factor(), 1.0) / input_rate;
+        ((z - input_fv) / (z + input_pv)).ln() / input_rate.ln_1p()
+    };
+
+    if !periods.is_finite() {
+        return Err(KandError::CalculationError(format!(
+            "NPER has no finite solution for rate={input_rate}, pmt={input_pmt}, pv={input_pv}, \
+             fv={input_fv}"
+        )));
+    }
+
+    Ok(periods)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_nper_calculation() {
+        let input_rate = vec![0.07 / 12.0, 0.0, 0.05];
+        let input_pmt = vec![-150.0, -100.0, -200.0];
+        let input_pv = vec![8000.0, 1000.0, -1000.0];
+        let input_fv = vec![0.0, 0.0, 5000.0];
+        let mut output_end = vec![0.0; 3];
+        let mut output_begin = vec![0.0; 3];
+
+        nper(
+            &input_rate,
+            &input_pmt,
+            &input_pv,
+            &input_fv,
+            PaymentTiming::End,
+            &mut output_end,
+        )
+        .unwrap();
+        nper(
+            &input_rate,
+            &input_pmt,
+            &input_pv,
+            &input_fv,
+            PaymentTiming::Begin,
+            &mut output_begin,
+        )
+        .unwrap();
+
+        // Reference values from numpy-financial
+        assert_relative_eq!(output_end[0], 64.073_348_770_661_85, epsilon = 1e-9);
+        assert_relative_eq!(output_begin[0], 63.623_635_374_352_02, epsilon = 1e-9);
+        assert_relative_eq!(output_end[1], 10.0, epsilon = 1e-9);
+        assert_relative_eq!(output_end[2], 12.047_236_874_648_156, epsilon = 1e-9);
+
+        for i in 0..input_rate.len() {
+            let result = nper_inc(
+                input_rate[i],
+                input_pmt[i],
+                input_pv[i],
+                input_fv[i],
+                PaymentTiming::End,
+            )
+            .unwrap();
+            assert_relative_eq!(result, output_end[i], epsilon = 1e-9);
+        }
+
+        // A payment of 40 never covers the 50 of interest on 1000 at 5%
+        assert!(matches!(
+            nper_inc(0.05, -40.0, 1000.0, 0.0, PaymentTiming::End),
+            Err(KandError::CalculationError(_))
+        ));
+    }
+}
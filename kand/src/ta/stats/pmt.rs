@@ -0,0 +1,226 @@
+use crate::{KandError, TAFloat, types::PaymentTiming};
+
+/// Returns the lookback period required for Payment calculation.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns Ok(0) since PMT is computed element-wise
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::stats::pmt;
+/// let lookback = pmt::lookback().unwrap();
+/// assert_eq!(lookback, 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Calculates the periodic Payment (PMT) for each element of the input slices.
+///
+/// PMT is the constant payment per period that amortizes the present value `pv` into the
+/// future value `fv` over `nper` periods at `rate`, e.g. the monthly installment of a loan.
+/// Cash paid out is negative and cash received is positive.
+///
+/// # Mathematical Formula
+/// ```text
+/// factor = NPER                                               if rate == 0
+///        = (1 + rate * when) * ((1 + rate)^NPER - 1) / rate   otherwise
+/// PMT = -(FV + PV * (1 + rate)^NPER) / factor
+/// ```
+/// where `when` is 0 for payments at the end of each period and 1 for payments at the beginning.
+///
+/// # Parameters
+/// * `input_rate` - Interest rate per period
+/// * `input_nper` - Number of compounding periods
+/// * `input_pv` - Present value
+/// * `input_fv` - Future value
+/// * `param_when` - Whether payments are due at the end or the beginning of each period
+/// * `output_pmt` - Array to store the calculated payments
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on successful calculation
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::CalculationError` - If the annuity factor is zero, e.g. when `nper` is 0
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{stats::pmt, types::PaymentTiming};
+///
+/// // Monthly payment of a 200,000 loan over 15 years at 7.5% a year
+/// let input_rate = vec![0.075 / 12.0];
+/// let input_nper = vec![180.0];
+/// let input_pv = vec![200_000.0];
+/// let input_fv = vec![0.0];
+/// let mut output_pmt = vec![0.0; 1];
+///
+/// pmt::pmt(
+///     &input_rate,
+///     &input_nper,
+///     &input_pv,
+///     &input_fv,
+///     PaymentTiming::End,
+///     &mut output_pmt,
+/// )
+/// .unwrap();
+/// assert!((output_pmt[0] + 1854.02).abs() < 0.01);
+/// ```
+pub fn pmt(
+    input_rate: &[TAFloat],
+    input_nper: &[TAFloat],
+    input_pv: &[TAFloat],
+    input_fv: &[TAFloat],
+    param_when: PaymentTiming,
+    output_pmt: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_rate.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if len != input_nper.len()
+            || len != input_pv.len()
+            || len != input_fv.len()
+            || len != output_pmt.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    for i in 0..len {
+        output_pmt[i] = pmt_inc(
+            input_rate[i],
+            input_nper[i],
+            input_pv[i],
+            input_fv[i],
+            param_when,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates a single periodic Payment.
+///
+/// # Parameters
+/// * `input_rate` - Interest rate per period
+/// * `input_nper` - Number of compounding periods
+/// * `input_pv` - Present value
+/// * `input_fv` - Future value
+/// * `param_when` - Whether payments are due at the end or the beginning of each period
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The payment per period
+///
+/// # Errors
+/// * `KandError::CalculationError` - If the annuity factor is zero, e.g. when `nper` is 0
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{stats::pmt, types::PaymentTiming};
+///
+/// let payment = pmt::pmt_inc(0.0, 12.0, 1200.0, 0.0, PaymentTiming::End).unwrap();
+/// assert_eq!(payment, -100.0);
+/// ```
+pub fn pmt_inc(
+    input_rate: TAFloat,
+    input_nper: TAFloat,
+    input_pv: TAFloat,
+    input_fv: TAFloat,
+    param_when: PaymentTiming,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_rate.is_nan() || input_nper.is_nan() || input_pv.is_nan() || input_fv.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let growth = (1.0 + input_rate).powf(input_nper);
+    let factor = if input_rate == 0.0 {
+        input_nper
+    } else {
+        input_rate.mul_add(param_when.factor(), 1.0) * (growth - 1.0) / input_rate
+    };
+
+    if factor == 0.0 {
+        return Err(KandError::CalculationError(format!(
+            "PMT is undefined: annuity factor is zero for rate={input_rate}, nper={input_nper}"
+        )));
+    }
+
+    Ok(-input_pv.mul_add(growth, input_fv) / factor)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_pmt_calculation() {
+        let input_rate = vec![0.075 / 12.0, 0.0, 0.04];
+        let input_nper = vec![180.0, 12.0, 10.0];
+        let input_pv = vec![200_000.0, 1200.0, 0.0];
+        let input_fv = vec![0.0, 0.0, -10000.0];
+        let mut output_end = vec![0.0; 3];
+        let mut output_begin = vec![0.0; 3];
+
+        pmt(
+            &input_rate,
+            &input_nper,
+            &input_pv,
+            &input_fv,
+            PaymentTiming::End,
+            &mut output_end,
+        )
+        .unwrap();
+        pmt(
+            &input_rate,
+            &input_nper,
+            &input_pv,
+            &input_fv,
+            PaymentTiming::Begin,
+            &mut output_begin,
+        )
+        .unwrap();
+
+        // Reference values from numpy-financial
+        assert_relative_eq!(output_end[0], -1_854.024_720_005_461_9, epsilon = 1e-6);
+        assert_relative_eq!(output_begin[0], -1_842.509_038_514_744_8, epsilon = 1e-6);
+        assert_relative_eq!(output_end[1], -100.0, epsilon = 1e-9);
+        assert_relative_eq!(output_end[2], 832.909_443_301_364_4, epsilon = 1e-6);
+
+        for i in 0..input_rate.len() {
+            let result = pmt_inc(
+                input_rate[i],
+                input_nper[i],
+                input_pv[i],
+                input_fv[i],
+                PaymentTiming::End,
+            )
+            .unwrap();
+            assert_relative_eq!(result, output_end[i], epsilon = 1e-9);
+        }
+
+        // Zero periods leave nothing to amortize over
+        assert!(matches!(
+            pmt_inc(0.05, 0.0, 1000.0, 0.0, PaymentTiming::End),
+            Err(KandError::CalculationError(_))
+        ));
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{KandError, TAFloat, types::PaymentTiming};
+
+/// Returns the lookback period required for Present Value calculation.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns Ok(0) since PV is computed element-wise
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::stats::pv;
+/// let lookback = pv::lookback().unwrap();
+/// assert_eq!(lookback, 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Calculates the Present Value (PV) for each element of the input slices.
+///
+/// PV is the amount today that, together with `nper` constant payments `pmt` compounded at
+/// `rate` per period, is equivalent to the future value `fv`. It is the inverse of FV and uses
+/// the same sign convention: cash paid out is negative, cash received is positive.
+///
+/// # Mathematical Formula
+/// ```text
+/// factor = NPER                                               if rate == 0
+///        = (1 + rate * when) * ((1 + rate)^NPER - 1) / rate   otherwise
+/// PV = -(FV + PMT * factor) / (1 + rate)^NPER
+/// ```
+/// where `when` is 0 for payments at the end of each period and 1 for payments at the beginning.
+///
+/// # Parameters
+/// * `input_rate` - Interest rate per period
+/// * `input_nper` - Number of compounding periods
+/// * `input_pmt` - Payment made each period
+/// * `input_fv` - Future value
+/// * `param_when` - Whether payments are due at the end or the beginning of each period
+/// * `output_pv` - Array to store the calculated present values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on successful calculation
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{stats::pv, types::PaymentTiming};
+///
+/// // Value today of receiving 500 a year for 20 years at 8%
+/// let input_rate = vec![0.08];
+/// let input_nper = vec![20.0];
+/// let input_pmt = vec![500.0];
+/// let input_fv = vec![0.0];
+/// let mut output_pv = vec![0.0; 1];
+///
+/// pv::pv(
+///     &input_rate,
+///     &input_nper,
+///     &input_pmt,
+///     &input_fv,
+///     PaymentTiming::End,
+///     &mut output_pv,
+/// )
+/// .unwrap();
+/// assert!((output_pv[0] + 4909.07).abs() < 0.01);
+/// ```
+pub fn pv(
+    input_rate: &[TAFloat],
+    input_nper: &[TAFloat],
+    input_pmt: &[TAFloat],
+    input_fv: &[TAFloat],
+    param_when: PaymentTiming,
+    output_pv: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_rate.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if len != input_nper.len()
+            || len != input_pmt.len()
+            || len != input_fv.len()
+            || len != output_pv.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    for i in 0..len {
+        output_pv[i] = pv_inc(
+            input_rate[i],
+            input_nper[i],
+            input_pmt[i],
+            input_fv[i],
+            param_when,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates a single Present Value.
+///
+/// # Parameters
+/// * `input_rate` - Interest rate per period
+/// * `input_nper` - Number of compounding periods
+/// * `input_pmt` - Payment made each period
+/// * `input_fv` - Future value
+/// * `param_when` - Whether payments are due at the end or the beginning of each period
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The present value
+///
+/// # Errors
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{stats::pv, types::PaymentTiming};
+///
+/// let present_value = pv::pv_inc(0.0, 10.0, -100.0, 0.0, PaymentTiming::End).unwrap();
+/// assert_eq!(present_value, 1000.0);
+/// ```
+pub fn pv_inc(
+    input_rate: TAFloat,
+    input_nper: TAFloat,
+    input_pmt: TAFloat,
+    input_fv: TAFloat,
+    param_when: PaymentTiming,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_rate.is_nan() || input_nper.is_nan() || input_pmt.is_nan() || input_fv.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let growth = (1.0 + input_rate).powf(input_nper);
+    let factor = if input_rate == 0.0 {
+        input_nper
+    } else {
+        input_rate.mul_add(param_when.factor(), 1.0) * (growth - 1.0) / input_rate
+    };
+    Ok(-input_pmt.mul_add(factor, input_fv) / growth)
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_pv_calculation() {
+        let input_rate = vec![0.05 / 12.0, 0.0, 0.08];
+        let input_nper = vec![120.0, 10.0, 20.0];
+        let input_pmt = vec![-100.0, -100.0, 500.0];
+        let input_fv = vec![15692.93, 0.0, 0.0];
+        let mut output_end = vec![0.0; 3];
+        let mut output_begin = vec![0.0; 3];
+
+        pv(
+            &input_rate,
+            &input_nper,
+            &input_pmt,
+            &input_fv,
+            PaymentTiming::End,
+            &mut output_end,
+        )
+        .unwrap();
+        pv(
+            &input_rate,
+            &input_nper,
+            &input_pmt,
+            &input_fv,
+            PaymentTiming::Begin,
+            &mut output_begin,
+        )
+        .unwrap();
+
+        // Reference values from numpy-financial
+        assert_relative_eq!(output_end[0], -100.000_671_316_258_19, epsilon = 1e-6);
+        assert_relative_eq!(output_begin[0], -60.716_775_346_158_83, epsilon = 1e-6);
+        assert_relative_eq!(output_end[1], 1000.0, epsilon = 1e-9);
+        assert_relative_eq!(output_end[2], -4_909.073_703_724_647, epsilon = 1e-6);
+
+        for i in 0..input_rate.len() {
+            let result = pv_inc(
+                input_rate[i],
+                input_nper[i],
+                input_pmt[i],
+                input_fv[i],
+                PaymentTiming::End,
+            )
+            .unwrap();
+            assert_relative_eq!(result, output_end[i], epsilon = 1e-9);
+        }
+    }
+}
@@ -0,0 +1,420 @@
+use crate::{KandError, TAFloat, types::PaymentTiming};
+
+/// Candidate rates scanned for a sign change when Newton's method fails.
+///
+/// The grid is denser near zero, where realistic per-period rates live, and covers the whole
+/// domain `(-1, 1000]` so that extreme but valid roots are still bracketed.
+const BRACKET_GRID: [TAFloat; 21] = [
+    -0.999, -0.99, -0.9, -0.75, -0.5, -0.25, -0.1, -0.05, -0.01, 0.0, 0.01, 0.05, 0.1, 0.25, 0.5,
+    1.0, 2.5, 5.0, 10.0, 100.0, 1000.0,
+];
+
+/// Maximum number of bisection steps used to refine a bracketed root.
+const BISECTION_MAX_ITER: usize = 200;
+
+/// Finds a root of `f` on the rate domain `(-1, inf)`.
+///
+/// Runs Newton's method from `guess` first. If it produces a non-finite value, leaves the
+/// domain, or does not converge within `max_iter` steps, the function falls back to scanning
+/// [`BRACKET_GRID`] for sign changes and bisecting each bracket. Among the roots found that way,
+/// the one closest to `guess` is returned.
+///
+/// # Parameters
+/// * `f` - Function returning the value and the derivative at a given rate
+/// * `guess` - Starting point for Newton's method
+/// * `tol` - Convergence tolerance on the step size (Newton) or bracket width (bisection)
+/// * `max_iter` - Maximum number of Newton iterations
+///
+/// # Returns
+/// * `Option<TAFloat>` - The root, or `None` if neither method found one
+pub(crate) fn solve<F>(f: F, guess: TAFloat, tol: TAFloat, max_iter: usize) -> Option<TAFloat>
+where F: Fn(TAFloat) -> (TAFloat, TAFloat) {
+    // Newton's method
+    let mut rate = guess;
+    for _ in 0..max_iter {
+        let (value, derivative) = f(rate);
+        if value == 0.0 {
+            return Some(rate);
+        }
+        if !value.is_finite() || !derivative.is_finite() || derivative == 0.0 {
+            break;
+        }
+
+        let next = rate - value / derivative;
+        if !next.is_finite() || next <= -1.0 {
+            break;
+        }
+        if (next - rate).abs() < tol {
+            return Some(next);
+        }
+        rate = next;
+    }
+
+    // Bracketing fallback
+    let mut best: Option<TAFloat> = None;
+    let mut consider = |root: TAFloat| {
+        if best.is_none_or(|b| (root - guess).abs() < (b - guess).abs()) {
+            best = Some(root);
+        }
+    };
+
+    for pair in BRACKET_GRID.windows(2) {
+        let (mut lo, mut hi) = (pair[0], pair[1]);
+        let mut f_lo = f(lo).0;
+        let f_hi = f(hi).0;
+
+        if f_lo == 0.0 {
+            consider(lo);
+            continue;
+        }
+        if !f_lo.is_finite() || !f_hi.is_finite() || f_lo.signum() == f_hi.signum() {
+            continue;
+        }
+
+        for _ in 0..BISECTION_MAX_ITER {
+            let mid = 0.5 * (lo + hi);
+            if hi - lo < tol || mid <= lo || mid >= hi {
+                break;
+            }
+            let f_mid = f(mid).0;
+            if f_mid == 0.0 {
+                (lo, hi) = (mid, mid);
+                break;
+            }
+            if f_mid.signum() == f_lo.signum() {
+                lo = mid;
+                f_lo = f_mid;
+            } else {
+                hi = mid;
+            }
+        }
+        consider(0.5 * (lo + hi));
+    }
+
+    best
+}
+
+/// Returns the lookback period required for Rate calculation.
+///
+/// # Returns
+/// * `Result<usize, KandError>` - Returns Ok(0) since RATE is computed element-wise
+///
+/// # Errors
+/// * This function does not return any errors
+///
+/// # Example
+/// ```
+/// use kand::stats::rate;
+/// let lookback = rate::lookback().unwrap();
+/// assert_eq!(lookback, 0);
+/// ```
+pub const fn lookback() -> Result<usize, KandError> {
+    Ok(0)
+}
+
+/// Calculates the interest Rate per period (RATE) for each element of the input slices.
+///
+/// RATE is the per-period rate at which `nper` constant payments `pmt` move the present value
+/// `pv` to the future value `fv`. It has no closed form and is solved numerically with Newton's
+/// method, falling back to bracketing and bisection when Newton fails.
+///
+/// # Mathematical Formula
+/// ```text
+/// Solve for rate:
+/// PV * (1 + rate)^NPER + PMT * (1 + rate * when) * ((1 + rate)^NPER - 1) / rate + FV = 0
+/// ```
+/// where `when` is 0 for payments at the end of each period and 1 for payments at the beginning.
+///
+/// # Parameters
+/// * `input_nper` - Number of compounding periods
+/// * `input_pmt` - Payment made each period
+/// * `input_pv` - Present value
+/// * `input_fv` - Future value
+/// * `param_when` - Whether payments are due at the end or the beginning of each period
+/// * `param_guess` - Starting rate for Newton's method (e.g. 0.1)
+/// * `param_tol` - Convergence tolerance (must be > 0, e.g. 1e-6)
+/// * `param_max_iter` - Maximum number of Newton iterations (must be > 0, e.g. 100)
+/// * `output_rate` - Array to store the calculated rates
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on successful calculation
+///
+/// # Errors
+/// * `KandError::InvalidData` - If input arrays are empty
+/// * `KandError::LengthMismatch` - If input arrays have different lengths
+/// * `KandError::InvalidParameter` - If `param_tol` <= 0 or `param_max_iter` is 0
+/// * `KandError::CalculationError` - If the solver does not converge for any element
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{stats::rate, types::PaymentTiming};
+///
+/// // Annual rate that grows 3,500 into 10,000 in 10 years
+/// let input_nper = vec![10.0];
+/// let input_pmt = vec![0.0];
+/// let input_pv = vec![-3500.0];
+/// let input_fv = vec![10000.0];
+/// let mut output_rate = vec![0.0; 1];
+///
+/// rate::rate(
+///     &input_nper,
+///     &input_pmt,
+///     &input_pv,
+///     &input_fv,
+///     PaymentTiming::End,
+///     0.1,
+///     1e-6,
+///     100,
+///     &mut output_rate,
+/// )
+/// .unwrap();
+/// assert!((output_rate[0] - 0.1107).abs() < 0.0001);
+/// ```
+pub fn rate(
+    input_nper: &[TAFloat],
+    input_pmt: &[TAFloat],
+    input_pv: &[TAFloat],
+    input_fv: &[TAFloat],
+    param_when: PaymentTiming,
+    param_guess: TAFloat,
+    param_tol: TAFloat,
+    param_max_iter: usize,
+    output_rate: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_nper.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if len != input_pmt.len()
+            || len != input_pv.len()
+            || len != input_fv.len()
+            || len != output_rate.len()
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    for i in 0..len {
+        output_rate[i] = rate_inc(
+            input_nper[i],
+            input_pmt[i],
+            input_pv[i],
+            input_fv[i],
+            param_when,
+            param_guess,
+            param_tol,
+            param_max_iter,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Calculates a single interest Rate per period.
+///
+/// # Parameters
+/// * `input_nper` - Number of compounding periods
+/// * `input_pmt` - Payment made each period
+/// * `input_pv` - Present value
+/// * `input_fv` - Future value
+/// * `param_when` - Whether payments are due at the end or the beginning of each period
+/// * `param_guess` - Starting rate for Newton's method (e.g. 0.1)
+/// * `param_tol` - Convergence tolerance (must be > 0, e.g. 1e-6)
+/// * `param_max_iter` - Maximum number of Newton iterations (must be > 0, e.g. 100)
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The rate per period
+///
+/// # Errors
+/// * `KandError::InvalidParameter` - If `param_tol` <= 0 or `param_max_iter` is 0
+/// * `KandError::CalculationError` - If the solver does not converge
+/// * `KandError::NaNDetected` - If any input value is NaN (when `deep-check` enabled)
+///
+/// # Example
+/// ```
+/// use kand::{stats::rate, types::PaymentTiming};
+///
+/// // Monthly rate of a 200,000 loan repaid with 180 payments of 1,854.02
+/// let monthly_rate = rate::rate_inc(
+///     180.0,
+///     -1854.02,
+///     200_000.0,
+///     0.0,
+///     PaymentTiming::End,
+///     0.1,
+///     1e-6,
+///     100,
+/// )
+/// .unwrap();
+/// assert!((monthly_rate * 12.0 - 0.075).abs() < 1e-6);
+/// ```
+pub fn rate_inc(
+    input_nper: TAFloat,
+    input_pmt: TAFloat,
+    input_pv: TAFloat,
+    input_fv: TAFloat,
+    param_when: PaymentTiming,
+    param_guess: TAFloat,
+    param_tol: TAFloat,
+    param_max_iter: usize,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "check")]
+    {
+        // Parameter range check
+        if param_tol <= 0.0 || param_max_iter == 0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_nper.is_nan()
+            || input_pmt.is_nan()
+            || input_pv.is_nan()
+            || input_fv.is_nan()
+            || param_guess.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let when = param_when.factor();
+    let equation = |r: TAFloat| {
+        if r == 0.0 {
+            // Limits of the annuity terms as rate -> 0
+            let value = input_pmt.mul_add(input_nper, input_pv + input_fv);
+            let derivative = input_nper.mul_add(
+                input_pv,
+                input_pmt * input_nper * (when + 0.5 * (input_nper - 1.0)),
+            );
+            return (value, derivative);
+        }
+
+        // (1 + r)^n - 1 via exp_m1 keeps precision for rates close to zero
+        let growth_m1 = (input_nper * r.ln_1p()).exp_m1();
+        let growth = growth_m1 + 1.0;
+        let annuity = growth_m1 / r;
+        let annuity_prime = (input_nper * growth / (1.0 + r) - annuity) / r;
+        let timing = r.mul_add(when, 1.0);
+
+        let value = input_pv.mul_add(growth, input_pmt * timing * annuity) + input_fv;
+        let derivative = (input_nper * input_pv * growth / (1.0 + r))
+            + input_pmt * when.mul_add(annuity, timing * annuity_prime);
+        (value, derivative)
+    };
+
+    solve(equation, param_guess, param_tol, param_max_iter).ok_or_else(|| {
+        KandError::CalculationError(format!(
+            "RATE did not converge after {param_max_iter} Newton iterations from \
+             guess={param_guess}, and no sign change was found in (-1, 1000] for \
+             nper={input_nper}, pmt={input_pmt}, pv={input_pv}, fv={input_fv}"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_rate_calculation() {
+        let input_nper = vec![10.0, 180.0, 12.0, 60.0];
+        let input_pmt = vec![0.0, -1_854.024_720_005_461_9, -100.0, -300.0];
+        let input_pv = vec![-3500.0, 200_000.0, 1200.0, 15000.0];
+        let input_fv = vec![10000.0, 0.0, 0.0, 0.0];
+        let mut output_rate = vec![0.0; 4];
+
+        rate(
+            &input_nper,
+            &input_pmt,
+            &input_pv,
+            &input_fv,
+            PaymentTiming::End,
+            0.1,
+            1e-10,
+            100,
+            &mut output_rate,
+        )
+        .unwrap();
+
+        // Reference values from numpy-financial
+        assert_relative_eq!(output_rate[0], 0.110_690_853_710_752_82, epsilon = 1e-9);
+        assert_relative_eq!(output_rate[1], 0.075 / 12.0, epsilon = 1e-9);
+        assert_relative_eq!(output_rate[2], 0.0, epsilon = 1e-9);
+
+        let begin = rate_inc(
+            60.0,
+            -300.0,
+            15000.0,
+            0.0,
+            PaymentTiming::Begin,
+            0.1,
+            1e-10,
+            100,
+        )
+        .unwrap();
+        assert_relative_eq!(begin, 0.006_407_985_777_783_516, epsilon = 1e-9);
+
+        for i in 0..input_nper.len() {
+            let result = rate_inc(
+                input_nper[i],
+                input_pmt[i],
+                input_pv[i],
+                input_fv[i],
+                PaymentTiming::End,
+                0.1,
+                1e-10,
+                100,
+            )
+            .unwrap();
+            assert_relative_eq!(result, output_rate[i], epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_rate_bracketing_fallback() {
+        // A single Newton step from a far-off guess is not enough, the bracket search must
+        // still recover the root
+        let result = rate_inc(
+            10.0,
+            0.0,
+            -3500.0,
+            10000.0,
+            PaymentTiming::End,
+            50.0,
+            1e-10,
+            1,
+        )
+        .unwrap();
+        assert_relative_eq!(result, 0.110_690_853_710_752_82, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_rate_no_solution() {
+        // Paying money in and out with nothing coming back has no rate of return
+        let result = rate_inc(
+            10.0,
+            -100.0,
+            -1000.0,
+            -500.0,
+            PaymentTiming::End,
+            0.1,
+            1e-10,
+            100,
+        );
+        match result {
+            Err(KandError::CalculationError(msg)) => assert!(msg.contains("RATE did not converge")),
+            other => panic!("expected CalculationError, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
+
+/// Calculates the lookback period required for Return calculation.
+///
+/// # Description
+/// A return over `param_period` bars compares each price with the price `param_period` bars
+/// earlier, so the lookback equals the period.
+///
+/// # Arguments
+/// * `param_period` - The number of bars the return spans (must be >= 1)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is 0
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let lookback = ret::lookback(5).unwrap();
+/// assert_eq!(lookback, 5);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 1 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period)
+}
+
+/// Calculates rolling simple Returns (RET) for an entire price series.
+///
+/// # Description
+/// The return over a rolling window is the relative change from the price `param_period` bars
+/// ago to the current price. With a period of 1 this yields the per-bar returns consumed by
+/// [`sharpe`](super::sharpe) and [`sortino`](super::sortino).
+///
+/// # Mathematical Formula
+/// ```text
+/// RET[t] = Price[t] / Price[t - n] - 1
+/// ```
+/// Where n is the period.
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices or equity values
+/// * `param_period` - The number of bars the return spans (must be >= 1)
+/// * `output_ret` - Array to store calculated return values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output array doesn't match input length
+/// * Returns `KandError::InvalidParameter` if period is 0
+/// * Returns `KandError::InsufficientData` if input length is not greater than the period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let input_prices = vec![100.0, 110.0, 99.0, 108.9];
+/// let mut output_ret = vec![0.0; 4];
+///
+/// ret::ret(&input_prices, 1, &mut output_ret).unwrap();
+/// assert!(output_ret[0].is_nan());
+/// assert!((output_ret[1] - 0.1).abs() < 1e-10);
+/// assert!((output_ret[2] + 0.1).abs() < 1e-10);
+/// ```
+pub fn ret(
+    input_prices: &[TAFloat],
+    param_period: usize,
+    output_ret: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_ret.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    for i in lookback..len {
+        output_ret[i] = input_prices[i] / input_prices[i - param_period] - 1.0;
+    }
+
+    // Fill initial values with NAN
+    for value in output_ret.iter_mut().take(lookback) {
+        *value = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest Return value.
+///
+/// # Description
+/// Serves both the rolling and the expanding variant: pass the price leaving the window for
+/// [`ret`] or the first price of the series for [`ret_expanding`].
+///
+/// # Arguments
+/// * `input_price` - The newest price value
+/// * `input_old_price` - The reference price the return is measured from
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The return on success
+///
+/// # Errors
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let value = ret::ret_inc(110.0, 100.0).unwrap();
+/// assert!((value - 0.1).abs() < 1e-10);
+/// ```
+pub fn ret_inc(input_price: TAFloat, input_old_price: TAFloat) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_price.is_nan() || input_old_price.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok(input_price / input_old_price - 1.0)
+}
+
+/// Calculates expanding (cumulative) Returns for an entire price series.
+///
+/// # Description
+/// The cumulative return measures the relative change since the first price, so the first
+/// value is always 0 and there is no lookback.
+///
+/// # Mathematical Formula
+/// ```text
+/// RET[t] = Price[t] / Price[0] - 1
+/// ```
+///
+/// # Arguments
+/// * `input_prices` - Array of input prices or equity values
+/// * `output_ret` - Array to store cumulative return values
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output array doesn't match input length
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::ret;
+/// let input_prices = vec![100.0, 110.0, 99.0];
+/// let mut output_ret = vec![0.0; 3];
+///
+/// ret::ret_expanding(&input_prices, &mut output_ret).unwrap();
+/// assert_eq!(output_ret[0], 0.0);
+/// assert!((output_ret[2] + 0.01).abs() < 1e-10);
+/// ```
+pub fn ret_expanding(
+    input_prices: &[TAFloat],
+    output_ret: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_prices.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_ret.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for price in input_prices {
+            if price.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let first_price = input_prices[0];
+    for i in 0..len {
+        output_ret[i] = input_prices[i] / first_price - 1.0;
+    }
+
+    Ok(())
+}
+
+/// Streaming rolling Return calculator.
+///
+/// Keeps the last `param_period + 1` prices and advances them with [`ret_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::ret::Ret};
+///
+/// let mut ret = Ret::new(2).unwrap();
+/// assert_eq!(ret.update(100.0).unwrap(), None);
+/// assert_eq!(ret.update(105.0).unwrap(), None);
+/// assert_eq!(ret.update(125.0).unwrap(), Some(0.25));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ret {
+    lookback: usize,
+    window:   VecDeque<TAFloat>,
+}
+
+impl Ret {
+    /// Creates a new streaming rolling Return.
+    ///
+    /// # Arguments
+    /// * `param_period` - The number of bars the return spans (must be >= 1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is 0
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        let lookback = lookback(param_period)?;
+        Ok(Self {
+            lookback,
+            window: VecDeque::with_capacity(lookback + 1),
+        })
+    }
+}
+
+impl Indicator for Ret {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if self.window.len() > self.lookback {
+            self.window.pop_front();
+        }
+        self.window.push_back(input_price);
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        Ok(Some(ret_inc(input_price, self.window[0])?))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PRICE: [TAFloat; 10] = [
+        35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6, 35184.7,
+    ];
+
+    #[test]
+    fn test_ret_calculation() {
+        let len = INPUT_PRICE.len();
+        let mut output_ret = vec![0.0; len];
+        ret(&INPUT_PRICE, 3, &mut output_ret).unwrap();
+
+        for value in output_ret.iter().take(3) {
+            assert!(value.is_nan());
+        }
+        let expected_values = [
+            -0.001_309_060_344_558_243_8,
+            -0.001_132_834_015_683_648,
+            0.001_815_820_657_162_348,
+            0.000_932_613_022_462_502_9,
+            0.002_001_051_689_098_032_3,
+            -0.001_616_810_288_586_401,
+            -0.000_514_163_646_073_817_5,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_ret[i + 3], *expected, epsilon = 1e-12);
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let mut stream = Ret::new(3).unwrap();
+        for i in 0..len {
+            match stream.update(INPUT_PRICE[i]).unwrap() {
+                Some(value) => {
+                    let inc = ret_inc(INPUT_PRICE[i], INPUT_PRICE[i - 3]).unwrap();
+                    assert_relative_eq!(inc, output_ret[i], epsilon = 1e-12);
+                    assert_relative_eq!(value, output_ret[i], epsilon = 1e-12);
+                }
+                None => assert!(output_ret[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_ret_expanding() {
+        let len = INPUT_PRICE.len();
+        let mut output_ret = vec![0.0; len];
+        ret_expanding(&INPUT_PRICE, &mut output_ret).unwrap();
+
+        assert_relative_eq!(output_ret[0], 0.0);
+        for i in 1..len {
+            let inc = ret_inc(INPUT_PRICE[i], INPUT_PRICE[0]).unwrap();
+            assert_relative_eq!(output_ret[i], inc, epsilon = 1e-12);
+        }
+        assert_relative_eq!(
+            output_ret[len - 1],
+            -0.000_891_637_631_651_454,
+            epsilon = 1e-12
+        );
+    }
+}
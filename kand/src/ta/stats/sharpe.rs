@@ -0,0 +1,687 @@
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
+
+/// Calculates the lookback period required for rolling Sharpe Ratio calculation.
+///
+/// # Description
+/// The rolling Sharpe Ratio uses the sample standard deviation of `param_period` returns, so
+/// the lookback is the period minus one.
+///
+/// # Arguments
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::sharpe;
+/// let lookback = sharpe::lookback(20).unwrap();
+/// assert_eq!(lookback, 19);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period - 1)
+}
+
+/// Validates the annualization factor shared by all Sharpe Ratio entry points.
+fn check_annualization(param_annualization: TAFloat) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_annualization <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(())
+}
+
+/// Annualized Sharpe Ratio from the sum and sum of squares of `count` excess returns.
+fn ratio(sum: TAFloat, sum_sq: TAFloat, count: TAFloat, param_annualization: TAFloat) -> TAFloat {
+    let mean = sum / count;
+    let var = (sum.mul_add(-mean, sum_sq) / (count - 1.0)).max(0.0);
+    let std = var.sqrt();
+    if std > 0.0 {
+        mean / std * param_annualization.sqrt()
+    } else {
+        0.0
+    }
+}
+
+/// Calculates the rolling Sharpe Ratio (SHARPE) for a series of returns.
+///
+/// # Description
+/// The Sharpe Ratio measures the excess return per unit of volatility. Returns are reduced by
+/// the per-period share of the annual risk-free rate and the ratio is annualized with the
+/// square root of the number of periods per year.
+///
+/// # Mathematical Formula
+/// ```text
+/// Excess[t] = Return[t] - RiskFree / Annualization
+/// SHARPE = mean(Excess) / stddev(Excess) * sqrt(Annualization)
+/// ```
+/// Where mean and the sample standard deviation (n - 1) are taken over the last n returns.
+/// The ratio is 0 when the standard deviation is 0.
+///
+/// # Arguments
+/// * `input_returns` - Array of per-period simple returns, e.g. from [`ret`](super::ret)
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+/// * `param_annualization` - Periods per year, e.g. 252 for daily bars (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate, e.g. 0.02 for 2%
+/// * `output_sharpe` - Array to store Sharpe Ratio values
+/// * `output_sum` - Array to store running sums of excess returns
+/// * `output_sum_sq` - Array to store running sums of squared excess returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or annualization is not positive
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sharpe;
+/// let input_returns = vec![0.01, -0.02, 0.015, 0.005, -0.01];
+/// let mut output_sharpe = vec![0.0; 5];
+/// let mut output_sum = vec![0.0; 5];
+/// let mut output_sum_sq = vec![0.0; 5];
+///
+/// sharpe::sharpe(
+///     &input_returns,
+///     3,
+///     252.0,
+///     0.0,
+///     &mut output_sharpe,
+///     &mut output_sum,
+///     &mut output_sum_sq,
+/// )
+/// .unwrap();
+/// assert!(output_sharpe[1].is_nan());
+/// ```
+pub fn sharpe(
+    input_returns: &[TAFloat],
+    param_period: usize,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+    output_sharpe: &mut [TAFloat],
+    output_sum: &mut [TAFloat],
+    output_sum_sq: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_returns.len();
+    let lookback = lookback(param_period)?;
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_sharpe.len() != len || output_sum.len() != len || output_sum_sq.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for value in input_returns {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let risk_free = param_risk_free / param_annualization;
+    let period_t = param_period as TAFloat;
+
+    // Calculate initial sums
+    let mut sum = 0.0;
+    let mut sum_sq = 0.0;
+    for value in input_returns.iter().take(param_period) {
+        let excess = *value - risk_free;
+        sum += excess;
+        sum_sq = excess.mul_add(excess, sum_sq);
+    }
+    output_sharpe[lookback] = ratio(sum, sum_sq, period_t, param_annualization);
+    output_sum[lookback] = sum;
+    output_sum_sq[lookback] = sum_sq;
+
+    // Slide the window over the remaining returns
+    for i in param_period..len {
+        let old_excess = input_returns[i - param_period] - risk_free;
+        let new_excess = input_returns[i] - risk_free;
+        sum = sum - old_excess + new_excess;
+        sum_sq = new_excess.mul_add(new_excess, old_excess.mul_add(-old_excess, sum_sq));
+
+        output_sharpe[i] = ratio(sum, sum_sq, period_t, param_annualization);
+        output_sum[i] = sum;
+        output_sum_sq[i] = sum_sq;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_sharpe[i] = TAFloat::NAN;
+        output_sum[i] = TAFloat::NAN;
+        output_sum_sq[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Sharpe Ratio value using incremental computation.
+///
+/// # Description
+/// Updates the running sums of excess returns by removing the return leaving the window and
+/// adding the newest one.
+///
+/// # Arguments
+/// * `input_return` - The newest return
+/// * `input_old_return` - The return leaving the window
+/// * `prev_sum` - Previous sum of excess returns
+/// * `prev_sum_sq` - Previous sum of squared excess returns
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+/// * `param_annualization` - Periods per year (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (sharpe, `new_sum`, `new_sum_sq`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or annualization is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sharpe;
+/// let (sharpe_value, new_sum, new_sum_sq) = sharpe::sharpe_inc(
+///     0.005,    // new return
+///     0.01,     // return leaving the window
+///     0.005,    // previous sum
+///     0.000625, // previous sum of squares
+///     3,        // period
+///     252.0,    // annualization
+///     0.0,      // risk-free rate
+/// )
+/// .unwrap();
+/// ```
+pub fn sharpe_inc(
+    input_return: TAFloat,
+    input_old_return: TAFloat,
+    prev_sum: TAFloat,
+    prev_sum_sq: TAFloat,
+    param_period: usize,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    lookback(param_period)?;
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_return.is_nan()
+            || input_old_return.is_nan()
+            || prev_sum.is_nan()
+            || prev_sum_sq.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let risk_free = param_risk_free / param_annualization;
+    let old_excess = input_old_return - risk_free;
+    let new_excess = input_return - risk_free;
+    let sum = prev_sum - old_excess + new_excess;
+    let sum_sq = new_excess.mul_add(new_excess, old_excess.mul_add(-old_excess, prev_sum_sq));
+
+    Ok((
+        ratio(sum, sum_sq, param_period as TAFloat, param_annualization),
+        sum,
+        sum_sq,
+    ))
+}
+
+/// Calculates the expanding Sharpe Ratio for a series of returns.
+///
+/// # Description
+/// Uses every return from the start of the series up to each bar. The first value is NaN since
+/// the sample standard deviation needs two returns; the running sums are valid from the first
+/// bar so [`sharpe_expanding_inc`] can continue from any index.
+///
+/// # Arguments
+/// * `input_returns` - Array of per-period simple returns
+/// * `param_annualization` - Periods per year, e.g. 252 for daily bars (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate
+/// * `output_sharpe` - Array to store Sharpe Ratio values
+/// * `output_sum` - Array to store cumulative sums of excess returns
+/// * `output_sum_sq` - Array to store cumulative sums of squared excess returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if annualization is not positive
+/// * Returns `KandError::InsufficientData` if there are fewer than two returns
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sharpe;
+/// let input_returns = vec![0.01, -0.02, 0.015, 0.005];
+/// let mut output_sharpe = vec![0.0; 4];
+/// let mut output_sum = vec![0.0; 4];
+/// let mut output_sum_sq = vec![0.0; 4];
+///
+/// sharpe::sharpe_expanding(
+///     &input_returns,
+///     252.0,
+///     0.0,
+///     &mut output_sharpe,
+///     &mut output_sum,
+///     &mut output_sum_sq,
+/// )
+/// .unwrap();
+/// assert!(output_sharpe[0].is_nan());
+/// ```
+pub fn sharpe_expanding(
+    input_returns: &[TAFloat],
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+    output_sharpe: &mut [TAFloat],
+    output_sum: &mut [TAFloat],
+    output_sum_sq: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_returns.len();
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_sharpe.len() != len || output_sum.len() != len || output_sum_sq.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len < 2 {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for value in input_returns {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let risk_free = param_risk_free / param_annualization;
+    let mut sum = 0.0;
+    let mut sum_sq = 0.0;
+    for i in 0..len {
+        let excess = input_returns[i] - risk_free;
+        sum += excess;
+        sum_sq = excess.mul_add(excess, sum_sq);
+
+        output_sharpe[i] = if i == 0 {
+            TAFloat::NAN
+        } else {
+            ratio(sum, sum_sq, (i + 1) as TAFloat, param_annualization)
+        };
+        output_sum[i] = sum;
+        output_sum_sq[i] = sum_sq;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest expanding Sharpe Ratio value using incremental computation.
+///
+/// # Arguments
+/// * `input_return` - The newest return
+/// * `prev_sum` - Previous cumulative sum of excess returns
+/// * `prev_sum_sq` - Previous cumulative sum of squared excess returns
+/// * `prev_count` - Number of returns already included in the sums
+/// * `param_annualization` - Periods per year (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (sharpe, `new_sum`, `new_sum_sq`),
+///   with NaN as the ratio while fewer than two returns are available
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if annualization is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sharpe;
+/// let (sharpe_value, new_sum, new_sum_sq) = sharpe::sharpe_expanding_inc(
+///     0.005,  // new return
+///     -0.01,  // previous sum
+///     0.0005, // previous sum of squares
+///     2,      // returns so far
+///     252.0,  // annualization
+///     0.0,    // risk-free rate
+/// )
+/// .unwrap();
+/// ```
+pub fn sharpe_expanding_inc(
+    input_return: TAFloat,
+    prev_sum: TAFloat,
+    prev_sum_sq: TAFloat,
+    prev_count: usize,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_return.is_nan() || prev_sum.is_nan() || prev_sum_sq.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let excess = input_return - param_risk_free / param_annualization;
+    let sum = prev_sum + excess;
+    let sum_sq = excess.mul_add(excess, prev_sum_sq);
+    let sharpe = if prev_count == 0 {
+        TAFloat::NAN
+    } else {
+        ratio(
+            sum,
+            sum_sq,
+            (prev_count + 1) as TAFloat,
+            param_annualization,
+        )
+    };
+
+    Ok((sharpe, sum, sum_sq))
+}
+
+/// Streaming rolling Sharpe Ratio calculator.
+///
+/// Keeps the last `param_period` returns together with the running sums required by
+/// [`sharpe_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::sharpe::Sharpe};
+///
+/// let mut sharpe = Sharpe::new(2, 252.0, 0.0).unwrap();
+/// assert_eq!(sharpe.update(0.01).unwrap(), None);
+/// assert!(sharpe.update(0.03).unwrap().unwrap() > 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sharpe {
+    param_period:        usize,
+    param_annualization: TAFloat,
+    param_risk_free:     TAFloat,
+    lookback:            usize,
+    window:              VecDeque<TAFloat>,
+    prev_sums:           Option<(TAFloat, TAFloat)>,
+}
+
+impl Sharpe {
+    /// Creates a new streaming rolling Sharpe Ratio.
+    ///
+    /// # Arguments
+    /// * `param_period` - The number of returns in the rolling window (must be >= 2)
+    /// * `param_annualization` - Periods per year (must be > 0)
+    /// * `param_risk_free` - Annual risk-free rate
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the period is less than 2 or annualization is not
+    ///   positive
+    pub fn new(
+        param_period: usize,
+        param_annualization: TAFloat,
+        param_risk_free: TAFloat,
+    ) -> Result<Self, KandError> {
+        check_annualization(param_annualization)?;
+        Ok(Self {
+            param_period,
+            param_annualization,
+            param_risk_free,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period),
+            prev_sums: None,
+        })
+    }
+}
+
+impl Indicator for Sharpe {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_return: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if let Some((prev_sum, prev_sum_sq)) = self.prev_sums {
+            let (output_sharpe, output_sum, output_sum_sq) = sharpe_inc(
+                input_return,
+                self.window[0],
+                prev_sum,
+                prev_sum_sq,
+                self.param_period,
+                self.param_annualization,
+                self.param_risk_free,
+            )?;
+            self.window.pop_front();
+            self.window.push_back(input_return);
+            self.prev_sums = Some((output_sum, output_sum_sq));
+            return Ok(Some(output_sharpe));
+        }
+
+        self.window.push_back(input_return);
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let window = self.window.make_contiguous();
+        let len = window.len();
+        let mut output_sharpe = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_sq = vec![0.0; len];
+        sharpe(
+            window,
+            self.param_period,
+            self.param_annualization,
+            self.param_risk_free,
+            &mut output_sharpe,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )?;
+        self.prev_sums = Some((output_sum[self.lookback], output_sum_sq[self.lookback]));
+        Ok(Some(output_sharpe[self.lookback]))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.prev_sums = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_sums.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_RETURNS: [TAFloat; 12] = [
+        0.012, -0.008, 0.004, 0.015, -0.021, 0.007, 0.003, -0.005, 0.018, -0.002, 0.009, -0.011,
+    ];
+
+    #[test]
+    fn test_sharpe_calculation() {
+        let len = INPUT_RETURNS.len();
+        let mut output_sharpe = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_sq = vec![0.0; len];
+
+        sharpe(
+            &INPUT_RETURNS,
+            5,
+            252.0,
+            0.02,
+            &mut output_sharpe,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+        .unwrap();
+
+        for value in output_sharpe.iter().take(4) {
+            assert!(value.is_nan());
+        }
+        let expected_values = [
+            0.341_382_582_184_943_8,
+            -0.765_847_206_996_575_9,
+            1.790_310_218_872_175_6,
+            -0.324_130_177_131_100_5,
+            0.349_742_260_336_462_3,
+            7.281_613_807_935_845,
+            7.816_024_617_762_672,
+            2.353_451_242_580_756_6,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_sharpe[i + 4], *expected, epsilon = 1e-9);
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let mut prev_sum = output_sum[4];
+        let mut prev_sum_sq = output_sum_sq[4];
+        for i in 5..len {
+            let (value, sum, sum_sq) = sharpe_inc(
+                INPUT_RETURNS[i],
+                INPUT_RETURNS[i - 5],
+                prev_sum,
+                prev_sum_sq,
+                5,
+                252.0,
+                0.02,
+            )
+            .unwrap();
+            assert_relative_eq!(value, output_sharpe[i], epsilon = 1e-9);
+            prev_sum = sum;
+            prev_sum_sq = sum_sq;
+        }
+
+        let mut stream = Sharpe::new(5, 252.0, 0.02).unwrap();
+        for (i, value) in INPUT_RETURNS.iter().enumerate() {
+            match stream.update(*value).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_sharpe[i], epsilon = 1e-9),
+                None => assert!(output_sharpe[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_sharpe_expanding() {
+        let len = INPUT_RETURNS.len();
+        let mut output_sharpe = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_sq = vec![0.0; len];
+
+        sharpe_expanding(
+            &INPUT_RETURNS,
+            252.0,
+            0.02,
+            &mut output_sharpe,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+        .unwrap();
+
+        assert!(output_sharpe[0].is_nan());
+        let expected_values = [
+            2.155_907_351_426_889,
+            4.080_103_320_722_333,
+            8.760_608_525_418_396,
+            0.341_382_582_184_943_8,
+            1.657_599_270_278_618_4,
+            2.087_528_124_399_319_6,
+            1.074_677_016_700_742_2,
+            3.458_064_247_182_739,
+            2.993_463_717_078_809,
+            3.956_703_307_537_758_5,
+            2.297_069_606_207_333_2,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_sharpe[i + 1], *expected, epsilon = 1e-9);
+        }
+
+        let mut prev_sum = 0.0;
+        let mut prev_sum_sq = 0.0;
+        for i in 0..len {
+            let (value, sum, sum_sq) =
+                sharpe_expanding_inc(INPUT_RETURNS[i], prev_sum, prev_sum_sq, i, 252.0, 0.02)
+                    .unwrap();
+            if i == 0 {
+                assert!(value.is_nan());
+            } else {
+                assert_relative_eq!(value, output_sharpe[i], epsilon = 1e-9);
+            }
+            assert_relative_eq!(sum, output_sum[i], epsilon = 1e-12);
+            prev_sum = sum;
+            prev_sum_sq = sum_sq;
+        }
+    }
+
+    #[test]
+    fn test_sharpe_invalid_params() {
+        let mut output_sharpe = vec![0.0; 12];
+        let mut output_sum = vec![0.0; 12];
+        let mut output_sum_sq = vec![0.0; 12];
+        assert!(matches!(
+            sharpe(
+                &INPUT_RETURNS,
+                5,
+                0.0,
+                0.02,
+                &mut output_sharpe,
+                &mut output_sum,
+                &mut output_sum_sq,
+            ),
+            Err(KandError::InvalidParameter)
+        ));
+        assert!(matches!(lookback(1), Err(KandError::InvalidParameter)));
+    }
+}
@@ -0,0 +1,705 @@
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
+
+/// Calculates the lookback period required for rolling Sortino Ratio calculation.
+///
+/// # Description
+/// The rolling Sortino Ratio uses windows of `param_period` returns, so the lookback is the
+/// period minus one.
+///
+/// # Arguments
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is less than 2
+///
+/// # Example
+/// ```
+/// use kand::stats::sortino;
+/// let lookback = sortino::lookback(20).unwrap();
+/// assert_eq!(lookback, 19);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period < 2 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period - 1)
+}
+
+/// Validates the annualization factor shared by all Sortino Ratio entry points.
+fn check_annualization(param_annualization: TAFloat) -> Result<(), KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_annualization <= 0.0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(())
+}
+
+/// Squared shortfall of an excess return below zero.
+fn downside_sq(excess: TAFloat) -> TAFloat {
+    let downside = excess.min(0.0);
+    downside * downside
+}
+
+/// Annualized Sortino Ratio from the sums of `count` excess returns and squared shortfalls.
+fn ratio(
+    sum: TAFloat,
+    sum_down_sq: TAFloat,
+    count: TAFloat,
+    param_annualization: TAFloat,
+) -> TAFloat {
+    let downside_dev = (sum_down_sq.max(0.0) / count).sqrt();
+    if downside_dev > 0.0 {
+        sum / count / downside_dev * param_annualization.sqrt()
+    } else {
+        0.0
+    }
+}
+
+/// Calculates the rolling Sortino Ratio (SORTINO) for a series of returns.
+///
+/// # Description
+/// The Sortino Ratio is a variant of the Sharpe Ratio that only penalizes downside volatility.
+/// Returns are reduced by the per-period share of the annual risk-free rate, which also serves
+/// as the target return, and the ratio is annualized with the square root of the number of
+/// periods per year.
+///
+/// # Mathematical Formula
+/// ```text
+/// Excess[t] = Return[t] - RiskFree / Annualization
+/// DownsideDev = sqrt(sum(min(Excess, 0)^2) / n)
+/// SORTINO = mean(Excess) / DownsideDev * sqrt(Annualization)
+/// ```
+/// Where the sums are taken over the last n returns. The ratio is 0 when there is no downside.
+///
+/// # Arguments
+/// * `input_returns` - Array of per-period simple returns, e.g. from [`ret`](super::ret)
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+/// * `param_annualization` - Periods per year, e.g. 252 for daily bars (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate, e.g. 0.02 for 2%
+/// * `output_sortino` - Array to store Sortino Ratio values
+/// * `output_sum` - Array to store running sums of excess returns
+/// * `output_sum_down_sq` - Array to store running sums of squared downside excess returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or annualization is not positive
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sortino;
+/// let input_returns = vec![0.01, -0.02, 0.015, 0.005, -0.01];
+/// let mut output_sortino = vec![0.0; 5];
+/// let mut output_sum = vec![0.0; 5];
+/// let mut output_sum_down_sq = vec![0.0; 5];
+///
+/// sortino::sortino(
+///     &input_returns,
+///     3,
+///     252.0,
+///     0.0,
+///     &mut output_sortino,
+///     &mut output_sum,
+///     &mut output_sum_down_sq,
+/// )
+/// .unwrap();
+/// assert!(output_sortino[1].is_nan());
+/// ```
+pub fn sortino(
+    input_returns: &[TAFloat],
+    param_period: usize,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+    output_sortino: &mut [TAFloat],
+    output_sum: &mut [TAFloat],
+    output_sum_down_sq: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_returns.len();
+    let lookback = lookback(param_period)?;
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_sortino.len() != len || output_sum.len() != len || output_sum_down_sq.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for value in input_returns {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let risk_free = param_risk_free / param_annualization;
+    let period_t = param_period as TAFloat;
+
+    // Calculate initial sums
+    let mut sum = 0.0;
+    let mut sum_down_sq = 0.0;
+    for value in input_returns.iter().take(param_period) {
+        let excess = *value - risk_free;
+        sum += excess;
+        sum_down_sq += downside_sq(excess);
+    }
+    output_sortino[lookback] = ratio(sum, sum_down_sq, period_t, param_annualization);
+    output_sum[lookback] = sum;
+    output_sum_down_sq[lookback] = sum_down_sq;
+
+    // Slide the window over the remaining returns
+    for i in param_period..len {
+        let old_excess = input_returns[i - param_period] - risk_free;
+        let new_excess = input_returns[i] - risk_free;
+        sum = sum - old_excess + new_excess;
+        sum_down_sq = sum_down_sq - downside_sq(old_excess) + downside_sq(new_excess);
+
+        output_sortino[i] = ratio(sum, sum_down_sq, period_t, param_annualization);
+        output_sum[i] = sum;
+        output_sum_down_sq[i] = sum_down_sq;
+    }
+
+    // Fill initial values with NAN
+    for i in 0..lookback {
+        output_sortino[i] = TAFloat::NAN;
+        output_sum[i] = TAFloat::NAN;
+        output_sum_down_sq[i] = TAFloat::NAN;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling Sortino Ratio value using incremental computation.
+///
+/// # Description
+/// Updates the running sums by removing the return leaving the window and adding the newest
+/// one.
+///
+/// # Arguments
+/// * `input_return` - The newest return
+/// * `input_old_return` - The return leaving the window
+/// * `prev_sum` - Previous sum of excess returns
+/// * `prev_sum_down_sq` - Previous sum of squared downside excess returns
+/// * `param_period` - The number of returns in the rolling window (must be >= 2)
+/// * `param_annualization` - Periods per year (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (sortino, `new_sum`,
+///   `new_sum_down_sq`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is less than 2 or annualization is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sortino;
+/// let (sortino_value, new_sum, new_sum_down_sq) = sortino::sortino_inc(
+///     0.005,  // new return
+///     0.01,   // return leaving the window
+///     0.005,  // previous sum
+///     0.0004, // previous sum of squared downside returns
+///     3,      // period
+///     252.0,  // annualization
+///     0.0,    // risk-free rate
+/// )
+/// .unwrap();
+/// ```
+pub fn sortino_inc(
+    input_return: TAFloat,
+    input_old_return: TAFloat,
+    prev_sum: TAFloat,
+    prev_sum_down_sq: TAFloat,
+    param_period: usize,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    lookback(param_period)?;
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_return.is_nan()
+            || input_old_return.is_nan()
+            || prev_sum.is_nan()
+            || prev_sum_down_sq.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let risk_free = param_risk_free / param_annualization;
+    let old_excess = input_old_return - risk_free;
+    let new_excess = input_return - risk_free;
+    let sum = prev_sum - old_excess + new_excess;
+    let sum_down_sq = prev_sum_down_sq - downside_sq(old_excess) + downside_sq(new_excess);
+
+    Ok((
+        ratio(
+            sum,
+            sum_down_sq,
+            param_period as TAFloat,
+            param_annualization,
+        ),
+        sum,
+        sum_down_sq,
+    ))
+}
+
+/// Calculates the expanding Sortino Ratio for a series of returns.
+///
+/// # Description
+/// Uses every return from the start of the series up to each bar. Like the expanding Sharpe
+/// Ratio, the first value is NaN; the running sums are valid from the first bar so
+/// [`sortino_expanding_inc`] can continue from any index.
+///
+/// # Arguments
+/// * `input_returns` - Array of per-period simple returns
+/// * `param_annualization` - Periods per year, e.g. 252 for daily bars (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate
+/// * `output_sortino` - Array to store Sortino Ratio values
+/// * `output_sum` - Array to store cumulative sums of excess returns
+/// * `output_sum_down_sq` - Array to store cumulative sums of squared downside excess returns
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if annualization is not positive
+/// * Returns `KandError::InsufficientData` if there are fewer than two returns
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sortino;
+/// let input_returns = vec![0.01, -0.02, 0.015, 0.005];
+/// let mut output_sortino = vec![0.0; 4];
+/// let mut output_sum = vec![0.0; 4];
+/// let mut output_sum_down_sq = vec![0.0; 4];
+///
+/// sortino::sortino_expanding(
+///     &input_returns,
+///     252.0,
+///     0.0,
+///     &mut output_sortino,
+///     &mut output_sum,
+///     &mut output_sum_down_sq,
+/// )
+/// .unwrap();
+/// assert!(output_sortino[0].is_nan());
+/// ```
+pub fn sortino_expanding(
+    input_returns: &[TAFloat],
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+    output_sortino: &mut [TAFloat],
+    output_sum: &mut [TAFloat],
+    output_sum_down_sq: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_returns.len();
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_sortino.len() != len || output_sum.len() != len || output_sum_down_sq.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len < 2 {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for value in input_returns {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let risk_free = param_risk_free / param_annualization;
+    let mut sum = 0.0;
+    let mut sum_down_sq = 0.0;
+    for i in 0..len {
+        let excess = input_returns[i] - risk_free;
+        sum += excess;
+        sum_down_sq += downside_sq(excess);
+
+        output_sortino[i] = if i == 0 {
+            TAFloat::NAN
+        } else {
+            ratio(sum, sum_down_sq, (i + 1) as TAFloat, param_annualization)
+        };
+        output_sum[i] = sum;
+        output_sum_down_sq[i] = sum_down_sq;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest expanding Sortino Ratio value using incremental computation.
+///
+/// # Arguments
+/// * `input_return` - The newest return
+/// * `prev_sum` - Previous cumulative sum of excess returns
+/// * `prev_sum_down_sq` - Previous cumulative sum of squared downside excess returns
+/// * `prev_count` - Number of returns already included in the sums
+/// * `param_annualization` - Periods per year (must be > 0)
+/// * `param_risk_free` - Annual risk-free rate
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat), KandError>` - Tuple containing (sortino, `new_sum`,
+///   `new_sum_down_sq`), with NaN as the ratio for the first return
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if annualization is not positive
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::sortino;
+/// let (sortino_value, new_sum, new_sum_down_sq) = sortino::sortino_expanding_inc(
+///     0.005,  // new return
+///     -0.01,  // previous sum
+///     0.0004, // previous sum of squared downside returns
+///     2,      // returns so far
+///     252.0,  // annualization
+///     0.0,    // risk-free rate
+/// )
+/// .unwrap();
+/// ```
+pub fn sortino_expanding_inc(
+    input_return: TAFloat,
+    prev_sum: TAFloat,
+    prev_sum_down_sq: TAFloat,
+    prev_count: usize,
+    param_annualization: TAFloat,
+    param_risk_free: TAFloat,
+) -> Result<(TAFloat, TAFloat, TAFloat), KandError> {
+    check_annualization(param_annualization)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_return.is_nan() || prev_sum.is_nan() || prev_sum_down_sq.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let excess = input_return - param_risk_free / param_annualization;
+    let sum = prev_sum + excess;
+    let sum_down_sq = prev_sum_down_sq + downside_sq(excess);
+    let sortino = if prev_count == 0 {
+        TAFloat::NAN
+    } else {
+        ratio(
+            sum,
+            sum_down_sq,
+            (prev_count + 1) as TAFloat,
+            param_annualization,
+        )
+    };
+
+    Ok((sortino, sum, sum_down_sq))
+}
+
+/// Streaming rolling Sortino Ratio calculator.
+///
+/// Keeps the last `param_period` returns together with the running sums required by
+/// [`sortino_inc`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::sortino::Sortino};
+///
+/// let mut sortino = Sortino::new(2, 252.0, 0.0).unwrap();
+/// assert_eq!(sortino.update(-0.01).unwrap(), None);
+/// assert!(sortino.update(0.03).unwrap().unwrap() > 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sortino {
+    param_period:        usize,
+    param_annualization: TAFloat,
+    param_risk_free:     TAFloat,
+    lookback:            usize,
+    window:              VecDeque<TAFloat>,
+    prev_sums:           Option<(TAFloat, TAFloat)>,
+}
+
+impl Sortino {
+    /// Creates a new streaming rolling Sortino Ratio.
+    ///
+    /// # Arguments
+    /// * `param_period` - The number of returns in the rolling window (must be >= 2)
+    /// * `param_annualization` - Periods per year (must be > 0)
+    /// * `param_risk_free` - Annual risk-free rate
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the period is less than 2 or annualization is not
+    ///   positive
+    pub fn new(
+        param_period: usize,
+        param_annualization: TAFloat,
+        param_risk_free: TAFloat,
+    ) -> Result<Self, KandError> {
+        check_annualization(param_annualization)?;
+        Ok(Self {
+            param_period,
+            param_annualization,
+            param_risk_free,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period),
+            prev_sums: None,
+        })
+    }
+}
+
+impl Indicator for Sortino {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_return: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if let Some((prev_sum, prev_sum_down_sq)) = self.prev_sums {
+            let (output_sortino, output_sum, output_sum_down_sq) = sortino_inc(
+                input_return,
+                self.window[0],
+                prev_sum,
+                prev_sum_down_sq,
+                self.param_period,
+                self.param_annualization,
+                self.param_risk_free,
+            )?;
+            self.window.pop_front();
+            self.window.push_back(input_return);
+            self.prev_sums = Some((output_sum, output_sum_down_sq));
+            return Ok(Some(output_sortino));
+        }
+
+        self.window.push_back(input_return);
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let window = self.window.make_contiguous();
+        let len = window.len();
+        let mut output_sortino = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_down_sq = vec![0.0; len];
+        sortino(
+            window,
+            self.param_period,
+            self.param_annualization,
+            self.param_risk_free,
+            &mut output_sortino,
+            &mut output_sum,
+            &mut output_sum_down_sq,
+        )?;
+        self.prev_sums = Some((output_sum[self.lookback], output_sum_down_sq[self.lookback]));
+        Ok(Some(output_sortino[self.lookback]))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.prev_sums = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_sums.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_RETURNS: [TAFloat; 12] = [
+        0.012, -0.008, 0.004, 0.015, -0.021, 0.007, 0.003, -0.005, 0.018, -0.002, 0.009, -0.011,
+    ];
+
+    #[test]
+    fn test_sortino_calculation() {
+        let len = INPUT_RETURNS.len();
+        let mut output_sortino = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_down_sq = vec![0.0; len];
+
+        sortino(
+            &INPUT_RETURNS,
+            5,
+            252.0,
+            0.02,
+            &mut output_sortino,
+            &mut output_sum,
+            &mut output_sum_down_sq,
+        )
+        .unwrap();
+
+        for value in output_sortino.iter().take(4) {
+            assert!(value.is_nan());
+        }
+        let expected_values = [
+            0.504_167_286_152_500_2,
+            -1.068_235_636_006_290_3,
+            2.560_664_653_106_670_6,
+            -0.457_345_049_654_724_7,
+            0.524_907_386_535_535_8,
+            26.649_875_918_121_41,
+            29.236_843_842_376_643,
+            4.939_742_312_596_377,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_sortino[i + 4], *expected, epsilon = 1e-9);
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let mut prev_sum = output_sum[4];
+        let mut prev_sum_down_sq = output_sum_down_sq[4];
+        for i in 5..len {
+            let (value, sum, sum_down_sq) = sortino_inc(
+                INPUT_RETURNS[i],
+                INPUT_RETURNS[i - 5],
+                prev_sum,
+                prev_sum_down_sq,
+                5,
+                252.0,
+                0.02,
+            )
+            .unwrap();
+            assert_relative_eq!(value, output_sortino[i], epsilon = 1e-9);
+            prev_sum = sum;
+            prev_sum_down_sq = sum_down_sq;
+        }
+
+        let mut stream = Sortino::new(5, 252.0, 0.02).unwrap();
+        for (i, value) in INPUT_RETURNS.iter().enumerate() {
+            match stream.update(*value).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_sortino[i], epsilon = 1e-9),
+                None => assert!(output_sortino[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_sortino_expanding() {
+        let len = INPUT_RETURNS.len();
+        let mut output_sortino = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_down_sq = vec![0.0; len];
+
+        sortino_expanding(
+            &INPUT_RETURNS,
+            252.0,
+            0.02,
+            &mut output_sortino,
+            &mut output_sum,
+            &mut output_sum_down_sq,
+        )
+        .unwrap();
+
+        assert!(output_sortino[0].is_nan());
+        let expected_values = [
+            5.336_823_699_013_517,
+            8.805_027_563_196_091,
+            22.283_567_525_606_87,
+            0.504_167_286_152_500_2,
+            2.447_016_800_661_274_2,
+            3.041_757_134_231_902,
+            1.543_879_996_593_173_8,
+            5.553_724_564_727_598,
+            4.798_277_288_556_074,
+            6.412_817_467_704_685,
+            3.569_292_924_814_3,
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_sortino[i + 1], *expected, epsilon = 1e-9);
+        }
+
+        let mut prev_sum = 0.0;
+        let mut prev_sum_down_sq = 0.0;
+        for i in 0..len {
+            let (value, sum, sum_down_sq) =
+                sortino_expanding_inc(INPUT_RETURNS[i], prev_sum, prev_sum_down_sq, i, 252.0, 0.02)
+                    .unwrap();
+            if i == 0 {
+                assert!(value.is_nan());
+            } else {
+                assert_relative_eq!(value, output_sortino[i], epsilon = 1e-9);
+            }
+            prev_sum = sum;
+            prev_sum_down_sq = sum_down_sq;
+        }
+    }
+
+    #[test]
+    fn test_sortino_no_downside() {
+        // Without any return below the target the ratio falls back to 0
+        let input_returns = [0.01, 0.02, 0.015];
+        let mut output_sortino = vec![0.0; 3];
+        let mut output_sum = vec![0.0; 3];
+        let mut output_sum_down_sq = vec![0.0; 3];
+        sortino(
+            &input_returns,
+            2,
+            252.0,
+            0.0,
+            &mut output_sortino,
+            &mut output_sum,
+            &mut output_sum_down_sq,
+        )
+        .unwrap();
+        assert_relative_eq!(output_sortino[1], 0.0);
+        assert_relative_eq!(output_sortino[2], 0.0);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat, ta::stats::var};
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat, ta::stats::var};
 
 /// Calculates the lookback period required for Standard Deviation calculation.
 ///
@@ -180,6 +182,100 @@ pub fn stddev_inc(
     Ok((var.sqrt(), new_sum, new_sum_sq))
 }
 
+/// Streaming rolling Standard Deviation calculator.
+///
+/// Keeps the last `param_period` values together with their running sum and sum of squares,
+/// as required by [`stddev_inc`]. The first value is seeded with [`stddev`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::stddev::Stddev};
+///
+/// let mut stddev = Stddev::new(2).unwrap();
+/// assert_eq!(stddev.update(1.0).unwrap(), None);
+/// assert_eq!(stddev.update(3.0).unwrap(), Some(1.0));
+/// assert_eq!(stddev.update(7.0).unwrap(), Some(2.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stddev {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<TAFloat>,
+    prev_sums:    Option<(TAFloat, TAFloat)>,
+}
+
+impl Stddev {
+    /// Creates a new streaming Standard Deviation.
+    ///
+    /// # Arguments
+    /// * `param_period` - The window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period),
+            prev_sums: None,
+        })
+    }
+}
+
+impl Indicator for Stddev {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if let Some((prev_sum, prev_sum_sq)) = self.prev_sums {
+            let (output_stddev, output_sum, output_sum_sq) = stddev_inc(
+                input_price,
+                prev_sum,
+                prev_sum_sq,
+                self.window[0],
+                self.param_period,
+            )?;
+            self.window.pop_front();
+            self.window.push_back(input_price);
+            self.prev_sums = Some((output_sum, output_sum_sq));
+            return Ok(Some(output_stddev));
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let window = self.window.make_contiguous();
+        let len = window.len();
+        let mut output_stddev = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_sq = vec![0.0; len];
+        stddev(
+            window,
+            self.param_period,
+            &mut output_stddev,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )?;
+        self.prev_sums = Some((output_sum[self.lookback], output_sum_sq[self.lookback]));
+        Ok(Some(output_stddev[self.lookback]))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.prev_sums = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_sums.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -252,4 +348,36 @@ fn test_stddev_calculation() {
             prev_sum_sq = new_sum_sq;
         }
     }
+
+    #[test]
+    fn test_stddev_stream() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 14;
+        let len = input_prices.len();
+        let mut output_stddev = vec![0.0; len];
+        let mut output_sum = vec![0.0; len];
+        let mut output_sum_sq = vec![0.0; len];
+
+        stddev(
+            &input_prices,
+            param_period,
+            &mut output_stddev,
+            &mut output_sum,
+            &mut output_sum_sq,
+        )
+        .unwrap();
+
+        let mut stream = Stddev::new(param_period).unwrap();
+        for (i, price) in input_prices.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_stddev[i], epsilon = 0.00001),
+                None => assert!(output_stddev[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
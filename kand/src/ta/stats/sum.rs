@@ -1,4 +1,6 @@
-use crate::{KandError, TAFloat};
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
 
 /// Calculates the lookback period required for Sum calculation.
 ///
@@ -178,6 +180,85 @@ pub fn sum_inc(
     Ok(prev_sum + input_new_price - input_old_price)
 }
 
+/// Streaming rolling Sum calculator.
+///
+/// Keeps the last `param_period` values so the value leaving the window is available to
+/// [`sum_inc`]. The first value is seeded with [`sum`].
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::sum::Sum};
+///
+/// let mut sum = Sum::new(2).unwrap();
+/// assert_eq!(sum.update(1.0).unwrap(), None);
+/// assert_eq!(sum.update(2.0).unwrap(), Some(3.0));
+/// assert_eq!(sum.update(4.0).unwrap(), Some(6.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sum {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<TAFloat>,
+    prev_sum:     Option<TAFloat>,
+}
+
+impl Sum {
+    /// Creates a new streaming Sum.
+    ///
+    /// # Arguments
+    /// * `param_period` - The window size (must be >= 2)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If `param_period` is less than 2
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period),
+            prev_sum: None,
+        })
+    }
+}
+
+impl Indicator for Sum {
+    type Input = TAFloat;
+    type Output = TAFloat;
+
+    fn update(&mut self, input_price: TAFloat) -> Result<Option<TAFloat>, KandError> {
+        if let Some(prev_sum) = self.prev_sum {
+            let output_sum = sum_inc(input_price, self.window[0], prev_sum)?;
+            self.window.pop_front();
+            self.window.push_back(input_price);
+            self.prev_sum = Some(output_sum);
+            return Ok(self.prev_sum);
+        }
+
+        self.window.push_back(input_price);
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+
+        let window = self.window.make_contiguous();
+        let mut output_sum = vec![0.0; window.len()];
+        sum(window, self.param_period, &mut output_sum)?;
+        self.prev_sum = Some(output_sum[self.lookback]);
+        Ok(self.prev_sum)
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.prev_sum = None;
+    }
+
+    fn is_ready(&self) -> bool {
+        self.prev_sum.is_some()
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use approx::assert_relative_eq;
@@ -226,4 +307,26 @@ fn test_sum_calculation() {
             prev_sum = result;
         }
     }
+
+    #[test]
+    fn test_sum_stream() {
+        let input_prices = vec![
+            35216.1, 35221.4, 35190.7, 35170.0, 35181.5, 35254.6, 35202.8, 35251.9, 35197.6,
+            35184.7, 35175.1, 35229.9, 35212.5, 35160.7, 35090.3, 35041.2, 34999.3, 35013.4,
+            35069.0, 35024.6, 34939.5, 34952.6, 35000.0, 35041.8, 35080.0,
+        ];
+        let param_period = 14;
+        let mut output_sum = vec![0.0; input_prices.len()];
+
+        sum(&input_prices, param_period, &mut output_sum).unwrap();
+
+        let mut stream = Sum::new(param_period).unwrap();
+        for (i, price) in input_prices.iter().enumerate() {
+            match stream.update(*price).unwrap() {
+                Some(value) => assert_relative_eq!(value, output_sum[i], epsilon = 0.00001),
+                None => assert!(output_sum[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
 }
@@ -0,0 +1,967 @@
+use std::collections::VecDeque;
+
+use crate::{Indicator, KandError, TAFloat};
+
+/// Running trade sums: (wins, losses, `gross_profit`, `gross_loss`).
+type TradeSums = (TAFloat, TAFloat, TAFloat, TAFloat);
+
+/// Trade statistics: (`win_rate`, `avg_win`, `avg_loss`, `profit_factor`, `expectancy`).
+type TradeStats = (TAFloat, TAFloat, TAFloat, TAFloat, TAFloat);
+
+/// Calculates the lookback period required for rolling trade statistics.
+///
+/// # Description
+/// The rolling statistics use windows of `param_period` trades, so the lookback is the period
+/// minus one.
+///
+/// # Arguments
+/// * `param_period` - The number of trades in the rolling window (must be >= 1)
+///
+/// # Returns
+/// * `Result<usize, KandError>` - The lookback period (period - 1) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if `param_period` is 0
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate;
+/// let lookback = winrate::lookback(20).unwrap();
+/// assert_eq!(lookback, 19);
+/// ```
+pub const fn lookback(param_period: usize) -> Result<usize, KandError> {
+    #[cfg(feature = "check")]
+    {
+        if param_period == 0 {
+            return Err(KandError::InvalidParameter);
+        }
+    }
+    Ok(param_period - 1)
+}
+
+/// Contribution of a single trade to the running sums.
+///
+/// Breakeven trades count towards the number of trades but are neither wins nor losses.
+fn contribution(pnl: TAFloat) -> TradeSums {
+    if pnl > 0.0 {
+        (1.0, 0.0, pnl, 0.0)
+    } else if pnl < 0.0 {
+        (0.0, 1.0, 0.0, -pnl)
+    } else {
+        (0.0, 0.0, 0.0, 0.0)
+    }
+}
+
+/// Adds the newest trade to the running sums and removes the trade leaving the window.
+fn roll(prev: TradeSums, pnl: TAFloat, old_pnl: TAFloat) -> TradeSums {
+    let new = contribution(pnl);
+    let old = contribution(old_pnl);
+    (
+        prev.0 + new.0 - old.0,
+        prev.1 + new.1 - old.1,
+        prev.2 + new.2 - old.2,
+        prev.3 + new.3 - old.3,
+    )
+}
+
+/// Trade statistics from the running sums of `count` trades.
+fn trade_stats(sums: TradeSums, count: TAFloat) -> TradeStats {
+    let (wins, losses, gross_profit, gross_loss) = sums;
+    let win_rate = wins / count;
+    let avg_win = if wins > 0.0 { gross_profit / wins } else { 0.0 };
+    let avg_loss = if losses > 0.0 {
+        gross_loss / losses
+    } else {
+        0.0
+    };
+    let profit_factor = if gross_loss > 0.0 {
+        gross_profit / gross_loss
+    } else if gross_profit > 0.0 {
+        TAFloat::INFINITY
+    } else {
+        TAFloat::NAN
+    };
+    let expectancy = (gross_profit - gross_loss) / count;
+    (win_rate, avg_win, avg_loss, profit_factor, expectancy)
+}
+
+/// Calculates the profit and loss of each trade from its entry and exit prices.
+///
+/// # Description
+/// Turns entry/exit pairs into per-trade `PnL` that the trade statistics in this module and in
+/// [`kelly`](super::kelly) consume.
+///
+/// # Mathematical Formula
+/// ```text
+/// PnL = (Exit - Entry) * Size
+/// ```
+/// Size is positive for long trades and negative for short trades. Passing `1 / Entry` as the
+/// size yields the return of each trade instead of its absolute `PnL`.
+///
+/// # Arguments
+/// * `input_entry` - Array of entry prices
+/// * `input_exit` - Array of exit prices
+/// * `input_size` - Array of signed position sizes
+/// * `output_pnl` - Array to store the `PnL` of each trade
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if array lengths don't match
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate;
+/// let input_entry = vec![100.0, 50.0];
+/// let input_exit = vec![110.0, 55.0];
+/// let input_size = vec![2.0, -1.0];
+/// let mut output_pnl = vec![0.0; 2];
+///
+/// winrate::trade_pnl(&input_entry, &input_exit, &input_size, &mut output_pnl).unwrap();
+/// assert_eq!(output_pnl, vec![20.0, -5.0]);
+/// ```
+pub fn trade_pnl(
+    input_entry: &[TAFloat],
+    input_exit: &[TAFloat],
+    input_size: &[TAFloat],
+    output_pnl: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_entry.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if input_exit.len() != len || input_size.len() != len || output_pnl.len() != len {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    for i in 0..len {
+        output_pnl[i] = trade_pnl_inc(input_entry[i], input_exit[i], input_size[i])?;
+    }
+
+    Ok(())
+}
+
+/// Calculates the profit and loss of a single trade.
+///
+/// # Arguments
+/// * `input_entry` - Entry price
+/// * `input_exit` - Exit price
+/// * `input_size` - Signed position size, negative for short trades
+///
+/// # Returns
+/// * `Result<TAFloat, KandError>` - The `PnL` of the trade
+///
+/// # Errors
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate;
+/// let pnl = winrate::trade_pnl_inc(100.0, 95.0, -3.0).unwrap();
+/// assert_eq!(pnl, 15.0);
+/// ```
+pub fn trade_pnl_inc(
+    input_entry: TAFloat,
+    input_exit: TAFloat,
+    input_size: TAFloat,
+) -> Result<TAFloat, KandError> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_entry.is_nan() || input_exit.is_nan() || input_size.is_nan() {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    Ok((input_exit - input_entry) * input_size)
+}
+
+/// Calculates rolling trade statistics (WINRATE) for a series of per-trade `PnL`.
+///
+/// # Description
+/// Summarizes the last `param_period` trades by their win rate, average win, average loss,
+/// profit factor and expectancy. Breakeven trades count as trades but are neither wins nor
+/// losses.
+///
+/// # Mathematical Formula
+/// ```text
+/// WinRate = Wins / n
+/// AvgWin = GrossProfit / Wins
+/// AvgLoss = GrossLoss / Losses
+/// ProfitFactor = GrossProfit / GrossLoss
+/// Expectancy = (GrossProfit - GrossLoss) / n
+/// ```
+/// Where n is the number of trades in the window and `GrossLoss` is the absolute sum of losing
+/// trades. Averages are 0 when their denominator is 0. Without losing trades the profit factor
+/// is infinite if there was any profit and NaN if the window holds only breakeven trades.
+///
+/// # Arguments
+/// * `input_pnl` - Array of per-trade profit and loss, e.g. from [`trade_pnl`]
+/// * `param_period` - The number of trades in the rolling window (must be >= 1)
+/// * `output_winrate` - Array to store win rates
+/// * `output_avg_win` - Array to store average winning trades
+/// * `output_avg_loss` - Array to store average losing trades (as positive values)
+/// * `output_profit_factor` - Array to store profit factors
+/// * `output_expectancy` - Array to store expectancies
+/// * `output_wins` - Array to store running counts of winning trades
+/// * `output_losses` - Array to store running counts of losing trades
+/// * `output_gross_profit` - Array to store running sums of winning trades
+/// * `output_gross_loss` - Array to store running absolute sums of losing trades
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::InvalidParameter` if period is 0
+/// * Returns `KandError::InsufficientData` if input length is less than period
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate;
+/// let input_pnl = vec![120.0, -50.0, 0.0, 80.0];
+/// let mut output_winrate = vec![0.0; 4];
+/// let mut output_avg_win = vec![0.0; 4];
+/// let mut output_avg_loss = vec![0.0; 4];
+/// let mut output_profit_factor = vec![0.0; 4];
+/// let mut output_expectancy = vec![0.0; 4];
+/// let mut output_wins = vec![0.0; 4];
+/// let mut output_losses = vec![0.0; 4];
+/// let mut output_gross_profit = vec![0.0; 4];
+/// let mut output_gross_loss = vec![0.0; 4];
+///
+/// winrate::winrate(
+///     &input_pnl,
+///     4,
+///     &mut output_winrate,
+///     &mut output_avg_win,
+///     &mut output_avg_loss,
+///     &mut output_profit_factor,
+///     &mut output_expectancy,
+///     &mut output_wins,
+///     &mut output_losses,
+///     &mut output_gross_profit,
+///     &mut output_gross_loss,
+/// )
+/// .unwrap();
+/// assert_eq!(output_winrate[3], 0.5);
+/// assert_eq!(output_avg_win[3], 100.0);
+/// assert_eq!(output_profit_factor[3], 4.0);
+/// assert_eq!(output_expectancy[3], 37.5);
+/// ```
+pub fn winrate(
+    input_pnl: &[TAFloat],
+    param_period: usize,
+    output_winrate: &mut [TAFloat],
+    output_avg_win: &mut [TAFloat],
+    output_avg_loss: &mut [TAFloat],
+    output_profit_factor: &mut [TAFloat],
+    output_expectancy: &mut [TAFloat],
+    output_wins: &mut [TAFloat],
+    output_losses: &mut [TAFloat],
+    output_gross_profit: &mut [TAFloat],
+    output_gross_loss: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_pnl.len();
+    let lookback = lookback(param_period)?;
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_winrate.len() != len
+            || output_avg_win.len() != len
+            || output_avg_loss.len() != len
+            || output_profit_factor.len() != len
+            || output_expectancy.len() != len
+            || output_wins.len() != len
+            || output_losses.len() != len
+            || output_gross_profit.len() != len
+            || output_gross_loss.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+
+        // Data sufficiency check
+        if len <= lookback {
+            return Err(KandError::InsufficientData);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for value in input_pnl {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut sums = (0.0, 0.0, 0.0, 0.0);
+    for i in 0..len {
+        // A zero PnL leaves the sums untouched, so it stands in for trades before the start
+        let old_pnl = if i >= param_period {
+            input_pnl[i - param_period]
+        } else {
+            0.0
+        };
+        sums = roll(sums, input_pnl[i], old_pnl);
+
+        if i < lookback {
+            output_winrate[i] = TAFloat::NAN;
+            output_avg_win[i] = TAFloat::NAN;
+            output_avg_loss[i] = TAFloat::NAN;
+            output_profit_factor[i] = TAFloat::NAN;
+            output_expectancy[i] = TAFloat::NAN;
+            output_wins[i] = TAFloat::NAN;
+            output_losses[i] = TAFloat::NAN;
+            output_gross_profit[i] = TAFloat::NAN;
+            output_gross_loss[i] = TAFloat::NAN;
+            continue;
+        }
+
+        let (win_rate, avg_win, avg_loss, profit_factor, expectancy) =
+            trade_stats(sums, param_period as TAFloat);
+        output_winrate[i] = win_rate;
+        output_avg_win[i] = avg_win;
+        output_avg_loss[i] = avg_loss;
+        output_profit_factor[i] = profit_factor;
+        output_expectancy[i] = expectancy;
+        (
+            output_wins[i],
+            output_losses[i],
+            output_gross_profit[i],
+            output_gross_loss[i],
+        ) = sums;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest rolling trade statistics using incremental computation.
+///
+/// # Description
+/// Updates the running sums by removing the trade leaving the window and adding the newest one.
+///
+/// # Arguments
+/// * `input_pnl` - `PnL` of the newest trade
+/// * `input_old_pnl` - `PnL` of the trade leaving the window
+/// * `prev_wins` - Previous count of winning trades
+/// * `prev_losses` - Previous count of losing trades
+/// * `prev_gross_profit` - Previous sum of winning trades
+/// * `prev_gross_loss` - Previous absolute sum of losing trades
+/// * `param_period` - The number of trades in the rolling window (must be >= 1)
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` -
+///   Tuple containing (winrate, `avg_win`, `avg_loss`, `profit_factor`, expectancy, `new_wins`,
+///   `new_losses`, `new_gross_profit`, `new_gross_loss`) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidParameter` if period is 0
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate;
+/// let (
+///     win_rate,
+///     avg_win,
+///     avg_loss,
+///     profit_factor,
+///     expectancy,
+///     wins,
+///     losses,
+///     gross_profit,
+///     gross_loss,
+/// ) = winrate::winrate_inc(
+///     -30.0, // newest trade
+///     120.0, // trade leaving the window
+///     2.0,   // previous wins
+///     1.0,   // previous losses
+///     200.0, // previous gross profit
+///     50.0,  // previous gross loss
+///     4,     // period
+/// )
+/// .unwrap();
+/// assert_eq!(win_rate, 0.25);
+/// assert_eq!(profit_factor, 1.0);
+/// ```
+pub fn winrate_inc(
+    input_pnl: TAFloat,
+    input_old_pnl: TAFloat,
+    prev_wins: TAFloat,
+    prev_losses: TAFloat,
+    prev_gross_profit: TAFloat,
+    prev_gross_loss: TAFloat,
+    param_period: usize,
+) -> Result<
+    (
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+    ),
+    KandError,
+> {
+    lookback(param_period)?;
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_pnl.is_nan()
+            || input_old_pnl.is_nan()
+            || prev_wins.is_nan()
+            || prev_losses.is_nan()
+            || prev_gross_profit.is_nan()
+            || prev_gross_loss.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let sums = roll(
+        (prev_wins, prev_losses, prev_gross_profit, prev_gross_loss),
+        input_pnl,
+        input_old_pnl,
+    );
+    let (win_rate, avg_win, avg_loss, profit_factor, expectancy) =
+        trade_stats(sums, param_period as TAFloat);
+
+    Ok((
+        win_rate,
+        avg_win,
+        avg_loss,
+        profit_factor,
+        expectancy,
+        sums.0,
+        sums.1,
+        sums.2,
+        sums.3,
+    ))
+}
+
+/// Calculates expanding trade statistics for a series of per-trade `PnL`.
+///
+/// # Description
+/// Uses every trade from the start of the series up to each index, so the last value holds the
+/// statistics of the whole sample. Unlike the rolling form, a single trade is enough and there
+/// is no warm-up.
+///
+/// # Arguments
+/// * `input_pnl` - Array of per-trade profit and loss
+/// * `output_winrate` - Array to store win rates
+/// * `output_avg_win` - Array to store average winning trades
+/// * `output_avg_loss` - Array to store average losing trades (as positive values)
+/// * `output_profit_factor` - Array to store profit factors
+/// * `output_expectancy` - Array to store expectancies
+/// * `output_wins` - Array to store cumulative counts of winning trades
+/// * `output_losses` - Array to store cumulative counts of losing trades
+/// * `output_gross_profit` - Array to store cumulative sums of winning trades
+/// * `output_gross_loss` - Array to store cumulative absolute sums of losing trades
+///
+/// # Returns
+/// * `Result<(), KandError>` - Ok(()) on success
+///
+/// # Errors
+/// * Returns `KandError::InvalidData` if input array is empty
+/// * Returns `KandError::LengthMismatch` if output arrays don't match input length
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate;
+/// let input_pnl = vec![120.0, -50.0, 0.0, 80.0];
+/// let mut output_winrate = vec![0.0; 4];
+/// let mut output_avg_win = vec![0.0; 4];
+/// let mut output_avg_loss = vec![0.0; 4];
+/// let mut output_profit_factor = vec![0.0; 4];
+/// let mut output_expectancy = vec![0.0; 4];
+/// let mut output_wins = vec![0.0; 4];
+/// let mut output_losses = vec![0.0; 4];
+/// let mut output_gross_profit = vec![0.0; 4];
+/// let mut output_gross_loss = vec![0.0; 4];
+///
+/// winrate::winrate_expanding(
+///     &input_pnl,
+///     &mut output_winrate,
+///     &mut output_avg_win,
+///     &mut output_avg_loss,
+///     &mut output_profit_factor,
+///     &mut output_expectancy,
+///     &mut output_wins,
+///     &mut output_losses,
+///     &mut output_gross_profit,
+///     &mut output_gross_loss,
+/// )
+/// .unwrap();
+/// assert_eq!(output_winrate[0], 1.0);
+/// assert_eq!(output_winrate[3], 0.5);
+/// ```
+pub fn winrate_expanding(
+    input_pnl: &[TAFloat],
+    output_winrate: &mut [TAFloat],
+    output_avg_win: &mut [TAFloat],
+    output_avg_loss: &mut [TAFloat],
+    output_profit_factor: &mut [TAFloat],
+    output_expectancy: &mut [TAFloat],
+    output_wins: &mut [TAFloat],
+    output_losses: &mut [TAFloat],
+    output_gross_profit: &mut [TAFloat],
+    output_gross_loss: &mut [TAFloat],
+) -> Result<(), KandError> {
+    let len = input_pnl.len();
+
+    #[cfg(feature = "check")]
+    {
+        // Empty data check
+        if len == 0 {
+            return Err(KandError::InvalidData);
+        }
+
+        // Length consistency check
+        if output_winrate.len() != len
+            || output_avg_win.len() != len
+            || output_avg_loss.len() != len
+            || output_profit_factor.len() != len
+            || output_expectancy.len() != len
+            || output_wins.len() != len
+            || output_losses.len() != len
+            || output_gross_profit.len() != len
+            || output_gross_loss.len() != len
+        {
+            return Err(KandError::LengthMismatch);
+        }
+    }
+
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        for value in input_pnl {
+            if value.is_nan() {
+                return Err(KandError::NaNDetected);
+            }
+        }
+    }
+
+    let mut sums = (0.0, 0.0, 0.0, 0.0);
+    for i in 0..len {
+        sums = roll(sums, input_pnl[i], 0.0);
+        let (win_rate, avg_win, avg_loss, profit_factor, expectancy) =
+            trade_stats(sums, (i + 1) as TAFloat);
+        output_winrate[i] = win_rate;
+        output_avg_win[i] = avg_win;
+        output_avg_loss[i] = avg_loss;
+        output_profit_factor[i] = profit_factor;
+        output_expectancy[i] = expectancy;
+        (
+            output_wins[i],
+            output_losses[i],
+            output_gross_profit[i],
+            output_gross_loss[i],
+        ) = sums;
+    }
+
+    Ok(())
+}
+
+/// Calculates the latest expanding trade statistics using incremental computation.
+///
+/// # Arguments
+/// * `input_pnl` - `PnL` of the newest trade
+/// * `prev_wins` - Previous cumulative count of winning trades
+/// * `prev_losses` - Previous cumulative count of losing trades
+/// * `prev_gross_profit` - Previous cumulative sum of winning trades
+/// * `prev_gross_loss` - Previous cumulative absolute sum of losing trades
+/// * `prev_count` - Number of trades already included in the sums
+///
+/// # Returns
+/// * `Result<(TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat, TAFloat), KandError>` -
+///   Tuple containing (winrate, `avg_win`, `avg_loss`, `profit_factor`, expectancy, `new_wins`,
+///   `new_losses`, `new_gross_profit`, `new_gross_loss`) on success
+///
+/// # Errors
+/// * Returns `KandError::NaNDetected` if any input value is NaN (when "`deep-check`" feature is enabled)
+///
+/// # Example
+/// ```
+/// use kand::stats::winrate;
+/// let (
+///     win_rate,
+///     avg_win,
+///     avg_loss,
+///     profit_factor,
+///     expectancy,
+///     wins,
+///     losses,
+///     gross_profit,
+///     gross_loss,
+/// ) = winrate::winrate_expanding_inc(
+///     80.0,  // newest trade
+///     1.0,   // previous wins
+///     1.0,   // previous losses
+///     120.0, // previous gross profit
+///     50.0,  // previous gross loss
+///     3,     // trades so far
+/// )
+/// .unwrap();
+/// assert_eq!(win_rate, 0.5);
+/// ```
+pub fn winrate_expanding_inc(
+    input_pnl: TAFloat,
+    prev_wins: TAFloat,
+    prev_losses: TAFloat,
+    prev_gross_profit: TAFloat,
+    prev_gross_loss: TAFloat,
+    prev_count: usize,
+) -> Result<
+    (
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+        TAFloat,
+    ),
+    KandError,
+> {
+    #[cfg(feature = "deep-check")]
+    {
+        // NaN check
+        if input_pnl.is_nan()
+            || prev_wins.is_nan()
+            || prev_losses.is_nan()
+            || prev_gross_profit.is_nan()
+            || prev_gross_loss.is_nan()
+        {
+            return Err(KandError::NaNDetected);
+        }
+    }
+
+    let sums = roll(
+        (prev_wins, prev_losses, prev_gross_profit, prev_gross_loss),
+        input_pnl,
+        0.0,
+    );
+    let (win_rate, avg_win, avg_loss, profit_factor, expectancy) =
+        trade_stats(sums, (prev_count + 1) as TAFloat);
+
+    Ok((
+        win_rate,
+        avg_win,
+        avg_loss,
+        profit_factor,
+        expectancy,
+        sums.0,
+        sums.1,
+        sums.2,
+        sums.3,
+    ))
+}
+
+/// Streaming rolling trade statistics calculator.
+///
+/// Keeps the last `param_period` trades together with the running sums required by
+/// [`winrate_inc`]. Each output is (winrate, `avg_win`, `avg_loss`, `profit_factor`,
+/// expectancy).
+///
+/// # Example
+/// ```
+/// use kand::{Indicator, stats::winrate::WinRate};
+///
+/// let mut winrate = WinRate::new(2).unwrap();
+/// assert_eq!(winrate.update(120.0).unwrap(), None);
+/// let (win_rate, _, _, profit_factor, _) = winrate.update(-40.0).unwrap().unwrap();
+/// assert_eq!(win_rate, 0.5);
+/// assert_eq!(profit_factor, 3.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WinRate {
+    param_period: usize,
+    lookback:     usize,
+    window:       VecDeque<TAFloat>,
+    sums:         TradeSums,
+}
+
+impl WinRate {
+    /// Creates a new streaming rolling trade statistics calculator.
+    ///
+    /// # Arguments
+    /// * `param_period` - The number of trades in the rolling window (must be >= 1)
+    ///
+    /// # Errors
+    /// * `KandError::InvalidParameter` - If the period is 0
+    pub fn new(param_period: usize) -> Result<Self, KandError> {
+        Ok(Self {
+            param_period,
+            lookback: lookback(param_period)?,
+            window: VecDeque::with_capacity(param_period),
+            sums: (0.0, 0.0, 0.0, 0.0),
+        })
+    }
+}
+
+impl Indicator for WinRate {
+    type Input = TAFloat;
+    type Output = TradeStats;
+
+    fn update(&mut self, input_pnl: TAFloat) -> Result<Option<Self::Output>, KandError> {
+        let old_pnl = if self.window.len() == self.param_period {
+            self.window[0]
+        } else {
+            0.0
+        };
+        let (win_rate, avg_win, avg_loss, profit_factor, expectancy, wins, losses, gp, gl) =
+            winrate_inc(
+                input_pnl,
+                old_pnl,
+                self.sums.0,
+                self.sums.1,
+                self.sums.2,
+                self.sums.3,
+                self.param_period,
+            )?;
+
+        if self.window.len() == self.param_period {
+            self.window.pop_front();
+        }
+        self.window.push_back(input_pnl);
+        self.sums = (wins, losses, gp, gl);
+
+        if self.window.len() <= self.lookback {
+            return Ok(None);
+        }
+        Ok(Some((
+            win_rate,
+            avg_win,
+            avg_loss,
+            profit_factor,
+            expectancy,
+        )))
+    }
+
+    fn reset(&mut self) {
+        self.window.clear();
+        self.sums = (0.0, 0.0, 0.0, 0.0);
+    }
+
+    fn is_ready(&self) -> bool {
+        self.window.len() > self.lookback
+    }
+
+    fn lookback(&self) -> usize {
+        self.lookback
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    const INPUT_PNL: [TAFloat; 12] = [
+        120.0, -50.0, 0.0, 80.0, -30.0, -70.0, 200.0, 40.0, -10.0, -90.0, 60.0, 0.0,
+    ];
+
+    #[test]
+    fn test_winrate_calculation() {
+        let len = INPUT_PNL.len();
+        let mut output_winrate = vec![0.0; len];
+        let mut output_avg_win = vec![0.0; len];
+        let mut output_avg_loss = vec![0.0; len];
+        let mut output_profit_factor = vec![0.0; len];
+        let mut output_expectancy = vec![0.0; len];
+        let mut output_wins = vec![0.0; len];
+        let mut output_losses = vec![0.0; len];
+        let mut output_gross_profit = vec![0.0; len];
+        let mut output_gross_loss = vec![0.0; len];
+
+        winrate(
+            &INPUT_PNL,
+            5,
+            &mut output_winrate,
+            &mut output_avg_win,
+            &mut output_avg_loss,
+            &mut output_profit_factor,
+            &mut output_expectancy,
+            &mut output_wins,
+            &mut output_losses,
+            &mut output_gross_profit,
+            &mut output_gross_loss,
+        )
+        .unwrap();
+
+        for value in output_winrate.iter().take(4) {
+            assert!(value.is_nan());
+        }
+        let expected_values = [
+            (0.4, 100.0, 40.0, 2.5, 24.0),
+            (0.2, 80.0, 50.0, 0.533_333_333_333_333_3, -14.0),
+            (0.4, 140.0, 50.0, 2.8, 36.0),
+            (0.6, 106.666_666_666_666_67, 50.0, 3.2, 44.0),
+            (
+                0.4,
+                120.0,
+                36.666_666_666_666_664,
+                2.181_818_181_818_181_7,
+                26.0,
+            ),
+            (
+                0.4,
+                120.0,
+                56.666_666_666_666_664,
+                1.411_764_705_882_353,
+                14.0,
+            ),
+            (0.6, 100.0, 50.0, 3.0, 40.0),
+            (0.4, 50.0, 50.0, 1.0, 0.0),
+        ];
+        for (i, expected) in expected_values.iter().enumerate() {
+            assert_relative_eq!(output_winrate[i + 4], expected.0, epsilon = 1e-9);
+            assert_relative_eq!(output_avg_win[i + 4], expected.1, epsilon = 1e-9);
+            assert_relative_eq!(output_avg_loss[i + 4], expected.2, epsilon = 1e-9);
+            assert_relative_eq!(output_profit_factor[i + 4], expected.3, epsilon = 1e-9);
+            assert_relative_eq!(output_expectancy[i + 4], expected.4, epsilon = 1e-9);
+        }
+
+        // Incremental and streaming calculation match the batch output
+        let mut prev_sums = (
+            output_wins[4],
+            output_losses[4],
+            output_gross_profit[4],
+            output_gross_loss[4],
+        );
+        for i in 5..len {
+            let (win_rate, avg_win, avg_loss, profit_factor, expectancy, wins, losses, gp, gl) =
+                winrate_inc(
+                    INPUT_PNL[i],
+                    INPUT_PNL[i - 5],
+                    prev_sums.0,
+                    prev_sums.1,
+                    prev_sums.2,
+                    prev_sums.3,
+                    5,
+                )
+                .unwrap();
+            assert_relative_eq!(win_rate, output_winrate[i], epsilon = 1e-9);
+            assert_relative_eq!(avg_win, output_avg_win[i], epsilon = 1e-9);
+            assert_relative_eq!(avg_loss, output_avg_loss[i], epsilon = 1e-9);
+            assert_relative_eq!(profit_factor, output_profit_factor[i], epsilon = 1e-9);
+            assert_relative_eq!(expectancy, output_expectancy[i], epsilon = 1e-9);
+            prev_sums = (wins, losses, gp, gl);
+        }
+
+        let mut stream = WinRate::new(5).unwrap();
+        for (i, value) in INPUT_PNL.iter().enumerate() {
+            match stream.update(*value).unwrap() {
+                Some((win_rate, _, _, profit_factor, _)) => {
+                    assert_relative_eq!(win_rate, output_winrate[i], epsilon = 1e-9);
+                    assert_relative_eq!(profit_factor, output_profit_factor[i], epsilon = 1e-9);
+                }
+                None => assert!(output_winrate[i].is_nan()),
+            }
+        }
+        assert!(stream.is_ready());
+    }
+
+    #[test]
+    fn test_profit_factor_without_losses() {
+        // Only winners: infinitely good, not 0
+        assert_eq!(
+            trade_stats((2.0, 0.0, 150.0, 0.0), 2.0).3,
+            TAFloat::INFINITY
+        );
+        // Only breakeven trades: undefined
+        assert!(trade_stats((0.0, 0.0, 0.0, 0.0), 2.0).3.is_nan());
+        // Only losers
+        assert_eq!(trade_stats((0.0, 2.0, 0.0, 80.0), 2.0).3, 0.0);
+    }
+
+    #[test]
+    fn test_winrate_expanding() {
+        let len = INPUT_PNL.len();
+        let mut output_winrate = vec![0.0; len];
+        let mut output_avg_win = vec![0.0; len];
+        let mut output_avg_loss = vec![0.0; len];
+        let mut output_profit_factor = vec![0.0; len];
+        let mut output_expectancy = vec![0.0; len];
+        let mut output_wins = vec![0.0; len];
+        let mut output_losses = vec![0.0; len];
+        let mut output_gross_profit = vec![0.0; len];
+        let mut output_gross_loss = vec![0.0; len];
+
+        winrate_expanding(
+            &INPUT_PNL,
+            &mut output_winrate,
+            &mut output_avg_win,
+            &mut output_avg_loss,
+            &mut output_profit_factor,
+            &mut output_expectancy,
+            &mut output_wins,
+            &mut output_losses,
+            &mut output_gross_profit,
+            &mut output_gross_loss,
+        )
+        .unwrap();
+
+        // A single winning trade has no losses, so the profit factor is infinite
+        assert_relative_eq!(output_winrate[0], 1.0, epsilon = 1e-9);
+        assert_eq!(output_profit_factor[0], TAFloat::INFINITY);
+        assert_relative_eq!(output_expectancy[0], 120.0, epsilon = 1e-9);
+
+        // Statistics over the whole sample
+        assert_relative_eq!(output_winrate[11], 0.416_666_666_666_666_7, epsilon = 1e-9);
+        assert_relative_eq!(output_avg_win[11], 100.0, epsilon = 1e-9);
+        assert_relative_eq!(output_avg_loss[11], 50.0, epsilon = 1e-9);
+        assert_relative_eq!(output_profit_factor[11], 2.0, epsilon = 1e-9);
+        assert_relative_eq!(
+            output_expectancy[11],
+            20.833_333_333_333_332,
+            epsilon = 1e-9
+        );
+
+        let mut prev_sums = (0.0, 0.0, 0.0, 0.0);
+        for i in 0..len {
+            let (win_rate, _, _, profit_factor, expectancy, wins, losses, gp, gl) =
+                winrate_expanding_inc(
+                    INPUT_PNL[i],
+                    prev_sums.0,
+                    prev_sums.1,
+                    prev_sums.2,
+                    prev_sums.3,
+                    i,
+                )
+                .unwrap();
+            assert_relative_eq!(win_rate, output_winrate[i], epsilon = 1e-9);
+            assert_relative_eq!(profit_factor, output_profit_factor[i], epsilon = 1e-9);
+            assert_relative_eq!(expectancy, output_expectancy[i], epsilon = 1e-9);
+            prev_sums = (wins, losses, gp, gl);
+        }
+    }
+}
@@ -112,3 +112,150 @@ fn default() -> Self {
         Self::Neutral
     }
 }
+
+/// Timing of periodic payments for time-value-of-money functions.
+///
+/// The integer representation of this enum is determined by the enabled features:
+/// - With feature "i64": Uses i64 representation (extended precision)
+/// - With feature "i32": Uses i32 representation (standard precision)
+/// - With no features enabled: Defaults to i32
+///
+/// # Variants
+///
+/// * `End` - Payments are due at the end of each period (ordinary annuity)
+/// * `Begin` - Payments are due at the beginning of each period (annuity due)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg(feature = "i64")]
+#[repr(i64)]
+pub enum PaymentTiming {
+    End   = 0,
+    Begin = 1,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg(not(feature = "i64"))]
+#[repr(i32)]
+pub enum PaymentTiming {
+    End   = 0,
+    Begin = 1,
+}
+
+impl PaymentTiming {
+    /// Returns the payment timing as the factor used in annuity formulas (0 or 1).
+    #[must_use]
+    pub const fn factor(self) -> crate::TAFloat {
+        match self {
+            Self::End => 0.0,
+            Self::Begin => 1.0,
+        }
+    }
+}
+
+impl Default for PaymentTiming {
+    /// Returns the default payment timing (End).
+    ///
+    /// # Returns
+    /// * [`PaymentTiming::End`] - Payments at the end of each period, as in most loans
+    fn default() -> Self {
+        Self::End
+    }
+}
+
+/// Prices that drive price-based charts such as Renko.
+///
+/// The integer representation of this enum is determined by the enabled features:
+/// - With feature "i64": Uses i64 representation (extended precision)
+/// - With feature "i32": Uses i32 representation (standard precision)
+/// - With no features enabled: Defaults to i32
+///
+/// # Variants
+///
+/// * `Close` - Only the close of each bar is used
+/// * `HighLow` - Upward moves are measured with the high and downward moves with the low
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg(feature = "i64")]
+#[repr(i64)]
+pub enum PriceSource {
+    Close   = 0,
+    HighLow = 1,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg(not(feature = "i64"))]
+#[repr(i32)]
+pub enum PriceSource {
+    Close   = 0,
+    HighLow = 1,
+}
+
+impl PriceSource {
+    /// Returns the prices measuring an upward and a downward move of a bar.
+    #[must_use]
+    pub const fn prices(
+        self,
+        input_high: crate::TAFloat,
+        input_low: crate::TAFloat,
+        input_close: crate::TAFloat,
+    ) -> (crate::TAFloat, crate::TAFloat) {
+        match self {
+            Self::Close => (input_close, input_close),
+            Self::HighLow => (input_high, input_low),
+        }
+    }
+}
+
+impl Default for PriceSource {
+    /// Returns the default price source (Close).
+    ///
+    /// # Returns
+    /// * [`PriceSource::Close`] - Charts are built from closing prices
+    fn default() -> Self {
+        Self::Close
+    }
+}
+
+/// Rules that close a bar when aggregating trades into OHLCV bars.
+///
+/// The integer representation of this enum is determined by the enabled features:
+/// - With feature "i64": Uses i64 representation (extended precision)
+/// - With feature "i32": Uses i32 representation (standard precision)
+/// - With no features enabled: Defaults to i32
+///
+/// # Variants
+///
+/// * `Time` - Bars cover fixed, epoch-aligned time intervals
+/// * `Tick` - Bars close after a fixed number of trades
+/// * `Volume` - Bars close once the traded size reaches a threshold
+/// * `Dollar` - Bars close once the traded value (price * size) reaches a threshold
+/// * `TickImbalance` - Bars close once the signed tick count reaches a threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg(feature = "i64")]
+#[repr(i64)]
+pub enum BarType {
+    Time          = 0,
+    Tick          = 1,
+    Volume        = 2,
+    Dollar        = 3,
+    TickImbalance = 4,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[cfg(not(feature = "i64"))]
+#[repr(i32)]
+pub enum BarType {
+    Time          = 0,
+    Tick          = 1,
+    Volume        = 2,
+    Dollar        = 3,
+    TickImbalance = 4,
+}
+
+impl Default for BarType {
+    /// Returns the default bar type (Time).
+    ///
+    /// # Returns
+    /// * [`BarType::Time`] - Classic time bars, as expected by most indicators
+    fn default() -> Self {
+        Self::Time
+    }
+}
@@ -298,6 +298,181 @@ def adxr_inc(high, low, prev_high, prev_low, prev_close, prev_adx, prev_adx_peri
     """
     ...
 
+def align(timestamp, interval, htf_timestamp, htf_interval, htf_value):
+    """
+    Aligns higher time frame values onto lower time frame bars without look-ahead.
+
+    Each lower time frame bar receives the value of the latest higher time frame bar that had
+    closed by the end of the lower bar, NaN before the first one.
+
+    Args:
+        timestamp: Lower time frame bar start times as a 1-D NumPy array of type `int64`.
+        interval: Length of the lower time frame bars (must be > 0).
+        htf_timestamp: Higher time frame bar start times as a 1-D NumPy array of type `int64`.
+        htf_interval: Length of the higher time frame bars (must be > 0).
+        htf_value: Values computed on the higher time frame as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+        A 1-D NumPy array with one aligned value per lower time frame bar.
+
+    Examples:
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> ts = np.arange(12) * 60
+        >>> aligned = kand.align(ts, 60, np.array([0, 300, 600]), 300, np.array([1.0, 2.0, 3.0]))
+        ```
+    """
+    ...
+
+def alpha(asset, benchmark, period, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the rolling Jensen's Alpha (ALPHA) of an asset against a benchmark
+
+    Alpha is the annualized part of the asset's return that its beta to the benchmark does not
+    explain, after subtracting the risk-free rate.
+
+    Args:
+      asset: Per-period asset returns as a 1-D NumPy array of type `TAFloat`.
+      benchmark: Per-period benchmark returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window (must be >= 2).
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple of six 1-D NumPy arrays containing:
+      - ALPHA values
+      - BETA values
+      - Running sum of asset returns
+      - Running sum of benchmark returns
+      - Running sum of squared benchmark returns
+      - Running sum of asset * benchmark returns
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> asset = np.array([0.021, -0.039, 0.011, 0.031])
+      >>> benchmark = np.array([0.01, -0.02, 0.005, 0.015])
+      >>> alpha, beta, sum_a, sum_b, sum_bb, sum_ab = kand.alpha(asset, benchmark, 3)
+      ```
+    """
+    ...
+
+def alpha_inc(asset, benchmark, old_asset, old_benchmark, prev_sum_a, prev_sum_b, prev_sum_bb, prev_sum_ab, period, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the latest Jensen's Alpha value incrementally
+
+    Args:
+      py: Python interpreter token
+      asset: Current asset return
+      benchmark: Current benchmark return
+      old_asset: Asset return leaving the window
+      old_benchmark: Benchmark return leaving the window
+      prev_sum_a: Previous sum of asset returns
+      prev_sum_b: Previous sum of benchmark returns
+      prev_sum_bb: Previous sum of squared benchmark returns
+      prev_sum_ab: Previous sum of asset * benchmark returns
+      period: Number of returns in the rolling window (must be >= 2)
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple containing:
+      - Latest ALPHA value
+      - Latest BETA value
+      - New sum of asset returns
+      - New sum of benchmark returns
+      - New sum of squared benchmark returns
+      - New sum of asset * benchmark returns
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> alpha, beta, sum_a, sum_b, sum_bb, sum_ab = kand.alpha_inc(
+      ...     0.031, 0.015,  # new asset, benchmark returns
+      ...     0.021, 0.01,  # returns leaving the window
+      ...     -0.007, -0.005, 0.000525, 0.001045,  # previous sums
+      ...     3  # period
+      ... )
+      ```
+    """
+    ...
+
+def anchored_vwap(high, low, close, volume, anchor, dev=2.0):
+    """
+    Calculates anchored VWAP with standard deviation bands, starting from bar `anchor`.
+
+    Bars before the anchor are NaN in every output.
+
+    Args:
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+      anchor: Index of the bar where accumulation starts.
+      dev: Number of standard deviations for the bands. Defaults to 2.0.
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - VWAP values
+      - Upper band values
+      - Lower band values
+      - Cumulative volume-weighted squared deviations
+      - Cumulative volumes
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([12.0, 15.0, 14.0, 13.0])
+      >>> low = np.array([8.0, 11.0, 10.0, 9.0])
+      >>> close = np.array([10.0, 13.0, 12.0, 11.0])
+      >>> volume = np.array([100.0, 100.0, 100.0, 300.0])
+      >>> vwap, upper, lower, _, _ = kand.anchored_vwap(high, low, close, volume, 1)
+      ```
+    """
+    ...
+
+def apo(data, fast_period=12, slow_period=26, signal_period=9, fast_ma_type=5, slow_ma_type=5, signal_ma_type=5):
+    """
+    Computes the Absolute Price Oscillator (APO) over a NumPy array.
+
+    The fast, slow and signal lines each use their own moving average type:
+    - APO: Difference between the fast and slow moving averages of price, in price units
+    - Signal Line: MA of the APO line
+    - Histogram: Difference between APO line and signal line
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+      fast_period: Period for fast MA calculation (typically 12).
+      slow_period: Period for slow MA calculation (typically 26).
+      signal_period: Period for signal line calculation (typically 9).
+      fast_ma_type: Moving average type for the fast line: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA,
+        4=RMA, 5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+      slow_ma_type: Moving average type for the slow line. Defaults to 5 (SMA).
+      signal_ma_type: Moving average type for the signal line. Defaults to 5 (SMA).
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - APO values
+      - Signal line values
+      - Histogram values
+      - Fast MA values
+      - Slow MA values
+      Each array has the same length as the input, with initial elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+      >>> apo, signal, histogram, fast_ma, slow_ma = kand.apo(data, 2, 3, 2)
+      ```
+    """
+    ...
+
 def aroon(high, low, period):
     """
     Calculate Aroon indicator for a NumPy array.
@@ -335,6 +510,11 @@ def aroon_inc(high, low, prev_high, prev_low, days_since_high, days_since_low, p
     """
     Calculate the next Aroon values incrementally.
 
+    Deprecated:
+      The days since the period high/low cannot be tracked exactly from the previous values once
+      the extreme leaves the window. Use the `Aroon` streaming object, which keeps the rolling
+      windows between calls.
+
     Args:
 
       high: Current period's high price.
@@ -406,6 +586,11 @@ def aroonosc_inc(high, low, prev_high, prev_low, days_since_high, days_since_low
     """
     Calculate the next Aroon Oscillator value incrementally.
 
+    Deprecated:
+      The days since the period high/low cannot be tracked exactly from the previous values once
+      the extreme leaves the window. Use the `Aroonosc` streaming object, which keeps the
+      rolling windows between calls.
+
     Args:
 
       high: Current period's high price.
@@ -498,12 +683,47 @@ def atr_inc(high, low, prev_close, prev_atr, period):
     """
     ...
 
-def bbands(price, period, dev_up, dev_down):
+def bandwidth(price, upper, middle, lower):
+    """
+    Calculates %B and bandwidth for any price band.
+
+    %B locates the price within the band (0 on the lower band, 1 on the upper band) and
+    bandwidth is the band width relative to the middle line. Works with the output of
+    `bbands`, `keltner`, `donchian` or `starc`.
+
+    Args:
+      price: Prices as a 1-D NumPy array of type `TAFloat`, usually the close.
+      upper: Upper band values as a 1-D NumPy array of type `TAFloat`.
+      middle: Middle band values as a 1-D NumPy array of type `TAFloat`.
+      lower: Lower band values as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A tuple of two 1-D NumPy arrays containing:
+      - %B values
+      - Bandwidth values
+      NaN band values give NaN outputs.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> price = np.array([12.0, 7.0])
+      >>> upper = np.array([12.0, 12.0])
+      >>> middle = np.array([10.0, 10.0])
+      >>> lower = np.array([8.0, 8.0])
+      >>> percent_b, bandwidth = kand.bandwidth(price, upper, middle, lower)
+      >>> print(percent_b)
+      [ 1.   -0.25]
+      ```
+    """
+    ...
+
+def bbands(price, period, dev_up, dev_down, ma_type=5):
     """
     Calculate Bollinger Bands for a NumPy array.
 
     Bollinger Bands consist of:
-    - A middle band (N-period simple moving average)
+    - A middle band (N-period moving average, simple by default)
     - An upper band (K standard deviations above middle band)
     - A lower band (K standard deviations below middle band)
 
@@ -512,6 +732,8 @@ def bbands(price, period, dev_up, dev_down):
       period: The time period for calculations (must be >= 2).
       dev_up: Number of standard deviations for upper band.
       dev_down: Number of standard deviations for lower band.
+      ma_type: Moving average type for the middle band: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA, 4=RMA,
+        5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
 
     Returns:
       A tuple of 7 1-D NumPy arrays containing:
@@ -522,7 +744,7 @@ def bbands(price, period, dev_up, dev_down):
       - Variance values
       - Sum values
       - Sum of squares values
-      The first (period-1) elements of each array contain NaN values.
+      The lookback elements of each array contain NaN values.
 
     Examples:
       ```python
@@ -575,6 +797,75 @@ def bbands_inc(price, prev_sma, prev_sum, prev_sum_sq, old_price, period, dev_up
     """
     ...
 
+def beta(asset, benchmark, period):
+    """
+    Calculate the rolling Beta (BETA) of an asset against a benchmark
+
+    Beta is the covariance of asset and benchmark returns divided by the variance of the
+    benchmark returns, i.e. the hedge ratio against the benchmark.
+
+    Args:
+      asset: Per-period asset returns as a 1-D NumPy array of type `TAFloat`.
+      benchmark: Per-period benchmark returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window (must be >= 2).
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - BETA values
+      - Running sum of asset returns
+      - Running sum of benchmark returns
+      - Running sum of squared benchmark returns
+      - Running sum of asset * benchmark returns
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> asset = np.array([0.02, -0.04, 0.01, 0.03])
+      >>> benchmark = np.array([0.01, -0.02, 0.005, 0.015])
+      >>> beta, sum_a, sum_b, sum_bb, sum_ab = kand.beta(asset, benchmark, 3)
+      ```
+    """
+    ...
+
+def beta_inc(asset, benchmark, old_asset, old_benchmark, prev_sum_a, prev_sum_b, prev_sum_bb, prev_sum_ab, period):
+    """
+    Calculate the latest Beta value incrementally
+
+    Args:
+      py: Python interpreter token
+      asset: Current asset return
+      benchmark: Current benchmark return
+      old_asset: Asset return leaving the window
+      old_benchmark: Benchmark return leaving the window
+      prev_sum_a: Previous sum of asset returns
+      prev_sum_b: Previous sum of benchmark returns
+      prev_sum_bb: Previous sum of squared benchmark returns
+      prev_sum_ab: Previous sum of asset * benchmark returns
+      period: Number of returns in the rolling window (must be >= 2)
+
+    Returns:
+      A tuple containing:
+      - Latest BETA value
+      - New sum of asset returns
+      - New sum of benchmark returns
+      - New sum of squared benchmark returns
+      - New sum of asset * benchmark returns
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> beta, sum_a, sum_b, sum_bb, sum_ab = kand.beta_inc(
+      ...     0.03, 0.015,  # new asset, benchmark returns
+      ...     0.02, 0.01,  # returns leaving the window
+      ...     -0.01, -0.005, 0.000525, 0.00105,  # previous sums
+      ...     3  # period
+      ... )
+      ```
+    """
+    ...
+
 def bop(open, high, low, close):
     """
     Calculate Balance of Power (BOP) indicator for NumPy arrays.
@@ -626,6 +917,126 @@ def bop_inc(open, high, low, close):
     """
     ...
 
+def calmar(prices, period, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the rolling Calmar Ratio for a NumPy array
+
+    The annualized return over the last `period` prices, minus the risk-free rate, is divided
+    by the absolute maximum drawdown within the same prices. The ratio is 0 when there is no
+    drawdown.
+
+    Args:
+      prices: Input prices or equity values as a 1-D NumPy array of type `TAFloat`.
+      period: Number of prices in the rolling window (must be >= 2).
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Calmar Ratio values
+      - Drawdown values
+      - Maximum drawdown values
+      Each array has the same length as the input, with the first (period-1) values
+      containing NaN.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([100.0, 120.0, 90.0, 108.0, 110.0])
+      >>> calmar, dd, mdd = kand.calmar(prices, 3, 2.0)
+      ```
+    """
+    ...
+
+def calmar_expanding(prices, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the expanding Calmar Ratio for a NumPy array
+
+    Uses the compound annual growth rate since the first price and the maximum drawdown since
+    the start of the series.
+
+    Args:
+      prices: Input prices or equity values as a 1-D NumPy array of type `TAFloat`.
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple of four 1-D NumPy arrays containing:
+      - Calmar Ratio values, NaN for the first element
+      - Drawdown values
+      - Maximum drawdown values
+      - Running peak values
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([100.0, 80.0, 121.0])
+      >>> calmar, dd, mdd, peak = kand.calmar_expanding(prices, 1.0)
+      ```
+    """
+    ...
+
+def calmar_expanding_inc(price, first_price, periods, prev_peak, prev_max_drawdown, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the latest expanding Calmar Ratio incrementally
+
+    Args:
+      py: Python interpreter token
+      price: Current period's price
+      first_price: First price of the series
+      periods: Number of bars between the first and the current price
+      prev_peak: Previous running peak, or the first price of the series
+      prev_max_drawdown: Previous maximum drawdown, 0 for the first price
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple containing:
+      - Latest Calmar Ratio value, NaN for the first price
+      - Latest drawdown value
+      - Latest maximum drawdown value
+      - New running peak
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> calmar, dd, mdd, peak = kand.calmar_expanding_inc(121.0, 100.0, 2, 100.0, -0.2, 1.0)
+      ```
+    """
+    ...
+
+def calmar_inc(price, prev_prices, period, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the latest rolling Calmar Ratio incrementally
+
+    The trailing prices that complete the window are passed in.
+
+    Args:
+      py: Python interpreter token
+      price: Current period's price
+      prev_prices: Prices preceding `price` as a 1-D NumPy array. At least `period - 1` values
+        are required; only the last `period - 1` are used.
+      period: Number of prices in the rolling window (must be >= 2)
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple containing:
+      - Latest Calmar Ratio value
+      - Latest drawdown value
+      - Latest maximum drawdown value
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> calmar, dd, mdd = kand.calmar_inc(110.0, np.array([100.0, 120.0, 90.0, 108.0]), 3, 2.0)
+      ```
+    """
+    ...
+
 def cci(high, low, close, period):
     """
     Computes the Commodity Channel Index (CCI) over NumPy arrays.
@@ -1106,81 +1517,411 @@ def cdl_marubozu_inc(open, high, low, close, prev_body_avg, period, shadow_perce
     """
     ...
 
-def dema(input_price, period):
+def cmf(high, low, close, volume, period=20):
     """
-    Calculates Double Exponential Moving Average (DEMA) over NumPy arrays.
+    Computes Chaikin Money Flow (CMF) over NumPy arrays.
+
+    CMF is the volume-weighted average of the Money Flow Multiplier over a rolling window.
+    Positive values indicate accumulation, negative values distribution.
 
     Args:
-      input_price: Price values as a 1-D NumPy array of type `TAFloat`.
-      period: Smoothing period for EMA calculations. Must be >= 2.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+      period: Summation period (must be >= 2). Defaults to 20.
 
     Returns:
-      A tuple of 1-D NumPy arrays containing:
-      - DEMA values
-      - First EMA values
-      - Second EMA values
-      Each array has the same length as the input, with the first `2*(period-1)` elements containing NaN values.
+      A tuple of three 1-D NumPy arrays containing:
+      - CMF values
+      - Rolling sum of money flow volume
+      - Rolling sum of volume
+      Each array has the same length as the input, with the first `period-1` elements
+      containing NaN values.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> prices = np.array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0])
-      >>> dema, ema1, ema2 = kand.dema(prices, 3)
+      >>> high = np.array([12.0, 12.0, 12.0])
+      >>> low = np.array([8.0, 8.0, 8.0])
+      >>> close = np.array([11.0, 9.0, 12.0])
+      >>> volume = np.array([100.0, 100.0, 200.0])
+      >>> cmf, mfv_sum, volume_sum = kand.cmf(high, low, close, volume, 2)
+      >>> print(cmf)
+      [nan 0.  0.5]
       ```
     """
     ...
 
-def dema_inc(price, prev_ema1, prev_ema2, period):
+def cmf_inc(high, low, close, volume, old_high, old_low, old_close, old_volume, prev_mfv_sum, prev_volume_sum):
     """
-    Calculates the next DEMA value incrementally.
+    Calculates the next CMF value incrementally.
 
     Args:
-
-      price: Current price value.
-      prev_ema1: Previous value of first EMA.
-      prev_ema2: Previous value of second EMA.
-      period: Smoothing period. Must be >= 2.
+      high: Current high price.
+      low: Current low price.
+      close: Current close price.
+      volume: Current volume.
+      old_high: High of the bar leaving the window, `period` bars back.
+      old_low: Low of the bar leaving the window.
+      old_close: Close of the bar leaving the window.
+      old_volume: Volume of the bar leaving the window.
+      prev_mfv_sum: Previous rolling sum of money flow volume.
+      prev_volume_sum: Previous rolling sum of volume.
 
     Returns:
-      A tuple containing (DEMA, new_ema1, new_ema2).
+      A tuple of (cmf, mfv_sum, volume_sum).
 
     Examples:
       ```python
       >>> import kand
-      >>> dema, ema1, ema2 = kand.dema_inc(10.0, 9.5, 9.0, 3)
+      >>> cmf, mfv_sum, volume_sum = kand.cmf_inc(
+      ...     12.0, 8.0, 12.0, 200.0, 12.0, 8.0, 11.0, 100.0, 0.0, 200.0
+      ... )
+      >>> print(cmf, mfv_sum, volume_sum)
+      0.5 150.0 300.0
       ```
     """
     ...
 
-def dx(high, low, close, period):
+def cmo(prices, period):
     """
-    Computes the Directional Movement Index (DX) over NumPy arrays.
+    Computes the Chande Momentum Oscillator (CMO) over NumPy arrays.
 
-    The DX indicator measures the strength of a trend by comparing positive and negative directional movements.
+    The CMO is a momentum oscillator that compares the Wilder-smoothed gains and losses of recent
+    price changes. It ranges from -100 to +100, with +50 and -50 as common overbought and oversold
+    levels.
 
     Args:
-      high: High prices as a 1-D NumPy array of type `TAFloat`.
-      low: Low prices as a 1-D NumPy array of type `TAFloat`.
-      close: Close prices as a 1-D NumPy array of type `TAFloat`.
-      period: Window size for DX calculation. Must be positive and less than input length.
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Window size for CMO calculation. Must be positive and less than input length.
 
     Returns:
-      A tuple of four 1-D NumPy arrays containing:
-      - DX values
-      - Smoothed +DM values
-      - Smoothed -DM values
-      - Smoothed TR values
+      A tuple of three 1-D NumPy arrays containing:
+      - CMO values
+      - Average gain values
+      - Average loss values
       Each array has the same length as the input, with the first `period` elements containing NaN values.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> high = np.array([24.20, 24.07, 24.04, 23.87, 23.67])
-      >>> low = np.array([23.85, 23.72, 23.64, 23.37, 23.46])
-      >>> close = np.array([23.89, 23.95, 23.67, 23.78, 23.50])
-      >>> dx, plus_dm, minus_dm, tr = kand.dx(high, low, close, 3)
+      >>> prices = np.array([44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42])
+      >>> cmo, avg_gain, avg_loss = kand.cmo(prices, 5)
+      ```
+    """
+    ...
+
+def cmo_inc(current_price, prev_price, prev_avg_gain, prev_avg_loss, period):
+    """
+    Calculates a single CMO value incrementally.
+
+    This function provides an optimized way to calculate the latest CMO value
+    when streaming data is available, without needing the full price history.
+
+    Args:
+      current_price: The current period's price value.
+      prev_price: The previous period's price value.
+      prev_avg_gain: The previous period's average gain.
+      prev_avg_loss: The previous period's average loss.
+      period: The time period for CMO calculation.
+
+    Returns:
+      A tuple containing (CMO value, new average gain, new average loss).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> cmo, avg_gain, avg_loss = kand.cmo_inc(45.42, 45.10, 0.24, 0.14, 14)
+      ```
+    """
+    ...
+
+def correl(x, y, period):
+    """
+    Calculate the rolling Pearson Correlation Coefficient (CORREL) of two NumPy arrays
+
+    CORREL measures how linearly related two series are over a rolling window, ranging from -1
+    to 1. The result is 0 when either series is constant over the window.
+
+    Args:
+      x: First input series as a 1-D NumPy array of type `TAFloat`.
+      y: Second input series as a 1-D NumPy array of type `TAFloat`.
+      period: Period for CORREL calculation (must be >= 2).
+
+    Returns:
+      A tuple of six 1-D NumPy arrays containing:
+      - CORREL values
+      - Running sum of x
+      - Running sum of y
+      - Running sum of x^2
+      - Running sum of y^2
+      - Running sum of x*y
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> x = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+      >>> y = np.array([2.0, 4.0, 5.0, 8.0, 9.0])
+      >>> correl, sum_x, sum_y, sum_xx, sum_yy, sum_xy = kand.correl(x, y, 3)
+      ```
+    """
+    ...
+
+def correl_inc(x, y, old_x, old_y, prev_sum_x, prev_sum_y, prev_sum_xx, prev_sum_yy, prev_sum_xy, period):
+    """
+    Calculate the latest Pearson Correlation value incrementally
+
+    Args:
+      py: Python interpreter token
+      x: Current value of the first series
+      y: Current value of the second series
+      old_x: Value of the first series leaving the window
+      old_y: Value of the second series leaving the window
+      prev_sum_x: Previous sum of x
+      prev_sum_y: Previous sum of y
+      prev_sum_xx: Previous sum of x^2
+      prev_sum_yy: Previous sum of y^2
+      prev_sum_xy: Previous sum of x*y
+      period: Period for CORREL calculation (must be >= 2)
+
+    Returns:
+      A tuple containing:
+      - Latest CORREL value
+      - New sum of x
+      - New sum of y
+      - New sum of x^2
+      - New sum of y^2
+      - New sum of x*y
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> correl, sum_x, sum_y, sum_xx, sum_yy, sum_xy = kand.correl_inc(
+      ...     4.0, 8.0,  # new x, y
+      ...     1.0, 2.0,  # x, y leaving the window
+      ...     6.0, 12.0, 14.0, 56.0, 28.0,  # previous sums
+      ...     3  # period
+      ... )
+      ```
+    """
+    ...
+
+def dema(input_price, period):
+    """
+    Calculates Double Exponential Moving Average (DEMA) over NumPy arrays.
+
+    Args:
+      input_price: Price values as a 1-D NumPy array of type `TAFloat`.
+      period: Smoothing period for EMA calculations. Must be >= 2.
+
+    Returns:
+      A tuple of 1-D NumPy arrays containing:
+      - DEMA values
+      - First EMA values
+      - Second EMA values
+      Each array has the same length as the input, with the first `2*(period-1)` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0])
+      >>> dema, ema1, ema2 = kand.dema(prices, 3)
+      ```
+    """
+    ...
+
+def dema_inc(price, prev_ema1, prev_ema2, period):
+    """
+    Calculates the next DEMA value incrementally.
+
+    Args:
+
+      price: Current price value.
+      prev_ema1: Previous value of first EMA.
+      prev_ema2: Previous value of second EMA.
+      period: Smoothing period. Must be >= 2.
+
+    Returns:
+      A tuple containing (DEMA, new_ema1, new_ema2).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> dema, ema1, ema2 = kand.dema_inc(10.0, 9.5, 9.0, 3)
+      ```
+    """
+    ...
+
+def donchian(high, low, close, period=20):
+    """
+    Computes Donchian Channels over NumPy arrays.
+
+    The upper and lower bands are the highest high and lowest low of the last `period` bars,
+    and the middle line is their average.
+
+    Args:
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      period: Window size (must be >= 2). Defaults to 20.
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - Upper band values
+      - Middle line values
+      - Lower band values
+      - %B values
+      - Bandwidth values
+      Each array has the same length as the input, with the first `period-1` elements
+      containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 12.0, 11.0, 13.0])
+      >>> low = np.array([8.0, 9.0, 9.5, 10.0])
+      >>> close = np.array([9.0, 11.0, 10.0, 12.5])
+      >>> upper, middle, lower, percent_b, bandwidth = kand.donchian(high, low, close, 2)
+      ```
+    """
+    ...
+
+def drawdown(prices, period):
+    """
+    Calculate the rolling Drawdown and Maximum Drawdown for a NumPy array
+
+    The drawdown is the decline of the price from its highest value of the last `period` bars,
+    and the maximum drawdown is the deepest decline within those same `period` bars.
+
+    Args:
+      prices: Input prices or equity values as a 1-D NumPy array of type `TAFloat`.
+      period: Number of prices in the rolling window (must be >= 2).
+
+    Returns:
+      A tuple of two 1-D NumPy arrays containing:
+      - Drawdown values, NaN for the first (period-1) elements
+      - Maximum drawdown values, NaN for the first (period-1) elements
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([100.0, 120.0, 90.0, 108.0, 110.0])
+      >>> dd, mdd = kand.drawdown(prices, 3)
+      ```
+    """
+    ...
+
+def drawdown_expanding(prices):
+    """
+    Calculate the expanding Drawdown and Maximum Drawdown for a NumPy array
+
+    Args:
+      prices: Input prices or equity values as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Drawdown values from the running peak
+      - Maximum drawdown values since the start
+      - Running peak values
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([100.0, 120.0, 90.0, 130.0])
+      >>> dd, mdd, peak = kand.drawdown_expanding(prices)
+      ```
+    """
+    ...
+
+def drawdown_expanding_inc(price, prev_peak, prev_max_drawdown):
+    """
+    Calculate the latest expanding Drawdown and Maximum Drawdown incrementally
+
+    Args:
+      py: Python interpreter token
+      price: Current period's price
+      prev_peak: Previous running peak, or the first price of the series
+      prev_max_drawdown: Previous maximum drawdown, 0 for the first price
+
+    Returns:
+      A tuple containing:
+      - Latest drawdown value
+      - Latest maximum drawdown value
+      - New running peak
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> dd, mdd, peak = kand.drawdown_expanding_inc(90.0, 120.0, -0.1)
+      ```
+    """
+    ...
+
+def drawdown_inc(price, prev_prices, period):
+    """
+    Calculate the latest rolling Drawdown and Maximum Drawdown incrementally
+
+    The maximum drawdown cannot be rebuilt from the previous outputs, so the trailing prices
+    that complete the window are passed in.
+
+    Args:
+      py: Python interpreter token
+      price: Current period's price
+      prev_prices: Prices preceding `price` as a 1-D NumPy array. At least `period - 1` values
+        are required; only the last `period - 1` are used.
+      period: Number of prices in the rolling window (must be >= 2)
+
+    Returns:
+      A tuple containing:
+      - Latest drawdown value
+      - Latest maximum drawdown value
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> dd, mdd = kand.drawdown_inc(110.0, np.array([100.0, 120.0, 90.0, 108.0]), 3)
+      ```
+    """
+    ...
+
+def dx(high, low, close, period):
+    """
+    Computes the Directional Movement Index (DX) over NumPy arrays.
+
+    The DX indicator measures the strength of a trend by comparing positive and negative directional movements.
+
+    Args:
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      period: Window size for DX calculation. Must be positive and less than input length.
+
+    Returns:
+      A tuple of four 1-D NumPy arrays containing:
+      - DX values
+      - Smoothed +DM values
+      - Smoothed -DM values
+      - Smoothed TR values
+      Each array has the same length as the input, with the first `period` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([24.20, 24.07, 24.04, 23.87, 23.67])
+      >>> low = np.array([23.85, 23.72, 23.64, 23.37, 23.46])
+      >>> close = np.array([23.89, 23.95, 23.67, 23.78, 23.50])
+      >>> dx, plus_dm, minus_dm, tr = kand.dx(high, low, close, 3)
       ```
     """
     ...
@@ -1337,735 +2078,2193 @@ def ema_inc(price, prev_ema, period, k=None):
     """
     ...
 
-def macd(data, fast_period, slow_period, signal_period):
+def emv(high, low, volume, period=14, divisor=100_000_000.0):
     """
-    Computes the Moving Average Convergence Divergence (MACD) over a NumPy array.
+    Computes Arms' Ease of Movement (EMV) over NumPy arrays.
 
-    MACD is a trend-following momentum indicator that shows the relationship between two moving averages
-    of an asset's price. It consists of three components:
-    - MACD Line: Difference between fast and slow EMAs
-    - Signal Line: EMA of the MACD line
-    - Histogram: Difference between MACD line and signal line
+    EMV relates the move of the bar midpoint to the volume needed to make it and smooths the
+    single-bar values with an SMA.
 
     Args:
-      data: Input price data as a 1-D NumPy array of type `TAFloat`.
-      fast_period: Period for fast EMA calculation (typically 12).
-      slow_period: Period for slow EMA calculation (typically 26).
-      signal_period: Period for signal line calculation (typically 9).
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+      period: SMA smoothing period (must be >= 2). Defaults to 14.
+      divisor: Volume scale divisor (must be positive). Defaults to 100,000,000.
 
     Returns:
-      A tuple of five 1-D NumPy arrays containing:
-      - MACD line values
-      - Signal line values
-      - MACD histogram values
-      - Fast EMA values
-      - Slow EMA values
-      Each array has the same length as the input, with initial elements containing NaN values.
+      A tuple of two 1-D NumPy arrays containing:
+      - Smoothed EMV values, NaN for the first `period` elements
+      - Single-bar EMV values, NaN for the first element
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> data = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
-      >>> macd_line, signal_line, histogram, fast_ema, slow_ema = kand.macd(data, 2, 3, 2)
+      >>> high = np.array([10.0, 12.0, 13.0])
+      >>> low = np.array([8.0, 10.0, 9.0])
+      >>> volume = np.array([100.0, 200.0, 400.0])
+      >>> emv, emv_raw = kand.emv(high, low, volume, 2, 100.0)
+      >>> print(emv)
+      [nan nan  1.]
       ```
     """
     ...
 
-def macd_inc(price, prev_fast_ema, prev_slow_ema, prev_signal, fast_period, slow_period, signal_period):
+def emv_inc(high, low, volume, prev_high, prev_low, old_emv_raw, prev_emv, period, divisor):
     """
-    Computes the latest MACD values incrementally from previous state.
-
-    This function provides an efficient way to calculate MACD for streaming data by using
-    previous EMA values instead of recalculating the entire series.
+    Calculates the next EMV value incrementally.
 
     Args:
-
-      price: Current price value as `TAFloat`.
-      prev_fast_ema: Previous fast EMA value as `TAFloat`.
-      prev_slow_ema: Previous slow EMA value as `TAFloat`.
-      prev_signal: Previous signal line value as `TAFloat`.
-      fast_period: Period for fast EMA calculation (typically 12).
-      slow_period: Period for slow EMA calculation (typically 26).
-      signal_period: Period for signal line calculation (typically 9).
+      high: Current high price.
+      low: Current low price.
+      volume: Current volume.
+      prev_high: Previous high price.
+      prev_low: Previous low price.
+      old_emv_raw: Single-bar EMV leaving the SMA window, `period` bars back.
+      prev_emv: Previous smoothed EMV value.
+      period: SMA smoothing period (must be >= 2).
+      divisor: Volume scale divisor (must be positive).
 
     Returns:
-      A tuple of three values:
-      - MACD line value
-      - Signal line value
-      - MACD histogram value
+      A tuple of (emv, emv_raw).
 
     Examples:
       ```python
       >>> import kand
-      >>> macd_line, signal_line, histogram = kand.macd_inc(
-      ...     100.0,  # current price
-      ...     95.0,   # previous fast EMA
-      ...     98.0,   # previous slow EMA
-      ...     -2.5,   # previous signal
-      ...     12,     # fast period
-      ...     26,     # slow period
-      ...     9       # signal period
-      ... )
+      >>> kand.emv_inc(13.0, 9.0, 400.0, 12.0, 10.0, 2.0, 2.0, 2, 100.0)
+      (1.0, 0.0)
       ```
     """
     ...
 
-def max(prices, period):
+def force_index(close, volume, period=13):
     """
-    Calculate Maximum Value for a NumPy array
+    Computes Elder's Force Index over NumPy arrays.
+
+    The Force Index is the EMA of each close-to-close change multiplied by its volume.
 
     Args:
-      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
-      period: Period for MAX calculation (must be >= 2).
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+      period: EMA smoothing period (must be >= 2). Defaults to 13.
 
     Returns:
-      A 1-D NumPy array containing MAX values. The first (period-1) elements contain NaN values.
+      A new 1-D NumPy array containing the Force Index values. The array has the same length
+      as the input, with the first `period` elements containing NaN values.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> prices = np.array([1.0, 2.0, 3.0, 2.5, 4.0])
-      >>> max_values = kand.max(prices, 3)
+      >>> close = np.array([10.0, 11.0, 10.5, 12.0])
+      >>> volume = np.array([100.0, 200.0, 100.0, 150.0])
+      >>> result = kand.force_index(close, volume, 2)
+      >>> print(result)
+      [ nan  nan  75. 175.]
       ```
     """
     ...
 
-def max_inc(price, prev_max, old_price, period):
+def force_index_inc(close, prev_close, volume, prev_force_index, period):
     """
-    Calculate the latest Maximum Value incrementally
+    Calculates the next Force Index value incrementally.
 
     Args:
-      py: Python interpreter token
-      price: Current period's price
-      prev_max: Previous period's MAX value
-      old_price: Price being removed from the period
-      period: Period for MAX calculation (must be >= 2)
+      close: Current close price.
+      prev_close: Previous close price.
+      volume: Current volume.
+      prev_force_index: Previous Force Index value.
+      period: EMA smoothing period (must be >= 2).
 
     Returns:
-      The new MAX value
+      The new Force Index value.
 
     Examples:
       ```python
       >>> import kand
-      >>> new_max = kand.max_inc(10.5, 11.0, 9.0, 14)
+      >>> kand.force_index_inc(12.0, 10.5, 150.0, 75.0, 2)
+      175.0
       ```
     """
     ...
 
-def medprice(high, low):
+def fv(rate, nper, pmt, pv, when=0):
     """
-    Calculates the Median Price (MEDPRICE) for a NumPy array.
+    Calculate the Future Value (FV) element-wise over NumPy arrays
 
-    The Median Price is a technical analysis indicator that represents the middle point between
-    high and low prices for each period.
+    FV is the value at the end of `nper` periods of a present amount plus a stream of constant
+    payments. Cash paid out is negative and cash received is positive.
 
     Args:
-      high: Array of high prices as a 1-D NumPy array of type `TAFloat`.
-      low: Array of low prices as a 1-D NumPy array of type `TAFloat`.
+      rate: Interest rate per period as a 1-D NumPy array of type `TAFloat`.
+      nper: Number of compounding periods as a 1-D NumPy array of type `TAFloat`.
+      pmt: Payment made each period as a 1-D NumPy array of type `TAFloat`.
+      pv: Present value as a 1-D NumPy array of type `TAFloat`.
+      when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
 
     Returns:
-      A 1-D NumPy array containing the median price values.
+      A 1-D NumPy array of future values with the same length as the inputs.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> high = np.array([10.0, 11.0, 12.0])
-      >>> low = np.array([8.0, 9.0, 10.0])
-      >>> result = kand.medprice(high, low)
-      >>> print(result)
-      [9.0, 10.0, 11.0]
+      >>> rate = np.array([0.05 / 12])
+      >>> nper = np.array([120.0])
+      >>> pmt = np.array([-100.0])
+      >>> pv = np.array([-100.0])
+      >>> kand.fv(rate, nper, pmt, pv)
+      array([15692.92889434])
       ```
     """
     ...
 
-def medprice_inc(high, low):
+def fv_inc(rate, nper, pmt, pv, when=0):
     """
-    Calculates a single Median Price value incrementally.
+    Calculate a single Future Value (FV)
 
     Args:
-
-      high: Current period's high price as `TAFloat`.
-      low: Current period's low price as `TAFloat`.
+      py: Python interpreter token
+      rate: Interest rate per period
+      nper: Number of compounding periods
+      pmt: Payment made each period
+      pv: Present value
+      when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
 
     Returns:
-      The calculated median price value.
+      The future value.
 
     Examples:
       ```python
       >>> import kand
-      >>> result = kand.medprice_inc(10.0, 8.0)
-      >>> print(result)
-      9.0
+      >>> kand.fv_inc(0.0, 10.0, -100.0, -1000.0)
+      2000.0
       ```
     """
     ...
 
-def mfi(high, low, close, volume, period):
+def ichimoku(high, low, close, tenkan_period=9, kijun_period=26, senkou_b_period=52, displacement=26):
     """
-    Calculates the Money Flow Index (MFI) for a NumPy array.
+    Calculates Ichimoku Kinko Hyo for a price series.
 
-    The Money Flow Index (MFI) is a technical oscillator that uses price and volume data to identify
-    overbought or oversold conditions in an asset.
+    The Senkou spans are `displacement` elements longer than the input: element `i` is the
+    cloud plotted at bar `i`, and the trailing elements hold the cloud projected past the
+    last bar. The Chikou span at bar `i` is the close of bar `i + displacement`, NaN for the
+    last `displacement` bars.
 
     Args:
-      high: Array of high prices as a 1-D NumPy array of type `TAFloat`.
-      low: Array of low prices as a 1-D NumPy array of type `TAFloat`.
-      close: Array of close prices as a 1-D NumPy array of type `TAFloat`.
-      volume: Array of volume data as a 1-D NumPy array of type `TAFloat`.
-      period: The time period for MFI calculation (typically 14).
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      tenkan_period: Tenkan-sen period (must be >= 2). Defaults to 9.
+      kijun_period: Kijun-sen period (must be >= 2). Defaults to 26.
+      senkou_b_period: Senkou Span B period (must be >= 2). Defaults to 52.
+      displacement: Forward shift of the cloud and backward shift of the Chikou span.
+        Defaults to 26.
 
     Returns:
       A tuple of five 1-D NumPy arrays containing:
-      - MFI values (0-100)
-      - Typical prices
-      - Money flows
-      - Positive money flows
-      - Negative money flows
+      - Tenkan-sen values
+      - Kijun-sen values
+      - Senkou Span A values (`displacement` longer than the input)
+      - Senkou Span B values (`displacement` longer than the input)
+      - Chikou Span values
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> high = np.array([10.0, 11.0, 12.0, 11.0])
-      >>> low = np.array([8.0, 9.0, 10.0, 9.0])
-      >>> close = np.array([9.0, 10.0, 11.0, 10.0])
-      >>> volume = np.array([100.0, 150.0, 200.0, 150.0])
-      >>> mfi, typ_prices, money_flows, pos_flows, neg_flows = kand.mfi(high, low, close, volume, 2)
+      >>> high = np.array([10.0, 11.0, 12.0, 13.0, 12.0, 11.0])
+      >>> low = np.array([8.0, 9.0, 10.0, 11.0, 10.0, 9.0])
+      >>> close = np.array([9.0, 10.0, 11.0, 12.0, 11.0, 10.0])
+      >>> tenkan, kijun, senkou_a, senkou_b, chikou = kand.ichimoku(high, low, close, 2, 3, 4, 2)
+      >>> len(senkou_a)
+      8
       ```
     """
     ...
 
-def midpoint(data, period):
+def irr(cashflows, guess=0.1, tol=1e-12, max_iter=100):
     """
-    Calculates Midpoint values for a NumPy array.
+    Calculate the Internal Rate of Return (IRR) of a series of periodic cash flows
 
-    The Midpoint is a technical indicator that represents the arithmetic mean of the highest and lowest
-    prices over a specified period.
+    IRR is the per-period discount rate at which the net present value of the cash flows is zero.
+    The first cash flow is at period 0, usually the negative initial investment. The rate is
+    solved with Newton's method, falling back to bracketing and bisection when Newton fails.
 
     Args:
-      data: Input price data as a 1-D NumPy array of type `TAFloat`.
-      period: Time period for calculation (must be >= 2).
+      cashflows: Cash flows as a 1-D NumPy array of type `TAFloat`, one per period.
+      guess: Starting rate for Newton's method. Defaults to 0.1.
+      tol: Convergence tolerance (must be > 0). Defaults to 1e-12.
+      max_iter: Maximum number of Newton iterations (must be > 0). Defaults to 100.
 
     Returns:
-      A tuple of three 1-D NumPy arrays containing:
-      - Midpoint values
-      - Highest values for each period
-      - Lowest values for each period
-      Each array has the same length as the input, with initial elements containing NaN values.
+      The internal rate of return per period.
+
+    Raises:
+      ValueError: If the cash flows do not change sign or the solver does not converge.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> data = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
-      >>> midpoint, highest, lowest = kand.midpoint(data, 3)
+      >>> cashflows = np.array([-100.0, 39.0, 59.0, 55.0, 20.0])
+      >>> round(kand.irr(cashflows), 4)
+      0.2809
       ```
     """
     ...
 
-def midpoint_inc(price, prev_highest, prev_lowest, period):
+def kagi(high, low, close, reversal, source=0):
     """
-    Calculates the next Midpoint value incrementally.
+    Builds a Kagi chart from NumPy arrays.
 
-    Provides an optimized way to calculate the next Midpoint value when new data arrives,
-    without recalculating the entire series.
+    A line extends while price keeps moving its way and turns once price moves back by the
+    reversal amount. Rising lines turn thick (yang) above the previous shoulder and falling
+    lines turn thin (yin) below the previous waist.
 
     Args:
-      price: Current price value as `TAFloat`.
-      prev_highest: Previous highest value as `TAFloat`.
-      prev_lowest: Previous lowest value as `TAFloat`.
-      period: Time period for calculation (must be >= 2).
+        high: High prices as a 1-D NumPy array of type `TAFloat`.
+        low: Low prices as a 1-D NumPy array of type `TAFloat`.
+        close: Close prices as a 1-D NumPy array of type `TAFloat`.
+        reversal: Price move against a line needed to turn it (must be > 0).
+        source: 0 to measure moves on closes, 1 on highs and lows. Defaults to 0.
+
+    Returns:
+        A tuple of six 1-D NumPy arrays with one entry per line, the last line still forming:
+        - Line start prices
+        - Line end prices
+        - Line directions (1 up, -1 down)
+        - Line thickness (1 yang, -1 yin)
+        - Index of the bar where each line starts
+        - Index of the bar where each line ends
+
+    Examples:
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> close = np.array([10.0, 12.0, 13.0, 11.5, 10.5, 11.0, 14.0])
+        >>> starts, ends, directions, thickness, start_idx, end_idx = kand.kagi(close, close, close, 2.0)
+        ```
+    """
+    ...
+
+def kama(prices, period):
+    """
+    Calculate Kaufman Adaptive Moving Average (KAMA) for a NumPy array.
+
+    KAMA adapts its speed to the market noise. The efficiency ratio compares the net price
+    change over the period with the sum of all bar-to-bar changes: in a clean trend the average
+    follows price like a 2-period EMA, in a choppy market it slows down to a 30-period EMA.
+
+    Args:
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period used for the efficiency ratio (must be >= 2).
+
+    Returns:
+      A tuple of 2 1-D NumPy arrays containing:
+      - KAMA values
+      - Rolling sums of absolute price changes
+      The first `period` elements of each array contain NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([10.0, 11.0, 12.0, 13.0, 14.0])
+      >>> kama, sum_roc = kand.kama(prices, 3)
+      ```
+    """
+    ...
+
+def kama_inc(price, prev_price, old_price, prev_old_price, prev_kama, prev_sum_roc, period):
+    """
+    Calculate the next KAMA value incrementally.
+
+    Args:
+      price: Current price.
+      prev_price: Previous price.
+      old_price: Price `period` bars ago.
+      prev_old_price: Price `period + 1` bars ago, which leaves the window.
+      prev_kama: Previous KAMA value.
+      prev_sum_roc: Previous rolling sum of absolute price changes.
+      period: Period used for the efficiency ratio (must be >= 2).
 
     Returns:
       A tuple containing:
-      - Midpoint value
-      - New highest value
-      - New lowest value
+      - Updated KAMA value
+      - Updated sum of absolute price changes
 
     Examples:
       ```python
       >>> import kand
-      >>> midpoint, new_highest, new_lowest = kand.midpoint_inc(
-      ...     15.0,  # current price
-      ...     16.0,  # previous highest
-      ...     14.0,  # previous lowest
-      ...     14     # period
+      >>> kama, sum_roc = kand.kama_inc(
+      ...     15.0,  # price
+      ...     14.0,  # prev_price
+      ...     12.0,  # old_price
+      ...     11.0,  # prev_old_price
+      ...     13.0,  # prev_kama
+      ...     3.0,   # prev_sum_roc
+      ...     3      # period
       ... )
       ```
     """
     ...
 
-def midprice(high, low, period):
+def kelly(pnl, period, fraction=1.0):
     """
-    Calculates Midpoint Price values for a NumPy array.
+    Calculate the rolling Kelly Criterion (KELLY) for a NumPy array of per-trade PnL
 
-    The Midpoint Price is a technical indicator that represents the mean value between the highest high
-    and lowest low prices over a specified period.
+    The Kelly Criterion is the fraction of capital to risk per trade that maximizes long-run
+    growth given the win rate and payoff ratio of the last `period` trades:
+    (p - q * avg_loss / avg_win) * fraction. It is 0 when there are no winning trades.
 
     Args:
-      high: Input high price data as a 1-D NumPy array of type `TAFloat`.
-      low: Input low price data as a 1-D NumPy array of type `TAFloat`.
-      period: Time period for calculation (must be >= 2).
+      pnl: Per-trade profit and loss as a 1-D NumPy array of type `TAFloat`.
+      period: Number of trades in the rolling window (must be >= 1).
+      fraction: Multiplier applied to the full Kelly fraction (must be > 0), e.g. 0.5 for half
+        Kelly. Defaults to 1.
 
     Returns:
-      A tuple of three 1-D NumPy arrays containing:
-      - Midpoint Price values
-      - Highest high values for each period
-      - Lowest low values for each period
-      Each array has the same length as the input, with initial elements containing NaN values.
+      A tuple of five 1-D NumPy arrays containing:
+      - Kelly fraction
+      - Running count of winning trades
+      - Running count of losing trades
+      - Running sum of winning trades
+      - Running absolute sum of losing trades
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
-      >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
-      >>> midprice, highest, lowest = kand.midprice(high, low, 3)
+      >>> pnl = np.array([120.0, -50.0, 0.0, 80.0])
+      >>> kelly, wins, losses, gross_profit, gross_loss = kand.kelly(pnl, 4)
+      >>> kelly[-1]
+      0.375
       ```
     """
     ...
 
-def midprice_inc(high, low, prev_highest, prev_lowest, period):
+def kelly_expanding(pnl, fraction=1.0):
     """
-    Calculates the next Midpoint Price value incrementally.
+    Calculate the expanding Kelly Criterion for a NumPy array of per-trade PnL
 
-    Provides an optimized way to calculate the next Midpoint Price value when new data arrives,
-    without recalculating the entire series.
+    Uses every trade from the start up to each index, so the last element is the Kelly fraction
+    of the whole sample.
 
     Args:
+      pnl: Per-trade profit and loss as a 1-D NumPy array of type `TAFloat`.
+      fraction: Multiplier applied to the full Kelly fraction (must be > 0). Defaults to 1.
 
-      high: Current high price value as `TAFloat`.
-      low: Current low price value as `TAFloat`.
-      prev_highest: Previous highest high value as `TAFloat`.
-      prev_lowest: Previous lowest low value as `TAFloat`.
-      period: Time period for calculation (must be >= 2).
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - Kelly fraction
+      - Cumulative count of winning trades
+      - Cumulative count of losing trades
+      - Cumulative sum of winning trades
+      - Cumulative absolute sum of losing trades
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> pnl = np.array([120.0, -50.0, 0.0, 80.0])
+      >>> kelly, wins, losses, gross_profit, gross_loss = kand.kelly_expanding(pnl, 0.5)
+      >>> kelly[-1]
+      0.1875
+      ```
+    """
+    ...
+
+def kelly_expanding_inc(pnl, prev_wins, prev_losses, prev_gross_profit, prev_gross_loss, prev_count, fraction=1.0):
+    """
+    Calculate the latest expanding Kelly Criterion incrementally
+
+    Args:
+      py: Python interpreter token
+      pnl: PnL of the newest trade
+      prev_wins: Previous cumulative count of winning trades
+      prev_losses: Previous cumulative count of losing trades
+      prev_gross_profit: Previous cumulative sum of winning trades
+      prev_gross_loss: Previous cumulative absolute sum of losing trades
+      prev_count: Number of trades already included in the sums
+      fraction: Multiplier applied to the full Kelly fraction (must be > 0). Defaults to 1.
 
     Returns:
       A tuple containing:
-      - Midpoint Price value
-      - New highest high value
-      - New lowest low value
+      - Latest Kelly fraction
+      - New count of winning trades
+      - New count of losing trades
+      - New sum of winning trades
+      - New absolute sum of losing trades
 
     Examples:
       ```python
       >>> import kand
-      >>> midprice, new_highest, new_lowest = kand.midprice_inc(
-      ...     10.5,  # current high
-      ...     9.8,   # current low
-      ...     10.2,  # previous highest high
-      ...     9.5,   # previous lowest low
-      ...     14     # period
+      >>> kelly, wins, losses, gross_profit, gross_loss = kand.kelly_expanding_inc(
+      ...     80.0,  # newest trade
+      ...     1.0, 1.0, 120.0, 50.0,  # previous sums
+      ...     3  # trades so far
+      ... )
+      ```
+    """
+    ...
+
+def kelly_inc(pnl, old_pnl, prev_wins, prev_losses, prev_gross_profit, prev_gross_loss, period, fraction=1.0):
+    """
+    Calculate the latest rolling Kelly Criterion incrementally
+
+    Args:
+      py: Python interpreter token
+      pnl: PnL of the newest trade
+      old_pnl: PnL of the trade leaving the window
+      prev_wins: Previous count of winning trades
+      prev_losses: Previous count of losing trades
+      prev_gross_profit: Previous sum of winning trades
+      prev_gross_loss: Previous absolute sum of losing trades
+      period: Number of trades in the rolling window (must be >= 1)
+      fraction: Multiplier applied to the full Kelly fraction (must be > 0). Defaults to 1.
+
+    Returns:
+      A tuple containing:
+      - Latest Kelly fraction
+      - New count of winning trades
+      - New count of losing trades
+      - New sum of winning trades
+      - New absolute sum of losing trades
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kelly, wins, losses, gross_profit, gross_loss = kand.kelly_inc(
+      ...     -40.0, 80.0,  # newest trade, trade leaving the window
+      ...     2.0, 0.0, 200.0, 0.0,  # previous sums
+      ...     2  # period
+      ... )
+      ```
+    """
+    ...
+
+def keltner(high, low, close, period=20, atr_period=10, multiplier=2.0, ma_type=1):
+    """
+    Computes Keltner Channels over NumPy arrays.
+
+    The middle line is a moving average of the close and the bands sit a multiple of the
+    Average True Range above and below it.
+
+    Args:
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period of the middle line moving average. Defaults to 20.
+      atr_period: Period of the ATR. Defaults to 10.
+      multiplier: ATR multiple for the bands. Defaults to 2.0.
+      ma_type: Moving average type for the middle line: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA,
+        4=RMA, 5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 1 (EMA).
+
+    Returns:
+      A tuple of six 1-D NumPy arrays containing:
+      - Upper band values
+      - Middle line values
+      - Lower band values
+      - ATR values
+      - %B values
+      - Bandwidth values
+      Each array has the same length as the input, with the warm-up elements containing
+      NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 11.0, 12.0, 11.5, 12.5])
+      >>> low = np.array([9.0, 9.5, 10.5, 10.0, 11.0])
+      >>> close = np.array([9.5, 10.5, 11.5, 10.5, 12.0])
+      >>> upper, middle, lower, atr, percent_b, bandwidth = kand.keltner(
+      ...     high, low, close, 3, 2, 2.0, 5
+      ... )
+      ```
+    """
+    ...
+
+def keltner_inc(high, low, close, prev_close, prev_middle, prev_atr, period, atr_period, multiplier):
+    """
+    Calculates the next Keltner Channel values incrementally.
+
+    The incremental form is EMA-only: other middle line types need more state than the
+    previous value.
+
+    Args:
+      high: Current high price.
+      low: Current low price.
+      close: Current close price.
+      prev_close: Previous close price.
+      prev_middle: Previous EMA middle line value.
+      prev_atr: Previous ATR value.
+      period: Period of the EMA middle line.
+      atr_period: Period of the ATR.
+      multiplier: ATR multiple for the bands.
+
+    Returns:
+      A tuple of (upper, middle, lower, atr, percent_b, bandwidth).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> upper, middle, lower, atr, percent_b, bandwidth = kand.keltner_inc(
+      ...     12.0, 10.0, 11.0, 10.0, 10.0, 1.0, 3, 2, 2.0
       ... )
+      >>> print(middle, atr)
+      10.5 1.5
+      ```
+    """
+    ...
+
+def line_break(close, lines=3):
+    """
+    Builds an N-line break chart from a NumPy array of closes.
+
+    A close beyond the last line draws a new line in the same direction. Reversing needs a close
+    beyond the range of the last `lines` lines.
+
+    Args:
+        close: Close prices as a 1-D NumPy array of type `TAFloat`.
+        lines: Number of lines a reversal has to break. Defaults to 3.
+
+    Returns:
+        A tuple of four 1-D NumPy arrays with one entry per line:
+        - Line open prices
+        - Line close prices
+        - Line directions (1 up, -1 down)
+        - Index of the source bar that completed each line
+
+    Examples:
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> close = np.array([10.0, 11.0, 12.0, 13.0, 11.5, 9.5, 9.0])
+        >>> opens, closes, directions, indices = kand.line_break(close)
+        ```
+    """
+    ...
+
+def ma(data, period, ma_type=5):
+    """
+    Computes a moving average of the selected type over a NumPy array.
+
+    Dispatches to the moving average selected by `ma_type`, like TA-Lib's `MA` function.
+    T3 uses a volume factor of 0.7 and MAMA ignores `period` and uses limits of 0.5 and 0.05.
+
+    Args:
+        data: Input data as a 1-D NumPy array of type `TAFloat`.
+        period: Window size for the moving average. Must be >= 2.
+        ma_type: Moving average type: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA, 4=RMA, 5=SMA, 6=T3,
+            7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+
+    Returns:
+        A new 1-D NumPy array containing the moving average values. The array has the same length
+        as the input, with the lookback elements containing NaN values.
+
+    Examples:
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> data = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+        >>> result = kand.ma(data, 3, 5)
+        >>> print(result)
+        [nan, nan, 2.0, 3.0, 4.0]
+        ```
+    """
+    ...
+
+def macd(data, fast_period, slow_period, signal_period):
+    """
+    Computes the Moving Average Convergence Divergence (MACD) over a NumPy array.
+
+    MACD is a trend-following momentum indicator that shows the relationship between two moving averages
+    of an asset's price. It consists of three components:
+    - MACD Line: Difference between fast and slow EMAs
+    - Signal Line: EMA of the MACD line
+    - Histogram: Difference between MACD line and signal line
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+      fast_period: Period for fast EMA calculation (typically 12).
+      slow_period: Period for slow EMA calculation (typically 26).
+      signal_period: Period for signal line calculation (typically 9).
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - MACD line values
+      - Signal line values
+      - MACD histogram values
+      - Fast EMA values
+      - Slow EMA values
+      Each array has the same length as the input, with initial elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+      >>> macd_line, signal_line, histogram, fast_ema, slow_ema = kand.macd(data, 2, 3, 2)
+      ```
+    """
+    ...
+
+def macd_inc(price, prev_fast_ema, prev_slow_ema, prev_signal, fast_period, slow_period, signal_period):
+    """
+    Computes the latest MACD values incrementally from previous state.
+
+    This function provides an efficient way to calculate MACD for streaming data by using
+    previous EMA values instead of recalculating the entire series.
+
+    Args:
+
+      price: Current price value as `TAFloat`.
+      prev_fast_ema: Previous fast EMA value as `TAFloat`.
+      prev_slow_ema: Previous slow EMA value as `TAFloat`.
+      prev_signal: Previous signal line value as `TAFloat`.
+      fast_period: Period for fast EMA calculation (typically 12).
+      slow_period: Period for slow EMA calculation (typically 26).
+      signal_period: Period for signal line calculation (typically 9).
+
+    Returns:
+      A tuple of three values:
+      - MACD line value
+      - Signal line value
+      - MACD histogram value
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> macd_line, signal_line, histogram = kand.macd_inc(
+      ...     100.0,  # current price
+      ...     95.0,   # previous fast EMA
+      ...     98.0,   # previous slow EMA
+      ...     -2.5,   # previous signal
+      ...     12,     # fast period
+      ...     26,     # slow period
+      ...     9       # signal period
+      ... )
+      ```
+    """
+    ...
+
+def macdext(data, fast_period, slow_period, signal_period, fast_ma_type=5, slow_ma_type=5, signal_ma_type=5):
+    """
+    Computes MACD with controllable moving average types (MACDEXT) over a NumPy array.
+
+    Like MACD, but the fast, slow and signal lines each use their own moving average type:
+    - MACD Line: Difference between fast and slow MAs
+    - Signal Line: MA of the MACD line
+    - Histogram: Difference between MACD line and signal line
+
+    As in TA-Lib, a slow period shorter than the fast one swaps the two lines with their MA types.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+      fast_period: Period for fast MA calculation (typically 12).
+      slow_period: Period for slow MA calculation (typically 26).
+      signal_period: Period for signal line calculation (typically 9).
+      fast_ma_type: Moving average type for the fast line: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA,
+        4=RMA, 5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+      slow_ma_type: Moving average type for the slow line. Defaults to 5 (SMA).
+      signal_ma_type: Moving average type for the signal line. Defaults to 5 (SMA).
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - MACD line values
+      - Signal line values
+      - MACD histogram values
+      - Fast MA values
+      - Slow MA values
+      Each array has the same length as the input, with initial elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+      >>> macd_line, signal_line, histogram, fast_ma, slow_ma = kand.macdext(data, 2, 3, 2, 1, 1, 5)
+      ```
+    """
+    ...
+
+def mama(prices, fast_limit=0.5, slow_limit=0.05):
+    """
+    Calculate MESA Adaptive Moving Average (MAMA) and Following Adaptive Moving Average (FAMA)
+    for a NumPy array.
+
+    MAMA is an EMA whose alpha follows the rate of change of the Hilbert Transform phase, bounded
+    by `fast_limit` and `slow_limit`. FAMA applies half of that alpha to MAMA itself.
+
+    The phase history cannot be passed around as scalars, so there is no incremental form.
+
+    Args:
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      fast_limit: Upper limit of the adaptive alpha (0.01 to 0.99). Defaults to 0.5.
+      slow_limit: Lower limit of the adaptive alpha (0.01 to 0.99). Defaults to 0.05.
+
+    Returns:
+      A tuple of 2 1-D NumPy arrays containing:
+      - MAMA values
+      - FAMA values
+      The first 32 elements of each array contain NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = 100.0 + np.sin(np.arange(64) * 0.3)
+      >>> mama, fama = kand.mama(prices, 0.5, 0.05)
+      ```
+    """
+    ...
+
+def max(prices, period):
+    """
+    Calculate Maximum Value for a NumPy array
+
+    Args:
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period for MAX calculation (must be >= 2).
+
+    Returns:
+      A 1-D NumPy array containing MAX values. The first (period-1) elements contain NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([1.0, 2.0, 3.0, 2.5, 4.0])
+      >>> max_values = kand.max(prices, 3)
+      ```
+    """
+    ...
+
+def max_inc(price, prev_max, old_price, period):
+    """
+    Calculate the latest Maximum Value incrementally
+
+    Deprecated:
+      The previous MAX alone cannot be updated exactly once it leaves the window, so this
+      function falls back to the current price in that case. Use the `Max` streaming object,
+      which keeps the window between calls.
+
+    Args:
+      py: Python interpreter token
+      price: Current period's price
+      prev_max: Previous period's MAX value
+      old_price: Price being removed from the period
+      period: Period for MAX calculation (must be >= 2)
+
+    Returns:
+      The new MAX value
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> new_max = kand.max_inc(10.5, 11.0, 9.0, 14)
+      ```
+    """
+    ...
+
+def medprice(high, low):
+    """
+    Calculates the Median Price (MEDPRICE) for a NumPy array.
+
+    The Median Price is a technical analysis indicator that represents the middle point between
+    high and low prices for each period.
+
+    Args:
+      high: Array of high prices as a 1-D NumPy array of type `TAFloat`.
+      low: Array of low prices as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A 1-D NumPy array containing the median price values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 11.0, 12.0])
+      >>> low = np.array([8.0, 9.0, 10.0])
+      >>> result = kand.medprice(high, low)
+      >>> print(result)
+      [9.0, 10.0, 11.0]
+      ```
+    """
+    ...
+
+def medprice_inc(high, low):
+    """
+    Calculates a single Median Price value incrementally.
+
+    Args:
+
+      high: Current period's high price as `TAFloat`.
+      low: Current period's low price as `TAFloat`.
+
+    Returns:
+      The calculated median price value.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> result = kand.medprice_inc(10.0, 8.0)
+      >>> print(result)
+      9.0
+      ```
+    """
+    ...
+
+def mfi(high, low, close, volume, period):
+    """
+    Calculates the Money Flow Index (MFI) for a NumPy array.
+
+    The Money Flow Index (MFI) is a technical oscillator that uses price and volume data to identify
+    overbought or oversold conditions in an asset.
+
+    Args:
+      high: Array of high prices as a 1-D NumPy array of type `TAFloat`.
+      low: Array of low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Array of close prices as a 1-D NumPy array of type `TAFloat`.
+      volume: Array of volume data as a 1-D NumPy array of type `TAFloat`.
+      period: The time period for MFI calculation (typically 14).
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - MFI values (0-100)
+      - Typical prices
+      - Money flows
+      - Rolling sums of positive money flow, NaN for the first `period` elements
+      - Rolling sums of negative money flow, NaN for the first `period` elements
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 11.0, 12.0, 11.0])
+      >>> low = np.array([8.0, 9.0, 10.0, 9.0])
+      >>> close = np.array([9.0, 10.0, 11.0, 10.0])
+      >>> volume = np.array([100.0, 150.0, 200.0, 150.0])
+      >>> mfi, typ_prices, money_flows, pos_flows, neg_flows = kand.mfi(high, low, close, volume, 2)
+      ```
+    """
+    ...
+
+def mfi_inc(high, low, close, volume, prev_typ_price, old_typ_price, old_prev_typ_price, old_money_flow, prev_pos_flow, prev_neg_flow):
+    """
+    Calculates the next Money Flow Index value incrementally.
+
+    Args:
+      high: Current high price.
+      low: Current low price.
+      close: Current close price.
+      volume: Current volume.
+      prev_typ_price: Previous typical price.
+      old_typ_price: Typical price of the bar leaving the window, `period` bars back.
+      old_prev_typ_price: Typical price of the bar before the one leaving the window.
+      old_money_flow: Money flow of the bar leaving the window.
+      prev_pos_flow: Previous rolling sum of positive money flow.
+      prev_neg_flow: Previous rolling sum of negative money flow.
+
+    Returns:
+      A tuple of (mfi, typ_price, money_flow, pos_flow, neg_flow).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> mfi, typ_price, money_flow, pos_flow, neg_flow = kand.mfi_inc(
+      ...     11.0, 9.0, 10.0, 150.0, 11.0, 10.0, 9.0, 1500.0, 3700.0, 0.0
+      ... )
+      >>> (pos_flow, neg_flow)
+      (2200.0, 1500.0)
+      ```
+    """
+    ...
+
+def midpoint(data, period):
+    """
+    Calculates Midpoint values for a NumPy array.
+
+    The Midpoint is a technical indicator that represents the arithmetic mean of the highest and lowest
+    prices over a specified period.
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+      period: Time period for calculation (must be >= 2).
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Midpoint values
+      - Highest values for each period
+      - Lowest values for each period
+      Each array has the same length as the input, with initial elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
+      >>> midpoint, highest, lowest = kand.midpoint(data, 3)
+      ```
+    """
+    ...
+
+def midpoint_inc(price, prev_highest, prev_lowest, period):
+    """
+    Calculates the next Midpoint value incrementally.
+
+    Provides an optimized way to calculate the next Midpoint value when new data arrives,
+    without recalculating the entire series.
+
+    Deprecated:
+      The highest and lowest values cannot be tracked exactly from the previous values once the
+      extreme leaves the window. Use the `Midpoint` streaming object, which keeps the rolling
+      windows between calls.
+
+    Args:
+      price: Current price value as `TAFloat`.
+      prev_highest: Previous highest value as `TAFloat`.
+      prev_lowest: Previous lowest value as `TAFloat`.
+      period: Time period for calculation (must be >= 2).
+
+    Returns:
+      A tuple containing:
+      - Midpoint value
+      - New highest value
+      - New lowest value
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> midpoint, new_highest, new_lowest = kand.midpoint_inc(
+      ...     15.0,  # current price
+      ...     16.0,  # previous highest
+      ...     14.0,  # previous lowest
+      ...     14     # period
+      ... )
+      ```
+    """
+    ...
+
+def midprice(high, low, period):
+    """
+    Calculates Midpoint Price values for a NumPy array.
+
+    The Midpoint Price is a technical indicator that represents the mean value between the highest high
+    and lowest low prices over a specified period.
+
+    Args:
+      high: Input high price data as a 1-D NumPy array of type `TAFloat`.
+      low: Input low price data as a 1-D NumPy array of type `TAFloat`.
+      period: Time period for calculation (must be >= 2).
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Midpoint Price values
+      - Highest high values for each period
+      - Lowest low values for each period
+      Each array has the same length as the input, with initial elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
+      >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
+      >>> midprice, highest, lowest = kand.midprice(high, low, 3)
+      ```
+    """
+    ...
+
+def midprice_inc(high, low, prev_highest, prev_lowest, period):
+    """
+    Calculates the next Midpoint Price value incrementally.
+
+    Provides an optimized way to calculate the next Midpoint Price value when new data arrives,
+    without recalculating the entire series.
+
+    Deprecated:
+      The highest high and lowest low cannot be tracked exactly from the previous values once
+      the extreme leaves the window. Use the `Midprice` streaming object, which keeps the
+      rolling windows between calls.
+
+    Args:
+
+      high: Current high price value as `TAFloat`.
+      low: Current low price value as `TAFloat`.
+      prev_highest: Previous highest high value as `TAFloat`.
+      prev_lowest: Previous lowest low value as `TAFloat`.
+      period: Time period for calculation (must be >= 2).
+
+    Returns:
+      A tuple containing:
+      - Midpoint Price value
+      - New highest high value
+      - New lowest low value
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> midprice, new_highest, new_lowest = kand.midprice_inc(
+      ...     10.5,  # current high
+      ...     9.8,   # current low
+      ...     10.2,  # previous highest high
+      ...     9.5,   # previous lowest low
+      ...     14     # period
+      ... )
+      ```
+    """
+    ...
+
+def min(prices, period):
+    """
+    Calculate Minimum Value (MIN) for a NumPy array
+
+    The MIN indicator finds the lowest price value within a given time period.
+
+    Args:
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period for MIN calculation (must be >= 2).
+
+    Returns:
+      A 1-D NumPy array containing MIN values. First (period-1) elements contain NaN.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([10.0, 8.0, 6.0, 7.0, 9.0])
+      >>> min_values = kand.min(prices, 3)
+      ```
+    """
+    ...
+
+def min_inc(price, prev_min, prev_price, period):
+    """
+    Calculate the latest MIN value incrementally
+
+    Deprecated:
+      The previous MIN alone cannot be updated once it leaves the window, so this function
+      raises a ValueError in that case. Use the `Min` streaming object, which keeps the window
+      between calls.
+
+    Args:
+      py: Python interpreter token
+      price: Current period's price
+      prev_min: Previous period's MIN value
+      prev_price: Price value being removed from the period
+      period: Period for MIN calculation (must be >= 2)
+
+    Returns:
+      The new MIN value
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> new_min = kand.min_inc(15.0, 12.0, 14.0, 14)
+      ```
+    """
+    ...
+
+def minus_di(high, low, close, period):
+    """
+    Computes the Minus Directional Indicator (-DI) over NumPy arrays.
+
+    The -DI measures the presence and strength of a downward price trend. It is one component used in calculating
+    the Average Directional Index (ADX), which helps determine trend strength.
+
+    Args:
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      period: Window size for -DI calculation. Must be positive and less than input length.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - The -DI values
+      - The smoothed -DM values
+      - The smoothed TR values
+      Each array has the same length as the input, with the first `period` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([35.0, 36.0, 35.5, 35.8, 36.2])
+      >>> low = np.array([34.0, 35.0, 34.5, 34.8, 35.2])
+      >>> close = np.array([34.5, 35.5, 35.0, 35.3, 35.7])
+      >>> minus_di, smoothed_minus_dm, smoothed_tr = kand.minus_di(high, low, close, 3)
+      >>> print(minus_di)
+      [nan, nan, nan, 25.3, 24.1]
+      ```
+    """
+    ...
+
+def minus_di_inc(high, low, prev_high, prev_low, prev_close, prev_smoothed_minus_dm, prev_smoothed_tr, period):
+    """
+    Calculates the next -DI value incrementally using previous smoothed values.
+
+    This function provides an efficient way to update -DI with new price data without recalculating the entire series.
+    It maintains the same mathematical properties as the full calculation.
+
+    Args:
+
+      high: Current high price as `TAFloat`.
+      low: Current low price as `TAFloat`.
+      prev_high: Previous high price as `TAFloat`.
+      prev_low: Previous low price as `TAFloat`.
+      prev_close: Previous close price as `TAFloat`.
+      prev_smoothed_minus_dm: Previous smoothed -DM value as `TAFloat`.
+      prev_smoothed_tr: Previous smoothed TR value as `TAFloat`.
+      period: Calculation period (>= 2).
+
+    Returns:
+      A tuple of three values:
+      - The new -DI value
+      - The new smoothed -DM value
+      - The new smoothed TR value
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> minus_di, smoothed_minus_dm, smoothed_tr = kand.minus_di_inc(
+      ...     36.2,  # high
+      ...     35.2,  # low
+      ...     35.8,  # prev_high
+      ...     34.8,  # prev_low
+      ...     35.3,  # prev_close
+      ...     0.5,   # prev_smoothed_minus_dm
+      ...     1.5,   # prev_smoothed_tr
+      ...     14     # period
+      ... )
+      ```
+    """
+    ...
+
+def minus_dm(high, low, period):
+    """
+    Computes the Minus Directional Movement (-DM) over NumPy arrays.
+
+    Minus Directional Movement (-DM) measures downward price movement and is used as part of the
+    Directional Movement System developed by J. Welles Wilder.
+
+    Args:
+      high: Input high prices as a 1-D NumPy array of type `TAFloat`.
+      low: Input low prices as a 1-D NumPy array of type `TAFloat`.
+      period: Window size for -DM calculation. Must be positive and less than input length.
+
+    Returns:
+      A new 1-D NumPy array containing the -DM values. The array has the same length as the input,
+      with the first `period-1` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([35266.0, 35247.5, 35235.7, 35190.8, 35182.0])
+      >>> low = np.array([35216.1, 35206.5, 35180.0, 35130.7, 35153.6])
+      >>> result = kand.minus_dm(high, low, 3)
+      ```
+    """
+    ...
+
+def minus_dm_inc(high, prev_high, low, prev_low, prev_minus_dm, period):
+    """
+    Calculates the next -DM value incrementally using previous values.
+
+    This function provides an efficient way to update -DM with new price data without recalculating the entire series.
+    It maintains the same mathematical properties as the full calculation.
+
+    Args:
+
+      high: Current high price as `TAFloat`.
+      prev_high: Previous high price as `TAFloat`.
+      low: Current low price as `TAFloat`.
+      prev_low: Previous low price as `TAFloat`.
+      prev_minus_dm: Previous -DM value as `TAFloat`.
+      period: Calculation period (must be between 2 and 100).
+
+    Returns:
+      The next -DM value.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> next_minus_dm = kand.minus_dm_inc(
+      ...     35182.0,  # high
+      ...     35190.8,  # prev_high
+      ...     35153.6,  # low
+      ...     35130.7,  # prev_low
+      ...     2.5,      # prev_minus_dm
+      ...     14        # period
+      ... )
+      ```
+    """
+    ...
+
+def mom(data, period):
+    """
+    Computes the Momentum (MOM) over a NumPy array.
+
+    Momentum measures the change in price between the current price and the price n periods ago.
+
+    Args:
+      data: Input data as a 1-D NumPy array of type `TAFloat`.
+      period: Window size for momentum calculation. Must be positive and less than input length.
+
+    Returns:
+      A new 1-D NumPy array containing the momentum values. The array has the same length as the input,
+      with the first `period` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([2.0, 4.0, 6.0, 8.0, 10.0])
+      >>> result = kand.mom(data, 2)
+      >>> print(result)
+      [nan, nan, 4.0, 4.0, 4.0]
+      ```
+    """
+    ...
+
+def mom_inc(current_price, old_price):
+    """
+    Calculates the next Momentum (MOM) value incrementally.
+
+    This function provides an optimized way to calculate the latest momentum value
+    when streaming data is available, without needing the full price history.
+
+    Args:
+
+      current_price: The current period's price value.
+      old_price: The price value from n periods ago.
+
+    Returns:
+      The calculated momentum value.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> momentum = kand.mom_inc(10.0, 6.0)
+      >>> print(momentum)
+      4.0
+      ```
+    """
+    ...
+
+def natr(high, low, close, period):
+    """
+    Computes the Normalized Average True Range (NATR) over NumPy arrays.
+
+    The NATR is a measure of volatility that accounts for the price level of the instrument.
+    It expresses the ATR as a percentage of the closing price.
+
+    Args:
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      period: Window size for NATR calculation. Must be positive and less than input length.
+
+    Returns:
+      A new 1-D NumPy array containing the NATR values. The array has the same length as the input,
+      with the first `period` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
+      >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
+      >>> close = np.array([9.0, 11.0, 14.0, 12.0, 11.0])
+      >>> result = kand.natr(high, low, close, 3)
+      ```
+    """
+    ...
+
+def natr_inc(high, low, close, prev_close, prev_atr, period):
+    """
+    Calculates the next NATR value incrementally.
+
+    This function provides an optimized way to calculate a single new NATR value
+    using the previous ATR value and current price data, without recalculating the entire series.
+
+    Args:
+
+      high: Current period's high price.
+      low: Current period's low price.
+      close: Current period's closing price.
+      prev_close: Previous period's closing price.
+      prev_atr: Previous period's ATR value.
+      period: Period for NATR calculation (must be >= 2).
+
+    Returns:
+      The calculated NATR value.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> natr = kand.natr_inc(
+      ...     15.0,  # high
+      ...     11.0,  # low
+      ...     14.0,  # close
+      ...     12.0,  # prev_close
+      ...     3.0,   # prev_atr
+      ...     3      # period
+      ... )
+      ```
+    """
+    ...
+
+def nper(rate, pmt, pv, fv, when=0):
+    """
+    Calculate the Number of Periods (NPER) element-wise over NumPy arrays
+
+    NPER is the number of constant payments needed to move the present value to the future
+    value, e.g. how many months it takes to pay off a loan.
+
+    Args:
+      rate: Interest rate per period as a 1-D NumPy array of type `TAFloat`.
+      pmt: Payment made each period as a 1-D NumPy array of type `TAFloat`.
+      pv: Present value as a 1-D NumPy array of type `TAFloat`.
+      fv: Future value as a 1-D NumPy array of type `TAFloat`.
+      when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+
+    Returns:
+      A 1-D NumPy array of numbers of periods with the same length as the inputs.
+
+    Raises:
+      ValueError: If no finite number of periods reaches `fv`.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> rate = np.array([0.07 / 12])
+      >>> pmt = np.array([-150.0])
+      >>> pv = np.array([8000.0])
+      >>> fv = np.array([0.0])
+      >>> kand.nper(rate, pmt, pv, fv)
+      array([64.07334877])
+      ```
+    """
+    ...
+
+def nper_inc(rate, pmt, pv, fv, when=0):
+    """
+    Calculate a single Number of Periods (NPER)
+
+    Args:
+      py: Python interpreter token
+      rate: Interest rate per period
+      pmt: Payment made each period
+      pv: Present value
+      fv: Future value
+      when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+
+    Returns:
+      The number of periods.
+
+    Raises:
+      ValueError: If no finite number of periods reaches `fv`.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.nper_inc(0.0, -100.0, 1000.0, 0.0)
+      10.0
+      ```
+    """
+    ...
+
+def nvi(close, volume, start=1000.0):
+    """
+    Computes the Negative Volume Index (NVI) over NumPy arrays.
+
+    The NVI compounds the percentage price change of bars whose volume fell below the
+    previous bar's and stays flat on every other bar.
+
+    Args:
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+      start: Initial index value. Defaults to 1000.
+
+    Returns:
+      A new 1-D NumPy array containing the NVI values. The array has the same length as the input.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> close = np.array([10.0, 11.0, 12.1])
+      >>> volume = np.array([100.0, 80.0, 120.0])
+      >>> result = kand.nvi(close, volume)
+      >>> print(result)
+      [1000. 1100. 1100.]
+      ```
+    """
+    ...
+
+def nvi_inc(close, volume, prev_close, prev_volume, prev_nvi):
+    """
+    Calculates the next NVI value incrementally.
+
+    Args:
+      close: Current close price.
+      volume: Current volume.
+      prev_close: Previous close price.
+      prev_volume: Previous volume.
+      prev_nvi: Previous NVI value.
+
+    Returns:
+      The new NVI value.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.nvi_inc(11.0, 80.0, 10.0, 100.0, 1000.0)
+      1100.0
+      ```
+    """
+    ...
+
+def obv(close, volume):
+    """
+    Computes the On Balance Volume (OBV) over NumPy arrays.
+
+    On Balance Volume (OBV) is a momentum indicator that uses volume flow to predict changes in stock price.
+    When volume increases without a significant price change, the price will eventually jump upward.
+    When volume decreases without a significant price change, the price will eventually jump downward.
+
+    Args:
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A new 1-D NumPy array containing the OBV values. The array has the same length as the input.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> close = np.array([10.0, 12.0, 11.0, 13.0])
+      >>> volume = np.array([100.0, 150.0, 120.0, 200.0])
+      >>> result = kand.obv(close, volume)
+      >>> print(result)
+      [100.0, 250.0, 130.0, 330.0]
+      ```
+    """
+    ...
+
+def obv_inc(curr_close, prev_close, volume, prev_obv):
+    """
+    Calculates the next OBV value incrementally.
+
+    This function provides an optimized way to calculate a single new OBV value
+    using the previous OBV value and current price/volume data.
+
+    Args:
+      curr_close: Current closing price as `TAFloat`.
+      prev_close: Previous closing price as `TAFloat`.
+      volume: Current volume as `TAFloat`.
+      prev_obv: Previous OBV value as `TAFloat`.
+
+    Returns:
+      The calculated OBV value.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> curr_close = 12.0
+      >>> prev_close = 10.0
+      >>> volume = 150.0
+      >>> prev_obv = 100.0
+      >>> result = kand.obv_inc(curr_close, prev_close, volume, prev_obv)
+      >>> print(result)
+      250.0
+      ```
+    """
+    ...
+
+def plus_di(high, low, close, period):
+    """
+    Computes the Plus Directional Indicator (+DI) over NumPy arrays.
+
+    +DI measures the presence and strength of an upward price trend. It is one component used in calculating
+    the Average Directional Index (ADX), which helps determine trend strength.
+
+    Args:
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      period: Window size for +DI calculation. Must be positive and less than input length.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - +DI values
+      - Smoothed +DM values
+      - Smoothed TR values
+      Each array has the same length as the input, with the first `period` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 12.0, 11.5, 11.0])
+      >>> low = np.array([9.0, 10.0, 10.0, 9.5])
+      >>> close = np.array([9.5, 11.0, 10.5, 10.0])
+      >>> plus_di, smoothed_plus_dm, smoothed_tr = kand.plus_di(high, low, close, 2)
+      ```
+    """
+    ...
+
+def plus_di_inc(high, low, prev_high, prev_low, prev_close, prev_smoothed_plus_dm, prev_smoothed_tr, period):
+    """
+    Calculates the next +DI value incrementally using previous smoothed values.
+
+    This function enables real-time calculation of +DI by using the previous smoothed values
+    and current price data, avoiding the need to recalculate the entire series.
+
+    Args:
+      high: Current high price as `TAFloat`.
+      low: Current low price as `TAFloat`.
+      prev_high: Previous high price as `TAFloat`.
+      prev_low: Previous low price as `TAFloat`.
+      prev_close: Previous close price as `TAFloat`.
+      prev_smoothed_plus_dm: Previous smoothed +DM value as `TAFloat`.
+      prev_smoothed_tr: Previous smoothed TR value as `TAFloat`.
+      period: Smoothing period (>= 2).
+
+    Returns:
+      A tuple containing (latest +DI, new smoothed +DM, new smoothed TR).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> plus_di, smoothed_plus_dm, smoothed_tr = kand.plus_di_inc(
+      ...     10.5,  # high
+      ...     9.5,   # low
+      ...     10.0,  # prev_high
+      ...     9.0,   # prev_low
+      ...     9.5,   # prev_close
+      ...     15.0,  # prev_smoothed_plus_dm
+      ...     20.0,  # prev_smoothed_tr
+      ...     14     # period
+      ... )
+      ```
+    """
+    ...
+
+def plus_dm(high, low, period):
+    """
+    Computes the Plus Directional Movement (+DM) over NumPy arrays.
+
+    Plus Directional Movement (+DM) measures upward price movement and is used as part of the
+    Directional Movement System developed by J. Welles Wilder.
+
+    Args:
+      high: Input high prices as a 1-D NumPy array of type `TAFloat`.
+      low: Input low prices as a 1-D NumPy array of type `TAFloat`.
+      period: Window size for +DM calculation. Must be positive and less than input length.
+
+    Returns:
+      A new 1-D NumPy array containing the +DM values. The array has the same length as the input,
+      with the first `period-1` elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([35266.0, 35247.5, 35235.7, 35190.8, 35182.0])
+      >>> low = np.array([35216.1, 35206.5, 35180.0, 35130.7, 35153.6])
+      >>> result = kand.plus_dm(high, low, 3)
+      ```
+    """
+    ...
+
+def plus_dm_inc(high, prev_high, low, prev_low, prev_plus_dm, period):
+    """
+    Calculates the next Plus DM value incrementally using previous values.
+
+    This function enables real-time calculation of Plus DM by using the previous Plus DM value
+    and current price data, avoiding the need to recalculate the entire series.
+
+    Args:
+      high: Current high price as `TAFloat`.
+      prev_high: Previous high price as `TAFloat`.
+      low: Current low price as `TAFloat`.
+      prev_low: Previous low price as `TAFloat`.
+      prev_plus_dm: Previous Plus DM value as `TAFloat`.
+      period: Smoothing period (>= 2).
+
+    Returns:
+      The latest Plus DM value.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> new_plus_dm = kand.plus_dm_inc(
+      ...     10.5,  # high
+      ...     10.0,  # prev_high
+      ...     9.8,   # low
+      ...     9.5,   # prev_low
+      ...     0.45,  # prev_plus_dm
+      ...     14     # period
+      ... )
+      ```
+    """
+    ...
+
+def pmt(rate, nper, pv, fv, when=0):
+    """
+    Calculate the periodic Payment (PMT) element-wise over NumPy arrays
+
+    PMT is the constant payment per period that moves the present value to the future value,
+    e.g. the installment of a loan. Cash paid out is negative and cash received is positive.
+
+    Args:
+      rate: Interest rate per period as a 1-D NumPy array of type `TAFloat`.
+      nper: Number of compounding periods as a 1-D NumPy array of type `TAFloat`.
+      pv: Present value as a 1-D NumPy array of type `TAFloat`.
+      fv: Future value as a 1-D NumPy array of type `TAFloat`.
+      when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+
+    Returns:
+      A 1-D NumPy array of payments with the same length as the inputs.
+
+    Raises:
+      ValueError: If the annuity factor is zero, e.g. when `nper` is 0.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> rate = np.array([0.075 / 12])
+      >>> nper = np.array([180.0])
+      >>> pv = np.array([200000.0])
+      >>> fv = np.array([0.0])
+      >>> kand.pmt(rate, nper, pv, fv)
+      array([-1854.02472001])
+      ```
+    """
+    ...
+
+def pmt_inc(rate, nper, pv, fv, when=0):
+    """
+    Calculate a single periodic Payment (PMT)
+
+    Args:
+      py: Python interpreter token
+      rate: Interest rate per period
+      nper: Number of compounding periods
+      pv: Present value
+      fv: Future value
+      when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+
+    Returns:
+      The payment per period.
+
+    Raises:
+      ValueError: If the annuity factor is zero, e.g. when `nper` is 0.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.pmt_inc(0.0, 12.0, 1200.0, 0.0)
+      -100.0
+      ```
+    """
+    ...
+
+def pnf(high, low, close, box_size, reversal=3, source=0):
+    """
+    Builds Point & Figure columns from NumPy arrays.
+
+    Columns of Xs grow while price fills higher boxes and columns of Os while it fills lower
+    ones. A new column starts once price fills `reversal` boxes the other way.
+
+    Args:
+        high: High prices as a 1-D NumPy array of type `TAFloat`.
+        low: Low prices as a 1-D NumPy array of type `TAFloat`.
+        close: Close prices as a 1-D NumPy array of type `TAFloat`.
+        box_size: Price distance covered by each box (must be > 0).
+        reversal: Boxes needed to start a new column. Defaults to 3.
+        source: 0 to measure moves on closes, 1 on highs and lows. Defaults to 0.
+
+    Returns:
+        A tuple of five 1-D NumPy arrays with one entry per column, the last column still forming:
+        - Column types (1 X, -1 O)
+        - Lowest box level of each column
+        - Highest box level of each column
+        - Index of the bar that started each column
+        - Index of the bar that added the last box to each column
+
+    Examples:
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> close = np.array([10.0, 11.2, 13.5, 12.4, 9.8, 11.8])
+        >>> directions, lows, highs, start_idx, end_idx = kand.pnf(close, close, close, 1.0)
+        ```
+    """
+    ...
+
+def ppo(data, fast_period=12, slow_period=26, signal_period=9, fast_ma_type=5, slow_ma_type=5, signal_ma_type=5):
+    """
+    Computes the Percentage Price Oscillator (PPO) over a NumPy array.
+
+    The fast, slow and signal lines each use their own moving average type:
+    - PPO: Difference between the fast and slow moving averages as a percentage of the slow one
+    - Signal Line: MA of the PPO line
+    - Histogram: Difference between PPO line and signal line
+
+    Args:
+      data: Input price data as a 1-D NumPy array of type `TAFloat`.
+      fast_period: Period for fast MA calculation (typically 12).
+      slow_period: Period for slow MA calculation (typically 26).
+      signal_period: Period for signal line calculation (typically 9).
+      fast_ma_type: Moving average type for the fast line: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA,
+        4=RMA, 5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+      slow_ma_type: Moving average type for the slow line. Defaults to 5 (SMA).
+      signal_ma_type: Moving average type for the signal line. Defaults to 5 (SMA).
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - PPO values
+      - Signal line values
+      - Histogram values
+      - Fast MA values
+      - Slow MA values
+      Each array has the same length as the input, with initial elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> data = np.array([1.0, 2.0, 3.0, 4.0, 5.0])
+      >>> ppo, signal, histogram, fast_ma, slow_ma = kand.ppo(data, 2, 3, 2)
       ```
     """
     ...
 
-def min(prices, period):
+def pv(rate, nper, pmt, fv, when=0):
     """
-    Calculate Minimum Value (MIN) for a NumPy array
+    Calculate the Present Value (PV) element-wise over NumPy arrays
 
-    The MIN indicator finds the lowest price value within a given time period.
+    PV is the amount today that, together with `nper` constant payments, is equivalent to the
+    future value. Cash paid out is negative and cash received is positive.
 
     Args:
-      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
-      period: Period for MIN calculation (must be >= 2).
+      rate: Interest rate per period as a 1-D NumPy array of type `TAFloat`.
+      nper: Number of compounding periods as a 1-D NumPy array of type `TAFloat`.
+      pmt: Payment made each period as a 1-D NumPy array of type `TAFloat`.
+      fv: Future value as a 1-D NumPy array of type `TAFloat`.
+      when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
 
     Returns:
-      A 1-D NumPy array containing MIN values. First (period-1) elements contain NaN.
+      A 1-D NumPy array of present values with the same length as the inputs.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> prices = np.array([10.0, 8.0, 6.0, 7.0, 9.0])
-      >>> min_values = kand.min(prices, 3)
+      >>> rate = np.array([0.08])
+      >>> nper = np.array([20.0])
+      >>> pmt = np.array([500.0])
+      >>> fv = np.array([0.0])
+      >>> kand.pv(rate, nper, pmt, fv)
+      array([-4909.07370372])
       ```
     """
     ...
 
-def min_inc(price, prev_min, prev_price, period):
+def pv_inc(rate, nper, pmt, fv, when=0):
     """
-    Calculate the latest MIN value incrementally
+    Calculate a single Present Value (PV)
 
     Args:
       py: Python interpreter token
-      price: Current period's price
-      prev_min: Previous period's MIN value
-      prev_price: Price value being removed from the period
-      period: Period for MIN calculation (must be >= 2)
+      rate: Interest rate per period
+      nper: Number of compounding periods
+      pmt: Payment made each period
+      fv: Future value
+      when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
 
     Returns:
-      The new MIN value
+      The present value.
 
     Examples:
       ```python
       >>> import kand
-      >>> new_min = kand.min_inc(15.0, 12.0, 14.0, 14)
+      >>> kand.pv_inc(0.0, 10.0, -100.0, 0.0)
+      1000.0
       ```
     """
     ...
 
-def minus_di(high, low, close, period):
+def pvi(close, volume, start=1000.0):
     """
-    Computes the Minus Directional Indicator (-DI) over NumPy arrays.
+    Computes the Positive Volume Index (PVI) over NumPy arrays.
 
-    The -DI measures the presence and strength of a downward price trend. It is one component used in calculating
-    the Average Directional Index (ADX), which helps determine trend strength.
+    The PVI compounds the percentage price change of bars whose volume rose above the
+    previous bar's and stays flat on every other bar.
 
     Args:
-      high: High prices as a 1-D NumPy array of type `TAFloat`.
-      low: Low prices as a 1-D NumPy array of type `TAFloat`.
       close: Close prices as a 1-D NumPy array of type `TAFloat`.
-      period: Window size for -DI calculation. Must be positive and less than input length.
+      volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+      start: Initial index value. Defaults to 1000.
 
     Returns:
-      A tuple of three 1-D NumPy arrays containing:
-      - The -DI values
-      - The smoothed -DM values
-      - The smoothed TR values
-      Each array has the same length as the input, with the first `period` elements containing NaN values.
+      A new 1-D NumPy array containing the PVI values. The array has the same length as the input.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> high = np.array([35.0, 36.0, 35.5, 35.8, 36.2])
-      >>> low = np.array([34.0, 35.0, 34.5, 34.8, 35.2])
-      >>> close = np.array([34.5, 35.5, 35.0, 35.3, 35.7])
-      >>> minus_di, smoothed_minus_dm, smoothed_tr = kand.minus_di(high, low, close, 3)
-      >>> print(minus_di)
-      [nan, nan, nan, 25.3, 24.1]
+      >>> close = np.array([10.0, 11.0, 12.1])
+      >>> volume = np.array([100.0, 120.0, 80.0])
+      >>> result = kand.pvi(close, volume)
+      >>> print(result)
+      [1000. 1100. 1100.]
       ```
     """
     ...
 
-def minus_dm(high, low, period):
+def pvi_inc(close, volume, prev_close, prev_volume, prev_pvi):
     """
-    Computes the Minus Directional Movement (-DM) over NumPy arrays.
-
-    Minus Directional Movement (-DM) measures downward price movement and is used as part of the
-    Directional Movement System developed by J. Welles Wilder.
+    Calculates the next PVI value incrementally.
 
     Args:
-      high: Input high prices as a 1-D NumPy array of type `TAFloat`.
-      low: Input low prices as a 1-D NumPy array of type `TAFloat`.
-      period: Window size for -DM calculation. Must be positive and less than input length.
+      close: Current close price.
+      volume: Current volume.
+      prev_close: Previous close price.
+      prev_volume: Previous volume.
+      prev_pvi: Previous PVI value.
 
     Returns:
-      A new 1-D NumPy array containing the -DM values. The array has the same length as the input,
-      with the first `period-1` elements containing NaN values.
+      The new PVI value.
 
     Examples:
       ```python
-      >>> import numpy as np
       >>> import kand
-      >>> high = np.array([35266.0, 35247.5, 35235.7, 35190.8, 35182.0])
-      >>> low = np.array([35216.1, 35206.5, 35180.0, 35130.7, 35153.6])
-      >>> result = kand.minus_dm(high, low, 3)
+      >>> kand.pvi_inc(11.0, 120.0, 10.0, 100.0, 1000.0)
+      1100.0
       ```
     """
     ...
 
-def mom(data, period):
+def pvt(close, volume):
     """
-    Computes the Momentum (MOM) over a NumPy array.
+    Computes the Price Volume Trend (PVT) over NumPy arrays.
 
-    Momentum measures the change in price between the current price and the price n periods ago.
+    PVT accumulates volume weighted by the percentage change of the close, starting from 0.
 
     Args:
-      data: Input data as a 1-D NumPy array of type `TAFloat`.
-      period: Window size for momentum calculation. Must be positive and less than input length.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      volume: Volume data as a 1-D NumPy array of type `TAFloat`.
 
     Returns:
-      A new 1-D NumPy array containing the momentum values. The array has the same length as the input,
-      with the first `period` elements containing NaN values.
+      A new 1-D NumPy array containing the PVT values. The array has the same length as the input.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> data = np.array([2.0, 4.0, 6.0, 8.0, 10.0])
-      >>> result = kand.mom(data, 2)
+      >>> close = np.array([10.0, 11.0, 9.9])
+      >>> volume = np.array([100.0, 200.0, 300.0])
+      >>> result = kand.pvt(close, volume)
       >>> print(result)
-      [nan, nan, 4.0, 4.0, 4.0]
+      [  0.  20. -10.]
       ```
     """
     ...
 
-def mom_inc(current_price, old_price):
+def pvt_inc(close, prev_close, volume, prev_pvt):
     """
-    Calculates the next Momentum (MOM) value incrementally.
-
-    This function provides an optimized way to calculate the latest momentum value
-    when streaming data is available, without needing the full price history.
+    Calculates the next PVT value incrementally.
 
     Args:
-
-      current_price: The current period's price value.
-      old_price: The price value from n periods ago.
+      close: Current close price.
+      prev_close: Previous close price.
+      volume: Current volume.
+      prev_pvt: Previous PVT value.
 
     Returns:
-      The calculated momentum value.
+      The new PVT value.
 
     Examples:
       ```python
       >>> import kand
-      >>> momentum = kand.mom_inc(10.0, 6.0)
-      >>> print(momentum)
-      4.0
+      >>> kand.pvt_inc(11.0, 10.0, 200.0, 0.0)
+      20.0
       ```
     """
     ...
 
-def natr(high, low, close, period):
+def range_bars(price, range):
     """
-    Computes the Normalized Average True Range (NATR) over NumPy arrays.
+    Builds fixed-range bars from a NumPy array of prices.
 
-    The NATR is a measure of volatility that accounts for the price level of the instrument.
-    It expresses the ATR as a percentage of the closing price.
+    Each finished bar spans exactly `range` from low to high; the next bar opens at its close.
 
     Args:
-      high: High prices as a 1-D NumPy array of type `TAFloat`.
-      low: Low prices as a 1-D NumPy array of type `TAFloat`.
-      close: Close prices as a 1-D NumPy array of type `TAFloat`.
-      period: Window size for NATR calculation. Must be positive and less than input length.
+        price: Prices as a 1-D NumPy array of type `TAFloat`, typically trades or closes.
+        range: High-low range of each finished bar (must be > 0).
 
     Returns:
-      A new 1-D NumPy array containing the NATR values. The array has the same length as the input,
-      with the first `period` elements containing NaN values.
+        A tuple of six 1-D NumPy arrays with one entry per bar, the last bar still forming:
+        - Bar open prices
+        - Bar high prices
+        - Bar low prices
+        - Bar close prices
+        - Index of the price that opened each bar
+        - Index of the last price in each bar
 
     Examples:
-      ```python
-      >>> import numpy as np
-      >>> import kand
-      >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
-      >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
-      >>> close = np.array([9.0, 11.0, 14.0, 12.0, 11.0])
-      >>> result = kand.natr(high, low, close, 3)
-      ```
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> price = np.array([10.0, 10.5, 9.8, 11.1, 10.9, 12.5])
+        >>> opens, highs, lows, closes, start_idx, end_idx = kand.range_bars(price, 1.0)
+        ```
     """
     ...
 
-def natr_inc(high, low, close, prev_close, prev_atr, period):
+def rate(nper, pmt, pv, fv, when=0, guess=0.1, tol=1e-6, max_iter=100):
     """
-    Calculates the next NATR value incrementally.
+    Calculate the interest Rate per period (RATE) element-wise over NumPy arrays
 
-    This function provides an optimized way to calculate a single new NATR value
-    using the previous ATR value and current price data, without recalculating the entire series.
+    RATE is the per-period rate at which `nper` constant payments move the present value to the
+    future value. It is solved with Newton's method, falling back to bracketing and bisection
+    when Newton fails.
 
     Args:
-
-      high: Current period's high price.
-      low: Current period's low price.
-      close: Current period's closing price.
-      prev_close: Previous period's closing price.
-      prev_atr: Previous period's ATR value.
-      period: Period for NATR calculation (must be >= 2).
+      nper: Number of compounding periods as a 1-D NumPy array of type `TAFloat`.
+      pmt: Payment made each period as a 1-D NumPy array of type `TAFloat`.
+      pv: Present value as a 1-D NumPy array of type `TAFloat`.
+      fv: Future value as a 1-D NumPy array of type `TAFloat`.
+      when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+      guess: Starting rate for Newton's method. Defaults to 0.1.
+      tol: Convergence tolerance (must be > 0). Defaults to 1e-6.
+      max_iter: Maximum number of Newton iterations (must be > 0). Defaults to 100.
 
     Returns:
-      The calculated NATR value.
+      A 1-D NumPy array of rates per period with the same length as the inputs.
+
+    Raises:
+      ValueError: If the solver does not converge for any element.
 
     Examples:
       ```python
+      >>> import numpy as np
       >>> import kand
-      >>> natr = kand.natr_inc(
-      ...     15.0,  # high
-      ...     11.0,  # low
-      ...     14.0,  # close
-      ...     12.0,  # prev_close
-      ...     3.0,   # prev_atr
-      ...     3      # period
-      ... )
+      >>> nper = np.array([10.0])
+      >>> pmt = np.array([0.0])
+      >>> pv = np.array([-3500.0])
+      >>> fv = np.array([10000.0])
+      >>> kand.rate(nper, pmt, pv, fv)
+      array([0.11069085])
       ```
     """
     ...
 
-def obv(close, volume):
+def rate_inc(nper, pmt, pv, fv, when=0, guess=0.1, tol=1e-6, max_iter=100):
     """
-    Computes the On Balance Volume (OBV) over NumPy arrays.
-
-    On Balance Volume (OBV) is a momentum indicator that uses volume flow to predict changes in stock price.
-    When volume increases without a significant price change, the price will eventually jump upward.
-    When volume decreases without a significant price change, the price will eventually jump downward.
+    Calculate a single interest Rate per period
 
     Args:
-      close: Close prices as a 1-D NumPy array of type `TAFloat`.
-      volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+      py: Python interpreter token
+      nper: Number of compounding periods
+      pmt: Payment made each period
+      pv: Present value
+      fv: Future value
+      when: Payment timing, 0 for the end of each period and 1 for the beginning. Defaults to 0.
+      guess: Starting rate for Newton's method. Defaults to 0.1.
+      tol: Convergence tolerance (must be > 0). Defaults to 1e-6.
+      max_iter: Maximum number of Newton iterations (must be > 0). Defaults to 100.
 
     Returns:
-      A new 1-D NumPy array containing the OBV values. The array has the same length as the input.
+      The rate per period.
+
+    Raises:
+      ValueError: If the solver does not converge.
 
     Examples:
       ```python
-      >>> import numpy as np
       >>> import kand
-      >>> close = np.array([10.0, 12.0, 11.0, 13.0])
-      >>> volume = np.array([100.0, 150.0, 120.0, 200.0])
-      >>> result = kand.obv(close, volume)
-      >>> print(result)
-      [100.0, 250.0, 130.0, 330.0]
+      >>> round(kand.rate_inc(180.0, -1854.02, 200000.0, 0.0) * 12, 4)
+      0.075
       ```
     """
     ...
 
-def obv_inc(curr_close, prev_close, volume, prev_obv):
+def renko(high, low, close, box_size, reversal=2, source=0):
     """
-    Calculates the next OBV value incrementally.
+    Builds Renko bricks from NumPy arrays using a fixed box size.
 
-    This function provides an optimized way to calculate a single new OBV value
-    using the previous OBV value and current price/volume data.
+    A brick is drawn each time price moves a full box size. Continuing the current direction
+    needs one box, reversing it needs `reversal` boxes. One bar can complete several bricks.
 
     Args:
-      curr_close: Current closing price as `TAFloat`.
-      prev_close: Previous closing price as `TAFloat`.
-      volume: Current volume as `TAFloat`.
-      prev_obv: Previous OBV value as `TAFloat`.
+        high: High prices as a 1-D NumPy array of type `TAFloat`.
+        low: Low prices as a 1-D NumPy array of type `TAFloat`.
+        close: Close prices as a 1-D NumPy array of type `TAFloat`.
+        box_size: Price distance covered by each brick (must be > 0).
+        reversal: Boxes needed to reverse the direction. Defaults to 2.
+        source: 0 to measure moves on closes, 1 on highs and lows. Defaults to 0.
 
     Returns:
-      The calculated OBV value.
+        A tuple of four 1-D NumPy arrays with one entry per brick:
+        - Brick open prices
+        - Brick close prices
+        - Brick directions (1 up, -1 down)
+        - Index of the source bar that completed each brick
 
     Examples:
-      ```python
-      >>> import kand
-      >>> curr_close = 12.0
-      >>> prev_close = 10.0
-      >>> volume = 150.0
-      >>> prev_obv = 100.0
-      >>> result = kand.obv_inc(curr_close, prev_close, volume, prev_obv)
-      >>> print(result)
-      250.0
-      ```
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> close = np.array([10.0, 11.2, 13.1, 12.5, 10.9])
+        >>> opens, closes, directions, indices = kand.renko(close, close, close, 1.0)
+        ```
     """
     ...
 
-def plus_di(high, low, close, period):
+def renko_atr(high, low, close, atr_period=14, reversal=2, source=0):
     """
-    Computes the Plus Directional Indicator (+DI) over NumPy arrays.
+    Builds Renko bricks from NumPy arrays using a box size derived from the ATR.
 
-    +DI measures the presence and strength of an upward price trend. It is one component used in calculating
-    the Average Directional Index (ADX), which helps determine trend strength.
+    The box size is the first available ATR value, and the close of that bar is the base price,
+    so no future data is used.
 
     Args:
-      high: High prices as a 1-D NumPy array of type `TAFloat`.
-      low: Low prices as a 1-D NumPy array of type `TAFloat`.
-      close: Close prices as a 1-D NumPy array of type `TAFloat`.
-      period: Window size for +DI calculation. Must be positive and less than input length.
+        high: High prices as a 1-D NumPy array of type `TAFloat`.
+        low: Low prices as a 1-D NumPy array of type `TAFloat`.
+        close: Close prices as a 1-D NumPy array of type `TAFloat`.
+        atr_period: ATR period used for the box size. Defaults to 14.
+        reversal: Boxes needed to reverse the direction. Defaults to 2.
+        source: 0 to measure moves on closes, 1 on highs and lows. Defaults to 0.
 
     Returns:
-      A tuple of three 1-D NumPy arrays containing:
-      - +DI values
-      - Smoothed +DM values
-      - Smoothed TR values
-      Each array has the same length as the input, with the first `period` elements containing NaN values.
+        A tuple of four 1-D NumPy arrays with one entry per brick (open, close, direction and
+        source bar index), followed by the box size that was used.
 
     Examples:
-      ```python
-      >>> import numpy as np
-      >>> import kand
-      >>> high = np.array([10.0, 12.0, 11.5, 11.0])
-      >>> low = np.array([9.0, 10.0, 10.0, 9.5])
-      >>> close = np.array([9.5, 11.0, 10.5, 10.0])
-      >>> plus_di, smoothed_plus_dm, smoothed_tr = kand.plus_di(high, low, close, 2)
-      ```
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> high = np.array([10.5, 11.0, 11.5, 12.5, 14.0, 13.0])
+        >>> low = np.array([9.5, 10.0, 10.5, 11.5, 12.5, 10.0])
+        >>> close = np.array([10.0, 10.5, 11.0, 12.0, 13.5, 10.5])
+        >>> opens, closes, directions, indices, box_size = kand.renko_atr(high, low, close, 2)
+        ```
     """
     ...
 
-def plus_di_inc(high, low, prev_high, prev_low, prev_close, prev_smoothed_plus_dm, prev_smoothed_tr, period):
+def resample(timestamp, open, high, low, close, volume, interval, offset=0):
     """
-    Calculates the next +DI value incrementally using previous smoothed values.
+    Resamples OHLCV bars from NumPy arrays onto a longer time frame.
 
-    This function enables real-time calculation of +DI by using the previous smoothed values
-    and current price data, avoiding the need to recalculate the entire series.
+    Bars are grouped by epoch-aligned intervals: first open, highest high, lowest low, last
+    close and summed volume. Intervals without source bars produce no bar.
 
     Args:
-      high: Current high price as `TAFloat`.
-      low: Current low price as `TAFloat`.
-      prev_high: Previous high price as `TAFloat`.
-      prev_low: Previous low price as `TAFloat`.
-      prev_close: Previous close price as `TAFloat`.
-      prev_smoothed_plus_dm: Previous smoothed +DM value as `TAFloat`.
-      prev_smoothed_tr: Previous smoothed TR value as `TAFloat`.
-      period: Smoothing period (>= 2).
+        timestamp: Bar start times as a 1-D NumPy array of type `int64`, strictly increasing.
+        open: Open prices as a 1-D NumPy array of type `TAFloat`.
+        high: High prices as a 1-D NumPy array of type `TAFloat`.
+        low: Low prices as a 1-D NumPy array of type `TAFloat`.
+        close: Close prices as a 1-D NumPy array of type `TAFloat`.
+        volume: Volumes as a 1-D NumPy array of type `TAFloat`.
+        interval: Length of the resampled bars in timestamp units (must be > 0).
+        offset: Shift of the interval grid from the epoch (0 <= offset < interval). Defaults to 0.
 
     Returns:
-      A tuple containing (latest +DI, new smoothed +DM, new smoothed TR).
+        A tuple of six 1-D NumPy arrays with one entry per resampled bar, the last bar possibly
+        covering only part of its interval:
+        - Bar start times
+        - Open prices
+        - High prices
+        - Low prices
+        - Close prices
+        - Volumes
 
     Examples:
-      ```python
-      >>> import kand
-      >>> plus_di, smoothed_plus_dm, smoothed_tr = kand.plus_di_inc(
-      ...     10.5,  # high
-      ...     9.5,   # low
-      ...     10.0,  # prev_high
-      ...     9.0,   # prev_low
-      ...     9.5,   # prev_close
-      ...     15.0,  # prev_smoothed_plus_dm
-      ...     20.0,  # prev_smoothed_tr
-      ...     14     # period
-      ... )
-      ```
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> ts = np.arange(10) * 60_000
+        >>> price = np.linspace(10.0, 11.0, 10)
+        >>> ts5, o, h, l, c, v = kand.resample(ts, price, price, price, price, np.ones(10), 300_000)
+        ```
     """
     ...
 
-def plus_dm(high, low, period):
+def ret(prices, period=1):
     """
-    Computes the Plus Directional Movement (+DM) over NumPy arrays.
+    Calculate rolling simple Returns (RET) for a NumPy array
 
-    Plus Directional Movement (+DM) measures upward price movement and is used as part of the
-    Directional Movement System developed by J. Welles Wilder.
+    The return over a window is the relative change from the price `period` bars ago to the
+    current price. With the default period of 1 this yields per-bar returns.
 
     Args:
-      high: Input high prices as a 1-D NumPy array of type `TAFloat`.
-      low: Input low prices as a 1-D NumPy array of type `TAFloat`.
-      period: Window size for +DM calculation. Must be positive and less than input length.
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+      period: Number of bars the return spans (must be >= 1). Defaults to 1.
 
     Returns:
-      A new 1-D NumPy array containing the +DM values. The array has the same length as the input,
-      with the first `period-1` elements containing NaN values.
+      A 1-D NumPy array containing return values. The first `period` elements contain NaN values.
 
     Examples:
       ```python
       >>> import numpy as np
       >>> import kand
-      >>> high = np.array([35266.0, 35247.5, 35235.7, 35190.8, 35182.0])
-      >>> low = np.array([35216.1, 35206.5, 35180.0, 35130.7, 35153.6])
-      >>> result = kand.plus_dm(high, low, 3)
+      >>> prices = np.array([100.0, 110.0, 99.0, 108.9])
+      >>> returns = kand.ret(prices)
       ```
     """
     ...
 
-def plus_dm_inc(high, prev_high, low, prev_low, prev_plus_dm, period):
+def ret_expanding(prices):
     """
-    Calculates the next Plus DM value incrementally using previous values.
+    Calculate expanding (cumulative) Returns for a NumPy array
+
+    Args:
+      prices: Input prices as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A 1-D NumPy array containing the return since the first price. The first element is 0.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> prices = np.array([100.0, 110.0, 99.0])
+      >>> cumulative = kand.ret_expanding(prices)
+      ```
+    """
+    ...
 
-    This function enables real-time calculation of Plus DM by using the previous Plus DM value
-    and current price data, avoiding the need to recalculate the entire series.
+def ret_inc(price, old_price):
+    """
+    Calculate the latest Return value
 
     Args:
-      high: Current high price as `TAFloat`.
-      prev_high: Previous high price as `TAFloat`.
-      low: Current low price as `TAFloat`.
-      prev_low: Previous low price as `TAFloat`.
-      prev_plus_dm: Previous Plus DM value as `TAFloat`.
-      period: Smoothing period (>= 2).
+      py: Python interpreter token
+      price: Current period's price
+      old_price: Reference price, either the price `period` bars ago or the first price
 
     Returns:
-      The latest Plus DM value.
+      The latest return value
 
     Examples:
       ```python
       >>> import kand
-      >>> new_plus_dm = kand.plus_dm_inc(
-      ...     10.5,  # high
-      ...     10.0,  # prev_high
-      ...     9.8,   # low
-      ...     9.5,   # prev_low
-      ...     0.45,  # prev_plus_dm
-      ...     14     # period
-      ... )
+      >>> value = kand.ret_inc(110.0, 100.0)
       ```
     """
     ...
@@ -2437,6 +4636,229 @@ def sar_inc(high, low, prev_high, prev_low, prev_sar, is_long, af, ep, accelerat
     """
     ...
 
+def sarext(high, low, start_value=0.0, offset_on_reverse=0.0, af_init_long=0.02, af_long=0.02, af_max_long=0.2, af_init_short=0.02, af_short=0.02, af_max_short=0.2):
+    """
+    Calculates the Extended Parabolic SAR (SAREXT) over NumPy arrays.
+
+    SAREXT adds a start value, an offset on reversals and separate long/short acceleration
+    factors to the Parabolic SAR. Following TA-Lib, values are positive while long and negative
+    while short.
+
+    Args:
+      high: Input high prices as a 1-D NumPy array of type `TAFloat`.
+      low: Input low prices as a 1-D NumPy array of type `TAFloat`.
+      start_value: Initial SAR; 0 detects the trend, a negative value starts short. Defaults to 0.
+      offset_on_reverse: Fraction of the SAR used as offset on reversals. Defaults to 0.
+      af_init_long: Initial long acceleration factor. Defaults to 0.02.
+      af_long: Long acceleration factor step. Defaults to 0.02.
+      af_max_long: Maximum long acceleration factor. Defaults to 0.2.
+      af_init_short: Initial short acceleration factor. Defaults to 0.02.
+      af_short: Short acceleration factor step. Defaults to 0.02.
+      af_max_short: Maximum short acceleration factor. Defaults to 0.2.
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - Signed SAR values
+      - Stops projected for the next bar
+      - Trend direction (true=long, false=short)
+      - Acceleration factors
+      - Extreme points
+      Each array has the same length as the input, with the first element containing NaN.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
+      >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
+      >>> sar, next_sar, is_long, af, ep = kand.sarext(high, low, af_max_short=0.3)
+      ```
+    """
+    ...
+
+def sarext_inc(high, low, prev_high, prev_low, prev_sar, is_long, af, ep, offset_on_reverse=0.0, af_init_long=0.02, af_long=0.02, af_max_long=0.2, af_init_short=0.02, af_short=0.02, af_max_short=0.2):
+    """
+    Incrementally updates the Extended Parabolic SAR with new price data.
+
+    Args:
+      high: Current period's high price.
+      low: Current period's low price.
+      prev_high: Previous period's high price.
+      prev_low: Previous period's low price.
+      prev_sar: Stop projected for the current period (the previous `next_sar`).
+      is_long: Current trend direction (true=long, false=short).
+      af: Current acceleration factor.
+      ep: Current extreme point.
+      offset_on_reverse: Fraction of the SAR used as offset on reversals. Defaults to 0.
+      af_init_long: Initial long acceleration factor. Defaults to 0.02.
+      af_long: Long acceleration factor step. Defaults to 0.02.
+      af_max_long: Maximum long acceleration factor. Defaults to 0.2.
+      af_init_short: Initial short acceleration factor. Defaults to 0.02.
+      af_short: Short acceleration factor step. Defaults to 0.02.
+      af_max_short: Maximum short acceleration factor. Defaults to 0.2.
+
+    Returns:
+      A tuple containing (signed SAR value, next projected stop, trend direction, acceleration
+      factor, extreme point).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> sar, next_sar, is_long, af, ep = kand.sarext_inc(
+      ...     15.0, 14.0, 14.5, 13.5, 13.0, True, 0.02, 14.5
+      ... )
+      ```
+    """
+    ...
+
+def session_vwap(timestamp, high, low, close, volume, session, offset=0, dev=2.0):
+    """
+    Calculates session VWAP with standard deviation bands, restarting at each session start.
+
+    Sessions are intervals of length `session` aligned to the epoch shifted by `offset`, e.g.
+    a daily session opening at 13:30 UTC on millisecond timestamps uses
+    `session=86_400_000, offset=48_600_000`.
+
+    Args:
+      timestamp: Bar start times as a 1-D NumPy array of type `int64`.
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+      session: Session length in timestamp units (must be > 0).
+      offset: Shift of the session grid from the epoch. Defaults to 0.
+      dev: Number of standard deviations for the bands. Defaults to 2.0.
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - VWAP values
+      - Upper band values
+      - Lower band values
+      - Cumulative volume-weighted squared deviations
+      - Cumulative volumes
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> ts = np.arange(48) * 3600
+      >>> close = 100.0 + np.arange(48, dtype=float)
+      >>> volume = np.ones(48)
+      >>> vwap, upper, lower, _, _ = kand.session_vwap(ts, close, close, close, volume, 86400)
+      ```
+    """
+    ...
+
+def sharpe(returns, period, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the rolling Sharpe Ratio for a NumPy array of returns
+
+    Excess returns over the per-period risk-free rate are divided by their sample standard
+    deviation and annualized with the square root of `annualization`.
+
+    Args:
+      returns: Per-period returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window (must be >= 2).
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Sharpe Ratio values
+      - Running sum of excess returns
+      - Running sum of squared excess returns
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+      >>> sharpe, sum, sum_sq = kand.sharpe(returns, 3, 252.0, 0.02)
+      ```
+    """
+    ...
+
+def sharpe_expanding(returns, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the expanding Sharpe Ratio for a NumPy array of returns
+
+    Args:
+      returns: Per-period returns as a 1-D NumPy array of type `TAFloat`.
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Sharpe Ratio values, NaN for the first element
+      - Cumulative sum of excess returns
+      - Cumulative sum of squared excess returns
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.015, 0.005])
+      >>> sharpe, sum, sum_sq = kand.sharpe_expanding(returns)
+      ```
+    """
+    ...
+
+def sharpe_expanding_inc(ret, prev_sum, prev_sum_sq, prev_count, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the latest expanding Sharpe Ratio incrementally
+
+    Args:
+      py: Python interpreter token
+      ret: Current period's return
+      prev_sum: Previous cumulative sum of excess returns
+      prev_sum_sq: Previous cumulative sum of squared excess returns
+      prev_count: Number of returns already included in the sums
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple containing:
+      - Latest Sharpe Ratio value, NaN for the first return
+      - New cumulative sum of excess returns
+      - New cumulative sum of squared excess returns
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> sharpe, sum, sum_sq = kand.sharpe_expanding_inc(0.005, -0.01, 0.0005, 2)
+      ```
+    """
+    ...
+
+def sharpe_inc(ret, old_ret, prev_sum, prev_sum_sq, period, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the latest rolling Sharpe Ratio incrementally
+
+    Args:
+      py: Python interpreter token
+      ret: Current period's return
+      old_ret: Return leaving the window
+      prev_sum: Previous sum of excess returns
+      prev_sum_sq: Previous sum of squared excess returns
+      period: Number of returns in the rolling window (must be >= 2)
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple containing:
+      - Latest Sharpe Ratio value
+      - New sum of excess returns
+      - New sum of squared excess returns
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> sharpe, sum, sum_sq = kand.sharpe_inc(0.005, 0.01, 0.005, 0.000725, 3)
+      ```
+    """
+    ...
+
 def sma(data, period):
     """
     Computes the Simple Moving Average (SMA) over a NumPy array.
@@ -2465,33 +4887,214 @@ def sma(data, period):
     """
     ...
 
-def sma_inc(prev_sma, new_price, old_price, period):
+def sma_inc(prev_sma, new_price, old_price, period):
+    """
+    Incrementally calculates the next SMA value.
+
+    This function provides an optimized way to update an existing SMA value
+    when new data arrives, without recalculating the entire series.
+
+    Args:
+        prev_sma: Previous SMA value.
+        new_price: New price to include in calculation.
+        old_price: Oldest price to remove from calculation.
+        period: The time period for SMA calculation (must be >= 2).
+
+    Returns:
+        The next SMA value.
+
+    Examples:
+        ```python
+        >>> import kand
+        >>> prev_sma = 4.0
+        >>> new_price = 10.0
+        >>> old_price = 2.0
+        >>> period = 3
+        >>> next_sma = kand.sma_inc(prev_sma, new_price, old_price, period)
+        >>> print(next_sma)
+        6.666666666666666
+        ```
+    """
+    ...
+
+def sortino(returns, period, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the rolling Sortino Ratio for a NumPy array of returns
+
+    Like the Sharpe Ratio, but excess returns over the per-period risk-free rate are divided by
+    their downside deviation, so only returns below the target count as risk.
+
+    Args:
+      returns: Per-period returns as a 1-D NumPy array of type `TAFloat`.
+      period: Number of returns in the rolling window (must be >= 2).
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Sortino Ratio values
+      - Running sum of excess returns
+      - Running sum of squared downside excess returns
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.015, 0.005, -0.01])
+      >>> sortino, sum, sum_down_sq = kand.sortino(returns, 3, 252.0, 0.02)
+      ```
+    """
+    ...
+
+def sortino_expanding(returns, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the expanding Sortino Ratio for a NumPy array of returns
+
+    Args:
+      returns: Per-period returns as a 1-D NumPy array of type `TAFloat`.
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple of three 1-D NumPy arrays containing:
+      - Sortino Ratio values, NaN for the first element
+      - Cumulative sum of excess returns
+      - Cumulative sum of squared downside excess returns
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> returns = np.array([0.01, -0.02, 0.015, 0.005])
+      >>> sortino, sum, sum_down_sq = kand.sortino_expanding(returns)
+      ```
+    """
+    ...
+
+def sortino_expanding_inc(ret, prev_sum, prev_sum_down_sq, prev_count, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the latest expanding Sortino Ratio incrementally
+
+    Args:
+      py: Python interpreter token
+      ret: Current period's return
+      prev_sum: Previous cumulative sum of excess returns
+      prev_sum_down_sq: Previous cumulative sum of squared downside excess returns
+      prev_count: Number of returns already included in the sums
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple containing:
+      - Latest Sortino Ratio value, NaN for the first return
+      - New cumulative sum of excess returns
+      - New cumulative sum of squared downside excess returns
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> sortino, sum, sum_down_sq = kand.sortino_expanding_inc(0.005, -0.01, 0.0004, 2)
+      ```
+    """
+    ...
+
+def sortino_inc(ret, old_ret, prev_sum, prev_sum_down_sq, period, annualization=252.0, risk_free=0.0):
+    """
+    Calculate the latest rolling Sortino Ratio incrementally
+
+    Args:
+      py: Python interpreter token
+      ret: Current period's return
+      old_ret: Return leaving the window
+      prev_sum: Previous sum of excess returns
+      prev_sum_down_sq: Previous sum of squared downside excess returns
+      period: Number of returns in the rolling window (must be >= 2)
+      annualization: Periods per year (must be > 0). Defaults to 252.
+      risk_free: Annual risk-free rate. Defaults to 0.
+
+    Returns:
+      A tuple containing:
+      - Latest Sortino Ratio value
+      - New sum of excess returns
+      - New sum of squared downside excess returns
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> sortino, sum, sum_down_sq = kand.sortino_inc(0.005, 0.01, 0.005, 0.0004, 3)
+      ```
+    """
+    ...
+
+def starc(high, low, close, period=6, atr_period=15, multiplier=2.0):
+    """
+    Computes STARC Bands (Stoller Average Range Channels) over NumPy arrays.
+
+    The middle line is a short SMA of the close and the bands sit a multiple of a longer
+    Average True Range above and below it.
+
+    Args:
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      period: Period of the SMA middle line. Defaults to 6.
+      atr_period: Period of the ATR. Defaults to 15.
+      multiplier: ATR multiple for the bands. Defaults to 2.0.
+
+    Returns:
+      A tuple of six 1-D NumPy arrays containing:
+      - Upper band values
+      - Middle line values
+      - Lower band values
+      - ATR values
+      - %B values
+      - Bandwidth values
+      Each array has the same length as the input, with the warm-up elements containing
+      NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([10.0, 11.0, 12.0, 11.5, 12.5])
+      >>> low = np.array([9.0, 9.5, 10.5, 10.0, 11.0])
+      >>> close = np.array([9.5, 10.5, 11.5, 10.5, 12.0])
+      >>> upper, middle, lower, atr, percent_b, bandwidth = kand.starc(high, low, close, 2, 3)
+      >>> print(upper[3], lower[3])
+      14.0 8.0
+      ```
+    """
+    ...
+
+def starc_inc(high, low, close, prev_close, old_close, prev_middle, prev_atr, period, atr_period, multiplier):
     """
-    Incrementally calculates the next SMA value.
-
-    This function provides an optimized way to update an existing SMA value
-    when new data arrives, without recalculating the entire series.
+    Calculates the next STARC Band values incrementally.
 
     Args:
-        prev_sma: Previous SMA value.
-        new_price: New price to include in calculation.
-        old_price: Oldest price to remove from calculation.
-        period: The time period for SMA calculation (must be >= 2).
+      high: Current high price.
+      low: Current low price.
+      close: Current close price.
+      prev_close: Previous close price.
+      old_close: Close leaving the SMA window, `period` bars back.
+      prev_middle: Previous SMA middle line value.
+      prev_atr: Previous ATR value.
+      period: Period of the SMA middle line.
+      atr_period: Period of the ATR.
+      multiplier: ATR multiple for the bands.
 
     Returns:
-        The next SMA value.
+      A tuple of (upper, middle, lower, atr, percent_b, bandwidth).
 
     Examples:
-        ```python
-        >>> import kand
-        >>> prev_sma = 4.0
-        >>> new_price = 10.0
-        >>> old_price = 2.0
-        >>> period = 3
-        >>> next_sma = kand.sma_inc(prev_sma, new_price, old_price, period)
-        >>> print(next_sma)
-        6.666666666666666
-        ```
+      ```python
+      >>> import kand
+      >>> upper, middle, lower, atr, percent_b, bandwidth = kand.starc_inc(
+      ...     12.0, 10.5, 12.0, 10.5, 11.5, 11.0, 1.5, 2, 3, 2.0
+      ... )
+      >>> print(upper, lower)
+      14.25 8.25
+      ```
     """
     ...
 
@@ -2555,7 +5158,7 @@ def stddev_inc(price, prev_sum, prev_sum_sq, old_price, period):
     """
     ...
 
-def stoch(high, low, close, k_period, k_slow_period, d_period):
+def stoch(high, low, close, k_period, k_slow_period, d_period, k_ma_type=5, d_ma_type=5):
     """
     Computes the Stochastic Oscillator indicator over NumPy arrays.
 
@@ -2570,6 +5173,9 @@ def stoch(high, low, close, k_period, k_slow_period, d_period):
         k_period: Period for %K calculation. Must be >= 2.
         k_slow_period: Smoothing period for slow %K. Must be >= 2.
         d_period: Period for %D calculation. Must be >= 2.
+        k_ma_type: Moving average type for slow %K: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA, 4=RMA,
+            5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+        d_ma_type: Moving average type for %D, same values as `k_ma_type`. Defaults to 5 (SMA).
 
     Returns:
         A tuple of three 1-D NumPy arrays containing:
@@ -2590,6 +5196,74 @@ def stoch(high, low, close, k_period, k_slow_period, d_period):
     """
     ...
 
+def stochf(high, low, close, k_period, d_period, d_ma_type=5):
+    """
+    Computes the Fast Stochastic Oscillator (STOCHF) over NumPy arrays.
+
+    The Fast Stochastic is the unsmoothed variant of the Stochastic Oscillator: fast %K locates
+    the close within the high-low range and fast %D is a moving average of fast %K.
+
+    Args:
+        high: High prices as a 1-D NumPy array of type `TAFloat`.
+        low: Low prices as a 1-D NumPy array of type `TAFloat`.
+        close: Close prices as a 1-D NumPy array of type `TAFloat`.
+        k_period: Period for fast %K calculation. Must be >= 2.
+        d_period: Period for fast %D calculation. Must be >= 2.
+        d_ma_type: Moving average type for fast %D: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA, 4=RMA,
+            5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+
+    Returns:
+        A tuple of two 1-D NumPy arrays containing:
+        - Fast %K values
+        - Fast %D values
+        Each array has the same length as the input, with initial values being NaN.
+
+    Examples:
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> high = np.array([10.0, 12.0, 15.0, 14.0, 13.0])
+        >>> low = np.array([8.0, 9.0, 11.0, 10.0, 9.0])
+        >>> close = np.array([9.0, 11.0, 14.0, 12.0, 11.0])
+        >>> fast_k, fast_d = kand.stochf(high, low, close, 3, 2)
+        ```
+    """
+    ...
+
+def stochrsi(data, rsi_period, k_period, d_period, d_ma_type=5):
+    """
+    Computes the Stochastic RSI (STOCHRSI) over a NumPy array.
+
+    Stochastic RSI applies the Fast Stochastic Oscillator to the RSI series instead of prices,
+    showing where the current RSI sits within its recent range.
+
+    Args:
+        data: Input prices as a 1-D NumPy array of type `TAFloat`.
+        rsi_period: Period for RSI calculation. Must be >= 2.
+        k_period: Period for fast %K calculation. Must be >= 2.
+        d_period: Period for fast %D calculation. Must be >= 2.
+        d_ma_type: Moving average type for fast %D: 0=DEMA, 1=EMA, 2=KAMA, 3=MAMA, 4=RMA,
+            5=SMA, 6=T3, 7=TEMA, 8=TRIMA, 9=WMA. Defaults to 5 (SMA).
+
+    Returns:
+        A tuple of five 1-D NumPy arrays containing:
+        - Fast %K values
+        - Fast %D values
+        - RSI values
+        - RSI average gain values
+        - RSI average loss values
+        Each array has the same length as the input, with initial values being NaN.
+
+    Examples:
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> data = np.array([44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42])
+        >>> fast_k, fast_d, rsi, avg_gain, avg_loss = kand.stochrsi(data, 3, 3, 2)
+        ```
+    """
+    ...
+
 def sum(input, period):
     """
     Calculate Sum for a NumPy array
@@ -2860,6 +5534,93 @@ def tema_inc(new_price, prev_ema1, prev_ema2, prev_ema3, period):
     """
     ...
 
+def trade_bars(timestamp, price, size, threshold, bar_type=0):
+    """
+    Aggregates trades from NumPy arrays into OHLCV bars.
+
+    Bars close on a fixed time interval, trade count, traded volume, traded value or tick
+    imbalance. Trades are never split between bars.
+
+    Args:
+        timestamp: Trade timestamps as a 1-D NumPy array of type `int64`, non-decreasing.
+        price: Trade prices as a 1-D NumPy array of type `TAFloat`.
+        size: Trade sizes as a 1-D NumPy array of type `TAFloat`.
+        threshold: Interval length in timestamp units, trade count, volume, value or imbalance
+            per bar (must be > 0, and a whole number for time and tick bars).
+        bar_type: 0=time, 1=tick, 2=volume, 3=dollar, 4=tick imbalance. Defaults to 0.
+
+    Returns:
+        A tuple of eight 1-D NumPy arrays with one entry per bar:
+        - Bar timestamps (interval start for time bars, first trade otherwise)
+        - Open prices
+        - High prices
+        - Low prices
+        - Close prices
+        - Volumes
+        - Trade counts
+        - Whether each bar is complete; the last bar is False while its rule has not been met
+
+    Examples:
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> ts = np.array([0, 20, 45, 61, 70, 130])
+        >>> price = np.array([10.0, 10.5, 9.8, 10.1, 10.4, 10.2])
+        >>> size = np.array([1.0, 2.0, 1.0, 3.0, 1.0, 2.0])
+        >>> ts_out, o, h, l, c, v, n, complete = kand.trade_bars(ts, price, size, 60.0)
+        >>> complete
+        array([ True,  True, False])
+        ```
+    """
+    ...
+
+def trade_pnl(entry, exit, size):
+    """
+    Calculate the profit and loss of each trade from its entry and exit prices
+
+    Args:
+      entry: Entry prices as a 1-D NumPy array of type `TAFloat`.
+      exit: Exit prices as a 1-D NumPy array of type `TAFloat`.
+      size: Signed position sizes as a 1-D NumPy array of type `TAFloat`, negative for shorts.
+
+    Returns:
+      A 1-D NumPy array with the PnL of each trade, (exit - entry) * size.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> entry = np.array([100.0, 50.0])
+      >>> exit = np.array([110.0, 55.0])
+      >>> size = np.array([2.0, -1.0])
+      >>> kand.trade_pnl(entry, exit, size)
+      array([20., -5.])
+      ```
+    """
+    ...
+
+def trade_pnl_inc(entry, exit, size):
+    """
+    Calculate the profit and loss of a single trade
+
+    Args:
+      py: Python interpreter token
+      entry: Entry price
+      exit: Exit price
+      size: Signed position size, negative for short trades
+
+    Returns:
+      The PnL of the trade.
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> kand.trade_pnl_inc(100.0, 95.0, -3.0)
+      15.0
+      ```
+    """
+    ...
+
 def trange(high, low, close):
     """
     Computes the True Range (TR) over NumPy arrays.
@@ -3083,6 +5844,69 @@ def typprice_inc(high, low, close):
     """
     ...
 
+def ultosc(high, low, close, period1=7, period2=14, period3=28):
+    """
+    Computes the Ultimate Oscillator (ULTOSC) over NumPy arrays.
+
+    The Ultimate Oscillator combines buying pressure relative to the true range over three
+    timeframes. The shortest window is weighted 4, the medium one 2 and the longest one 1,
+    whatever order the periods are given in.
+
+    Args:
+        high: High prices as a 1-D NumPy array of type `TAFloat`.
+        low: Low prices as a 1-D NumPy array of type `TAFloat`.
+        close: Close prices as a 1-D NumPy array of type `TAFloat`.
+        period1: First averaging period. Defaults to 7.
+        period2: Second averaging period. Defaults to 14.
+        period3: Third averaging period. Defaults to 28.
+
+    Returns:
+        A new 1-D NumPy array containing the ULTOSC values. The array has the same length as the
+        inputs, with the first `max(period1, period2, period3)` elements containing NaN values.
+
+    Examples:
+        ```python
+        >>> import numpy as np
+        >>> import kand
+        >>> high = np.array([10.0, 11.0, 12.0, 11.5, 12.5])
+        >>> low = np.array([9.0, 9.5, 10.5, 10.0, 11.0])
+        >>> close = np.array([9.5, 10.5, 11.5, 10.5, 12.0])
+        >>> result = kand.ultosc(high, low, close, 1, 2, 3)
+        ```
+    """
+    ...
+
+def ultosc_inc(high, low, close, prev_close, old_values, prev_sums):
+    """
+    Computes the latest Ultimate Oscillator (ULTOSC) value incrementally.
+
+    Updates the rolling buying pressure and true range sums of the three windows, ordered from
+    the shortest to the longest period.
+
+    Args:
+        high: Current high price.
+        low: Current low price.
+        close: Current close price.
+        prev_close: Previous close price.
+        old_values: `(buying pressure, true range)` leaving each window, `(0.0, 0.0)` while a
+            window is still filling up.
+        prev_sums: Previous `(buying pressure, true range)` sums of each window.
+
+    Returns:
+        A tuple of (ULTOSC value, buying pressure, true range, new sums).
+
+    Examples:
+        ```python
+        >>> import kand
+        >>> value, bp, tr, sums = kand.ultosc_inc(
+        ...     12.0, 11.0, 12.0, 10.5,
+        ...     [(1.0, 2.0), (0.0, 0.0), (0.0, 0.0)],
+        ...     [(1.0, 2.0), (2.0, 4.0), (3.0, 6.0)],
+        ... )
+        ```
+    """
+    ...
+
 def var(prices, period):
     """
     Calculate Variance (VAR) for a NumPy array
@@ -3232,6 +6056,69 @@ def vwap(high, low, close, volume):
     """
     ...
 
+def vwap_bands(high, low, close, volume, reset, dev=2.0):
+    """
+    Calculates VWAP with standard deviation bands, restarting wherever `reset` is true.
+
+    Args:
+      high: High prices as a 1-D NumPy array of type `TAFloat`.
+      low: Low prices as a 1-D NumPy array of type `TAFloat`.
+      close: Close prices as a 1-D NumPy array of type `TAFloat`.
+      volume: Volume data as a 1-D NumPy array of type `TAFloat`.
+      reset: Session start flags as a 1-D NumPy array of type `bool`.
+      dev: Number of standard deviations for the bands. Defaults to 2.0.
+
+    Returns:
+      A tuple of five 1-D NumPy arrays containing:
+      - VWAP values
+      - Upper band values
+      - Lower band values
+      - Cumulative volume-weighted squared deviations
+      - Cumulative volumes
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> high = np.array([12.0, 15.0, 14.0, 13.0])
+      >>> low = np.array([8.0, 11.0, 10.0, 9.0])
+      >>> close = np.array([10.0, 13.0, 12.0, 11.0])
+      >>> volume = np.array([100.0, 100.0, 100.0, 300.0])
+      >>> reset = np.array([False, False, True, False])
+      >>> vwap, upper, lower, cum_sq_dev, cum_vol = kand.vwap_bands(high, low, close, volume, reset, 1.0)
+      ```
+    """
+    ...
+
+def vwap_bands_inc(high, low, close, volume, prev_vwap, prev_cum_sq_dev, prev_cum_vol, dev=2.0):
+    """
+    Calculates the next VWAP and band values from the previous session state.
+
+    Pass zero for `prev_vwap`, `prev_cum_sq_dev` and `prev_cum_vol` on the first bar of a
+    session or at the anchor.
+
+    Args:
+      high: Latest high price value as `TAFloat`.
+      low: Latest low price value as `TAFloat`.
+      close: Latest close price value as `TAFloat`.
+      volume: Latest volume value as `TAFloat`.
+      prev_vwap: Previous VWAP value as `TAFloat`.
+      prev_cum_sq_dev: Previous cumulative volume-weighted squared deviation as `TAFloat`.
+      prev_cum_vol: Previous cumulative volume as `TAFloat`.
+      dev: Number of standard deviations for the bands. Defaults to 2.0.
+
+    Returns:
+      A tuple containing (VWAP, upper band, lower band, cumulative squared deviation,
+      cumulative volume).
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> vwap, upper, lower, cum_sq_dev, cum_vol = kand.vwap_bands_inc(15.0, 11.0, 13.0, 100.0, 10.0, 0.0, 100.0, 1.0)
+      ```
+    """
+    ...
+
 def vwap_inc(high, low, close, volume, prev_cum_pv, prev_cum_vol):
     """
     Calculates a single VWAP value from the latest price and volume data.
@@ -3343,6 +6230,11 @@ def willr_inc(prev_highest_high, prev_lowest_low, prev_high, prev_low, close, hi
     This function provides an optimized way to calculate the latest Williams %R value
     by using previously calculated highest high and lowest low values.
 
+    Deprecated:
+        The highest high and lowest low cannot be updated exactly from their previous values
+        once the extreme leaves the window. Use the `Willr` streaming object, which keeps the
+        rolling windows between calls.
+
     Args:
         prev_highest_high: Previous period's highest high value.
         prev_lowest_low: Previous period's lowest low value.
@@ -3366,6 +6258,155 @@ def willr_inc(prev_highest_high, prev_lowest_low, prev_high, prev_low, close, hi
     """
     ...
 
+def winrate(pnl, period):
+    """
+    Calculate rolling trade statistics (WINRATE) for a NumPy array of per-trade PnL
+
+    Summarizes the last `period` trades by their win rate, average win, average loss, profit
+    factor and expectancy. Breakeven trades count as trades but are neither wins nor losses.
+    Averages are 0 when their denominator is 0. Without losing trades the profit factor is
+    infinite if there was any profit and NaN if the window holds only breakeven trades.
+
+    Args:
+      pnl: Per-trade profit and loss as a 1-D NumPy array of type `TAFloat`.
+      period: Number of trades in the rolling window (must be >= 1).
+
+    Returns:
+      A tuple of nine 1-D NumPy arrays containing:
+      - Win rate
+      - Average winning trade
+      - Average losing trade, as a positive value
+      - Profit factor
+      - Expectancy
+      - Running count of winning trades
+      - Running count of losing trades
+      - Running sum of winning trades
+      - Running absolute sum of losing trades
+      Each array has the same length as the input, with the first (period-1) elements containing NaN values.
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> pnl = np.array([120.0, -50.0, 0.0, 80.0, -30.0])
+      >>> (winrate, avg_win, avg_loss, profit_factor, expectancy,
+      ...  wins, losses, gross_profit, gross_loss) = kand.winrate(pnl, 4)
+      ```
+    """
+    ...
+
+def winrate_expanding(pnl):
+    """
+    Calculate expanding trade statistics for a NumPy array of per-trade PnL
+
+    Uses every trade from the start up to each index, so the last element holds the statistics
+    of the whole sample.
+
+    Args:
+      pnl: Per-trade profit and loss as a 1-D NumPy array of type `TAFloat`.
+
+    Returns:
+      A tuple of nine 1-D NumPy arrays containing:
+      - Win rate
+      - Average winning trade
+      - Average losing trade, as a positive value
+      - Profit factor
+      - Expectancy
+      - Cumulative count of winning trades
+      - Cumulative count of losing trades
+      - Cumulative sum of winning trades
+      - Cumulative absolute sum of losing trades
+
+    Examples:
+      ```python
+      >>> import numpy as np
+      >>> import kand
+      >>> pnl = np.array([120.0, -50.0, 0.0, 80.0])
+      >>> (winrate, avg_win, avg_loss, profit_factor, expectancy,
+      ...  wins, losses, gross_profit, gross_loss) = kand.winrate_expanding(pnl)
+      >>> winrate[-1]
+      0.5
+      ```
+    """
+    ...
+
+def winrate_expanding_inc(pnl, prev_wins, prev_losses, prev_gross_profit, prev_gross_loss, prev_count):
+    """
+    Calculate the latest expanding trade statistics incrementally
+
+    Args:
+      py: Python interpreter token
+      pnl: PnL of the newest trade
+      prev_wins: Previous cumulative count of winning trades
+      prev_losses: Previous cumulative count of losing trades
+      prev_gross_profit: Previous cumulative sum of winning trades
+      prev_gross_loss: Previous cumulative absolute sum of losing trades
+      prev_count: Number of trades already included in the sums
+
+    Returns:
+      A tuple containing:
+      - Win rate
+      - Average winning trade
+      - Average losing trade
+      - Profit factor
+      - Expectancy
+      - New count of winning trades
+      - New count of losing trades
+      - New sum of winning trades
+      - New absolute sum of losing trades
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> (winrate, avg_win, avg_loss, profit_factor, expectancy,
+      ...  wins, losses, gross_profit, gross_loss) = kand.winrate_expanding_inc(
+      ...     80.0,  # newest trade
+      ...     1.0, 1.0, 120.0, 50.0,  # previous sums
+      ...     3  # trades so far
+      ... )
+      ```
+    """
+    ...
+
+def winrate_inc(pnl, old_pnl, prev_wins, prev_losses, prev_gross_profit, prev_gross_loss, period):
+    """
+    Calculate the latest rolling trade statistics incrementally
+
+    Args:
+      py: Python interpreter token
+      pnl: PnL of the newest trade
+      old_pnl: PnL of the trade leaving the window
+      prev_wins: Previous count of winning trades
+      prev_losses: Previous count of losing trades
+      prev_gross_profit: Previous sum of winning trades
+      prev_gross_loss: Previous absolute sum of losing trades
+      period: Number of trades in the rolling window (must be >= 1)
+
+    Returns:
+      A tuple containing:
+      - Win rate
+      - Average winning trade
+      - Average losing trade
+      - Profit factor
+      - Expectancy
+      - New count of winning trades
+      - New count of losing trades
+      - New sum of winning trades
+      - New absolute sum of losing trades
+
+    Examples:
+      ```python
+      >>> import kand
+      >>> (winrate, avg_win, avg_loss, profit_factor, expectancy,
+      ...  wins, losses, gross_profit, gross_loss) = kand.winrate_inc(
+      ...     -30.0, 120.0,  # newest trade, trade leaving the window
+      ...     2.0, 1.0, 200.0, 50.0,  # previous sums
+      ...     4  # period
+      ... )
+      ```
+    """
+    ...
+
 def wma(data, period):
     """
     Computes the Weighted Moving Average (WMA) over a NumPy array.
@@ -3417,3 +6458,187 @@ def wma_inc(input_window, period):
         ```
     """
     ...
+
+class Aroon:
+    """
+    Streaming Aroon indicator.
+
+    Keeps monotonic windows of the last `period + 1` highs and lows between calls, so every
+    update is O(1) amortized and the days since the extremes stay exact.
+    """
+    def __init__(self, period): ...
+    def update(self, high, low):
+        """Adds the next bar and returns (Aroon Up, Aroon Down), or None while the window is filling."""
+        ...
+    def reset(self):
+        """Clears the rolling windows."""
+        ...
+    @property
+    def is_ready(self):
+        """Whether enough bars were consumed for `update` to return values."""
+        ...
+    @property
+    def lookback(self):
+        """Number of bars consumed before the first value."""
+        ...
+
+class Aroonosc:
+    """
+    Streaming Aroon Oscillator.
+
+    Keeps monotonic windows of the last `period + 1` highs and lows between calls, so every
+    update is O(1) amortized and the days since the extremes stay exact.
+    """
+    def __init__(self, period): ...
+    def update(self, high, low):
+        """Adds the next bar and returns the oscillator, or None while the window is filling."""
+        ...
+    def reset(self):
+        """Clears the rolling windows."""
+        ...
+    @property
+    def is_ready(self):
+        """Whether enough bars were consumed for `update` to return values."""
+        ...
+    @property
+    def lookback(self):
+        """Number of bars consumed before the first value."""
+        ...
+
+class Dmi:
+    """
+    Streaming Directional Movement System.
+
+    Keeps one Wilder-smoothed +DM, -DM and TR state between calls and derives +DI, -DI, DX
+    and ADX from it, matching the `plus_di`, `minus_di`, `dx` and `adx` batch functions.
+    """
+    def __init__(self, period): ...
+    def update(self, high, low, close):
+        """Adds the next bar and returns (+DI, -DI, DX, ADX), or None while the smoothing warms up."""
+        ...
+    def reset(self):
+        """Clears the smoothed state."""
+        ...
+    @property
+    def is_ready(self):
+        """Whether enough bars were consumed for `update` to return values."""
+        ...
+    @property
+    def lookback(self):
+        """Number of bars consumed before the first value."""
+        ...
+
+class Max:
+    """
+    Streaming rolling Maximum Value.
+
+    Keeps a monotonic window of the last `period` prices between calls, so every update is
+    O(1) amortized and stays exact when the maximum leaves the window.
+    """
+    def __init__(self, period): ...
+    def update(self, price):
+        """Adds the next price and returns the MAX of the window, or None while it is filling."""
+        ...
+    def reset(self):
+        """Clears the rolling windows."""
+        ...
+    @property
+    def is_ready(self):
+        """Whether enough bars were consumed for `update` to return values."""
+        ...
+    @property
+    def lookback(self):
+        """Number of bars consumed before the first value."""
+        ...
+
+class Midpoint:
+    """
+    Streaming Midpoint.
+
+    Keeps monotonic windows of the last `period` prices between calls, so every update is
+    O(1) amortized and stays exact when an extreme leaves the window.
+    """
+    def __init__(self, period): ...
+    def update(self, price):
+        """Adds the next price and returns the midpoint, or None while the window is filling."""
+        ...
+    def reset(self):
+        """Clears the rolling windows."""
+        ...
+    @property
+    def is_ready(self):
+        """Whether enough bars were consumed for `update` to return values."""
+        ...
+    @property
+    def lookback(self):
+        """Number of bars consumed before the first value."""
+        ...
+
+class Midprice:
+    """
+    Streaming Midpoint Price.
+
+    Keeps monotonic windows of the last `period` highs and lows between calls, so every
+    update is O(1) amortized and stays exact when an extreme leaves the window.
+    """
+    def __init__(self, period): ...
+    def update(self, high, low):
+        """Adds the next bar and returns the midpoint price, or None while the window is filling."""
+        ...
+    def reset(self):
+        """Clears the rolling windows."""
+        ...
+    @property
+    def is_ready(self):
+        """Whether enough bars were consumed for `update` to return values."""
+        ...
+    @property
+    def lookback(self):
+        """Number of bars consumed before the first value."""
+        ...
+
+class Min:
+    """
+    Streaming rolling Minimum Value.
+
+    Keeps a monotonic window of the last `period` prices between calls, so every update is
+    O(1) amortized and stays exact when the minimum leaves the window.
+    """
+    def __init__(self, period): ...
+    def update(self, price):
+        """Adds the next price and returns the MIN of the window, or None while it is filling."""
+        ...
+    def reset(self):
+        """Clears the rolling windows."""
+        ...
+    @property
+    def is_ready(self):
+        """Whether enough bars were consumed for `update` to return values."""
+        ...
+    @property
+    def lookback(self):
+        """Number of bars consumed before the first value."""
+        ...
+
+class Willr:
+    """
+    Streaming Williams %R.
+
+    Keeps monotonic windows of the highest high and lowest low between calls, so every update
+    is O(1) amortized and stays exact when an extreme leaves the window.
+    """
+    def __init__(self, period): ...
+    def update(self, high, low, close):
+        """Adds the next bar and returns its Williams %R, or None while the window is filling."""
+        ...
+    def reset(self):
+        """Clears the rolling windows."""
+        ...
+    @property
+    def is_ready(self):
+        """Whether enough bars were consumed for `update` to return values."""
+        ...
+    @property
+    def lookback(self):
+        """Number of bars consumed before the first value."""
+        ...